@@ -0,0 +1,55 @@
+//! ## Backend
+//!
+//! `Backend` sets up the terminal and the input listener of the examples with the backend enabled by the features:
+//! crossterm by default, or termion with `--no-default-features --features termion`
+
+use std::time::Duration;
+
+use tuirealm::terminal::TerminalBridge;
+use tuirealm::EventListenerCfg;
+
+#[cfg(not(any(feature = "crossterm", feature = "termion")))]
+compile_error!("the examples require either the `crossterm` or the `termion` feature");
+
+#[cfg(feature = "crossterm")]
+pub use tuirealm::terminal::CrosstermTerminalAdapter as Adapter;
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+pub use tuirealm::terminal::TermionTerminalAdapter as Adapter;
+
+/// ### terminal
+///
+/// Initialize the terminal with the enabled backend
+pub fn terminal() -> TerminalBridge<Adapter> {
+    #[cfg(feature = "crossterm")]
+    let terminal = TerminalBridge::init_crossterm();
+    #[cfg(all(feature = "termion", not(feature = "crossterm")))]
+    let terminal = TerminalBridge::init_termion();
+    terminal.expect("Cannot create terminal bridge")
+}
+
+/// ## InputListener
+///
+/// Adds the input listener of the enabled backend to `EventListenerCfg`
+pub trait InputListener {
+    fn input_listener(self, interval: Duration, max_poll: usize) -> Self;
+}
+
+#[cfg(feature = "crossterm")]
+impl<U> InputListener for EventListenerCfg<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn input_listener(self, interval: Duration, max_poll: usize) -> Self {
+        self.crossterm_input_listener(interval, max_poll)
+    }
+}
+
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+impl<U> InputListener for EventListenerCfg<U>
+where
+    U: Eq + PartialEq + Clone + PartialOrd + Send + 'static,
+{
+    fn input_listener(self, interval: Duration, max_poll: usize) -> Self {
+        self.termion_input_listener(interval, max_poll)
+    }
+}
@@ -1,9 +1,12 @@
+mod backend;
+use backend::InputListener;
+
 use std::time::Duration;
 
 use tui_realm_stdlib::BarChart;
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{Alignment, BorderType, Borders, Color, Style};
-use tuirealm::terminal::{CrosstermTerminalAdapter, TerminalBridge};
+use tuirealm::terminal::TerminalBridge;
 use tuirealm::{
     application::PollStrategy,
     event::{Key, KeyEvent},
@@ -36,7 +39,7 @@ struct Model {
 impl Default for Model {
     fn default() -> Self {
         let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
-            EventListenerCfg::default().crossterm_input_listener(Duration::from_millis(10), 10),
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
         );
         assert!(app
             .mount(Id::ChartAlfa, Box::new(ChartAlfa::default()), vec![])
@@ -55,7 +58,7 @@ impl Default for Model {
 }
 
 impl Model {
-    fn view(&mut self, terminal: &mut TerminalBridge<CrosstermTerminalAdapter>) {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
         let _ = terminal.raw_mut().draw(|f| {
             // Prepare chunks
             let chunks = Layout::default()
@@ -77,7 +80,7 @@ impl Model {
 }
 
 fn main() {
-    let mut terminal = TerminalBridge::init_crossterm().expect("Cannot create terminal bridge");
+    let mut terminal = backend::terminal();
     let mut model = Model::default();
     let _ = terminal.enable_raw_mode();
     let _ = terminal.enter_alternate_screen();
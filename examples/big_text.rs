@@ -0,0 +1,149 @@
+//! ## Demo
+//!
+//! `Demo` shows how to use tui-realm in a real case
+
+mod backend;
+use backend::InputListener;
+
+use std::time::Duration;
+
+use tui_realm_stdlib::{BigText, BigTextFont};
+use tuirealm::command::CmdResult;
+use tuirealm::props::{Alignment, BorderType, Borders, Color};
+use tuirealm::terminal::TerminalBridge;
+use tuirealm::{
+    application::PollStrategy,
+    event::{Key, KeyEvent},
+    Application, Component, Event, EventListenerCfg, MockComponent, NoUserEvent, Update,
+};
+// tui
+use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection, Layout};
+
+#[derive(Debug, PartialEq)]
+pub enum Msg {
+    AppClose,
+    None,
+}
+
+// Let's define the component ids for our application
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum Id {
+    BigTextAlfa,
+}
+
+struct Model {
+    quit: bool,   // Becomes true when the user presses <ESC>
+    redraw: bool, // Tells whether to refresh the UI; performance optimization
+    app: Application<Id, Msg, NoUserEvent>,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        // Setup app
+        let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
+        );
+        assert!(app
+            .mount(Id::BigTextAlfa, Box::new(BigTextAlfa::default()), vec![])
+            .is_ok());
+        // We need to give focus to input then
+        assert!(app.active(&Id::BigTextAlfa).is_ok());
+        Self {
+            quit: false,
+            redraw: true,
+            app,
+        }
+    }
+}
+
+impl Model {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
+        let _ = terminal.raw_mut().draw(|f| {
+            // Prepare chunks
+            let chunks = Layout::default()
+                .direction(LayoutDirection::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(7), Constraint::Length(1)].as_ref())
+                .split(f.area());
+            self.app.view(&Id::BigTextAlfa, f, chunks[0]);
+        });
+    }
+}
+
+fn main() {
+    let mut model = Model::default();
+    let mut terminal = backend::terminal();
+    let _ = terminal.enable_raw_mode();
+    let _ = terminal.enter_alternate_screen();
+    // Now we use the Model struct to keep track of some states
+
+    // let's loop until quit is true
+    while !model.quit {
+        // Tick
+        if let Ok(messages) = model.app.tick(PollStrategy::Once) {
+            for msg in messages.into_iter() {
+                let mut msg = Some(msg);
+                while msg.is_some() {
+                    msg = model.update(msg);
+                }
+            }
+        }
+        // Redraw
+        if model.redraw {
+            model.view(&mut terminal);
+            model.redraw = false;
+        }
+    }
+    // Terminate terminal
+    let _ = terminal.leave_alternate_screen();
+    let _ = terminal.disable_raw_mode();
+    let _ = terminal.clear_screen();
+}
+
+impl Update<Msg> for Model {
+    fn update(&mut self, msg: Option<Msg>) -> Option<Msg> {
+        self.redraw = true;
+        match msg.unwrap_or(Msg::None) {
+            Msg::AppClose => {
+                self.quit = true;
+                None
+            }
+            Msg::None => None,
+        }
+    }
+}
+
+// -- components
+
+#[derive(MockComponent)]
+struct BigTextAlfa {
+    component: BigText,
+}
+
+impl Default for BigTextAlfa {
+    fn default() -> Self {
+        Self {
+            component: BigText::default()
+                .borders(
+                    Borders::default()
+                        .modifiers(BorderType::Rounded)
+                        .color(Color::LightYellow),
+                )
+                .title("Welcome", Alignment::Center)
+                .alignment(Alignment::Center)
+                .font(BigTextFont::Block)
+                .gradient(&[Color::Rgb(255, 94, 98), Color::Rgb(255, 195, 113)])
+                .text("tui-realm"),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for BigTextAlfa {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        let _ = match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
+            _ => CmdResult::None,
+        };
+        Some(Msg::None)
+    }
+}
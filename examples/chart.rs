@@ -2,7 +2,9 @@
 //!
 //! `Demo` shows how to use tui-realm in a real case
 
+mod backend;
 mod utils;
+use backend::InputListener;
 use utils::DataGen;
 
 use std::time::Duration;
@@ -18,7 +20,7 @@ use tuirealm::props::{
     Alignment, AttrValue, Attribute, BorderType, Borders, Color, Dataset, PropPayload, PropValue,
     Style,
 };
-use tuirealm::terminal::{CrosstermTerminalAdapter, TerminalBridge};
+use tuirealm::terminal::TerminalBridge;
 use tuirealm::{
     application::PollStrategy,
     event::{Key, KeyEvent},
@@ -55,7 +57,7 @@ impl Default for Model {
         // Setup app
         let mut app: Application<Id, Msg, UserEvent> = Application::init(
             EventListenerCfg::default()
-                .crossterm_input_listener(Duration::from_millis(10), 10)
+                .input_listener(Duration::from_millis(10), 10)
                 .add_port(
                     Box::new(DataGen::new((0.0, 0.0), (50.0, 35.0))),
                     Duration::from_millis(100),
@@ -76,7 +78,7 @@ impl Default for Model {
 }
 
 impl Model {
-    fn view(&mut self, terminal: &mut TerminalBridge<CrosstermTerminalAdapter>) {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
         let _ = terminal.raw_mut().draw(|f| {
             // Prepare chunks
             let chunks = Layout::default()
@@ -91,7 +93,7 @@ impl Model {
 
 fn main() {
     let mut model = Model::default();
-    let mut terminal = TerminalBridge::init_crossterm().expect("Cannot create terminal bridge");
+    let mut terminal = backend::terminal();
     let _ = terminal.enable_raw_mode();
     let _ = terminal.enter_alternate_screen();
     // Now we use the Model struct to keep track of some states
@@ -2,12 +2,15 @@
 //!
 //! `Demo` shows how to use tui-realm in a real case
 
+mod backend;
+use backend::InputListener;
+
 use std::time::Duration;
 
 use tui_realm_stdlib::Checkbox;
 use tuirealm::command::{Cmd, CmdResult, Direction};
 use tuirealm::props::{Alignment, BorderType, Borders, Color};
-use tuirealm::terminal::{CrosstermTerminalAdapter, TerminalBridge};
+use tuirealm::terminal::TerminalBridge;
 use tuirealm::{
     application::PollStrategy,
     event::{Key, KeyEvent},
@@ -41,7 +44,7 @@ impl Default for Model {
     fn default() -> Self {
         // Setup app
         let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
-            EventListenerCfg::default().crossterm_input_listener(Duration::from_millis(10), 10),
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
         );
         assert!(app
             .mount(Id::CheckboxAlfa, Box::new(CheckboxAlfa::default()), vec![])
@@ -60,7 +63,7 @@ impl Default for Model {
 }
 
 impl Model {
-    fn view(&mut self, terminal: &mut TerminalBridge<CrosstermTerminalAdapter>) {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
         let _ = terminal.raw_mut().draw(|f| {
             // Prepare chunks
             let chunks = Layout::default()
@@ -83,7 +86,7 @@ impl Model {
 
 fn main() {
     let mut model = Model::default();
-    let mut terminal = TerminalBridge::init_crossterm().expect("Cannot create terminal bridge");
+    let mut terminal = backend::terminal();
     let _ = terminal.enable_raw_mode();
     let _ = terminal.enter_alternate_screen();
     // Now we use the Model struct to keep track of some states
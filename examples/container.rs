@@ -2,12 +2,16 @@
 //!
 //! `Demo` shows how to use tui-realm in a real case
 
+mod backend;
+use backend::InputListener;
+
 use std::time::Duration;
 
-use tui_realm_stdlib::{Container, Table};
-use tuirealm::command::CmdResult;
+use tui_realm_stdlib::commands::CONTAINER_CMD_FOCUS_NEXT;
+use tui_realm_stdlib::{Container, ContainerBreakpoint, Table};
+use tuirealm::command::{Cmd, CmdResult, Direction};
 use tuirealm::props::{Alignment, BorderType, Borders, Color, Layout, TableBuilder, TextSpan};
-use tuirealm::terminal::{CrosstermTerminalAdapter, TerminalBridge};
+use tuirealm::terminal::TerminalBridge;
 use tuirealm::{
     application::PollStrategy,
     event::{Key, KeyEvent},
@@ -38,7 +42,7 @@ impl Default for Model {
     fn default() -> Self {
         // Setup app
         let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
-            EventListenerCfg::default().crossterm_input_listener(Duration::from_millis(10), 10),
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
         );
         assert!(app
             .mount(Id::Container, Box::new(MyContainer::default()), vec![])
@@ -54,7 +58,7 @@ impl Default for Model {
 }
 
 impl Model {
-    fn view(&mut self, terminal: &mut TerminalBridge<CrosstermTerminalAdapter>) {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
         let _ = terminal.raw_mut().draw(|f| {
             // Prepare chunks
             let chunks = TuiLayout::default()
@@ -69,7 +73,7 @@ impl Model {
 
 fn main() {
     let mut model = Model::default();
-    let mut terminal = TerminalBridge::init_crossterm().expect("Cannot create terminal bridge");
+    let mut terminal = backend::terminal();
     let _ = terminal.enable_raw_mode();
     let _ = terminal.enter_alternate_screen();
     // Now we use the Model struct to keep track of some states
@@ -121,12 +125,25 @@ impl Default for MyContainer {
             component: Container::default()
                 .background(Color::Yellow)
                 .foreground(Color::Yellow)
-                .title("This is a div with two tables", Alignment::Left)
+                .title(
+                    "This is a div with two tables; <TAB> to switch table",
+                    Alignment::Left,
+                )
+                // Tables are stacked, unless there are at least 120 columns
                 .layout(
                     Layout::default()
-                        .constraints(&[Constraint::Percentage(30), Constraint::Percentage(70)])
-                        .direction(LayoutDirection::Horizontal)
-                        .margin(2),
+                        .constraints(&[Constraint::Percentage(50), Constraint::Percentage(50)])
+                        .direction(LayoutDirection::Vertical)
+                        .margin(1),
+                )
+                .breakpoint(
+                    ContainerBreakpoint::new(
+                        Layout::default()
+                            .constraints(&[Constraint::Percentage(30), Constraint::Percentage(70)])
+                            .direction(LayoutDirection::Horizontal)
+                            .margin(1),
+                    )
+                    .min_width(120),
                 )
                 .children(vec![
                     Box::new(
@@ -229,7 +246,8 @@ impl Default for MyContainer {
                                     .build(),
                             ),
                     ),
-                ]),
+                ])
+                .focused(0),
         }
     }
 }
@@ -237,6 +255,15 @@ impl Default for MyContainer {
 impl Component<Msg, NoUserEvent> for MyContainer {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
         let _ = match ev {
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
+                self.perform(Cmd::Custom(CONTAINER_CMD_FOCUS_NEXT))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => self.perform(Cmd::Move(Direction::Down)),
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up))
+            }
             Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
             _ => CmdResult::None,
         };
@@ -0,0 +1,208 @@
+//! ## Demo
+//!
+//! `Demo` shows how to use tui-realm in a real case
+
+mod backend;
+use backend::InputListener;
+
+use std::time::Duration;
+
+use tui_realm_stdlib::commands::{FORM_CMD_NEXT_FIELD, FORM_CMD_PREV_FIELD};
+use tui_realm_stdlib::{Form, FormField, Input, Radio};
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::props::{Alignment, BorderType, Borders, Color, InputType};
+use tuirealm::terminal::TerminalBridge;
+use tuirealm::{
+    application::PollStrategy,
+    event::{Key, KeyEvent},
+    Application, Component, Event, EventListenerCfg, MockComponent, NoUserEvent, State, StateValue,
+    Update,
+};
+// tui
+use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection, Layout};
+
+#[derive(Debug, PartialEq)]
+pub enum Msg {
+    AppClose,
+    None,
+}
+
+// Let's define the component ids for our application
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum Id {
+    FormAlfa,
+}
+
+struct Model {
+    quit: bool,   // Becomes true when the user presses <ESC>
+    redraw: bool, // Tells whether to refresh the UI; performance optimization
+    app: Application<Id, Msg, NoUserEvent>,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        // Setup app
+        let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
+        );
+        assert!(app
+            .mount(Id::FormAlfa, Box::new(FormAlfa::default()), vec![])
+            .is_ok());
+        // We need to give focus to input then
+        assert!(app.active(&Id::FormAlfa).is_ok());
+        Self {
+            quit: false,
+            redraw: true,
+            app,
+        }
+    }
+}
+
+impl Model {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
+        let _ = terminal.raw_mut().draw(|f| {
+            // Prepare chunks
+            let chunks = Layout::default()
+                .direction(LayoutDirection::Vertical)
+                .margin(1)
+                .constraints([Constraint::Min(12), Constraint::Length(1)].as_ref())
+                .split(f.area());
+            self.app.view(&Id::FormAlfa, f, chunks[0]);
+        });
+    }
+}
+
+fn main() {
+    let mut model = Model::default();
+    let mut terminal = backend::terminal();
+    let _ = terminal.enable_raw_mode();
+    let _ = terminal.enter_alternate_screen();
+    // Now we use the Model struct to keep track of some states
+
+    // let's loop until quit is true
+    while !model.quit {
+        // Tick
+        if let Ok(messages) = model.app.tick(PollStrategy::Once) {
+            for msg in messages.into_iter() {
+                let mut msg = Some(msg);
+                while msg.is_some() {
+                    msg = model.update(msg);
+                }
+            }
+        }
+        // Redraw
+        if model.redraw {
+            model.view(&mut terminal);
+            model.redraw = false;
+        }
+    }
+    // Terminate terminal
+    let _ = terminal.leave_alternate_screen();
+    let _ = terminal.disable_raw_mode();
+    let _ = terminal.clear_screen();
+}
+
+impl Update<Msg> for Model {
+    fn update(&mut self, msg: Option<Msg>) -> Option<Msg> {
+        self.redraw = true;
+        match msg.unwrap_or(Msg::None) {
+            Msg::AppClose => {
+                self.quit = true;
+                None
+            }
+            Msg::None => None,
+        }
+    }
+}
+
+// -- components
+
+#[derive(MockComponent)]
+struct FormAlfa {
+    component: Form,
+}
+
+impl Default for FormAlfa {
+    fn default() -> Self {
+        Self {
+            component: Form::default()
+                .borders(
+                    Borders::default()
+                        .modifiers(BorderType::Rounded)
+                        .color(Color::LightYellow),
+                )
+                .title(
+                    "Sign up (<TAB> next field, <ENTER> submit)",
+                    Alignment::Center,
+                )
+                .highlighted_color(Color::LightYellow)
+                .fields(vec![
+                    FormField::new(
+                        "Username",
+                        Box::new(
+                            Input::default()
+                                .borders(Borders::default().modifiers(BorderType::Rounded))
+                                .input_type(InputType::Text),
+                        ),
+                    )
+                    .validator(|state| match state {
+                        State::One(StateValue::String(x)) if x.len() >= 3 => Ok(()),
+                        _ => Err(String::from("at least 3 characters")),
+                    }),
+                    FormField::new(
+                        "Email",
+                        Box::new(
+                            Input::default()
+                                .borders(Borders::default().modifiers(BorderType::Rounded))
+                                .input_type(InputType::Email),
+                        ),
+                    )
+                    .validator(|state| match state {
+                        State::One(StateValue::String(_)) => Ok(()),
+                        _ => Err(String::from("invalid email address")),
+                    }),
+                    FormField::new(
+                        "Role",
+                        Box::new(
+                            Radio::default()
+                                .borders(Borders::default().modifiers(BorderType::Rounded))
+                                .choices(&["Developer", "Designer", "Manager"]),
+                        ),
+                    ),
+                ]),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for FormAlfa {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        let _ = match ev {
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
+                self.perform(Cmd::Custom(FORM_CMD_NEXT_FIELD))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::BackTab, ..
+            }) => self.perform(Cmd::Custom(FORM_CMD_PREV_FIELD)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => self.perform(Cmd::Submit),
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => self.perform(Cmd::Move(Direction::Left)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => self.perform(Cmd::Move(Direction::Right)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Backspace,
+                ..
+            }) => self.perform(Cmd::Delete),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(ch),
+                ..
+            }) => self.perform(Cmd::Type(ch)),
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
+            _ => CmdResult::None,
+        };
+        Some(Msg::None)
+    }
+}
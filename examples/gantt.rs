@@ -0,0 +1,206 @@
+//! ## Demo
+//!
+//! `Demo` shows how to use tui-realm in a real case
+
+mod backend;
+use backend::InputListener;
+
+use std::time::Duration;
+
+use tui_realm_stdlib::commands::{GANTT_CMD_ZOOM_IN, GANTT_CMD_ZOOM_OUT};
+use tui_realm_stdlib::{Gantt, GanttTask};
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{Alignment, BorderType, Borders, Color};
+use tuirealm::terminal::TerminalBridge;
+use tuirealm::{
+    application::PollStrategy,
+    event::{Key, KeyEvent},
+    Application, Component, Event, EventListenerCfg, MockComponent, NoUserEvent, Update,
+};
+// tui
+use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection, Layout};
+
+/// 2024-03-04 00:00:00 UTC
+const START: i64 = 1709510400;
+const DAY: i64 = 86400;
+
+#[derive(Debug, PartialEq)]
+pub enum Msg {
+    AppClose,
+    None,
+}
+
+// Let's define the component ids for our application
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum Id {
+    GanttAlfa,
+}
+
+struct Model {
+    quit: bool,   // Becomes true when the user presses <ESC>
+    redraw: bool, // Tells whether to refresh the UI; performance optimization
+    app: Application<Id, Msg, NoUserEvent>,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        // Setup app
+        let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
+        );
+        assert!(app
+            .mount(Id::GanttAlfa, Box::new(GanttAlfa::default()), vec![])
+            .is_ok());
+        // We need to give focus to input then
+        assert!(app.active(&Id::GanttAlfa).is_ok());
+        Self {
+            quit: false,
+            redraw: true,
+            app,
+        }
+    }
+}
+
+impl Model {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
+        let _ = terminal.raw_mut().draw(|f| {
+            // Prepare chunks
+            let chunks = Layout::default()
+                .direction(LayoutDirection::Vertical)
+                .margin(1)
+                .constraints([Constraint::Min(10), Constraint::Length(1)].as_ref())
+                .split(f.area());
+            self.app.view(&Id::GanttAlfa, f, chunks[0]);
+        });
+    }
+}
+
+fn main() {
+    let mut model = Model::default();
+    let mut terminal = backend::terminal();
+    let _ = terminal.enable_raw_mode();
+    let _ = terminal.enter_alternate_screen();
+    // Now we use the Model struct to keep track of some states
+
+    // let's loop until quit is true
+    while !model.quit {
+        // Tick
+        if let Ok(messages) = model.app.tick(PollStrategy::Once) {
+            for msg in messages.into_iter() {
+                let mut msg = Some(msg);
+                while msg.is_some() {
+                    msg = model.update(msg);
+                }
+            }
+        }
+        // Redraw
+        if model.redraw {
+            model.view(&mut terminal);
+            model.redraw = false;
+        }
+    }
+    // Terminate terminal
+    let _ = terminal.leave_alternate_screen();
+    let _ = terminal.disable_raw_mode();
+    let _ = terminal.clear_screen();
+}
+
+impl Update<Msg> for Model {
+    fn update(&mut self, msg: Option<Msg>) -> Option<Msg> {
+        self.redraw = true;
+        match msg.unwrap_or(Msg::None) {
+            Msg::AppClose => {
+                self.quit = true;
+                None
+            }
+            Msg::None => None,
+        }
+    }
+}
+
+// -- components
+
+#[derive(MockComponent)]
+struct GanttAlfa {
+    component: Gantt,
+}
+
+impl Default for GanttAlfa {
+    fn default() -> Self {
+        Self {
+            component: Gantt::default()
+                .borders(
+                    Borders::default()
+                        .modifiers(BorderType::Rounded)
+                        .color(Color::LightCyan),
+                )
+                .title("Roadmap (<+/-> to zoom)", Alignment::Center)
+                .highlighted_color(Color::LightCyan)
+                .axis_format("%b %d")
+                .start(START - 2 * DAY)
+                .today(START + 9 * DAY)
+                .tasks(&[
+                    GanttTask::new("Specification", START, START + 5 * DAY).color(Color::LightBlue),
+                    GanttTask::new("Design", START + 3 * DAY, START + 10 * DAY)
+                        .color(Color::LightBlue)
+                        .depends_on(0),
+                    GanttTask::new("Backend", START + 10 * DAY, START + 24 * DAY)
+                        .color(Color::LightGreen)
+                        .depends_on(1),
+                    GanttTask::new("Frontend", START + 12 * DAY, START + 26 * DAY)
+                        .color(Color::LightGreen)
+                        .depends_on(1),
+                    GanttTask::new("Testing", START + 24 * DAY, START + 31 * DAY)
+                        .color(Color::LightYellow)
+                        .depends_on(2)
+                        .depends_on(3),
+                    GanttTask::new("Release", START + 32 * DAY, START + 32 * DAY)
+                        .color(Color::LightMagenta)
+                        .depends_on(4),
+                ]),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for GanttAlfa {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        let _ = match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => self.perform(Cmd::Move(Direction::Down)),
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => self.perform(Cmd::Move(Direction::Left)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => self.perform(Cmd::Move(Direction::Right)),
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => self.perform(Cmd::Scroll(Direction::Left)),
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => self.perform(Cmd::Scroll(Direction::Right)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => self.perform(Cmd::GoTo(Position::Begin)),
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('+'),
+                ..
+            }) => self.perform(Cmd::Custom(GANTT_CMD_ZOOM_IN)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('-'),
+                ..
+            }) => self.perform(Cmd::Custom(GANTT_CMD_ZOOM_OUT)),
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
+            _ => CmdResult::None,
+        };
+        Some(Msg::None)
+    }
+}
@@ -0,0 +1,165 @@
+//! ## Demo
+//!
+//! `Demo` shows how to use tui-realm in a real case
+
+mod backend;
+use backend::InputListener;
+
+use std::time::Duration;
+
+use tui_realm_stdlib::Heatmap;
+use tuirealm::command::CmdResult;
+use tuirealm::props::{Alignment, BorderType, Borders, Color};
+use tuirealm::terminal::TerminalBridge;
+use tuirealm::{
+    application::PollStrategy,
+    event::{Key, KeyEvent},
+    Application, Component, Event, EventListenerCfg, MockComponent, NoUserEvent, Update,
+};
+// tui
+use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection, Layout};
+
+#[derive(Debug, PartialEq)]
+pub enum Msg {
+    AppClose,
+    None,
+}
+
+// Let's define the component ids for our application
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum Id {
+    HeatmapAlfa,
+}
+
+struct Model {
+    quit: bool,   // Becomes true when the user presses <ESC>
+    redraw: bool, // Tells whether to refresh the UI; performance optimization
+    app: Application<Id, Msg, NoUserEvent>,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        // Setup app
+        let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
+        );
+        assert!(app
+            .mount(Id::HeatmapAlfa, Box::new(HeatmapAlfa::default()), vec![])
+            .is_ok());
+        // We need to give focus to input then
+        assert!(app.active(&Id::HeatmapAlfa).is_ok());
+        Self {
+            quit: false,
+            redraw: true,
+            app,
+        }
+    }
+}
+
+impl Model {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
+        let _ = terminal.raw_mut().draw(|f| {
+            // Prepare chunks
+            let chunks = Layout::default()
+                .direction(LayoutDirection::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(10), Constraint::Length(1)].as_ref())
+                .split(f.area());
+            self.app.view(&Id::HeatmapAlfa, f, chunks[0]);
+        });
+    }
+}
+
+fn main() {
+    let mut model = Model::default();
+    let mut terminal = backend::terminal();
+    let _ = terminal.enable_raw_mode();
+    let _ = terminal.enter_alternate_screen();
+    // Now we use the Model struct to keep track of some states
+
+    // let's loop until quit is true
+    while !model.quit {
+        // Tick
+        if let Ok(messages) = model.app.tick(PollStrategy::Once) {
+            for msg in messages.into_iter() {
+                let mut msg = Some(msg);
+                while msg.is_some() {
+                    msg = model.update(msg);
+                }
+            }
+        }
+        // Redraw
+        if model.redraw {
+            model.view(&mut terminal);
+            model.redraw = false;
+        }
+    }
+    // Terminate terminal
+    let _ = terminal.leave_alternate_screen();
+    let _ = terminal.disable_raw_mode();
+    let _ = terminal.clear_screen();
+}
+
+impl Update<Msg> for Model {
+    fn update(&mut self, msg: Option<Msg>) -> Option<Msg> {
+        self.redraw = true;
+        match msg.unwrap_or(Msg::None) {
+            Msg::AppClose => {
+                self.quit = true;
+                None
+            }
+            Msg::None => None,
+        }
+    }
+}
+
+// -- components
+
+#[derive(MockComponent)]
+struct HeatmapAlfa {
+    component: Heatmap,
+}
+
+impl Default for HeatmapAlfa {
+    fn default() -> Self {
+        Self {
+            component: Heatmap::default()
+                .title("commits per day", Alignment::Center)
+                .borders(
+                    Borders::default()
+                        .modifiers(BorderType::Rounded)
+                        .color(Color::LightGreen),
+                )
+                .foreground(Color::LightGreen)
+                .colors(&[
+                    Color::Rgb(22, 27, 34),
+                    Color::Rgb(14, 68, 41),
+                    Color::Rgb(0, 109, 50),
+                    Color::Rgb(38, 166, 65),
+                    Color::Rgb(57, 211, 83),
+                ])
+                .cell_width(3)
+                .x_labels(&["w1", "w2", "w3", "w4", "w5", "w6", "w7", "w8"])
+                .y_labels(&["mon", "tue", "wed", "thu", "fri", "sat", "sun"])
+                .data(&[
+                    &[0.0, 2.0, 4.0, 1.0, 0.0, 7.0, 3.0, 2.0],
+                    &[1.0, 5.0, 8.0, 2.0, 3.0, 6.0, 4.0, 0.0],
+                    &[3.0, 0.0, 2.0, 9.0, 4.0, 1.0, 5.0, 3.0],
+                    &[6.0, 2.0, 1.0, 4.0, 8.0, 2.0, 0.0, 1.0],
+                    &[2.0, 7.0, 3.0, 0.0, 5.0, 3.0, 2.0, 6.0],
+                    &[0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 1.0, 0.0],
+                    &[0.0, 1.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0],
+                ]),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for HeatmapAlfa {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        let _ = match ev {
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
+            _ => CmdResult::None,
+        };
+        Some(Msg::None)
+    }
+}
@@ -2,6 +2,9 @@
 //!
 //! `Demo` shows how to use tui-realm in a real case
 
+mod backend;
+use backend::InputListener;
+
 use std::time::Duration;
 
 use tui_realm_stdlib::Input;
@@ -10,7 +13,7 @@ use tuirealm::event::KeyModifiers;
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, BorderType, Borders, Color, InputType, Style,
 };
-use tuirealm::terminal::{CrosstermTerminalAdapter, TerminalBridge};
+use tuirealm::terminal::TerminalBridge;
 use tuirealm::{
     application::PollStrategy,
     event::{Key, KeyEvent},
@@ -53,7 +56,7 @@ impl Default for Model {
     fn default() -> Self {
         // Setup app
         let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
-            EventListenerCfg::default().crossterm_input_listener(Duration::from_millis(10), 10),
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
         );
         assert!(app
             .mount(Id::Text, Box::new(InputText::default()), vec![])
@@ -84,7 +87,7 @@ impl Default for Model {
 }
 
 impl Model {
-    fn view(&mut self, terminal: &mut TerminalBridge<CrosstermTerminalAdapter>) {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
         let _ = terminal.raw_mut().draw(|f| {
             // Prepare chunks
             let chunks = Layout::default()
@@ -115,7 +118,7 @@ impl Model {
 
 fn main() {
     let mut model = Model::default();
-    let mut terminal = TerminalBridge::init_crossterm().expect("Cannot create terminal bridge");
+    let mut terminal = backend::terminal();
     let _ = terminal.enable_raw_mode();
     let _ = terminal.enter_alternate_screen();
     // Now we use the Model struct to keep track of some states
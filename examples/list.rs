@@ -2,12 +2,16 @@
 //!
 //! `Demo` shows how to use tui-realm in a real case
 
+mod backend;
+use backend::InputListener;
+
 use std::time::Duration;
 
+use tui_realm_stdlib::keymap::KeyMap;
 use tui_realm_stdlib::List;
-use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::command::CmdResult;
 use tuirealm::props::{Alignment, BorderType, Borders, Color, TableBuilder, TextSpan};
-use tuirealm::terminal::{CrosstermTerminalAdapter, TerminalBridge};
+use tuirealm::terminal::TerminalBridge;
 use tuirealm::{
     application::PollStrategy,
     event::{Key, KeyEvent},
@@ -41,7 +45,7 @@ impl Default for Model {
     fn default() -> Self {
         // Setup app
         let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
-            EventListenerCfg::default().crossterm_input_listener(Duration::from_millis(10), 10),
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
         );
         assert!(app
             .mount(Id::ListAlfa, Box::new(ListAlfa::default()), vec![])
@@ -60,7 +64,7 @@ impl Default for Model {
 }
 
 impl Model {
-    fn view(&mut self, terminal: &mut TerminalBridge<CrosstermTerminalAdapter>) {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
         let _ = terminal.raw_mut().draw(|f| {
             // Prepare chunks
             let chunks = Layout::default()
@@ -82,7 +86,7 @@ impl Model {
 }
 
 fn main() {
-    let mut terminal = TerminalBridge::init_crossterm().expect("Cannot create terminal bridge");
+    let mut terminal = backend::terminal();
     let mut model = Model::default();
     let _ = terminal.enable_raw_mode();
     let _ = terminal.enter_alternate_screen();
@@ -135,6 +139,7 @@ impl Update<Msg> for Model {
 #[derive(MockComponent)]
 struct ListAlfa {
     component: List,
+    keymap: KeyMap,
 }
 
 impl Default for ListAlfa {
@@ -208,37 +213,22 @@ impl Default for ListAlfa {
                         .build()
                 )
                 .selected_line(2),
+            keymap: KeyMap::list(),
         }
     }
 }
 
 impl Component<Msg, NoUserEvent> for ListAlfa {
     fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
-        let _ = match ev {
-            Event::Keyboard(KeyEvent {
-                code: Key::Down, ..
-            }) => self.perform(Cmd::Move(Direction::Down)),
-            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
-                self.perform(Cmd::Move(Direction::Up))
-            }
-            Event::Keyboard(KeyEvent {
-                code: Key::PageDown,
-                ..
-            }) => self.perform(Cmd::Scroll(Direction::Down)),
-            Event::Keyboard(KeyEvent {
-                code: Key::PageUp, ..
-            }) => self.perform(Cmd::Scroll(Direction::Up)),
-            Event::Keyboard(KeyEvent {
-                code: Key::Home, ..
-            }) => self.perform(Cmd::GoTo(Position::Begin)),
-            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
-                self.perform(Cmd::GoTo(Position::End))
-            }
-            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => return Some(Msg::ListAlfaBlur),
-            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
-            _ => CmdResult::None,
-        };
-        Some(Msg::None)
+        if let Some(cmd) = self.keymap.resolve(&ev) {
+            self.perform(cmd);
+            return Some(Msg::None);
+        }
+        match ev {
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => Some(Msg::ListAlfaBlur),
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => Some(Msg::AppClose),
+            _ => Some(Msg::None),
+        }
     }
 }
 
@@ -0,0 +1,176 @@
+//! ## Demo
+//!
+//! `Demo` shows how to use tui-realm in a real case
+
+mod backend;
+use backend::InputListener;
+
+use std::time::Duration;
+
+use rand::Rng;
+use tui_realm_stdlib::Meter;
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, BorderType, Borders, Color, PropPayload, PropValue,
+};
+use tuirealm::terminal::TerminalBridge;
+use tuirealm::{
+    application::PollStrategy,
+    event::{Key, KeyEvent},
+    Application, Component, Event, EventListenerCfg, MockComponent, NoUserEvent, Update,
+};
+// tui
+use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection, Layout};
+
+const CORES: usize = 8;
+
+#[derive(Debug, PartialEq)]
+pub enum Msg {
+    AppClose,
+    None,
+}
+
+// Let's define the component ids for our application
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum Id {
+    MeterAlfa,
+}
+
+struct Model {
+    quit: bool,   // Becomes true when the user presses <ESC>
+    redraw: bool, // Tells whether to refresh the UI; performance optimization
+    app: Application<Id, Msg, NoUserEvent>,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        // Setup app
+        let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
+            EventListenerCfg::default()
+                .input_listener(Duration::from_millis(10), 10)
+                .tick_interval(Duration::from_millis(100)),
+        );
+        assert!(app
+            .mount(Id::MeterAlfa, Box::new(MeterAlfa::default()), vec![])
+            .is_ok());
+        // We need to give focus to input then
+        assert!(app.active(&Id::MeterAlfa).is_ok());
+        Self {
+            quit: false,
+            redraw: true,
+            app,
+        }
+    }
+}
+
+impl Model {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
+        let _ = terminal.raw_mut().draw(|f| {
+            // Prepare chunks
+            let chunks = Layout::default()
+                .direction(LayoutDirection::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(14), Constraint::Length(1)].as_ref())
+                .split(f.area());
+            self.app.view(&Id::MeterAlfa, f, chunks[0]);
+        });
+    }
+}
+
+fn main() {
+    let mut model = Model::default();
+    let mut terminal = backend::terminal();
+    let _ = terminal.enable_raw_mode();
+    let _ = terminal.enter_alternate_screen();
+    // Now we use the Model struct to keep track of some states
+
+    // let's loop until quit is true
+    while !model.quit {
+        // Tick
+        if let Ok(messages) = model.app.tick(PollStrategy::Once) {
+            for msg in messages.into_iter() {
+                let mut msg = Some(msg);
+                while msg.is_some() {
+                    msg = model.update(msg);
+                }
+            }
+        }
+        // Redraw
+        if model.redraw {
+            model.view(&mut terminal);
+            model.redraw = false;
+        }
+    }
+    // Terminate terminal
+    let _ = terminal.leave_alternate_screen();
+    let _ = terminal.disable_raw_mode();
+    let _ = terminal.clear_screen();
+}
+
+impl Update<Msg> for Model {
+    fn update(&mut self, msg: Option<Msg>) -> Option<Msg> {
+        self.redraw = true;
+        match msg.unwrap_or(Msg::None) {
+            Msg::AppClose => {
+                self.quit = true;
+                None
+            }
+            Msg::None => None,
+        }
+    }
+}
+
+// -- components
+
+#[derive(MockComponent)]
+struct MeterAlfa {
+    component: Meter,
+    values: [f64; CORES],
+}
+
+impl Default for MeterAlfa {
+    fn default() -> Self {
+        Self {
+            component: Meter::default()
+                .borders(
+                    Borders::default()
+                        .modifiers(BorderType::Rounded)
+                        .color(Color::LightBlue),
+                )
+                .title("CPU usage per core", Alignment::Center)
+                .labels(&["c0", "c1", "c2", "c3", "c4", "c5", "c6", "c7"])
+                .values(&[0.0; CORES])
+                .bar_width(2)
+                .peak_hold(10),
+            values: [0.0; CORES],
+        }
+    }
+}
+
+impl MeterAlfa {
+    /// Move the usage of each core randomly, then age the peaks
+    fn update_values(&mut self) -> CmdResult {
+        let mut rng = rand::thread_rng();
+        for value in self.values.iter_mut() {
+            *value = (*value + rng.gen_range(-20.0..20.0)).clamp(0.0, 100.0);
+        }
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::Vec(
+                self.values.iter().copied().map(PropValue::F64).collect(),
+            )),
+        );
+        self.perform(Cmd::Tick)
+    }
+}
+
+impl Component<Msg, NoUserEvent> for MeterAlfa {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        let _ = match ev {
+            Event::Tick => self.update_values(),
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
+            _ => CmdResult::None,
+        };
+        Some(Msg::None)
+    }
+}
@@ -0,0 +1,218 @@
+//! ## Demo
+//!
+//! `Demo` shows how to use tui-realm in a real case
+
+mod backend;
+use backend::InputListener;
+
+use std::time::Duration;
+
+use tui_realm_stdlib::commands::{OUTLINE_CMD_COLLAPSE_LEVEL, OUTLINE_CMD_EXPAND_ALL};
+use tui_realm_stdlib::props::OUTLINE_ACTIVE;
+use tui_realm_stdlib::{Outline, OutlineEntry};
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, BorderType, Borders, Color, PropPayload, PropValue,
+};
+use tuirealm::terminal::TerminalBridge;
+use tuirealm::{
+    application::PollStrategy,
+    event::{Key, KeyEvent},
+    Application, Component, Event, EventListenerCfg, MockComponent, NoUserEvent, Update,
+};
+use tuirealm::{State, StateValue};
+// tui
+use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection, Layout};
+
+#[derive(Debug, PartialEq)]
+pub enum Msg {
+    AppClose,
+    None,
+}
+
+// Let's define the component ids for our application
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum Id {
+    OutlineAlfa,
+}
+
+struct Model {
+    quit: bool,   // Becomes true when the user presses <ESC>
+    redraw: bool, // Tells whether to refresh the UI; performance optimization
+    app: Application<Id, Msg, NoUserEvent>,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        // Setup app
+        let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
+        );
+        assert!(app
+            .mount(Id::OutlineAlfa, Box::new(OutlineAlfa::default()), vec![])
+            .is_ok());
+        // We need to give focus to input then
+        assert!(app.active(&Id::OutlineAlfa).is_ok());
+        Self {
+            quit: false,
+            redraw: true,
+            app,
+        }
+    }
+}
+
+impl Model {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
+        let _ = terminal.raw_mut().draw(|f| {
+            // Prepare chunks
+            let chunks = Layout::default()
+                .direction(LayoutDirection::Vertical)
+                .margin(1)
+                .constraints([Constraint::Length(14), Constraint::Length(1)].as_ref())
+                .split(f.area());
+            self.app.view(&Id::OutlineAlfa, f, chunks[0]);
+        });
+    }
+}
+
+fn main() {
+    let mut model = Model::default();
+    let mut terminal = backend::terminal();
+    let _ = terminal.enable_raw_mode();
+    let _ = terminal.enter_alternate_screen();
+    // Now we use the Model struct to keep track of some states
+
+    // let's loop until quit is true
+    while !model.quit {
+        // Tick
+        if let Ok(messages) = model.app.tick(PollStrategy::Once) {
+            for msg in messages.into_iter() {
+                let mut msg = Some(msg);
+                while msg.is_some() {
+                    msg = model.update(msg);
+                }
+            }
+        }
+        // Redraw
+        if model.redraw {
+            model.view(&mut terminal);
+            model.redraw = false;
+        }
+    }
+    // Terminate terminal
+    let _ = terminal.leave_alternate_screen();
+    let _ = terminal.disable_raw_mode();
+    let _ = terminal.clear_screen();
+}
+
+impl Update<Msg> for Model {
+    fn update(&mut self, msg: Option<Msg>) -> Option<Msg> {
+        self.redraw = true;
+        match msg.unwrap_or(Msg::None) {
+            Msg::AppClose => {
+                self.quit = true;
+                None
+            }
+            Msg::None => None,
+        }
+    }
+}
+
+// -- components
+
+#[derive(MockComponent)]
+struct OutlineAlfa {
+    component: Outline,
+}
+
+impl Default for OutlineAlfa {
+    fn default() -> Self {
+        Self {
+            component: Outline::default()
+                .borders(
+                    Borders::default()
+                        .modifiers(BorderType::Rounded)
+                        .color(Color::LightBlue),
+                )
+                .foreground(Color::White)
+                .highlighted_color(Color::LightYellow)
+                .title("Contents", Alignment::Left)
+                .entries(&[
+                    OutlineEntry::new(1, "Introduction"),
+                    OutlineEntry::new(2, "Goals"),
+                    OutlineEntry::new(2, "Non-goals"),
+                    OutlineEntry::new(1, "Getting started"),
+                    OutlineEntry::new(2, "Installation"),
+                    OutlineEntry::new(3, "From crates.io"),
+                    OutlineEntry::new(3, "From source"),
+                    OutlineEntry::new(2, "First application"),
+                    OutlineEntry::new(1, "Components"),
+                    OutlineEntry::new(2, "Layout"),
+                    OutlineEntry::new(2, "Input"),
+                    OutlineEntry::new(1, "License"),
+                ])
+                .active(0),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for OutlineAlfa {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        let _ = match ev {
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => self.perform(Cmd::Move(Direction::Down)),
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Right, ..
+            }) => self.perform(Cmd::Move(Direction::Right)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Left, ..
+            }) => self.perform(Cmd::Move(Direction::Left)),
+            Event::Keyboard(KeyEvent {
+                code: Key::PageDown,
+                ..
+            }) => self.perform(Cmd::Scroll(Direction::Down)),
+            Event::Keyboard(KeyEvent {
+                code: Key::PageUp, ..
+            }) => self.perform(Cmd::Scroll(Direction::Up)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Home, ..
+            }) => self.perform(Cmd::GoTo(Position::Begin)),
+            Event::Keyboard(KeyEvent { code: Key::End, .. }) => {
+                self.perform(Cmd::GoTo(Position::End))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char(' '),
+                ..
+            }) => self.perform(Cmd::Toggle),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('c'),
+                ..
+            }) => self.perform(Cmd::Custom(OUTLINE_CMD_COLLAPSE_LEVEL)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('e'),
+                ..
+            }) => self.perform(Cmd::Custom(OUTLINE_CMD_EXPAND_ALL)),
+            // Make the submitted entry the active one, as a viewer scrolled to it would
+            Event::Keyboard(KeyEvent {
+                code: Key::Enter, ..
+            }) => {
+                if let CmdResult::Submit(State::One(StateValue::Usize(index))) =
+                    self.perform(Cmd::Submit)
+                {
+                    self.attr(
+                        Attribute::Custom(OUTLINE_ACTIVE),
+                        AttrValue::Payload(PropPayload::One(PropValue::Usize(index))),
+                    );
+                }
+                CmdResult::None
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
+            _ => CmdResult::None,
+        };
+        Some(Msg::None)
+    }
+}
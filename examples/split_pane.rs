@@ -0,0 +1,208 @@
+//! ## Demo
+//!
+//! `Demo` shows how to use tui-realm in a real case
+
+mod backend;
+use backend::InputListener;
+
+use std::time::Duration;
+
+use tui_realm_stdlib::commands::{
+    SPLIT_PANE_CMD_COLLAPSE_FIRST, SPLIT_PANE_CMD_COLLAPSE_SECOND, SPLIT_PANE_CMD_GROW_FIRST,
+    SPLIT_PANE_CMD_SHRINK_FIRST, SPLIT_PANE_CMD_SWITCH_PANE,
+};
+use tui_realm_stdlib::{List, Paragraph, SplitPane};
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::props::{Alignment, BorderSides, BorderType, Borders, Color, TableBuilder, TextSpan};
+use tuirealm::terminal::TerminalBridge;
+use tuirealm::{
+    application::PollStrategy,
+    event::{Key, KeyEvent},
+    Application, Component, Event, EventListenerCfg, MockComponent, NoUserEvent, Update,
+};
+// tui
+use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection, Layout};
+
+#[derive(Debug, PartialEq)]
+pub enum Msg {
+    AppClose,
+    None,
+}
+
+// Let's define the component ids for our application
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub enum Id {
+    SplitPaneAlfa,
+}
+
+struct Model {
+    quit: bool,   // Becomes true when the user presses <ESC>
+    redraw: bool, // Tells whether to refresh the UI; performance optimization
+    app: Application<Id, Msg, NoUserEvent>,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        // Setup app
+        let mut app: Application<Id, Msg, NoUserEvent> = Application::init(
+            EventListenerCfg::default().input_listener(Duration::from_millis(10), 10),
+        );
+        assert!(app
+            .mount(
+                Id::SplitPaneAlfa,
+                Box::new(SplitPaneAlfa::default()),
+                vec![]
+            )
+            .is_ok());
+        // We need to give focus to input then
+        assert!(app.active(&Id::SplitPaneAlfa).is_ok());
+        Self {
+            quit: false,
+            redraw: true,
+            app,
+        }
+    }
+}
+
+impl Model {
+    fn view(&mut self, terminal: &mut TerminalBridge<backend::Adapter>) {
+        let _ = terminal.raw_mut().draw(|f| {
+            // Prepare chunks
+            let chunks = Layout::default()
+                .direction(LayoutDirection::Vertical)
+                .margin(1)
+                .constraints([Constraint::Min(10), Constraint::Length(1)].as_ref())
+                .split(f.area());
+            self.app.view(&Id::SplitPaneAlfa, f, chunks[0]);
+        });
+    }
+}
+
+fn main() {
+    let mut model = Model::default();
+    let mut terminal = backend::terminal();
+    let _ = terminal.enable_raw_mode();
+    let _ = terminal.enter_alternate_screen();
+    // Now we use the Model struct to keep track of some states
+
+    // let's loop until quit is true
+    while !model.quit {
+        // Tick
+        if let Ok(messages) = model.app.tick(PollStrategy::Once) {
+            for msg in messages.into_iter() {
+                let mut msg = Some(msg);
+                while msg.is_some() {
+                    msg = model.update(msg);
+                }
+            }
+        }
+        // Redraw
+        if model.redraw {
+            model.view(&mut terminal);
+            model.redraw = false;
+        }
+    }
+    // Terminate terminal
+    let _ = terminal.leave_alternate_screen();
+    let _ = terminal.disable_raw_mode();
+    let _ = terminal.clear_screen();
+}
+
+impl Update<Msg> for Model {
+    fn update(&mut self, msg: Option<Msg>) -> Option<Msg> {
+        self.redraw = true;
+        match msg.unwrap_or(Msg::None) {
+            Msg::AppClose => {
+                self.quit = true;
+                None
+            }
+            Msg::None => None,
+        }
+    }
+}
+
+// -- components
+
+#[derive(MockComponent)]
+struct SplitPaneAlfa {
+    component: SplitPane,
+}
+
+impl Default for SplitPaneAlfa {
+    fn default() -> Self {
+        Self {
+            component: SplitPane::default()
+                            .borders(
+                                Borders::default()
+                                    .modifiers(BorderType::Rounded)
+                                    .color(Color::LightGreen),
+                            )
+                            .foreground(Color::LightGreen)
+                            .title(
+                                "<TAB> switch pane, <+/-> move divider, <1/2> collapse",
+                                Alignment::Center,
+                            )
+                            .position(30)
+                            .first(Box::new(
+                                List::default()
+                                    .borders(Borders::default().sides(BorderSides::NONE))
+                                    .highlighted_color(Color::LightGreen)
+                                    .scroll(true)
+                                    .rows(
+                                        TableBuilder::default()
+                                            .add_col(TextSpan::from("Cargo.toml"))
+                                            .add_row()
+                                            .add_col(TextSpan::from("README.md"))
+                                            .add_row()
+                                            .add_col(TextSpan::from("src/lib.rs"))
+                                            .add_row()
+                                            .add_col(TextSpan::from("src/utils.rs"))
+                                            .build(),
+                                    ),
+                            ))
+                            .second(Box::new(
+                                Paragraph::default()
+                                    .borders(Borders::default().sides(BorderSides::NONE))
+                                    .wrap(true)
+                                    .text(&[TextSpan::from(
+                                        "Lorem ipsum dolor sit amet, consectetur adipiscing elit. Praesent mauris est, vehicula et imperdiet sed, tincidunt sed est.",
+                                    )]),
+                            )),
+        }
+    }
+}
+
+impl Component<Msg, NoUserEvent> for SplitPaneAlfa {
+    fn on(&mut self, ev: Event<NoUserEvent>) -> Option<Msg> {
+        let _ = match ev {
+            Event::Keyboard(KeyEvent { code: Key::Tab, .. }) => {
+                self.perform(Cmd::Custom(SPLIT_PANE_CMD_SWITCH_PANE))
+            }
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('+'),
+                ..
+            }) => self.perform(Cmd::Custom(SPLIT_PANE_CMD_GROW_FIRST)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('-'),
+                ..
+            }) => self.perform(Cmd::Custom(SPLIT_PANE_CMD_SHRINK_FIRST)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('1'),
+                ..
+            }) => self.perform(Cmd::Custom(SPLIT_PANE_CMD_COLLAPSE_FIRST)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Char('2'),
+                ..
+            }) => self.perform(Cmd::Custom(SPLIT_PANE_CMD_COLLAPSE_SECOND)),
+            Event::Keyboard(KeyEvent {
+                code: Key::Down, ..
+            }) => self.perform(Cmd::Move(Direction::Down)),
+            Event::Keyboard(KeyEvent { code: Key::Up, .. }) => {
+                self.perform(Cmd::Move(Direction::Up))
+            }
+            Event::Keyboard(KeyEvent { code: Key::Esc, .. }) => return Some(Msg::AppClose),
+            _ => CmdResult::None,
+        };
+        Some(Msg::None)
+    }
+}
@@ -0,0 +1,308 @@
+//! ## Animation
+//!
+//! `animation` provides the values animated by the components, such as the fill of `ProgressBar`, the scrolling of
+//! `ScrollView` and the cursor of `Input`, which can be used by custom components as well.
+//!
+//! Animations are driven by ticks: components advance them on `Cmd::Tick`, which should be performed on
+//! `Event::Tick`, so their speed depends on the tick interval of the application.
+
+/// ## Easing
+///
+/// The progression of an animation over time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+    /// Starts slow and accelerates
+    EaseIn,
+    /// Starts fast and decelerates
+    #[default]
+    EaseOut,
+    /// Starts and ends slow
+    EaseInOut,
+}
+
+impl Easing {
+    /// ### apply
+    ///
+    /// Get the progression of the animation at `t`, where both are in range [0.0, 1.0]
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut if t < 0.5 => 2.0 * t * t,
+            Easing::EaseInOut => 1.0 - (-2.0 * t + 2.0).powi(2) / 2.0,
+        }
+    }
+}
+
+/// ## Interpolate
+///
+/// Values which can be animated
+pub trait Interpolate: Copy + PartialEq {
+    /// ### interpolate
+    ///
+    /// Get the value between `from` and `to` at `t`, in range [0.0, 1.0]
+    fn interpolate(from: Self, to: Self, t: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(from: Self, to: Self, t: f64) -> Self {
+        from + (to - from) * t
+    }
+}
+
+impl Interpolate for f32 {
+    fn interpolate(from: Self, to: Self, t: f64) -> Self {
+        from + (to - from) * t as f32
+    }
+}
+
+macro_rules! impl_interpolate_int {
+    ($($t:ty),*) => {
+        $(
+            impl Interpolate for $t {
+                fn interpolate(from: Self, to: Self, t: f64) -> Self {
+                    (from as f64 + (to as f64 - from as f64) * t).round() as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// ## Animated
+///
+/// A value moving towards its target in a number of ticks. With 0 ticks, the value is set immediately
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Animated<T: Interpolate> {
+    from: T,
+    to: T,
+    ticks: usize,
+    elapsed: usize,
+    easing: Easing,
+}
+
+impl<T: Interpolate> Animated<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value,
+            to: value,
+            ticks: 0,
+            elapsed: 0,
+            easing: Easing::default(),
+        }
+    }
+
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.set_ticks(ticks);
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// ### set_ticks
+    ///
+    /// Set the duration of the animations, in ticks
+    pub fn set_ticks(&mut self, ticks: usize) {
+        self.ticks = ticks;
+        self.elapsed = self.elapsed.min(ticks);
+        if ticks == 0 {
+            self.from = self.to;
+        }
+    }
+
+    /// ### set
+    ///
+    /// Start moving from the current value to `target`
+    pub fn set(&mut self, target: T) {
+        if self.ticks == 0 {
+            self.jump(target);
+        } else if target != self.to {
+            self.from = self.value();
+            self.to = target;
+            self.elapsed = 0;
+        }
+    }
+
+    /// ### jump
+    ///
+    /// Set the value to `value`, without animating it
+    pub fn jump(&mut self, value: T) {
+        self.from = value;
+        self.to = value;
+        self.elapsed = self.ticks;
+    }
+
+    /// ### tick
+    ///
+    /// Advance the animation by a tick. Returns whether the value has changed
+    pub fn tick(&mut self) -> bool {
+        if !self.is_running() {
+            return false;
+        }
+        let prev = self.value();
+        self.elapsed += 1;
+        if self.elapsed == self.ticks {
+            self.from = self.to;
+        }
+        self.value() != prev
+    }
+
+    /// ### value
+    ///
+    /// Get the current value
+    pub fn value(&self) -> T {
+        match self.is_running() {
+            true => T::interpolate(
+                self.from,
+                self.to,
+                self.easing.apply(self.elapsed as f64 / self.ticks as f64),
+            ),
+            false => self.to,
+        }
+    }
+
+    /// ### target
+    ///
+    /// Get the value the animation is moving to
+    pub fn target(&self) -> T {
+        self.to
+    }
+
+    /// ### is_running
+    ///
+    /// Whether the value hasn't reached its target yet
+    pub fn is_running(&self) -> bool {
+        self.elapsed < self.ticks && self.from != self.to
+    }
+}
+
+/// ## Blink
+///
+/// Alternates between visible and hidden every number of ticks. With 0 ticks, it's always visible
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Blink {
+    ticks: usize,
+    elapsed: usize,
+}
+
+impl Blink {
+    pub fn new(ticks: usize) -> Self {
+        Self { ticks, elapsed: 0 }
+    }
+
+    /// ### set_ticks
+    ///
+    /// Set the ticks between each change of visibility
+    pub fn set_ticks(&mut self, ticks: usize) {
+        self.ticks = ticks;
+        self.reset();
+    }
+
+    /// ### reset
+    ///
+    /// Make it visible, starting a new period
+    pub fn reset(&mut self) {
+        self.elapsed = 0;
+    }
+
+    /// ### tick
+    ///
+    /// Advance by a tick. Returns whether the visibility has changed
+    pub fn tick(&mut self) -> bool {
+        if self.ticks == 0 {
+            return false;
+        }
+        let prev = self.visible();
+        self.elapsed = (self.elapsed + 1) % (self.ticks * 2);
+        self.visible() != prev
+    }
+
+    /// ### visible
+    ///
+    /// Whether it's in the visible half of the period
+    pub fn visible(&self) -> bool {
+        self.elapsed < self.ticks || self.ticks == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_animation_easing() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+            assert_eq!(easing.apply(2.0), 1.0);
+        }
+        assert_eq!(Easing::Linear.apply(0.25), 0.25);
+        assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+        assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+    }
+
+    #[test]
+    fn test_animation_animated() {
+        let mut value = Animated::new(0_u16).ticks(4).easing(Easing::Linear);
+        assert!(!value.tick());
+        value.set(8);
+        assert!(value.is_running());
+        assert_eq!(value.value(), 0);
+        assert_eq!(value.target(), 8);
+        let mut values = Vec::new();
+        while value.tick() {
+            values.push(value.value());
+        }
+        assert_eq!(values, vec![2, 4, 6, 8]);
+        assert!(!value.is_running());
+        // Retargeting starts from the current value
+        value.set(0);
+        value.tick();
+        value.set(10);
+        assert_eq!(value.value(), 6);
+        // Jump
+        value.jump(3);
+        assert_eq!(value.value(), 3);
+        assert!(!value.is_running());
+        // Without ticks
+        let mut value = Animated::new(0.0);
+        value.set(0.5);
+        assert_eq!(value.value(), 0.5);
+        assert!(!value.tick());
+    }
+
+    #[test]
+    fn test_animation_blink() {
+        let mut blink = Blink::new(2);
+        let visible: Vec<bool> = (0..6)
+            .map(|_| {
+                blink.tick();
+                blink.visible()
+            })
+            .collect();
+        assert_eq!(visible, vec![true, false, false, true, true, false]);
+        blink.reset();
+        assert!(blink.visible());
+        // Never blinks without ticks
+        let mut blink = Blink::default();
+        assert!(!blink.tick());
+        assert!(blink.visible());
+    }
+}
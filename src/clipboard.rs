@@ -0,0 +1,131 @@
+//! ## Clipboard
+//!
+//! `Clipboard` is the clipboard used by the components for copy, cut and paste, such as `Input` and `Textarea`.
+//! With the `clipboard` feature, components use the clipboard of the system; otherwise, or when the system clipboard
+//! isn't available, they share an in-memory clipboard, so text can still be copied between components.
+//! Applications can provide their own implementation with the `clipboard` builder of the components.
+
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// ## Clipboard
+///
+/// A clipboard holding text. It's `Send`, so that the components using it can be moved between threads
+pub trait Clipboard: Send {
+    /// ### get
+    ///
+    /// Get the text in the clipboard, if any
+    fn get(&mut self) -> Option<String>;
+
+    /// ### set
+    ///
+    /// Put `text` in the clipboard. Errors are ignored, since there's nothing the components can do about them
+    fn set(&mut self, text: &str);
+}
+
+/// ## MemoryClipboard
+///
+/// A clipboard kept in memory. Clones share the same text
+#[derive(Debug, Clone, Default)]
+pub struct MemoryClipboard {
+    text: Arc<Mutex<Option<String>>>,
+}
+
+impl MemoryClipboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ### shared
+    ///
+    /// Get the in-memory clipboard shared by the components of the current thread
+    pub fn shared() -> Self {
+        thread_local! {
+            static SHARED: MemoryClipboard = MemoryClipboard::new();
+        }
+        SHARED.with(Clone::clone)
+    }
+}
+
+impl Clipboard for MemoryClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.text
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn set(&mut self, text: &str) {
+        *self.text.lock().unwrap_or_else(PoisonError::into_inner) = Some(text.to_string());
+    }
+}
+
+/// ## SystemClipboard
+///
+/// The clipboard of the system
+#[cfg(feature = "clipboard")]
+pub struct SystemClipboard {
+    clipboard: arboard::Clipboard,
+}
+
+#[cfg(feature = "clipboard")]
+impl SystemClipboard {
+    /// ### new
+    ///
+    /// Connect to the clipboard of the system. Returns `None` if it isn't available, e.g. without a display server
+    pub fn new() -> Option<Self> {
+        arboard::Clipboard::new()
+            .ok()
+            .map(|clipboard| Self { clipboard })
+    }
+}
+
+#[cfg(feature = "clipboard")]
+impl Clipboard for SystemClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.clipboard.get_text().ok()
+    }
+
+    fn set(&mut self, text: &str) {
+        let _ = self.clipboard.set_text(text);
+    }
+}
+
+/// ### default_clipboard
+///
+/// Get the clipboard used by the components when none is given: the system clipboard, with the `clipboard` feature
+/// and when available, otherwise the shared in-memory clipboard
+pub fn default_clipboard() -> Box<dyn Clipboard> {
+    #[cfg(feature = "clipboard")]
+    if let Some(clipboard) = SystemClipboard::new() {
+        return Box::new(clipboard);
+    }
+    Box::new(MemoryClipboard::shared())
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_clipboard_memory() {
+        let mut clipboard = MemoryClipboard::new();
+        assert_eq!(clipboard.get(), None);
+        clipboard.set("hello");
+        assert_eq!(clipboard.get().as_deref(), Some("hello"));
+        // Clones share the text
+        let mut other = clipboard.clone();
+        other.set("world");
+        assert_eq!(clipboard.get().as_deref(), Some("world"));
+        // But new clipboards don't
+        assert_eq!(MemoryClipboard::new().get(), None);
+        MemoryClipboard::shared().set("shared");
+        assert_eq!(MemoryClipboard::shared().get().as_deref(), Some("shared"));
+        // Components with a clipboard can be moved between threads
+        fn is_send<T: Send>() {}
+        is_send::<crate::components::Input>();
+        is_send::<crate::components::Textarea>();
+    }
+}
@@ -0,0 +1,526 @@
+//! ## Accordion
+//!
+//! `Accordion` displays titled sections which can be expanded and collapsed
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{ACCORDION_MULTIPLE, TOOLTIP};
+
+/// Indentation of the content of sections
+const INDENT: u16 = 2;
+
+/// ## AccordionSection
+///
+/// A section of the `Accordion`, with its title and its content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccordionSection {
+    pub title: String,
+    /// Text displayed when the section is expanded, wrapped to the width of the area
+    pub content: String,
+}
+
+impl AccordionSection {
+    pub fn new<T: Into<String>, S: Into<String>>(title: T, content: S) -> Self {
+        Self {
+            title: title.into(),
+            content: content.into(),
+        }
+    }
+
+    fn to_payload(&self) -> PropPayload {
+        PropPayload::Tup2((
+            PropValue::Str(self.title.clone()),
+            PropValue::Str(self.content.clone()),
+        ))
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        let (title, content) = payload.unwrap_tup2();
+        Self {
+            title: title.unwrap_str(),
+            content: content.unwrap_str(),
+        }
+    }
+}
+
+/// ## AccordionRow
+///
+/// A row of the accordion
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AccordionRow {
+    /// Title of a section
+    Title(usize),
+    /// A line of the content of a section
+    Content(String),
+}
+
+// -- states
+
+/// ## AccordionStates
+///
+/// AccordionStates contains states for this component
+#[derive(Default)]
+pub struct AccordionStates {
+    /// Index of the selected section
+    pub selected: usize,
+    /// Whether each section is expanded
+    pub open: Vec<bool>,
+    /// First row displayed
+    pub scroll: usize,
+}
+
+impl AccordionStates {
+    /// ### set_len
+    ///
+    /// Set the number of sections, keeping the expanded sections and the selection in range
+    pub fn set_len(&mut self, len: usize) {
+        self.open.resize(len, false);
+        self.select(self.selected);
+    }
+
+    /// ### select
+    ///
+    /// Select the section at `index`, clamped to the sections
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.open.len().saturating_sub(1));
+    }
+
+    /// ### toggle
+    ///
+    /// Expand the section at `index`, or collapse it if expanded. Unless `multiple`, expanding a section collapses the
+    /// other ones
+    pub fn toggle(&mut self, index: usize, multiple: bool) {
+        let Some(open) = self.open.get(index).map(|x| !x) else {
+            return;
+        };
+        if open && !multiple {
+            self.open.iter_mut().for_each(|x| *x = false);
+        }
+        self.open[index] = open;
+    }
+
+    /// ### open_sections
+    ///
+    /// Get the indexes of the expanded sections
+    pub fn open_sections(&self) -> Vec<usize> {
+        self.open
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| **x)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// ### fit
+    ///
+    /// Scroll so that the rows in `range` are visible in `height` rows, or at least the first one
+    fn fit(&mut self, range: std::ops::Range<usize>, total: usize, height: usize) {
+        if range.start < self.scroll {
+            self.scroll = range.start;
+        } else if range.end > self.scroll + height {
+            self.scroll = range.start.min(range.end.saturating_sub(height));
+        }
+        self.scroll = self.scroll.min(total.saturating_sub(height));
+    }
+}
+
+// -- component
+
+/// ## Accordion
+///
+/// A list of titled sections, such as a settings panel. The selected section is expanded or collapsed on submit; by
+/// default a single section can be expanded at once, unless multiple mode is enabled
+#[derive(Default)]
+pub struct Accordion {
+    props: Props,
+    pub states: AccordionStates,
+}
+
+impl Accordion {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `accordion` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("accordion", self);
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Allow expanding more sections at once
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.attr(
+            Attribute::Custom(ACCORDION_MULTIPLE),
+            AttrValue::Flag(multiple),
+        );
+        self
+    }
+
+    pub fn sections(mut self, sections: &[AccordionSection]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(
+                sections.iter().map(|x| x.to_payload()).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the indexes of the expanded sections
+    pub fn open_sections(mut self, sections: &[usize]) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::Vec(
+                sections.iter().map(|x| PropValue::Usize(*x)).collect(),
+            )),
+        );
+        self
+    }
+
+    fn get_sections(&self) -> Vec<AccordionSection> {
+        match self
+            .props
+            .get(Attribute::Content)
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Linked(sections)) => sections
+                .into_iter()
+                .map(AccordionSection::from_payload)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn is_multiple(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(ACCORDION_MULTIPLE),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    /// ### layout_rows
+    ///
+    /// Lay out the rows of the sections, wrapping the content of expanded sections to `width`.
+    /// Returns the rows, and the range of rows of the selected section
+    fn layout_rows(
+        &self,
+        sections: &[AccordionSection],
+        width: usize,
+    ) -> (Vec<AccordionRow>, std::ops::Range<usize>) {
+        let mut rows = Vec::new();
+        let mut selected = 0..0;
+        for (i, section) in sections.iter().enumerate() {
+            let start = rows.len();
+            rows.push(AccordionRow::Title(i));
+            if self.states.open.get(i).copied().unwrap_or(false) {
+                rows.extend(section.content.lines().flat_map(|line| {
+                    textwrap::wrap(line, width.max(1))
+                        .into_iter()
+                        .map(|x| AccordionRow::Content(x.into_owned()))
+                        .collect::<Vec<_>>()
+                }));
+            }
+            if i == self.states.selected {
+                selected = start..rows.len();
+            }
+        }
+        (rows, selected)
+    }
+
+    /// ### render_sections
+    ///
+    /// Render the sections in `area`, scrolled to the selected one
+    fn render_sections(&mut self, buf: &mut Buffer, area: Rect, style: Style, highlight: Style) {
+        let sections = self.get_sections();
+        let (rows, selected) =
+            self.layout_rows(&sections, area.width.saturating_sub(INDENT) as usize);
+        self.states.fit(selected, rows.len(), area.height as usize);
+        let content_x = area.x + INDENT.min(area.width);
+        for (y, row) in (area.y..area.bottom()).zip(rows.iter().skip(self.states.scroll)) {
+            match row {
+                AccordionRow::Title(i) => {
+                    let symbol = match self.states.open[*i] {
+                        true => "▾ ",
+                        false => "▸ ",
+                    };
+                    let (x, _) = buf.set_stringn(area.x, y, symbol, area.width as usize, style);
+                    buf.set_stringn(
+                        x,
+                        y,
+                        &sections[*i].title,
+                        area.right().saturating_sub(x) as usize,
+                        style.add_modifier(TextModifiers::BOLD),
+                    );
+                    if *i == self.states.selected {
+                        buf.set_style(Rect::new(area.x, y, area.width, 1), highlight);
+                    }
+                }
+                AccordionRow::Content(line) => {
+                    buf.set_stringn(
+                        content_x,
+                        y,
+                        line,
+                        area.right().saturating_sub(content_x) as usize,
+                        style,
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl MockComponent for Accordion {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let mut highlight = Style::default();
+            if let Some(color) = self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color())
+            {
+                highlight = highlight.fg(color);
+            }
+            if focus {
+                highlight = highlight.add_modifier(TextModifiers::REVERSED);
+            }
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_sections(render.buffer_mut(), inner, style, highlight);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Content => {
+                let len = self.get_sections().len();
+                self.states.set_len(len);
+            }
+            Attribute::Value => {
+                if let Some(PropPayload::Vec(sections)) =
+                    self.props.get(attr).map(|x| x.unwrap_payload())
+                {
+                    self.states.open.iter_mut().for_each(|x| *x = false);
+                    for index in sections.into_iter().map(|x| x.unwrap_usize()) {
+                        if let Some(open) = self.states.open.get_mut(index) {
+                            *open = true;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Vec(
+            self.states
+                .open_sections()
+                .into_iter()
+                .map(StateValue::Usize)
+                .collect(),
+        )
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let prev = self.states.selected;
+        match cmd {
+            Cmd::Move(Direction::Down) => self.states.select(prev + 1),
+            Cmd::Move(Direction::Up) => self.states.select(prev.saturating_sub(1)),
+            Cmd::GoTo(Position::Begin) => self.states.select(0),
+            Cmd::GoTo(Position::End) => self.states.select(usize::MAX),
+            Cmd::Submit => {
+                if self.states.open.is_empty() {
+                    return CmdResult::None;
+                }
+                self.states.toggle(prev, self.is_multiple());
+                return CmdResult::Changed(self.state());
+            }
+            _ => {}
+        }
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn component() -> Accordion {
+        Accordion::default().sections(&[
+            AccordionSection::new("General", "Language: English\nTheme: dark"),
+            AccordionSection::new("Network", "Proxy: none"),
+            AccordionSection::new("About", "tui-realm-stdlib"),
+        ])
+    }
+
+    #[test]
+    fn test_components_accordion() {
+        let mut component = component();
+        assert_eq!(component.states.open, vec![false, false, false]);
+        assert_eq!(component.state(), State::Vec(vec![]));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Changed(State::Vec(vec![StateValue::Usize(0)]))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.selected, 1);
+        // A single section is expanded
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Changed(State::Vec(vec![StateValue::Usize(1)]))
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Changed(State::Vec(vec![]))
+        );
+        // Multiple sections
+        component.attr(Attribute::Custom(ACCORDION_MULTIPLE), AttrValue::Flag(true));
+        component.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::Vec(vec![PropValue::Usize(0)])),
+        );
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Changed(State::Vec(vec![StateValue::Usize(0), StateValue::Usize(2)]))
+        );
+        // Fewer sections
+        component.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(Default::default())),
+        );
+        assert_eq!(component.states.selected, 0);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_accordion_render() {
+        let mut component = component().open_sections(&[0]);
+        let area = Rect::new(0, 0, 14, 4);
+        let mut buf = Buffer::empty(area);
+        component.render_sections(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec!["▾ General", "  Language:", "  English", "  Theme: dark",]
+        );
+        // Scroll to the last section
+        component.states.select(2);
+        let mut buf = Buffer::empty(area);
+        component.render_sections(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec!["  English", "  Theme: dark", "▸ Network", "▸ About"]
+        );
+    }
+}
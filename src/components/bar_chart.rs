@@ -7,14 +7,24 @@ use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
 };
-use tuirealm::ratatui::{layout::Rect, widgets::BarChart as TuiBarChart};
+use tuirealm::ratatui::{
+    buffer::Buffer,
+    layout::{Direction as LayoutDirection, Rect},
+    text::{Line, Span},
+    widgets::{Bar, BarChart as TuiBarChart, BarGroup, Block, Paragraph},
+};
 use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, render_tooltip};
 
 // -- Props
 
 use super::props::{
-    BAR_CHART_BARS_GAP, BAR_CHART_BARS_STYLE, BAR_CHART_LABEL_STYLE, BAR_CHART_MAX_BARS,
-    BAR_CHART_VALUES_STYLE,
+    BAR_CHART_BARS_GAP, BAR_CHART_BARS_STYLE, BAR_CHART_GROUPS, BAR_CHART_HORIZONTAL,
+    BAR_CHART_LABEL_STYLE, BAR_CHART_MAX_BARS, BAR_CHART_SERIES, BAR_CHART_STACKED,
+    BAR_CHART_VALUES_STYLE, TOOLTIP,
 };
 
 // -- states
@@ -80,6 +90,15 @@ impl BarChartStates {
 /// #### Active mode
 ///
 /// While in active mode (default) you can put as many entries as you wish. You can move with arrows and END/HOME keys
+///
+/// #### Horizontal mode
+///
+/// When horizontal, bars grow from left to right with labels on the left; use up/down arrows to move through data
+///
+/// #### Multi-series data
+///
+/// Set `series` and `groups` to display more values for each category, either as grouped bars or as stacked bars.
+/// When groups are set, `data` is ignored
 #[derive(Default)]
 pub struct BarChart {
     props: Props,
@@ -112,6 +131,23 @@ impl BarChart {
         self
     }
 
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `bar_chart` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("bar_chart", self);
+    }
+
     pub fn inactive(mut self, s: Style) -> Self {
         self.attr(Attribute::FocusStyle, AttrValue::Style(s));
         self
@@ -168,20 +204,341 @@ impl BarChart {
         self
     }
 
+    /// Display bars horizontally, growing from left to right, with labels on the left.
+    /// In horizontal mode, `width` is the height of each bar
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.attr(
+            Attribute::Custom(BAR_CHART_HORIZONTAL),
+            AttrValue::Flag(horizontal),
+        );
+        self
+    }
+
+    /// Set the series for multi-series data, as name and color. A legend is displayed with the series names
+    pub fn series(mut self, series: &[(&str, Color)]) -> Self {
+        self.attr(
+            Attribute::Custom(BAR_CHART_SERIES),
+            AttrValue::Payload(PropPayload::Linked(
+                series
+                    .iter()
+                    .map(|(name, color)| {
+                        PropPayload::Tup2((
+                            PropValue::Str(name.to_string()),
+                            PropValue::Color(*color),
+                        ))
+                    })
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set multi-series data. Each group is a category label with a value for each series
+    pub fn groups(mut self, groups: &[(&str, &[u64])]) -> Self {
+        self.attr(
+            Attribute::Custom(BAR_CHART_GROUPS),
+            AttrValue::Payload(PropPayload::Linked(
+                groups
+                    .iter()
+                    .map(|(label, values)| {
+                        PropPayload::Vec(
+                            std::iter::once(PropValue::Str(label.to_string()))
+                                .chain(values.iter().map(|x| PropValue::U64(*x)))
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// If true, multi-series values are stacked on top of each other, instead of being displayed side-by-side
+    pub fn stacked(mut self, stacked: bool) -> Self {
+        self.attr(
+            Attribute::Custom(BAR_CHART_STACKED),
+            AttrValue::Flag(stacked),
+        );
+        self
+    }
+
     fn is_disabled(&self) -> bool {
         self.props
             .get_or(Attribute::Disabled, AttrValue::Flag(false))
             .unwrap_flag()
     }
 
+    fn is_horizontal(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(BAR_CHART_HORIZONTAL),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
     /// ### data_len
     ///
     /// Retrieve current data len from properties
     fn data_len(&self) -> usize {
+        match self.props.get(Attribute::Custom(BAR_CHART_GROUPS)) {
+            Some(groups) => groups.unwrap_payload().unwrap_linked().len(),
+            None => self
+                .props
+                .get(Attribute::Dataset)
+                .map(|x| x.unwrap_payload().unwrap_linked().len())
+                .unwrap_or(0),
+        }
+    }
+
+    fn get_series(&self) -> Vec<(String, Color)> {
         self.props
-            .get(Attribute::Dataset)
-            .map(|x| x.unwrap_payload().unwrap_linked().len())
+            .get(Attribute::Custom(BAR_CHART_SERIES))
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_linked()
+                    .into_iter()
+                    .map(|x| {
+                        let (name, color) = x.unwrap_tup2();
+                        (name.unwrap_str(), color.unwrap_color())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// ### get_groups
+    ///
+    /// Get multi-series groups to display, starting from `start` with a max length of `len`
+    fn get_groups(&self, start: usize, len: usize) -> Vec<(String, Vec<u64>)> {
+        self.props
+            .get(Attribute::Custom(BAR_CHART_GROUPS))
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_linked()
+                    .into_iter()
+                    .skip(start)
+                    .take(len)
+                    .map(|group| {
+                        let mut values = group.unwrap_vec().into_iter();
+                        let label = values.next().map(|x| x.unwrap_str()).unwrap_or_default();
+                        (label, values.map(|x| x.unwrap_u64()).collect())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// ### configure_widget
+    ///
+    /// Apply properties shared by all the display modes to the bar chart widget
+    fn configure_widget<'a>(&self, mut widget: TuiBarChart<'a>) -> TuiBarChart<'a> {
+        if self.is_horizontal() {
+            widget = widget.direction(LayoutDirection::Horizontal);
+        }
+        if let Some(gap) = self
+            .props
+            .get(Attribute::Custom(BAR_CHART_BARS_GAP))
+            .map(|x| x.unwrap_size())
+        {
+            widget = widget.bar_gap(gap);
+        }
+        if let Some(width) = self.props.get(Attribute::Width).map(|x| x.unwrap_size()) {
+            widget = widget.bar_width(width);
+        }
+        if let Some(style) = self
+            .props
+            .get(Attribute::Custom(BAR_CHART_BARS_STYLE))
+            .map(|x| x.unwrap_style())
+        {
+            widget = widget.bar_style(style);
+        }
+        if let Some(style) = self
+            .props
+            .get(Attribute::Custom(BAR_CHART_LABEL_STYLE))
+            .map(|x| x.unwrap_style())
+        {
+            widget = widget.label_style(style);
+        }
+        if let Some(style) = self
+            .props
+            .get(Attribute::Custom(BAR_CHART_VALUES_STYLE))
+            .map(|x| x.unwrap_style())
+        {
+            widget = widget.value_style(style);
+        }
+        widget
+    }
+
+    /// ### view_groups
+    ///
+    /// Render multi-series data with a legend on top
+    fn view_groups(&self, render: &mut Frame, area: Rect, div: Block, len: usize) {
+        let inner = div.inner(area);
+        render.render_widget(div, area);
+        let series = self.get_series();
+        let groups = self.get_groups(self.states.cursor, len);
+        // Legend
+        let mut legend: Vec<Span> = Vec::with_capacity(series.len() * 2);
+        for (name, color) in series.iter() {
+            legend.push(Span::styled("■ ", Style::default().fg(*color)));
+            legend.push(Span::raw(format!("{name}  ")));
+        }
+        let legend_height = u16::from(!series.is_empty()).min(inner.height);
+        render.render_widget(
+            Paragraph::new(Line::from(legend)),
+            Rect::new(inner.x, inner.y, inner.width, legend_height),
+        );
+        let chart_area = Rect::new(
+            inner.x,
+            inner.y + legend_height,
+            inner.width,
+            inner.height - legend_height,
+        );
+        let colors: Vec<Color> = series.iter().map(|(_, color)| *color).collect();
+        let gap = self
+            .props
+            .get_or(Attribute::Custom(BAR_CHART_BARS_GAP), AttrValue::Size(1))
+            .unwrap_size();
+        if self
+            .props
+            .get_or(Attribute::Custom(BAR_CHART_STACKED), AttrValue::Flag(false))
+            .unwrap_flag()
+        {
+            let width = self
+                .props
+                .get_or(Attribute::Width, AttrValue::Size(1))
+                .unwrap_size()
+                .max(1);
+            let label_style = self
+                .props
+                .get_or(
+                    Attribute::Custom(BAR_CHART_LABEL_STYLE),
+                    AttrValue::Style(Style::default()),
+                )
+                .unwrap_style();
+            Self::render_stacked(
+                render.buffer_mut(),
+                chart_area,
+                &groups,
+                &colors,
+                (width, gap),
+                self.is_horizontal(),
+                label_style,
+            );
+        } else {
+            let mut widget = self
+                .configure_widget(TuiBarChart::default())
+                .bar_gap(0)
+                .group_gap(gap);
+            for (label, values) in groups.iter() {
+                let bars: Vec<Bar> = values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| {
+                        let bar = Bar::default().value(*value);
+                        match colors.get(i) {
+                            Some(color) => bar.style(Style::default().fg(*color)),
+                            None => bar,
+                        }
+                    })
+                    .collect();
+                widget = widget.data(
+                    BarGroup::default()
+                        .label(Line::from(label.as_str()))
+                        .bars(&bars),
+                );
+            }
+            render.render_widget(widget, chart_area);
+        }
+    }
+
+    /// ### render_stacked
+    ///
+    /// Draw stacked bars into buffer. `bar` is the bar width and the gap between bars.
+    /// Each bar is scaled on the highest total among groups
+    fn render_stacked(
+        buf: &mut Buffer,
+        area: Rect,
+        groups: &[(String, Vec<u64>)],
+        colors: &[Color],
+        bar: (u16, u16),
+        horizontal: bool,
+        label_style: Style,
+    ) {
+        // The legend may take the whole area
+        if area.is_empty() {
+            return;
+        }
+        let (width, gap) = bar;
+        // Sums are in u128, so that they can't overflow
+        let max = groups
+            .iter()
+            .map(|(_, values)| values.iter().map(|x| u128::from(*x)).sum::<u128>())
+            .max()
             .unwrap_or(0)
+            .max(1);
+        // Labels take the bottom row when vertical, or the left columns when horizontal
+        let label_width = match horizontal {
+            true => groups
+                .iter()
+                .map(|(label, _)| label.width() as u16)
+                .max()
+                .unwrap_or(0)
+                .min(area.width / 3),
+            false => width,
+        };
+        let (length, cross) = match horizontal {
+            true => (area.width.saturating_sub(label_width + 1), area.height),
+            false => (area.height.saturating_sub(1), area.width),
+        };
+        for (i, (label, values)) in groups.iter().enumerate() {
+            let offset = i as u32 * (u32::from(width) + u32::from(gap));
+            if offset + u32::from(width) > u32::from(cross) {
+                break;
+            }
+            let offset = offset as u16;
+            // Label
+            match horizontal {
+                true => buf.set_stringn(
+                    area.x,
+                    area.y + offset,
+                    label,
+                    label_width as usize,
+                    label_style,
+                ),
+                false => buf.set_stringn(
+                    area.x + offset,
+                    area.bottom().saturating_sub(1),
+                    label,
+                    label_width as usize,
+                    label_style,
+                ),
+            };
+            // Bar
+            let mut total: u128 = 0;
+            let mut filled: u16 = 0;
+            for (serie, value) in values.iter().enumerate() {
+                total += u128::from(*value);
+                let end = ((total * u128::from(length) + max / 2) / max) as u16;
+                let style = Style::default().fg(colors.get(serie).copied().unwrap_or(Color::Reset));
+                for step in filled..end {
+                    for thickness in 0..width {
+                        let (x, y) = match horizontal {
+                            true => (area.x + label_width + 1 + step, area.y + offset + thickness),
+                            false => (
+                                area.x + offset + thickness,
+                                area.bottom().saturating_sub(2 + step),
+                            ),
+                        };
+                        if let Some(cell) = buf.cell_mut((x, y)) {
+                            cell.set_symbol("█").set_style(style);
+                        }
+                    }
+                }
+                filled = end;
+            }
+        }
     }
 
     fn get_data(&self, start: usize, len: usize) -> Vec<(String, u64)> {
@@ -244,7 +601,8 @@ impl MockComponent for BarChart {
                 true => true,
                 false => focus,
             };
-            let mut div = crate::utils::get_block(borders, title, active, inactive_style);
+            let mut div = crate::utils::get_block(borders, title, active, inactive_style)
+                .title_style(get_title_style(&self.props));
             div = div.style(Style::default().bg(background).fg(foreground));
             // Get max elements
             let data_max_len: u64 = self
@@ -252,46 +610,26 @@ impl MockComponent for BarChart {
                 .get(Attribute::Custom(BAR_CHART_MAX_BARS))
                 .map(|x| x.unwrap_length() as u64)
                 .unwrap_or(self.data_len() as u64);
-            // Get data
-            let data = self.get_data(self.states.cursor, data_max_len as usize);
-            let data_ref: Vec<(&str, u64)> = data.iter().map(|x| (x.0.as_str(), x.1)).collect();
-            // Create widget
-            let mut widget: TuiBarChart =
-                TuiBarChart::default().block(div).data(data_ref.as_slice());
-            if let Some(gap) = self
-                .props
-                .get(Attribute::Custom(BAR_CHART_BARS_GAP))
-                .map(|x| x.unwrap_size())
-            {
-                widget = widget.bar_gap(gap);
-            }
-            if let Some(width) = self.props.get(Attribute::Width).map(|x| x.unwrap_size()) {
-                widget = widget.bar_width(width);
-            }
-            if let Some(style) = self
-                .props
-                .get(Attribute::Custom(BAR_CHART_BARS_STYLE))
-                .map(|x| x.unwrap_style())
-            {
-                widget = widget.bar_style(style);
-            }
-            if let Some(style) = self
+            if self
                 .props
-                .get(Attribute::Custom(BAR_CHART_LABEL_STYLE))
-                .map(|x| x.unwrap_style())
+                .get(Attribute::Custom(BAR_CHART_GROUPS))
+                .is_some()
             {
-                widget = widget.label_style(style);
+                self.view_groups(render, area, div, data_max_len as usize);
+            } else {
+                // Get data
+                let data = self.get_data(self.states.cursor, data_max_len as usize);
+                let data_ref: Vec<(&str, u64)> = data.iter().map(|x| (x.0.as_str(), x.1)).collect();
+                // Create widget
+                let widget: TuiBarChart = self
+                    .configure_widget(TuiBarChart::default())
+                    .block(div)
+                    .data(data_ref.as_slice());
+                // Render
+                render.render_widget(widget, area);
             }
-            if let Some(style) = self
-                .props
-                .get(Attribute::Custom(BAR_CHART_VALUES_STYLE))
-                .map(|x| x.unwrap_style())
-            {
-                widget = widget.value_style(style);
-            }
-            // Render
-            render.render_widget(widget, area);
         }
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -304,11 +642,18 @@ impl MockComponent for BarChart {
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
         if !self.is_disabled() {
+            let horizontal = self.is_horizontal();
             match cmd {
-                Cmd::Move(Direction::Left) => {
+                Cmd::Move(Direction::Left) if !horizontal => {
                     self.states.move_cursor_left();
                 }
-                Cmd::Move(Direction::Right) => {
+                Cmd::Move(Direction::Right) if !horizontal => {
+                    self.states.move_cursor_right(self.data_len());
+                }
+                Cmd::Move(Direction::Up) if horizontal => {
+                    self.states.move_cursor_left();
+                }
+                Cmd::Move(Direction::Down) if horizontal => {
                     self.states.move_cursor_right(self.data_len());
                 }
                 Cmd::GoTo(Position::Begin) => {
@@ -408,4 +753,116 @@ mod test {
         );
         assert_eq!(component.states.cursor, 0);
     }
+
+    #[test]
+    fn test_components_bar_chart_horizontal() {
+        let mut component: BarChart = BarChart::default().horizontal(true).data(&[
+            ("january", 250),
+            ("february", 300),
+            ("march", 275),
+        ]);
+        assert!(component.is_horizontal());
+        // Left and right are ignored
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.cursor, 0);
+        // Down / Up
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.states.cursor, 1);
+        component.perform(Cmd::Move(Direction::Up));
+        assert_eq!(component.states.cursor, 0);
+        assert!(!BarChart::default().is_horizontal());
+    }
+
+    #[test]
+    fn test_components_bar_chart_groups() {
+        let component: BarChart = BarChart::default()
+            .series(&[("income", Color::Green), ("expenses", Color::Red)])
+            .groups(&[
+                ("jan", &[250, 120]),
+                ("feb", &[300, 280]),
+                ("mar", &[275, 60]),
+            ])
+            .stacked(true);
+        assert_eq!(component.data_len(), 3);
+        assert_eq!(
+            component.get_series(),
+            vec![
+                (String::from("income"), Color::Green),
+                (String::from("expenses"), Color::Red)
+            ]
+        );
+        assert_eq!(
+            component.get_groups(1, 8),
+            vec![
+                (String::from("feb"), vec![300, 280]),
+                (String::from("mar"), vec![275, 60])
+            ]
+        );
+        // Render stacked
+        let area = Rect::new(0, 0, 6, 5);
+        let mut buf = Buffer::empty(area);
+        BarChart::render_stacked(
+            &mut buf,
+            area,
+            &[
+                (String::from("a"), vec![2, 2]),
+                (String::from("b"), vec![1, 0]),
+            ],
+            &[Color::Green, Color::Red],
+            (1, 1),
+            false,
+            Style::default(),
+        );
+        let column =
+            |x: u16| -> Vec<String> { (0..5).map(|y| buf[(x, y)].symbol().to_string()).collect() };
+        assert_eq!(column(0), vec!["█", "█", "█", "█", "a"]);
+        assert_eq!(buf[(0, 0)].fg, Color::Red);
+        assert_eq!(buf[(0, 3)].fg, Color::Green);
+        assert_eq!(column(2), vec![" ", " ", " ", "█", "b"]);
+        // Horizontal
+        let area = Rect::new(0, 0, 12, 1);
+        let mut buf = Buffer::empty(area);
+        BarChart::render_stacked(
+            &mut buf,
+            area,
+            &[(String::from("abcd"), vec![1, 1])],
+            &[Color::Green, Color::Red],
+            (1, 1),
+            true,
+            Style::default(),
+        );
+        let row: String = (0..12).map(|x| buf[(x, 0)].symbol().to_string()).collect();
+        assert_eq!(row, "abcd ███████");
+        // Nothing is rendered without room, e.g. when the legend takes the whole area
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        BarChart::render_stacked(
+            &mut buf,
+            Rect::new(0, 0, 6, 0),
+            &[(String::from("a"), vec![2, 2])],
+            &[Color::Green, Color::Red],
+            (1, 1),
+            false,
+            Style::default(),
+        );
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 6, 1)));
+        // Nor does a view with no room below the legend
+        let mut component = BarChart::default()
+            .series(&[("income", Color::Green)])
+            .groups(&[("jan", &[250])])
+            .stacked(true);
+        crate::testing::render(&mut component, 10, 3);
+        // Large values don't overflow
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 5));
+        BarChart::render_stacked(
+            &mut buf,
+            Rect::new(0, 0, 6, 5),
+            &[(String::from("a"), vec![u64::MAX, u64::MAX])],
+            &[Color::Green, Color::Red],
+            (1, u16::MAX),
+            false,
+            Style::default(),
+        );
+        assert_eq!(buf[(0, 0)].fg, Color::Red);
+        assert_eq!(buf[(0, 3)].fg, Color::Green);
+    }
 }
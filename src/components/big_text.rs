@@ -0,0 +1,663 @@
+//! ## BigText
+//!
+//! `BigText` renders a short text with large glyphs
+
+use std::collections::HashMap;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{
+    BIG_TEXT_COLORS, BIG_TEXT_FIGLET_FONT, BIG_TEXT_FONT, BIG_TEXT_GRADIENT, TOOLTIP,
+};
+
+// -- font
+
+/// ## BigTextFont
+///
+/// Built-in fonts, drawn with block characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BigTextFont {
+    /// Glyphs 5 rows tall, with full blocks
+    #[default]
+    Block,
+    /// Glyphs 3 rows tall, with half blocks
+    HalfBlock,
+}
+
+impl BigTextFont {
+    pub(crate) const ALL: [Self; 2] = [Self::Block, Self::HalfBlock];
+}
+
+/// ### bitmap
+///
+/// Returns the 5 rows of the glyph of `c`, where `#` is a filled pixel. Letters are uppercase
+fn bitmap(c: char) -> Option<[&'static str; 5]> {
+    let glyph = match c.to_ascii_uppercase() {
+        'A' => [" ### ", "#   #", "#####", "#   #", "#   #"],
+        'B' => ["#### ", "#   #", "#### ", "#   #", "#### "],
+        'C' => [" ####", "#    ", "#    ", "#    ", " ####"],
+        'D' => ["#### ", "#   #", "#   #", "#   #", "#### "],
+        'E' => ["#####", "#    ", "#### ", "#    ", "#####"],
+        'F' => ["#####", "#    ", "#### ", "#    ", "#    "],
+        'G' => [" ####", "#    ", "#  ##", "#   #", " ####"],
+        'H' => ["#   #", "#   #", "#####", "#   #", "#   #"],
+        'I' => ["###", " # ", " # ", " # ", "###"],
+        'J' => ["  ###", "    #", "    #", "#   #", " ### "],
+        'K' => ["#   #", "#  # ", "###  ", "#  # ", "#   #"],
+        'L' => ["#    ", "#    ", "#    ", "#    ", "#####"],
+        'M' => ["#   #", "## ##", "# # #", "#   #", "#   #"],
+        'N' => ["#   #", "##  #", "# # #", "#  ##", "#   #"],
+        'O' => [" ### ", "#   #", "#   #", "#   #", " ### "],
+        'P' => ["#### ", "#   #", "#### ", "#    ", "#    "],
+        'Q' => [" ### ", "#   #", "# # #", "#  # ", " ## #"],
+        'R' => ["#### ", "#   #", "#### ", "#  # ", "#   #"],
+        'S' => [" ####", "#    ", " ### ", "    #", "#### "],
+        'T' => ["#####", "  #  ", "  #  ", "  #  ", "  #  "],
+        'U' => ["#   #", "#   #", "#   #", "#   #", " ### "],
+        'V' => ["#   #", "#   #", "#   #", " # # ", "  #  "],
+        'W' => ["#   #", "#   #", "# # #", "## ##", "#   #"],
+        'X' => ["#   #", " # # ", "  #  ", " # # ", "#   #"],
+        'Y' => ["#   #", " # # ", "  #  ", "  #  ", "  #  "],
+        'Z' => ["#####", "   # ", "  #  ", " #   ", "#####"],
+        '0' => [" ### ", "#  ##", "# # #", "##  #", " ### "],
+        '1' => [" # ", "## ", " # ", " # ", "###"],
+        '2' => [" ### ", "#   #", "  ## ", " #   ", "#####"],
+        '3' => ["#### ", "    #", " ### ", "    #", "#### "],
+        '4' => ["#   #", "#   #", "#####", "    #", "    #"],
+        '5' => ["#####", "#    ", "#### ", "    #", "#### "],
+        '6' => [" ### ", "#    ", "#### ", "#   #", " ### "],
+        '7' => ["#####", "    #", "   # ", "  #  ", "  #  "],
+        '8' => [" ### ", "#   #", " ### ", "#   #", " ### "],
+        '9' => [" ### ", "#   #", " ####", "    #", " ### "],
+        ' ' => ["   ", "   ", "   ", "   ", "   "],
+        '!' => ["#", "#", "#", " ", "#"],
+        '"' => ["# #", "# #", "   ", "   ", "   "],
+        '\'' => ["#", "#", " ", " ", " "],
+        '(' => [" #", "# ", "# ", "# ", " #"],
+        ')' => ["# ", " #", " #", " #", "# "],
+        '*' => ["     ", "# # #", " ### ", "# # #", "     "],
+        '+' => ["   ", " # ", "###", " # ", "   "],
+        ',' => ["  ", "  ", "  ", " #", "# "],
+        '-' => ["   ", "   ", "###", "   ", "   "],
+        '.' => [" ", " ", " ", " ", "#"],
+        '/' => ["    #", "   # ", "  #  ", " #   ", "#    "],
+        ':' => [" ", "#", " ", "#", " "],
+        ';' => ["  ", " #", "  ", " #", "# "],
+        '<' => ["  #", " # ", "#  ", " # ", "  #"],
+        '=' => ["   ", "###", "   ", "###", "   "],
+        '>' => ["#  ", " # ", "  #", " # ", "#  "],
+        '?' => [" ### ", "#   #", "  ## ", "     ", "  #  "],
+        '%' => ["#   #", "   # ", "  #  ", " #   ", "#   #"],
+        '_' => ["   ", "   ", "   ", "   ", "###"],
+        _ => return None,
+    };
+    Some(glyph)
+}
+
+/// ## FigletFont
+///
+/// A font in the FIGlet format (`.flf`). Characters are laid out at full width, without smushing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FigletFont {
+    height: usize,
+    glyphs: HashMap<char, Vec<String>>,
+}
+
+impl FigletFont {
+    /// ### parse
+    ///
+    /// Parse the source of a FIGlet font; returns `None` if the header is invalid.
+    /// Only the printable ASCII characters are read
+    pub(crate) fn parse(source: &str) -> Option<Self> {
+        let mut lines = source.lines();
+        let header = lines.next()?;
+        let hardblank = header.strip_prefix("flf2a")?.chars().next()?;
+        let params: Vec<usize> = header
+            .split_whitespace()
+            .skip(1)
+            .map_while(|x| x.parse().ok())
+            .collect();
+        let (height, comments) = (*params.first()?, *params.get(4)?);
+        if height == 0 {
+            return None;
+        }
+        let mut lines = lines.skip(comments);
+        let mut glyphs = HashMap::new();
+        for c in ' '..='~' {
+            let rows: Vec<String> = lines
+                .by_ref()
+                .take(height)
+                .map(|line| {
+                    // Rows end with one or two end marks, usually `@`
+                    let line = line.trim_end();
+                    match line.chars().last() {
+                        Some(mark) => line.trim_end_matches(mark).replace(hardblank, " "),
+                        None => String::new(),
+                    }
+                })
+                .collect();
+            if rows.len() < height {
+                break;
+            }
+            let width = rows.iter().map(|x| x.chars().count()).max().unwrap_or(0);
+            let rows = rows.into_iter().map(|x| format!("{x:width$}")).collect();
+            glyphs.insert(c, rows);
+        }
+        Some(Self { height, glyphs })
+    }
+}
+
+/// Rows of a glyph
+type Glyph = Vec<Vec<char>>;
+
+/// ## Glyphs
+///
+/// The font used to render a big text
+pub(crate) enum Glyphs<'a> {
+    Builtin(BigTextFont),
+    Figlet(&'a FigletFont),
+}
+
+impl Glyphs<'_> {
+    pub(crate) fn height(&self) -> usize {
+        match self {
+            Self::Builtin(BigTextFont::Block) => 5,
+            Self::Builtin(BigTextFont::HalfBlock) => 3,
+            Self::Figlet(font) => font.height,
+        }
+    }
+
+    /// Columns between glyphs
+    fn spacing(&self) -> usize {
+        match self {
+            Self::Builtin(_) => 1,
+            Self::Figlet(_) => 0,
+        }
+    }
+
+    /// ### glyph
+    ///
+    /// Returns the rows of the glyph of `c`, all of the same width; unknown characters are rendered as `?`
+    fn glyph(&self, c: char) -> Glyph {
+        match self {
+            Self::Builtin(font) => {
+                let bitmap = bitmap(c).or_else(|| bitmap('?')).unwrap_or_default();
+                let pixels: Vec<Vec<bool>> = bitmap
+                    .iter()
+                    .map(|row| row.chars().map(|x| x == '#').collect())
+                    .collect();
+                match font {
+                    BigTextFont::Block => pixels
+                        .iter()
+                        .map(|row| row.iter().map(|x| if *x { '█' } else { ' ' }).collect())
+                        .collect(),
+                    BigTextFont::HalfBlock => pixels
+                        .chunks(2)
+                        .map(|rows| {
+                            (0..rows[0].len())
+                                .map(|x| {
+                                    let bottom = rows.get(1).is_some_and(|row| row[x]);
+                                    match (rows[0][x], bottom) {
+                                        (true, true) => '█',
+                                        (true, false) => '▀',
+                                        (false, true) => '▄',
+                                        (false, false) => ' ',
+                                    }
+                                })
+                                .collect()
+                        })
+                        .collect(),
+                }
+            }
+            Self::Figlet(font) => font
+                .glyphs
+                .get(&c)
+                .or_else(|| font.glyphs.get(&'?'))
+                .map(|rows| rows.iter().map(|x| x.chars().collect()).collect())
+                .unwrap_or_else(|| vec![Vec::new(); font.height]),
+        }
+    }
+
+    /// ### layout
+    ///
+    /// Returns the glyphs of `text` with their column, and the total width
+    fn layout(&self, text: &str) -> (Vec<(usize, char, Glyph)>, usize) {
+        let mut x = 0;
+        let mut glyphs = Vec::new();
+        for c in text.chars() {
+            if !glyphs.is_empty() {
+                x += self.spacing();
+            }
+            let glyph = self.glyph(c);
+            let width = glyph.first().map(|x| x.len()).unwrap_or(0);
+            glyphs.push((x, c, glyph));
+            x += width;
+        }
+        (glyphs, x)
+    }
+}
+
+/// ## Coloring
+///
+/// How the letters of a big text are colored
+pub(crate) enum Coloring<'a> {
+    /// Letter by letter, cycling through colors
+    Letters(&'a [Color]),
+    /// With a horizontal gradient through colors
+    Gradient(&'a [Color]),
+}
+
+/// ### gradient
+///
+/// Returns the color at `t` (from 0 to 1) of a gradient through `colors`.
+/// RGB colors are interpolated; other colors can't be, so the nearest one is taken
+fn gradient(colors: &[Color], t: f64) -> Color {
+    match colors.len() {
+        0 => return Color::Reset,
+        1 => return colors[0],
+        _ => {}
+    }
+    let position = t.clamp(0.0, 1.0) * (colors.len() - 1) as f64;
+    let i = (position.floor() as usize).min(colors.len() - 2);
+    let t = position - i as f64;
+    match (colors[i], colors[i + 1]) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+            let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+            Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+        }
+        (a, b) => match t < 0.5 {
+            true => a,
+            false => b,
+        },
+    }
+}
+
+/// ### render_big_text
+///
+/// Render `text` in `area` with `glyphs`, aligned horizontally; overflowing glyphs are cut.
+/// Returns the area of each character of `text`
+pub(crate) fn render_big_text(
+    buf: &mut Buffer,
+    area: Rect,
+    text: &str,
+    glyphs: &Glyphs,
+    alignment: Alignment,
+    coloring: &Coloring,
+    style: Style,
+) -> Vec<Rect> {
+    let (layout, width) = glyphs.layout(text);
+    let offset = match alignment {
+        Alignment::Left => 0,
+        Alignment::Center => (area.width as usize).saturating_sub(width) / 2,
+        Alignment::Right => (area.width as usize).saturating_sub(width),
+    };
+    let mut letter = 0;
+    let mut areas = Vec::with_capacity(layout.len());
+    for (x, c, glyph) in layout {
+        let glyph_width = glyph.first().map(|x| x.len()).unwrap_or(0);
+        areas.push(
+            Rect::new(
+                area.x
+                    .saturating_add((offset + x).min(u16::MAX as usize) as u16),
+                area.y,
+                glyph_width.min(u16::MAX as usize) as u16,
+                glyph.len().min(u16::MAX as usize) as u16,
+            )
+            .intersection(area),
+        );
+        for (dy, row) in glyph.iter().enumerate().take(area.height as usize) {
+            for (dx, symbol) in row.iter().enumerate() {
+                let column = offset + x + dx;
+                if *symbol == ' ' || column >= area.width as usize {
+                    continue;
+                }
+                let style = match coloring {
+                    Coloring::Letters(colors) if !colors.is_empty() => {
+                        style.fg(colors[letter % colors.len()])
+                    }
+                    Coloring::Gradient(colors) if !colors.is_empty() => style.fg(gradient(
+                        colors,
+                        (x + dx) as f64 / width.saturating_sub(1).max(1) as f64,
+                    )),
+                    _ => style,
+                };
+                buf[(area.x + column as u16, area.y + dy as u16)]
+                    .set_char(*symbol)
+                    .set_style(style);
+            }
+        }
+        if !c.is_whitespace() {
+            letter += 1;
+        }
+    }
+    areas
+}
+
+// -- component
+
+/// ## BigText
+///
+/// A short text rendered with large glyphs, for splash screens and headers. Glyphs are drawn with blocks, or with a
+/// FIGlet font, and letters can be colored one by one or with a gradient
+#[derive(Default)]
+pub struct BigText {
+    props: Props,
+    /// Parsed FIGlet font
+    figlet: Option<FigletFont>,
+}
+
+impl BigText {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `big_text` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("big_text", self);
+    }
+
+    pub fn alignment(mut self, a: Alignment) -> Self {
+        self.attr(Attribute::Alignment, AttrValue::Alignment(a));
+        self
+    }
+
+    pub fn text<S: Into<String>>(mut self, s: S) -> Self {
+        self.attr(Attribute::Text, AttrValue::String(s.into()));
+        self
+    }
+
+    /// Set the built-in font (default: block)
+    pub fn font(mut self, font: BigTextFont) -> Self {
+        self.attr(
+            Attribute::Custom(BIG_TEXT_FONT),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(font as usize))),
+        );
+        self
+    }
+
+    /// Use a FIGlet font, from the source of a `.flf` file, instead of the built-in font
+    pub fn figlet_font<S: Into<String>>(mut self, source: S) -> Self {
+        self.attr(
+            Attribute::Custom(BIG_TEXT_FIGLET_FONT),
+            AttrValue::String(source.into()),
+        );
+        self
+    }
+
+    /// Color letters with `colors`, cycling through them
+    pub fn colors(mut self, colors: &[Color]) -> Self {
+        self.attr(
+            Attribute::Custom(BIG_TEXT_COLORS),
+            AttrValue::Payload(PropPayload::Vec(
+                colors.iter().map(|x| PropValue::Color(*x)).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Color the text with a horizontal gradient through `colors`
+    pub fn gradient(self, colors: &[Color]) -> Self {
+        let mut this = self.colors(colors);
+        this.attr(Attribute::Custom(BIG_TEXT_GRADIENT), AttrValue::Flag(true));
+        this
+    }
+
+    fn get_colors(&self) -> Vec<Color> {
+        match self
+            .props
+            .get(Attribute::Custom(BIG_TEXT_COLORS))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(colors)) => {
+                colors.into_iter().map(|x| x.unwrap_color()).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_glyphs(&self) -> Glyphs<'_> {
+        match &self.figlet {
+            Some(font) => Glyphs::Figlet(font),
+            None => {
+                let font = match self
+                    .props
+                    .get(Attribute::Custom(BIG_TEXT_FONT))
+                    .map(|x| x.unwrap_payload())
+                {
+                    Some(PropPayload::One(PropValue::Usize(x))) => {
+                        BigTextFont::ALL.get(x).copied().unwrap_or_default()
+                    }
+                    _ => BigTextFont::default(),
+                };
+                Glyphs::Builtin(font)
+            }
+        }
+    }
+
+    /// ### render_text
+    ///
+    /// Render the text in `area`
+    fn render_text(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let text = self
+            .props
+            .get(Attribute::Text)
+            .map(|x| x.unwrap_string())
+            .unwrap_or_default();
+        let alignment = self
+            .props
+            .get_or(Attribute::Alignment, AttrValue::Alignment(Alignment::Left))
+            .unwrap_alignment();
+        let colors = self.get_colors();
+        let coloring = match self
+            .props
+            .get_or(Attribute::Custom(BIG_TEXT_GRADIENT), AttrValue::Flag(false))
+            .unwrap_flag()
+        {
+            true => Coloring::Gradient(&colors),
+            false => Coloring::Letters(&colors),
+        };
+        render_big_text(
+            buf,
+            area,
+            &text,
+            &self.get_glyphs(),
+            alignment,
+            &coloring,
+            style,
+        );
+    }
+}
+
+impl MockComponent for BigText {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_text(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if attr == Attribute::Custom(BIG_TEXT_FIGLET_FONT) {
+            self.figlet = self
+                .props
+                .get(attr)
+                .and_then(|x| FigletFont::parse(&x.unwrap_string()));
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_components_big_text_gradient() {
+        let colors = [Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0)];
+        assert_eq!(gradient(&colors, 0.0), Color::Rgb(0, 0, 0));
+        assert_eq!(gradient(&colors, 0.5), Color::Rgb(100, 50, 0));
+        assert_eq!(gradient(&colors, 1.0), Color::Rgb(200, 100, 0));
+        assert_eq!(gradient(&[Color::Red, Color::Blue], 0.6), Color::Blue);
+        assert_eq!(gradient(&[Color::Red], 0.6), Color::Red);
+    }
+
+    #[test]
+    fn test_components_big_text() {
+        let component = BigText::default()
+            .text("Hi!")
+            .alignment(Alignment::Right)
+            .colors(&[Color::Red, Color::Blue]);
+        assert_eq!(component.state(), State::None);
+        let area = Rect::new(0, 0, 14, 5);
+        let mut buf = Buffer::empty(area);
+        component.render_text(&mut buf, area, Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![
+                "   █   █ ███ █",
+                "   █   █  █  █",
+                "   █████  █  █",
+                "   █   █  █   ",
+                "   █   █ ███ █",
+            ]
+        );
+        assert_eq!(buf[(3, 0)].fg, Color::Red);
+        assert_eq!(buf[(9, 0)].fg, Color::Blue);
+        assert_eq!(buf[(13, 0)].fg, Color::Red);
+        // Half blocks
+        let mut component = component.font(BigTextFont::HalfBlock);
+        component.attr(Attribute::Text, AttrValue::String(String::from("I!")));
+        let area = Rect::new(0, 0, 5, 3);
+        let mut buf = Buffer::empty(area);
+        component.render_text(&mut buf, area, Style::default());
+        assert_eq!(rows(&buf), vec!["▀█▀ █", " █  ▀", "▀▀▀ ▀"]);
+    }
+
+    #[test]
+    fn test_components_big_text_figlet() {
+        let font = "flf2a$ 2 2 5 0 1\ncomment\n$$@\n$$@@\n|@\n.@@\n";
+        let font = FigletFont::parse(font).unwrap();
+        assert_eq!(font.height, 2);
+        assert_eq!(font.glyphs[&' '], vec!["  ", "  "]);
+        assert_eq!(font.glyphs[&'!'], vec!["|", "."]);
+        assert_eq!(FigletFont::parse("not a font"), None);
+        let glyphs = Glyphs::Figlet(&font);
+        assert_eq!(glyphs.layout("! !").1, 4);
+        let component = BigText::default()
+            .figlet_font("flf2a$ 2 2 5 0 1\ncomment\n$$@\n$$@@\n|@\n.@@\n")
+            .alignment(Alignment::Center)
+            .gradient(&[Color::Rgb(0, 0, 0), Color::Rgb(30, 30, 30)])
+            .text("!x!");
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buf = Buffer::empty(area);
+        component.render_text(&mut buf, area, Style::default());
+        // Unknown characters have no glyph and no `?` in this font
+        assert_eq!(rows(&buf), vec![" ||  ", " ..  "]);
+        assert_eq!(buf[(1, 0)].fg, Color::Rgb(0, 0, 0));
+        assert_eq!(buf[(2, 0)].fg, Color::Rgb(30, 30, 30));
+    }
+}
@@ -0,0 +1,442 @@
+//! ## Breadcrumbs
+//!
+//! `Breadcrumbs` renders a navigation path of segments
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{BREADCRUMBS_SEPARATOR, TOOLTIP};
+
+const ELLIPSIS: &str = "…";
+
+/// ### visible_segments
+///
+/// Choose which segments fit in `max` columns, given their `widths` and the width of the separator.
+/// When the path is too wide, it's truncated from the middle, keeping the first segment and the `selected` one visible;
+/// `None` stands for the ellipsis replacing the hidden segments
+fn visible_segments(
+    widths: &[usize],
+    separator: usize,
+    selected: usize,
+    max: usize,
+) -> Vec<Option<usize>> {
+    let build = |lo: usize, hi: usize| -> Vec<Option<usize>> {
+        let mut items = Vec::new();
+        if lo > 0 {
+            items.push(Some(0));
+        }
+        if lo > 1 {
+            items.push(None);
+        }
+        items.extend((lo..hi).map(Some));
+        if hi < widths.len() {
+            items.push(None);
+        }
+        items
+    };
+    let fits = |items: &[Option<usize>]| {
+        let width: usize = items
+            .iter()
+            .map(|x| x.map(|i| widths[i]).unwrap_or(ELLIPSIS.width()))
+            .sum();
+        width + separator * items.len().saturating_sub(1) <= max
+    };
+    let all = build(0, widths.len());
+    if widths.is_empty() || fits(&all) {
+        return all;
+    }
+    // Grow a window around the selected segment, towards the end first
+    let selected = selected.min(widths.len() - 1);
+    let (mut lo, mut hi) = (selected, selected + 1);
+    loop {
+        if hi < widths.len() && fits(&build(lo, hi + 1)) {
+            hi += 1;
+        } else if lo > 0 && fits(&build(lo - 1, hi)) {
+            lo -= 1;
+        } else {
+            break;
+        }
+    }
+    build(lo, hi)
+}
+
+// -- states
+
+/// ## BreadcrumbsStates
+///
+/// BreadcrumbsStates contains states for this component
+#[derive(Default)]
+pub struct BreadcrumbsStates {
+    pub segments: Vec<String>,
+    pub selected: usize,
+}
+
+impl BreadcrumbsStates {
+    /// ### set_segments
+    ///
+    /// Set the path segments and select the last one
+    pub fn set_segments(&mut self, segments: Vec<String>) {
+        self.selected = segments.len().saturating_sub(1);
+        self.segments = segments;
+    }
+
+    /// ### select
+    ///
+    /// Select segment at `index`, if it exists. Returns whether the selection has changed
+    pub fn select(&mut self, index: usize) -> bool {
+        if index < self.segments.len() && index != self.selected {
+            self.selected = index;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+// -- component
+
+/// ## Breadcrumbs
+///
+/// A path of segments joined by a separator, such as `home › docs › report.md`.
+/// Segments can be selected to navigate back to them; when the path is too wide, it's truncated from the middle.
+#[derive(Default)]
+pub struct Breadcrumbs {
+    props: Props,
+    pub states: BreadcrumbsStates,
+}
+
+impl Breadcrumbs {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `breadcrumbs` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("breadcrumbs", self);
+    }
+
+    /// Set the color of the selected segment; `foreground` by default
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Set the separator between segments (default: ` › `)
+    pub fn separator<S: Into<String>>(mut self, s: S) -> Self {
+        self.attr(
+            Attribute::Custom(BREADCRUMBS_SEPARATOR),
+            AttrValue::String(s.into()),
+        );
+        self
+    }
+
+    /// Set the path segments; the last one gets selected
+    pub fn segments<S: AsRef<str>>(mut self, segments: &[S]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(
+                segments
+                    .iter()
+                    .map(|x| PropValue::Str(x.as_ref().to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Select the segment at `index`
+    pub fn value(mut self, index: usize) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(index))),
+        );
+        self
+    }
+
+    fn get_separator(&self) -> String {
+        self.props
+            .get_or(
+                Attribute::Custom(BREADCRUMBS_SEPARATOR),
+                AttrValue::String(String::from(" › ")),
+            )
+            .unwrap_string()
+    }
+
+    fn select(&mut self, index: usize) -> CmdResult {
+        match self.states.select(index) {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+
+    /// ### render_path
+    ///
+    /// Render the path on the first row of `area`. If `highlight` is some, the selected segment is drawn with it
+    fn render_path(&self, buf: &mut Buffer, area: Rect, style: Style, highlight: Option<Style>) {
+        if area.height == 0 {
+            return;
+        }
+        let separator = self.get_separator();
+        let widths: Vec<usize> = self.states.segments.iter().map(|x| x.width()).collect();
+        let items = visible_segments(
+            &widths,
+            separator.width(),
+            self.states.selected,
+            area.width as usize,
+        );
+        let mut x = area.x;
+        for (n, item) in items.into_iter().enumerate() {
+            let max_width = |x: u16| area.right().saturating_sub(x) as usize;
+            if n > 0 {
+                (x, _) = buf.set_stringn(x, area.y, &separator, max_width(x), style);
+            }
+            let (text, style) = match item {
+                Some(i) if i == self.states.selected => {
+                    (self.states.segments[i].as_str(), highlight.unwrap_or(style))
+                }
+                Some(i) => (self.states.segments[i].as_str(), style),
+                None => (ELLIPSIS, style),
+            };
+            (x, _) = buf.set_stringn(x, area.y, text, max_width(x), style);
+        }
+    }
+}
+
+impl MockComponent for Breadcrumbs {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let highlighted_color = self
+                .props
+                .get_or(Attribute::HighlightedColor, AttrValue::Color(foreground))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            // The selected segment is highlighted only while navigating
+            let highlight = match focus {
+                true => Some(
+                    style
+                        .fg(highlighted_color)
+                        .add_modifier(TextModifiers::REVERSED),
+                ),
+                false => None,
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_path(render.buffer_mut(), inner, style, highlight);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Content => {
+                let segments = value
+                    .unwrap_payload()
+                    .unwrap_vec()
+                    .into_iter()
+                    .map(|x| x.unwrap_str())
+                    .collect();
+                self.states.set_segments(segments);
+            }
+            Attribute::Value => {
+                self.states
+                    .select(value.unwrap_payload().unwrap_one().unwrap_usize());
+            }
+            attr => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.segments.is_empty() {
+            true => State::None,
+            false => State::One(StateValue::Usize(self.states.selected)),
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Move(Direction::Left) if self.states.selected > 0 => {
+                self.select(self.states.selected - 1)
+            }
+            Cmd::Move(Direction::Right) => self.select(self.states.selected + 1),
+            Cmd::GoTo(Position::Begin) => self.select(0),
+            Cmd::GoTo(Position::End) => self.select(self.states.segments.len().saturating_sub(1)),
+            Cmd::Submit => match self.state() {
+                State::None => CmdResult::None,
+                state => CmdResult::Submit(state),
+            },
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_breadcrumbs_visible_segments() {
+        let widths = [4, 4, 4, 4, 4];
+        // Everything fits: 5 * 4 + 4 * 3
+        assert_eq!(visible_segments(&widths, 3, 4, 32).len(), 5);
+        // first, …, 3, 4
+        assert_eq!(
+            visible_segments(&widths, 3, 4, 25),
+            vec![Some(0), None, Some(3), Some(4)]
+        );
+        // Selected segment is kept visible
+        assert_eq!(
+            visible_segments(&widths, 3, 1, 18),
+            vec![Some(0), Some(1), None]
+        );
+        assert_eq!(
+            visible_segments(&widths, 3, 2, 19),
+            vec![Some(0), None, Some(2), None]
+        );
+        assert_eq!(visible_segments(&[], 3, 0, 18), vec![]);
+    }
+
+    #[test]
+    fn test_components_breadcrumbs() {
+        let mut component = Breadcrumbs::default()
+            .borders(Borders::default())
+            .title("Location", Alignment::Left)
+            .segments(&["home", "user", "docs"]);
+        assert_eq!(component.state(), State::One(StateValue::Usize(2)));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(0)))
+        );
+        component.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(5))),
+        );
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        component.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(vec![])),
+        );
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_breadcrumbs_render() {
+        let component = Breadcrumbs::default()
+            .separator("/")
+            .segments(&["usr", "local", "share", "fonts"])
+            .value(2);
+        let area = Rect::new(0, 0, 16, 1);
+        let mut buf = Buffer::empty(area);
+        let highlight = Style::default().fg(Color::Yellow);
+        component.render_path(&mut buf, area, Style::default(), Some(highlight));
+        let row: String = (0..area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect();
+        assert_eq!(row, "usr/…/share/…   ");
+        assert_eq!(buf[(6, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(11, 0)].fg, Color::Reset);
+    }
+}
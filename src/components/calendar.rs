@@ -0,0 +1,548 @@
+//! ## Calendar
+//!
+//! `Calendar` renders one or more months, highlighting dates such as events and today
+
+use std::collections::HashMap;
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{
+    get_block, get_title_style, is_disabled, render_disabled, render_tooltip, Date,
+};
+
+// -- Props
+
+use super::props::{
+    CALENDAR_EVENTS, CALENDAR_INTERACTIVE, CALENDAR_MONTHS, CALENDAR_TODAY_STYLE, TOOLTIP,
+};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAYS_HEADER: &str = "Mo Tu We Th Fr Sa Su";
+/// Width of a day cell in the month grid
+const DAY_WIDTH: u16 = 3;
+/// Width of the month grid: seven day cells, without the trailing space
+const MONTH_WIDTH: u16 = DAY_WIDTH * 7 - 1;
+/// Height of the month grid: month title, weekdays header and six weeks
+pub(super) const MONTH_HEIGHT: u16 = 8;
+/// Space between months
+const MONTH_SPACING: u16 = 2;
+
+/// ### render_month
+///
+/// Render the grid of `month` into `area`: the month title, the weekdays header and the days, styled with `day_style`.
+/// If `arrows` is true, arrows are displayed around the title, to hint the month can be changed
+pub(super) fn render_month<F>(
+    buf: &mut Buffer,
+    area: Rect,
+    month: Date,
+    arrows: bool,
+    style: Style,
+    day_style: F,
+) where
+    F: Fn(Date) -> Style,
+{
+    let x = area.x + area.width.saturating_sub(MONTH_WIDTH) / 2;
+    let title = match arrows {
+        true => format!(
+            "◀ {} {} ▶",
+            MONTH_NAMES[month.month as usize - 1],
+            month.year
+        ),
+        false => format!("{} {}", MONTH_NAMES[month.month as usize - 1], month.year),
+    };
+    let title_x = area.x + area.width.saturating_sub(title.chars().count() as u16) / 2;
+    buf.set_stringn(title_x, area.y, title, area.width as usize, style);
+    if area.height < 2 {
+        return;
+    }
+    buf.set_stringn(
+        x,
+        area.y + 1,
+        WEEKDAYS_HEADER,
+        area.width as usize,
+        style.add_modifier(TextModifiers::BOLD),
+    );
+    let first = month.first_of_month();
+    for day in 1..=Date::days_in_month(month.year, month.month) {
+        let date = Date { day, ..first };
+        let index = u16::from(first.weekday()) + u16::from(day) - 1;
+        let (column, week) = (index % 7, index / 7);
+        let y = area.y + 2 + week;
+        if y >= area.bottom() {
+            break;
+        }
+        buf.set_stringn(
+            x + column * DAY_WIDTH,
+            y,
+            format!("{day:>2}"),
+            area.right().saturating_sub(x + column * DAY_WIDTH) as usize,
+            day_style(date),
+        );
+    }
+}
+
+// -- states
+
+/// ## CalendarStates
+///
+/// Calendar states: the date cursor and the first displayed month
+pub struct CalendarStates {
+    pub cursor: Date,
+    /// First day of the first displayed month
+    pub first_month: Date,
+}
+
+impl Default for CalendarStates {
+    fn default() -> Self {
+        let today = Date::today();
+        Self {
+            cursor: today,
+            first_month: today.first_of_month(),
+        }
+    }
+}
+
+impl CalendarStates {
+    /// ### set_cursor
+    ///
+    /// Move the cursor to `date`, scrolling the displayed months to keep it visible
+    pub fn set_cursor(&mut self, date: Date, months: usize) {
+        self.cursor = date;
+        let month = date.first_of_month();
+        let last_month = self.first_month.add_months(months.max(1) as i32 - 1);
+        if month < self.first_month {
+            self.first_month = month;
+        } else if month > last_month {
+            self.first_month = month.add_months(1 - months.max(1) as i32);
+        }
+    }
+
+    /// ### scroll
+    ///
+    /// Scroll the displayed months by `months`, without moving the cursor
+    pub fn scroll(&mut self, months: i32) {
+        self.first_month = self.first_month.add_months(months);
+    }
+}
+
+// -- component
+
+/// ## Calendar
+///
+/// A calendar displaying one or more months side by side. Dates can be highlighted with custom styles, and the user can
+/// be allowed to move a date cursor and submit a date.
+#[derive(Default)]
+pub struct Calendar {
+    props: Props,
+    pub states: CalendarStates,
+}
+
+impl Calendar {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `calendar` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("calendar", self);
+    }
+
+    /// Set the date of the cursor; the months around it are displayed
+    pub fn value(mut self, date: Date) -> Self {
+        self.attr(Attribute::Value, AttrValue::String(date.to_string()));
+        self
+    }
+
+    /// Set the amount of months to display; defaults to 1
+    pub fn months(mut self, months: usize) -> Self {
+        self.attr(
+            Attribute::Custom(CALENDAR_MONTHS),
+            AttrValue::Length(months.max(1)),
+        );
+        self
+    }
+
+    /// Set the dates to highlight, with their style
+    pub fn events(mut self, events: &[(Date, Style)]) -> Self {
+        self.attr(
+            Attribute::Custom(CALENDAR_EVENTS),
+            AttrValue::Payload(PropPayload::Map(
+                events
+                    .iter()
+                    .map(|(date, style)| (date.to_string(), PropValue::Style(*style)))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the style of today; defaults to underlined
+    pub fn today_style(mut self, s: Style) -> Self {
+        self.attr(Attribute::Custom(CALENDAR_TODAY_STYLE), AttrValue::Style(s));
+        self
+    }
+
+    /// Set whether the user can move the date cursor and submit a date
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CALENDAR_INTERACTIVE),
+            AttrValue::Flag(interactive),
+        );
+        self
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(CALENDAR_INTERACTIVE),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn get_months(&self) -> usize {
+        self.props
+            .get_or(Attribute::Custom(CALENDAR_MONTHS), AttrValue::Length(1))
+            .unwrap_length()
+    }
+
+    fn get_events(&self) -> HashMap<String, PropValue> {
+        self.props
+            .get(Attribute::Custom(CALENDAR_EVENTS))
+            .map(|x| x.unwrap_payload().unwrap_map())
+            .unwrap_or_default()
+    }
+
+    fn move_cursor<F>(&mut self, f: F) -> CmdResult
+    where
+        F: Fn(&Date) -> Date,
+    {
+        if !self.is_interactive() {
+            return CmdResult::None;
+        }
+        let cursor = f(&self.states.cursor);
+        self.states.set_cursor(cursor, self.get_months());
+        CmdResult::Changed(self.state())
+    }
+
+    /// ### render_calendar
+    ///
+    /// Render the displayed months into `area`, laid out in a grid which fits the area width
+    fn render_calendar(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let highlighted = self
+            .props
+            .get_or(
+                Attribute::HighlightedColor,
+                AttrValue::Color(style.fg.unwrap_or(Color::Reset)),
+            )
+            .unwrap_color();
+        let today_style = self
+            .props
+            .get_or(
+                Attribute::Custom(CALENDAR_TODAY_STYLE),
+                AttrValue::Style(Style::default().add_modifier(TextModifiers::UNDERLINED)),
+            )
+            .unwrap_style();
+        let events = self.get_events();
+        let today = Date::today();
+        let cursor = match self.is_interactive() {
+            true => Some(self.states.cursor),
+            false => None,
+        };
+        let day_style = |date: Date| {
+            let mut day_style = style;
+            if date == today {
+                day_style = day_style.patch(today_style);
+            }
+            if let Some(PropValue::Style(event)) = events.get(&date.to_string()) {
+                day_style = day_style.patch(*event);
+            }
+            if Some(date) == cursor {
+                day_style = day_style
+                    .fg(highlighted)
+                    .add_modifier(TextModifiers::REVERSED);
+            }
+            day_style
+        };
+        let columns = ((area.width + MONTH_SPACING) / (MONTH_WIDTH + MONTH_SPACING)).max(1);
+        for i in 0..self.get_months() as u16 {
+            let (column, row) = (i % columns, i / columns);
+            let x = area.x + column * (MONTH_WIDTH + MONTH_SPACING);
+            let y = area.y + row * (MONTH_HEIGHT + 1);
+            if x >= area.right() || y >= area.bottom() {
+                break;
+            }
+            let month_area = Rect::new(
+                x,
+                y,
+                MONTH_WIDTH.min(area.right() - x),
+                MONTH_HEIGHT.min(area.bottom() - y),
+            );
+            render_month(
+                buf,
+                month_area,
+                self.states.first_month.add_months(i32::from(i)),
+                false,
+                style,
+                day_style,
+            );
+        }
+    }
+}
+
+impl MockComponent for Calendar {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_calendar(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Value => {
+                if let Some(date) = self
+                    .props
+                    .get(Attribute::Value)
+                    .and_then(|x| Date::parse(&x.unwrap_string()))
+                {
+                    self.states.set_cursor(date, self.get_months());
+                }
+            }
+            Attribute::Custom(CALENDAR_MONTHS) => {
+                let cursor = self.states.cursor;
+                self.states.set_cursor(cursor, self.get_months());
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.is_interactive() {
+            true => State::One(StateValue::String(self.states.cursor.to_string())),
+            false => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Move(Direction::Left) => self.move_cursor(|x| x.add_days(-1)),
+            Cmd::Move(Direction::Right) => self.move_cursor(|x| x.add_days(1)),
+            Cmd::Move(Direction::Up) => self.move_cursor(|x| x.add_days(-7)),
+            Cmd::Move(Direction::Down) => self.move_cursor(|x| x.add_days(7)),
+            Cmd::Scroll(Direction::Up) if self.is_interactive() => {
+                self.move_cursor(|x| x.add_months(-1))
+            }
+            Cmd::Scroll(Direction::Down) if self.is_interactive() => {
+                self.move_cursor(|x| x.add_months(1))
+            }
+            // Read-only calendars scroll the displayed months
+            Cmd::Scroll(Direction::Up) => {
+                self.states.scroll(-1);
+                CmdResult::None
+            }
+            Cmd::Scroll(Direction::Down) => {
+                self.states.scroll(1);
+                CmdResult::None
+            }
+            Cmd::GoTo(Position::Begin) => self.move_cursor(|x| x.first_of_month()),
+            Cmd::GoTo(Position::End) => self.move_cursor(|x| Date {
+                day: Date::days_in_month(x.year, x.month),
+                ..*x
+            }),
+            Cmd::Submit if self.is_interactive() => CmdResult::Submit(self.state()),
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn date(s: &str) -> Date {
+        Date::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_components_calendar_interactive() {
+        let mut component = Calendar::default()
+            .title("Agenda", Alignment::Center)
+            .borders(Borders::default())
+            .months(2)
+            .interactive(true)
+            .value(date("2024-01-31"));
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("2024-01-31")))
+        );
+        assert_eq!(component.states.first_month, date("2024-01-01"));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("2024-02-01"))))
+        );
+        // February is displayed already
+        assert_eq!(component.states.first_month, date("2024-01-01"));
+        component.perform(Cmd::Scroll(Direction::Down));
+        assert_eq!(component.states.cursor, date("2024-03-01"));
+        assert_eq!(component.states.first_month, date("2024-02-01"));
+        component.perform(Cmd::GoTo(Position::End));
+        component.perform(Cmd::Move(Direction::Up));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::String(String::from("2024-03-24"))))
+        );
+        component.perform(Cmd::Move(Direction::Up));
+        component.perform(Cmd::Move(Direction::Up));
+        component.perform(Cmd::Move(Direction::Up));
+        component.perform(Cmd::Move(Direction::Up));
+        assert_eq!(component.states.cursor, date("2024-02-25"));
+        assert_eq!(component.states.first_month, date("2024-02-01"));
+    }
+
+    #[test]
+    fn test_components_calendar_read_only() {
+        let mut component = Calendar::default().value(date("2024-05-10"));
+        assert_eq!(component.state(), State::None);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(component.states.cursor, date("2024-05-10"));
+        component.perform(Cmd::Scroll(Direction::Down));
+        assert_eq!(component.states.first_month, date("2024-06-01"));
+        assert_eq!(component.states.cursor, date("2024-05-10"));
+    }
+
+    #[test]
+    fn test_components_calendar_render() {
+        let event = Style::default().fg(Color::Red);
+        let component = Calendar::default()
+            .months(3)
+            .interactive(true)
+            .events(&[(date("2024-02-14"), event)])
+            .value(date("2024-01-02"));
+        let area = Rect::new(0, 0, 44, 17);
+        let mut buf = Buffer::empty(area);
+        component.render_calendar(&mut buf, area, Style::default());
+        let row =
+            |y: u16| -> String { (0..44).map(|x| buf[(x, y)].symbol().to_string()).collect() };
+        // Two months fit the width; the third one is displayed below
+        assert_eq!(row(0), "    January 2024         February 2024      ");
+        assert_eq!(row(1), "Mo Tu We Th Fr Sa Su  Mo Tu We Th Fr Sa Su  ");
+        assert_eq!(row(2), " 1  2  3  4  5  6  7            1  2  3  4  ");
+        assert_eq!(row(9), "     March 2024                             ");
+        assert!(buf[(4, 2)].modifier.contains(TextModifiers::REVERSED));
+        assert!(!buf[(1, 2)].modifier.contains(TextModifiers::REVERSED));
+        // 14th of February
+        assert_eq!(buf[(28, 4)].symbol(), "1");
+        assert_eq!(buf[(28, 4)].fg, Color::Red);
+        assert_eq!(buf[(31, 4)].fg, Color::Reset);
+    }
+}
@@ -0,0 +1,538 @@
+//! ## CandlestickChart
+//!
+//! A chart to display open, high, low and close values over time
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{CANDLESTICK_CHART_CMD_ZOOM_IN, CANDLESTICK_CHART_CMD_ZOOM_OUT};
+use super::props::{
+    CANDLESTICK_CHART_DOWN_COLOR, CANDLESTICK_CHART_TIME_FORMAT, CANDLESTICK_CHART_UP_COLOR,
+    TOOLTIP,
+};
+
+/// Candle widths, from the most zoomed out to the most zoomed in
+const CANDLE_WIDTHS: [u16; 4] = [1, 3, 5, 7];
+/// Minimum gap between two time labels
+const TIME_LABELS_GAP: u16 = 2;
+
+// -- states
+
+/// ## CandlestickChartStates
+///
+/// CandlestickChart states: how many candles are scrolled out of view on the right, and the zoom level
+#[derive(Default)]
+pub struct CandlestickChartStates {
+    pub scroll: usize,
+    pub zoom: usize,
+    /// Candles fitting the last rendered area
+    pub visible: usize,
+}
+
+impl CandlestickChartStates {
+    /// ### scroll_left
+    ///
+    /// Scroll towards older candles
+    pub fn scroll_left(&mut self, candles: usize) {
+        if self.scroll + self.visible < candles {
+            self.scroll += 1;
+        }
+    }
+
+    /// ### scroll_right
+    ///
+    /// Scroll towards newer candles
+    pub fn scroll_right(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    /// ### scroll_to_begin
+    ///
+    /// Scroll to the oldest candles
+    pub fn scroll_to_begin(&mut self, candles: usize) {
+        self.scroll = candles.saturating_sub(self.visible);
+    }
+
+    /// ### scroll_to_end
+    ///
+    /// Scroll to the newest candles
+    pub fn scroll_to_end(&mut self) {
+        self.scroll = 0;
+    }
+
+    /// ### zoom_in
+    ///
+    /// Make candles wider
+    pub fn zoom_in(&mut self) {
+        self.zoom = (self.zoom + 1).min(CANDLE_WIDTHS.len() - 1);
+    }
+
+    /// ### zoom_out
+    ///
+    /// Make candles narrower
+    pub fn zoom_out(&mut self) {
+        self.zoom = self.zoom.saturating_sub(1);
+    }
+
+    /// ### candle_width
+    ///
+    /// Get the width of candles at the current zoom level
+    pub fn candle_width(&self) -> u16 {
+        CANDLE_WIDTHS[self.zoom.min(CANDLE_WIDTHS.len() - 1)]
+    }
+
+    /// ### window
+    ///
+    /// Get the range of visible candles, given the total of `candles` and how many of them fit the view
+    fn window(&mut self, candles: usize, fit: usize) -> std::ops::Range<usize> {
+        self.visible = fit.min(candles);
+        self.scroll = self.scroll.min(candles - self.visible);
+        let end = candles - self.scroll;
+        end - self.visible..end
+    }
+}
+
+// -- candle
+
+/// ### Candle
+///
+/// A candle as time, open, high, low and close
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candle {
+    time: f64,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+}
+
+// -- component
+
+/// ## CandlestickChart
+///
+/// A component to display candles with open, high, low and close values over time.
+/// Candles are rendered with up and down colors; the newest candles are visible first, and the window can be scrolled and zoomed.
+#[derive(Default)]
+pub struct CandlestickChart {
+    props: Props,
+    pub states: CandlestickChartStates,
+}
+
+impl CandlestickChart {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `candlestick_chart` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("candlestick_chart", self);
+    }
+
+    /// Set candles as (time, open, high, low, close), sorted by time
+    pub fn data(mut self, candles: &[(f64, f64, f64, f64, f64)]) -> Self {
+        self.attr(
+            Attribute::Dataset,
+            AttrValue::Payload(PropPayload::Linked(
+                candles
+                    .iter()
+                    .map(|(time, open, high, low, close)| {
+                        PropPayload::Vec(vec![
+                            PropValue::F64(*time),
+                            PropValue::F64(*open),
+                            PropValue::F64(*high),
+                            PropValue::F64(*low),
+                            PropValue::F64(*close),
+                        ])
+                    })
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set color of candles closing higher than or equal to their open value (default: green)
+    pub fn up_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(CANDLESTICK_CHART_UP_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    /// Set color of candles closing lower than their open value (default: red)
+    pub fn down_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(CANDLESTICK_CHART_DOWN_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    /// Format times as UNIX timestamps with `fmt` (see `utils::format_timestamp`)
+    pub fn time_format<S: Into<String>>(mut self, fmt: S) -> Self {
+        self.attr(
+            Attribute::Custom(CANDLESTICK_CHART_TIME_FORMAT),
+            AttrValue::String(fmt.into()),
+        );
+        self
+    }
+
+    fn get_data(&self) -> Vec<Candle> {
+        self.props
+            .get(Attribute::Dataset)
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_linked()
+                    .into_iter()
+                    .map(|x| {
+                        let values: Vec<f64> =
+                            x.unwrap_vec().into_iter().map(|x| x.unwrap_f64()).collect();
+                        Candle {
+                            time: values[0],
+                            open: values[1],
+                            high: values[2],
+                            low: values[3],
+                            close: values[4],
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn data_len(&self) -> usize {
+        self.props
+            .get(Attribute::Dataset)
+            .map(|x| x.unwrap_payload().unwrap_linked().len())
+            .unwrap_or(0)
+    }
+
+    fn fmt_time(&self, time: f64) -> String {
+        match self
+            .props
+            .get(Attribute::Custom(CANDLESTICK_CHART_TIME_FORMAT))
+            .map(|x| x.unwrap_string())
+        {
+            Some(fmt) => crate::utils::format_timestamp(time, &fmt),
+            None => Self::fmt_value(time),
+        }
+    }
+
+    fn fmt_value(value: f64) -> String {
+        match value.fract() == 0.0 {
+            true => format!("{value:.0}"),
+            false => format!("{value:.2}"),
+        }
+    }
+
+    /// ### value_row
+    ///
+    /// Get the row offset from the top of a plot of `height` for `value`, given the `bounds` of values
+    fn value_row(value: f64, bounds: (f64, f64), height: u16) -> u16 {
+        let (low, high) = bounds;
+        let ratio = match high > low {
+            true => ((high - value) / (high - low)).clamp(0.0, 1.0),
+            false => 0.5,
+        };
+        (ratio * (height.saturating_sub(1)) as f64).round() as u16
+    }
+
+    /// ### render_candles
+    ///
+    /// Render price axis, candles and time axis into `area`
+    fn render_candles(&mut self, buf: &mut Buffer, area: Rect, style: Style) {
+        let data = self.get_data();
+        let up_color = self
+            .props
+            .get_or(
+                Attribute::Custom(CANDLESTICK_CHART_UP_COLOR),
+                AttrValue::Color(Color::Green),
+            )
+            .unwrap_color();
+        let down_color = self
+            .props
+            .get_or(
+                Attribute::Custom(CANDLESTICK_CHART_DOWN_COLOR),
+                AttrValue::Color(Color::Red),
+            )
+            .unwrap_color();
+        let bounds = data
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), x| {
+                (low.min(x.low), high.max(x.high))
+            });
+        let (low_label, high_label) = (Self::fmt_value(bounds.0), Self::fmt_value(bounds.1));
+        // Price labels on the left; time axis and time labels take the last two rows
+        let labels_width = (low_label.width().max(high_label.width()) as u16).min(area.width);
+        let plot = Rect::new(
+            area.x + labels_width + 1,
+            area.y,
+            area.width.saturating_sub(labels_width + 1),
+            area.height.saturating_sub(2),
+        );
+        if plot.area() == 0 || data.is_empty() {
+            return;
+        }
+        // Visible candles, with bounds restricted to them
+        let candle_width = self.states.candle_width();
+        let slot = candle_width + u16::from(candle_width > 1);
+        let window = self
+            .states
+            .window(data.len(), (plot.width / slot).max(1) as usize);
+        let candles = &data[window];
+        let bounds = candles
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), x| {
+                (low.min(x.low), high.max(x.high))
+            });
+        // Axes
+        buf.set_stringn(
+            area.x,
+            plot.y,
+            Self::fmt_value(bounds.1),
+            labels_width as usize,
+            style,
+        );
+        buf.set_stringn(
+            area.x,
+            plot.bottom() - 1,
+            Self::fmt_value(bounds.0),
+            labels_width as usize,
+            style,
+        );
+        for y in plot.top()..plot.bottom() {
+            buf.set_string(plot.x - 1, y, "│", style);
+        }
+        buf.set_string(plot.x - 1, plot.bottom(), "└", style);
+        buf.set_string(
+            plot.x,
+            plot.bottom(),
+            "─".repeat(plot.width as usize),
+            style,
+        );
+        // Candles
+        let mut labels_end = plot.x;
+        for (i, candle) in candles.iter().enumerate() {
+            let x = plot.x + i as u16 * slot;
+            let center = x + candle_width / 2;
+            let color = match candle.close >= candle.open {
+                true => up_color,
+                false => down_color,
+            };
+            let candle_style = style.fg(color);
+            let row = |value: f64| plot.y + Self::value_row(value, bounds, plot.height);
+            for y in row(candle.high)..=row(candle.low) {
+                buf.set_string(center, y, "│", candle_style);
+            }
+            let (body_top, body_bottom) = (
+                row(candle.open.max(candle.close)),
+                row(candle.open.min(candle.close)),
+            );
+            let body = match body_top == body_bottom && candle.open == candle.close {
+                true => "─",
+                false => "█",
+            };
+            for y in body_top..=body_bottom {
+                buf.set_string(x, y, body.repeat(candle_width as usize), candle_style);
+            }
+            // Time label under the candle, if it doesn't overlap the previous one
+            let label = self.fmt_time(candle.time);
+            let label_x = center.saturating_sub(label.width() as u16 / 2).max(plot.x);
+            if label_x >= labels_end && label_x + label.width() as u16 <= plot.right() {
+                buf.set_string(label_x, plot.bottom() + 1, &label, style);
+                labels_end = label_x + label.width() as u16 + TIME_LABELS_GAP;
+            }
+        }
+    }
+}
+
+impl MockComponent for CandlestickChart {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = Style::default().fg(foreground).bg(background);
+            let div = crate::utils::get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = div.inner(area);
+            render.render_widget(div, area);
+            self.render_candles(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let candles = self.data_len();
+        match cmd {
+            Cmd::Move(Direction::Left) => self.states.scroll_left(candles),
+            Cmd::Move(Direction::Right) => self.states.scroll_right(),
+            Cmd::GoTo(Position::Begin) => self.states.scroll_to_begin(candles),
+            Cmd::GoTo(Position::End) => self.states.scroll_to_end(),
+            Cmd::Scroll(Direction::Up) | Cmd::Custom(CANDLESTICK_CHART_CMD_ZOOM_IN) => {
+                self.states.zoom_in()
+            }
+            Cmd::Scroll(Direction::Down) | Cmd::Custom(CANDLESTICK_CHART_CMD_ZOOM_OUT) => {
+                self.states.zoom_out()
+            }
+            _ => {}
+        }
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_candlestick_chart_states() {
+        let mut states = CandlestickChartStates::default();
+        assert_eq!(states.candle_width(), 1);
+        states.zoom_out();
+        assert_eq!(states.candle_width(), 1);
+        states.zoom_in();
+        assert_eq!(states.candle_width(), 3);
+        for _ in 0..10 {
+            states.zoom_in();
+        }
+        assert_eq!(states.candle_width(), 7);
+        // Window: newest candles first
+        assert_eq!(states.window(10, 4), 6..10);
+        states.scroll_left(10);
+        assert_eq!(states.window(10, 4), 5..9);
+        states.scroll_right();
+        states.scroll_right();
+        assert_eq!(states.window(10, 4), 6..10);
+        states.scroll_to_begin(10);
+        assert_eq!(states.window(10, 4), 0..4);
+        // Can't scroll further
+        states.scroll_left(10);
+        assert_eq!(states.window(10, 4), 0..4);
+        states.scroll_to_end();
+        assert_eq!(states.window(10, 4), 6..10);
+        // Less candles than fit
+        assert_eq!(states.window(2, 4), 0..2);
+    }
+
+    #[test]
+    fn test_components_candlestick_chart() {
+        let mut component = CandlestickChart::default()
+            .title("BTC", Alignment::Center)
+            .borders(Borders::default())
+            .up_color(Color::Cyan)
+            .down_color(Color::Magenta)
+            .data(&[
+                (1.0, 10.0, 14.0, 8.0, 12.0),
+                (2.0, 12.0, 13.0, 6.0, 7.0),
+                (3.0, 7.0, 10.0, 6.0, 7.0),
+            ]);
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.data_len(), 3);
+        // Render
+        let area = Rect::new(0, 0, 9, 6);
+        let mut buf = Buffer::empty(area);
+        component.render_candles(&mut buf, area, Style::default());
+        let row = |y: u16| -> String { (0..9).map(|x| buf[(x, y)].symbol().to_string()).collect() };
+        assert_eq!(row(0), "14│││    ");
+        assert_eq!(row(1), "  │██    ");
+        assert_eq!(row(2), "  │██│   ");
+        assert_eq!(row(3), "6 │ █─   ");
+        assert_eq!(row(4), "  └──────");
+        assert_eq!(row(5), "   1     ");
+        assert_eq!(buf[(3, 1)].fg, Color::Cyan);
+        assert_eq!(buf[(4, 2)].fg, Color::Magenta);
+        // Time format
+        let mut component = component.time_format("%H:%M");
+        assert_eq!(component.fmt_time(3600.0), "01:00");
+        // Zoom and scroll
+        assert_eq!(
+            component.perform(Cmd::Custom(CANDLESTICK_CHART_CMD_ZOOM_IN)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.candle_width(), 3);
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(component.states.scroll, 0);
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! A canvas where you can draw more complex figures
 
-use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Shape, Style,
 };
@@ -11,24 +11,197 @@ use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::{
     layout::Rect,
     text::Span,
-    widgets::canvas::{Canvas as TuiCanvas, Context, Points},
+    widgets::canvas::{Canvas as TuiCanvas, Context, Line, Map, Points, Rectangle},
 };
 use tuirealm::{Frame, MockComponent, State};
 
+use crate::theme::Theme;
+use crate::utils::{get_title_style, is_disabled, render_disabled, render_tooltip, FocusStyles};
+
+// -- Commands
+use super::commands::{CANVAS_CMD_RESET_VIEW, CANVAS_CMD_ZOOM_IN, CANVAS_CMD_ZOOM_OUT};
+
 // -- Props
 use super::props::{
     CANVAS_MARKER, CANVAS_MARKER_BLOCK, CANVAS_MARKER_BRAILLE, CANVAS_MARKER_DOT, CANVAS_X_BOUNDS,
-    CANVAS_Y_BOUNDS,
+    CANVAS_Y_BOUNDS, TOOLTIP,
 };
 
+/// Amount of segments used to approximate a circle
+const CIRCLE_SEGMENTS: usize = 64;
+/// Fraction of the visible range the view is moved by at each pan step
+const PAN_STEP: f64 = 0.1;
+/// Factor the zoom is multiplied or divided by at each zoom step
+const ZOOM_STEP: f64 = 1.25;
+const MIN_ZOOM: f64 = 0.01;
+const MAX_ZOOM: f64 = 1000.0;
+
+// -- states
+
+/// ## CanvasStates
+///
+/// Canvas view states: the offset of the view center from the center of the bounds, in world coordinates, and the zoom level
+pub struct CanvasStates {
+    pub offset: (f64, f64),
+    pub zoom: f64,
+}
+
+impl Default for CanvasStates {
+    fn default() -> Self {
+        Self {
+            offset: (0.0, 0.0),
+            zoom: 1.0,
+        }
+    }
+}
+
+impl CanvasStates {
+    /// ### pan
+    ///
+    /// Move view by `(dx, dy)` in world coordinates
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        self.offset = (self.offset.0 + dx, self.offset.1 + dy);
+    }
+
+    /// ### zoom_in
+    ///
+    /// Increase zoom by one step
+    pub fn zoom_in(&mut self) {
+        self.zoom = (self.zoom * ZOOM_STEP).min(MAX_ZOOM);
+    }
+
+    /// ### zoom_out
+    ///
+    /// Decrease zoom by one step
+    pub fn zoom_out(&mut self) {
+        self.zoom = (self.zoom / ZOOM_STEP).max(MIN_ZOOM);
+    }
+
+    /// ### reset
+    ///
+    /// Reset offset and zoom
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// ### view_bounds
+    ///
+    /// Get the visible bounds on an axis with `bounds`, given the view offset on the same axis
+    pub fn view_bounds(&self, bounds: (f64, f64), offset: f64) -> (f64, f64) {
+        let center = (bounds.0 + bounds.1) / 2.0 + offset;
+        let half = (bounds.1 - bounds.0) / 2.0 / self.zoom;
+        (center - half, center + half)
+    }
+}
+
+// -- shapes
+
+/// ## CanvasShapes
+///
+/// A typed builder for the shapes to draw on a [`Canvas`].
+/// Shapes are drawn in the order they're added; use `layer` to draw the following shapes on top of the previous ones.
+///
+/// Shapes can be passed to the canvas with [`Canvas::shapes`] or via `attr()`, since they can be converted into an `AttrValue`
+#[derive(Default, Clone)]
+pub struct CanvasShapes {
+    shapes: Vec<Shape>,
+}
+
+impl CanvasShapes {
+    pub fn line(mut self, from: (f64, f64), to: (f64, f64), color: Color) -> Self {
+        self.shapes.push(Shape::Line(Line {
+            x1: from.0,
+            y1: from.1,
+            x2: to.0,
+            y2: to.1,
+            color,
+        }));
+        self
+    }
+
+    /// A rectangle with the bottom-left corner at `(x, y)`
+    pub fn rectangle(mut self, x: f64, y: f64, width: f64, height: f64, color: Color) -> Self {
+        self.shapes.push(Shape::Rectangle(Rectangle {
+            x,
+            y,
+            width,
+            height,
+            color,
+        }));
+        self
+    }
+
+    /// A circle, approximated with lines
+    pub fn circle(self, center: (f64, f64), radius: f64, color: Color) -> Self {
+        let (x, y) = center;
+        let points: Vec<(f64, f64)> = (0..=CIRCLE_SEGMENTS)
+            .map(|i| {
+                let angle = std::f64::consts::TAU * i as f64 / CIRCLE_SEGMENTS as f64;
+                (x + radius * angle.cos(), y + radius * angle.sin())
+            })
+            .collect();
+        self.polyline(&points, color)
+    }
+
+    /// Lines connecting each point to the next one
+    pub fn polyline(mut self, points: &[(f64, f64)], color: Color) -> Self {
+        for segment in points.windows(2) {
+            self = self.line(segment[0], segment[1], color);
+        }
+        self
+    }
+
+    pub fn points(mut self, points: &[(f64, f64)], color: Color) -> Self {
+        self.shapes.push(Shape::Points((points.to_vec(), color)));
+        self
+    }
+
+    /// A text printed at `(x, y)`
+    pub fn label<S: Into<String>>(mut self, x: f64, y: f64, text: S, color: Color) -> Self {
+        self.shapes.push(Shape::Label((x, y, text.into(), color)));
+        self
+    }
+
+    /// A point with a text printed next to it
+    pub fn labeled_point<S: Into<String>>(self, x: f64, y: f64, text: S, color: Color) -> Self {
+        self.points(&[(x, y)], color).label(x, y, text, color)
+    }
+
+    pub fn map(mut self, map: Map) -> Self {
+        self.shapes.push(Shape::Map(map));
+        self
+    }
+
+    /// Draw the following shapes on a new layer, on top of the previous ones
+    pub fn layer(mut self) -> Self {
+        self.shapes.push(Shape::Layer);
+        self
+    }
+
+    pub fn build(self) -> Vec<Shape> {
+        self.shapes
+    }
+}
+
+impl From<CanvasShapes> for AttrValue {
+    fn from(shapes: CanvasShapes) -> Self {
+        AttrValue::Payload(PropPayload::Vec(
+            shapes.shapes.into_iter().map(PropValue::Shape).collect(),
+        ))
+    }
+}
+
 // -- Component
 
 /// ## Canvas
 ///
 /// The Canvas widget may be used to draw more detailed figures using braille patterns (each cell can have a braille character in 8 different positions).
+///
+/// The view can be moved with arrows and zoomed with scroll commands or with the canvas custom commands.
 #[derive(Default)]
 pub struct Canvas {
     props: Props,
+    pub states: CanvasStates,
 }
 
 impl Canvas {
@@ -52,6 +225,28 @@ impl Canvas {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `canvas` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("canvas", self);
+    }
+
     pub fn data(mut self, data: &[Shape]) -> Self {
         self.attr(
             Attribute::Shape,
@@ -62,6 +257,12 @@ impl Canvas {
         self
     }
 
+    /// Set shapes to draw, built with [`CanvasShapes`]
+    pub fn shapes(mut self, shapes: CanvasShapes) -> Self {
+        self.attr(Attribute::Shape, shapes.into());
+        self
+    }
+
     /// From <https://github.com/fdehau/tui-rs/issues/286>:
     ///
     /// > Those are used to define the viewport of the canvas.
@@ -130,6 +331,29 @@ impl Canvas {
         }
     }
 
+    /// ### bounds
+    ///
+    /// Get the bounds set for an axis
+    fn bounds(&self, attr: &'static str) -> (f64, f64) {
+        self.props
+            .get(Attribute::Custom(attr))
+            .map(|x| x.unwrap_payload().unwrap_tup2())
+            .map(|(a, b)| (a.unwrap_f64(), b.unwrap_f64()))
+            .unwrap_or((0.0, 0.0))
+    }
+
+    /// ### view_bounds
+    ///
+    /// Get the visible bounds for the x and y axis, after panning and zooming
+    fn view_bounds(&self) -> ((f64, f64), (f64, f64)) {
+        (
+            self.states
+                .view_bounds(self.bounds(CANVAS_X_BOUNDS), self.states.offset.0),
+            self.states
+                .view_bounds(self.bounds(CANVAS_Y_BOUNDS), self.states.offset.1),
+        )
+    }
+
     /// Draw a shape into the canvas `Context`
     fn draw_shape(ctx: &mut Context, shape: &Shape) {
         match shape {
@@ -165,25 +389,15 @@ impl MockComponent for Canvas {
                 .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
                 .unwrap_borders();
             let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
-            let focus = self
-                .props
-                .get_or(Attribute::Focus, AttrValue::Flag(false))
-                .unwrap_flag();
-            let mut block = crate::utils::get_block(borders, title, focus, None);
-            block = block.style(Style::default().bg(background).fg(foreground));
+            let style = Style::default().bg(background).fg(foreground);
+            let block = FocusStyles::new(&self.props, style)
+                .block(borders, title)
+                .title_style(get_title_style(&self.props))
+                .style(style);
             // Get properties
-            let x_bounds: [f64; 2] = self
-                .props
-                .get(Attribute::Custom(CANVAS_X_BOUNDS))
-                .map(|x| x.unwrap_payload().unwrap_tup2())
-                .map(|(a, b)| [a.unwrap_f64(), b.unwrap_f64()])
-                .unwrap_or([0.0, 0.0]);
-            let y_bounds: [f64; 2] = self
-                .props
-                .get(Attribute::Custom(CANVAS_X_BOUNDS))
-                .map(|x| x.unwrap_payload().unwrap_tup2())
-                .map(|(a, b)| [a.unwrap_f64(), b.unwrap_f64()])
-                .unwrap_or([0.0, 0.0]);
+            let ((x_floor, x_ceil), (y_floor, y_ceil)) = self.view_bounds();
+            let x_bounds: [f64; 2] = [x_floor, x_ceil];
+            let y_bounds: [f64; 2] = [y_floor, y_ceil];
             // Get shapes
             let shapes: Vec<Shape> = self
                 .props
@@ -208,6 +422,8 @@ impl MockComponent for Canvas {
             // Render
             render.render_widget(canvas, area);
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -222,7 +438,24 @@ impl MockComponent for Canvas {
         State::None
     }
 
-    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let ((x_floor, x_ceil), (y_floor, y_ceil)) = self.view_bounds();
+        let (dx, dy) = ((x_ceil - x_floor) * PAN_STEP, (y_ceil - y_floor) * PAN_STEP);
+        match cmd {
+            Cmd::Move(Direction::Left) => self.states.pan(-dx, 0.0),
+            Cmd::Move(Direction::Right) => self.states.pan(dx, 0.0),
+            Cmd::Move(Direction::Up) => self.states.pan(0.0, dy),
+            Cmd::Move(Direction::Down) => self.states.pan(0.0, -dy),
+            Cmd::Scroll(Direction::Up) | Cmd::Custom(CANVAS_CMD_ZOOM_IN) => self.states.zoom_in(),
+            Cmd::Scroll(Direction::Down) | Cmd::Custom(CANVAS_CMD_ZOOM_OUT) => {
+                self.states.zoom_out()
+            }
+            Cmd::GoTo(Position::Begin) | Cmd::Custom(CANVAS_CMD_RESET_VIEW) => self.states.reset(),
+            _ => {}
+        }
         CmdResult::None
     }
 }
@@ -233,7 +466,7 @@ mod test {
     use super::*;
 
     use pretty_assertions::assert_eq;
-    use tuirealm::ratatui::widgets::canvas::{Line, Map, MapResolution, Rectangle};
+    use tuirealm::ratatui::widgets::canvas::MapResolution;
 
     #[test]
     fn test_component_canvas_with_shapes() {
@@ -280,4 +513,77 @@ mod test {
             ]);
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_component_canvas_shapes_builder() {
+        let shapes = CanvasShapes::default()
+            .map(Map {
+                resolution: MapResolution::Low,
+                color: Color::White,
+            })
+            .layer()
+            .line((0.0, 0.0), (10.0, 10.0), Color::Red)
+            .rectangle(0.0, 0.0, 5.0, 5.0, Color::Blue)
+            .polyline(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)], Color::Green)
+            .labeled_point(3.0, 4.0, "here", Color::Yellow);
+        let built = shapes.clone().build();
+        assert_eq!(built.len(), 8);
+        assert!(matches!(built[1], Shape::Layer));
+        assert!(matches!(built[4], Shape::Line(Line { x1, y2, .. }) if x1 == 0.0 && y2 == 1.0));
+        assert!(
+            matches!(&built[7], Shape::Label((x, y, text, _)) if *x == 3.0 && *y == 4.0 && text == "here")
+        );
+        // Circle is made of lines, which start and end at the same point
+        let circle = CanvasShapes::default()
+            .circle((0.0, 0.0), 2.0, Color::Red)
+            .build();
+        assert_eq!(circle.len(), CIRCLE_SEGMENTS);
+        assert!(matches!(circle[0], Shape::Line(Line { x1, y1, .. }) if x1 == 2.0 && y1 == 0.0));
+        // Into attr value
+        let mut component = Canvas::default().shapes(shapes);
+        assert_eq!(
+            component
+                .query(Attribute::Shape)
+                .map(|x| x.unwrap_payload().unwrap_vec().len()),
+            Some(8)
+        );
+        component.attr(Attribute::Shape, CanvasShapes::default().layer().into());
+        assert_eq!(
+            component
+                .query(Attribute::Shape)
+                .map(|x| x.unwrap_payload().unwrap_vec().len()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_component_canvas_pan_zoom() {
+        let mut component = Canvas::default()
+            .x_bounds((-100.0, 100.0))
+            .y_bounds((0.0, 50.0));
+        assert_eq!(component.view_bounds(), ((-100.0, 100.0), (0.0, 50.0)));
+        // Pan
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Move(Direction::Up));
+        assert_eq!(component.view_bounds(), ((-80.0, 120.0), (5.0, 55.0)));
+        component.perform(Cmd::Move(Direction::Left));
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.view_bounds(), ((-100.0, 100.0), (0.0, 50.0)));
+        // Zoom
+        component.perform(Cmd::Custom(CANVAS_CMD_ZOOM_IN));
+        assert_eq!(component.view_bounds(), ((-80.0, 80.0), (5.0, 45.0)));
+        component.perform(Cmd::Scroll(Direction::Down));
+        assert_eq!(component.states.zoom, 1.0);
+        component.perform(Cmd::Scroll(Direction::Up));
+        component.perform(Cmd::Move(Direction::Right));
+        // Reset
+        component.perform(Cmd::Custom(CANVAS_CMD_RESET_VIEW));
+        assert_eq!(component.states.offset, (0.0, 0.0));
+        assert_eq!(component.states.zoom, 1.0);
+        // Zoom limits
+        (0..100).for_each(|_| {
+            component.perform(Cmd::Custom(CANVAS_CMD_ZOOM_OUT));
+        });
+        assert_eq!(component.states.zoom, MIN_ZOOM);
+    }
 }
@@ -2,6 +2,9 @@
 //!
 //! A component to plot one or more dataset in a cartesian coordinate system
 
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, Dataset, PropPayload, PropValue, Props, Style,
@@ -9,24 +12,47 @@ use tuirealm::props::{
 use tuirealm::ratatui::text::Line;
 use tuirealm::ratatui::{
     layout::Rect,
+    symbols::Marker,
     text::Span,
-    widgets::{Axis, Chart as TuiChart, Dataset as TuiDataset},
+    widgets::{
+        Axis, Block, BorderType, Chart as TuiChart, Clear, Dataset as TuiDataset, GraphType,
+        Paragraph,
+    },
 };
 use tuirealm::{Frame, MockComponent, State};
 
+use super::states::RenderCache;
+use crate::theme::Theme;
+use crate::utils::{get_title_style, render_tooltip};
+
 // -- Props
 use super::props::{
-    CHART_X_BOUNDS, CHART_X_LABELS, CHART_X_STYLE, CHART_X_TITLE, CHART_Y_BOUNDS, CHART_Y_LABELS,
-    CHART_Y_STYLE, CHART_Y_TITLE,
+    CHART_AUTO_BOUNDS_NICE, CHART_AUTO_BOUNDS_PADDING, CHART_CROSSHAIR, CHART_CROSSHAIR_STYLE,
+    CHART_DECIMATION, CHART_X_AUTO_BOUNDS, CHART_X_BOUNDS, CHART_X_LABELS, CHART_X_LOG_SCALE,
+    CHART_X_STYLE, CHART_X_TIME_FORMAT, CHART_X_TITLE, CHART_Y_AUTO_BOUNDS, CHART_Y_BOUNDS,
+    CHART_Y_LABELS, CHART_Y_LOG_SCALE, CHART_Y_STYLE, CHART_Y_TITLE, TOOLTIP,
 };
 
+/// Amount of intervals the axis is divided into when rounding auto bounds to nice numbers
+const AUTO_BOUNDS_TICKS: f64 = 5.0;
+
+/// Minimum amount of columns between two time labels on the x axis
+const TIME_LABELS_GAP: usize = 2;
+
+/// Lowest and highest values on the x and y axis
+type Bounds = ((f64, f64), (f64, f64));
+
 /// ### ChartStates
 ///
 /// chart states
 #[derive(Default)]
 pub struct ChartStates {
     pub cursor: usize,
+    pub crosshair: usize,
+    /// Datasets in props, as displayed. Shared datasets aren't copied here
     pub data: Vec<Dataset>,
+    /// Points per dataset displayed from the cursor in the last render; `None` before the first one
+    pub window: Option<usize>,
 }
 
 impl ChartStates {
@@ -65,6 +91,38 @@ impl ChartStates {
             self.cursor = 0;
         }
     }
+
+    /// ### move_crosshair_left
+    ///
+    /// Move crosshair to the previous point
+    pub fn move_crosshair_left(&mut self) {
+        if self.crosshair > 0 {
+            self.crosshair -= 1;
+        }
+    }
+
+    /// ### move_crosshair_right
+    ///
+    /// Move crosshair to the next point
+    pub fn move_crosshair_right(&mut self, points: usize) {
+        if points > 0 && self.crosshair + 1 < points {
+            self.crosshair += 1;
+        }
+    }
+
+    /// ### reset_crosshair
+    ///
+    /// Move crosshair to the first point
+    pub fn reset_crosshair(&mut self) {
+        self.crosshair = 0;
+    }
+
+    /// ### crosshair_at_end
+    ///
+    /// Move crosshair to the last point
+    pub fn crosshair_at_end(&mut self, points: usize) {
+        self.crosshair = points.saturating_sub(1);
+    }
 }
 
 // -- component
@@ -86,6 +144,15 @@ impl ChartStates {
 pub struct Chart {
     props: Props,
     pub states: ChartStates,
+    shared_data: Option<Arc<Vec<Dataset>>>,
+    /// Whether the datasets in props have been copied into `states.data` since they've last changed
+    data_copied: bool,
+    /// Datasets converted to the logarithmic axes scale; empty if no axis is logarithmic
+    scaled: RenderCache<Vec<Dataset>>,
+    /// Decimated points, along with the cursor and the amount of columns they've been decimated for
+    decimated: Option<((usize, usize), Vec<Dataset>)>,
+    /// Bounds of the displayed points, along with the cursor and the amount of points they've been computed for
+    bounds: Option<((usize, usize), Option<Bounds>)>,
 }
 
 impl Chart {
@@ -115,13 +182,30 @@ impl Chart {
         self
     }
 
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `chart` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("chart", self);
+    }
+
     pub fn inactive(mut self, s: Style) -> Self {
         self.props.set(Attribute::FocusStyle, AttrValue::Style(s));
         self
     }
 
     pub fn data(mut self, data: &[Dataset]) -> Self {
-        self.props.set(
+        self.attr(
             Attribute::Dataset,
             AttrValue::Payload(PropPayload::Vec(
                 data.iter().cloned().map(PropValue::Dataset).collect(),
@@ -130,6 +214,24 @@ impl Chart {
         self
     }
 
+    /// Set the datasets shared with the application, which aren't copied into the props.
+    /// See `set_shared_data`
+    pub fn shared_data(mut self, data: Arc<Vec<Dataset>>) -> Self {
+        self.set_shared_data(data);
+        self
+    }
+
+    /// ### set_shared_data
+    ///
+    /// Set the datasets shared with the application. Unlike `Attribute::Dataset`, the points aren't cloned when set
+    /// and on each frame: to update them, set a new `Arc`. They're replaced by `Attribute::Dataset` when set again
+    pub fn set_shared_data(&mut self, data: Arc<Vec<Dataset>>) {
+        self.shared_data = Some(data);
+        self.invalidate_data();
+        self.states.reset_cursor();
+        self.states.reset_crosshair();
+    }
+
     pub fn x_bounds(mut self, bounds: (f64, f64)) -> Self {
         self.props.set(
             Attribute::Custom(CHART_X_BOUNDS),
@@ -204,36 +306,219 @@ impl Chart {
         self
     }
 
+    /// Compute x axis bounds from the data currently displayed, instead of using `x_bounds`.
+    /// If no labels are set for the axis, they're generated from the computed bounds.
+    pub fn x_auto_bounds(mut self, auto: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CHART_X_AUTO_BOUNDS),
+            AttrValue::Flag(auto),
+        );
+        self
+    }
+
+    /// Compute y axis bounds from the data currently displayed, instead of using `y_bounds`.
+    /// If no labels are set for the axis, they're generated from the computed bounds.
+    pub fn y_auto_bounds(mut self, auto: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CHART_Y_AUTO_BOUNDS),
+            AttrValue::Flag(auto),
+        );
+        self
+    }
+
+    /// Padding to add to auto bounds on both sides, expressed as a fraction of the data range (e.g. `0.1` for 10%)
+    pub fn auto_bounds_padding(mut self, padding: f64) -> Self {
+        self.attr(
+            Attribute::Custom(CHART_AUTO_BOUNDS_PADDING),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(padding))),
+        );
+        self
+    }
+
+    /// If true, auto bounds are rounded to "nice" numbers (multiples of 1, 2 or 5 times a power of 10)
+    pub fn nice_bounds(mut self, nice: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CHART_AUTO_BOUNDS_NICE),
+            AttrValue::Flag(nice),
+        );
+        self
+    }
+
+    /// Treat x values as unix timestamps (seconds, UTC) and format x axis labels with a strftime-like format
+    /// (e.g. `%H:%M` or `%b %e`). The amount of labels depends on the available width.
+    /// Labels set with `x_labels` take precedence. See `utils::format_timestamp` for supported specifiers
+    pub fn x_time_format<S: Into<String>>(mut self, fmt: S) -> Self {
+        self.attr(
+            Attribute::Custom(CHART_X_TIME_FORMAT),
+            AttrValue::String(fmt.into()),
+        );
+        self
+    }
+
+    /// Use a logarithmic (base 10) scale for the x axis. Points with non-positive x values are not displayed.
+    /// If no labels are set for the axis, a label is generated for each power of 10
+    pub fn x_log_scale(mut self, log: bool) -> Self {
+        self.attr(Attribute::Custom(CHART_X_LOG_SCALE), AttrValue::Flag(log));
+        self
+    }
+
+    /// Use a logarithmic (base 10) scale for the y axis. Points with non-positive y values are not displayed.
+    /// If no labels are set for the axis, a label is generated for each power of 10
+    pub fn y_log_scale(mut self, log: bool) -> Self {
+        self.attr(Attribute::Custom(CHART_Y_LOG_SCALE), AttrValue::Flag(log));
+        self
+    }
+
+    /// If true, all the points from the cursor onwards are displayed, instead of one point per column.
+    /// Datasets are decimated with the largest-triangle-three-buckets algorithm to two points per column,
+    /// which preserves the shape of the data while keeping large datasets fast to render
+    pub fn decimation(mut self, decimation: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CHART_DECIMATION),
+            AttrValue::Flag(decimation),
+        );
+        self
+    }
+
+    /// Enable crosshair mode: Left/Right move a crosshair along the x axis, snapping to the displayed data points,
+    /// and the values of the points under the crosshair are displayed in an overlay. When the crosshair is at an edge
+    /// of the chart, moving further scrolls the chart
+    pub fn crosshair(mut self, crosshair: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CHART_CROSSHAIR),
+            AttrValue::Flag(crosshair),
+        );
+        self
+    }
+
+    pub fn crosshair_style(mut self, s: Style) -> Self {
+        self.attr(
+            Attribute::Custom(CHART_CROSSHAIR_STYLE),
+            AttrValue::Style(s),
+        );
+        self
+    }
+
+    /// ### crosshair_points
+    ///
+    /// Get, for each dataset, the displayed point closest to the crosshair, along with the dataset name.
+    /// Returns an empty vec if the crosshair is not enabled
+    pub fn crosshair_points(&self) -> Vec<(String, (f64, f64))> {
+        let x = match self.crosshair_x() {
+            Some(x) => x,
+            None => return Vec::new(),
+        };
+        let x_log = self.custom_flag(CHART_X_LOG_SCALE);
+        let y_log = self.custom_flag(CHART_Y_LOG_SCALE);
+        let window = self.displayed_window();
+        // Compare on scaled values, but return the original ones
+        self.datasets()
+            .iter()
+            .filter_map(|dataset| {
+                dataset
+                    .get_data()
+                    .iter()
+                    .filter(|(_, y)| Self::scale(*y, y_log).is_some())
+                    .filter_map(|point| Self::scale(point.0, x_log).map(|x| (x, point)))
+                    .skip(self.states.cursor)
+                    .take(window)
+                    .min_by(|(a, _), (b, _)| (a - x).abs().total_cmp(&(b - x).abs()))
+                    .map(|(_, point)| (dataset.name.clone(), *point))
+            })
+            .collect()
+    }
+
     fn is_disabled(&self) -> bool {
         self.props
             .get_or(Attribute::Disabled, AttrValue::Flag(false))
             .unwrap_flag()
     }
 
-    /// ### max_dataset_len
-    ///
-    /// Get the maximum len among the datasets
-    fn max_dataset_len(&self) -> usize {
+    fn is_crosshair(&self) -> bool {
         self.props
-            .get(Attribute::Dataset)
-            .map(|x| {
-                x.unwrap_payload()
-                    .unwrap_vec()
-                    .iter()
-                    .cloned()
-                    .map(|x| x.unwrap_dataset().get_data().len())
-                    .max()
-            })
-            .unwrap_or(None)
-            .unwrap_or(0)
+            .get_or(Attribute::Custom(CHART_CROSSHAIR), AttrValue::Flag(false))
+            .unwrap_flag()
     }
 
-    /// ### data
+    /// ### datasets
     ///
-    /// Get data to be displayed, starting from provided index at `start` with a max length of `len`
-    fn get_data(&mut self, start: usize, len: usize) -> Vec<TuiDataset> {
-        self.states.data = self
-            .props
+    /// Get the shared datasets if set, otherwise the ones in props
+    fn datasets(&self) -> Cow<'_, [Dataset]> {
+        if let Some(data) = &self.shared_data {
+            return Cow::Borrowed(data.as_slice());
+        }
+        match self.data_copied {
+            true => Cow::Borrowed(self.states.data.as_slice()),
+            false => Cow::Owned(Self::props_datasets(&self.props)),
+        }
+    }
+
+    /// ### scaled_datasets
+    ///
+    /// Get datasets with values converted to the axes scale.
+    /// Points which can't be represented on a logarithmic axis are removed
+    fn scaled_datasets(&self) -> Cow<'_, [Dataset]> {
+        let x_log = self.custom_flag(CHART_X_LOG_SCALE);
+        let y_log = self.custom_flag(CHART_Y_LOG_SCALE);
+        match (x_log || y_log, self.scaled.is_dirty()) {
+            (false, _) => self.datasets(),
+            (true, false) => Cow::Borrowed(self.scaled.value()),
+            (true, true) => Cow::Owned(Self::scale_datasets(&self.datasets(), x_log, y_log)),
+        }
+    }
+
+    /// ### cached_datasets
+    ///
+    /// Get the datasets converted to the axes scale, as cached by `refresh_datasets`, which must be called first
+    fn cached_datasets(&self) -> &[Dataset] {
+        if self.custom_flag(CHART_X_LOG_SCALE) || self.custom_flag(CHART_Y_LOG_SCALE) {
+            return self.scaled.value();
+        }
+        match &self.shared_data {
+            Some(data) => data.as_slice(),
+            None => self.states.data.as_slice(),
+        }
+    }
+
+    /// ### refresh_datasets
+    ///
+    /// Copy the datasets in props into `states.data` and convert them to the axes scale, if they've changed since
+    /// the last time
+    fn refresh_datasets(&mut self) {
+        let x_log = self.custom_flag(CHART_X_LOG_SCALE);
+        let y_log = self.custom_flag(CHART_Y_LOG_SCALE);
+        if !self.data_copied {
+            self.states.data = match self.shared_data.is_some() {
+                true => Vec::new(),
+                false => Self::props_datasets(&self.props),
+            };
+            self.data_copied = true;
+        }
+        let datasets = match &self.shared_data {
+            Some(data) => data.as_slice(),
+            None => self.states.data.as_slice(),
+        };
+        self.scaled.refresh(|| match x_log || y_log {
+            true => Self::scale_datasets(datasets, x_log, y_log),
+            false => Vec::new(),
+        });
+    }
+
+    /// ### invalidate_data
+    ///
+    /// Discard the cached datasets, decimated points and bounds, after data or props have changed
+    fn invalidate_data(&mut self) {
+        self.data_copied = false;
+        self.scaled.invalidate();
+        self.decimated = None;
+        self.bounds = None;
+    }
+
+    /// ### props_datasets
+    ///
+    /// Get the datasets in props
+    fn props_datasets(props: &Props) -> Vec<Dataset> {
+        props
             .get(Attribute::Dataset)
             .map(|x| {
                 x.unwrap_payload()
@@ -242,9 +527,445 @@ impl Chart {
                     .map(|x| x.unwrap_dataset())
                     .collect()
             })
-            .unwrap_or_default();
-        self.states
-            .data
+            .unwrap_or_default()
+    }
+
+    /// ### scale_datasets
+    ///
+    /// Convert the values of datasets to the axes scale, removing the points which can't be represented
+    fn scale_datasets(datasets: &[Dataset], x_log: bool, y_log: bool) -> Vec<Dataset> {
+        datasets
+            .iter()
+            .map(|dataset| {
+                let points: Vec<(f64, f64)> = dataset
+                    .get_data()
+                    .iter()
+                    .filter_map(|(x, y)| Some((Self::scale(*x, x_log)?, Self::scale(*y, y_log)?)))
+                    .collect();
+                Self::with_points(dataset, points)
+            })
+            .collect()
+    }
+
+    /// ### with_points
+    ///
+    /// Make a dataset with the name and the style of `dataset`, but with `points`
+    fn with_points(dataset: &Dataset, points: Vec<(f64, f64)>) -> Dataset {
+        Dataset::default()
+            .name(dataset.name.clone())
+            .marker(dataset.marker)
+            .graph_type(dataset.graph_type)
+            .style(dataset.style)
+            .data(points)
+    }
+
+    /// ### scale
+    ///
+    /// Convert value to the axis scale. Returns `None` if value can't be represented on a logarithmic scale
+    fn scale(value: f64, log: bool) -> Option<f64> {
+        match log {
+            false => Some(value),
+            true if value > 0.0 => Some(value.log10()),
+            true => None,
+        }
+    }
+
+    /// ### displayed_window
+    ///
+    /// Get the amount of points per dataset displayed from the cursor, as in the last render.
+    /// Before the first render, all the points from the cursor are considered displayed
+    fn displayed_window(&self) -> usize {
+        self.states.window.unwrap_or(usize::MAX)
+    }
+
+    /// ### crosshair_stops
+    ///
+    /// Get the sorted x values of the displayed points in datasets, which are the positions the crosshair can snap to
+    fn crosshair_stops(&self) -> Vec<f64> {
+        // With decimation, the displayed points are the decimated ones
+        let (datasets, start, window) = match &self.decimated {
+            Some(((cursor, _), data)) if *cursor == self.states.cursor => {
+                (Cow::Borrowed(data.as_slice()), 0, usize::MAX)
+            }
+            _ => (
+                self.scaled_datasets(),
+                self.states.cursor,
+                self.displayed_window(),
+            ),
+        };
+        let mut stops: Vec<f64> = datasets
+            .iter()
+            .flat_map(|x| {
+                let points = x.get_data();
+                points[Self::data_window(points.len(), start, window)]
+                    .iter()
+                    .map(|(x, _)| *x)
+            })
+            .collect();
+        stops.sort_by(f64::total_cmp);
+        stops.dedup();
+        stops
+    }
+
+    /// ### crosshair_x
+    ///
+    /// Get the x value the crosshair is at. Returns `None` if crosshair is disabled or there's no data
+    fn crosshair_x(&self) -> Option<f64> {
+        if !self.is_crosshair() {
+            return None;
+        }
+        let stops = self.crosshair_stops();
+        stops
+            .get(self.states.crosshair.min(stops.len().saturating_sub(1)))
+            .copied()
+    }
+
+    fn custom_flag(&self, attr: &'static str) -> bool {
+        self.props
+            .get_or(Attribute::Custom(attr), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    /// ### axis_bounds
+    ///
+    /// Get bounds for an axis. If auto bounds are enabled for the axis, they're calculated from `data_bounds`,
+    /// otherwise (or if there is no data) the bounds set in properties are used.
+    /// On a logarithmic axis, bounds are converted to the log scale and rounded to the enclosing powers of 10
+    fn axis_bounds(
+        &self,
+        bounds_attr: &'static str,
+        auto_attr: &'static str,
+        log_attr: &'static str,
+        data_bounds: Option<(f64, f64)>,
+    ) -> Option<(f64, f64)> {
+        let bounds = self.linear_axis_bounds(bounds_attr, auto_attr, log_attr, data_bounds);
+        match self.custom_flag(log_attr) {
+            true => bounds.map(|(floor, ceil)| (floor.floor(), ceil.ceil())),
+            false => bounds,
+        }
+    }
+
+    fn linear_axis_bounds(
+        &self,
+        bounds_attr: &'static str,
+        auto_attr: &'static str,
+        log_attr: &'static str,
+        data_bounds: Option<(f64, f64)>,
+    ) -> Option<(f64, f64)> {
+        let auto_bounds = match self.custom_flag(auto_attr) {
+            true => data_bounds,
+            false => None,
+        };
+        match auto_bounds {
+            Some(bounds) => {
+                let padding = self
+                    .props
+                    .get(Attribute::Custom(CHART_AUTO_BOUNDS_PADDING))
+                    .map(|x| x.unwrap_payload().unwrap_one().unwrap_f64())
+                    .unwrap_or(0.0);
+                let nice = self
+                    .props
+                    .get_or(
+                        Attribute::Custom(CHART_AUTO_BOUNDS_NICE),
+                        AttrValue::Flag(false),
+                    )
+                    .unwrap_flag();
+                Some(Self::auto_bounds(bounds, padding, nice))
+            }
+            None => {
+                let (floor, ceil) = self
+                    .props
+                    .get(Attribute::Custom(bounds_attr))
+                    .map(|x| x.unwrap_payload().unwrap_tup2())
+                    .map(|(floor, ceil)| (floor.unwrap_f64(), ceil.unwrap_f64()))?;
+                match self.custom_flag(log_attr) {
+                    false => Some((floor, ceil)),
+                    true => {
+                        // A non-positive floor can't be displayed; start one power of 10 below ceil
+                        let ceil = Self::scale(ceil, true)?;
+                        Some((Self::scale(floor, true).unwrap_or(ceil - 1.0), ceil))
+                    }
+                }
+            }
+        }
+    }
+
+    /// ### data_bounds
+    ///
+    /// Get the lowest and the highest values on the x and y axis among the points displayed,
+    /// starting from `start` with a max length of `len`.
+    /// Returns `None` if there are no points to display
+    fn data_bounds(&self, start: usize, len: usize) -> Option<Bounds> {
+        let datasets = self.scaled_datasets();
+        let mut bounds: Option<Bounds> = None;
+        for dataset in datasets.iter() {
+            let points = dataset.get_data();
+            for (x, y) in points[Self::data_window(points.len(), start, len)].iter() {
+                bounds = Some(match bounds {
+                    None => ((*x, *x), (*y, *y)),
+                    Some(((x_min, x_max), (y_min, y_max))) => (
+                        (x_min.min(*x), x_max.max(*x)),
+                        (y_min.min(*y), y_max.max(*y)),
+                    ),
+                });
+            }
+        }
+        bounds
+    }
+
+    /// ### displayed_bounds
+    ///
+    /// Get the bounds of the points displayed from `start` with a max length of `len`, computing them only if the
+    /// cursor, the window or data have changed since the last time
+    fn displayed_bounds(&mut self, start: usize, len: usize) -> Option<Bounds> {
+        match self.bounds {
+            Some((key, bounds)) if key == (start, len) => bounds,
+            _ => {
+                let bounds = self.data_bounds(start, len);
+                self.bounds = Some(((start, len), bounds));
+                bounds
+            }
+        }
+    }
+
+    /// ### auto_bounds
+    ///
+    /// Calculate axis bounds from the lowest and the highest values in data, applying padding and
+    /// optionally rounding to nice numbers
+    fn auto_bounds(bounds: (f64, f64), padding: f64, nice: bool) -> (f64, f64) {
+        let (mut floor, mut ceil) = bounds;
+        // Flat data would make an empty axis; widen it
+        if (ceil - floor).abs() < f64::EPSILON {
+            floor -= 1.0;
+            ceil += 1.0;
+        }
+        let padding = (ceil - floor) * padding;
+        floor -= padding;
+        ceil += padding;
+        if nice {
+            let step = Self::nice_number((ceil - floor) / AUTO_BOUNDS_TICKS);
+            floor = (floor / step).floor() * step;
+            ceil = (ceil / step).ceil() * step;
+        }
+        (floor, ceil)
+    }
+
+    /// ### nice_number
+    ///
+    /// Round value to the closest "nice" number, which is 1, 2, 5 or 10 times a power of 10
+    fn nice_number(value: f64) -> f64 {
+        let exponent = value.log10().floor();
+        let fraction = value / 10_f64.powf(exponent);
+        let nice = if fraction < 1.5 {
+            1.0
+        } else if fraction < 3.0 {
+            2.0
+        } else if fraction < 7.0 {
+            5.0
+        } else {
+            10.0
+        };
+        nice * 10_f64.powf(exponent)
+    }
+
+    /// ### auto_labels
+    ///
+    /// Make labels for an axis with auto bounds: lower bound, middle and upper bound
+    fn auto_labels(bounds: (f64, f64)) -> Vec<String> {
+        let (floor, ceil) = bounds;
+        [floor, (floor + ceil) / 2.0, ceil]
+            .into_iter()
+            .map(Self::fmt_value)
+            .collect()
+    }
+
+    /// ### log_labels
+    ///
+    /// Make a label for each power of 10 between the bounds of a logarithmic axis
+    fn log_labels(bounds: (f64, f64)) -> Vec<String> {
+        let (floor, ceil) = bounds;
+        (floor.ceil() as i32..=ceil.floor() as i32)
+            .map(|exp| match exp {
+                0..=6 => 10_u64.pow(exp as u32).to_string(),
+                -4..=-1 => 10_f64.powi(exp).to_string(),
+                _ => format!("1e{exp}"),
+            })
+            .collect()
+    }
+
+    /// ### time_labels
+    ///
+    /// Make evenly spaced time labels for an axis with `bounds`, fitting as many labels as possible in `width`
+    fn time_labels(bounds: (f64, f64), fmt: &str, width: usize) -> Vec<String> {
+        let (floor, ceil) = bounds;
+        let label_width = crate::utils::format_timestamp(floor, fmt)
+            .chars()
+            .count()
+            .max(crate::utils::format_timestamp(ceil, fmt).chars().count());
+        let amount = (width / (label_width + TIME_LABELS_GAP).max(1)).max(2);
+        (0..amount)
+            .map(|i| floor + (ceil - floor) * i as f64 / (amount - 1) as f64)
+            .map(|x| crate::utils::format_timestamp(x, fmt))
+            .collect()
+    }
+
+    /// ### fmt_value
+    ///
+    /// Format a value for labels and readouts, omitting decimals for integral values
+    fn fmt_value(x: f64) -> String {
+        match x.fract() == 0.0 {
+            true => format!("{x:.0}"),
+            false => format!("{x:.2}"),
+        }
+    }
+
+    /// ### crosshair_readout
+    ///
+    /// Make the lines to display in the crosshair readout overlay
+    fn crosshair_readout(&self) -> Vec<String> {
+        self.crosshair_points()
+            .into_iter()
+            .map(|(name, (x, y))| {
+                let point = format!("({}, {})", Self::fmt_value(x), Self::fmt_value(y));
+                match name.is_empty() {
+                    true => point,
+                    false => format!("{name}: {point}"),
+                }
+            })
+            .collect()
+    }
+
+    /// ### move_crosshair_left
+    ///
+    /// Move the crosshair to the previous displayed point or, if it's at the first one, scroll the chart to the left
+    fn move_crosshair_left(&mut self) {
+        // The displayed points may be less than in the last move
+        let stops = self.crosshair_stops().len();
+        self.states.crosshair = self.states.crosshair.min(stops.saturating_sub(1));
+        if self.states.crosshair > 0 {
+            self.states.move_crosshair_left();
+        } else {
+            self.states.move_cursor_left();
+        }
+    }
+
+    /// ### move_crosshair_right
+    ///
+    /// Move the crosshair to the next displayed point or, if it's at the last one, scroll the chart to the right,
+    /// keeping the crosshair on the last displayed point
+    fn move_crosshair_right(&mut self) {
+        let stops = self.crosshair_stops().len();
+        if self.states.crosshair + 1 < stops {
+            self.states.move_crosshair_right(stops);
+        } else if self.states.cursor.saturating_add(self.displayed_window())
+            < self.max_dataset_len()
+        {
+            self.states.move_cursor_right(self.max_dataset_len());
+            self.states.crosshair_at_end(self.crosshair_stops().len());
+        }
+    }
+
+    /// ### window_len
+    ///
+    /// Get the maximum amount of points per dataset to display in an area with `width`
+    fn window_len(&self, width: u16) -> usize {
+        match self.custom_flag(CHART_DECIMATION) {
+            true => usize::MAX,
+            false => width as usize,
+        }
+    }
+
+    /// ### lttb
+    ///
+    /// Decimate points to `threshold` points using the largest-triangle-three-buckets algorithm.
+    /// Points are returned as they are if they're less than threshold
+    fn lttb(points: &[(f64, f64)], threshold: usize) -> Vec<(f64, f64)> {
+        if threshold >= points.len() || threshold < 3 {
+            return points.to_vec();
+        }
+        let mut sampled: Vec<(f64, f64)> = Vec::with_capacity(threshold);
+        // First and last points are always kept; the others are divided into buckets
+        let every = (points.len() - 2) as f64 / (threshold - 2) as f64;
+        let mut selected = 0;
+        sampled.push(points[0]);
+        for bucket in 0..threshold - 2 {
+            // Average point of the next bucket
+            let next_start = ((bucket + 1) as f64 * every) as usize + 1;
+            let next_end = (((bucket + 2) as f64 * every) as usize + 1).min(points.len());
+            let next = &points[next_start..next_end.max(next_start + 1)];
+            let (avg_x, avg_y) = next
+                .iter()
+                .fold((0.0, 0.0), |(x, y), point| (x + point.0, y + point.1));
+            let (avg_x, avg_y) = (avg_x / next.len() as f64, avg_y / next.len() as f64);
+            // Select the point of this bucket making the largest triangle with the previously selected one
+            let start = (bucket as f64 * every) as usize + 1;
+            let end = ((bucket + 1) as f64 * every) as usize + 1;
+            let (a_x, a_y) = points[selected];
+            selected = (start..end)
+                .max_by(|a, b| {
+                    let area = |i: &usize| {
+                        let (x, y) = points[*i];
+                        ((a_x - avg_x) * (y - a_y) - (a_x - x) * (avg_y - a_y)).abs()
+                    };
+                    area(a).total_cmp(&area(b))
+                })
+                .unwrap_or(start);
+            sampled.push(points[selected]);
+        }
+        sampled.push(points[points.len() - 1]);
+        sampled
+    }
+
+    /// ### max_dataset_len
+    ///
+    /// Get the maximum len among the datasets
+    fn max_dataset_len(&self) -> usize {
+        self.datasets()
+            .iter()
+            .map(|x| x.get_data().len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// ### decimate
+    ///
+    /// If decimation is enabled, decimate all points from `start` to `len * 2` points,
+    /// unless they've already been decimated for the same `start` and `len`
+    fn decimate(&mut self, start: usize, len: usize) {
+        if !self.custom_flag(CHART_DECIMATION) {
+            self.decimated = None;
+            return;
+        }
+        if matches!(&self.decimated, Some((key, _)) if *key == (start, len)) {
+            return;
+        }
+        let data = self
+            .cached_datasets()
+            .iter()
+            .map(|dataset| {
+                let points = dataset.get_data();
+                let points = Self::lttb(
+                    &points[Self::data_window(points.len(), start, usize::MAX)],
+                    len.saturating_mul(2),
+                );
+                Self::with_points(dataset, points)
+            })
+            .collect();
+        self.decimated = Some(((start, len), data));
+    }
+
+    /// ### data
+    ///
+    /// Get data to be displayed, starting from provided index at `start` with a max length of `len`,
+    /// or the decimated points if decimation is enabled
+    fn get_data(&self, start: usize, len: usize) -> Vec<TuiDataset> {
+        if let Some((_, data)) = &self.decimated {
+            return data
+                .iter()
+                .map(|x| Self::get_tui_dataset(x, 0, usize::MAX))
+                .collect();
+        }
+        self.cached_datasets()
             .iter()
             .map(|x| Self::get_tui_dataset(x, start, len))
             .collect()
@@ -257,20 +978,22 @@ impl<'a> Chart {
     /// Create tui_dataset from dataset
     /// Only elements from `start` to `len` are preserved from dataset
     fn get_tui_dataset(dataset: &'a Dataset, start: usize, len: usize) -> TuiDataset<'a> {
-        // Recalc len
         let points = dataset.get_data();
-        let end: usize = match points.len() > start {
-            true => std::cmp::min(len, points.len() - start),
-            false => 0,
-        };
-
         // Prepare data storage
         TuiDataset::default()
             .name(dataset.name.clone())
             .marker(dataset.marker)
             .graph_type(dataset.graph_type)
             .style(dataset.style)
-            .data(&points[start..end])
+            .data(&points[Self::data_window(points.len(), start, len)])
+    }
+
+    /// ### data_window
+    ///
+    /// Get the range of points to display, starting from `start` with a max length of `len`
+    fn data_window(points: usize, start: usize, len: usize) -> std::ops::Range<usize> {
+        let start = std::cmp::min(start, points);
+        start..std::cmp::min(start.saturating_add(len), points)
     }
 }
 
@@ -302,15 +1025,26 @@ impl MockComponent for Chart {
                 true => true,
                 false => focus,
             };
-            let div = crate::utils::get_block(borders, title, active, inactive_style);
+            let div = crate::utils::get_block(borders, title, active, inactive_style)
+                .title_style(get_title_style(&self.props));
+            let inner = div.inner(area);
+            self.states.window = Some(self.window_len(area.width));
+            // Data is copied and decimated again only if it has changed or the chart has been scrolled
+            self.refresh_datasets();
+            self.decimate(self.states.cursor, area.width as usize);
+            // Get bounds of displayed data (used by auto bounds)
+            let data_bounds =
+                self.displayed_bounds(self.states.cursor, self.window_len(area.width));
             // Create widget
             // -- x axis
             let mut x_axis: Axis = Axis::default();
-            if let Some((PropValue::F64(floor), PropValue::F64(ceil))) = self
-                .props
-                .get(Attribute::Custom(CHART_X_BOUNDS))
-                .map(|x| x.unwrap_payload().unwrap_tup2())
-            {
+            let x_bounds = self.axis_bounds(
+                CHART_X_BOUNDS,
+                CHART_X_AUTO_BOUNDS,
+                CHART_X_LOG_SCALE,
+                data_bounds.map(|(x, _)| x),
+            );
+            if let Some((floor, ceil)) = x_bounds {
                 let why_using_vecs_when_you_can_use_useless_arrays: [f64; 2] = [floor, ceil];
                 x_axis = x_axis.bounds(why_using_vecs_when_you_can_use_useless_arrays);
             }
@@ -320,6 +1054,21 @@ impl MockComponent for Chart {
                 .map(|x| x.unwrap_payload())
             {
                 x_axis = x_axis.labels(labels.iter().cloned().map(|x| Line::from(x.unwrap_str())));
+            } else if let (Some(fmt), Some(bounds)) = (
+                self.props
+                    .get(Attribute::Custom(CHART_X_TIME_FORMAT))
+                    .map(|x| x.unwrap_string()),
+                x_bounds,
+            ) {
+                x_axis = x_axis.labels(
+                    Self::time_labels(bounds, &fmt, inner.width as usize)
+                        .into_iter()
+                        .map(Line::from),
+                );
+            } else if let (true, Some(bounds)) = (self.custom_flag(CHART_X_LOG_SCALE), x_bounds) {
+                x_axis = x_axis.labels(Self::log_labels(bounds).into_iter().map(Line::from));
+            } else if let (true, Some(bounds)) = (self.custom_flag(CHART_X_AUTO_BOUNDS), x_bounds) {
+                x_axis = x_axis.labels(Self::auto_labels(bounds).into_iter().map(Line::from));
             }
             if let Some(s) = self
                 .props
@@ -340,11 +1089,13 @@ impl MockComponent for Chart {
             }
             // -- y axis
             let mut y_axis: Axis = Axis::default();
-            if let Some((PropValue::F64(floor), PropValue::F64(ceil))) = self
-                .props
-                .get(Attribute::Custom(CHART_Y_BOUNDS))
-                .map(|x| x.unwrap_payload().unwrap_tup2())
-            {
+            let y_bounds = self.axis_bounds(
+                CHART_Y_BOUNDS,
+                CHART_Y_AUTO_BOUNDS,
+                CHART_Y_LOG_SCALE,
+                data_bounds.map(|(_, y)| y),
+            );
+            if let Some((floor, ceil)) = y_bounds {
                 let why_using_vecs_when_you_can_use_useless_arrays: [f64; 2] = [floor, ceil];
                 y_axis = y_axis.bounds(why_using_vecs_when_you_can_use_useless_arrays);
             }
@@ -354,6 +1105,10 @@ impl MockComponent for Chart {
                 .map(|x| x.unwrap_payload())
             {
                 y_axis = y_axis.labels(labels.iter().cloned().map(|x| Line::from(x.unwrap_str())));
+            } else if let (true, Some(bounds)) = (self.custom_flag(CHART_Y_LOG_SCALE), y_bounds) {
+                y_axis = y_axis.labels(Self::log_labels(bounds).into_iter().map(Line::from));
+            } else if let (true, Some(bounds)) = (self.custom_flag(CHART_Y_AUTO_BOUNDS), y_bounds) {
+                y_axis = y_axis.labels(Self::auto_labels(bounds).into_iter().map(Line::from));
             }
             if let Some(s) = self
                 .props
@@ -372,13 +1127,55 @@ impl MockComponent for Chart {
                     Style::default().fg(foreground).bg(background),
                 ));
             }
+            // -- crosshair
+            let crosshair_style = self
+                .props
+                .get(Attribute::Custom(CHART_CROSSHAIR_STYLE))
+                .map(|x| x.unwrap_style())
+                .unwrap_or_else(|| Style::default().fg(foreground).bg(background));
+            let crosshair_line: Vec<(f64, f64)> = match (self.crosshair_x(), y_bounds) {
+                (Some(x), Some((floor, ceil))) => vec![(x, floor), (x, ceil)],
+                _ => Vec::new(),
+            };
+            let readout = self.crosshair_readout();
             // Get data
-            let data: Vec<TuiDataset> = self.get_data(self.states.cursor, area.width as usize);
+            let mut data: Vec<TuiDataset> = self.get_data(self.states.cursor, area.width as usize);
+            if !crosshair_line.is_empty() {
+                data.push(
+                    TuiDataset::default()
+                        .marker(Marker::Braille)
+                        .graph_type(GraphType::Line)
+                        .style(crosshair_style)
+                        .data(&crosshair_line),
+                );
+            }
             // Build widget
             let widget: TuiChart = TuiChart::new(data).block(div).x_axis(x_axis).y_axis(y_axis);
             // Render
             render.render_widget(widget, area);
+            // Render readout on the top-right corner
+            if !readout.is_empty() {
+                let width = readout
+                    .iter()
+                    .map(|x| x.chars().count() as u16 + 2)
+                    .max()
+                    .unwrap_or(0)
+                    .min(inner.width);
+                let height = (readout.len() as u16 + 2).min(inner.height);
+                let overlay = Rect::new(inner.right() - width, inner.y, width, height);
+                let lines: Vec<Line> = readout.into_iter().map(Line::from).collect();
+                render.render_widget(Clear, overlay);
+                render.render_widget(
+                    Paragraph::new(lines).style(crosshair_style).block(
+                        Block::bordered()
+                            .border_type(BorderType::Plain)
+                            .style(crosshair_style),
+                    ),
+                    overlay,
+                );
+            }
         }
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -386,12 +1183,38 @@ impl MockComponent for Chart {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        if attr == Attribute::Dataset {
+            self.shared_data = None;
+        }
         self.props.set(attr, value);
+        self.invalidate_data();
         self.states.reset_cursor();
+        self.states.reset_crosshair();
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
-        if !self.is_disabled() {
+        self.refresh_datasets();
+        if !self.is_disabled() && self.is_crosshair() {
+            match cmd {
+                Cmd::Move(Direction::Left) => {
+                    self.move_crosshair_left();
+                }
+                Cmd::Move(Direction::Right) => {
+                    self.move_crosshair_right();
+                }
+                Cmd::GoTo(Position::Begin) => {
+                    self.states.reset_cursor();
+                    self.states.reset_crosshair();
+                }
+                Cmd::GoTo(Position::End) => {
+                    // Scroll so that the last points are displayed
+                    let window = self.displayed_window();
+                    self.states.cursor = self.max_dataset_len().saturating_sub(window);
+                    self.states.crosshair_at_end(self.crosshair_stops().len());
+                }
+                _ => {}
+            }
+        } else if !self.is_disabled() {
             match cmd {
                 Cmd::Move(Direction::Left) => {
                     self.states.move_cursor_left();
@@ -422,7 +1245,6 @@ mod test {
     use super::*;
 
     use pretty_assertions::assert_eq;
-    use tuirealm::ratatui::{symbols::Marker, widgets::GraphType};
 
     #[test]
     fn test_components_chart_states() {
@@ -542,7 +1364,11 @@ mod test {
         // component funcs
         assert_eq!(component.max_dataset_len(), 12);
         assert_eq!(component.is_disabled(), false);
+        component.refresh_datasets();
         assert_eq!(component.get_data(2, 4).len(), 2);
+        // Data holds the datasets displayed
+        assert_eq!(component.states.data.len(), 2);
+        assert_eq!(component.states.data[0].get_data().len(), 12);
 
         let mut comp = Chart::default().data(&[Dataset::default()
             .name("Maximum")
@@ -550,6 +1376,7 @@ mod test {
             .marker(Marker::Dot)
             .style(Style::default().fg(Color::LightRed))
             .data(vec![(0.0, 7.0)])]);
+        comp.refresh_datasets();
         assert!(comp.get_data(0, 1).len() > 0);
 
         // Update and test empty data
@@ -562,4 +1389,283 @@ mod test {
         // Cursor is reset
         assert_eq!(component.states.cursor, 0);
     }
+
+    #[test]
+    fn test_components_chart_shared_data() {
+        let data = Arc::new(vec![
+            Dataset::default().data(vec![(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]),
+            Dataset::default().data(vec![(0.0, 4.0)]),
+        ]);
+        let mut component = Chart::default().shared_data(Arc::clone(&data));
+        assert_eq!(component.max_dataset_len(), 3);
+        assert_eq!(component.get_data(1, 2).len(), 2);
+        assert_eq!(component.data_bounds(0, 3), Some(((0.0, 2.0), (1.0, 4.0))));
+        // Shared datasets aren't copied into data
+        let _ = crate::testing::render(&mut component, 20, 10);
+        assert!(component.states.data.is_empty());
+        // Dataset replaces shared data
+        component.attr(
+            Attribute::Dataset,
+            AttrValue::Payload(PropPayload::Vec(vec![])),
+        );
+        assert_eq!(component.max_dataset_len(), 0);
+        assert_eq!(Arc::strong_count(&data), 1);
+    }
+
+    #[test]
+    fn test_components_chart_auto_bounds() {
+        let component: Chart = Chart::default()
+            .x_auto_bounds(true)
+            .y_auto_bounds(true)
+            .y_bounds((0.0, 10.0))
+            .data(&[
+                Dataset::default().data(vec![(0.0, -1.0), (1.0, 3.0), (2.0, 12.0)]),
+                Dataset::default().data(vec![(0.0, 4.0), (1.0, 18.0)]),
+            ]);
+        // Data bounds
+        assert_eq!(
+            component.data_bounds(0, 8),
+            Some(((0.0, 2.0), (-1.0, 18.0)))
+        );
+        assert_eq!(component.data_bounds(1, 1), Some(((1.0, 1.0), (3.0, 18.0))));
+        assert_eq!(component.data_bounds(5, 8), None);
+        // Auto bounds override bounds set in props
+        assert_eq!(
+            component.axis_bounds(
+                CHART_Y_BOUNDS,
+                CHART_Y_AUTO_BOUNDS,
+                CHART_Y_LOG_SCALE,
+                Some((-1.0, 18.0))
+            ),
+            Some((-1.0, 18.0))
+        );
+        // Without data, use props
+        assert_eq!(
+            component.axis_bounds(CHART_Y_BOUNDS, CHART_Y_AUTO_BOUNDS, CHART_Y_LOG_SCALE, None),
+            Some((0.0, 10.0))
+        );
+        // Padding and nice numbers
+        assert_eq!(Chart::auto_bounds((0.0, 10.0), 0.1, false), (-1.0, 11.0));
+        assert_eq!(Chart::auto_bounds((-1.0, 18.0), 0.0, true), (-5.0, 20.0));
+        assert_eq!(Chart::auto_bounds((3.0, 3.0), 0.0, false), (2.0, 4.0));
+        assert_eq!(Chart::nice_number(3.8), 5.0);
+        assert_eq!(Chart::nice_number(0.12), 0.1);
+        assert_eq!(Chart::nice_number(230.0), 200.0);
+        // Labels
+        assert_eq!(
+            Chart::auto_labels((-4.0, 21.0)),
+            vec!["-4".to_string(), "8.50".to_string(), "21".to_string()]
+        );
+        // Window
+        assert_eq!(Chart::data_window(12, 8, 100), 8..12);
+        assert_eq!(Chart::data_window(12, 20, 4), 12..12);
+    }
+
+    #[test]
+    fn test_components_chart_crosshair() {
+        let mut component: Chart = Chart::default().crosshair(true).data(&[
+            Dataset::default()
+                .name("temp")
+                .data(vec![(0.0, 3.0), (2.0, 5.5), (4.0, 1.0)]),
+            Dataset::default().data(vec![(1.0, 7.0), (2.0, 8.0)]),
+        ]);
+        assert_eq!(component.crosshair_stops(), vec![0.0, 1.0, 2.0, 4.0]);
+        assert_eq!(component.crosshair_x(), Some(0.0));
+        // Move crosshair; cursor is unchanged
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.crosshair, 2);
+        assert_eq!(component.states.cursor, 0);
+        assert_eq!(
+            component.crosshair_points(),
+            vec![
+                (String::from("temp"), (2.0, 5.5)),
+                (String::new(), (2.0, 8.0))
+            ]
+        );
+        assert_eq!(
+            component.crosshair_readout(),
+            vec![String::from("temp: (2, 5.50)"), String::from("(2, 8)")]
+        );
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.crosshair, 3);
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.crosshair, 3);
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(component.states.crosshair, 2);
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(component.states.crosshair, 0);
+        // Disabled crosshair
+        let component = component.crosshair(false);
+        assert_eq!(component.crosshair_x(), None);
+        assert!(component.crosshair_points().is_empty());
+    }
+
+    #[test]
+    fn test_components_chart_crosshair_scroll() {
+        let points: Vec<(f64, f64)> = (0..10).map(|x| (x as f64, x as f64 * 2.0)).collect();
+        let mut component: Chart = Chart::default()
+            .crosshair(true)
+            .data(&[Dataset::default().data(points)]);
+        // Only the 5 points displayed are stops
+        crate::testing::render(&mut component, 5, 10);
+        assert_eq!(component.crosshair_stops(), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        for _ in 0..4 {
+            component.perform(Cmd::Move(Direction::Right));
+        }
+        assert_eq!(component.crosshair_x(), Some(4.0));
+        assert_eq!(component.states.cursor, 0);
+        // Moving beyond the last displayed point scrolls the chart
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.cursor, 1);
+        assert_eq!(component.crosshair_x(), Some(5.0));
+        assert_eq!(
+            component.crosshair_points(),
+            vec![(String::new(), (5.0, 10.0))]
+        );
+        // And so does moving before the first one
+        for _ in 0..4 {
+            component.perform(Cmd::Move(Direction::Left));
+        }
+        assert_eq!(component.crosshair_x(), Some(1.0));
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(component.states.cursor, 0);
+        assert_eq!(component.crosshair_x(), Some(0.0));
+        // Go to the last and the first points
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.cursor, 5);
+        assert_eq!(component.crosshair_x(), Some(9.0));
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.crosshair_x(), Some(9.0));
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(component.states.cursor, 0);
+        assert_eq!(component.crosshair_x(), Some(0.0));
+    }
+
+    #[test]
+    fn test_components_chart_time_labels() {
+        // 12:00 to 13:00
+        let bounds = (1709208000.0, 1709211600.0);
+        assert_eq!(
+            Chart::time_labels(bounds, "%H:%M", 28),
+            vec![
+                String::from("12:00"),
+                String::from("12:20"),
+                String::from("12:40"),
+                String::from("13:00")
+            ]
+        );
+        // At least two labels
+        assert_eq!(
+            Chart::time_labels(bounds, "%H:%M", 0),
+            vec![String::from("12:00"), String::from("13:00")]
+        );
+        assert_eq!(
+            Chart::time_labels((0.0, 86400.0 * 4.0), "%b %e", 16),
+            vec![String::from("Jan 1"), String::from("Jan 5")]
+        );
+    }
+
+    #[test]
+    fn test_components_chart_log_scale() {
+        let mut component: Chart = Chart::default()
+            .y_log_scale(true)
+            .y_bounds((2.0, 5000.0))
+            .crosshair(true)
+            .data(&[Dataset::default().data(vec![
+                (0.0, 0.0),
+                (1.0, 10.0),
+                (2.0, 100.0),
+                (3.0, 1000.0),
+            ])]);
+        // Non positive values are removed and values are scaled
+        assert_eq!(
+            component.scaled_datasets()[0].get_data(),
+            &[(1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]
+        );
+        assert_eq!(component.data_bounds(0, 8), Some(((1.0, 3.0), (1.0, 3.0))));
+        // Bounds are scaled and rounded to powers of 10
+        assert_eq!(
+            component.axis_bounds(CHART_Y_BOUNDS, CHART_Y_AUTO_BOUNDS, CHART_Y_LOG_SCALE, None),
+            Some((0.0, 4.0))
+        );
+        // Crosshair returns original values
+        assert_eq!(
+            component.crosshair_points(),
+            vec![(String::new(), (1.0, 10.0))]
+        );
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(
+            component.crosshair_points(),
+            vec![(String::new(), (3.0, 1000.0))]
+        );
+        // Labels
+        assert_eq!(
+            Chart::log_labels((-2.0, 1.5)),
+            vec![
+                String::from("0.01"),
+                String::from("0.1"),
+                String::from("1"),
+                String::from("10")
+            ]
+        );
+        assert_eq!(
+            Chart::log_labels((6.0, 7.0)),
+            vec![String::from("1000000"), String::from("1e7")]
+        );
+        assert_eq!(Chart::scale(-1.0, true), None);
+        assert_eq!(Chart::scale(-1.0, false), Some(-1.0));
+    }
+
+    #[test]
+    fn test_components_chart_decimation() {
+        let points: Vec<(f64, f64)> = (0..1000)
+            .map(|x| (x as f64, if x == 500 { 100.0 } else { 0.0 }))
+            .collect();
+        let sampled = Chart::lttb(&points, 20);
+        assert_eq!(sampled.len(), 20);
+        // Bounds and spike are preserved
+        assert_eq!(sampled.first(), Some(&(0.0, 0.0)));
+        assert_eq!(sampled.last(), Some(&(999.0, 0.0)));
+        assert!(sampled.contains(&(500.0, 100.0)));
+        // Less points than threshold
+        assert_eq!(Chart::lttb(&points[0..10], 20), points[0..10].to_vec());
+        // Component
+        let mut component = Chart::default()
+            .decimation(true)
+            .data(&[Dataset::default().data(points)]);
+        assert_eq!(component.window_len(40), usize::MAX);
+        component.refresh_datasets();
+        let decimated = |x: &Chart| {
+            x.decimated
+                .as_ref()
+                .map(|(key, data)| (*key, data[0].get_data().len()))
+        };
+        component.decimate(0, 10);
+        assert_eq!(decimated(&component), Some(((0, 10), 20)));
+        assert_eq!(component.get_data(0, 10).len(), 1);
+        component.decimate(995, 10);
+        assert_eq!(decimated(&component), Some(((995, 10), 5)));
+        // Data keeps all the points
+        assert_eq!(component.states.data[0].get_data().len(), 1000);
+        // Points are decimated again only when the cursor, the width or data change
+        let _ = crate::testing::render(&mut component, 20, 10);
+        assert_eq!(decimated(&component), Some(((0, 20), 40)));
+        if let Some((_, data)) = component.decimated.as_mut() {
+            data[0] = Dataset::default();
+        }
+        let _ = crate::testing::render(&mut component, 20, 10);
+        assert_eq!(decimated(&component), Some(((0, 20), 0)));
+        component.attr(
+            Attribute::Dataset,
+            AttrValue::Payload(PropPayload::Vec(vec![PropValue::Dataset(
+                Dataset::default().data(vec![(0.0, 1.0), (1.0, 2.0)]),
+            )])),
+        );
+        assert!(component.decimated.is_none());
+        let _ = crate::testing::render(&mut component, 20, 10);
+        assert_eq!(decimated(&component), Some(((0, 20), 2)));
+        assert_eq!(component.states.data[0].get_data().len(), 2);
+        assert_eq!(Chart::default().window_len(40), 40);
+    }
 }
@@ -0,0 +1,542 @@
+//! ## ChatView
+//!
+//! `ChatView` renders a conversation as message bubbles
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{
+    CHAT_VIEW_APPEND, CHAT_VIEW_FOLLOW, CHAT_VIEW_OTHER_COLOR, CHAT_VIEW_OWN_COLOR, TOOLTIP,
+};
+
+/// ## ChatMessage
+///
+/// A message displayed by the `ChatView`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChatMessage {
+    pub sender: String,
+    pub text: String,
+    /// Time the message was sent, as displayed
+    pub time: String,
+    /// Whether the message was sent by the user; own messages are aligned to the right
+    pub own: bool,
+}
+
+impl ChatMessage {
+    /// ### new
+    ///
+    /// Make a new message from someone else, without time
+    pub fn new<S: Into<String>, T: Into<String>>(sender: S, text: T) -> Self {
+        Self {
+            sender: sender.into(),
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn time<S: Into<String>>(mut self, time: S) -> Self {
+        self.time = time.into();
+        self
+    }
+
+    pub fn own(mut self, own: bool) -> Self {
+        self.own = own;
+        self
+    }
+
+    /// ### to_payload
+    ///
+    /// Returns the message as a payload, to append it with `Custom($CHAT_VIEW_APPEND)`
+    pub fn to_payload(&self) -> PropPayload {
+        PropPayload::Tup4((
+            PropValue::Str(self.sender.clone()),
+            PropValue::Str(self.text.clone()),
+            PropValue::Str(self.time.clone()),
+            PropValue::Bool(self.own),
+        ))
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        let (sender, text, time, own) = payload.unwrap_tup4();
+        Self {
+            sender: sender.unwrap_str(),
+            text: text.unwrap_str(),
+            time: time.unwrap_str(),
+            own: own.unwrap_bool(),
+        }
+    }
+}
+
+/// ## ChatRow
+///
+/// A row of the conversation
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ChatRow {
+    /// Sender and time of a message
+    Header(usize),
+    /// A line of a message, in a bubble of the given width
+    Line(usize, String, usize),
+    /// Space between two messages
+    Blank,
+}
+
+/// ### layout_rows
+///
+/// Lay out the rows of `messages` in `width` columns. Bubbles take up to three quarters of the width
+fn layout_rows(messages: &[ChatMessage], width: usize) -> Vec<ChatRow> {
+    // One column of padding on each side
+    let text_width = (width * 3 / 4).saturating_sub(2).max(1);
+    let mut rows = Vec::new();
+    for (i, message) in messages.iter().enumerate() {
+        if i > 0 {
+            rows.push(ChatRow::Blank);
+        }
+        rows.push(ChatRow::Header(i));
+        let lines = textwrap::wrap(&message.text, text_width);
+        let bubble_width = lines.iter().map(|x| x.width()).max().unwrap_or(0) + 2;
+        rows.extend(
+            lines
+                .into_iter()
+                .map(|x| ChatRow::Line(i, x.into_owned(), bubble_width)),
+        );
+    }
+    rows
+}
+
+// -- states
+
+/// ## ChatViewStates
+///
+/// ChatViewStates contains states for this component
+pub struct ChatViewStates {
+    pub messages: Vec<ChatMessage>,
+    /// First row displayed
+    pub scroll: usize,
+    /// Whether the view sticks to the last message
+    pub tail: bool,
+    /// Rows of the conversation, at the last render
+    rows: usize,
+    /// Rows displayed, at the last render
+    height: usize,
+}
+
+impl Default for ChatViewStates {
+    fn default() -> Self {
+        Self {
+            messages: Vec::new(),
+            scroll: 0,
+            tail: true,
+            rows: 0,
+            height: 0,
+        }
+    }
+}
+
+impl ChatViewStates {
+    /// ### set_messages
+    ///
+    /// Replace the messages
+    pub fn set_messages(&mut self, messages: Vec<ChatMessage>) {
+        self.messages = messages;
+    }
+
+    /// ### append
+    ///
+    /// Append a message at the end of the conversation
+    pub fn append(&mut self, message: ChatMessage) {
+        self.messages.push(message);
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.rows.saturating_sub(self.height)
+    }
+
+    /// ### scroll_to
+    ///
+    /// Scroll to `row`; the view sticks to the last message if it's reached
+    pub fn scroll_to(&mut self, row: usize) {
+        self.scroll = row.min(self.max_scroll());
+        self.tail = self.scroll == self.max_scroll();
+    }
+}
+
+// -- component
+
+/// ## ChatView
+///
+/// A conversation, rendered as message bubbles with the sender and the time: own messages are on the right, the others
+/// on the left. The view follows new messages, unless it has been scrolled up. Messages can be appended one at a
+/// time with the append attribute, without setting the whole conversation again
+#[derive(Default)]
+pub struct ChatView {
+    props: Props,
+    pub states: ChatViewStates,
+}
+
+impl ChatView {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `chat_view` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("chat_view", self);
+    }
+
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    pub fn messages(mut self, messages: &[ChatMessage]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(
+                messages.iter().map(|x| x.to_payload()).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set whether the view follows new messages, when scrolled to the bottom (default: true)
+    pub fn follow(mut self, follow: bool) -> Self {
+        self.attr(Attribute::Custom(CHAT_VIEW_FOLLOW), AttrValue::Flag(follow));
+        self
+    }
+
+    /// Set the color of the bubbles of own messages (default: blue)
+    pub fn own_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::Custom(CHAT_VIEW_OWN_COLOR), AttrValue::Color(c));
+        self
+    }
+
+    /// Set the color of the bubbles of other messages (default: dark gray)
+    pub fn other_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(CHAT_VIEW_OTHER_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    fn is_following(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(CHAT_VIEW_FOLLOW), AttrValue::Flag(true))
+            .unwrap_flag()
+    }
+
+    /// ### render_messages
+    ///
+    /// Render the messages in `area`, at the current scroll
+    fn render_messages(&mut self, buf: &mut Buffer, area: Rect, style: Style) {
+        let rows = layout_rows(&self.states.messages, area.width as usize);
+        self.states.rows = rows.len();
+        self.states.height = area.height as usize;
+        match self.is_following() && self.states.tail {
+            true => self.states.scroll = self.states.max_scroll(),
+            false => self.states.scroll = self.states.scroll.min(self.states.max_scroll()),
+        }
+        let own_color = self
+            .props
+            .get_or(
+                Attribute::Custom(CHAT_VIEW_OWN_COLOR),
+                AttrValue::Color(Color::Blue),
+            )
+            .unwrap_color();
+        let other_color = self
+            .props
+            .get_or(
+                Attribute::Custom(CHAT_VIEW_OTHER_COLOR),
+                AttrValue::Color(Color::DarkGray),
+            )
+            .unwrap_color();
+        let width = area.width as usize;
+        let max_width = |x: u16| area.right().saturating_sub(x) as usize;
+        // Own messages are aligned to the right
+        let left = |own: bool, w: usize| -> u16 {
+            match own {
+                true => area.x + width.saturating_sub(w) as u16,
+                false => area.x,
+            }
+        };
+        for (y, row) in (area.y..area.bottom()).zip(rows.iter().skip(self.states.scroll)) {
+            match row {
+                ChatRow::Header(i) => {
+                    let message = &self.states.messages[*i];
+                    let time = match message.time.is_empty() {
+                        true => String::new(),
+                        false => format!(" · {}", message.time),
+                    };
+                    let x = left(message.own, message.sender.width() + time.width());
+                    let (x, _) = buf.set_stringn(
+                        x,
+                        y,
+                        &message.sender,
+                        max_width(x),
+                        style.add_modifier(TextModifiers::BOLD),
+                    );
+                    buf.set_stringn(
+                        x,
+                        y,
+                        &time,
+                        max_width(x),
+                        style.add_modifier(TextModifiers::DIM),
+                    );
+                }
+                ChatRow::Line(i, line, bubble_width) => {
+                    let own = self.states.messages[*i].own;
+                    let bubble_width = (*bubble_width).min(width);
+                    let x = left(own, bubble_width);
+                    let bubble_style = style.bg(match own {
+                        true => own_color,
+                        false => other_color,
+                    });
+                    buf.set_style(Rect::new(x, y, bubble_width as u16, 1), bubble_style);
+                    buf.set_stringn(x + 1, y, line, bubble_width.saturating_sub(2), bubble_style);
+                }
+                ChatRow::Blank => {}
+            }
+        }
+    }
+}
+
+impl MockComponent for ChatView {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_messages(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            // Appended messages are only kept in the states
+            (Attribute::Custom(CHAT_VIEW_APPEND), AttrValue::Payload(payload)) => {
+                self.states.append(ChatMessage::from_payload(payload));
+            }
+            (Attribute::Content, value) => {
+                self.props.set(attr, value);
+                let messages = match self
+                    .props
+                    .get(Attribute::Content)
+                    .map(|x| x.unwrap_payload())
+                {
+                    Some(PropPayload::Linked(messages)) => messages
+                        .into_iter()
+                        .map(ChatMessage::from_payload)
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                self.states.set_messages(messages);
+            }
+            (attr, value) => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length();
+        let scroll = self.states.scroll;
+        match cmd {
+            Cmd::Move(Direction::Down) => self.states.scroll_to(scroll + 1),
+            Cmd::Move(Direction::Up) => self.states.scroll_to(scroll.saturating_sub(1)),
+            Cmd::Scroll(Direction::Down) => self.states.scroll_to(scroll + step),
+            Cmd::Scroll(Direction::Up) => self.states.scroll_to(scroll.saturating_sub(step)),
+            Cmd::GoTo(Position::Begin) => self.states.scroll_to(0),
+            Cmd::GoTo(Position::End) => self.states.scroll_to(usize::MAX),
+            _ => {}
+        }
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_components_chat_view() {
+        let mut component = ChatView::default().messages(&[
+            ChatMessage::new("alice", "hello there, how are you?").time("10:32"),
+            ChatMessage::new("me", "fine").own(true),
+        ]);
+        assert_eq!(component.states.messages.len(), 2);
+        assert_eq!(component.state(), State::None);
+        let area = Rect::new(0, 0, 20, 8);
+        let mut buf = Buffer::empty(area);
+        component.render_messages(&mut buf, area, Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![
+                "alice · 10:32",
+                " hello there,",
+                " how are you?",
+                "",
+                "                  me",
+                "               fine",
+                "",
+                "",
+            ]
+        );
+        assert_eq!(buf[(0, 1)].bg, Color::DarkGray);
+        assert_eq!(buf[(13, 1)].bg, Color::DarkGray);
+        assert_eq!(buf[(14, 1)].bg, Color::Reset);
+        assert_eq!(buf[(14, 5)].bg, Color::Blue);
+        assert_eq!(buf[(19, 5)].bg, Color::Blue);
+        assert_eq!(buf[(13, 5)].bg, Color::Reset);
+        // Append
+        component.attr(
+            Attribute::Custom(CHAT_VIEW_APPEND),
+            AttrValue::Payload(ChatMessage::new("bob", "hi").to_payload()),
+        );
+        assert_eq!(component.states.messages.len(), 3);
+        assert_eq!(component.query(Attribute::Custom(CHAT_VIEW_APPEND)), None);
+        // The view follows the tail
+        let area = Rect::new(0, 0, 20, 4);
+        let mut buf = Buffer::empty(area);
+        component.render_messages(&mut buf, area, Style::default());
+        assert_eq!(component.states.scroll, 5);
+        assert_eq!(rows(&buf), vec!["               fine", "", "bob", " hi"]);
+    }
+
+    #[test]
+    fn test_components_chat_view_scroll() {
+        let mut component = ChatView::default().step(2).messages(&[
+            ChatMessage::new("alice", "one"),
+            ChatMessage::new("alice", "two"),
+            ChatMessage::new("alice", "three"),
+        ]);
+        let area = Rect::new(0, 0, 20, 3);
+        let mut buf = Buffer::empty(area);
+        // 8 rows
+        component.render_messages(&mut buf, area, Style::default());
+        assert_eq!(component.states.scroll, 5);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.scroll, 3);
+        assert!(!component.states.tail);
+        // New messages don't move the view
+        component.attr(
+            Attribute::Custom(CHAT_VIEW_APPEND),
+            AttrValue::Payload(ChatMessage::new("alice", "four").to_payload()),
+        );
+        component.render_messages(&mut buf, area, Style::default());
+        assert_eq!(component.states.scroll, 3);
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.scroll, 8);
+        assert!(component.states.tail);
+        component.perform(Cmd::GoTo(Position::Begin));
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.states.scroll, 1);
+    }
+}
@@ -25,13 +25,28 @@
  * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
  * SOFTWARE.
  */
-use tuirealm::command::{Cmd, CmdResult, Direction};
+use std::ops::{Deref, DerefMut};
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::event::MouseEvent;
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
 };
 use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::{layout::Rect, text::Span, widgets::Tabs};
 use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{
+    clicked, get_title_style, is_disabled, render_disabled, render_tabs_overflow, render_tooltip,
+    scroll_tabs, tab_at, tabs_strip, visible_tabs,
+};
+
+// -- Props
+
+use super::props::TOOLTIP;
+use super::states::{Choice, ChoiceState};
 
 // -- states
 
@@ -40,39 +55,35 @@ use tuirealm::{Frame, MockComponent, State, StateValue};
 /// CheckboxStates contains states for this component
 #[derive(Default)]
 pub struct CheckboxStates {
-    pub choice: usize,         // Selected option
-    pub choices: Vec<String>,  // Available choices
+    pub options: ChoiceState,  // Available choices and option under the cursor
     pub selection: Vec<usize>, // Selected options
+    pub area: Rect,            // Area of the options in the last render
+    pub offset: usize,         // First visible option
 }
 
-impl CheckboxStates {
-    /// ### next_choice
-    ///
-    /// Move choice index to next choice
-    pub fn next_choice(&mut self, rewind: bool) {
-        if rewind && self.choice + 1 >= self.choices.len() {
-            self.choice = 0;
-        } else if self.choice + 1 < self.choices.len() {
-            self.choice += 1;
-        }
+impl Deref for CheckboxStates {
+    type Target = ChoiceState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.options
     }
+}
 
-    /// ### prev_choice
-    ///
-    /// Move choice index to previous choice
-    pub fn prev_choice(&mut self, rewind: bool) {
-        if rewind && self.choice == 0 && !self.choices.is_empty() {
-            self.choice = self.choices.len() - 1;
-        } else if self.choice > 0 {
-            self.choice -= 1;
-        }
+impl DerefMut for CheckboxStates {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
     }
+}
 
+impl CheckboxStates {
     /// ### toggle
     ///
-    /// Check or uncheck the option
+    /// Check or uncheck the option, unless it's disabled
     pub fn toggle(&mut self) {
         let option = self.choice;
+        if !self.is_enabled(option) {
+            return;
+        }
         if self.selection.contains(&option) {
             let target_index = self.selection.iter().position(|x| *x == option).unwrap();
             self.selection.remove(target_index);
@@ -94,22 +105,36 @@ impl CheckboxStates {
         self.selection.contains(&option)
     }
 
+    /// ### page
+    ///
+    /// Move the cursor by a page, i.e. by the number of options visible in the last render, or by one if none was
+    /// rendered yet
+    pub fn page(&mut self, forward: bool) {
+        let page = visible_tabs(&self.widths(), self.offset, self.area.width as usize).max(1);
+        for _ in 0..page {
+            match forward {
+                true => self.next_choice(false),
+                false => self.prev_choice(false),
+            }
+        }
+    }
+
+    /// ### widths
+    ///
+    /// Get the widths of the labels of the options, including their checkbox
+    fn widths(&self) -> Vec<usize> {
+        self.choices.iter().map(|x| x.label.width() + 2).collect()
+    }
+
     /// ### set_choices
     ///
     /// Set CheckboxStates choices from a vector of str
     /// In addition resets current selection and keep index if possible or set it to the first value
     /// available
-    pub fn set_choices(&mut self, choices: &[String]) {
-        self.choices = choices.to_vec();
+    pub fn set_choices<C: Into<Choice> + Clone>(&mut self, choices: &[C]) {
+        self.options.set_choices(choices);
         // Clear selection
         self.selection.clear();
-        // Keep index if possible
-        if self.choice >= self.choices.len() {
-            self.choice = match self.choices.len() {
-                0 => 0,
-                l => l - 1,
-            };
-        }
     }
 }
 
@@ -150,6 +175,28 @@ impl Checkbox {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `checkbox` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("checkbox", self);
+    }
+
     pub fn rewind(mut self, r: bool) -> Self {
         self.attr(Attribute::Rewind, AttrValue::Flag(r));
         self
@@ -168,6 +215,12 @@ impl Checkbox {
         self
     }
 
+    /// Set the choices as entries, which can be disabled and styled
+    pub fn entries(mut self, entries: &[Choice]) -> Self {
+        self.states.set_choices(entries);
+        self
+    }
+
     pub fn values(mut self, selected: &[usize]) -> Self {
         // Set state
         self.attr(
@@ -184,6 +237,25 @@ impl Checkbox {
             .get_or(Attribute::Rewind, AttrValue::Flag(false))
             .unwrap_flag()
     }
+
+    /// ### on_mouse
+    ///
+    /// Handle a mouse event: clicking an option moves the cursor to it and toggles it
+    pub fn on_mouse(&mut self, ev: MouseEvent) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let widths = self.states.widths();
+        let offset = self.states.offset.min(widths.len());
+        match clicked(&ev, self.states.area).and_then(|(x, _)| tab_at(&widths[offset..], x)) {
+            Some(choice) if self.states.is_enabled(offset + choice) => {
+                self.states.choice = offset + choice;
+                self.states.toggle();
+                CmdResult::Changed(self.state())
+            }
+            _ => CmdResult::None,
+        }
+    }
 }
 
 impl MockComponent for Checkbox {
@@ -210,7 +282,23 @@ impl MockComponent for Checkbox {
                 .props
                 .get(Attribute::FocusStyle)
                 .map(|x| x.unwrap_style());
-            let div = crate::utils::get_block(borders, title, focus, inactive_style);
+            let div = crate::utils::get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props));
+            // Scroll the options if they don't fit, leaving a column on both sides for the indicators
+            let inner = div.inner(area);
+            let widths = self.states.widths();
+            let strip = tabs_strip(&widths, inner);
+            self.states.offset = match strip == inner {
+                true => 0,
+                false => scroll_tabs(
+                    &widths,
+                    self.states.choice,
+                    self.states.offset,
+                    strip.width as usize,
+                ),
+            };
+            self.states.area = strip;
+            let offset = self.states.offset;
             // Make colors
             let (bg, fg, block_color): (Color, Color, Color) = match &focus {
                 true => (foreground, background, foreground),
@@ -222,6 +310,7 @@ impl MockComponent for Checkbox {
                 .choices
                 .iter()
                 .enumerate()
+                .skip(offset)
                 .map(|(idx, x)| {
                     let checkbox: &str = match self.states.has(idx) {
                         true => "☑ ",
@@ -235,18 +324,31 @@ impl MockComponent for Checkbox {
                         false => (fg, bg),
                     };
                     // Make spans
+                    let style = x.patch_style(Style::default().fg(fg).bg(bg));
                     Spans::from(vec![
-                        Span::styled(checkbox, Style::default().fg(fg).bg(bg)),
-                        Span::styled(x.to_string(), Style::default().fg(fg).bg(bg)),
+                        Span::styled(checkbox, style),
+                        Span::styled(x.label.clone(), style),
                     ])
                 })
                 .collect();
+            let style = Style::default().fg(block_color);
             let checkbox: Tabs = Tabs::new(choices)
-                .block(div)
-                .select(self.states.choice)
-                .style(Style::default().fg(block_color));
-            render.render_widget(checkbox, area);
+                .select(self.states.choice.saturating_sub(offset))
+                .style(style);
+            render.buffer_mut().set_style(area, style);
+            render.render_widget(div, area);
+            render.render_widget(checkbox, strip);
+            render_tabs_overflow(
+                render.buffer_mut(),
+                inner,
+                strip,
+                &widths,
+                offset,
+                Style::default().fg(foreground),
+            );
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -299,18 +401,39 @@ impl MockComponent for Checkbox {
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
         match cmd {
             Cmd::Move(Direction::Right) => {
                 // Increment choice
-                self.states.next_choice(self.rewindable());
+                let rewind = self.rewindable();
+                self.states.next_choice(rewind);
                 CmdResult::None
             }
             Cmd::Move(Direction::Left) => {
                 // Decrement choice
-                self.states.prev_choice(self.rewindable());
+                let rewind = self.rewindable();
+                self.states.prev_choice(rewind);
+                CmdResult::None
+            }
+            Cmd::Scroll(Direction::Right) => {
+                self.states.page(true);
+                CmdResult::None
+            }
+            Cmd::Scroll(Direction::Left) => {
+                self.states.page(false);
+                CmdResult::None
+            }
+            Cmd::GoTo(Position::Begin) => {
+                self.states.first_choice();
                 CmdResult::None
             }
-            Cmd::Toggle => {
+            Cmd::GoTo(Position::End) => {
+                self.states.last_choice();
+                CmdResult::None
+            }
+            Cmd::Toggle if self.states.is_enabled(self.states.choice) => {
                 self.states.toggle();
                 CmdResult::Changed(self.state())
             }
@@ -498,5 +621,123 @@ mod test {
             component.perform(Cmd::Submit),
             CmdResult::Submit(State::Vec(vec![StateValue::Usize(0)])),
         );
+        // Disabled
+        component.attr(Attribute::Disabled, AttrValue::Flag(true));
+        assert_eq!(component.perform(Cmd::Toggle), CmdResult::None);
+        assert_eq!(component.state(), State::Vec(vec![StateValue::Usize(0)]));
+    }
+
+    #[test]
+    fn test_components_checkbox_entries() {
+        let mut component = Checkbox::default().entries(&[
+            Choice::new("Gnocchi"),
+            Choice::new("Spaghetti").disabled(true),
+            Choice::new("Lasagne"),
+        ]);
+        assert_eq!(
+            component.perform(Cmd::Toggle),
+            CmdResult::Changed(State::Vec(vec![StateValue::Usize(0)])),
+        );
+        // Disabled entries are skipped
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.choice, 2);
+        component.states.choice = 1;
+        assert_eq!(component.perform(Cmd::Toggle), CmdResult::None);
+        assert_eq!(component.state(), State::Vec(vec![StateValue::Usize(0)]));
+    }
+
+    #[test]
+    fn test_components_checkbox_paging() {
+        use crate::testing::{assert_lines, render};
+        use tuirealm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+        let click = |column| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+            column,
+            row: 1,
+        };
+        let mut component = Checkbox::default()
+            .borders(Borders::default())
+            .choices(&["read", "write", "exec", "admin", "owner", "guest"])
+            .values(&[1]);
+        let buf = render(&mut component, 30, 3);
+        assert_lines(
+            &buf,
+            &[
+                "┌────────────────────────────┐",
+                "│  ☐ read │ ☑ write │ ☐ exec›│",
+                "└────────────────────────────┘",
+            ],
+        );
+        // Page down moves by the visible options
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.choice, 2);
+        render(&mut component, 30, 3);
+        assert_eq!(component.states.offset, 1);
+        // End
+        assert_eq!(component.perform(Cmd::GoTo(Position::End)), CmdResult::None);
+        assert_eq!(component.states.choice, 5);
+        let buf = render(&mut component, 30, 3);
+        assert_eq!(component.states.offset, 4);
+        assert_lines(
+            &buf,
+            &[
+                "┌────────────────────────────┐",
+                "│‹ ☐ owner │ ☐ guest         │",
+                "└────────────────────────────┘",
+            ],
+        );
+        // Clicks are mapped to the visible options
+        assert_eq!(
+            component.on_mouse(click(14)),
+            CmdResult::Changed(State::Vec(vec![StateValue::Usize(1), StateValue::Usize(5)]))
+        );
+        // Page up and home
+        component.perform(Cmd::Scroll(Direction::Left));
+        assert_eq!(component.states.choice, 3);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.choice, 0);
+        component.perform(Cmd::Scroll(Direction::Left));
+        assert_eq!(component.states.choice, 0);
+    }
+
+    #[test]
+    fn test_components_checkbox_mouse() {
+        use tuirealm::event::{KeyModifiers, MouseButton, MouseEventKind};
+        use tuirealm::ratatui::{backend::TestBackend, Terminal};
+
+        let click = |column, row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+            column,
+            row,
+        };
+        let mut component = Checkbox::default()
+            .borders(Borders::default())
+            .choices(&["Pizza", "Hummus", "Ramen"])
+            .values(&[1]);
+        let mut terminal = Terminal::new(TestBackend::new(40, 3)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 40, 3)))
+            .unwrap();
+        // " ☐ Pizza │ ☑ Hummus │ ☐ Ramen "
+        assert_eq!(
+            component.on_mouse(click(13, 1)),
+            CmdResult::Changed(State::Vec(vec![]))
+        );
+        assert_eq!(component.states.choice, 1);
+        assert_eq!(
+            component.on_mouse(click(24, 1)),
+            CmdResult::Changed(State::Vec(vec![StateValue::Usize(2)]))
+        );
+        // Border
+        assert_eq!(component.on_mouse(click(24, 0)), CmdResult::None);
     }
 }
@@ -0,0 +1,425 @@
+//! ## Clock
+//!
+//! `Clock` displays the current time
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect, widgets::Paragraph, widgets::Widget};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use super::big_text::{render_big_text, BigTextFont, Coloring, Glyphs};
+use crate::theme::Theme;
+use crate::utils::{
+    format_timestamp, get_block, get_title_style, is_disabled, render_disabled, render_tooltip,
+};
+
+// -- Props
+
+use super::props::{CLOCK_BIG, CLOCK_BLINK, CLOCK_FONT, CLOCK_FORMAT, CLOCK_UTC_OFFSET, TOOLTIP};
+
+/// Format used when none is set
+const DEFAULT_FORMAT: &str = "%H:%M:%S";
+
+// -- states
+
+/// ## ClockStates
+///
+/// ClockStates contains states for this component
+pub struct ClockStates {
+    /// Time displayed, as a unix timestamp (seconds, UTC)
+    pub timestamp: i64,
+}
+
+impl Default for ClockStates {
+    fn default() -> Self {
+        Self {
+            timestamp: Self::now(),
+        }
+    }
+}
+
+impl ClockStates {
+    /// ### now
+    ///
+    /// Get the current unix timestamp
+    pub fn now() -> i64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|x| x.as_secs() as i64)
+            .unwrap_or(0)
+    }
+}
+
+// -- component
+
+/// ## Clock
+///
+/// A clock, showing the time in a strftime-like format, in a single line or with big digits.
+/// The time is updated with `Cmd::Tick`, which should be performed on `Event::Tick`
+#[derive(Default)]
+pub struct Clock {
+    props: Props,
+    pub states: ClockStates,
+}
+
+impl Clock {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn modifiers(mut self, m: TextModifiers) -> Self {
+        self.attr(Attribute::TextProps, AttrValue::TextModifiers(m));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `clock` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("clock", self);
+    }
+
+    pub fn alignment(mut self, a: Alignment) -> Self {
+        self.attr(Attribute::Alignment, AttrValue::Alignment(a));
+        self
+    }
+
+    /// Set the format of the time (default: `%H:%M:%S`). See `utils::format_timestamp` for supported specifiers
+    pub fn format<S: Into<String>>(mut self, fmt: S) -> Self {
+        self.attr(
+            Attribute::Custom(CLOCK_FORMAT),
+            AttrValue::String(fmt.into()),
+        );
+        self
+    }
+
+    /// Render the time with big digits
+    pub fn big(mut self, big: bool) -> Self {
+        self.attr(Attribute::Custom(CLOCK_BIG), AttrValue::Flag(big));
+        self
+    }
+
+    /// Set the font of big digits (default: block)
+    pub fn font(mut self, font: BigTextFont) -> Self {
+        self.attr(
+            Attribute::Custom(CLOCK_FONT),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(font as usize))),
+        );
+        self
+    }
+
+    /// Make the `:` separators blink, hiding them on odd seconds
+    pub fn blink(mut self, blink: bool) -> Self {
+        self.attr(Attribute::Custom(CLOCK_BLINK), AttrValue::Flag(blink));
+        self
+    }
+
+    /// Set the offset of the displayed time from UTC, in minutes (default: 0)
+    pub fn utc_offset(mut self, minutes: i32) -> Self {
+        self.attr(
+            Attribute::Custom(CLOCK_UTC_OFFSET),
+            AttrValue::Payload(PropPayload::One(PropValue::I32(minutes))),
+        );
+        self
+    }
+
+    /// ### text
+    ///
+    /// Get the time, formatted
+    fn text(&self) -> String {
+        let fmt = self
+            .props
+            .get(Attribute::Custom(CLOCK_FORMAT))
+            .map(|x| x.unwrap_string())
+            .unwrap_or_else(|| DEFAULT_FORMAT.to_string());
+        let offset = match self
+            .props
+            .get(Attribute::Custom(CLOCK_UTC_OFFSET))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::I32(minutes))) => i64::from(minutes) * 60,
+            _ => 0,
+        };
+        format_timestamp((self.states.timestamp + offset) as f64, &fmt)
+    }
+
+    /// ### separators_visible
+    ///
+    /// Whether separators are visible: they're hidden on odd seconds, when blinking
+    fn separators_visible(&self) -> bool {
+        !self
+            .props
+            .get_or(Attribute::Custom(CLOCK_BLINK), AttrValue::Flag(false))
+            .unwrap_flag()
+            || self.states.timestamp.rem_euclid(2) == 0
+    }
+
+    fn get_font(&self) -> BigTextFont {
+        match self
+            .props
+            .get(Attribute::Custom(CLOCK_FONT))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::Usize(x))) => {
+                BigTextFont::ALL.get(x).copied().unwrap_or_default()
+            }
+            _ => BigTextFont::default(),
+        }
+    }
+
+    /// ### render_time
+    ///
+    /// Render the time, centered vertically in `area`
+    fn render_time(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let text = self.text();
+        let alignment = self
+            .props
+            .get_or(
+                Attribute::Alignment,
+                AttrValue::Alignment(Alignment::Center),
+            )
+            .unwrap_alignment();
+        let visible = self.separators_visible();
+        if self
+            .props
+            .get_or(Attribute::Custom(CLOCK_BIG), AttrValue::Flag(false))
+            .unwrap_flag()
+        {
+            let glyphs = Glyphs::Builtin(self.get_font());
+            let height = (glyphs.height() as u16).min(area.height);
+            let area = Rect::new(
+                area.x,
+                area.y + (area.height - height) / 2,
+                area.width,
+                height,
+            );
+            let areas = render_big_text(
+                buf,
+                area,
+                &text,
+                &glyphs,
+                alignment,
+                &Coloring::Letters(&[]),
+                style,
+            );
+            // Hidden separators are cleared, so that digits don't move
+            for (_, area) in text
+                .chars()
+                .zip(areas)
+                .filter(|(c, _)| !visible && *c == ':')
+            {
+                buf.set_style(area, style);
+                for position in area.positions() {
+                    buf[position].set_char(' ');
+                }
+            }
+        } else {
+            let text = match visible {
+                true => text,
+                false => text.replace(':', " "),
+            };
+            let area = Rect::new(
+                area.x,
+                area.y + area.height / 2,
+                area.width,
+                area.height.min(1),
+            );
+            Paragraph::new(text)
+                .style(style)
+                .alignment(alignment)
+                .render(area, buf);
+        }
+    }
+}
+
+impl MockComponent for Clock {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let modifiers = self
+                .props
+                .get_or(
+                    Attribute::TextProps,
+                    AttrValue::TextModifiers(TextModifiers::empty()),
+                )
+                .unwrap_text_modifiers();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default()
+                    .fg(foreground)
+                    .bg(background)
+                    .add_modifier(modifiers),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_time(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::String(self.text()))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Tick => {
+                let prev = (self.text(), self.separators_visible());
+                self.states.timestamp = ClockStates::now();
+                match (self.text(), self.separators_visible()) == prev {
+                    true => CmdResult::None,
+                    false => CmdResult::Changed(self.state()),
+                }
+            }
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_components_clock() {
+        let mut component = Clock::default().format("%H:%M").utc_offset(90);
+        // 2024-02-29 12:30:05 UTC
+        component.states.timestamp = 1709209805;
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("14:00")))
+        );
+        let area = Rect::new(0, 0, 9, 3);
+        let mut buf = Buffer::empty(area);
+        component.render_time(&mut buf, area, Style::default());
+        assert_eq!(rows(&buf), vec!["         ", "  14:00  ", "         "]);
+        // Blinking
+        component.attr(Attribute::Custom(CLOCK_BLINK), AttrValue::Flag(true));
+        let mut buf = Buffer::empty(area);
+        component.render_time(&mut buf, area, Style::default());
+        assert_eq!(rows(&buf)[1], "  14 00  ");
+        // Time moves on
+        assert!(matches!(
+            component.perform(Cmd::Tick),
+            CmdResult::Changed(State::One(StateValue::String(_)))
+        ));
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_clock_big() {
+        let mut component = Clock::default()
+            .format("%H:%M")
+            .big(true)
+            .blink(true)
+            .font(BigTextFont::HalfBlock)
+            .alignment(Alignment::Left);
+        component.states.timestamp = 1709209805;
+        let area = Rect::new(0, 0, 25, 5);
+        let mut buf = Buffer::empty(area);
+        component.render_time(&mut buf, area, Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![
+                "                         ",
+                "▄█  ▄▀▀▀▄   ▀▀▀▀▄ ▄▀▀█▄  ",
+                " █   ▄▀▀     ▀▀▀▄ █▄▀ █  ",
+                "▀▀▀ ▀▀▀▀▀   ▀▀▀▀   ▀▀▀   ",
+                "                         ",
+            ]
+        );
+        // Separator is visible on even seconds
+        component.states.timestamp += 1;
+        let mut buf = Buffer::empty(area);
+        component.render_time(&mut buf, area, Style::default());
+        assert_eq!(rows(&buf)[1], "▄█  ▄▀▀▀▄ ▄ ▀▀▀▀▄ ▄▀▀█▄  ");
+        assert_eq!(rows(&buf)[2], " █   ▄▀▀  ▄  ▀▀▀▄ █▄▀ █  ");
+    }
+}
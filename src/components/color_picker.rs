@@ -0,0 +1,594 @@
+//! ## ColorPicker
+//!
+//! `ColorPicker` lets the user pick one of the 256 terminal colors, or type a RGB color as hex
+
+use std::str::FromStr;
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{COLOR_PICKER_HEX_INPUT, TOOLTIP};
+
+/// The 16 ANSI colors, by index
+const ANSI_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+/// Usual RGB values of the 16 ANSI colors (xterm)
+const ANSI_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (128, 0, 0),
+    (0, 128, 0),
+    (128, 128, 0),
+    (0, 0, 128),
+    (128, 0, 128),
+    (0, 128, 128),
+    (192, 192, 192),
+    (128, 128, 128),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (0, 0, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+/// Levels of the 6x6x6 color cube
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+/// Rows of the grid: the ANSI colors, the six planes of the color cube and the grayscale ramp
+const GRID_ROWS: u8 = 8;
+/// Max length of the hex input (`RRGGBB`)
+const HEX_MAX_LEN: usize = 6;
+
+/// ### palette_color
+///
+/// Get the color at `index` in the 256-color palette; the ANSI colors are returned by name
+fn palette_color(index: u8) -> Color {
+    match index {
+        0..=15 => ANSI_COLORS[index as usize],
+        _ => Color::Indexed(index),
+    }
+}
+
+/// ### palette_rgb
+///
+/// Get the RGB value of the color at `index` in the 256-color palette
+fn palette_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI_RGB[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[((i / 6) % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
+
+/// ### grid_position
+///
+/// Get the row and column of `index` in the grid
+fn grid_position(index: u8) -> (u8, u8) {
+    match index {
+        0..=15 => (0, index),
+        16..=231 => (1 + (index - 16) / 36, (index - 16) % 36),
+        _ => (GRID_ROWS - 1, index - 232),
+    }
+}
+
+/// ### row_len
+///
+/// Get the amount of colors in a row of the grid
+fn row_len(row: u8) -> u8 {
+    match row {
+        0 => 16,
+        1..=6 => 36,
+        _ => 24,
+    }
+}
+
+/// ### grid_index
+///
+/// Get the palette index at `row` and `column` of the grid
+fn grid_index(row: u8, column: u8) -> u8 {
+    match row {
+        0 => column,
+        1..=6 => 16 + (row - 1) * 36 + column,
+        _ => 232 + column,
+    }
+}
+
+/// ### parse_hex
+///
+/// Parse a `RRGGBB` hex color
+fn parse_hex(hex: &str) -> Option<Color> {
+    if hex.len() != HEX_MAX_LEN {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?))
+}
+
+// -- states
+
+/// ## ColorPickerStates
+///
+/// ColorPicker states: the index of the selected color in the palette and the typed hex color
+#[derive(Default)]
+pub struct ColorPickerStates {
+    pub selected: u8,
+    pub hex: String,
+}
+
+impl ColorPickerStates {
+    /// ### color
+    ///
+    /// Get the selected color: the typed hex color if valid, otherwise the selected palette color
+    pub fn color(&self) -> Color {
+        parse_hex(&self.hex).unwrap_or_else(|| palette_color(self.selected))
+    }
+
+    /// ### select
+    ///
+    /// Select the palette color at `index`, discarding the typed hex color. Returns whether the color has changed
+    pub fn select(&mut self, index: u8) -> bool {
+        let before = self.color();
+        self.selected = index;
+        self.hex.clear();
+        self.color() != before
+    }
+
+    /// ### move_by
+    ///
+    /// Move the selection in the grid by `rows` and `columns`.
+    /// When moving to a row with a different length, the column is scaled to keep the relative position
+    pub fn move_by(&mut self, rows: i16, columns: i16) -> bool {
+        let (row, column) = grid_position(self.selected);
+        let target_row = (i16::from(row) + rows).clamp(0, i16::from(GRID_ROWS) - 1) as u8;
+        let column = match target_row == row {
+            true => column,
+            false => {
+                ((u16::from(column) * 2 + 1) * u16::from(row_len(target_row))
+                    / (u16::from(row_len(row)) * 2)) as u8
+            }
+        };
+        let target_column =
+            (i16::from(column) + columns).clamp(0, i16::from(row_len(target_row)) - 1) as u8;
+        self.select(grid_index(target_row, target_column))
+    }
+
+    /// ### type_char
+    ///
+    /// Type a hex digit into the hex input
+    pub fn type_char(&mut self, ch: char) {
+        if ch.is_ascii_hexdigit() && self.hex.len() < HEX_MAX_LEN {
+            self.hex.push(ch.to_ascii_uppercase());
+        }
+    }
+
+    /// ### backspace
+    ///
+    /// Delete the last typed hex digit
+    pub fn backspace(&mut self) {
+        self.hex.pop();
+    }
+}
+
+// -- component
+
+/// ## ColorPicker
+///
+/// A grid with the 16 ANSI colors, the 6x6x6 color cube and the grayscale ramp of the 256-color palette, navigable
+/// with arrows, and a preview of the selected color. Optionally, a RGB color can be typed as hex.
+#[derive(Default)]
+pub struct ColorPicker {
+    props: Props,
+    pub states: ColorPickerStates,
+}
+
+impl ColorPicker {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `color_picker` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("color_picker", self);
+    }
+
+    /// Set the selected color. Colors out of the palette are selected only if the hex input is enabled
+    pub fn value(mut self, color: Color) -> Self {
+        self.attr(Attribute::Value, AttrValue::String(color.to_string()));
+        self
+    }
+
+    /// Set whether the user can type a RGB color as hex; useful for terminals supporting true colors
+    pub fn hex_input(mut self, enabled: bool) -> Self {
+        self.attr(
+            Attribute::Custom(COLOR_PICKER_HEX_INPUT),
+            AttrValue::Flag(enabled),
+        );
+        self
+    }
+
+    fn is_hex_input(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(COLOR_PICKER_HEX_INPUT),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn set_color(&mut self, color: Color) {
+        match color {
+            Color::Indexed(index) => {
+                self.states.select(index);
+            }
+            Color::Rgb(r, g, b) if self.is_hex_input() => {
+                self.states.hex = format!("{r:02X}{g:02X}{b:02X}");
+            }
+            color => {
+                if let Some(index) = ANSI_COLORS.iter().position(|x| *x == color) {
+                    self.states.select(index as u8);
+                }
+            }
+        }
+    }
+
+    fn changed(&self, changed: bool) -> CmdResult {
+        match changed {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+
+    /// ### render_picker
+    ///
+    /// Render the color grid and the preview into `area`. Cells are two columns wide if the whole cube fits the area
+    fn render_picker(&self, buf: &mut Buffer, area: Rect, style: Style, focus: bool) {
+        let cell_width: u16 = match area.width >= 36 * 2 {
+            true => 2,
+            false => 1,
+        };
+        for index in 0..=255 {
+            let (row, column) = grid_position(index);
+            let (x, y) = (
+                area.x + u16::from(column) * cell_width,
+                area.y + u16::from(row),
+            );
+            if x + cell_width > area.right() || y >= area.bottom() {
+                continue;
+            }
+            let (r, g, b) = palette_rgb(index);
+            let symbol = match (focus && index == self.states.selected, cell_width) {
+                (true, 2) => "<>",
+                (true, _) => "◆",
+                (false, 2) => "  ",
+                (false, _) => " ",
+            };
+            // Keep the selection marker readable on light colors
+            let marker = match u16::from(r) * 3 + u16::from(g) * 6 + u16::from(b) > 1280 {
+                true => Color::Black,
+                false => Color::White,
+            };
+            buf.set_string(
+                x,
+                y,
+                symbol,
+                Style::default().fg(marker).bg(palette_color(index)),
+            );
+        }
+        // Preview
+        let y = area.y + u16::from(GRID_ROWS) + 1;
+        if y >= area.bottom() {
+            return;
+        }
+        let color = self.states.color();
+        let (x, _) = buf.set_stringn(
+            area.x,
+            y,
+            "      ",
+            area.width as usize,
+            Style::default().bg(color),
+        );
+        let label = match color {
+            Color::Rgb(r, g, b) => format!(" #{r:02X}{g:02X}{b:02X}"),
+            _ => {
+                let (r, g, b) = palette_rgb(self.states.selected);
+                format!(" {color} (#{r:02X}{g:02X}{b:02X})")
+            }
+        };
+        let (x, _) = buf.set_stringn(x, y, label, area.right().saturating_sub(x) as usize, style);
+        if self.is_hex_input() {
+            let hex = format!("  Hex: #{:_<6}", self.states.hex);
+            let hex_style =
+                match self.states.hex.is_empty() || parse_hex(&self.states.hex).is_some() {
+                    true => style,
+                    false => style.add_modifier(TextModifiers::DIM),
+                };
+            buf.set_stringn(
+                x,
+                y,
+                hex,
+                area.right().saturating_sub(x) as usize,
+                hex_style,
+            );
+        }
+    }
+}
+
+impl MockComponent for ColorPicker {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_picker(render.buffer_mut(), inner, style, focus);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if matches!(attr, Attribute::Value) {
+            if let Some(color) = self
+                .props
+                .get(Attribute::Value)
+                .and_then(|x| Color::from_str(&x.unwrap_string()).ok())
+            {
+                self.set_color(color);
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::String(self.states.color().to_string()))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Move(Direction::Left) => {
+                let changed = self.states.move_by(0, -1);
+                self.changed(changed)
+            }
+            Cmd::Move(Direction::Right) => {
+                let changed = self.states.move_by(0, 1);
+                self.changed(changed)
+            }
+            Cmd::Move(Direction::Up) => {
+                let changed = self.states.move_by(-1, 0);
+                self.changed(changed)
+            }
+            Cmd::Move(Direction::Down) => {
+                let changed = self.states.move_by(1, 0);
+                self.changed(changed)
+            }
+            Cmd::GoTo(Position::Begin) => {
+                let changed = self.states.select(0);
+                self.changed(changed)
+            }
+            Cmd::GoTo(Position::End) => {
+                let changed = self.states.select(255);
+                self.changed(changed)
+            }
+            Cmd::Type(ch) if self.is_hex_input() => {
+                let before = self.states.color();
+                self.states.type_char(ch);
+                self.changed(self.states.color() != before)
+            }
+            Cmd::Delete if self.is_hex_input() => {
+                let before = self.states.color();
+                self.states.backspace();
+                self.changed(self.states.color() != before)
+            }
+            Cmd::Submit => CmdResult::Submit(self.state()),
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn color(s: &str) -> State {
+        State::One(StateValue::String(String::from(s)))
+    }
+
+    #[test]
+    fn test_components_color_picker_palette() {
+        assert_eq!(palette_rgb(9), (255, 0, 0));
+        assert_eq!(palette_rgb(196), (255, 0, 0));
+        assert_eq!(palette_rgb(21), (0, 0, 255));
+        assert_eq!(palette_rgb(255), (238, 238, 238));
+        for index in 0..=255 {
+            let (row, column) = grid_position(index);
+            assert!(column < row_len(row));
+            assert_eq!(grid_index(row, column), index);
+        }
+    }
+
+    #[test]
+    fn test_components_color_picker() {
+        let mut component = ColorPicker::default()
+            .title("Accent color", Alignment::Center)
+            .borders(Borders::default())
+            .value(Color::LightRed);
+        assert_eq!(component.state(), color("LightRed"));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(color("DarkGray"))
+        );
+        // Column 8 of 16 is column 19 of 36
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(color("35"))
+        );
+        component.perform(Cmd::Move(Direction::Up));
+        assert_eq!(component.state(), color("DarkGray"));
+        assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.state(), color("255"));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        // Hex input is disabled
+        assert_eq!(component.perform(Cmd::Type('f')), CmdResult::None);
+        component.attr(Attribute::Value, AttrValue::String(String::from("#FF8800")));
+        assert_eq!(component.state(), color("255"));
+        component.attr(Attribute::Value, AttrValue::String(String::from("123")));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(color("123"))
+        );
+    }
+
+    #[test]
+    fn test_components_color_picker_hex() {
+        let mut component = ColorPicker::default()
+            .hex_input(true)
+            .value(Color::Rgb(0x12, 0x34, 0x56));
+        assert_eq!(component.state(), color("#123456"));
+        assert_eq!(
+            component.perform(Cmd::Delete),
+            CmdResult::Changed(color("Black"))
+        );
+        assert_eq!(component.perform(Cmd::Type('x')), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Type('7')),
+            CmdResult::Changed(color("#123457"))
+        );
+        assert_eq!(component.perform(Cmd::Type('7')), CmdResult::None);
+        // Moving discards the hex color
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.state(), color("Red"));
+        assert!(component.states.hex.is_empty());
+    }
+
+    #[test]
+    fn test_components_color_picker_render() {
+        let mut component = ColorPicker::default().hex_input(true);
+        component.states.select(16);
+        component.perform(Cmd::Type('f'));
+        let area = Rect::new(0, 0, 72, 10);
+        let mut buf = Buffer::empty(area);
+        component.render_picker(&mut buf, area, Style::default(), true);
+        assert_eq!(buf[(0, 0)].bg, Color::Black);
+        assert_eq!(buf[(30, 0)].bg, Color::White);
+        assert_eq!(buf[(71, 6)].bg, Color::Indexed(231));
+        assert_eq!(buf[(2, 7)].bg, Color::Indexed(233));
+        assert_eq!(buf[(0, 1)].symbol(), "<");
+        assert_eq!(buf[(1, 1)].symbol(), ">");
+        let row: String = (0..46).map(|x| buf[(x, 9)].symbol().to_string()).collect();
+        assert_eq!(row, "       16 (#000000)  Hex: #F_____             ");
+        assert_eq!(buf[(0, 9)].bg, Color::Indexed(16));
+        assert!(buf[(25, 9)].modifier.contains(TextModifiers::DIM));
+    }
+}
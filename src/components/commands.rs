@@ -0,0 +1,114 @@
+//! # Commands
+//!
+//! This module exposes the names of the custom commands (`Cmd::Custom`) handled by components
+
+// -- candlestick chart
+
+pub const CANDLESTICK_CHART_CMD_ZOOM_IN: &str = "candlestick-chart-zoom-in";
+pub const CANDLESTICK_CHART_CMD_ZOOM_OUT: &str = "candlestick-chart-zoom-out";
+
+// -- canvas
+
+pub const CANVAS_CMD_ZOOM_IN: &str = "canvas-zoom-in";
+pub const CANVAS_CMD_ZOOM_OUT: &str = "canvas-zoom-out";
+pub const CANVAS_CMD_RESET_VIEW: &str = "canvas-reset-view";
+
+// -- container
+
+pub const CONTAINER_CMD_FOCUS_NEXT: &str = "container-focus-next";
+pub const CONTAINER_CMD_FOCUS_PREV: &str = "container-focus-prev";
+pub const CONTAINER_CMD_TOGGLE_COLLAPSE: &str = "container-toggle-collapse";
+
+// -- date picker
+
+pub const DATE_PICKER_CMD_NEXT_YEAR: &str = "date-picker-next-year";
+pub const DATE_PICKER_CMD_PREV_YEAR: &str = "date-picker-prev-year";
+
+// -- diff viewer
+
+pub const DIFF_VIEWER_CMD_NEXT_HUNK: &str = "diff-viewer-next-hunk";
+pub const DIFF_VIEWER_CMD_PREV_HUNK: &str = "diff-viewer-prev-hunk";
+
+// -- dual list transfer
+
+pub const DUAL_LIST_TRANSFER_CMD_MOVE_ALL_LEFT: &str = "dual-list-transfer-move-all-left";
+pub const DUAL_LIST_TRANSFER_CMD_MOVE_ALL_RIGHT: &str = "dual-list-transfer-move-all-right";
+pub const DUAL_LIST_TRANSFER_CMD_SWITCH_PANE: &str = "dual-list-transfer-switch-pane";
+
+// -- file explorer
+
+pub const FILE_EXPLORER_CMD_GO_UP: &str = "file-explorer-go-up";
+pub const FILE_EXPLORER_CMD_TOGGLE_HIDDEN: &str = "file-explorer-toggle-hidden";
+
+// -- form
+
+pub const FORM_CMD_NEXT_FIELD: &str = "form-next-field";
+pub const FORM_CMD_PREV_FIELD: &str = "form-prev-field";
+
+// -- gantt
+
+pub const GANTT_CMD_ZOOM_IN: &str = "gantt-zoom-in";
+pub const GANTT_CMD_ZOOM_OUT: &str = "gantt-zoom-out";
+
+// -- grid
+
+pub const GRID_CMD_NEXT_CELL: &str = "grid-next-cell";
+pub const GRID_CMD_PREV_CELL: &str = "grid-prev-cell";
+
+// -- help overlay
+
+pub const HELP_OVERLAY_DISMISSED: &str = "help-overlay-dismissed";
+
+// -- input
+
+pub const INPUT_CMD_COPY: &str = "input-copy";
+pub const INPUT_CMD_CUT: &str = "input-cut";
+pub const INPUT_CMD_PASTE: &str = "input-paste";
+
+// -- json viewer
+
+pub const JSON_VIEWER_CMD_COPY_PATH: &str = "json-viewer-copy-path";
+
+// -- kanban
+
+pub const KANBAN_CMD_MOVE_CARD_DOWN: &str = "kanban-move-card-down";
+pub const KANBAN_CMD_MOVE_CARD_LEFT: &str = "kanban-move-card-left";
+pub const KANBAN_CMD_MOVE_CARD_RIGHT: &str = "kanban-move-card-right";
+pub const KANBAN_CMD_MOVE_CARD_UP: &str = "kanban-move-card-up";
+
+// -- markdown
+
+pub const MARKDOWN_CMD_NEXT_LINK: &str = "markdown-next-link";
+pub const MARKDOWN_CMD_PREV_LINK: &str = "markdown-prev-link";
+
+// -- outline
+
+pub const OUTLINE_CMD_COLLAPSE_LEVEL: &str = "outline-collapse-level";
+pub const OUTLINE_CMD_EXPAND_ALL: &str = "outline-expand-all";
+
+// -- search box
+
+pub const SEARCH_BOX_CMD_CLEAR: &str = "search-box-clear";
+
+// -- split pane
+
+pub const SPLIT_PANE_CMD_COLLAPSE_FIRST: &str = "split-pane-collapse-first";
+pub const SPLIT_PANE_CMD_COLLAPSE_SECOND: &str = "split-pane-collapse-second";
+pub const SPLIT_PANE_CMD_GROW_FIRST: &str = "split-pane-grow-first";
+pub const SPLIT_PANE_CMD_SHRINK_FIRST: &str = "split-pane-shrink-first";
+pub const SPLIT_PANE_CMD_SWITCH_PANE: &str = "split-pane-switch-pane";
+
+// -- tag input
+
+pub const TAG_INPUT_CMD_COMPLETE: &str = "tag-input-complete";
+
+// -- textarea
+
+pub const TEXTAREA_CMD_COPY: &str = "textarea-copy";
+
+// -- timer
+
+pub const TIMER_CMD_PAUSE: &str = "timer-pause";
+pub const TIMER_CMD_RESET: &str = "timer-reset";
+pub const TIMER_CMD_START: &str = "timer-start";
+pub const TIMER_EXPIRED: &str = "timer-expired";
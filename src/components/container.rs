@@ -5,22 +5,122 @@
 //! The way it updates properties is usually assigning the attributes to all the children components, but
 //! when defining the component you can override these behaviours implementing `attr()` by yourself.
 //! By default it will forward `Commands' to all the children and will return a `CmdResult::Batch` with all the results.
+//! If a child is focused, it is the only one receiving focus and commands instead.
+//! The container can be collapsed to its title bar, hiding the children without unmounting them.
+//! Alternative layouts can be given for breakpoints, to adapt the layout to the size of the area.
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::props::{Alignment, AttrValue, Attribute, Borders, Color, Layout, Props};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, Layout, PropPayload, PropValue, Props, Style,
+};
 use tuirealm::ratatui::layout::Rect;
-use tuirealm::{Frame, MockComponent, State};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{
+    CONTAINER_CMD_FOCUS_NEXT, CONTAINER_CMD_FOCUS_PREV, CONTAINER_CMD_TOGGLE_COLLAPSE,
+};
+use super::props::{CONTAINER_COLLAPSED, CONTAINER_FOCUS, TOOLTIP};
+
+/// ## ContainerBreakpoint
+///
+/// An alternative layout of the `Container`, used when its area is at least as large as the breakpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerBreakpoint {
+    /// Minimum width of the area, inside of the borders
+    pub min_width: u16,
+    /// Minimum height of the area, inside of the borders
+    pub min_height: u16,
+    pub layout: Layout,
+}
+
+impl ContainerBreakpoint {
+    pub fn new(layout: Layout) -> Self {
+        Self {
+            min_width: 0,
+            min_height: 0,
+            layout,
+        }
+    }
+
+    pub fn min_width(mut self, width: u16) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    pub fn min_height(mut self, height: u16) -> Self {
+        self.min_height = height;
+        self
+    }
+
+    /// ### fits
+    ///
+    /// Whether `area` is large enough for the breakpoint
+    fn fits(&self, area: Rect) -> bool {
+        area.width >= self.min_width && area.height >= self.min_height
+    }
+}
+
+// -- states
+
+/// ## ContainerStates
+///
+/// ContainerStates contains states for this component
+#[derive(Default)]
+pub struct ContainerStates {
+    /// Index of the focused child, receiving focus and commands. If `None`, they are forwarded to all the children
+    pub focus: Option<usize>,
+    /// Whether the container is collapsed to its title bar
+    pub collapsed: bool,
+    /// Index of the breakpoint whose layout was used by the last render, if any
+    pub breakpoint: Option<usize>,
+}
+
+impl ContainerStates {
+    /// ### focus_next
+    ///
+    /// Focus the next child out of `len`, going back to the first one after the last one
+    pub fn focus_next(&mut self, len: usize) {
+        self.focus = match self.focus {
+            _ if len == 0 => None,
+            Some(i) if i + 1 < len => Some(i + 1),
+            _ => Some(0),
+        };
+    }
+
+    /// ### focus_prev
+    ///
+    /// Focus the previous child out of `len`, going to the last one before the first one
+    pub fn focus_prev(&mut self, len: usize) {
+        self.focus = match self.focus {
+            _ if len == 0 => None,
+            Some(i) if i > 0 && i < len => Some(i - 1),
+            _ => Some(len - 1),
+        };
+    }
+}
 
 // -- Component
 
 /// ## Container
 ///
-/// represents a read-only text component without any container.
+/// A container of components, rendered in the chunks of its layout, in order. Attributes are applied to all the
+/// children; commands too, unless a child is focused: then it's the only one receiving focus and commands, and its
+/// state is the state of the container. The container can be collapsed to its title bar, on the first row of its area;
+/// children are then neither rendered, nor receive commands. The layout can change with the size of the area, with
+/// breakpoints: the first one the area fits in is used, otherwise the `Layout` attribute
 #[derive(Default)]
 pub struct Container {
     props: Props,
+    pub states: ContainerStates,
     /// Container children
     pub children: Vec<Box<dyn MockComponent>>,
+    /// Alternative layouts, by priority
+    pub breakpoints: Vec<ContainerBreakpoint>,
 }
 
 impl Container {
@@ -44,6 +144,28 @@ impl Container {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `container` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("container", self);
+    }
+
     pub fn layout(mut self, layout: Layout) -> Self {
         self.attr(Attribute::Layout, AttrValue::Layout(layout));
         self
@@ -51,8 +173,69 @@ impl Container {
 
     pub fn children(mut self, children: Vec<Box<dyn MockComponent>>) -> Self {
         self.children = children;
+        self.focus_children();
         self
     }
+
+    /// Add an alternative layout, used if the area fits it and doesn't fit the breakpoints added before
+    pub fn breakpoint(mut self, breakpoint: ContainerBreakpoint) -> Self {
+        self.breakpoints.push(breakpoint);
+        self
+    }
+
+    /// Add a child, rendered in the next chunk of the layout
+    pub fn child(mut self, child: Box<dyn MockComponent>) -> Self {
+        self.children.push(child);
+        self.focus_children();
+        self
+    }
+
+    /// Focus the child at `index`: it will be the only one receiving focus and commands
+    pub fn focused(mut self, index: usize) -> Self {
+        self.attr(
+            Attribute::Custom(CONTAINER_FOCUS),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(index))),
+        );
+        self
+    }
+
+    /// Collapse the container to its title bar
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.attr(
+            Attribute::Custom(CONTAINER_COLLAPSED),
+            AttrValue::Flag(collapsed),
+        );
+        self
+    }
+
+    /// ### layout_for
+    ///
+    /// Get the layout to use in `area`, with the index of its breakpoint, if any
+    fn layout_for(&self, area: Rect) -> Option<(Option<usize>, Layout)> {
+        match self.breakpoints.iter().position(|x| x.fits(area)) {
+            Some(i) => Some((Some(i), self.breakpoints[i].layout.clone())),
+            None => self
+                .props
+                .get(Attribute::Layout)
+                .map(|x| (None, x.unwrap_layout())),
+        }
+    }
+
+    /// ### focus_children
+    ///
+    /// If a child is focused, give it focus, if the container is focused, and remove it from the other ones
+    fn focus_children(&mut self) {
+        let Some(focused) = self.states.focus else {
+            return;
+        };
+        let focus = self
+            .props
+            .get_or(Attribute::Focus, AttrValue::Flag(false))
+            .unwrap_flag();
+        for (i, child) in self.children.iter_mut().enumerate() {
+            child.attr(Attribute::Focus, AttrValue::Flag(focus && i == focused));
+        }
+    }
 }
 
 impl MockComponent for Container {
@@ -64,14 +247,44 @@ impl MockComponent for Container {
                 .props
                 .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
                 .unwrap_borders();
-            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
-            let div = crate::utils::get_block(borders, title, true, None);
+            let mut title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let style = Style::default().fg(foreground).bg(background);
+            if self.states.collapsed {
+                // Render the title bar only
+                let (text, alignment) = title.unwrap_or((String::new(), Alignment::Left));
+                title = Some((format!("▸ {text}"), alignment));
+                let div = get_block(borders, title, true, None)
+                    .title_style(get_title_style(&self.props))
+                    .style(style);
+                let area = Rect {
+                    height: area.height.min(1),
+                    ..area
+                };
+                render.render_widget(div, area);
+                render_disabled(&self.props, render.buffer_mut(), area);
+                render_tooltip(&self.props, render.buffer_mut(), area);
+                return;
+            }
+            let div = get_block(borders, title, true, None)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = div.inner(area);
             // Render block
             render.render_widget(div, area);
             // Render children
-            if let Some(layout) = self.props.get(Attribute::Layout).map(|x| x.unwrap_layout()) {
-                // make chunks
-                let chunks = layout.chunks(area);
+            let layout = self.layout_for(inner);
+            self.states.breakpoint = layout.as_ref().and_then(|(breakpoint, _)| *breakpoint);
+            if let Some((_, layout)) = layout {
+                // make chunks, inside of the borders
+                let chunks = layout.chunks(inner);
                 // iter chunks
                 for (i, chunk) in chunks.into_iter().enumerate() {
                     if let Some(child) = self.children.get_mut(i) {
@@ -80,6 +293,8 @@ impl MockComponent for Container {
                 }
             }
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -88,19 +303,68 @@ impl MockComponent for Container {
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
         self.props.set(attr, value.clone());
-        // Patch attribute to children
-        self.children
-            .iter_mut()
-            .for_each(|x| x.attr(attr, value.clone()));
+        match attr {
+            Attribute::Custom(CONTAINER_FOCUS) => {
+                if let AttrValue::Payload(PropPayload::One(PropValue::Usize(index))) = value {
+                    self.states.focus = Some(index);
+                    self.focus_children();
+                }
+            }
+            Attribute::Custom(CONTAINER_COLLAPSED) => {
+                self.states.collapsed = value.unwrap_flag();
+            }
+            Attribute::Focus if self.states.focus.is_some() => self.focus_children(),
+            // Patch attribute to children
+            _ => self
+                .children
+                .iter_mut()
+                .for_each(|x| x.attr(attr, value.clone())),
+        }
     }
 
     fn state(&self) -> State {
-        State::None
+        match self.states.focus.and_then(|i| self.children.get(i)) {
+            Some(child) => child.state(),
+            None => State::None,
+        }
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
-        // Send command to children and return batch
-        CmdResult::Batch(self.children.iter_mut().map(|x| x.perform(cmd)).collect())
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let len = self.children.len();
+        match cmd {
+            Cmd::Custom(CONTAINER_CMD_TOGGLE_COLLAPSE) => {
+                let collapsed = !self.states.collapsed;
+                self.attr(
+                    Attribute::Custom(CONTAINER_COLLAPSED),
+                    AttrValue::Flag(collapsed),
+                );
+                // Report whether the container is expanded
+                return CmdResult::Changed(State::One(StateValue::Bool(!collapsed)));
+            }
+            // Children are hidden
+            _ if self.states.collapsed => return CmdResult::None,
+            Cmd::Custom(CONTAINER_CMD_FOCUS_NEXT) => self.states.focus_next(len),
+            Cmd::Custom(CONTAINER_CMD_FOCUS_PREV) => self.states.focus_prev(len),
+            // Send command to the focused child
+            cmd if self.states.focus.is_some() => {
+                return match self.states.focus.and_then(|i| self.children.get_mut(i)) {
+                    Some(child) => child.perform(cmd),
+                    None => CmdResult::None,
+                };
+            }
+            // Send command to children and return batch
+            cmd => {
+                return CmdResult::Batch(self.children.iter_mut().map(|x| x.perform(cmd)).collect())
+            }
+        }
+        self.focus_children();
+        match self.states.focus {
+            Some(focus) => CmdResult::Changed(State::One(StateValue::Usize(focus))),
+            None => CmdResult::None,
+        }
     }
 }
 
@@ -108,8 +372,11 @@ impl MockComponent for Container {
 mod tests {
 
     use super::*;
+    use crate::components::Radio;
 
     use pretty_assertions::assert_eq;
+    use tuirealm::command::Direction;
+    use tuirealm::ratatui::layout::{Constraint, Direction as LayoutDirection};
 
     #[test]
     fn test_components_paragraph() {
@@ -120,4 +387,122 @@ mod tests {
         // Get value
         assert_eq!(component.state(), State::None);
     }
+
+    fn is_focused(child: &dyn MockComponent) -> bool {
+        child
+            .query(Attribute::Focus)
+            .map(|x| x.unwrap_flag())
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_components_container_children() {
+        let mut component = Container::default()
+            .child(Box::new(Radio::default().choices(&["a", "b"])))
+            .child(Box::new(Radio::default().choices(&["c", "d"])));
+        // Without a focused child, focus and commands go to all children
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        assert!(component.children.iter().all(|x| is_focused(x.as_ref())));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Batch(vec![
+                CmdResult::Changed(State::One(StateValue::Usize(1))),
+                CmdResult::Changed(State::One(StateValue::Usize(1))),
+            ])
+        );
+        assert_eq!(component.state(), State::None);
+        // Focus a child
+        let mut component = component.focused(0);
+        assert!(is_focused(component.children[0].as_ref()));
+        assert!(!is_focused(component.children[1].as_ref()));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.children[1].state(),
+            State::One(StateValue::Usize(1))
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(CONTAINER_CMD_FOCUS_NEXT)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert!(!is_focused(component.children[0].as_ref()));
+        assert!(is_focused(component.children[1].as_ref()));
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+        assert_eq!(
+            component.perform(Cmd::Custom(CONTAINER_CMD_FOCUS_NEXT)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(CONTAINER_CMD_FOCUS_PREV)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        // Blur
+        component.attr(Attribute::Focus, AttrValue::Flag(false));
+        assert!(component.children.iter().all(|x| !is_focused(x.as_ref())));
+    }
+
+    #[test]
+    fn test_components_container_collapse() {
+        let mut component = Container::default()
+            .child(Box::new(Radio::default().choices(&["a", "b"])))
+            .collapsed(true);
+        assert!(component.states.collapsed);
+        // Commands don't reach hidden children
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(CONTAINER_CMD_TOGGLE_COLLAPSE)),
+            CmdResult::Changed(State::One(StateValue::Bool(true)))
+        );
+        assert_eq!(
+            component.query(Attribute::Custom(CONTAINER_COLLAPSED)),
+            Some(AttrValue::Flag(false))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Batch(vec![CmdResult::Changed(State::One(StateValue::Usize(1)))])
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(CONTAINER_CMD_TOGGLE_COLLAPSE)),
+            CmdResult::Changed(State::One(StateValue::Bool(false)))
+        );
+        assert!(component.states.collapsed);
+        // The collapsed flag isn't patched to children
+        assert_eq!(
+            component.children[0].query(Attribute::Custom(CONTAINER_COLLAPSED)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_components_container_breakpoints() {
+        let stacked = Layout::default()
+            .direction(LayoutDirection::Vertical)
+            .constraints(&[Constraint::Length(3), Constraint::Fill(1)]);
+        let side_by_side = Layout::default()
+            .direction(LayoutDirection::Horizontal)
+            .constraints(&[Constraint::Percentage(50), Constraint::Percentage(50)]);
+        let component = Container::default()
+            .layout(stacked.clone())
+            .breakpoint(ContainerBreakpoint::new(side_by_side.clone()).min_width(120));
+        assert_eq!(
+            component.layout_for(Rect::new(0, 0, 120, 10)),
+            Some((Some(0), side_by_side.clone()))
+        );
+        assert_eq!(
+            component.layout_for(Rect::new(0, 0, 119, 10)),
+            Some((None, stacked))
+        );
+        // Without layout
+        let component = Container::default().breakpoint(
+            ContainerBreakpoint::new(side_by_side)
+                .min_width(120)
+                .min_height(20),
+        );
+        assert_eq!(component.layout_for(Rect::new(0, 0, 120, 10)), None);
+    }
 }
@@ -0,0 +1,584 @@
+//! ## DatePicker
+//!
+//! `DatePicker` is a text field to enter a date, with a month grid popup to pick it
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, BorderSides, Borders, Color, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Block, Paragraph},
+};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use super::calendar::{render_month, MONTH_HEIGHT};
+use crate::theme::Theme;
+use crate::utils::{
+    get_title_style, is_disabled, popup_rect, render_disabled, render_tooltip, Date,
+};
+
+// -- Props
+
+use super::commands::{DATE_PICKER_CMD_NEXT_YEAR, DATE_PICKER_CMD_PREV_YEAR};
+use super::props::{DATE_PICKER_MAX_DATE, DATE_PICKER_MIN_DATE, TOOLTIP};
+
+/// Max length of a typed date (`YYYY-MM-DD`)
+const INPUT_MAX_LEN: usize = 10;
+
+// -- states
+
+/// ## DatePickerStates
+///
+/// DatePicker states: the selected date, the typed text and the cursor in the popup, which is set only while the popup is open
+#[derive(Default)]
+pub struct DatePickerStates {
+    pub value: Option<Date>,
+    pub input: String,
+    pub cursor: Option<Date>,
+}
+
+impl DatePickerStates {
+    /// ### is_open
+    ///
+    /// Returns whether the popup is open
+    pub fn is_open(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// ### open
+    ///
+    /// Open the popup, with the cursor on the selected date or on `today`
+    pub fn open(&mut self, today: Date, min: Option<Date>, max: Option<Date>) {
+        self.cursor = Some(clamp_date(self.value.unwrap_or(today), min, max));
+    }
+
+    /// ### close
+    ///
+    /// Close the popup, discarding the cursor
+    pub fn close(&mut self) {
+        self.cursor = None;
+    }
+
+    /// ### submit
+    ///
+    /// Select the date under the cursor and close the popup
+    pub fn submit(&mut self) {
+        if let Some(cursor) = self.cursor.take() {
+            self.value = Some(cursor);
+        }
+    }
+
+    /// ### move_cursor
+    ///
+    /// Move the cursor with `f`, keeping it in range. Returns whether the cursor has moved
+    pub fn move_cursor<F>(&mut self, f: F, min: Option<Date>, max: Option<Date>) -> bool
+    where
+        F: Fn(&Date) -> Date,
+    {
+        match self.cursor {
+            Some(cursor) => {
+                let moved = clamp_date(f(&cursor), min, max);
+                self.cursor = Some(moved);
+                moved != cursor
+            }
+            None => false,
+        }
+    }
+
+    /// ### type_char
+    ///
+    /// Type a character of the date; only digits and `-` are accepted
+    pub fn type_char(&mut self, ch: char) {
+        if (ch.is_ascii_digit() || ch == '-') && self.input.len() < INPUT_MAX_LEN {
+            self.input.push(ch);
+        }
+    }
+
+    /// ### backspace
+    ///
+    /// Delete the last typed character
+    pub fn backspace(&mut self) {
+        self.input.pop();
+    }
+
+    /// ### commit_input
+    ///
+    /// Parse the typed text and select it, if it's a valid date in range. Returns whether the date has been selected
+    pub fn commit_input(&mut self, min: Option<Date>, max: Option<Date>) -> bool {
+        match Date::parse(&self.input).filter(|x| clamp_date(*x, min, max) == *x) {
+            Some(date) => {
+                self.value = Some(date);
+                self.input.clear();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// ### clamp_date
+///
+/// Keep `date` between `min` and `max`
+fn clamp_date(date: Date, min: Option<Date>, max: Option<Date>) -> Date {
+    let date = min.map(|min| date.max(min)).unwrap_or(date);
+    max.map(|max| date.min(max)).unwrap_or(date)
+}
+
+// -- component
+
+/// ## DatePicker
+///
+/// A text field where a date can be typed as `YYYY-MM-DD`; submitting an empty field opens a popup with a month grid,
+/// where the date can be picked with arrows. Dates out of the min and max constraints can't be selected.
+#[derive(Default)]
+pub struct DatePicker {
+    props: Props,
+    pub states: DatePickerStates,
+}
+
+impl DatePicker {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `date_picker` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("date_picker", self);
+    }
+
+    /// Set the selected date
+    pub fn value(mut self, date: Date) -> Self {
+        self.attr(Attribute::Value, AttrValue::String(date.to_string()));
+        self
+    }
+
+    /// Set the earliest date which can be selected
+    pub fn min_date(mut self, date: Date) -> Self {
+        self.attr(
+            Attribute::Custom(DATE_PICKER_MIN_DATE),
+            AttrValue::String(date.to_string()),
+        );
+        self
+    }
+
+    /// Set the latest date which can be selected
+    pub fn max_date(mut self, date: Date) -> Self {
+        self.attr(
+            Attribute::Custom(DATE_PICKER_MAX_DATE),
+            AttrValue::String(date.to_string()),
+        );
+        self
+    }
+
+    fn get_date(&self, attr: Attribute) -> Option<Date> {
+        self.props
+            .get(attr)
+            .and_then(|x| Date::parse(&x.unwrap_string()))
+    }
+
+    fn get_bounds(&self) -> (Option<Date>, Option<Date>) {
+        (
+            self.get_date(Attribute::Custom(DATE_PICKER_MIN_DATE)),
+            self.get_date(Attribute::Custom(DATE_PICKER_MAX_DATE)),
+        )
+    }
+
+    fn cursor_changed(&self, moved: bool) -> CmdResult {
+        match (moved, self.states.cursor) {
+            (true, Some(cursor)) => {
+                CmdResult::Changed(State::One(StateValue::String(cursor.to_string())))
+            }
+            _ => CmdResult::None,
+        }
+    }
+
+    /// ### field_text
+    ///
+    /// Get the text of the field: the typed text, if any, otherwise the selected date
+    fn field_text(&self) -> String {
+        match (self.states.input.is_empty(), self.states.value) {
+            (false, _) => self.states.input.clone(),
+            (true, Some(value)) => value.to_string(),
+            (true, None) => String::new(),
+        }
+    }
+
+    /// ### render_month
+    ///
+    /// Render the month grid of the cursor into `area`
+    fn render_month(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let Some(cursor) = self.states.cursor else {
+            return;
+        };
+        let (min, max) = self.get_bounds();
+        let highlighted = self
+            .props
+            .get_or(
+                Attribute::HighlightedColor,
+                AttrValue::Color(style.fg.unwrap_or(Color::Reset)),
+            )
+            .unwrap_color();
+        let today = Date::today();
+        render_month(buf, area, cursor, true, style, |date| {
+            if date == cursor {
+                Style::default()
+                    .fg(highlighted)
+                    .add_modifier(TextModifiers::REVERSED)
+            } else if clamp_date(date, min, max) != date {
+                style.add_modifier(TextModifiers::DIM)
+            } else if date == today {
+                style.add_modifier(TextModifiers::UNDERLINED)
+            } else {
+                style
+            }
+        });
+    }
+
+    fn get_styles(&self) -> (Style, Style, Borders) {
+        let foreground = self
+            .props
+            .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+            .unwrap_color();
+        let background = self
+            .props
+            .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+            .unwrap_color();
+        let focus = self
+            .props
+            .get_or(Attribute::Focus, AttrValue::Flag(false))
+            .unwrap_flag();
+        let inactive_style = self
+            .props
+            .get(Attribute::FocusStyle)
+            .map(|x| x.unwrap_style());
+        let borders = self
+            .props
+            .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+            .unwrap_borders();
+        let (style, borders_style) = match focus {
+            true => (
+                Style::default().fg(foreground).bg(background),
+                borders.style(),
+            ),
+            false => (
+                inactive_style.unwrap_or_default(),
+                inactive_style.unwrap_or_default(),
+            ),
+        };
+        (style, borders_style, borders)
+    }
+}
+
+impl MockComponent for DatePicker {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let (style, borders_style, borders) = self.get_styles();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let field_sides = match self.states.is_open() {
+                true => BorderSides::LEFT | BorderSides::TOP | BorderSides::RIGHT,
+                false => BorderSides::ALL,
+            };
+            let block = Block::default()
+                .borders(field_sides)
+                .border_style(borders_style)
+                .border_type(borders.modifiers)
+                .style(style);
+            let block = match title {
+                Some((text, alignment)) => block
+                    .title(text)
+                    .title_alignment(alignment)
+                    .title_style(get_title_style(&self.props)),
+                None => block,
+            };
+            let text = self.field_text();
+            let field = match text.is_empty() {
+                true => Paragraph::new("YYYY-MM-DD").style(style.add_modifier(TextModifiers::DIM)),
+                false => Paragraph::new(text).style(style),
+            };
+            if !self.states.is_open() {
+                render.render_widget(field.block(block), area);
+                render_disabled(&self.props, render.buffer_mut(), area);
+                render_tooltip(&self.props, render.buffer_mut(), area);
+                return;
+            }
+            let field_area = Rect {
+                height: area.height.min(2),
+                ..area
+            };
+            let popup_area = popup_rect(field_area, field_area.width, MONTH_HEIGHT + 1, area);
+            render.render_widget(field.block(block), field_area);
+            let popup = Block::default()
+                .borders(BorderSides::LEFT | BorderSides::BOTTOM | BorderSides::RIGHT)
+                .border_style(borders_style)
+                .border_type(borders.modifiers)
+                .style(style);
+            let inner = popup.inner(popup_area);
+            render.render_widget(popup, popup_area);
+            self.render_month(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if matches!(attr, Attribute::Value) {
+            self.states.value = self.get_date(Attribute::Value);
+        }
+    }
+
+    fn state(&self) -> State {
+        match (self.states.is_open(), self.states.value) {
+            (false, Some(value)) => State::One(StateValue::String(value.to_string())),
+            _ => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let (min, max) = self.get_bounds();
+        if !self.states.is_open() {
+            return match cmd {
+                Cmd::Type(ch) => {
+                    self.states.type_char(ch);
+                    CmdResult::None
+                }
+                Cmd::Delete => {
+                    self.states.backspace();
+                    CmdResult::None
+                }
+                Cmd::Submit if self.states.input.is_empty() => {
+                    self.states.open(Date::today(), min, max);
+                    CmdResult::None
+                }
+                Cmd::Submit => match self.states.commit_input(min, max) {
+                    true => CmdResult::Submit(self.state()),
+                    false => CmdResult::None,
+                },
+                _ => CmdResult::None,
+            };
+        }
+        match cmd {
+            Cmd::Move(Direction::Left) => {
+                let moved = self.states.move_cursor(|x| x.add_days(-1), min, max);
+                self.cursor_changed(moved)
+            }
+            Cmd::Move(Direction::Right) => {
+                let moved = self.states.move_cursor(|x| x.add_days(1), min, max);
+                self.cursor_changed(moved)
+            }
+            Cmd::Move(Direction::Up) => {
+                let moved = self.states.move_cursor(|x| x.add_days(-7), min, max);
+                self.cursor_changed(moved)
+            }
+            Cmd::Move(Direction::Down) => {
+                let moved = self.states.move_cursor(|x| x.add_days(7), min, max);
+                self.cursor_changed(moved)
+            }
+            Cmd::Scroll(Direction::Up) => {
+                let moved = self.states.move_cursor(|x| x.add_months(-1), min, max);
+                self.cursor_changed(moved)
+            }
+            Cmd::Scroll(Direction::Down) => {
+                let moved = self.states.move_cursor(|x| x.add_months(1), min, max);
+                self.cursor_changed(moved)
+            }
+            Cmd::Custom(DATE_PICKER_CMD_PREV_YEAR) => {
+                let moved = self.states.move_cursor(|x| x.add_months(-12), min, max);
+                self.cursor_changed(moved)
+            }
+            Cmd::Custom(DATE_PICKER_CMD_NEXT_YEAR) => {
+                let moved = self.states.move_cursor(|x| x.add_months(12), min, max);
+                self.cursor_changed(moved)
+            }
+            Cmd::GoTo(Position::Begin) => {
+                let moved = self.states.move_cursor(|x| x.first_of_month(), min, max);
+                self.cursor_changed(moved)
+            }
+            Cmd::GoTo(Position::End) => {
+                let moved = self.states.move_cursor(
+                    |x| Date {
+                        day: Date::days_in_month(x.year, x.month),
+                        ..*x
+                    },
+                    min,
+                    max,
+                );
+                self.cursor_changed(moved)
+            }
+            Cmd::Submit => {
+                self.states.submit();
+                CmdResult::Submit(self.state())
+            }
+            Cmd::Cancel => {
+                self.states.close();
+                CmdResult::Changed(self.state())
+            }
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn date(s: &str) -> Date {
+        Date::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_components_date_picker_popup() {
+        let mut component = DatePicker::default()
+            .title("Departure", Alignment::Left)
+            .borders(Borders::default())
+            .highlighted_color(Color::LightYellow)
+            .min_date(date("2024-02-10"))
+            .max_date(date("2024-03-20"))
+            .value(date("2024-02-29"));
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(String::from("2024-02-29")))
+        );
+        // Open popup
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert!(component.states.is_open());
+        assert_eq!(component.state(), State::None);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("2024-03-01"))))
+        );
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.states.cursor, Some(date("2024-03-08")));
+        // Clamped to max
+        component.perform(Cmd::Scroll(Direction::Down));
+        assert_eq!(component.states.cursor, Some(date("2024-03-20")));
+        assert_eq!(
+            component.perform(Cmd::Custom(DATE_PICKER_CMD_NEXT_YEAR)),
+            CmdResult::None
+        );
+        // Clamped to min
+        component.perform(Cmd::Custom(DATE_PICKER_CMD_PREV_YEAR));
+        assert_eq!(component.states.cursor, Some(date("2024-02-10")));
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.cursor, Some(date("2024-02-29")));
+        component.perform(Cmd::Move(Direction::Up));
+        // Cancel keeps value
+        assert_eq!(
+            component.perform(Cmd::Cancel),
+            CmdResult::Changed(State::One(StateValue::String(String::from("2024-02-29"))))
+        );
+        assert!(!component.states.is_open());
+        // Submit
+        component.perform(Cmd::Submit);
+        component.perform(Cmd::Scroll(Direction::Up));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::String(String::from("2024-02-10"))))
+        );
+        assert!(!component.states.is_open());
+    }
+
+    #[test]
+    fn test_components_date_picker_input() {
+        let mut component = DatePicker::default().max_date(date("2024-12-31"));
+        assert_eq!(component.state(), State::None);
+        for ch in "2024-0x6-15".chars() {
+            component.perform(Cmd::Type(ch));
+        }
+        assert_eq!(component.states.input, "2024-06-15");
+        assert_eq!(component.field_text(), "2024-06-15");
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::String(String::from("2024-06-15"))))
+        );
+        assert!(component.states.input.is_empty());
+        // Out of range and invalid dates
+        for ch in "2025-01-01".chars() {
+            component.perform(Cmd::Type(ch));
+        }
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        component.perform(Cmd::Delete);
+        component.perform(Cmd::Delete);
+        for ch in "99".chars() {
+            component.perform(Cmd::Type(ch));
+        }
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(component.field_text(), "2025-01-99");
+        assert_eq!(component.states.value, Some(date("2024-06-15")));
+    }
+
+    #[test]
+    fn test_components_date_picker_month() {
+        let mut component = DatePicker::default()
+            .min_date(date("2024-02-03"))
+            .value(date("2024-02-29"));
+        component.perform(Cmd::Submit);
+        let area = Rect::new(0, 0, 20, 8);
+        let mut buf = Buffer::empty(area);
+        component.render_month(&mut buf, area, Style::default());
+        let row =
+            |y: u16| -> String { (0..20).map(|x| buf[(x, y)].symbol().to_string()).collect() };
+        assert_eq!(row(0), " ◀ February 2024 ▶  ");
+        assert_eq!(row(1), "Mo Tu We Th Fr Sa Su");
+        assert_eq!(row(2), "          1  2  3  4");
+        assert_eq!(row(6), "26 27 28 29         ");
+        assert!(buf[(9, 6)].modifier.contains(TextModifiers::REVERSED));
+        assert!(buf[(12, 2)].modifier.contains(TextModifiers::DIM));
+        assert!(!buf[(15, 2)].modifier.contains(TextModifiers::DIM));
+    }
+}
@@ -0,0 +1,402 @@
+//! ## Dial
+//!
+//! A semicircular gauge with a needle
+
+use std::f64::consts::PI;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    symbols::Marker,
+    widgets::{
+        canvas::{Canvas as TuiCanvas, Line, Points},
+        Widget,
+    },
+};
+use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, render_disabled, render_tooltip, FocusStyles};
+
+// -- Props
+
+use super::props::{DIAL_NEEDLE_COLOR, DIAL_RANGE, DIAL_ZONES, TOOLTIP};
+
+/// Inner radius of the arc, as a fraction of the dial radius
+const ARC_INNER_RADIUS: f64 = 0.75;
+/// Length of the needle, as a fraction of the dial radius
+const NEEDLE_LENGTH: f64 = 0.9;
+
+// -- component
+
+/// ## Dial
+///
+/// A component to display a value on a semicircular gauge drawn with braille characters, going from the minimum on the left
+/// to the maximum on the right. The arc can be split into colored zones; a needle points to the current value.
+#[derive(Default)]
+pub struct Dial {
+    props: Props,
+}
+
+impl Dial {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `dial` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("dial", self);
+    }
+
+    /// Set the label displayed under the needle. If not set, the value is displayed
+    pub fn label<S: Into<String>>(mut self, s: S) -> Self {
+        self.attr(Attribute::Text, AttrValue::String(s.into()));
+        self
+    }
+
+    /// Set the value the needle points to. Values out of range are clamped
+    pub fn value(mut self, v: f64) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::F64(v))),
+        );
+        self
+    }
+
+    /// Set the range of values (default: 0 to 100)
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.attr(
+            Attribute::Custom(DIAL_RANGE),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::F64(min),
+                PropValue::F64(max),
+            ))),
+        );
+        self
+    }
+
+    /// Set colored zones as upper bound and color, sorted by bound. Values above the last bound use the foreground color
+    pub fn zones(mut self, zones: &[(f64, Color)]) -> Self {
+        self.attr(
+            Attribute::Custom(DIAL_ZONES),
+            AttrValue::Payload(PropPayload::Linked(
+                zones
+                    .iter()
+                    .map(|(bound, color)| {
+                        PropPayload::Tup2((PropValue::F64(*bound), PropValue::Color(*color)))
+                    })
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    pub fn needle_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::Custom(DIAL_NEEDLE_COLOR), AttrValue::Color(c));
+        self
+    }
+
+    fn get_value(&self) -> f64 {
+        self.props
+            .get(Attribute::Value)
+            .map(|x| x.unwrap_payload().unwrap_one().unwrap_f64())
+            .unwrap_or(0.0)
+    }
+
+    fn get_range(&self) -> (f64, f64) {
+        self.props
+            .get(Attribute::Custom(DIAL_RANGE))
+            .map(|x| {
+                let (min, max) = x.unwrap_payload().unwrap_tup2();
+                (min.unwrap_f64(), max.unwrap_f64())
+            })
+            .unwrap_or((0.0, 100.0))
+    }
+
+    fn get_zones(&self) -> Vec<(f64, Color)> {
+        self.props
+            .get(Attribute::Custom(DIAL_ZONES))
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_linked()
+                    .into_iter()
+                    .map(|x| {
+                        let (bound, color) = x.unwrap_tup2();
+                        (bound.unwrap_f64(), color.unwrap_color())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// ### value_angle
+    ///
+    /// Get the angle of `value`, from `PI` for the minimum to `0` for the maximum
+    fn value_angle(value: f64, range: (f64, f64)) -> f64 {
+        let (min, max) = range;
+        let ratio = match max > min {
+            true => ((value - min) / (max - min)).clamp(0.0, 1.0),
+            false => 0.0,
+        };
+        PI * (1.0 - ratio)
+    }
+
+    /// ### zone_of
+    ///
+    /// Get the index of the zone containing `value`; values above all bounds get `zones.len()`
+    fn zone_of(value: f64, zones: &[(f64, Color)]) -> usize {
+        zones
+            .iter()
+            .position(|(bound, _)| value <= *bound)
+            .unwrap_or(zones.len())
+    }
+
+    /// ### arc_points
+    ///
+    /// Get the dots of the arc on a grid of `dots` (columns, rows), in the `[-1, 1]x[0, 1]` coordinate space,
+    /// grouped by zone index
+    fn arc_points(
+        range: (f64, f64),
+        zones: &[(f64, Color)],
+        dots: (u16, u16),
+    ) -> Vec<Vec<(f64, f64)>> {
+        let (min, max) = range;
+        let mut points: Vec<Vec<(f64, f64)>> = vec![Vec::new(); zones.len() + 1];
+        let (columns, rows) = dots;
+        for column in 0..columns {
+            for row in 0..rows {
+                let x = -1.0 + (column as f64 + 0.5) * 2.0 / columns as f64;
+                let y = 1.0 - (row as f64 + 0.5) / rows as f64;
+                let radius = x.hypot(y);
+                if !(ARC_INNER_RADIUS..=1.0).contains(&radius) {
+                    continue;
+                }
+                let value = min + (1.0 - y.atan2(x) / PI) * (max - min);
+                points[Self::zone_of(value, zones)].push((x, y));
+            }
+        }
+        points
+    }
+
+    fn fmt_value(value: f64) -> String {
+        match value.fract() == 0.0 {
+            true => format!("{value:.0}"),
+            false => format!("{value:.1}"),
+        }
+    }
+
+    /// ### render_dial
+    ///
+    /// Render the arc, the needle and labels into `area`; the dial keeps round taking the largest area it fits in
+    fn render_dial(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        if area.height < 2 {
+            return;
+        }
+        let foreground = style.fg.unwrap_or(Color::Reset);
+        let range = self.get_range();
+        let value = self.get_value();
+        let zones = self.get_zones();
+        // Each cell has 2x4 braille dots: the dial is round if width is four times height
+        let height = (area.height - 1).min(area.width / 4);
+        let width = height * 4;
+        let dial = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - 1 - height) / 2,
+            width,
+            height,
+        );
+        let arc = Self::arc_points(range, &zones, (dial.width * 2, dial.height * 4));
+        let angle = Self::value_angle(value, range);
+        let needle_color = self
+            .props
+            .get_or(
+                Attribute::Custom(DIAL_NEEDLE_COLOR),
+                AttrValue::Color(foreground),
+            )
+            .unwrap_color();
+        TuiCanvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([-1.0, 1.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|ctx| {
+                for (zone, coords) in arc.iter().enumerate() {
+                    let color = zones.get(zone).map(|(_, x)| *x).unwrap_or(foreground);
+                    ctx.draw(&Points { coords, color });
+                }
+                ctx.layer();
+                ctx.draw(&Line {
+                    x1: 0.0,
+                    y1: 0.0,
+                    x2: angle.cos() * NEEDLE_LENGTH,
+                    y2: angle.sin() * NEEDLE_LENGTH,
+                    color: needle_color,
+                });
+            })
+            .render(dial, buf);
+        // Min, label and max under the dial
+        let y = dial.bottom();
+        let min_label = Self::fmt_value(range.0);
+        let max_label = Self::fmt_value(range.1);
+        let label = self
+            .props
+            .get(Attribute::Text)
+            .map(|x| x.unwrap_string())
+            .unwrap_or_else(|| Self::fmt_value(value));
+        buf.set_stringn(area.x, y, &min_label, area.width as usize, style);
+        let center = area.x + area.width / 2;
+        let label_x = center.saturating_sub(label.width() as u16 / 2).max(area.x);
+        if label_x > area.x + min_label.width() as u16 {
+            buf.set_stringn(label_x, y, &label, (area.right() - label_x) as usize, style);
+        }
+        let max_x = area.right().saturating_sub(max_label.width() as u16);
+        if max_x > label_x + label.width() as u16 {
+            buf.set_string(max_x, y, &max_label, style);
+        }
+    }
+}
+
+impl MockComponent for Dial {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let styles =
+                FocusStyles::display(&self.props, Style::default().fg(foreground).bg(background));
+            let style = styles.style;
+            let div = styles
+                .block(borders, title)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = div.inner(area);
+            render.render_widget(div, area);
+            self.render_dial(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_dial() {
+        let component = Dial::default()
+            .title("cpu temperature", Alignment::Center)
+            .borders(Borders::default())
+            .range(20.0, 100.0)
+            .zones(&[
+                (60.0, Color::Green),
+                (80.0, Color::Yellow),
+                (100.0, Color::Red),
+            ])
+            .needle_color(Color::White)
+            .value(60.0);
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.get_range(), (20.0, 100.0));
+        assert_eq!(component.get_value(), 60.0);
+        assert_eq!(Dial::default().get_range(), (0.0, 100.0));
+        // Angles
+        assert_eq!(Dial::value_angle(20.0, (20.0, 100.0)), PI);
+        assert_eq!(Dial::value_angle(60.0, (20.0, 100.0)), PI / 2.0);
+        assert_eq!(Dial::value_angle(200.0, (20.0, 100.0)), 0.0);
+        // Zones
+        let zones = component.get_zones();
+        assert_eq!(Dial::zone_of(30.0, &zones), 0);
+        assert_eq!(Dial::zone_of(60.0, &zones), 0);
+        assert_eq!(Dial::zone_of(61.0, &zones), 1);
+        assert_eq!(Dial::zone_of(101.0, &zones), 3);
+        // Arc: first zone on the left, last zone on the right
+        let arc = Dial::arc_points((20.0, 100.0), &zones, (40, 20));
+        assert!(arc[0].iter().all(|(x, _)| *x < 0.0));
+        assert!(arc[2].iter().all(|(x, _)| *x > 0.0));
+        assert!(arc[3].is_empty());
+        assert!(arc
+            .iter()
+            .flatten()
+            .all(|(x, y)| (ARC_INNER_RADIUS..=1.0).contains(&x.hypot(*y))));
+        // Labels
+        let area = Rect::new(0, 0, 16, 4);
+        let mut buf = Buffer::empty(area);
+        component.render_dial(&mut buf, area, Style::default());
+        let row =
+            |y: u16| -> String { (0..16).map(|x| buf[(x, y)].symbol().to_string()).collect() };
+        assert_eq!(row(3), "20     60    100");
+        let mut buf = Buffer::empty(area);
+        component
+            .label("hot")
+            .render_dial(&mut buf, area, Style::default());
+        let row =
+            |y: u16| -> String { (0..16).map(|x| buf[(x, y)].symbol().to_string()).collect() };
+        assert_eq!(row(3), "20     hot   100");
+    }
+}
@@ -0,0 +1,750 @@
+//! ## DiffViewer
+//!
+//! `DiffViewer` renders a unified diff, either unified or side by side
+
+use std::ops::Range;
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{DIFF_VIEWER_CMD_NEXT_HUNK, DIFF_VIEWER_CMD_PREV_HUNK};
+use super::props::{
+    DIFF_VIEWER_ADDED_COLOR, DIFF_VIEWER_HUNK_COLOR, DIFF_VIEWER_REMOVED_COLOR,
+    DIFF_VIEWER_SIDE_BY_SIDE, TOOLTIP,
+};
+
+// -- diff
+
+/// ## DiffLineKind
+///
+/// Describes what a line of the diff is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    /// File headers, such as `--- a/file` and `+++ b/file`
+    Header,
+    /// Hunk header, such as `@@ -1,4 +1,5 @@`
+    Hunk,
+    Context,
+    Added,
+    Removed,
+}
+
+/// ## DiffLine
+///
+/// A line of the diff, without its `+`, `-` or ` ` sign
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub text: String,
+    /// Bytes of the text which changed compared to the paired line, for replaced lines
+    pub changed: Option<Range<usize>>,
+}
+
+impl DiffLine {
+    fn new<S: Into<String>>(kind: DiffLineKind, text: S) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            changed: None,
+        }
+    }
+}
+
+/// ### hunk_lengths
+///
+/// Returns the amount of old and new lines of a hunk header such as `@@ -1,4 +1,5 @@`
+fn hunk_lengths(header: &str) -> (usize, usize) {
+    let length = |range: Option<&str>| -> usize {
+        range
+            .and_then(|x| match x.split_once(',') {
+                Some((_, length)) => length.parse().ok(),
+                None => Some(1),
+            })
+            .unwrap_or(0)
+    };
+    let mut ranges = header.trim_start_matches('@').split_whitespace();
+    let old = ranges.next().and_then(|x| x.strip_prefix('-'));
+    let new = ranges.next().and_then(|x| x.strip_prefix('+'));
+    (length(old), length(new))
+}
+
+/// ### changed_range
+///
+/// Returns the bytes which differ between `old` and `new`, after their common prefix and before their common suffix.
+/// If the lines have nothing in common, `None` is returned, since the whole line changed
+fn changed_range(old: &str, new: &str) -> Option<(Range<usize>, Range<usize>)> {
+    let prefix: usize = old
+        .chars()
+        .zip(new.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum();
+    let suffix: usize = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum();
+    match prefix + suffix {
+        0 => None,
+        _ => Some((prefix..old.len() - suffix, prefix..new.len() - suffix)),
+    }
+}
+
+/// ### parse_diff
+///
+/// Parse a unified diff. Removed lines directly followed by added lines are paired to highlight their changes
+pub fn parse_diff(diff: &str) -> Vec<DiffLine> {
+    let mut lines: Vec<DiffLine> = Vec::new();
+    // Old and new lines left in the current hunk
+    let (mut old, mut new) = (0, 0);
+    for line in diff.lines() {
+        let in_hunk = old > 0 || new > 0;
+        let line = match line.chars().next() {
+            Some('@') if line.starts_with("@@") => {
+                (old, new) = hunk_lengths(line);
+                DiffLine::new(DiffLineKind::Hunk, line)
+            }
+            Some('+') if in_hunk => {
+                new = new.saturating_sub(1);
+                DiffLine::new(DiffLineKind::Added, &line[1..])
+            }
+            Some('-') if in_hunk => {
+                old = old.saturating_sub(1);
+                DiffLine::new(DiffLineKind::Removed, &line[1..])
+            }
+            Some(' ') if in_hunk => {
+                old = old.saturating_sub(1);
+                new = new.saturating_sub(1);
+                DiffLine::new(DiffLineKind::Context, &line[1..])
+            }
+            None if in_hunk => {
+                old = old.saturating_sub(1);
+                new = new.saturating_sub(1);
+                DiffLine::new(DiffLineKind::Context, "")
+            }
+            _ => DiffLine::new(DiffLineKind::Header, line),
+        };
+        lines.push(line);
+    }
+    // Pair replaced lines
+    let mut i = 0;
+    while i < lines.len() {
+        let removed = lines[i..]
+            .iter()
+            .take_while(|x| x.kind == DiffLineKind::Removed)
+            .count();
+        let added = lines[i + removed..]
+            .iter()
+            .take_while(|x| x.kind == DiffLineKind::Added)
+            .count();
+        for n in 0..removed.min(added) {
+            let (old, new) = (i + n, i + removed + n);
+            if let Some((old_range, new_range)) = changed_range(&lines[old].text, &lines[new].text)
+            {
+                lines[old].changed = Some(old_range);
+                lines[new].changed = Some(new_range);
+            }
+        }
+        i += (removed + added).max(1);
+    }
+    lines
+}
+
+/// ## DiffRow
+///
+/// A rendered row, referring to lines by index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffRow {
+    /// A line across the whole width
+    Full(usize),
+    /// Old and new line, side by side
+    Split(Option<usize>, Option<usize>),
+}
+
+/// ### make_rows
+///
+/// Lay out the lines in rows; side by side, removed and added lines are aligned
+fn make_rows(lines: &[DiffLine], side_by_side: bool) -> Vec<DiffRow> {
+    if !side_by_side {
+        return (0..lines.len()).map(DiffRow::Full).collect();
+    }
+    let mut rows = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        match lines[i].kind {
+            DiffLineKind::Header | DiffLineKind::Hunk => {
+                rows.push(DiffRow::Full(i));
+                i += 1;
+            }
+            DiffLineKind::Context => {
+                rows.push(DiffRow::Split(Some(i), Some(i)));
+                i += 1;
+            }
+            DiffLineKind::Added | DiffLineKind::Removed => {
+                let removed: Vec<usize> = (i..lines.len())
+                    .take_while(|x| lines[*x].kind == DiffLineKind::Removed)
+                    .collect();
+                let start = i + removed.len();
+                let added: Vec<usize> = (start..lines.len())
+                    .take_while(|x| lines[*x].kind == DiffLineKind::Added)
+                    .collect();
+                for n in 0..removed.len().max(added.len()) {
+                    rows.push(DiffRow::Split(
+                        removed.get(n).copied(),
+                        added.get(n).copied(),
+                    ));
+                }
+                i = start + added.len();
+            }
+        }
+    }
+    rows
+}
+
+// -- states
+
+/// ## DiffViewerStates
+///
+/// DiffViewerStates contains states for this component
+#[derive(Default)]
+pub struct DiffViewerStates {
+    pub lines: Vec<DiffLine>,
+    rows: Vec<DiffRow>,
+    /// Rows of hunk headers
+    hunks: Vec<usize>,
+    /// Current hunk
+    pub hunk: Option<usize>,
+    /// First visible row
+    pub scroll: usize,
+    /// Height of the viewport, as of the last render
+    pub height: usize,
+}
+
+impl DiffViewerStates {
+    /// ### set_diff
+    ///
+    /// Parse a new diff and lay it out
+    pub fn set_diff(&mut self, diff: &str, side_by_side: bool) {
+        self.lines = parse_diff(diff);
+        self.scroll = 0;
+        self.layout(side_by_side);
+    }
+
+    /// ### layout
+    ///
+    /// Lay out the lines in rows, keeping the current hunk at the top
+    pub fn layout(&mut self, side_by_side: bool) {
+        self.rows = make_rows(&self.lines, side_by_side);
+        self.hunks = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| {
+                matches!(row, DiffRow::Full(i) if self.lines[*i].kind == DiffLineKind::Hunk)
+            })
+            .map(|(n, _)| n)
+            .collect();
+        match self.hunk {
+            Some(hunk) => self.go_to_hunk(hunk),
+            None => self.scroll_to(self.scroll),
+        }
+    }
+
+    pub fn max_scroll(&self) -> usize {
+        self.rows.len().saturating_sub(self.height)
+    }
+
+    /// ### scroll_to
+    ///
+    /// Scroll to `row`; the current hunk becomes the one shown at the top
+    pub fn scroll_to(&mut self, row: usize) {
+        self.scroll = row.min(self.max_scroll());
+        self.hunk = self.hunks.iter().rposition(|x| *x <= self.scroll);
+    }
+
+    /// ### go_to_hunk
+    ///
+    /// Make `hunk` the current hunk and scroll to its header
+    pub fn go_to_hunk(&mut self, hunk: usize) {
+        match self.hunks.get(hunk) {
+            Some(row) => {
+                self.scroll = (*row).min(self.max_scroll());
+                self.hunk = Some(hunk);
+            }
+            None => self.hunk = None,
+        }
+    }
+
+    pub fn next_hunk(&mut self) {
+        let hunk = self.hunk.map(|x| x + 1).unwrap_or(0);
+        if hunk < self.hunks.len() {
+            self.go_to_hunk(hunk);
+        }
+    }
+
+    pub fn prev_hunk(&mut self) {
+        if let Some(hunk) = self.hunk {
+            // If the header is above the view, go back to it first
+            match self.hunks[hunk] < self.scroll {
+                true => self.go_to_hunk(hunk),
+                false => self.go_to_hunk(hunk.saturating_sub(1)),
+            }
+        }
+    }
+}
+
+// -- component
+
+/// ## DiffViewer
+///
+/// A viewer for unified diffs: added and removed lines are colored, and the changed part of replaced lines is
+/// highlighted. The diff can be displayed unified or side by side, and navigated by hunks
+#[derive(Default)]
+pub struct DiffViewer {
+    props: Props,
+    pub states: DiffViewerStates,
+}
+
+impl DiffViewer {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `diff_viewer` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("diff_viewer", self);
+    }
+
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    /// Set the unified diff to display
+    pub fn diff<S: Into<String>>(mut self, diff: S) -> Self {
+        self.attr(Attribute::Text, AttrValue::String(diff.into()));
+        self
+    }
+
+    /// Display old and new lines side by side
+    pub fn side_by_side(mut self, side_by_side: bool) -> Self {
+        self.attr(
+            Attribute::Custom(DIFF_VIEWER_SIDE_BY_SIDE),
+            AttrValue::Flag(side_by_side),
+        );
+        self
+    }
+
+    /// Set the color of added lines (default: green)
+    pub fn added_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(DIFF_VIEWER_ADDED_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    /// Set the color of removed lines (default: red)
+    pub fn removed_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(DIFF_VIEWER_REMOVED_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    /// Set the color of hunk headers (default: cyan)
+    pub fn hunk_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(DIFF_VIEWER_HUNK_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    fn is_side_by_side(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(DIFF_VIEWER_SIDE_BY_SIDE),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn get_color(&self, attr: &'static str, default: Color) -> Color {
+        self.props
+            .get_or(Attribute::Custom(attr), AttrValue::Color(default))
+            .unwrap_color()
+    }
+
+    /// ### render_line
+    ///
+    /// Render a line from `x` in `width` columns. Context, added and removed lines are preceded by their sign
+    fn render_line(
+        &self,
+        buf: &mut Buffer,
+        x: u16,
+        y: u16,
+        width: u16,
+        line: &DiffLine,
+        style: Style,
+    ) {
+        let (sign, style) = match line.kind {
+            DiffLineKind::Header => (None, style.add_modifier(TextModifiers::BOLD)),
+            DiffLineKind::Hunk => (
+                None,
+                style.fg(self.get_color(DIFF_VIEWER_HUNK_COLOR, Color::Cyan)),
+            ),
+            DiffLineKind::Context => (Some(" "), style),
+            DiffLineKind::Added => (
+                Some("+"),
+                style.fg(self.get_color(DIFF_VIEWER_ADDED_COLOR, Color::Green)),
+            ),
+            DiffLineKind::Removed => (
+                Some("-"),
+                style.fg(self.get_color(DIFF_VIEWER_REMOVED_COLOR, Color::Red)),
+            ),
+        };
+        let right = x + width;
+        let max_width = |x: u16| right.saturating_sub(x) as usize;
+        let mut x = x;
+        if let Some(sign) = sign {
+            (x, _) = buf.set_stringn(x, y, sign, max_width(x), style);
+        }
+        let changed = line.changed.clone().unwrap_or(0..0);
+        let parts = [
+            (&line.text[..changed.start], style),
+            (
+                &line.text[changed.clone()],
+                style.add_modifier(TextModifiers::REVERSED),
+            ),
+            (&line.text[changed.end..], style),
+        ];
+        for (text, style) in parts {
+            (x, _) = buf.set_stringn(x, y, text, max_width(x), style);
+        }
+    }
+
+    /// ### render_diff
+    ///
+    /// Render the visible rows in `area`
+    fn render_diff(&mut self, buf: &mut Buffer, area: Rect, style: Style) {
+        if self.states.height != area.height as usize {
+            self.states.height = area.height as usize;
+            let scroll = self.states.scroll;
+            self.states.scroll = scroll.min(self.states.max_scroll());
+        }
+        let current_hunk = self.states.hunk.map(|x| self.states.hunks[x]);
+        let half = area.width.saturating_sub(1) / 2;
+        let rows = self.states.rows.iter().enumerate().skip(self.states.scroll);
+        for (y, (n, row)) in (area.y..area.bottom()).zip(rows) {
+            match *row {
+                DiffRow::Full(i) => {
+                    let style = match current_hunk == Some(n) {
+                        true => style.add_modifier(TextModifiers::REVERSED),
+                        false => style,
+                    };
+                    let line = &self.states.lines[i];
+                    self.render_line(buf, area.x, y, area.width, line, style);
+                }
+                DiffRow::Split(old, new) => {
+                    if let Some(i) = old {
+                        let line = &self.states.lines[i];
+                        self.render_line(buf, area.x, y, half, line, style);
+                    }
+                    buf.set_stringn(area.x + half, y, "│", 1, style);
+                    if let Some(i) = new {
+                        let x = area.x + half + 1;
+                        let line = &self.states.lines[i];
+                        self.render_line(buf, x, y, area.right() - x, line, style);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl MockComponent for DiffViewer {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_diff(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Text => {
+                let diff = self
+                    .props
+                    .get(Attribute::Text)
+                    .map(|x| x.unwrap_string())
+                    .unwrap_or_default();
+                let side_by_side = self.is_side_by_side();
+                self.states.hunk = None;
+                self.states.set_diff(&diff, side_by_side);
+            }
+            Attribute::Custom(DIFF_VIEWER_SIDE_BY_SIDE) => {
+                let side_by_side = self.is_side_by_side();
+                self.states.layout(side_by_side);
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.hunk {
+            Some(hunk) => State::One(StateValue::Usize(hunk)),
+            None => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length();
+        let prev_hunk = self.states.hunk;
+        match cmd {
+            Cmd::Move(Direction::Down) => self.states.scroll_to(self.states.scroll + 1),
+            Cmd::Move(Direction::Up) => self.states.scroll_to(self.states.scroll.saturating_sub(1)),
+            Cmd::Scroll(Direction::Down) => self.states.scroll_to(self.states.scroll + step),
+            Cmd::Scroll(Direction::Up) => self
+                .states
+                .scroll_to(self.states.scroll.saturating_sub(step)),
+            Cmd::GoTo(Position::Begin) => self.states.scroll_to(0),
+            Cmd::GoTo(Position::End) => self.states.scroll_to(usize::MAX),
+            Cmd::Custom(DIFF_VIEWER_CMD_NEXT_HUNK) => self.states.next_hunk(),
+            Cmd::Custom(DIFF_VIEWER_CMD_PREV_HUNK) => self.states.prev_hunk(),
+            _ => return CmdResult::None,
+        }
+        match self.states.hunk != prev_hunk {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    const DIFF: &str = "--- a/main.rs
++++ b/main.rs
+@@ -1,3 +1,4 @@
+ fn main() {
+-    println!(\"Hello\");
++    println!(\"Hello, world\");
++    println!(\"Bye\");
+ }
+@@ -10,2 +11,1 @@
+-// old
+-// todo
++// new
+";
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width)
+            .map(|x| buf[(x, y)].symbol().to_string())
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn test_components_diff_viewer_parse() {
+        assert_eq!(hunk_lengths("@@ -10,2 +11 @@ fn main"), (2, 1));
+        let lines = parse_diff(DIFF);
+        let kinds: Vec<DiffLineKind> = lines.iter().map(|x| x.kind).collect();
+        use DiffLineKind::*;
+        assert_eq!(
+            kinds,
+            vec![
+                Header, Header, Hunk, Context, Removed, Added, Added, Context, Hunk, Removed,
+                Removed, Added
+            ]
+        );
+        assert_eq!(lines[4].text, "    println!(\"Hello\");");
+        assert_eq!(lines[4].changed, Some(19..19));
+        assert_eq!(lines[5].changed, Some(19..26));
+        assert_eq!(lines[6].changed, None);
+        assert_eq!(lines[9].changed, Some(3..6));
+        assert_eq!(lines[10].changed, None);
+        // Lines with nothing in common
+        assert_eq!(changed_range("abc", "xyz"), None);
+        // Header-like lines in a hunk
+        let lines = parse_diff("@@ -1 +1 @@\n--- x\n+++ y\n");
+        assert_eq!(lines[1].kind, Removed);
+        assert_eq!(lines[2].kind, Added);
+    }
+
+    #[test]
+    fn test_components_diff_viewer() {
+        let mut component = DiffViewer::default()
+            .borders(Borders::default())
+            .title("main.rs", Alignment::Left)
+            .step(4)
+            .diff(DIFF);
+        assert_eq!(component.state(), State::None);
+        component.states.height = 5;
+        assert_eq!(
+            component.perform(Cmd::Custom(DIFF_VIEWER_CMD_NEXT_HUNK)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(component.states.scroll, 2);
+        // Second hunk can't reach the top
+        assert_eq!(
+            component.perform(Cmd::Custom(DIFF_VIEWER_CMD_NEXT_HUNK)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(component.states.scroll, 7);
+        assert_eq!(
+            component.perform(Cmd::Custom(DIFF_VIEWER_CMD_NEXT_HUNK)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(DIFF_VIEWER_CMD_PREV_HUNK)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::Changed(State::None)
+        );
+        component.perform(Cmd::Scroll(Direction::Down));
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        // Side by side keeps the current hunk
+        component.attr(
+            Attribute::Custom(DIFF_VIEWER_SIDE_BY_SIDE),
+            AttrValue::Flag(true),
+        );
+        assert_eq!(component.states.scroll, 2);
+        assert_eq!(component.states.rows.len(), 10);
+    }
+
+    #[test]
+    fn test_components_diff_viewer_render() {
+        let mut component = DiffViewer::default().diff(DIFF);
+        let area = Rect::new(0, 0, 41, 3);
+        let mut buf = Buffer::empty(area);
+        component.states.next_hunk();
+        component.render_diff(&mut buf, area, Style::default());
+        assert_eq!(row(&buf, 0), "@@ -1,3 +1,4 @@");
+        assert_eq!(row(&buf, 1), " fn main() {");
+        assert_eq!(row(&buf, 2), "-    println!(\"Hello\");");
+        assert_eq!(buf[(0, 2)].fg, Color::Red);
+        assert!(buf[(0, 0)].modifier.contains(TextModifiers::REVERSED));
+        // Side by side
+        component.attr(
+            Attribute::Custom(DIFF_VIEWER_SIDE_BY_SIDE),
+            AttrValue::Flag(true),
+        );
+        component.perform(Cmd::Move(Direction::Down));
+        component.perform(Cmd::Move(Direction::Down));
+        let area = Rect::new(0, 0, 61, 3);
+        let mut buf = Buffer::empty(area);
+        component.render_diff(&mut buf, area, Style::default());
+        assert_eq!(
+            row(&buf, 0),
+            "-    println!(\"Hello\");       │+    println!(\"Hello, world\");"
+        );
+        assert_eq!(
+            row(&buf, 1),
+            "                              │+    println!(\"Bye\");"
+        );
+        assert_eq!(buf[(31, 0)].fg, Color::Green);
+        assert!(buf[(51, 0)].modifier.contains(TextModifiers::REVERSED));
+        assert!(!buf[(50, 0)].modifier.contains(TextModifiers::REVERSED));
+        assert!(!buf[(20, 0)].modifier.contains(TextModifiers::REVERSED));
+    }
+}
@@ -0,0 +1,572 @@
+//! ## DualListTransfer
+//!
+//! `DualListTransfer` displays two lists, the available items and the selected ones, and moves items between them
+
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{
+    DUAL_LIST_TRANSFER_CMD_MOVE_ALL_LEFT, DUAL_LIST_TRANSFER_CMD_MOVE_ALL_RIGHT,
+    DUAL_LIST_TRANSFER_CMD_SWITCH_PANE,
+};
+use super::props::{DUAL_LIST_TRANSFER_TITLES, TOOLTIP};
+
+/// Index of the pane with the available items
+const AVAILABLE: usize = 0;
+/// Index of the pane with the selected items
+const SELECTED: usize = 1;
+
+// -- states
+
+/// ## DualListTransferStates
+///
+/// DualListTransferStates contains states for this component
+#[derive(Default)]
+pub struct DualListTransferStates {
+    /// All the items
+    pub items: Vec<String>,
+    /// Indexes of the selected items, in the order they have been selected
+    pub selected: Vec<usize>,
+    /// Pane receiving the cursor movements and the filter edits: 0 for the available items, 1 for the selected ones
+    pub active: usize,
+    /// Position of the cursor in the visible rows of each pane
+    pub cursor: [usize; 2],
+    /// Filter of each pane; only the items containing it, ignoring case, are visible
+    pub filter: [String; 2],
+}
+
+impl DualListTransferStates {
+    /// ### set_items
+    ///
+    /// Set the items, dropping the selected indexes out of range
+    pub fn set_items(&mut self, items: Vec<String>) {
+        self.items = items;
+        let len = self.items.len();
+        self.selected.retain(|x| *x < len);
+        self.fix_cursors();
+    }
+
+    /// ### set_selected
+    ///
+    /// Set the selected items, ignoring the indexes out of range and the duplicated ones
+    pub fn set_selected(&mut self, selected: &[usize]) {
+        self.selected.clear();
+        for index in selected {
+            if *index < self.items.len() && !self.selected.contains(index) {
+                self.selected.push(*index);
+            }
+        }
+        self.fix_cursors();
+    }
+
+    /// ### visible
+    ///
+    /// Get the indexes of the items visible in `pane`, according to its filter
+    pub fn visible(&self, pane: usize) -> Vec<usize> {
+        let filter = self.filter[pane].to_lowercase();
+        let matches = |x: &usize| self.items[*x].to_lowercase().contains(&filter);
+        match pane {
+            AVAILABLE => (0..self.items.len())
+                .filter(|x| !self.selected.contains(x))
+                .filter(matches)
+                .collect(),
+            _ => self.selected.iter().copied().filter(matches).collect(),
+        }
+    }
+
+    /// ### move_cursor
+    ///
+    /// Move the cursor of the active pane by `offset` rows, staying in the visible rows
+    pub fn move_cursor(&mut self, offset: isize) {
+        let cursor = &mut self.cursor[self.active];
+        *cursor = cursor.saturating_add_signed(offset);
+        self.fix_cursors();
+    }
+
+    /// ### transfer
+    ///
+    /// Move the item under the cursor of `pane` to the other pane; returns whether an item has been moved
+    pub fn transfer(&mut self, pane: usize) -> bool {
+        let Some(index) = self.visible(pane).get(self.cursor[pane]).copied() else {
+            return false;
+        };
+        match pane {
+            AVAILABLE => self.selected.push(index),
+            _ => self.selected.retain(|x| *x != index),
+        }
+        self.fix_cursors();
+        true
+    }
+
+    /// ### transfer_all
+    ///
+    /// Move all the items visible in `pane` to the other pane; returns whether any item has been moved
+    pub fn transfer_all(&mut self, pane: usize) -> bool {
+        let visible = self.visible(pane);
+        match pane {
+            AVAILABLE => self.selected.extend(visible.iter()),
+            _ => self.selected.retain(|x| !visible.contains(x)),
+        }
+        self.fix_cursors();
+        !visible.is_empty()
+    }
+
+    /// ### switch_pane
+    ///
+    /// Make the other pane active
+    pub fn switch_pane(&mut self) {
+        self.active = 1 - self.active;
+    }
+
+    /// ### push_filter
+    ///
+    /// Append a character to the filter of the active pane
+    pub fn push_filter(&mut self, ch: char) {
+        self.filter[self.active].push(ch);
+        self.cursor[self.active] = 0;
+    }
+
+    /// ### pop_filter
+    ///
+    /// Remove the last character of the filter of the active pane; returns whether there was one
+    pub fn pop_filter(&mut self) -> bool {
+        let popped = self.filter[self.active].pop().is_some();
+        self.fix_cursors();
+        popped
+    }
+
+    /// ### fix_cursors
+    ///
+    /// Keep the cursors in the visible rows of their pane
+    fn fix_cursors(&mut self) {
+        for pane in [AVAILABLE, SELECTED] {
+            let len = self.visible(pane).len();
+            self.cursor[pane] = self.cursor[pane].min(len.saturating_sub(1));
+        }
+    }
+}
+
+// -- component
+
+/// ## DualListTransfer
+///
+/// Two lists side by side, the available items on the left and the selected ones on the right. Items are moved
+/// to the right with `Move(Right)` and back with `Move(Left)`, one at a time or all the visible ones at once; typing
+/// filters the active pane
+#[derive(Default)]
+pub struct DualListTransfer {
+    props: Props,
+    pub states: DualListTransferStates,
+}
+
+impl DualListTransfer {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `dual_list_transfer` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("dual_list_transfer", self);
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Set the titles of the panes (default: "Available" and "Selected")
+    pub fn titles<A: Into<String>, S: Into<String>>(mut self, available: A, selected: S) -> Self {
+        self.attr(
+            Attribute::Custom(DUAL_LIST_TRANSFER_TITLES),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::Str(available.into()),
+                PropValue::Str(selected.into()),
+            ))),
+        );
+        self
+    }
+
+    pub fn items<S: AsRef<str>>(mut self, items: &[S]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(
+                items
+                    .iter()
+                    .map(|x| PropValue::Str(x.as_ref().to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the indexes of the selected items
+    pub fn selected(mut self, selected: &[usize]) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::Vec(
+                selected.iter().map(|x| PropValue::Usize(*x)).collect(),
+            )),
+        );
+        self
+    }
+
+    fn get_titles(&self) -> [String; 2] {
+        match self
+            .props
+            .get(Attribute::Custom(DUAL_LIST_TRANSFER_TITLES))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Tup2((available, selected))) => {
+                [available.unwrap_str(), selected.unwrap_str()]
+            }
+            _ => [String::from("Available"), String::from("Selected")],
+        }
+    }
+
+    /// ### render_pane
+    ///
+    /// Render `pane` in `area`: a header with its title and the number of items, the filter if any, then the
+    /// visible items, scrolled to keep the cursor visible
+    fn render_pane(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        pane: usize,
+        style: Style,
+        highlight_style: Style,
+    ) {
+        if area.height == 0 {
+            return;
+        }
+        let visible = self.states.visible(pane);
+        let title = &self.get_titles()[pane];
+        let header = format!("{title} ({})", visible.len());
+        let header_style = match pane == self.states.active {
+            true => style.add_modifier(TextModifiers::BOLD | TextModifiers::UNDERLINED),
+            false => style.add_modifier(TextModifiers::BOLD),
+        };
+        buf.set_stringn(area.x, area.y, header, area.width as usize, header_style);
+        let mut rows = area.rows().skip(1);
+        let filter = &self.states.filter[pane];
+        if !filter.is_empty() {
+            if let Some(row) = rows.next() {
+                buf.set_stringn(
+                    row.x,
+                    row.y,
+                    format!("/{filter}"),
+                    row.width as usize,
+                    style.add_modifier(TextModifiers::ITALIC),
+                );
+            }
+        }
+        let rows: Vec<Rect> = rows.collect();
+        let cursor = self.states.cursor[pane];
+        let offset = (cursor + 1).saturating_sub(rows.len());
+        for (row, (i, index)) in rows.iter().zip(visible.iter().enumerate().skip(offset)) {
+            let item_style = match i == cursor && pane == self.states.active {
+                true => highlight_style,
+                false => style,
+            };
+            buf.set_stringn(
+                row.x,
+                row.y,
+                &self.states.items[*index],
+                row.width as usize,
+                item_style,
+            );
+        }
+    }
+}
+
+impl MockComponent for DualListTransfer {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let highlighted_color = self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color());
+            let mut highlight_style = match highlighted_color {
+                Some(color) => style.fg(color),
+                None => style,
+            };
+            if focus {
+                highlight_style = highlight_style.add_modifier(TextModifiers::REVERSED);
+            }
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            // Panes are separated by a vertical line
+            let left = Rect {
+                width: inner.width.saturating_sub(1) / 2,
+                ..inner
+            };
+            let right = Rect {
+                x: left.right() + 1,
+                width: inner.width.saturating_sub(left.width + 1),
+                ..inner
+            };
+            let buf = render.buffer_mut();
+            if inner.width > 0 {
+                for y in inner.top()..inner.bottom() {
+                    buf[(left.right(), y)].set_symbol("│").set_style(style);
+                }
+            }
+            self.render_pane(buf, left, AVAILABLE, style, highlight_style);
+            self.render_pane(buf, right, SELECTED, style, highlight_style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, &value) {
+            (Attribute::Content, AttrValue::Payload(PropPayload::Vec(items))) => {
+                self.states
+                    .set_items(items.iter().cloned().map(|x| x.unwrap_str()).collect());
+            }
+            (Attribute::Value, AttrValue::Payload(PropPayload::Vec(selected))) => {
+                let selected: Vec<usize> =
+                    selected.iter().cloned().map(|x| x.unwrap_usize()).collect();
+                self.states.set_selected(&selected);
+            }
+            _ => {}
+        }
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::Vec(
+            self.states
+                .selected
+                .iter()
+                .map(|x| StateValue::Usize(*x))
+                .collect(),
+        )
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let changed = match cmd {
+            Cmd::Move(Direction::Right) => self.states.transfer(AVAILABLE),
+            Cmd::Move(Direction::Left) => self.states.transfer(SELECTED),
+            Cmd::Custom(DUAL_LIST_TRANSFER_CMD_MOVE_ALL_RIGHT) => {
+                self.states.transfer_all(AVAILABLE)
+            }
+            Cmd::Custom(DUAL_LIST_TRANSFER_CMD_MOVE_ALL_LEFT) => self.states.transfer_all(SELECTED),
+            Cmd::Move(Direction::Down) => {
+                self.states.move_cursor(1);
+                false
+            }
+            Cmd::Move(Direction::Up) => {
+                self.states.move_cursor(-1);
+                false
+            }
+            Cmd::Custom(DUAL_LIST_TRANSFER_CMD_SWITCH_PANE) => {
+                self.states.switch_pane();
+                false
+            }
+            Cmd::Type(ch) => {
+                self.states.push_filter(ch);
+                false
+            }
+            Cmd::Delete => {
+                self.states.pop_filter();
+                false
+            }
+            Cmd::Submit => return CmdResult::Submit(self.state()),
+            _ => false,
+        };
+        match changed {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn selected(indexes: &[usize]) -> State {
+        State::Vec(indexes.iter().map(|x| StateValue::Usize(*x)).collect())
+    }
+
+    #[test]
+    fn test_components_dual_list_transfer() {
+        let mut component = DualListTransfer::default()
+            .items(&["apple", "banana", "cherry", "grape"])
+            .selected(&[2, 2, 7]);
+        assert_eq!(component.state(), selected(&[2]));
+        assert_eq!(component.states.visible(AVAILABLE), vec![0, 1, 3]);
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(selected(&[2, 1]))
+        );
+        // The cursor stays in the rows
+        assert_eq!(component.states.cursor[AVAILABLE], 1);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(selected(&[1]))
+        );
+        // Filter
+        "AP".chars().for_each(|ch| {
+            component.perform(Cmd::Type(ch));
+        });
+        assert_eq!(component.states.visible(AVAILABLE), vec![0, 3]);
+        assert_eq!(
+            component.perform(Cmd::Custom(DUAL_LIST_TRANSFER_CMD_MOVE_ALL_RIGHT)),
+            CmdResult::Changed(selected(&[1, 0, 3]))
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(DUAL_LIST_TRANSFER_CMD_MOVE_ALL_RIGHT)),
+            CmdResult::None
+        );
+        component.perform(Cmd::Delete);
+        component.perform(Cmd::Delete);
+        assert_eq!(component.states.visible(AVAILABLE), vec![2]);
+        // Filter of the selected pane
+        component.perform(Cmd::Custom(DUAL_LIST_TRANSFER_CMD_SWITCH_PANE));
+        component.perform(Cmd::Type('n'));
+        assert_eq!(component.states.visible(SELECTED), vec![1]);
+        assert_eq!(
+            component.perform(Cmd::Custom(DUAL_LIST_TRANSFER_CMD_MOVE_ALL_LEFT)),
+            CmdResult::Changed(selected(&[0, 3]))
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(selected(&[0, 3]))
+        );
+    }
+
+    #[test]
+    fn test_components_dual_list_transfer_render() {
+        let mut component = DualListTransfer::default()
+            .items(&["apple", "banana", "cherry", "grape"])
+            .selected(&[3]);
+        component.perform(Cmd::Type('e'));
+        let area = Rect::new(0, 0, 13, 4);
+        let mut buf = Buffer::empty(area);
+        component.render_pane(
+            &mut buf,
+            area,
+            AVAILABLE,
+            Style::default(),
+            Style::default(),
+        );
+        assert_eq!(
+            rows(&buf),
+            vec![
+                "Available (2)",
+                "/e           ",
+                "apple        ",
+                "cherry       ",
+            ]
+        );
+        let mut buf = Buffer::empty(area);
+        component.render_pane(&mut buf, area, SELECTED, Style::default(), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![
+                "Selected (1) ",
+                "grape        ",
+                "             ",
+                "             ",
+            ]
+        );
+    }
+}
@@ -0,0 +1,726 @@
+//! ## FileExplorer
+//!
+//! `FileExplorer` lists the entries of a directory and lets the user navigate through directories and select a file
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{
+    layout::Rect,
+    widgets::{List as TuiList, ListItem, ListState},
+};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{FILE_EXPLORER_CMD_GO_UP, FILE_EXPLORER_CMD_TOGGLE_HIDDEN};
+use super::props::{
+    FILE_EXPLORER_DIRECTORY, FILE_EXPLORER_EXTENSIONS, FILE_EXPLORER_SHOW_HIDDEN, TOOLTIP,
+};
+
+// -- entries
+
+/// ## FileEntry
+///
+/// An entry of the explored directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEntry {
+    pub path: PathBuf,
+    pub name: String,
+    pub kind: FileKind,
+    /// Size in bytes; only set for files
+    pub size: Option<u64>,
+}
+
+/// ## FileKind
+///
+/// Describes the kind of a [`FileEntry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    /// The parent directory entry (`..`)
+    Parent,
+    Directory,
+    File,
+    Symlink,
+}
+
+impl FileEntry {
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, FileKind::Parent | FileKind::Directory)
+    }
+
+    fn icon(&self) -> &'static str {
+        match self.kind {
+            FileKind::Parent | FileKind::Directory => "📁",
+            FileKind::File => "📄",
+            FileKind::Symlink => "🔗",
+        }
+    }
+}
+
+// -- states
+
+/// ## FileExplorerStates
+///
+/// FileExplorer states: the explored directory, its entries and the selected entry
+#[derive(Default)]
+pub struct FileExplorerStates {
+    pub directory: PathBuf,
+    pub entries: Vec<FileEntry>,
+    pub selected: usize,
+}
+
+impl FileExplorerStates {
+    /// ### change_dir
+    ///
+    /// Explore `directory`, selecting the first entry. The directory path is made absolute, if possible
+    pub fn change_dir(&mut self, directory: &Path, show_hidden: bool, extensions: &[String]) {
+        self.directory = directory
+            .canonicalize()
+            .unwrap_or_else(|_| directory.to_path_buf());
+        self.entries = Self::read_entries(&self.directory, show_hidden, extensions);
+        self.selected = 0;
+    }
+
+    /// ### go_up
+    ///
+    /// Explore the parent directory, selecting the directory we come from.
+    /// Returns whether the directory has changed
+    pub fn go_up(&mut self, show_hidden: bool, extensions: &[String]) -> bool {
+        let previous = self.directory.clone();
+        match previous.parent() {
+            Some(parent) => {
+                self.change_dir(parent, show_hidden, extensions);
+                self.select_path(&previous);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// ### reload
+    ///
+    /// Read entries again, keeping the selected entry if still listed
+    pub fn reload(&mut self, show_hidden: bool, extensions: &[String]) {
+        let selected = self.selected_entry().map(|x| x.path.clone());
+        let directory = self.directory.clone();
+        self.change_dir(&directory, show_hidden, extensions);
+        if let Some(selected) = selected {
+            self.select_path(&selected);
+        }
+    }
+
+    /// ### selected_entry
+    ///
+    /// Get the selected entry
+    pub fn selected_entry(&self) -> Option<&FileEntry> {
+        self.entries.get(self.selected)
+    }
+
+    /// ### select_path
+    ///
+    /// Select the entry with `path`, if listed
+    pub fn select_path(&mut self, path: &Path) {
+        if let Some(index) = self
+            .entries
+            .iter()
+            .position(|x| x.kind != FileKind::Parent && x.path == path)
+        {
+            self.selected = index;
+        }
+    }
+
+    /// ### next
+    ///
+    /// Move selection down by `step` entries
+    pub fn next(&mut self, step: usize) {
+        self.selected = (self.selected + step).min(self.entries.len().saturating_sub(1));
+    }
+
+    /// ### previous
+    ///
+    /// Move selection up by `step` entries
+    pub fn previous(&mut self, step: usize) {
+        self.selected = self.selected.saturating_sub(step);
+    }
+
+    /// ### first
+    ///
+    /// Select the first entry
+    pub fn first(&mut self) {
+        self.selected = 0;
+    }
+
+    /// ### last
+    ///
+    /// Select the last entry
+    pub fn last(&mut self) {
+        self.selected = self.entries.len().saturating_sub(1);
+    }
+
+    /// ### read_entries
+    ///
+    /// Read the entries of `directory`: the parent entry first, then directories and files sorted by name.
+    /// Files are filtered by `extensions`, if any; hidden entries are listed only if `show_hidden` is set.
+    /// If the directory can't be read, only the parent entry is listed
+    fn read_entries(directory: &Path, show_hidden: bool, extensions: &[String]) -> Vec<FileEntry> {
+        let mut entries: Vec<FileEntry> = fs::read_dir(directory)
+            .map(|x| {
+                x.flatten()
+                    .filter_map(|entry| {
+                        let name = entry.file_name().to_string_lossy().to_string();
+                        let file_type = entry.file_type().ok()?;
+                        let path = entry.path();
+                        let kind = match (file_type.is_symlink(), path.is_dir()) {
+                            (_, true) => FileKind::Directory,
+                            (true, false) => FileKind::Symlink,
+                            (false, false) => FileKind::File,
+                        };
+                        let size = match kind {
+                            FileKind::File => entry.metadata().ok().map(|x| x.len()),
+                            _ => None,
+                        };
+                        Some(FileEntry {
+                            path,
+                            name,
+                            kind,
+                            size,
+                        })
+                    })
+                    .filter(|x| show_hidden || !x.name.starts_with('.'))
+                    .filter(|x| x.is_dir() || Self::matches_extensions(&x.path, extensions))
+                    .collect()
+            })
+            .unwrap_or_default();
+        entries.sort_by(|a, b| {
+            b.is_dir()
+                .cmp(&a.is_dir())
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+        if let Some(parent) = directory.parent() {
+            entries.insert(
+                0,
+                FileEntry {
+                    path: parent.to_path_buf(),
+                    name: String::from(".."),
+                    kind: FileKind::Parent,
+                    size: None,
+                },
+            );
+        }
+        entries
+    }
+
+    fn matches_extensions(path: &Path, extensions: &[String]) -> bool {
+        extensions.is_empty()
+            || path
+                .extension()
+                .map(|x| x.to_string_lossy().to_lowercase())
+                .is_some_and(|x| extensions.iter().any(|ext| ext.to_lowercase() == x))
+    }
+}
+
+// -- component
+
+/// ## FileExplorer
+///
+/// A component to browse the file system. Entries are listed with an icon and their size; submitting a directory explores it,
+/// while submitting a file returns its path. The parent directory is always listed as the first entry.
+#[derive(Default)]
+pub struct FileExplorer {
+    props: Props,
+    pub states: FileExplorerStates,
+    hg_str: Option<String>,
+}
+
+impl FileExplorer {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn modifiers(mut self, m: TextModifiers) -> Self {
+        self.attr(Attribute::TextProps, AttrValue::TextModifiers(m));
+        self
+    }
+
+    /// Set title. If not set, the explored directory is displayed
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `file_explorer` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("file_explorer", self);
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn highlighted_str<S: Into<String>>(mut self, s: S) -> Self {
+        self.attr(Attribute::HighlightedStr, AttrValue::String(s.into()));
+        self
+    }
+
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    /// List hidden entries
+    pub fn show_hidden(mut self, show: bool) -> Self {
+        self.attr(
+            Attribute::Custom(FILE_EXPLORER_SHOW_HIDDEN),
+            AttrValue::Flag(show),
+        );
+        self
+    }
+
+    /// List only files with one of `extensions` (e.g. `&["rs", "toml"]`); directories are always listed
+    pub fn extensions(mut self, extensions: &[&str]) -> Self {
+        self.attr(
+            Attribute::Custom(FILE_EXPLORER_EXTENSIONS),
+            AttrValue::Payload(PropPayload::Vec(
+                extensions
+                    .iter()
+                    .map(|x| PropValue::Str(x.trim_start_matches('.').to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the directory to explore
+    pub fn directory<P: AsRef<Path>>(mut self, p: P) -> Self {
+        self.attr(
+            Attribute::Custom(FILE_EXPLORER_DIRECTORY),
+            AttrValue::String(p.as_ref().to_string_lossy().to_string()),
+        );
+        self
+    }
+
+    fn is_show_hidden(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(FILE_EXPLORER_SHOW_HIDDEN),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    fn get_extensions(&self) -> Vec<String> {
+        self.props
+            .get(Attribute::Custom(FILE_EXPLORER_EXTENSIONS))
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_vec()
+                    .into_iter()
+                    .map(|x| x.unwrap_str())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn fmt_size(size: u64) -> String {
+        const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+        let mut value = size as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        match unit {
+            0 => format!("{size} B"),
+            _ => format!("{value:.1} {}", UNITS[unit]),
+        }
+    }
+
+    /// ### entry_line
+    ///
+    /// Make the line for `entry`, with the size aligned to the right of `width`
+    fn entry_line(entry: &FileEntry, width: usize) -> String {
+        let name = match entry.kind {
+            FileKind::Directory => format!("{} {}/", entry.icon(), entry.name),
+            _ => format!("{} {}", entry.icon(), entry.name),
+        };
+        match entry.size.map(Self::fmt_size) {
+            Some(size) => {
+                let padding = width.saturating_sub(name.width() + size.width()).max(1);
+                format!("{name}{}{size}", " ".repeat(padding))
+            }
+            None => name,
+        }
+    }
+
+    fn changed(&self, prev: usize) -> CmdResult {
+        if prev != self.states.selected {
+            CmdResult::Changed(self.state())
+        } else {
+            CmdResult::None
+        }
+    }
+}
+
+impl MockComponent for FileExplorer {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let modifiers = self
+                .props
+                .get_or(
+                    Attribute::TextProps,
+                    AttrValue::TextModifiers(TextModifiers::empty()),
+                )
+                .unwrap_text_modifiers();
+            let title = self
+                .props
+                .get(Attribute::Title)
+                .map(|x| x.unwrap_title())
+                .unwrap_or_else(|| {
+                    (
+                        self.states.directory.to_string_lossy().to_string(),
+                        Alignment::Left,
+                    )
+                });
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let div = crate::utils::get_block(borders, Some(title), focus, inactive_style)
+                .title_style(get_title_style(&self.props));
+            // Highlighted symbol
+            self.hg_str = self
+                .props
+                .get(Attribute::HighlightedStr)
+                .map(|x| x.unwrap_string());
+            let width = div
+                .inner(area)
+                .width
+                .saturating_sub(self.hg_str.as_deref().map(|x| x.width()).unwrap_or(0) as u16)
+                as usize;
+            let items: Vec<ListItem> = self
+                .states
+                .entries
+                .iter()
+                .map(|x| ListItem::new(Self::entry_line(x, width)))
+                .collect();
+            let highlighted_color = self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color())
+                .unwrap_or(foreground);
+            let modifiers = match focus {
+                true => modifiers | TextModifiers::REVERSED,
+                false => modifiers,
+            };
+            let mut list = TuiList::new(items)
+                .block(div)
+                .style(Style::default().fg(foreground).bg(background))
+                .highlight_style(
+                    Style::default()
+                        .fg(highlighted_color)
+                        .add_modifier(modifiers),
+                );
+            if let Some(hg_str) = &self.hg_str {
+                list = list.highlight_symbol(hg_str);
+            }
+            let mut state: ListState = ListState::default();
+            state.select(Some(self.states.selected));
+            render.render_stateful_widget(list, area, &mut state);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Custom(FILE_EXPLORER_DIRECTORY) => {
+                let directory = PathBuf::from(
+                    self.props
+                        .get_or(attr, AttrValue::String(String::from(".")))
+                        .unwrap_string(),
+                );
+                self.states
+                    .change_dir(&directory, self.is_show_hidden(), &self.get_extensions());
+            }
+            Attribute::Custom(FILE_EXPLORER_SHOW_HIDDEN)
+            | Attribute::Custom(FILE_EXPLORER_EXTENSIONS) => {
+                self.states
+                    .reload(self.is_show_hidden(), &self.get_extensions());
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.selected_entry() {
+            Some(entry) => State::One(StateValue::String(entry.path.to_string_lossy().to_string())),
+            None => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let prev = self.states.selected;
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length();
+        match cmd {
+            Cmd::Move(Direction::Down) => {
+                self.states.next(1);
+                self.changed(prev)
+            }
+            Cmd::Move(Direction::Up) => {
+                self.states.previous(1);
+                self.changed(prev)
+            }
+            Cmd::Scroll(Direction::Down) => {
+                self.states.next(step);
+                self.changed(prev)
+            }
+            Cmd::Scroll(Direction::Up) => {
+                self.states.previous(step);
+                self.changed(prev)
+            }
+            Cmd::GoTo(Position::Begin) => {
+                self.states.first();
+                self.changed(prev)
+            }
+            Cmd::GoTo(Position::End) => {
+                self.states.last();
+                self.changed(prev)
+            }
+            Cmd::Submit => match self.states.selected_entry().cloned() {
+                Some(entry) if entry.kind == FileKind::Parent => {
+                    self.states
+                        .go_up(self.is_show_hidden(), &self.get_extensions());
+                    CmdResult::Changed(self.state())
+                }
+                Some(entry) if entry.is_dir() => {
+                    self.states.change_dir(
+                        &entry.path,
+                        self.is_show_hidden(),
+                        &self.get_extensions(),
+                    );
+                    CmdResult::Changed(self.state())
+                }
+                Some(_) => CmdResult::Submit(self.state()),
+                None => CmdResult::None,
+            },
+            Cmd::Custom(FILE_EXPLORER_CMD_GO_UP) => {
+                match self
+                    .states
+                    .go_up(self.is_show_hidden(), &self.get_extensions())
+                {
+                    true => CmdResult::Changed(self.state()),
+                    false => CmdResult::None,
+                }
+            }
+            Cmd::Custom(FILE_EXPLORER_CMD_TOGGLE_HIDDEN) => {
+                let show = !self.is_show_hidden();
+                self.attr(
+                    Attribute::Custom(FILE_EXPLORER_SHOW_HIDDEN),
+                    AttrValue::Flag(show),
+                );
+                CmdResult::Changed(self.state())
+            }
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    /// Make a directory tree in the temp dir:
+    ///
+    /// ```txt
+    /// .hidden
+    /// README.md
+    /// main.rs
+    /// src/
+    ///     lib.rs
+    /// ```
+    fn make_tree(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("tui-realm-stdlib-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join(".hidden"), "").unwrap();
+        fs::write(root.join("README.md"), "# readme").unwrap();
+        fs::write(root.join("main.rs"), "fn main() {}").unwrap();
+        fs::write(root.join("src").join("lib.rs"), "").unwrap();
+        root.canonicalize().unwrap()
+    }
+
+    fn names(component: &FileExplorer) -> Vec<&str> {
+        component
+            .states
+            .entries
+            .iter()
+            .map(|x| x.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_components_file_explorer() {
+        let root = make_tree("file-explorer");
+        let mut component = FileExplorer::default()
+            .borders(Borders::default())
+            .highlighted_str(">> ")
+            .directory(&root);
+        // Parent first, then directories, then files
+        assert_eq!(names(&component), vec!["..", "src", "main.rs", "README.md"]);
+        assert_eq!(component.states.entries[3].size, Some(8));
+        assert_eq!(component.states.entries[1].kind, FileKind::Directory);
+        assert_eq!(
+            component.state(),
+            State::One(StateValue::String(
+                root.parent().unwrap().to_string_lossy().to_string()
+            ))
+        );
+        // Hidden files
+        assert_eq!(
+            component.perform(Cmd::Custom(FILE_EXPLORER_CMD_TOGGLE_HIDDEN)),
+            CmdResult::Changed(component.state())
+        );
+        assert_eq!(
+            names(&component),
+            vec!["..", "src", ".hidden", "main.rs", "README.md"]
+        );
+        component.perform(Cmd::Custom(FILE_EXPLORER_CMD_TOGGLE_HIDDEN));
+        // Extensions filter keeps selection
+        component.perform(Cmd::GoTo(Position::End));
+        component.attr(
+            Attribute::Custom(FILE_EXPLORER_EXTENSIONS),
+            AttrValue::Payload(PropPayload::Vec(vec![PropValue::Str(String::from("MD"))])),
+        );
+        assert_eq!(names(&component), vec!["..", "src", "README.md"]);
+        assert_eq!(component.states.selected, 2);
+        // Submit file
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::String(
+                root.join("README.md").to_string_lossy().to_string()
+            )))
+        );
+        // Enter directory, then go back to parent with the parent entry
+        component.perform(Cmd::Move(Direction::Up));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Changed(State::One(StateValue::String(
+                root.to_string_lossy().to_string()
+            )))
+        );
+        assert_eq!(component.states.directory, root.join("src"));
+        assert_eq!(names(&component), vec![".."]);
+        component.perform(Cmd::Submit);
+        assert_eq!(component.states.directory, root);
+        assert_eq!(component.states.selected, 1);
+        // Go up
+        assert_eq!(
+            component.perform(Cmd::Custom(FILE_EXPLORER_CMD_GO_UP)),
+            CmdResult::Changed(component.state())
+        );
+        assert_eq!(component.states.directory, root.parent().unwrap());
+        // Movements
+        let mut component = FileExplorer::default().directory(&root);
+        assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
+        component.perform(Cmd::Scroll(Direction::Down));
+        assert_eq!(component.states.selected, 3);
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(component.states.selected, 0);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_components_file_explorer_lines() {
+        let entry = FileEntry {
+            path: PathBuf::from("/tmp/a.txt"),
+            name: String::from("a.txt"),
+            kind: FileKind::File,
+            size: Some(2048),
+        };
+        assert_eq!(FileExplorer::entry_line(&entry, 20), "📄 a.txt      2.0 KB");
+        let entry = FileEntry {
+            path: PathBuf::from("/tmp/src"),
+            name: String::from("src"),
+            kind: FileKind::Directory,
+            size: None,
+        };
+        assert_eq!(FileExplorer::entry_line(&entry, 20), "📁 src/");
+        assert_eq!(FileExplorer::fmt_size(512), "512 B");
+        assert_eq!(FileExplorer::fmt_size(1024 * 1024 * 3 / 2), "1.5 MB");
+    }
+}
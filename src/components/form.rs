@@ -0,0 +1,560 @@
+//! ## Form
+//!
+//! `Form` is a container of labeled fields, validating their values and collecting them on submit
+
+use std::collections::HashMap;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{Alignment, AttrValue, Attribute, Borders, Color, Props, Style};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{FORM_CMD_NEXT_FIELD, FORM_CMD_PREV_FIELD};
+use super::props::{FORM_ERROR_COLOR, TOOLTIP};
+
+/// Rows of a field used when none is set, fitting a component with borders
+const DEFAULT_FIELD_HEIGHT: u16 = 3;
+
+/// ## FormValidator
+///
+/// Validates the state of a field, returning the error message if it is invalid
+pub type FormValidator = Box<dyn Fn(&State) -> Result<(), String>>;
+
+/// ## FormField
+///
+/// A field of the `Form`: a component with a label and an optional validator
+pub struct FormField {
+    pub label: String,
+    pub component: Box<dyn MockComponent>,
+    pub validator: Option<FormValidator>,
+    /// Rows taken by the component
+    pub height: u16,
+}
+
+impl FormField {
+    pub fn new<S: Into<String>>(label: S, component: Box<dyn MockComponent>) -> Self {
+        Self {
+            label: label.into(),
+            component,
+            validator: None,
+            height: DEFAULT_FIELD_HEIGHT,
+        }
+    }
+
+    pub fn validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&State) -> Result<(), String> + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// ### validate
+    ///
+    /// Validate the state of the component, returning the error message if it is invalid
+    fn validate(&self) -> Option<String> {
+        self.validator
+            .as_ref()
+            .and_then(|validator| validator(&self.component.state()).err())
+    }
+}
+
+/// ### flatten_state
+///
+/// Insert the values of `state` into `values` with `key`; values of states with more values are inserted with their
+/// index or key appended to `key`, as `key.index`
+fn flatten_state(key: String, state: State, values: &mut HashMap<String, StateValue>) {
+    let mut insert_all = |states: Vec<State>| {
+        for (i, state) in states.into_iter().enumerate() {
+            flatten_state(format!("{key}.{i}"), state, values);
+        }
+    };
+    match state {
+        State::None => {
+            values.insert(key, StateValue::None);
+        }
+        State::One(value) => {
+            values.insert(key, value);
+        }
+        State::Tup2((a, b)) => insert_all(vec![State::One(a), State::One(b)]),
+        State::Tup3((a, b, c)) => insert_all(vec![State::One(a), State::One(b), State::One(c)]),
+        State::Tup4((a, b, c, d)) => insert_all(vec![
+            State::One(a),
+            State::One(b),
+            State::One(c),
+            State::One(d),
+        ]),
+        State::Vec(vec) => insert_all(vec.into_iter().map(State::One).collect()),
+        State::Linked(list) => insert_all(list.into_iter().collect()),
+        State::Map(map) => {
+            for (name, value) in map {
+                values.insert(format!("{key}.{name}"), value);
+            }
+        }
+    }
+}
+
+// -- states
+
+/// ## FormStates
+///
+/// FormStates contains states for this component
+#[derive(Default)]
+pub struct FormStates {
+    /// Index of the active field, receiving commands and focus
+    pub active: usize,
+    /// Error message of each field, if invalid
+    pub errors: Vec<Option<String>>,
+}
+
+impl FormStates {
+    /// ### next_field
+    ///
+    /// Make the next field active, going back to the first one after the last one
+    pub fn next_field(&mut self) {
+        match self.active + 1 < self.errors.len() {
+            true => self.active += 1,
+            false => self.active = 0,
+        }
+    }
+
+    /// ### prev_field
+    ///
+    /// Make the previous field active, going to the last one before the first one
+    pub fn prev_field(&mut self) {
+        match self.active {
+            0 => self.active = self.errors.len().saturating_sub(1),
+            _ => self.active -= 1,
+        }
+    }
+}
+
+// -- component
+
+/// ## Form
+///
+/// A container of labeled fields, displayed one below the other. Commands are forwarded to the active field, which is
+/// the only one receiving focus. Fields are validated when leaving them and on submit, which returns the values of all
+/// the fields, if valid. Errors are displayed below invalid fields
+#[derive(Default)]
+pub struct Form {
+    props: Props,
+    pub states: FormStates,
+    /// Form fields, in order
+    pub fields: Vec<FormField>,
+}
+
+impl Form {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `form` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("form", self);
+    }
+
+    /// Set the color of the label of the active field
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Set the color of errors and of the labels of invalid fields (default: red)
+    pub fn error_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::Custom(FORM_ERROR_COLOR), AttrValue::Color(c));
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<FormField>) -> Self {
+        self.states.errors = vec![None; fields.len()];
+        self.states.active = 0;
+        self.fields = fields;
+        self.focus_fields();
+        self
+    }
+
+    /// ### focus_fields
+    ///
+    /// Give focus to the active field, if the form is focused, and remove it from the other ones
+    fn focus_fields(&mut self) {
+        let focus = self
+            .props
+            .get_or(Attribute::Focus, AttrValue::Flag(false))
+            .unwrap_flag();
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            field.component.attr(
+                Attribute::Focus,
+                AttrValue::Flag(focus && i == self.states.active),
+            );
+        }
+    }
+
+    /// ### validate
+    ///
+    /// Validate the field at `index`, updating its error. Returns whether it is valid
+    fn validate(&mut self, index: usize) -> bool {
+        let error = self.fields.get(index).and_then(|x| x.validate());
+        let valid = error.is_none();
+        if let Some(x) = self.states.errors.get_mut(index) {
+            *x = error;
+        }
+        valid
+    }
+
+    /// ### values
+    ///
+    /// Get the values of the fields, by label
+    fn values(&self) -> HashMap<String, StateValue> {
+        let mut values = HashMap::new();
+        for field in self.fields.iter() {
+            flatten_state(field.label.clone(), field.component.state(), &mut values);
+        }
+        values
+    }
+
+    fn label_width(&self, area: Rect) -> u16 {
+        (self
+            .fields
+            .iter()
+            .map(|x| x.label.width())
+            .max()
+            .unwrap_or(0) as u16)
+            .min(area.width / 3)
+    }
+
+    /// ### layout
+    ///
+    /// Get the area of each field in `area`, after the labels, and the row of its error, if invalid.
+    /// Fields which don't fit have no area
+    fn layout(&self, area: Rect) -> Vec<(Rect, Option<Rect>)> {
+        // Labels, space, then the fields
+        let x = area.x + (self.label_width(area) + 1).min(area.width);
+        let width = area.right() - x;
+        let mut y = area.y;
+        self.fields
+            .iter()
+            .zip(self.states.errors.iter())
+            .map(|(field, error)| {
+                let height = field.height.min(area.bottom() - y);
+                let field_area = Rect::new(x, y, width, height);
+                y += height;
+                let error_area = match error.is_some() && y < area.bottom() {
+                    true => {
+                        y += 1;
+                        Some(Rect::new(x, y - 1, width, 1))
+                    }
+                    false => None,
+                };
+                (field_area, error_area)
+            })
+            .collect()
+    }
+}
+
+impl MockComponent for Form {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let active_style = match self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color())
+            {
+                Some(color) if focus => style.fg(color),
+                _ => style,
+            };
+            let error_style = style.fg(self
+                .props
+                .get_or(
+                    Attribute::Custom(FORM_ERROR_COLOR),
+                    AttrValue::Color(Color::Red),
+                )
+                .unwrap_color());
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            let label_width = self.label_width(inner) as usize;
+            let layout = self.layout(inner);
+            for (i, (field, (field_area, error_area))) in
+                self.fields.iter_mut().zip(layout).enumerate()
+            {
+                if field_area.height == 0 {
+                    continue;
+                }
+                let error = &self.states.errors[i];
+                let label_style = match (error, i == self.states.active) {
+                    (Some(_), _) => error_style,
+                    (None, true) => active_style,
+                    (None, false) => style,
+                };
+                render.buffer_mut().set_stringn(
+                    inner.x,
+                    field_area.y + field_area.height / 2,
+                    &field.label,
+                    label_width,
+                    label_style,
+                );
+                if let (Some(error), Some(error_area)) = (error, error_area) {
+                    render.buffer_mut().set_stringn(
+                        error_area.x,
+                        error_area.y,
+                        format!("✘ {error}"),
+                        error_area.width as usize,
+                        error_style,
+                    );
+                }
+                field.component.view(render, field_area);
+            }
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if attr == Attribute::Focus {
+            self.focus_fields();
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Linked(self.fields.iter().map(|x| x.component.state()).collect())
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        if self.fields.is_empty() {
+            return CmdResult::None;
+        }
+        let active = self.states.active;
+        match cmd {
+            Cmd::Custom(FORM_CMD_NEXT_FIELD) => {
+                self.validate(active);
+                self.states.next_field();
+            }
+            Cmd::Custom(FORM_CMD_PREV_FIELD) => {
+                self.validate(active);
+                self.states.prev_field();
+            }
+            Cmd::Submit => {
+                let valid: Vec<bool> = (0..self.fields.len()).map(|i| self.validate(i)).collect();
+                return match valid.iter().position(|x| !x) {
+                    Some(invalid) => {
+                        // Go to the first invalid field
+                        self.states.active = invalid;
+                        self.focus_fields();
+                        CmdResult::Invalid(Cmd::Submit)
+                    }
+                    None => CmdResult::Submit(State::Map(self.values())),
+                };
+            }
+            cmd => {
+                let result = self.fields[active].component.perform(cmd);
+                // Errors are cleared as soon as the field is fixed
+                if self.states.errors[active].is_some() {
+                    self.validate(active);
+                }
+                return result;
+            }
+        }
+        self.focus_fields();
+        CmdResult::Changed(State::One(StateValue::Usize(self.states.active)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::components::{Input, Radio};
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::command::Direction;
+
+    fn required(state: &State) -> Result<(), String> {
+        match state {
+            State::One(StateValue::String(x)) if !x.is_empty() => Ok(()),
+            _ => Err(String::from("required")),
+        }
+    }
+
+    fn component() -> Form {
+        Form::default().fields(vec![
+            FormField::new("Name", Box::new(Input::default())).validator(required),
+            FormField::new("Role", Box::new(Radio::default().choices(&["dev", "ops"]))).height(1),
+        ])
+    }
+
+    fn is_focused(field: &FormField) -> bool {
+        field
+            .component
+            .query(Attribute::Focus)
+            .map(|x| x.unwrap_flag())
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_components_form() {
+        let mut component = component();
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        assert!(is_focused(&component.fields[0]));
+        // Leaving an invalid field
+        assert_eq!(
+            component.perform(Cmd::Custom(FORM_CMD_NEXT_FIELD)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(component.states.errors[0], Some(String::from("required")));
+        assert!(!is_focused(&component.fields[0]));
+        assert!(is_focused(&component.fields[1]));
+        // Commands are forwarded to the active field
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        // Submit goes to the first invalid field
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Invalid(Cmd::Submit)
+        );
+        assert_eq!(component.states.active, 0);
+        component.perform(Cmd::Type('a'));
+        assert_eq!(component.states.errors[0], None);
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::Map(HashMap::from([
+                (String::from("Name"), StateValue::String(String::from("a"))),
+                (String::from("Role"), StateValue::Usize(1)),
+            ])))
+        );
+        // Cycling
+        component.perform(Cmd::Custom(FORM_CMD_PREV_FIELD));
+        assert_eq!(component.states.active, 1);
+        component.perform(Cmd::Custom(FORM_CMD_NEXT_FIELD));
+        assert_eq!(component.states.active, 0);
+    }
+
+    #[test]
+    fn test_components_form_layout() {
+        let mut component = component();
+        let area = Rect::new(0, 0, 20, 5);
+        assert_eq!(
+            component.layout(area),
+            vec![
+                (Rect::new(5, 0, 15, 3), None),
+                (Rect::new(5, 3, 15, 1), None)
+            ]
+        );
+        component.states.errors[0] = Some(String::from("required"));
+        assert_eq!(
+            component.layout(area),
+            vec![
+                (Rect::new(5, 0, 15, 3), Some(Rect::new(5, 3, 15, 1))),
+                (Rect::new(5, 4, 15, 1), None)
+            ]
+        );
+        // Fields which don't fit
+        let area = Rect::new(0, 0, 20, 3);
+        assert_eq!(component.layout(area)[1].0.height, 0);
+    }
+
+    #[test]
+    fn test_components_form_values() {
+        let mut values = HashMap::new();
+        flatten_state(
+            String::from("tags"),
+            State::Vec(vec![StateValue::Usize(0), StateValue::Usize(2)]),
+            &mut values,
+        );
+        flatten_state(String::from("empty"), State::None, &mut values);
+        assert_eq!(
+            values,
+            HashMap::from([
+                (String::from("tags.0"), StateValue::Usize(0)),
+                (String::from("tags.1"), StateValue::Usize(2)),
+                (String::from("empty"), StateValue::None),
+            ])
+        );
+    }
+}
@@ -0,0 +1,745 @@
+//! ## Gantt
+//!
+//! `Gantt` renders tasks as bars across a time axis
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use super::clock::ClockStates;
+use crate::theme::Theme;
+use crate::utils::{
+    format_timestamp, get_block, get_title_style, is_disabled, render_disabled, render_tooltip,
+};
+
+// -- Props
+
+use super::commands::{GANTT_CMD_ZOOM_IN, GANTT_CMD_ZOOM_OUT};
+use super::props::{
+    GANTT_AXIS_FORMAT, GANTT_SCALE, GANTT_START, GANTT_TODAY, GANTT_TODAY_COLOR, TOOLTIP,
+};
+
+/// Format of the time axis used when none is set
+const DEFAULT_AXIS_FORMAT: &str = "%m-%d";
+/// Seconds per column used when no scale is set: a day
+const DEFAULT_SCALE: i64 = 86400;
+/// Rows of the time axis: labels and ticks
+const AXIS_HEIGHT: u16 = 2;
+
+/// ## GanttTask
+///
+/// A task displayed by the `Gantt` chart
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GanttTask {
+    pub label: String,
+    /// Start of the task, as a unix timestamp (seconds, UTC)
+    pub start: i64,
+    /// End of the task, as a unix timestamp (seconds, UTC). A task ending when it starts is a milestone
+    pub end: i64,
+    /// Color of the bar; `Reset` uses the foreground
+    pub color: Color,
+    /// Indexes of the tasks this task depends on
+    pub dependencies: Vec<usize>,
+}
+
+impl GanttTask {
+    /// ### new
+    ///
+    /// Make a new task without dependencies
+    pub fn new<S: Into<String>>(label: S, start: i64, end: i64) -> Self {
+        Self {
+            label: label.into(),
+            start,
+            end: end.max(start),
+            color: Color::Reset,
+            dependencies: Vec::new(),
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Make the task depend on the task at `index`
+    pub fn depends_on(mut self, index: usize) -> Self {
+        self.dependencies.push(index);
+        self
+    }
+
+    fn to_payload(&self) -> PropPayload {
+        PropPayload::Vec(
+            [
+                PropValue::Str(self.label.clone()),
+                PropValue::I64(self.start),
+                PropValue::I64(self.end),
+                PropValue::Color(self.color),
+            ]
+            .into_iter()
+            .chain(self.dependencies.iter().map(|x| PropValue::Usize(*x)))
+            .collect(),
+        )
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        let mut values = payload.unwrap_vec().into_iter();
+        let label = values.next().map(|x| x.unwrap_str()).unwrap_or_default();
+        let start = values.next().map(|x| x.unwrap_i64()).unwrap_or(0);
+        let end = values.next().map(|x| x.unwrap_i64()).unwrap_or(start);
+        let color = values
+            .next()
+            .map(|x| x.unwrap_color())
+            .unwrap_or(Color::Reset);
+        Self {
+            label,
+            start,
+            end,
+            color,
+            dependencies: values.map(|x| x.unwrap_usize()).collect(),
+        }
+    }
+}
+
+// -- states
+
+/// ## GanttStates
+///
+/// GanttStates contains states for this component
+pub struct GanttStates {
+    /// Index of the selected task
+    pub selected: usize,
+    /// Number of tasks
+    pub len: usize,
+    /// First task displayed
+    pub scroll: usize,
+    /// Start of the time window, as a unix timestamp (seconds, UTC)
+    pub offset: i64,
+    /// Seconds per column
+    pub scale: i64,
+    /// Columns of the time window, as of the last render
+    pub width: u16,
+}
+
+impl Default for GanttStates {
+    fn default() -> Self {
+        Self {
+            selected: 0,
+            len: 0,
+            scroll: 0,
+            offset: 0,
+            scale: DEFAULT_SCALE,
+            width: 0,
+        }
+    }
+}
+
+impl GanttStates {
+    /// ### set_len
+    ///
+    /// Set the number of tasks, keeping the selection in range
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len;
+        self.selected = self.selected.min(len.saturating_sub(1));
+    }
+
+    /// ### select
+    ///
+    /// Select the task at `index`, clamped to the tasks
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.len.saturating_sub(1));
+    }
+
+    /// ### scroll_by
+    ///
+    /// Move the time window by `columns`; backward if negative
+    pub fn scroll_by(&mut self, columns: i64) {
+        self.offset += columns * self.scale;
+    }
+
+    /// ### zoom
+    ///
+    /// Set the seconds per column, keeping the middle of the time window in place
+    pub fn zoom(&mut self, scale: i64) {
+        let half = i64::from(self.width / 2);
+        let middle = self.offset + half * self.scale;
+        self.scale = scale.max(1);
+        self.offset = middle - half * self.scale;
+    }
+
+    /// ### column
+    ///
+    /// Get the column of the time window containing `timestamp`
+    fn column(&self, timestamp: i64) -> i64 {
+        (timestamp - self.offset).div_euclid(self.scale)
+    }
+
+    /// ### fit
+    ///
+    /// Scroll so that the selected task is visible in `height` rows
+    fn fit(&mut self, height: usize) {
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + height {
+            self.scroll = (self.selected + 1).saturating_sub(height);
+        }
+        self.scroll = self.scroll.min(self.len.saturating_sub(height));
+    }
+}
+
+// -- component
+
+/// ## Gantt
+///
+/// A gantt chart, laying out tasks as bars across a time axis, with their labels on the left. Dependencies between
+/// tasks are drawn as connectors, and a marker shows today. The time window can be scrolled and zoomed, and a task
+/// can be selected
+#[derive(Default)]
+pub struct Gantt {
+    props: Props,
+    pub states: GanttStates,
+}
+
+impl Gantt {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `gantt` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("gantt", self);
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    /// Set the format of the time axis labels (default: `%m-%d`). See `utils::format_timestamp` for supported specifiers
+    pub fn axis_format<S: Into<String>>(mut self, fmt: S) -> Self {
+        self.attr(
+            Attribute::Custom(GANTT_AXIS_FORMAT),
+            AttrValue::String(fmt.into()),
+        );
+        self
+    }
+
+    /// Set the seconds per column (default: a day)
+    pub fn scale(mut self, seconds: u64) -> Self {
+        self.attr(
+            Attribute::Custom(GANTT_SCALE),
+            AttrValue::Payload(PropPayload::One(PropValue::U64(seconds))),
+        );
+        self
+    }
+
+    /// Set the start of the time window, as a unix timestamp (default: the start of the first task)
+    pub fn start(mut self, timestamp: i64) -> Self {
+        self.attr(
+            Attribute::Custom(GANTT_START),
+            AttrValue::Payload(PropPayload::One(PropValue::I64(timestamp))),
+        );
+        self
+    }
+
+    /// Set the time of the today marker, as a unix timestamp (default: now)
+    pub fn today(mut self, timestamp: i64) -> Self {
+        self.attr(
+            Attribute::Custom(GANTT_TODAY),
+            AttrValue::Payload(PropPayload::One(PropValue::I64(timestamp))),
+        );
+        self
+    }
+
+    /// Set the color of the today marker (default: red)
+    pub fn today_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::Custom(GANTT_TODAY_COLOR), AttrValue::Color(c));
+        self
+    }
+
+    pub fn tasks(mut self, tasks: &[GanttTask]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(
+                tasks.iter().map(|x| x.to_payload()).collect(),
+            )),
+        );
+        self
+    }
+
+    pub fn selected_task(mut self, index: usize) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(index))),
+        );
+        self
+    }
+
+    fn get_tasks(&self) -> Vec<GanttTask> {
+        match self
+            .props
+            .get(Attribute::Content)
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Linked(tasks)) => {
+                tasks.into_iter().map(GanttTask::from_payload).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_today(&self) -> i64 {
+        match self
+            .props
+            .get(Attribute::Custom(GANTT_TODAY))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::I64(timestamp))) => timestamp,
+            _ => ClockStates::now(),
+        }
+    }
+
+    /// ### render_axis
+    ///
+    /// Render the time axis in the first two rows of `area`, with a label and a tick every few columns
+    fn render_axis(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let fmt = self
+            .props
+            .get(Attribute::Custom(GANTT_AXIS_FORMAT))
+            .map(|x| x.unwrap_string())
+            .unwrap_or_else(|| DEFAULT_AXIS_FORMAT.to_string());
+        // Ticks are aligned to the time, so that they don't move while scrolling
+        let spacing = format_timestamp(self.states.offset as f64, &fmt).width() as i64 + 2;
+        let first = self.states.offset.div_euclid(self.states.scale);
+        for x in area.left()..area.right() {
+            let column = i64::from(x - area.x);
+            let tick = (first + column).rem_euclid(spacing) == 0;
+            if tick && area.height > 0 {
+                let time = self.states.offset + column * self.states.scale;
+                let label = format_timestamp(time as f64, &fmt);
+                // Labels are displayed only if they fit
+                if x as usize + label.width() <= area.right() as usize {
+                    buf.set_string(x, area.y, label, style);
+                }
+            }
+            if area.height > 1 {
+                buf[(x, area.y + 1)]
+                    .set_symbol(if tick { "┬" } else { "─" })
+                    .set_style(style);
+            }
+        }
+    }
+
+    /// ### render_chart
+    ///
+    /// Render the labels and the time axis of the tasks in `area`, scrolled to the selected task
+    fn render_chart(&mut self, buf: &mut Buffer, area: Rect, style: Style, highlight: Style) {
+        let tasks = self.get_tasks();
+        let label_width =
+            (tasks.iter().map(|x| x.label.width()).max().unwrap_or(0) as u16).min(area.width / 3);
+        // Labels, separator, then the time window
+        let chart = Rect::new(
+            area.x + (label_width + 1).min(area.width),
+            area.y,
+            area.width.saturating_sub(label_width + 1),
+            area.height,
+        );
+        self.states.width = chart.width;
+        let height = area.height.saturating_sub(AXIS_HEIGHT);
+        self.states.fit(height as usize);
+        if label_width < area.width {
+            for y in area.top()..area.bottom() {
+                buf[(area.x + label_width, y)]
+                    .set_symbol(if y == area.y + 1 { "┼" } else { "│" })
+                    .set_style(style);
+            }
+            if area.height > 1 {
+                for x in area.x..area.x + label_width {
+                    buf[(x, area.y + 1)].set_symbol("─").set_style(style);
+                }
+            }
+        }
+        self.render_axis(buf, chart, style);
+        let tasks_area = Rect::new(
+            area.x,
+            area.y + AXIS_HEIGHT.min(area.height),
+            area.width,
+            height,
+        );
+        let visible = self.states.scroll..(self.states.scroll + height as usize).min(tasks.len());
+        // Cell of a task row and a column of the time window, if visible
+        let cell = |row: usize, column: i64| -> Option<(u16, u16)> {
+            match visible.contains(&row) && (0..i64::from(chart.width)).contains(&column) {
+                true => Some((
+                    chart.x + column as u16,
+                    tasks_area.y + (row - visible.start) as u16,
+                )),
+                false => None,
+            }
+        };
+        // Labels and bars
+        for row in visible.clone() {
+            let task = &tasks[row];
+            let y = tasks_area.y + (row - visible.start) as u16;
+            buf.set_stringn(area.x, y, &task.label, label_width as usize, style);
+            if row == self.states.selected {
+                buf.set_style(Rect::new(area.x, y, label_width, 1), highlight);
+            }
+            let bar_style = match task.color {
+                Color::Reset => style,
+                color => style.fg(color),
+            };
+            let start = self.states.column(task.start);
+            match task.end > task.start {
+                true => {
+                    // Bars cover at least a column
+                    let end = self.states.column(task.end - 1) + 1;
+                    for (x, y) in (start..end).filter_map(|column| cell(row, column)) {
+                        buf[(x, y)].set_symbol("█").set_style(bar_style);
+                    }
+                }
+                false => {
+                    if let Some((x, y)) = cell(row, start) {
+                        buf[(x, y)].set_symbol("◆").set_style(bar_style);
+                    }
+                }
+            }
+        }
+        // Connectors from the end of dependencies to the start of tasks, drawn only on empty cells
+        let mut connect = |row: usize, column: i64, symbol: &str| {
+            if let Some(position) = cell(row, column) {
+                if buf[position].symbol() == " " {
+                    buf[position].set_symbol(symbol).set_style(style);
+                }
+            }
+        };
+        for (row, task) in tasks.iter().enumerate() {
+            let start = self.states.column(task.start);
+            for &dependency in task.dependencies.iter().filter(|x| **x != row) {
+                let Some(from) = tasks.get(dependency) else {
+                    continue;
+                };
+                let column = match from.end > from.start {
+                    true => self.states.column(from.end - 1) + 1,
+                    false => self.states.column(from.end) + 1,
+                };
+                let down = row > dependency;
+                connect(dependency, column, if down { "┐" } else { "┘" });
+                for between in row.min(dependency) + 1..row.max(dependency) {
+                    connect(between, column, "│");
+                }
+                if column < start {
+                    connect(row, column, if down { "└" } else { "┌" });
+                    for column in column + 1..start {
+                        connect(row, column, "─");
+                    }
+                }
+            }
+        }
+        // Today marker
+        let today = self.states.column(self.get_today());
+        if (0..i64::from(chart.width)).contains(&today) {
+            let today_style = style.fg(self
+                .props
+                .get_or(
+                    Attribute::Custom(GANTT_TODAY_COLOR),
+                    AttrValue::Color(Color::Red),
+                )
+                .unwrap_color());
+            if chart.height > 1 {
+                buf[(chart.x + today as u16, chart.y + 1)]
+                    .set_symbol("▼")
+                    .set_style(today_style);
+            }
+            for (x, y) in visible.clone().filter_map(|row| cell(row, today)) {
+                if buf[(x, y)].symbol() == " " {
+                    buf[(x, y)].set_symbol("┊").set_style(today_style);
+                }
+            }
+        }
+    }
+}
+
+impl MockComponent for Gantt {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let mut highlight = Style::default();
+            if let Some(color) = self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color())
+            {
+                highlight = highlight.fg(color);
+            }
+            if focus {
+                highlight = highlight.add_modifier(TextModifiers::REVERSED);
+            }
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_chart(render.buffer_mut(), inner, style, highlight);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Content => {
+                let tasks = self.get_tasks();
+                self.states.set_len(tasks.len());
+                if self.props.get(Attribute::Custom(GANTT_START)).is_none() {
+                    if let Some(start) = tasks.iter().map(|x| x.start).min() {
+                        self.states.offset = start;
+                    }
+                }
+            }
+            Attribute::Value => {
+                if let Some(PropPayload::One(PropValue::Usize(index))) =
+                    self.props.get(attr).map(|x| x.unwrap_payload())
+                {
+                    self.states.select(index);
+                }
+            }
+            Attribute::Custom(GANTT_SCALE) => {
+                if let Some(PropPayload::One(PropValue::U64(seconds))) =
+                    self.props.get(attr).map(|x| x.unwrap_payload())
+                {
+                    self.states.scale = (seconds as i64).max(1);
+                }
+            }
+            Attribute::Custom(GANTT_START) => {
+                if let Some(PropPayload::One(PropValue::I64(timestamp))) =
+                    self.props.get(attr).map(|x| x.unwrap_payload())
+                {
+                    self.states.offset = timestamp;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.len {
+            0 => State::None,
+            _ => State::One(StateValue::Usize(self.states.selected)),
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length() as i64;
+        let prev = self.states.selected;
+        match cmd {
+            Cmd::Move(Direction::Down) => self.states.select(prev + 1),
+            Cmd::Move(Direction::Up) => self.states.select(prev.saturating_sub(1)),
+            Cmd::GoTo(Position::Begin) => self.states.select(0),
+            Cmd::GoTo(Position::End) => self.states.select(usize::MAX),
+            Cmd::Move(Direction::Right) => self.states.scroll_by(1),
+            Cmd::Move(Direction::Left) => self.states.scroll_by(-1),
+            Cmd::Scroll(Direction::Right) => self.states.scroll_by(step),
+            Cmd::Scroll(Direction::Left) => self.states.scroll_by(-step),
+            Cmd::Custom(GANTT_CMD_ZOOM_IN) => self.states.zoom(self.states.scale / 2),
+            Cmd::Custom(GANTT_CMD_ZOOM_OUT) => self.states.zoom(self.states.scale * 2),
+            Cmd::Submit => {
+                return match self.state() {
+                    State::None => CmdResult::None,
+                    state => CmdResult::Submit(state),
+                }
+            }
+            _ => return CmdResult::None,
+        }
+        match self.states.selected != prev {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    const DAY: i64 = 86400;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn component() -> Gantt {
+        Gantt::default().axis_format("%d").today(4 * DAY).tasks(&[
+            GanttTask::new("Design", 0, 3 * DAY).color(Color::Blue),
+            GanttTask::new("Build", 3 * DAY, 8 * DAY).depends_on(0),
+            GanttTask::new("Review", 5 * DAY, 7 * DAY).depends_on(0),
+            GanttTask::new("Launch", 10 * DAY, 10 * DAY).depends_on(1),
+        ])
+    }
+
+    #[test]
+    fn test_components_gantt() {
+        let mut component = component();
+        assert_eq!(component.states.len, 4);
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::One(StateValue::Usize(3)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(3)))
+        );
+        // Time window
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.offset, 8 * DAY);
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(component.states.offset, 7 * DAY);
+        component.states.width = 20;
+        component.perform(Cmd::Custom(GANTT_CMD_ZOOM_OUT));
+        assert_eq!(component.states.scale, 2 * DAY);
+        assert_eq!(component.states.offset, -3 * DAY);
+        component.perform(Cmd::Custom(GANTT_CMD_ZOOM_IN));
+        assert_eq!(component.states.scale, DAY);
+        assert_eq!(component.states.offset, 7 * DAY);
+        // Start is kept when tasks change
+        component.attr(
+            Attribute::Custom(GANTT_START),
+            AttrValue::Payload(PropPayload::One(PropValue::I64(DAY))),
+        );
+        component.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(Default::default())),
+        );
+        assert_eq!(component.states.offset, DAY);
+        assert_eq!(component.state(), State::None);
+    }
+
+    #[test]
+    fn test_components_gantt_render() {
+        let mut component = component();
+        let area = Rect::new(0, 0, 20, 6);
+        let mut buf = Buffer::empty(area);
+        component.render_chart(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![
+                "      │01  05  09",
+                "──────┼┬───▼───┬───┬",
+                "Design│███┐┊",
+                "Build │   █████┐",
+                "Review│   └─██ │",
+                "Launch│    ┊   └─◆",
+            ]
+        );
+        assert_eq!(buf[(7, 2)].fg, Color::Blue);
+        assert_eq!(buf[(11, 2)].fg, Color::Red);
+        // Scroll to the last task
+        component.states.select(3);
+        let area = Rect::new(0, 0, 20, 4);
+        let mut buf = Buffer::empty(area);
+        component.render_chart(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(component.states.scroll, 2);
+        assert_eq!(rows(&buf)[3], "Launch│    ┊   └─◆");
+    }
+}
@@ -0,0 +1,454 @@
+//! ## Grid
+//!
+//! `Grid` is a container arranging components in rows and columns
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{GRID_CMD_NEXT_CELL, GRID_CMD_PREV_CELL};
+use super::props::{GRID_GAP, GRID_SIZE, TOOLTIP};
+
+/// ## GridCell
+///
+/// A cell of the `Grid`: a component, placed at a row and a column, spanning one or more of them
+pub struct GridCell {
+    pub component: Box<dyn MockComponent>,
+    pub row: usize,
+    pub column: usize,
+    /// Rows taken by the cell, from `row`
+    pub row_span: usize,
+    /// Columns taken by the cell, from `column`
+    pub column_span: usize,
+}
+
+impl GridCell {
+    pub fn new(row: usize, column: usize, component: Box<dyn MockComponent>) -> Self {
+        Self {
+            component,
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+        }
+    }
+
+    pub fn row_span(mut self, span: usize) -> Self {
+        self.row_span = span.max(1);
+        self
+    }
+
+    pub fn column_span(mut self, span: usize) -> Self {
+        self.column_span = span.max(1);
+        self
+    }
+}
+
+/// ### tracks
+///
+/// Split `length` in `count` tracks, separated by `gap`, as their start and length.
+/// The cells left by the division are given to the first tracks
+fn tracks(start: u16, length: u16, count: usize, gap: u16) -> Vec<(u16, u16)> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let gaps = (gap as usize).saturating_mul(count - 1);
+    let available = (length as usize).saturating_sub(gaps);
+    let (size, rest) = (available / count, available % count);
+    let mut position = start as usize;
+    (0..count)
+        .map(|i| {
+            let track = size + usize::from(i < rest);
+            let x = (position as u16, track as u16);
+            position += track + gap as usize;
+            x
+        })
+        .collect()
+}
+
+// -- states
+
+/// ## GridStates
+///
+/// GridStates contains states for this component
+#[derive(Default)]
+pub struct GridStates {
+    /// Index of the active cell, receiving commands and focus
+    pub active: usize,
+}
+
+impl GridStates {
+    /// ### next_cell
+    ///
+    /// Make the next cell out of `len` active, going back to the first one after the last one
+    pub fn next_cell(&mut self, len: usize) {
+        match self.active + 1 < len {
+            true => self.active += 1,
+            false => self.active = 0,
+        }
+    }
+
+    /// ### prev_cell
+    ///
+    /// Make the previous cell out of `len` active, going to the last one before the first one
+    pub fn prev_cell(&mut self, len: usize) {
+        match self.active {
+            0 => self.active = len.saturating_sub(1),
+            _ => self.active -= 1,
+        }
+    }
+}
+
+// -- component
+
+/// ## Grid
+///
+/// A container arranging its cells in rows and columns of equal size, separated by a gap. A cell can span several rows
+/// and columns. Commands are forwarded to the active cell, which is the only one receiving focus
+#[derive(Default)]
+pub struct Grid {
+    props: Props,
+    pub states: GridStates,
+    /// Grid cells; the order is the order in which the active cell moves
+    pub cells: Vec<GridCell>,
+}
+
+impl Grid {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `grid` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("grid", self);
+    }
+
+    /// Set the number of rows and columns of the grid. If not set, the grid fits its cells
+    pub fn size(mut self, rows: usize, columns: usize) -> Self {
+        self.attr(
+            Attribute::Custom(GRID_SIZE),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::Usize(rows),
+                PropValue::Usize(columns),
+            ))),
+        );
+        self
+    }
+
+    /// Set the gap between rows and between columns (default: none)
+    pub fn gap(mut self, rows: u16, columns: u16) -> Self {
+        self.attr(
+            Attribute::Custom(GRID_GAP),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::U16(rows),
+                PropValue::U16(columns),
+            ))),
+        );
+        self
+    }
+
+    pub fn cells(mut self, cells: Vec<GridCell>) -> Self {
+        self.states.active = 0;
+        self.cells = cells;
+        self.focus_cells();
+        self
+    }
+
+    /// ### focus_cells
+    ///
+    /// Give focus to the active cell, if the grid is focused, and remove it from the other ones
+    fn focus_cells(&mut self) {
+        let focus = self
+            .props
+            .get_or(Attribute::Focus, AttrValue::Flag(false))
+            .unwrap_flag();
+        for (i, cell) in self.cells.iter_mut().enumerate() {
+            cell.component.attr(
+                Attribute::Focus,
+                AttrValue::Flag(focus && i == self.states.active),
+            );
+        }
+    }
+
+    /// ### grid_size
+    ///
+    /// Get the number of rows and columns, fitting the cells if not set
+    fn grid_size(&self) -> (usize, usize) {
+        match self
+            .props
+            .get(Attribute::Custom(GRID_SIZE))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Tup2((PropValue::Usize(rows), PropValue::Usize(columns)))) => {
+                (rows, columns)
+            }
+            _ => self.cells.iter().fold((0, 0), |(rows, columns), cell| {
+                (
+                    rows.max(cell.row + cell.row_span),
+                    columns.max(cell.column + cell.column_span),
+                )
+            }),
+        }
+    }
+
+    fn get_gap(&self) -> (u16, u16) {
+        match self
+            .props
+            .get(Attribute::Custom(GRID_GAP))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Tup2((PropValue::U16(rows), PropValue::U16(columns)))) => {
+                (rows, columns)
+            }
+            _ => (0, 0),
+        }
+    }
+
+    /// ### layout
+    ///
+    /// Get the area of each cell in `area`. Spans are cut at the edges of the grid; cells out of it have no area
+    fn layout(&self, area: Rect) -> Vec<Rect> {
+        let (rows, columns) = self.grid_size();
+        let (row_gap, column_gap) = self.get_gap();
+        let rows = tracks(area.y, area.height, rows, row_gap);
+        let columns = tracks(area.x, area.width, columns, column_gap);
+        // Get the start and the length of the tracks from `first` to `first + span`
+        let span = |tracks: &[(u16, u16)], first: usize, span: usize| {
+            let last = (first + span).min(tracks.len()).checked_sub(1)?;
+            let (start, _) = tracks.get(first)?;
+            let (end, length) = tracks[last];
+            Some((*start, end + length - start))
+        };
+        self.cells
+            .iter()
+            .map(|cell| {
+                match (
+                    span(&rows, cell.row, cell.row_span),
+                    span(&columns, cell.column, cell.column_span),
+                ) {
+                    (Some((y, height)), Some((x, width))) => Rect::new(x, y, width, height),
+                    _ => Rect::default(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl MockComponent for Grid {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            let layout = self.layout(inner);
+            for (cell, cell_area) in self.cells.iter_mut().zip(layout) {
+                if !cell_area.is_empty() {
+                    cell.component.view(render, cell_area);
+                }
+            }
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if attr == Attribute::Focus {
+            self.focus_cells();
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.cells.get(self.states.active) {
+            Some(cell) => cell.component.state(),
+            None => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let len = self.cells.len();
+        match cmd {
+            _ if len == 0 => return CmdResult::None,
+            Cmd::Custom(GRID_CMD_NEXT_CELL) => self.states.next_cell(len),
+            Cmd::Custom(GRID_CMD_PREV_CELL) => self.states.prev_cell(len),
+            cmd => {
+                return match self.cells.get_mut(self.states.active) {
+                    Some(cell) => cell.component.perform(cmd),
+                    None => CmdResult::None,
+                };
+            }
+        }
+        self.focus_cells();
+        CmdResult::Changed(State::One(StateValue::Usize(self.states.active)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::components::{Label, Radio};
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::command::Direction;
+
+    fn is_focused(cell: &GridCell) -> bool {
+        cell.component
+            .query(Attribute::Focus)
+            .map(|x| x.unwrap_flag())
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_components_grid_layout() {
+        let component = Grid::default().gap(1, 2).cells(vec![
+            GridCell::new(0, 0, Box::new(Label::default())).column_span(2),
+            GridCell::new(0, 2, Box::new(Label::default())).row_span(2),
+            GridCell::new(1, 0, Box::new(Label::default())),
+            GridCell::new(1, 1, Box::new(Label::default())),
+        ]);
+        assert_eq!(component.grid_size(), (2, 3));
+        // 3 columns of 20 cells with 2 gaps of 2: the 2 cells left go to the first columns
+        assert_eq!(
+            component.layout(Rect::new(0, 0, 66, 11)),
+            vec![
+                Rect::new(0, 0, 44, 5),
+                Rect::new(46, 0, 20, 11),
+                Rect::new(0, 6, 21, 5),
+                Rect::new(23, 6, 21, 5),
+            ]
+        );
+        // Out of the grid
+        let component = component.size(1, 2).cells(vec![
+            GridCell::new(0, 1, Box::new(Label::default()))
+                .row_span(3)
+                .column_span(3),
+            GridCell::new(1, 0, Box::new(Label::default())),
+        ]);
+        assert_eq!(
+            component.layout(Rect::new(0, 0, 10, 4)),
+            vec![Rect::new(6, 0, 4, 4), Rect::default()]
+        );
+    }
+
+    #[test]
+    fn test_tracks() {
+        assert_eq!(tracks(2, 10, 3, 1), vec![(2, 3), (6, 3), (10, 2)]);
+        assert_eq!(tracks(0, 10, 0, 1), vec![]);
+        assert_eq!(tracks(0, 2, 3, 1), vec![(0, 0), (1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn test_components_grid() {
+        let mut component = Grid::default().cells(vec![
+            GridCell::new(0, 0, Box::new(Radio::default().choices(&["a", "b"]))),
+            GridCell::new(0, 1, Box::new(Radio::default().choices(&["c", "d"]))),
+        ]);
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        assert!(is_focused(&component.cells[0]));
+        assert!(!is_focused(&component.cells[1]));
+        // Commands are forwarded to the active cell
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(GRID_CMD_NEXT_CELL)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert!(!is_focused(&component.cells[0]));
+        assert!(is_focused(&component.cells[1]));
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        assert_eq!(
+            component.perform(Cmd::Custom(GRID_CMD_NEXT_CELL)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(GRID_CMD_PREV_CELL)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        // Blur
+        component.attr(Attribute::Focus, AttrValue::Flag(false));
+        assert!(!is_focused(&component.cells[1]));
+    }
+}
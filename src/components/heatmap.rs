@@ -0,0 +1,429 @@
+//! ## Heatmap
+//!
+//! A grid of values rendered as colored cells
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, render_disabled, render_tooltip, FocusStyles};
+
+// -- Props
+
+use super::props::{
+    HEATMAP_CELL_LABELS, HEATMAP_COLORS, HEATMAP_RANGE, HEATMAP_X_LABELS, HEATMAP_Y_LABELS, TOOLTIP,
+};
+
+/// Color scale used if no colors are set
+const DEFAULT_COLORS: [Color; 5] = [
+    Color::Blue,
+    Color::Cyan,
+    Color::Green,
+    Color::Yellow,
+    Color::Red,
+];
+
+// -- component
+
+/// ## Heatmap
+///
+/// A component to display a matrix of values as a grid of colored cells.
+/// Each value is mapped to a color of the color scale, from the lowest to the highest value.
+#[derive(Default)]
+pub struct Heatmap {
+    props: Props,
+}
+
+impl Heatmap {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `heatmap` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("heatmap", self);
+    }
+
+    /// Set data as a list of rows
+    pub fn data(mut self, rows: &[&[f64]]) -> Self {
+        self.attr(
+            Attribute::Dataset,
+            AttrValue::Payload(PropPayload::Linked(
+                rows.iter()
+                    .map(|row| PropPayload::Vec(row.iter().map(|x| PropValue::F64(*x)).collect()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the color scale, from the color for the lowest values to the color for the highest values
+    pub fn colors(mut self, colors: &[Color]) -> Self {
+        self.attr(
+            Attribute::Custom(HEATMAP_COLORS),
+            AttrValue::Payload(PropPayload::Vec(
+                colors.iter().map(|x| PropValue::Color(*x)).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the range of values mapped on the color scale. If not set, the lowest and the highest values in data are used
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.attr(
+            Attribute::Custom(HEATMAP_RANGE),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::F64(min),
+                PropValue::F64(max),
+            ))),
+        );
+        self
+    }
+
+    /// Display values inside cells
+    pub fn cell_labels(mut self, show: bool) -> Self {
+        self.attr(
+            Attribute::Custom(HEATMAP_CELL_LABELS),
+            AttrValue::Flag(show),
+        );
+        self
+    }
+
+    /// Set the width of each cell
+    pub fn cell_width(mut self, w: u16) -> Self {
+        self.attr(Attribute::Width, AttrValue::Size(w));
+        self
+    }
+
+    /// Set labels for columns, displayed below the grid
+    pub fn x_labels(mut self, labels: &[&str]) -> Self {
+        self.attr(
+            Attribute::Custom(HEATMAP_X_LABELS),
+            Self::labels_to_prop(labels),
+        );
+        self
+    }
+
+    /// Set labels for rows, displayed on the left of the grid
+    pub fn y_labels(mut self, labels: &[&str]) -> Self {
+        self.attr(
+            Attribute::Custom(HEATMAP_Y_LABELS),
+            Self::labels_to_prop(labels),
+        );
+        self
+    }
+
+    fn labels_to_prop(labels: &[&str]) -> AttrValue {
+        AttrValue::Payload(PropPayload::Vec(
+            labels
+                .iter()
+                .map(|x| PropValue::Str(x.to_string()))
+                .collect(),
+        ))
+    }
+
+    fn get_labels(&self, attr: &'static str) -> Vec<String> {
+        self.props
+            .get(Attribute::Custom(attr))
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_vec()
+                    .into_iter()
+                    .map(|x| x.unwrap_str())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// ### get_data
+    ///
+    /// Get rows from properties
+    fn get_data(&self) -> Vec<Vec<f64>> {
+        self.props
+            .get(Attribute::Dataset)
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_linked()
+                    .into_iter()
+                    .map(|row| {
+                        row.unwrap_vec()
+                            .into_iter()
+                            .map(|x| x.unwrap_f64())
+                            .collect()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn get_colors(&self) -> Vec<Color> {
+        self.props
+            .get(Attribute::Custom(HEATMAP_COLORS))
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_vec()
+                    .into_iter()
+                    .map(|x| x.unwrap_color())
+                    .collect()
+            })
+            .filter(|x: &Vec<Color>| !x.is_empty())
+            .unwrap_or_else(|| DEFAULT_COLORS.to_vec())
+    }
+
+    /// ### get_range
+    ///
+    /// Get the range of values mapped on colors; if not set, it's calculated from data
+    fn get_range(&self, data: &[Vec<f64>]) -> (f64, f64) {
+        match self
+            .props
+            .get(Attribute::Custom(HEATMAP_RANGE))
+            .map(|x| x.unwrap_payload().unwrap_tup2())
+        {
+            Some((min, max)) => (min.unwrap_f64(), max.unwrap_f64()),
+            None => data
+                .iter()
+                .flatten()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
+                    (min.min(*x), max.max(*x))
+                }),
+        }
+    }
+
+    /// ### value_color
+    ///
+    /// Get the color for `value` from the color scale, given the `range` of values
+    fn value_color(value: f64, range: (f64, f64), colors: &[Color]) -> Color {
+        let (min, max) = range;
+        let ratio = match max > min {
+            true => ((value - min) / (max - min)).clamp(0.0, 1.0),
+            false => 0.0,
+        };
+        let index = ((ratio * colors.len() as f64) as usize).min(colors.len().saturating_sub(1));
+        colors.get(index).copied().unwrap_or(Color::Reset)
+    }
+
+    /// ### fmt_value
+    ///
+    /// Format value to fit in `width` columns: decimals are dropped if it doesn't fit, then it's truncated
+    fn fmt_value(value: f64, width: usize) -> String {
+        let label = match value.fract() == 0.0 {
+            true => format!("{value:.0}"),
+            false => format!("{value:.1}"),
+        };
+        let label = match label.len() > width {
+            true => format!("{value:.0}"),
+            false => label,
+        };
+        label.chars().take(width).collect()
+    }
+
+    /// ### render_grid
+    ///
+    /// Render cells and axis labels into `area`
+    fn render_grid(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let data = self.get_data();
+        let colors = self.get_colors();
+        let range = self.get_range(&data);
+        let cell_width = self
+            .props
+            .get_or(Attribute::Width, AttrValue::Size(2))
+            .unwrap_size()
+            .max(1);
+        let cell_labels = self
+            .props
+            .get_or(
+                Attribute::Custom(HEATMAP_CELL_LABELS),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag();
+        let x_labels = self.get_labels(HEATMAP_X_LABELS);
+        let y_labels = self.get_labels(HEATMAP_Y_LABELS);
+        // Y labels are displayed on the left; x labels take the last row
+        let y_labels_width = y_labels
+            .iter()
+            .map(|x| x.width() as u16 + 1)
+            .max()
+            .unwrap_or(0)
+            .min(area.width);
+        let grid_height = match x_labels.is_empty() {
+            true => area.height,
+            false => area.height.saturating_sub(1),
+        };
+        let grid_x = area.x + y_labels_width;
+        let columns = ((area.right() - grid_x) / cell_width) as usize;
+        for (row, values) in data.iter().take(grid_height as usize).enumerate() {
+            let y = area.y + row as u16;
+            if let Some(label) = y_labels.get(row) {
+                buf.set_stringn(area.x, y, label, y_labels_width as usize, style);
+            }
+            for (column, value) in values.iter().take(columns).enumerate() {
+                let x = grid_x + column as u16 * cell_width;
+                let color = Self::value_color(*value, range, &colors);
+                let cell = Rect::new(x, y, cell_width, 1);
+                buf.set_style(cell, Style::default().bg(color));
+                // Labels leave the last column blank, to separate them from the next cell
+                if cell_labels {
+                    buf.set_string(
+                        x,
+                        y,
+                        Self::fmt_value(*value, cell_width as usize - 1),
+                        Style::default().fg(Color::Black).bg(color),
+                    );
+                }
+            }
+        }
+        if !x_labels.is_empty() && area.height > 0 {
+            let y = area.y + grid_height.min(data.len() as u16);
+            for (column, label) in x_labels.iter().take(columns).enumerate() {
+                let x = grid_x + column as u16 * cell_width;
+                buf.set_stringn(x, y, label, cell_width as usize, style);
+            }
+        }
+    }
+}
+
+impl MockComponent for Heatmap {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let styles =
+                FocusStyles::display(&self.props, Style::default().fg(foreground).bg(background));
+            let style = styles.style;
+            let div = styles
+                .block(borders, title)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = div.inner(area);
+            render.render_widget(div, area);
+            self.render_grid(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_heatmap() {
+        let component = Heatmap::default()
+            .title("activity", Alignment::Center)
+            .borders(Borders::default())
+            .colors(&[Color::Black, Color::Green])
+            .cell_labels(true)
+            .cell_width(3)
+            .x_labels(&["mon", "tue", "wed"])
+            .y_labels(&["w1", "w2"])
+            .data(&[&[0.0, 4.0, 10.0], &[2.5, 7.0]]);
+        assert_eq!(component.state(), State::None);
+        let data = component.get_data();
+        assert_eq!(data, vec![vec![0.0, 4.0, 10.0], vec![2.5, 7.0]]);
+        assert_eq!(component.get_range(&data), (0.0, 10.0));
+        assert_eq!(
+            Heatmap::default().range(1.0, 2.0).get_range(&data),
+            (1.0, 2.0)
+        );
+        // Colors
+        let colors = component.get_colors();
+        assert_eq!(
+            Heatmap::value_color(4.0, (0.0, 10.0), &colors),
+            Color::Black
+        );
+        assert_eq!(
+            Heatmap::value_color(5.0, (0.0, 10.0), &colors),
+            Color::Green
+        );
+        assert_eq!(
+            Heatmap::value_color(99.0, (0.0, 10.0), &colors),
+            Color::Green
+        );
+        assert_eq!(Heatmap::value_color(3.0, (3.0, 3.0), &colors), Color::Black);
+        assert_eq!(Heatmap::default().get_colors(), DEFAULT_COLORS.to_vec());
+        // Render
+        let area = Rect::new(0, 0, 12, 3);
+        let mut buf = Buffer::empty(area);
+        component.render_grid(&mut buf, area, Style::default());
+        let row =
+            |y: u16| -> String { (0..12).map(|x| buf[(x, y)].symbol().to_string()).collect() };
+        assert_eq!(row(0), "w1 0  4  10 ");
+        assert_eq!(row(1), "w2 2  7     ");
+        assert_eq!(row(2), "   montuewed");
+        assert_eq!(buf[(9, 0)].bg, Color::Green);
+        assert_eq!(buf[(3, 0)].bg, Color::Black);
+        assert_eq!(Heatmap::fmt_value(2.5, 3), "2.5");
+        assert_eq!(Heatmap::fmt_value(12.5, 3), "12");
+        assert_eq!(Heatmap::fmt_value(1234.0, 2), "12");
+        assert_eq!(Heatmap::fmt_value(7.0, 0), "");
+    }
+}
@@ -0,0 +1,533 @@
+//! ## HelpOverlay
+//!
+//! `HelpOverlay` is a dialog listing the key bindings of the application, grouped by section
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Clear, Widget},
+};
+use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{
+    centered_rect_fixed, get_block, get_title_style, is_disabled, render_disabled, render_tooltip,
+};
+
+// -- Props
+
+use super::commands::HELP_OVERLAY_DISMISSED;
+use super::props::{HELP_OVERLAY_COLUMNS, TOOLTIP};
+
+/// Space between the key and its description
+const KEY_SPACING: u16 = 2;
+/// Space between columns
+const COLUMN_SPACING: u16 = 4;
+/// Horizontal padding between the borders and the content, on each side
+const PADDING: u16 = 1;
+
+/// ## HelpSection
+///
+/// A section of the `HelpOverlay`, with its title and its key bindings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpSection {
+    pub title: String,
+    /// Key bindings, as key and description
+    pub bindings: Vec<(String, String)>,
+}
+
+impl HelpSection {
+    pub fn new<S: Into<String>>(title: S) -> Self {
+        Self {
+            title: title.into(),
+            bindings: Vec::new(),
+        }
+    }
+
+    pub fn binding<K: Into<String>, D: Into<String>>(mut self, key: K, description: D) -> Self {
+        self.bindings.push((key.into(), description.into()));
+        self
+    }
+
+    /// ### height
+    ///
+    /// Returns the number of rows of the section, title included
+    fn height(&self) -> usize {
+        self.bindings.len() + 1
+    }
+
+    fn to_payload(&self) -> PropPayload {
+        PropPayload::Vec(
+            std::iter::once(PropValue::Str(self.title.clone()))
+                .chain(self.bindings.iter().flat_map(|(key, description)| {
+                    [
+                        PropValue::Str(key.clone()),
+                        PropValue::Str(description.clone()),
+                    ]
+                }))
+                .collect(),
+        )
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        let mut values = payload.unwrap_vec().into_iter().map(|x| x.unwrap_str());
+        let title = values.next().unwrap_or_default();
+        let mut bindings = Vec::new();
+        while let (Some(key), Some(description)) = (values.next(), values.next()) {
+            bindings.push((key, description));
+        }
+        Self { title, bindings }
+    }
+}
+
+/// ### split_columns
+///
+/// Distribute the sections, keeping their order, in at most `columns` columns of balanced height; a section is
+/// never split between columns. Returns the indexes of the sections of each column
+fn split_columns(sections: &[HelpSection], columns: usize) -> Vec<Vec<usize>> {
+    // Sections in the same column are separated by an empty row
+    let total: usize = sections.iter().map(|x| x.height() + 1).sum();
+    let target = total.div_ceil(columns.max(1));
+    let mut split: Vec<Vec<usize>> = vec![Vec::new()];
+    let mut height = 0;
+    for (i, section) in sections.iter().enumerate() {
+        if height > 0 && height + section.height() > target && split.len() < columns {
+            split.push(Vec::new());
+            height = 0;
+        }
+        height += section.height() + 1;
+        if let Some(column) = split.last_mut() {
+            column.push(i);
+        }
+    }
+    split
+}
+
+/// ### content_height
+///
+/// Get the number of rows of the tallest column
+fn content_height(sections: &[HelpSection], layout: &[(Vec<usize>, u16, u16)]) -> usize {
+    layout
+        .iter()
+        .map(|(column, _, _)| column.iter().map(|i| sections[*i].height() + 1).sum())
+        .max()
+        .unwrap_or(0usize)
+        .saturating_sub(1)
+}
+
+// -- states
+
+/// ## HelpOverlayStates
+///
+/// HelpOverlayStates contains states for this component
+#[derive(Default)]
+pub struct HelpOverlayStates {
+    /// Index of the first row displayed
+    pub scroll: usize,
+    /// Maximum scroll, according to the size of the last render
+    pub max_scroll: usize,
+}
+
+impl HelpOverlayStates {
+    /// ### scroll_by
+    ///
+    /// Scroll by `offset` rows, staying in the content
+    pub fn scroll_by(&mut self, offset: isize) {
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(offset)
+            .min(self.max_scroll);
+    }
+}
+
+// -- component
+
+/// ## HelpOverlay
+///
+/// A dialog, centered in the area it is rendered into, listing key bindings and their description grouped by
+/// section. Sections are distributed in columns, and the dialog is scrolled when they don't fit. `Cmd::Cancel`
+/// dismisses it, hiding it and returning `CmdResult::Custom(HELP_OVERLAY_DISMISSED)`.
+///
+/// Render it with the whole view as area, after the other components.
+#[derive(Default)]
+pub struct HelpOverlay {
+    props: Props,
+    pub states: HelpOverlayStates,
+}
+
+impl HelpOverlay {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `help_overlay` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("help_overlay", self);
+    }
+
+    /// Set the color of the keys
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn sections(mut self, sections: &[HelpSection]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(
+                sections.iter().map(|x| x.to_payload()).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the maximum number of columns (default: 2)
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.attr(
+            Attribute::Custom(HELP_OVERLAY_COLUMNS),
+            AttrValue::Length(columns),
+        );
+        self
+    }
+
+    /// Set the width of the dialog, including borders; by default it fits the content
+    pub fn width(mut self, w: u16) -> Self {
+        self.attr(Attribute::Width, AttrValue::Size(w));
+        self
+    }
+
+    /// Set the height of the dialog, including borders; by default it fits the content
+    pub fn height(mut self, h: u16) -> Self {
+        self.attr(Attribute::Height, AttrValue::Size(h));
+        self
+    }
+
+    fn get_sections(&self) -> Vec<HelpSection> {
+        match self
+            .props
+            .get(Attribute::Content)
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Linked(sections)) => sections
+                .into_iter()
+                .map(HelpSection::from_payload)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### layout
+    ///
+    /// Get the columns of the content, as the sections they contain, the width of their keys and their width
+    fn layout(&self, sections: &[HelpSection]) -> Vec<(Vec<usize>, u16, u16)> {
+        let columns = self
+            .props
+            .get_or(
+                Attribute::Custom(HELP_OVERLAY_COLUMNS),
+                AttrValue::Length(2),
+            )
+            .unwrap_length();
+        split_columns(sections, columns)
+            .into_iter()
+            .map(|column| {
+                let bindings = || column.iter().flat_map(|i| sections[*i].bindings.iter());
+                let key_width = bindings().map(|(x, _)| x.width()).max().unwrap_or(0) as u16;
+                let description_width =
+                    bindings().map(|(_, x)| x.width()).max().unwrap_or(0) as u16;
+                let title_width = column
+                    .iter()
+                    .map(|i| sections[*i].title.width())
+                    .max()
+                    .unwrap_or(0) as u16;
+                let width = title_width.max(key_width + KEY_SPACING + description_width);
+                (column, key_width, width)
+            })
+            .collect()
+    }
+
+    /// ### render_content
+    ///
+    /// Render the columns of sections in `area`, scrolled by the current scroll
+    fn render_content(&mut self, buf: &mut Buffer, area: Rect, style: Style, key_style: Style) {
+        let sections = self.get_sections();
+        let layout = self.layout(&sections);
+        let height = content_height(&sections, &layout);
+        self.states.max_scroll = height.saturating_sub(area.height as usize);
+        self.states.scroll = self.states.scroll.min(self.states.max_scroll);
+        let title_style = style.add_modifier(TextModifiers::BOLD | TextModifiers::UNDERLINED);
+        let mut x = area.x;
+        for (column, key_width, width) in layout {
+            if x >= area.right() {
+                break;
+            }
+            let max_width = area.right().saturating_sub(x) as usize;
+            let mut rows: Vec<(&str, Option<&str>)> = Vec::new();
+            for i in column {
+                if !rows.is_empty() {
+                    rows.push(("", None));
+                }
+                rows.push((sections[i].title.as_str(), None));
+                rows.extend(
+                    sections[i]
+                        .bindings
+                        .iter()
+                        .map(|(key, description)| (key.as_str(), Some(description.as_str()))),
+                );
+            }
+            for (y, row) in (area.y..area.bottom()).zip(rows.into_iter().skip(self.states.scroll)) {
+                match row {
+                    (title, None) => {
+                        buf.set_stringn(x, y, title, max_width, title_style);
+                    }
+                    (key, Some(description)) => {
+                        buf.set_stringn(x, y, key, max_width, key_style);
+                        let offset = key_width + KEY_SPACING;
+                        if (offset as usize) < max_width {
+                            buf.set_stringn(
+                                x + offset,
+                                y,
+                                description,
+                                max_width - offset as usize,
+                                style,
+                            );
+                        }
+                    }
+                }
+            }
+            x = x.saturating_add(width + COLUMN_SPACING);
+        }
+    }
+
+    /// ### dialog_size
+    ///
+    /// Get the size of the dialog: the width and height properties if set, otherwise the size fitting the content
+    fn dialog_size(&self) -> (u16, u16) {
+        let sections = self.get_sections();
+        let layout = self.layout(&sections);
+        let content_width: u16 = layout.iter().map(|(_, _, width)| *width).sum::<u16>()
+            + COLUMN_SPACING * (layout.len() as u16).saturating_sub(1);
+        let content_height = content_height(&sections, &layout) as u16;
+        (
+            self.props
+                .get(Attribute::Width)
+                .map(|x| x.unwrap_size())
+                .unwrap_or(content_width + PADDING * 2 + 2),
+            self.props
+                .get(Attribute::Height)
+                .map(|x| x.unwrap_size())
+                .unwrap_or(content_height + 2),
+        )
+    }
+}
+
+impl MockComponent for HelpOverlay {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let highlighted = self
+                .props
+                .get_or(Attribute::HighlightedColor, AttrValue::Color(foreground))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let style = Style::default().fg(foreground).bg(background);
+            let (width, height) = self.dialog_size();
+            let dialog = centered_rect_fixed(width, height, area);
+            let buf = render.buffer_mut();
+            Clear.render(dialog, buf);
+            let block = get_block(borders, title, true, None)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(dialog);
+            block.render(dialog, buf);
+            let content = Rect {
+                x: inner.x + PADDING.min(inner.width / 2),
+                width: inner.width.saturating_sub(PADDING * 2),
+                ..inner
+            };
+            self.render_content(
+                buf,
+                content,
+                style,
+                style.fg(highlighted).add_modifier(TextModifiers::BOLD),
+            );
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        if attr == Attribute::Content {
+            self.states.scroll = 0;
+        }
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Move(Direction::Down) => self.states.scroll_by(1),
+            Cmd::Move(Direction::Up) => self.states.scroll_by(-1),
+            Cmd::Scroll(Direction::Down) => {
+                let step = self
+                    .props
+                    .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+                    .unwrap_length();
+                self.states.scroll_by(step as isize);
+            }
+            Cmd::Scroll(Direction::Up) => {
+                let step = self
+                    .props
+                    .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+                    .unwrap_length();
+                self.states.scroll_by(-(step as isize));
+            }
+            Cmd::GoTo(Position::Begin) => self.states.scroll = 0,
+            Cmd::GoTo(Position::End) => self.states.scroll = self.states.max_scroll,
+            Cmd::Cancel => {
+                self.attr(Attribute::Display, AttrValue::Flag(false));
+                self.states.scroll = 0;
+                return CmdResult::Custom(HELP_OVERLAY_DISMISSED, State::None);
+            }
+            _ => {}
+        }
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn sections() -> Vec<HelpSection> {
+        vec![
+            HelpSection::new("General")
+                .binding("q", "Quit")
+                .binding("?", "Help"),
+            HelpSection::new("Move")
+                .binding("j", "Down")
+                .binding("k", "Up")
+                .binding("gg", "Top"),
+            HelpSection::new("Edit").binding("dd", "Delete"),
+        ]
+    }
+
+    #[test]
+    fn test_components_help_overlay_columns() {
+        let sections = sections();
+        assert_eq!(split_columns(&sections, 1), vec![vec![0, 1, 2]]);
+        assert_eq!(split_columns(&sections, 2), vec![vec![0], vec![1, 2]]);
+        assert_eq!(split_columns(&sections, 3), vec![vec![0], vec![1], vec![2]]);
+        assert_eq!(
+            HelpSection::from_payload(sections[1].to_payload()),
+            sections[1]
+        );
+    }
+
+    #[test]
+    fn test_components_help_overlay() {
+        let mut component = HelpOverlay::default().sections(&sections()).columns(2);
+        assert_eq!(component.dialog_size(), (25, 9));
+        let area = Rect::new(0, 0, 24, 4);
+        let mut buf = Buffer::empty(area);
+        component.render_content(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![
+                "General    Move         ",
+                "q  Quit    j   Down     ",
+                "?  Help    k   Up       ",
+                "           gg  Top      ",
+            ]
+        );
+        // Scroll
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.scroll, 3);
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.states.scroll, 3);
+        let mut buf = Buffer::empty(area);
+        component.render_content(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(rows(&buf)[3], "           dd  Delete   ");
+        // Dismiss
+        assert_eq!(
+            component.perform(Cmd::Cancel),
+            CmdResult::Custom(HELP_OVERLAY_DISMISSED, State::None)
+        );
+        assert_eq!(
+            component.query(Attribute::Display),
+            Some(AttrValue::Flag(false))
+        );
+    }
+}
@@ -0,0 +1,516 @@
+//! ## Histogram
+//!
+//! A chart to display the distribution of samples
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    symbols::Marker,
+    widgets::{
+        canvas::{Canvas as TuiCanvas, Line},
+        Widget,
+    },
+};
+use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, render_disabled, render_tooltip, FocusStyles};
+
+// -- Props
+
+use super::props::{
+    HISTOGRAM_BARS_STYLE, HISTOGRAM_BINS, HISTOGRAM_BIN_WIDTH, HISTOGRAM_CUMULATIVE,
+    HISTOGRAM_CUMULATIVE_STYLE, TOOLTIP,
+};
+
+/// Bins count used if neither bins nor bin width are set
+const DEFAULT_BINS: usize = 10;
+/// Symbols for the top of bars, by eighths of a cell
+const BAR_SYMBOLS: [&str; 9] = [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+
+// -- bins
+
+/// ### Bins
+///
+/// Samples counted into bins of the same width, from `start`
+#[derive(Debug, Clone, PartialEq)]
+struct Bins {
+    start: f64,
+    width: f64,
+    counts: Vec<u64>,
+}
+
+impl Bins {
+    /// ### with_count
+    ///
+    /// Split the range of samples in `bins` bins
+    fn with_count(samples: &[f64], bins: usize) -> Self {
+        let bins = bins.max(1);
+        let (min, max) = Self::range(samples);
+        let width = match max > min {
+            true => (max - min) / bins as f64,
+            false => 1.0,
+        };
+        Self::count(samples, min, width, bins)
+    }
+
+    /// ### with_width
+    ///
+    /// Split the range of samples in bins of `width`, aligned to multiples of `width`
+    fn with_width(samples: &[f64], width: f64) -> Self {
+        let (min, max) = Self::range(samples);
+        let start = (min / width).floor() * width;
+        let bins = ((max - start) / width).floor() as usize + 1;
+        Self::count(samples, start, width, bins)
+    }
+
+    fn range(samples: &[f64]) -> (f64, f64) {
+        match samples.is_empty() {
+            true => (0.0, 0.0),
+            false => samples
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
+                    (min.min(*x), max.max(*x))
+                }),
+        }
+    }
+
+    fn count(samples: &[f64], start: f64, width: f64, bins: usize) -> Self {
+        let mut counts = vec![0; bins];
+        for sample in samples {
+            let bin = (((sample - start) / width).floor() as usize).min(bins - 1);
+            counts[bin] += 1;
+        }
+        Self {
+            start,
+            width,
+            counts,
+        }
+    }
+
+    fn end(&self) -> f64 {
+        self.start + self.width * self.counts.len() as f64
+    }
+
+    /// ### cumulative
+    ///
+    /// Get the cumulative fraction of samples at the end of each bin
+    fn cumulative(&self) -> Vec<f64> {
+        let total: u64 = self.counts.iter().sum();
+        self.counts
+            .iter()
+            .scan(0, |acc, count| {
+                *acc += count;
+                Some(match total {
+                    0 => 0.0,
+                    total => *acc as f64 / total as f64,
+                })
+            })
+            .collect()
+    }
+}
+
+// -- component
+
+/// ## Histogram
+///
+/// A component to display the distribution of raw samples. Samples are counted into bins, with either a fixed bins count
+/// or a fixed bin width, and each bin is rendered as a bar. The cumulative distribution can be displayed as a line over bars.
+#[derive(Default)]
+pub struct Histogram {
+    props: Props,
+}
+
+impl Histogram {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `histogram` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("histogram", self);
+    }
+
+    /// Set samples to count
+    pub fn data(mut self, samples: &[f64]) -> Self {
+        self.attr(
+            Attribute::Dataset,
+            AttrValue::Payload(PropPayload::Vec(
+                samples.iter().map(|x| PropValue::F64(*x)).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Split the range of samples in `bins` bins of the same width (default: 10)
+    pub fn bins(mut self, bins: usize) -> Self {
+        self.attr(
+            Attribute::Custom(HISTOGRAM_BINS),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(bins))),
+        );
+        self
+    }
+
+    /// Use bins of `width`, aligned to multiples of `width`. Takes precedence over `bins`
+    pub fn bin_width(mut self, width: f64) -> Self {
+        self.attr(
+            Attribute::Custom(HISTOGRAM_BIN_WIDTH),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(width))),
+        );
+        self
+    }
+
+    pub fn bar_style(mut self, s: Style) -> Self {
+        self.attr(Attribute::Custom(HISTOGRAM_BARS_STYLE), AttrValue::Style(s));
+        self
+    }
+
+    /// Display the cumulative distribution line
+    pub fn cumulative(mut self, show: bool) -> Self {
+        self.attr(
+            Attribute::Custom(HISTOGRAM_CUMULATIVE),
+            AttrValue::Flag(show),
+        );
+        self
+    }
+
+    pub fn cumulative_style(mut self, s: Style) -> Self {
+        self.attr(
+            Attribute::Custom(HISTOGRAM_CUMULATIVE_STYLE),
+            AttrValue::Style(s),
+        );
+        self
+    }
+
+    /// ### get_data
+    ///
+    /// Get samples from properties. Non finite samples are ignored
+    fn get_data(&self) -> Vec<f64> {
+        self.props
+            .get(Attribute::Dataset)
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_vec()
+                    .into_iter()
+                    .map(|x| x.unwrap_f64())
+                    .filter(|x| x.is_finite())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// ### get_bins
+    ///
+    /// Count samples into bins, by bin width if set, by bins count otherwise
+    fn get_bins(&self) -> Bins {
+        let samples = self.get_data();
+        match self
+            .props
+            .get(Attribute::Custom(HISTOGRAM_BIN_WIDTH))
+            .map(|x| x.unwrap_payload().unwrap_one().unwrap_f64())
+            .filter(|x| *x > 0.0)
+        {
+            Some(width) => Bins::with_width(&samples, width),
+            None => Bins::with_count(
+                &samples,
+                self.props
+                    .get(Attribute::Custom(HISTOGRAM_BINS))
+                    .map(|x| x.unwrap_payload().unwrap_one().unwrap_usize())
+                    .unwrap_or(DEFAULT_BINS),
+            ),
+        }
+    }
+
+    fn fmt_value(value: f64) -> String {
+        match value.fract() == 0.0 {
+            true => format!("{value:.0}"),
+            false => format!("{value:.1}"),
+        }
+    }
+
+    /// ### render_histogram
+    ///
+    /// Render axes, bars and the cumulative line into `area`
+    fn render_histogram(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let bins = self.get_bins();
+        let max = bins.counts.iter().copied().max().unwrap_or(0).max(1);
+        // Y labels on the left; x axis and x labels take the last two rows
+        let y_labels_width = (max.to_string().width() as u16).min(area.width);
+        let plot = Rect::new(
+            area.x + y_labels_width + 1,
+            area.y,
+            area.width.saturating_sub(y_labels_width + 1),
+            area.height.saturating_sub(2),
+        );
+        if plot.area() == 0 {
+            return;
+        }
+        // Axes
+        buf.set_stringn(
+            area.x,
+            plot.y,
+            max.to_string(),
+            y_labels_width as usize,
+            style,
+        );
+        buf.set_stringn(
+            area.x,
+            plot.bottom() - 1,
+            "0",
+            y_labels_width as usize,
+            style,
+        );
+        for y in plot.top()..plot.bottom() {
+            buf.set_string(plot.x - 1, y, "│", style);
+        }
+        buf.set_string(plot.x - 1, plot.bottom(), "└", style);
+        buf.set_string(
+            plot.x,
+            plot.bottom(),
+            "─".repeat(plot.width as usize),
+            style,
+        );
+        let start_label = Self::fmt_value(bins.start);
+        let end_label = Self::fmt_value(bins.end());
+        buf.set_stringn(
+            plot.x,
+            plot.bottom() + 1,
+            &start_label,
+            plot.width as usize,
+            style,
+        );
+        if start_label.width() + end_label.width() < plot.width as usize {
+            buf.set_string(
+                plot.right() - end_label.width() as u16,
+                plot.bottom() + 1,
+                end_label,
+                style,
+            );
+        }
+        // Bars; leave a gap between bars if there's enough room
+        let bar_width = ((plot.width as usize / bins.counts.len()) as u16).max(1);
+        let gap = u16::from(bar_width > 2);
+        let bar_style = self
+            .props
+            .get_or(
+                Attribute::Custom(HISTOGRAM_BARS_STYLE),
+                AttrValue::Style(style),
+            )
+            .unwrap_style();
+        for (i, count) in bins.counts.iter().enumerate() {
+            let x = plot.x + i as u16 * bar_width;
+            if x >= plot.right() {
+                break;
+            }
+            let width = (bar_width - gap).min(plot.right() - x) as usize;
+            let eighths = count * plot.height as u64 * 8 / max;
+            for row in 0..plot.height {
+                let filled = eighths.saturating_sub(row as u64 * 8).min(8) as usize;
+                if filled > 0 {
+                    buf.set_string(
+                        x,
+                        plot.bottom() - 1 - row,
+                        BAR_SYMBOLS[filled].repeat(width),
+                        bar_style,
+                    );
+                }
+            }
+        }
+        // Cumulative line, from the start of the first bin to the end of each bin
+        if self
+            .props
+            .get_or(
+                Attribute::Custom(HISTOGRAM_CUMULATIVE),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+        {
+            let color = self
+                .props
+                .get(Attribute::Custom(HISTOGRAM_CUMULATIVE_STYLE))
+                .and_then(|x| x.unwrap_style().fg)
+                .or(style.fg)
+                .unwrap_or(Color::Reset);
+            let points: Vec<(f64, f64)> = std::iter::once((0.0, 0.0))
+                .chain(
+                    bins.cumulative()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, y)| ((i + 1) as f64, y)),
+                )
+                .collect();
+            let bins_count = bins.counts.len() as f64;
+            let canvas_area = Rect {
+                width: (bar_width as usize * bins.counts.len()).min(plot.width as usize) as u16,
+                ..plot
+            };
+            TuiCanvas::default()
+                .marker(Marker::Braille)
+                .x_bounds([0.0, bins_count])
+                .y_bounds([0.0, 1.0])
+                .paint(|ctx| {
+                    for segment in points.windows(2) {
+                        ctx.draw(&Line {
+                            x1: segment[0].0,
+                            y1: segment[0].1,
+                            x2: segment[1].0,
+                            y2: segment[1].1,
+                            color,
+                        });
+                    }
+                })
+                .render(canvas_area, buf);
+        }
+    }
+}
+
+impl MockComponent for Histogram {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let styles =
+                FocusStyles::display(&self.props, Style::default().fg(foreground).bg(background));
+            let style = styles.style;
+            let div = styles
+                .block(borders, title)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = div.inner(area);
+            render.render_widget(div, area);
+            self.render_histogram(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_histogram_bins() {
+        let samples = [1.0, 2.0, 2.5, 3.0, 9.0];
+        // By count
+        assert_eq!(
+            Bins::with_count(&samples, 4),
+            Bins {
+                start: 1.0,
+                width: 2.0,
+                counts: vec![3, 1, 0, 1],
+            }
+        );
+        // By width
+        assert_eq!(
+            Bins::with_width(&samples, 5.0),
+            Bins {
+                start: 0.0,
+                width: 5.0,
+                counts: vec![4, 1],
+            }
+        );
+        // Same values and no values
+        assert_eq!(Bins::with_count(&[3.0, 3.0], 2).counts, vec![2, 0]);
+        assert_eq!(Bins::with_count(&[], 2).counts, vec![0, 0]);
+        // Cumulative
+        assert_eq!(
+            Bins::with_count(&samples, 4).cumulative(),
+            vec![0.6, 0.8, 0.8, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_components_histogram() {
+        let component = Histogram::default()
+            .title("latency", Alignment::Center)
+            .borders(Borders::default())
+            .bins(2)
+            .cumulative(false)
+            .data(&[0.0, 1.0, 3.0, f64::NAN, 4.0]);
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.get_data(), vec![0.0, 1.0, 3.0, 4.0]);
+        assert_eq!(component.get_bins().counts, vec![2, 2]);
+        // Bin width takes precedence
+        let component = component.bin_width(1.0);
+        assert_eq!(component.get_bins().counts, vec![1, 1, 0, 1, 1]);
+        // Render
+        let component = Histogram::default().bins(2).data(&[0.0, 1.0, 1.0, 4.0]);
+        let area = Rect::new(0, 0, 8, 4);
+        let mut buf = Buffer::empty(area);
+        component.render_histogram(&mut buf, area, Style::default());
+        let row = |y: u16| -> String { (0..8).map(|x| buf[(x, y)].symbol().to_string()).collect() };
+        assert_eq!(row(0), "3│██    ");
+        assert_eq!(row(1), "0│██ ▅▅ ");
+        assert_eq!(row(2), " └──────");
+        assert_eq!(row(3), "  0    4");
+    }
+}
@@ -0,0 +1,760 @@
+//! ## Image
+//!
+//! `Image` renders a picture, with unicode blocks or with a terminal graphics protocol
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use ::image::imageops::{self, FilterType};
+use ::image::{DynamicImage, Rgba, RgbaImage};
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{IMAGE_CELL_SIZE, IMAGE_PROTOCOL, IMAGE_VERTICAL_ALIGNMENT, TOOLTIP};
+
+/// Ids of images transmitted with the kitty protocol, to replace them when redrawn
+static KITTY_IMAGE_ID: AtomicU32 = AtomicU32::new(1);
+
+/// ## ImageProtocol
+///
+/// How the image is drawn on the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageProtocol {
+    /// Two pixels per cell, drawn with the upper half block; works on any terminal supporting true colors
+    #[default]
+    HalfBlocks,
+    /// 2x4 dots per cell, drawn with braille patterns; each cell has a single color
+    Braille,
+    /// Sixel graphics, supported by foot, mlterm, iTerm2 and others
+    Sixel,
+    /// Kitty graphics protocol, supported by kitty, ghostty and WezTerm
+    Kitty,
+}
+
+impl ImageProtocol {
+    const ALL: [Self; 4] = [Self::HalfBlocks, Self::Braille, Self::Sixel, Self::Kitty];
+
+    /// ### detect
+    ///
+    /// Guess the best protocol supported by the terminal from the environment, falling back to half blocks
+    pub fn detect() -> Self {
+        let var = |name: &str| std::env::var(name).unwrap_or_default().to_lowercase();
+        let term = var("TERM");
+        let program = var("TERM_PROGRAM");
+        if !var("KITTY_WINDOW_ID").is_empty()
+            || term.contains("kitty")
+            || term.contains("ghostty")
+            || program == "ghostty"
+            || program == "wezterm"
+        {
+            Self::Kitty
+        } else if ["foot", "mlterm", "contour"]
+            .iter()
+            .any(|x| term.contains(x))
+            || program == "iterm.app"
+        {
+            Self::Sixel
+        } else {
+            Self::HalfBlocks
+        }
+    }
+
+    /// Returns the amount of pixels drawn in a cell, for unicode protocols
+    fn cell_pixels(self) -> Option<(u32, u32)> {
+        match self {
+            Self::HalfBlocks => Some((1, 2)),
+            Self::Braille => Some((2, 4)),
+            Self::Sixel | Self::Kitty => None,
+        }
+    }
+}
+
+/// ## VerticalAlignment
+///
+/// Vertical alignment of the image in its area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlignment {
+    Top,
+    #[default]
+    Center,
+    Bottom,
+}
+
+impl VerticalAlignment {
+    const ALL: [Self; 3] = [Self::Top, Self::Center, Self::Bottom];
+}
+
+/// ### fit
+///
+/// Returns the size of an image of `size` pixels, scaled to fit in `bounds` keeping its aspect ratio
+fn fit(size: (u32, u32), bounds: (u32, u32)) -> (u32, u32) {
+    let (width, height) = size;
+    let (max_width, max_height) = bounds;
+    if width == 0 || height == 0 || max_width == 0 || max_height == 0 {
+        return (0, 0);
+    }
+    let scale = (max_width as f64 / width as f64).min(max_height as f64 / height as f64);
+    let scaled = |x: u32, max: u32| ((x as f64 * scale).round() as u32).clamp(1, max);
+    (scaled(width, max_width), scaled(height, max_height))
+}
+
+/// Returns the color of a pixel, or `None` if it's transparent
+fn pixel_color(pixel: &Rgba<u8>) -> Option<Color> {
+    let [r, g, b, a] = pixel.0;
+    (a >= 128).then_some(Color::Rgb(r, g, b))
+}
+
+/// ### render_half_blocks
+///
+/// Render `image` in `area`, two pixels per cell: the upper one is the foreground of `▀`, the lower one the background
+fn render_half_blocks(buf: &mut Buffer, area: Rect, image: &RgbaImage, style: Style) {
+    let pixel = |x: u32, y: u32| -> Option<Color> {
+        match x < image.width() && y < image.height() {
+            true => pixel_color(image.get_pixel(x, y)),
+            false => None,
+        }
+    };
+    for row in 0..area.height {
+        for col in 0..area.width {
+            let (x, y) = (col as u32, row as u32 * 2);
+            let cell = &mut buf[(area.x + col, area.y + row)];
+            match (pixel(x, y), pixel(x, y + 1)) {
+                (Some(top), bottom) => {
+                    cell.set_symbol("▀").set_style(
+                        style
+                            .fg(top)
+                            .bg(bottom.or(style.bg).unwrap_or(Color::Reset)),
+                    );
+                }
+                (None, Some(bottom)) => {
+                    cell.set_symbol("▄").set_style(style.fg(bottom));
+                }
+                (None, None) => {}
+            }
+        }
+    }
+}
+
+/// ### render_braille
+///
+/// Render `image` in `area`, 2x4 dots per cell. Dots brighter than the average are lit, and the cell takes the
+/// average color of its lit dots
+fn render_braille(buf: &mut Buffer, area: Rect, image: &RgbaImage, style: Style) {
+    const DOTS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+    let luma = |pixel: &Rgba<u8>| -> u32 {
+        let [r, g, b, _] = pixel.0;
+        (r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000
+    };
+    let opaque: Vec<&Rgba<u8>> = image.pixels().filter(|x| x.0[3] >= 128).collect();
+    let threshold = match opaque.len() {
+        0 => return,
+        n => opaque.iter().map(|x| luma(x)).sum::<u32>() / n as u32,
+    };
+    for row in 0..area.height {
+        for col in 0..area.width {
+            let mut dots = 0;
+            let mut sum = [0u32; 3];
+            let mut lit = 0;
+            for (dx, column) in DOTS.iter().enumerate() {
+                for (dy, dot) in column.iter().enumerate() {
+                    let (x, y) = (col as u32 * 2 + dx as u32, row as u32 * 4 + dy as u32);
+                    if x >= image.width() || y >= image.height() {
+                        continue;
+                    }
+                    let pixel = image.get_pixel(x, y);
+                    if pixel.0[3] >= 128 && luma(pixel) >= threshold {
+                        dots |= dot;
+                        lit += 1;
+                        for (sum, channel) in sum.iter_mut().zip(pixel.0) {
+                            *sum += channel as u32;
+                        }
+                    }
+                }
+            }
+            if lit > 0 {
+                let [r, g, b] = sum.map(|x| (x / lit) as u8);
+                let symbol = char::from_u32(0x2800 + dots).unwrap_or(' ').to_string();
+                buf[(area.x + col, area.y + row)]
+                    .set_symbol(&symbol)
+                    .set_style(style.fg(Color::Rgb(r, g, b)));
+            }
+        }
+    }
+}
+
+/// ### encode_sixel
+///
+/// Encode `image` as sixel graphics, with colors quantized to a 6x6x6 palette. Transparent pixels aren't drawn
+fn encode_sixel(image: &RgbaImage) -> String {
+    let quantize = |x: u8| (x as u32 * 5 + 127) / 255;
+    let index = |pixel: &Rgba<u8>| -> Option<u32> {
+        let [r, g, b, a] = pixel.0;
+        (a >= 128).then(|| quantize(r) * 36 + quantize(g) * 6 + quantize(b))
+    };
+    let (width, height) = image.dimensions();
+    let mut out = format!("\x1bP0;1;0q\"1;1;{width};{height}");
+    for i in 0..216 {
+        let percent = |x: u32| x * 100 / 5;
+        out.push_str(&format!(
+            "#{i};2;{};{};{}",
+            percent(i / 36),
+            percent(i / 6 % 6),
+            percent(i % 6)
+        ));
+    }
+    for band in (0..height).step_by(6) {
+        let rows = band..(band + 6).min(height);
+        let mut colors: Vec<u32> = rows
+            .clone()
+            .flat_map(|y| (0..width).filter_map(move |x| index(image.get_pixel(x, y))))
+            .collect();
+        colors.sort_unstable();
+        colors.dedup();
+        for color in colors {
+            out.push_str(&format!("#{color}"));
+            let sixels: Vec<u8> = (0..width)
+                .map(|x| {
+                    let bits = rows
+                        .clone()
+                        .filter(|y| index(image.get_pixel(x, *y)) == Some(color))
+                        .fold(0, |bits, y| bits | 1 << (y - band));
+                    63 + bits
+                })
+                .collect();
+            // Run length encoding
+            let mut x = 0;
+            while x < sixels.len() {
+                let run = sixels[x..].iter().take_while(|s| **s == sixels[x]).count();
+                match run {
+                    1..=3 => (0..run).for_each(|_| out.push(sixels[x] as char)),
+                    _ => out.push_str(&format!("!{run}{}", sixels[x] as char)),
+                }
+                x += run;
+            }
+            out.push('$');
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out
+}
+
+/// ### base64
+///
+/// Encode `data` in base64, with padding
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => out.push(ALPHABET[(n >> (18 - i * 6) & 0x3f) as usize] as char),
+                false => out.push('='),
+            }
+        }
+    }
+    out
+}
+
+/// ### encode_kitty
+///
+/// Encode `image` with the kitty graphics protocol, displayed in `cols` x `rows` cells.
+/// A previous image with the same `id` is replaced
+fn encode_kitty(image: &RgbaImage, id: u32, cols: u16, rows: u16) -> String {
+    const CHUNK: usize = 4096;
+    let data: Vec<u8> = image.pixels().flat_map(|x| x.0).collect();
+    let data = base64(&data);
+    let (width, height) = image.dimensions();
+    let mut out = format!("\x1b_Ga=d,d=I,i={id},q=2\x1b\\");
+    let chunks: Vec<&[u8]> = data.as_bytes().chunks(CHUNK).collect();
+    for (n, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(n + 1 < chunks.len());
+        let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+        match n {
+            0 => out.push_str(&format!(
+                "\x1b_Ga=T,f=32,s={width},v={height},c={cols},r={rows},i={id},C=1,q=2,m={more};{chunk}\x1b\\"
+            )),
+            _ => out.push_str(&format!("\x1b_Gm={more};{chunk}\x1b\\")),
+        }
+    }
+    out
+}
+
+/// ### render_sequence
+///
+/// Write the escape sequence of an image in the first cell of `area`; other cells are skipped, so that the
+/// image isn't drawn over
+fn render_sequence(buf: &mut Buffer, area: Rect, sequence: &str) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            buf[(x, y)].set_skip(true);
+        }
+    }
+    if !area.is_empty() {
+        buf[(area.x, area.y)].set_skip(false).set_symbol(sequence);
+    }
+}
+
+// -- states
+
+/// ## ImageStates
+///
+/// ImageStates contains states for this component
+#[derive(Default)]
+pub struct ImageStates {
+    pub image: Option<RgbaImage>,
+    /// The image resized for the last render
+    resized: Option<RgbaImage>,
+    /// Escape sequence of the last render, with its protocol and size in cells
+    sequence: Option<(ImageProtocol, u16, u16, String)>,
+    kitty_id: Option<u32>,
+}
+
+impl ImageStates {
+    pub fn set_image(&mut self, image: Option<RgbaImage>) {
+        self.image = image;
+        self.resized = None;
+        self.sequence = None;
+    }
+
+    /// ### resize
+    ///
+    /// Resize the image to `width` x `height` pixels, unless it's already been resized to that size
+    fn resize(&mut self, width: u32, height: u32) {
+        let cached = self.resized.as_ref().map(|x| x.dimensions());
+        if let (Some(image), false) = (&self.image, cached == Some((width, height))) {
+            self.resized = Some(imageops::resize(image, width, height, FilterType::Triangle));
+            self.sequence = None;
+        }
+    }
+
+    /// ### sequence
+    ///
+    /// Returns the escape sequence of the resized image for `protocol`, displayed in `cols` x `rows` cells
+    fn sequence(&mut self, protocol: ImageProtocol, cols: u16, rows: u16) -> Option<&str> {
+        let resized = self.resized.as_ref()?;
+        if !matches!(&self.sequence, Some((p, c, r, _)) if (*p, *c, *r) == (protocol, cols, rows)) {
+            let sequence = match protocol {
+                ImageProtocol::Kitty => {
+                    let id = *self
+                        .kitty_id
+                        .get_or_insert_with(|| KITTY_IMAGE_ID.fetch_add(1, Ordering::Relaxed));
+                    encode_kitty(resized, id, cols, rows)
+                }
+                _ => encode_sixel(resized),
+            };
+            self.sequence = Some((protocol, cols, rows, sequence));
+        }
+        self.sequence.as_ref().map(|(_, _, _, x)| x.as_str())
+    }
+}
+
+// -- component
+
+/// ## Image
+///
+/// An image, scaled to fit its area keeping its aspect ratio, and aligned in it.
+/// It's drawn with half blocks or braille patterns, or with the sixel or kitty protocol on terminals supporting them
+#[derive(Default)]
+pub struct Image {
+    props: Props,
+    pub states: ImageStates,
+}
+
+impl Image {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `image` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("image", self);
+    }
+
+    /// Set the image to display
+    pub fn image(mut self, image: &DynamicImage) -> Self {
+        self.states.set_image(Some(image.to_rgba8()));
+        self
+    }
+
+    /// Load the image to display from a file
+    pub fn path<S: Into<String>>(mut self, path: S) -> Self {
+        self.attr(Attribute::Text, AttrValue::String(path.into()));
+        self
+    }
+
+    /// Set the horizontal alignment of the image (default: center)
+    pub fn alignment(mut self, a: Alignment) -> Self {
+        self.attr(Attribute::Alignment, AttrValue::Alignment(a));
+        self
+    }
+
+    /// Set the vertical alignment of the image (default: center)
+    pub fn vertical_alignment(mut self, a: VerticalAlignment) -> Self {
+        self.attr(
+            Attribute::Custom(IMAGE_VERTICAL_ALIGNMENT),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(a as usize))),
+        );
+        self
+    }
+
+    /// Set how the image is drawn (default: half blocks)
+    pub fn protocol(mut self, protocol: ImageProtocol) -> Self {
+        self.attr(
+            Attribute::Custom(IMAGE_PROTOCOL),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(protocol as usize))),
+        );
+        self
+    }
+
+    /// Set the size of a cell in pixels, for the sixel and kitty protocols (default: 10x20)
+    pub fn cell_size(mut self, width: u16, height: u16) -> Self {
+        self.attr(
+            Attribute::Custom(IMAGE_CELL_SIZE),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::U16(width),
+                PropValue::U16(height),
+            ))),
+        );
+        self
+    }
+
+    fn get_protocol(&self) -> ImageProtocol {
+        match self
+            .props
+            .get(Attribute::Custom(IMAGE_PROTOCOL))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::Usize(x))) => {
+                ImageProtocol::ALL.get(x).copied().unwrap_or_default()
+            }
+            _ => ImageProtocol::default(),
+        }
+    }
+
+    fn get_vertical_alignment(&self) -> VerticalAlignment {
+        match self
+            .props
+            .get(Attribute::Custom(IMAGE_VERTICAL_ALIGNMENT))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::Usize(x))) => {
+                VerticalAlignment::ALL.get(x).copied().unwrap_or_default()
+            }
+            _ => VerticalAlignment::default(),
+        }
+    }
+
+    /// Returns the pixels drawn in a cell with `protocol`
+    fn get_cell_pixels(&self, protocol: ImageProtocol) -> (u32, u32) {
+        protocol.cell_pixels().unwrap_or_else(|| {
+            match self
+                .props
+                .get(Attribute::Custom(IMAGE_CELL_SIZE))
+                .map(|x| x.unwrap_payload())
+            {
+                Some(PropPayload::Tup2((PropValue::U16(w), PropValue::U16(h)))) => {
+                    (w.max(1) as u32, h.max(1) as u32)
+                }
+                _ => (10, 20),
+            }
+        })
+    }
+
+    /// ### image_area
+    ///
+    /// Returns the cells taken by the image in `area`, given its alignment, and its size in pixels
+    fn image_area(&self, area: Rect, protocol: ImageProtocol) -> Option<(Rect, (u32, u32))> {
+        let image = self.states.image.as_ref()?;
+        let (cell_width, cell_height) = self.get_cell_pixels(protocol);
+        let (width, height) = fit(
+            image.dimensions(),
+            (
+                area.width as u32 * cell_width,
+                area.height as u32 * cell_height,
+            ),
+        );
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let cols = width.div_ceil(cell_width) as u16;
+        let rows = height.div_ceil(cell_height) as u16;
+        let alignment = self
+            .props
+            .get_or(
+                Attribute::Alignment,
+                AttrValue::Alignment(Alignment::Center),
+            )
+            .unwrap_alignment();
+        let x = match alignment {
+            Alignment::Left => 0,
+            Alignment::Center => (area.width - cols) / 2,
+            Alignment::Right => area.width - cols,
+        };
+        let y = match self.get_vertical_alignment() {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => (area.height - rows) / 2,
+            VerticalAlignment::Bottom => area.height - rows,
+        };
+        Some((
+            Rect::new(area.x + x, area.y + y, cols, rows),
+            (width, height),
+        ))
+    }
+
+    /// ### render_image
+    ///
+    /// Render the image in `area`
+    fn render_image(&mut self, buf: &mut Buffer, area: Rect, style: Style) {
+        let protocol = self.get_protocol();
+        let Some((area, (width, height))) = self.image_area(area, protocol) else {
+            return;
+        };
+        self.states.resize(width, height);
+        match protocol {
+            ImageProtocol::HalfBlocks | ImageProtocol::Braille => {
+                let Some(image) = self.states.resized.as_ref() else {
+                    return;
+                };
+                match protocol {
+                    ImageProtocol::Braille => render_braille(buf, area, image, style),
+                    _ => render_half_blocks(buf, area, image, style),
+                }
+            }
+            ImageProtocol::Sixel | ImageProtocol::Kitty => {
+                if let Some(sequence) = self.states.sequence(protocol, area.width, area.height) {
+                    render_sequence(buf, area, sequence);
+                }
+            }
+        }
+    }
+}
+
+impl MockComponent for Image {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_image(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if attr == Attribute::Text {
+            let image = self
+                .props
+                .get(Attribute::Text)
+                .and_then(|x| ::image::open(x.unwrap_string()).ok())
+                .map(|x| x.to_rgba8());
+            self.states.set_image(image);
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+    const CLEAR: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+    /// A 4x4 image: red on top, blue at the bottom, with a transparent pixel in the top left corner
+    fn image() -> RgbaImage {
+        let mut image = RgbaImage::new(4, 4);
+        for y in 0..4 {
+            for x in 0..4 {
+                image.put_pixel(x, y, if y < 2 { RED } else { BLUE });
+            }
+        }
+        image.put_pixel(0, 0, CLEAR);
+        image
+    }
+
+    #[test]
+    fn test_components_image_fit() {
+        assert_eq!(fit((100, 50), (40, 40)), (40, 20));
+        assert_eq!(fit((50, 100), (40, 40)), (20, 40));
+        // Small images are scaled up
+        assert_eq!(fit((2, 1), (40, 40)), (40, 20));
+        assert_eq!(fit((1000, 1), (10, 10)), (10, 1));
+        assert_eq!(fit((0, 10), (10, 10)), (0, 0));
+    }
+
+    #[test]
+    fn test_components_image_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_components_image_half_blocks() {
+        let mut component = Image::default()
+            .image(&DynamicImage::from(image()))
+            .alignment(Alignment::Left)
+            .vertical_alignment(VerticalAlignment::Bottom);
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        // 4x4 pixels fit in 4x2 cells
+        let area = Rect::new(0, 0, 4, 4);
+        assert_eq!(
+            component.image_area(area, ImageProtocol::HalfBlocks),
+            Some((Rect::new(0, 2, 4, 2), (4, 4)))
+        );
+        let mut buf = Buffer::empty(area);
+        component.render_image(&mut buf, area, Style::default());
+        assert_eq!(buf[(0, 2)].symbol(), "▄");
+        assert_eq!(buf[(0, 2)].fg, Color::Rgb(255, 0, 0));
+        assert_eq!(buf[(1, 2)].symbol(), "▀");
+        assert_eq!(buf[(1, 2)].bg, Color::Rgb(255, 0, 0));
+        assert_eq!(buf[(1, 3)].fg, Color::Rgb(0, 0, 255));
+        assert_eq!(buf[(0, 0)].symbol(), " ");
+    }
+
+    #[test]
+    fn test_components_image_braille() {
+        let mut component = Image::default()
+            .image(&DynamicImage::from(image()))
+            .protocol(ImageProtocol::Braille);
+        let area = Rect::new(0, 0, 4, 1);
+        assert_eq!(
+            component.image_area(area, ImageProtocol::Braille),
+            Some((Rect::new(1, 0, 2, 1), (4, 4)))
+        );
+        let mut buf = Buffer::empty(area);
+        component.render_image(&mut buf, area, Style::default());
+        // Red is brighter than blue: the right column and the second dot of the left column are lit
+        assert_eq!(buf[(1, 0)].symbol(), "⠚");
+        assert_eq!(buf[(1, 0)].fg, Color::Rgb(255, 0, 0));
+        assert_eq!(buf[(2, 0)].symbol(), "⠛");
+    }
+
+    #[test]
+    fn test_components_image_sequences() {
+        let mut image = RgbaImage::new(2, 1);
+        image.put_pixel(0, 0, RED);
+        image.put_pixel(1, 0, RED);
+        let sixel = encode_sixel(&image);
+        assert!(sixel.starts_with("\x1bP0;1;0q\"1;1;2;1#0;2;0;0;0"));
+        assert!(sixel.ends_with("#180@@$-\x1b\\"));
+        let kitty = encode_kitty(&image, 3, 1, 1);
+        assert_eq!(
+            kitty,
+            "\x1b_Ga=d,d=I,i=3,q=2\x1b\\\x1b_Ga=T,f=32,s=2,v=1,c=1,r=1,i=3,C=1,q=2,m=0;/wAA//8AAP8=\x1b\\"
+        );
+        // Escape sequence is written in the first cell
+        let mut component = Image::default()
+            .image(&DynamicImage::from(image))
+            .protocol(ImageProtocol::Sixel)
+            .cell_size(1, 1);
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+        component.render_image(&mut buf, area, Style::default());
+        let symbol = buf[(0, 0)].symbol();
+        assert!(symbol.starts_with("\x1bP0;1;0q\"1;1;4;2"));
+        assert!(symbol.ends_with("#180!4B$-\x1b\\"));
+        assert!(buf[(1, 1)].skip);
+        assert!(!buf[(0, 0)].skip);
+    }
+}
@@ -3,14 +3,25 @@
 //! `Input` represents a read-write input field. This component supports different input types, input length
 //! and handles input events related to cursor position, backspace, canc, ...
 
-use super::props::{INPUT_INVALID_STYLE, INPUT_PLACEHOLDER, INPUT_PLACEHOLDER_STYLE};
-use crate::utils::calc_utf8_cursor_position;
+use super::commands::{INPUT_CMD_COPY, INPUT_CMD_CUT, INPUT_CMD_PASTE};
+use super::props::{
+    INPUT_CURSOR_BLINK, INPUT_INVALID_STYLE, INPUT_PLACEHOLDER, INPUT_PLACEHOLDER_STYLE, TOOLTIP,
+};
+use crate::animation::Blink;
+use crate::clipboard::{default_clipboard, Clipboard};
+use crate::theme::Theme;
+use crate::utils::{
+    clicked, get_title_style, graphemes_width, is_disabled, render_disabled, render_tooltip,
+};
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::event::MouseEvent;
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, InputType, Props, Style, TextModifiers,
 };
 use tuirealm::ratatui::{layout::Rect, widgets::Paragraph};
 use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 // -- states
 
@@ -18,18 +29,27 @@ use tuirealm::{Frame, MockComponent, State, StateValue};
 pub struct InputStates {
     pub input: Vec<char>, // Current input
     pub cursor: usize,    // Input position
+    pub area: Rect,       // Area of the text in the last render
+    pub blink: Blink,     // Blinking of the cursor
 }
 
 impl InputStates {
     /// ### append
     ///
-    /// Append, if possible according to input type, the character to the input vec
+    /// Append, if possible according to input type, the character to the input vec.
+    /// The maximum length is in grapheme clusters, so combining characters can always join the previous character
     pub fn append(&mut self, ch: char, itype: &InputType, max_len: Option<usize>) {
-        // Check if max length has been reached
-        if self.input.len() < max_len.unwrap_or(usize::MAX) {
-            // Check whether can push
-            if itype.char_valid(self.input.iter().collect::<String>().as_str(), ch) {
-                self.input.insert(self.cursor, ch);
+        // Check whether can push
+        if itype.char_valid(self.get_value().as_str(), ch) {
+            self.input.insert(self.cursor, ch);
+            // Check if max length has been exceeded
+            if self.len() > max_len.unwrap_or(usize::MAX) {
+                self.input.remove(self.cursor);
+                return;
+            }
+            self.cursor += 1;
+            // The character may have joined the next grapheme
+            if !self.boundaries().contains(&self.cursor) {
                 self.incr_cursor();
             }
         }
@@ -37,31 +57,26 @@ impl InputStates {
 
     /// ### backspace
     ///
-    /// Delete element at cursor -1; then decrement cursor by 1
+    /// Delete the grapheme before the cursor; then move the cursor back by it
     pub fn backspace(&mut self) {
-        if self.cursor > 0 && !self.input.is_empty() {
-            self.input.remove(self.cursor - 1);
-            // Decrement cursor
-            self.cursor -= 1;
-        }
+        let prev = self.prev_boundary();
+        self.input.drain(prev..self.cursor);
+        self.cursor = prev;
     }
 
     /// ### delete
     ///
-    /// Delete element at cursor
+    /// Delete the grapheme at cursor
     pub fn delete(&mut self) {
-        if self.cursor < self.input.len() {
-            self.input.remove(self.cursor);
-        }
+        let next = self.next_boundary();
+        self.input.drain(self.cursor..next);
     }
 
     /// ### incr_cursor
     ///
-    /// Increment cursor value by one if possible
+    /// Move the cursor after the next grapheme, if possible
     pub fn incr_cursor(&mut self) {
-        if self.cursor < self.input.len() {
-            self.cursor += 1;
-        }
+        self.cursor = self.next_boundary();
     }
 
     /// ### cursoro_at_begin
@@ -80,11 +95,51 @@ impl InputStates {
 
     /// ### decr_cursor
     ///
-    /// Decrement cursor value by one if possible
+    /// Move the cursor before the previous grapheme, if possible
     pub fn decr_cursor(&mut self) {
-        if self.cursor > 0 {
-            self.cursor -= 1;
+        self.cursor = self.prev_boundary();
+    }
+
+    /// ### len
+    ///
+    /// Get the length of the input, in grapheme clusters
+    pub fn len(&self) -> usize {
+        self.get_value().graphemes(true).count()
+    }
+
+    /// ### is_empty
+    ///
+    /// Whether the input is empty
+    pub fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    /// ### boundaries
+    ///
+    /// Get the indexes of the chars starting a grapheme cluster, and the length of the input
+    fn boundaries(&self) -> Vec<usize> {
+        let mut index = 0;
+        let mut boundaries = vec![0];
+        for grapheme in self.get_value().graphemes(true) {
+            index += grapheme.chars().count();
+            boundaries.push(index);
         }
+        boundaries
+    }
+
+    fn prev_boundary(&self) -> usize {
+        self.boundaries()
+            .into_iter()
+            .filter(|x| *x < self.cursor)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn next_boundary(&self) -> usize {
+        self.boundaries()
+            .into_iter()
+            .find(|x| *x > self.cursor)
+            .unwrap_or(self.input.len())
     }
 
     /// ### render_value
@@ -96,16 +151,45 @@ impl InputStates {
 
     /// ### render_value_chars
     ///
-    /// Render value as a vec of chars
+    /// Render value as a vec of chars. Passwords are rendered with a character for each grapheme
     pub fn render_value_chars(&self, itype: InputType) -> Vec<char> {
         match itype {
             InputType::Password(ch) | InputType::CustomPassword(ch, _, _) => {
-                (0..self.input.len()).map(|_| ch).collect()
+                (0..self.len()).map(|_| ch).collect()
             }
             _ => self.input.clone(),
         }
     }
 
+    /// ### cursor_width
+    ///
+    /// Get the width of the rendered value before the cursor, which is the column of the cursor
+    pub fn cursor_width(&self, itype: InputType) -> u16 {
+        let before = self
+            .boundaries()
+            .iter()
+            .take_while(|x| **x < self.cursor)
+            .count();
+        let value = self.render_value(itype);
+        graphemes_width(&value.graphemes(true).take(before).collect::<String>()) as u16
+    }
+
+    /// ### cursor_at_column
+    ///
+    /// Place the cursor before the grapheme rendered at column `x`, or at the end of the input if after it
+    pub fn cursor_at_column(&mut self, x: u16, itype: InputType) {
+        let mut width: usize = 0;
+        let before = self
+            .render_value(itype)
+            .graphemes(true)
+            .take_while(|grapheme| {
+                width += grapheme.width();
+                width <= x as usize
+            })
+            .count();
+        self.cursor = self.boundaries()[before];
+    }
+
     /// ### get_value
     ///
     /// Get value as string
@@ -123,6 +207,7 @@ impl InputStates {
 pub struct Input {
     props: Props,
     pub states: InputStates,
+    clipboard: Option<Box<dyn Clipboard>>,
 }
 
 impl Input {
@@ -141,6 +226,28 @@ impl Input {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `input` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("input", self);
+    }
+
     pub fn borders(mut self, b: Borders) -> Self {
         self.attr(Attribute::Borders, AttrValue::Borders(b));
         self
@@ -183,6 +290,21 @@ impl Input {
         self
     }
 
+    /// Make the cursor blink, hiding it every `ticks` ticks. 0 disables blinking
+    pub fn cursor_blink(mut self, ticks: usize) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_CURSOR_BLINK),
+            AttrValue::Length(ticks),
+        );
+        self
+    }
+
+    /// Set the clipboard used to copy, cut and paste. By default, the one of `default_clipboard`
+    pub fn clipboard(mut self, clipboard: Box<dyn Clipboard>) -> Self {
+        self.clipboard = Some(clipboard);
+        self
+    }
+
     fn get_input_len(&self) -> Option<usize> {
         self.props
             .get(Attribute::InputLength)
@@ -202,6 +324,59 @@ impl Input {
         let value = self.states.get_value();
         self.get_input_type().validate(value.as_str())
     }
+
+    /// ### is_password
+    ///
+    /// Whether the input is a password, which can't be copied
+    fn is_password(&self) -> bool {
+        matches!(
+            self.get_input_type(),
+            InputType::Password(_) | InputType::CustomPassword(..)
+        )
+    }
+
+    /// ### copy
+    ///
+    /// Copy the value to the clipboard, unless it's empty or a password. Returns whether it has been copied
+    fn copy(&mut self) -> bool {
+        if self.states.is_empty() || self.is_password() {
+            return false;
+        }
+        let value = self.states.get_value();
+        self.clipboard
+            .get_or_insert_with(default_clipboard)
+            .set(&value);
+        true
+    }
+
+    /// ### paste
+    ///
+    /// Type the text in the clipboard at the cursor, skipping control characters such as new lines
+    fn paste(&mut self) {
+        let Some(text) = self.clipboard.get_or_insert_with(default_clipboard).get() else {
+            return;
+        };
+        let itype = self.get_input_type();
+        let max_len = self.get_input_len();
+        text.chars()
+            .filter(|ch| !ch.is_control())
+            .for_each(|ch| self.states.append(ch, &itype, max_len));
+    }
+
+    /// ### on_mouse
+    ///
+    /// Handle a mouse event: clicking the text moves the cursor before the clicked character, or at the end of the
+    /// input if clicked after it
+    pub fn on_mouse(&mut self, ev: MouseEvent) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        if let Some((x, _)) = clicked(&ev, self.states.area) {
+            let itype = self.get_input_type();
+            self.states.cursor_at_column(x, itype);
+        }
+        CmdResult::None
+    }
 }
 
 impl MockComponent for Input {
@@ -242,7 +417,8 @@ impl MockComponent for Input {
                 .get(Attribute::FocusStyle)
                 .map(|x| x.unwrap_style());
             let itype = self.get_input_type();
-            let mut block = crate::utils::get_block(borders, Some(title), focus, inactive_style);
+            let mut block = crate::utils::get_block(borders, Some(title), focus, inactive_style)
+                .title_style(get_title_style(&self.props));
             // Apply invalid style
             if focus && !self.is_valid() {
                 if let Some(style) = self
@@ -262,7 +438,8 @@ impl MockComponent for Input {
                             AttrValue::Title((String::default(), Alignment::Center)),
                         )
                         .unwrap_title();
-                    block = crate::utils::get_block(borders, Some(title), focus, None);
+                    block = crate::utils::get_block(borders, Some(title), focus, None)
+                        .title_style(get_title_style(&self.props));
                     foreground = style.fg.unwrap_or(Color::Reset);
                     background = style.bg.unwrap_or(Color::Reset);
                 }
@@ -300,20 +477,20 @@ impl MockComponent for Input {
             };
             // Create widget
             let block_inner_area = block.inner(area);
+            self.states.area = block_inner_area;
             let p: Paragraph = Paragraph::new(text_to_display)
                 .style(paragraph_style)
                 .block(block);
             render.render_widget(p, area);
-            // Set cursor, if focus
-            if focus {
-                let x: u16 = block_inner_area.x
-                    + calc_utf8_cursor_position(
-                        &self.states.render_value_chars(itype)[0..self.states.cursor],
-                    );
+            // Set cursor, if focus and not hidden by blinking
+            if focus && self.states.blink.visible() {
+                let x: u16 = block_inner_area.x + self.states.cursor_width(itype);
                 render
                     .set_cursor_position(tuirealm::ratatui::prelude::Position { x, y: area.y + 1 });
             }
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -330,6 +507,9 @@ impl MockComponent for Input {
             Attribute::Value => Some(value.clone().unwrap_string()),
             _ => None,
         };
+        if let (Attribute::Custom(INPUT_CURSOR_BLINK), AttrValue::Length(ticks)) = (attr, &value) {
+            self.states.blink.set_ticks(*ticks);
+        }
         self.props.set(attr, value);
         if sanitize_input {
             let input = match new_input {
@@ -356,7 +536,19 @@ impl MockComponent for Input {
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        // The cursor is visible while editing
+        if cmd != Cmd::Tick {
+            self.states.blink.reset();
+        }
         match cmd {
+            // Blinking changes the view only, not the value
+            Cmd::Tick => match self.states.blink.tick() {
+                true => CmdResult::Changed(State::None),
+                false => CmdResult::None,
+            },
             Cmd::Delete => {
                 // Backspace and None
                 let prev_input = self.states.input.clone();
@@ -406,6 +598,27 @@ impl MockComponent for Input {
                     CmdResult::None
                 }
             }
+            Cmd::Custom(INPUT_CMD_COPY) => {
+                self.copy();
+                CmdResult::None
+            }
+            Cmd::Custom(INPUT_CMD_CUT) => match self.copy() {
+                true => {
+                    self.states.input.clear();
+                    self.states.cursor = 0;
+                    CmdResult::Changed(self.state())
+                }
+                false => CmdResult::None,
+            },
+            Cmd::Custom(INPUT_CMD_PASTE) => {
+                let prev_input = self.states.input.clone();
+                self.paste();
+                if prev_input != self.states.input {
+                    CmdResult::Changed(self.state())
+                } else {
+                    CmdResult::None
+                }
+            }
             _ => CmdResult::None,
         }
     }
@@ -415,6 +628,7 @@ impl MockComponent for Input {
 mod tests {
 
     use super::*;
+    use crate::clipboard::MemoryClipboard;
 
     use pretty_assertions::assert_eq;
 
@@ -620,4 +834,151 @@ mod tests {
         );
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_components_input_mouse() {
+        use tuirealm::event::{KeyModifiers, MouseButton, MouseEventKind};
+        use tuirealm::ratatui::{backend::TestBackend, Terminal};
+
+        let click = |column, row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+            column,
+            row,
+        };
+        let mut component = Input::default()
+            .borders(Borders::default())
+            .value("日本 text");
+        let mut terminal = Terminal::new(TestBackend::new(20, 3)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 20, 3)))
+            .unwrap();
+        assert_eq!(component.states.cursor, 7);
+        // Wide characters take two cells
+        assert_eq!(component.on_mouse(click(4, 1)), CmdResult::None);
+        assert_eq!(component.states.cursor, 1);
+        component.on_mouse(click(6, 1));
+        assert_eq!(component.states.cursor, 3);
+        // After the text
+        component.on_mouse(click(18, 1));
+        assert_eq!(component.states.cursor, 7);
+        // Borders
+        component.on_mouse(click(4, 0));
+        assert_eq!(component.states.cursor, 7);
+    }
+
+    #[test]
+    fn test_components_input_cursor_blink() {
+        let mut component = Input::default().cursor_blink(2).value("text");
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        assert!(component.states.blink.visible());
+        assert_eq!(
+            component.perform(Cmd::Tick),
+            CmdResult::Changed(State::None)
+        );
+        assert!(!component.states.blink.visible());
+        // Editing shows the cursor
+        component.perform(Cmd::Move(Direction::Left));
+        assert!(component.states.blink.visible());
+        // Without blinking
+        let mut component = Input::default();
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        assert!(component.states.blink.visible());
+    }
+
+    #[test]
+    fn test_components_input_clipboard() {
+        let mut clipboard = MemoryClipboard::new();
+        let mut component = Input::default()
+            .clipboard(Box::new(clipboard.clone()))
+            .value("hello");
+        assert_eq!(
+            component.perform(Cmd::Custom(INPUT_CMD_COPY)),
+            CmdResult::None
+        );
+        assert_eq!(clipboard.get().as_deref(), Some("hello"));
+        // Cut
+        let mut other = Input::default()
+            .clipboard(Box::new(clipboard.clone()))
+            .input_len(8)
+            .value("world");
+        assert_eq!(
+            other.perform(Cmd::Custom(INPUT_CMD_CUT)),
+            CmdResult::Changed(State::One(StateValue::String(String::new())))
+        );
+        assert_eq!(other.states.cursor, 0);
+        assert_eq!(clipboard.get().as_deref(), Some("world"));
+        // Paste at the cursor, up to the maximum length
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(
+            component.perform(Cmd::Custom(INPUT_CMD_PASTE)),
+            CmdResult::Changed(State::One(StateValue::String(String::from("worldhello"))))
+        );
+        assert_eq!(component.states.cursor, 5);
+        clipboard.set("one\ntwo three");
+        other.perform(Cmd::Custom(INPUT_CMD_PASTE));
+        assert_eq!(other.states.get_value(), "onetwo t");
+        // Passwords can't be copied
+        let mut component = Input::default()
+            .clipboard(Box::new(clipboard.clone()))
+            .input_type(InputType::Password('*'))
+            .value("secret");
+        assert_eq!(
+            component.perform(Cmd::Custom(INPUT_CMD_CUT)),
+            CmdResult::None
+        );
+        assert_eq!(component.states.get_value(), "secret");
+        assert_eq!(clipboard.get().as_deref(), Some("one\ntwo three"));
+    }
+
+    #[test]
+    fn test_components_input_graphemes() {
+        let mut component = Input::default().input_len(4).value("cafe\u{301}");
+        // The accented 'e' is a single grapheme
+        assert_eq!(component.states.len(), 4);
+        assert_eq!(component.states.cursor, 5);
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(component.states.cursor, 3);
+        assert_eq!(component.states.cursor_width(InputType::Text), 3);
+        component.perform(Cmd::GoTo(Position::End));
+        component.perform(Cmd::Delete);
+        assert_eq!(component.states.get_value(), "caf");
+        // Emoji sequences and wide characters
+        component.perform(Cmd::Type('日'));
+        assert_eq!(component.states.cursor_width(InputType::Text), 5);
+        // Reached length
+        component.perform(Cmd::Type('x'));
+        assert_eq!(component.states.get_value(), "caf日");
+        // Combining characters join the previous one, even at the maximum length
+        component.perform(Cmd::GoTo(Position::Begin));
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Type('\u{301}'));
+        assert_eq!(component.states.get_value(), "ca\u{301}f日");
+        assert_eq!(component.states.cursor, 3);
+        component.perform(Cmd::Cancel);
+        assert_eq!(component.states.get_value(), "ca\u{301}日");
+        let mut component = Input::default().value("👩\u{200d}💻ab");
+        component.perform(Cmd::GoTo(Position::Begin));
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.cursor, 3);
+        assert_eq!(component.states.cursor_width(InputType::Text), 2);
+        component.perform(Cmd::Delete);
+        assert_eq!(component.states.get_value(), "ab");
+        assert_eq!(component.states.cursor, 0);
+        // Passwords are masked by grapheme
+        let mut states = InputStates::default();
+        "e\u{301}日x".chars().for_each(|ch| {
+            states.append(ch, &InputType::Password('*'), None);
+        });
+        assert_eq!(states.render_value(InputType::Password('*')), "***");
+        assert_eq!(states.cursor_width(InputType::Password('*')), 3);
+        assert_eq!(states.cursor_width(InputType::Text), 4);
+        states.cursor_at_column(1, InputType::Password('*'));
+        assert_eq!(states.cursor, 2);
+        states.cursor_at_column(2, InputType::Text);
+        assert_eq!(states.cursor, 2);
+        states.cursor_at_column(3, InputType::Text);
+        assert_eq!(states.cursor, 3);
+    }
 }
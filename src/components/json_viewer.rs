@@ -0,0 +1,759 @@
+//! ## JsonViewer
+//!
+//! `JsonViewer` displays a JSON value as a collapsible key/value tree
+
+use serde_json::Value;
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::JSON_VIEWER_CMD_COPY_PATH;
+use super::props::{
+    JSON_VIEWER_BOOL_COLOR, JSON_VIEWER_BREADCRUMB, JSON_VIEWER_KEY_COLOR, JSON_VIEWER_NULL_COLOR,
+    JSON_VIEWER_NUMBER_COLOR, JSON_VIEWER_STRING_COLOR, TOOLTIP,
+};
+
+// -- tree
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonType {
+    Null,
+    Bool,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum JsonKey {
+    Root,
+    Key(String),
+    Index(usize),
+}
+
+/// ## JsonNode
+///
+/// A value of the document, flattened in the tree
+#[derive(Debug)]
+struct JsonNode {
+    key: JsonKey,
+    kind: JsonType,
+    /// The value as JSON for scalars, a summary of the children for arrays and objects
+    text: String,
+    depth: usize,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    expanded: bool,
+}
+
+/// ### build_nodes
+///
+/// Flatten `value` and its children into `nodes`; returns the index of the node of `value`
+fn build_nodes(
+    nodes: &mut Vec<JsonNode>,
+    key: JsonKey,
+    value: &Value,
+    depth: usize,
+    parent: Option<usize>,
+) -> usize {
+    let count = |n: usize, what: &str| match n {
+        1 => format!("1 {what}"),
+        n => format!("{n} {what}s"),
+    };
+    let (kind, text) = match value {
+        Value::Null => (JsonType::Null, value.to_string()),
+        Value::Bool(_) => (JsonType::Bool, value.to_string()),
+        Value::Number(_) => (JsonType::Number, value.to_string()),
+        Value::String(_) => (JsonType::String, value.to_string()),
+        Value::Array(items) => (JsonType::Array, format!("[{}]", count(items.len(), "item"))),
+        Value::Object(map) => (JsonType::Object, format!("{{{}}}", count(map.len(), "key"))),
+    };
+    let id = nodes.len();
+    nodes.push(JsonNode {
+        key,
+        kind,
+        text,
+        depth,
+        parent,
+        children: Vec::new(),
+        expanded: false,
+    });
+    let children: Vec<usize> = match value {
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, x)| build_nodes(nodes, JsonKey::Index(i), x, depth + 1, Some(id)))
+            .collect(),
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, x)| build_nodes(nodes, JsonKey::Key(k.clone()), x, depth + 1, Some(id)))
+            .collect(),
+        _ => Vec::new(),
+    };
+    nodes[id].children = children;
+    id
+}
+
+/// ### is_identifier
+///
+/// Returns whether `key` can be written as `.key` in a path
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// -- states
+
+/// ## JsonViewerStates
+///
+/// JsonViewerStates contains states for this component
+#[derive(Default)]
+pub struct JsonViewerStates {
+    nodes: Vec<JsonNode>,
+    /// Nodes displayed, in order
+    visible: Vec<usize>,
+    /// Index of the selected node in the visible nodes
+    pub selected: usize,
+    /// First visible row
+    pub scroll: usize,
+}
+
+impl JsonViewerStates {
+    /// ### set_value
+    ///
+    /// Build the tree of `value`; only the root is expanded
+    pub fn set_value(&mut self, value: Option<&Value>) {
+        self.nodes.clear();
+        if let Some(value) = value {
+            build_nodes(&mut self.nodes, JsonKey::Root, value, 0, None);
+            self.nodes[0].expanded = true;
+        }
+        self.selected = 0;
+        self.scroll = 0;
+        self.layout();
+    }
+
+    /// ### layout
+    ///
+    /// Compute the visible nodes, keeping the selected node selected
+    fn layout(&mut self) {
+        let selected = self.selected_node();
+        self.visible.clear();
+        let mut stack: Vec<usize> = match self.nodes.is_empty() {
+            true => Vec::new(),
+            false => vec![0],
+        };
+        while let Some(id) = stack.pop() {
+            self.visible.push(id);
+            let node = &self.nodes[id];
+            if node.expanded {
+                stack.extend(node.children.iter().rev());
+            }
+        }
+        self.selected = selected
+            .and_then(|id| self.visible.iter().position(|x| *x == id))
+            .unwrap_or(0);
+    }
+
+    fn selected_node(&self) -> Option<usize> {
+        self.visible.get(self.selected).copied()
+    }
+
+    fn select_node(&mut self, id: usize) {
+        if let Some(pos) = self.visible.iter().position(|x| *x == id) {
+            self.selected = pos;
+        }
+    }
+
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.visible.len().saturating_sub(1));
+    }
+
+    /// ### path
+    ///
+    /// Returns the path of the selected node, such as `$.store.books[0].title`
+    pub fn path(&self) -> Option<String> {
+        let mut keys = Vec::new();
+        let mut node = self.selected_node();
+        while let Some(id) = node {
+            keys.push(&self.nodes[id].key);
+            node = self.nodes[id].parent;
+        }
+        if keys.is_empty() {
+            return None;
+        }
+        let mut path = String::new();
+        for key in keys.into_iter().rev() {
+            match key {
+                JsonKey::Root => path.push('$'),
+                JsonKey::Key(key) if is_identifier(key) => {
+                    path.push('.');
+                    path.push_str(key);
+                }
+                JsonKey::Key(key) => {
+                    path.push('[');
+                    path.push_str(&Value::String(key.clone()).to_string());
+                    path.push(']');
+                }
+                JsonKey::Index(i) => path.push_str(&format!("[{i}]")),
+            }
+        }
+        Some(path)
+    }
+
+    /// ### set_expanded
+    ///
+    /// Expand or collapse the selected node, if it has children. Returns whether it changed
+    pub fn set_expanded(&mut self, expanded: bool) -> bool {
+        match self.selected_node() {
+            Some(id)
+                if !self.nodes[id].children.is_empty() && self.nodes[id].expanded != expanded =>
+            {
+                self.nodes[id].expanded = expanded;
+                self.layout();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn toggle(&mut self) -> bool {
+        let expanded = self
+            .selected_node()
+            .map(|id| self.nodes[id].expanded)
+            .unwrap_or_default();
+        self.set_expanded(!expanded)
+    }
+
+    /// ### enter
+    ///
+    /// Expand the selected node, or select its first child if it's already expanded
+    pub fn enter(&mut self) -> bool {
+        if self.set_expanded(true) {
+            return true;
+        }
+        match self.selected_node().map(|id| &self.nodes[id]) {
+            Some(node) if node.expanded && !node.children.is_empty() => {
+                self.select_node(node.children[0]);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// ### leave
+    ///
+    /// Collapse the selected node, or select its parent if it's already collapsed
+    pub fn leave(&mut self) -> bool {
+        if self.set_expanded(false) {
+            return true;
+        }
+        match self.selected_node().and_then(|id| self.nodes[id].parent) {
+            Some(parent) => {
+                self.select_node(parent);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// ### fit
+    ///
+    /// Scroll so that the selected node is visible in `height` rows
+    fn fit(&mut self, height: usize) {
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if height > 0 && self.selected >= self.scroll + height {
+            self.scroll = self.selected + 1 - height;
+        }
+    }
+}
+
+// -- component
+
+/// ## JsonViewer
+///
+/// A viewer for structured data: a `serde_json::Value` is displayed as a tree of keys and values, colored by type.
+/// Arrays and objects can be expanded and collapsed, and the path of the selected node is shown in a breadcrumb
+/// and can be copied
+#[derive(Default)]
+pub struct JsonViewer {
+    props: Props,
+    pub states: JsonViewerStates,
+}
+
+impl JsonViewer {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `json_viewer` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("json_viewer", self);
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    /// Set the value to display
+    pub fn value(mut self, value: &Value) -> Self {
+        self.attr(Attribute::Text, AttrValue::String(value.to_string()));
+        self
+    }
+
+    /// Show the path of the selected node on the last row (default: true)
+    pub fn breadcrumb(mut self, breadcrumb: bool) -> Self {
+        self.attr(
+            Attribute::Custom(JSON_VIEWER_BREADCRUMB),
+            AttrValue::Flag(breadcrumb),
+        );
+        self
+    }
+
+    /// Set the color of keys (default: blue)
+    pub fn key_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(JSON_VIEWER_KEY_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    /// Set the color of strings (default: green)
+    pub fn string_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(JSON_VIEWER_STRING_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    /// Set the color of numbers (default: yellow)
+    pub fn number_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(JSON_VIEWER_NUMBER_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    /// Set the color of booleans (default: magenta)
+    pub fn bool_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(JSON_VIEWER_BOOL_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    /// Set the color of nulls (default: dark gray)
+    pub fn null_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(JSON_VIEWER_NULL_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    fn get_color(&self, attr: &'static str, default: Color) -> Color {
+        self.props
+            .get_or(Attribute::Custom(attr), AttrValue::Color(default))
+            .unwrap_color()
+    }
+
+    fn value_style(&self, kind: JsonType, style: Style) -> Style {
+        match kind {
+            JsonType::Null => style.fg(self.get_color(JSON_VIEWER_NULL_COLOR, Color::DarkGray)),
+            JsonType::Bool => style.fg(self.get_color(JSON_VIEWER_BOOL_COLOR, Color::Magenta)),
+            JsonType::Number => style.fg(self.get_color(JSON_VIEWER_NUMBER_COLOR, Color::Yellow)),
+            JsonType::String => style.fg(self.get_color(JSON_VIEWER_STRING_COLOR, Color::Green)),
+            JsonType::Array | JsonType::Object => style.add_modifier(TextModifiers::DIM),
+        }
+    }
+
+    /// ### render_node
+    ///
+    /// Render a node as `▾ key: value`, indented by its depth
+    fn render_node(&self, buf: &mut Buffer, area: Rect, y: u16, node: &JsonNode, style: Style) {
+        let max_width = |x: u16| area.right().saturating_sub(x) as usize;
+        let indent = (node.depth * 2).min(area.width as usize) as u16;
+        let mut x = area.x + indent;
+        let marker = match (node.children.is_empty(), node.expanded) {
+            (true, _) => "  ",
+            (false, true) => "▾ ",
+            (false, false) => "▸ ",
+        };
+        (x, _) = buf.set_stringn(x, y, marker, max_width(x), style);
+        let key_style = style.fg(self.get_color(JSON_VIEWER_KEY_COLOR, Color::Blue));
+        let key = match &node.key {
+            JsonKey::Root => None,
+            JsonKey::Key(key) => Some(key.clone()),
+            JsonKey::Index(i) => Some(i.to_string()),
+        };
+        if let Some(key) = key {
+            (x, _) = buf.set_stringn(x, y, key, max_width(x), key_style);
+            (x, _) = buf.set_stringn(x, y, ": ", max_width(x), style);
+        }
+        let value_style = self.value_style(node.kind, style);
+        buf.set_stringn(x, y, &node.text, max_width(x), value_style);
+    }
+
+    /// ### render_breadcrumb
+    ///
+    /// Render the path of the selected node; if it doesn't fit, its beginning is elided
+    fn render_breadcrumb(&self, buf: &mut Buffer, area: Rect, y: u16, style: Style) {
+        let path = self.states.path().unwrap_or_default();
+        let width = area.width as usize;
+        let path = match path.width() > width {
+            true => {
+                let mut tail: Vec<char> = Vec::new();
+                let mut tail_width = 1;
+                for c in path.chars().rev() {
+                    tail_width += c.to_string().width();
+                    if tail_width > width {
+                        break;
+                    }
+                    tail.push(c);
+                }
+                std::iter::once('…').chain(tail.into_iter().rev()).collect()
+            }
+            false => path,
+        };
+        buf.set_stringn(
+            area.x,
+            y,
+            path,
+            width,
+            style.add_modifier(TextModifiers::BOLD),
+        );
+    }
+
+    /// ### render_tree
+    ///
+    /// Render the visible nodes and the breadcrumb in `area`
+    fn render_tree(&mut self, buf: &mut Buffer, area: Rect, style: Style, highlight: Style) {
+        let breadcrumb = self
+            .props
+            .get_or(
+                Attribute::Custom(JSON_VIEWER_BREADCRUMB),
+                AttrValue::Flag(true),
+            )
+            .unwrap_flag()
+            && area.height > 1;
+        let height = area.height.saturating_sub(u16::from(breadcrumb));
+        self.states.fit(height as usize);
+        let rows = self
+            .states
+            .visible
+            .iter()
+            .enumerate()
+            .skip(self.states.scroll);
+        for (y, (n, id)) in (area.y..area.y + height).zip(rows) {
+            self.render_node(buf, area, y, &self.states.nodes[*id], style);
+            if n == self.states.selected {
+                buf.set_style(Rect::new(area.x, y, area.width, 1), highlight);
+            }
+        }
+        if breadcrumb {
+            self.render_breadcrumb(buf, area, area.bottom() - 1, style);
+        }
+    }
+}
+
+impl MockComponent for JsonViewer {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let mut highlight = Style::default();
+            if let Some(color) = self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color())
+            {
+                highlight = highlight.fg(color);
+            }
+            if focus {
+                highlight = highlight.add_modifier(TextModifiers::REVERSED);
+            }
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_tree(render.buffer_mut(), inner, style, highlight);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if attr == Attribute::Text {
+            let value = self
+                .props
+                .get(Attribute::Text)
+                .and_then(|x| serde_json::from_str(&x.unwrap_string()).ok());
+            self.states.set_value(value.as_ref());
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.path() {
+            Some(path) => State::One(StateValue::String(path)),
+            None => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length();
+        let prev = self.states.selected;
+        let changed = match cmd {
+            Cmd::Move(Direction::Down) => {
+                self.states.select(prev + 1);
+                false
+            }
+            Cmd::Move(Direction::Up) => {
+                self.states.select(prev.saturating_sub(1));
+                false
+            }
+            Cmd::Move(Direction::Right) => self.states.enter(),
+            Cmd::Move(Direction::Left) => self.states.leave(),
+            Cmd::Scroll(Direction::Down) => {
+                self.states.select(prev + step);
+                false
+            }
+            Cmd::Scroll(Direction::Up) => {
+                self.states.select(prev.saturating_sub(step));
+                false
+            }
+            Cmd::GoTo(Position::Begin) => {
+                self.states.select(0);
+                false
+            }
+            Cmd::GoTo(Position::End) => {
+                self.states.select(usize::MAX);
+                false
+            }
+            Cmd::Toggle => self.states.toggle(),
+            Cmd::Submit => return CmdResult::Submit(self.state()),
+            Cmd::Custom(JSON_VIEWER_CMD_COPY_PATH) => {
+                return match self.state() {
+                    State::None => CmdResult::None,
+                    state => CmdResult::Custom(JSON_VIEWER_CMD_COPY_PATH, state),
+                }
+            }
+            _ => return CmdResult::None,
+        };
+        match changed || self.states.selected != prev {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    const JSON: &str = r#"{
+        "name": "tui-realm",
+        "stars": 800,
+        "stable": true,
+        "license": null,
+        "authors": [{ "name": "veeso" }, { "name": "hasezoey" }],
+        "build-deps": {}
+    }"#;
+
+    fn string(s: &str) -> State {
+        State::One(StateValue::String(s.to_string()))
+    }
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width)
+            .map(|x| buf[(x, y)].symbol().to_string())
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn test_components_json_viewer() {
+        let mut component = JsonViewer::default()
+            .borders(Borders::default())
+            .title("Cargo.json", Alignment::Left)
+            .highlighted_color(Color::Yellow)
+            .value(&serde_json::from_str(JSON).unwrap());
+        // Keys are sorted
+        assert_eq!(component.state(), string("$"));
+        assert_eq!(component.states.visible.len(), 7);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(string("$.authors"))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(string("$.authors"))
+        );
+        assert_eq!(component.states.visible.len(), 9);
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Move(Direction::Down));
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(string("$.authors[1].name"))
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(JSON_VIEWER_CMD_COPY_PATH)),
+            CmdResult::Custom(JSON_VIEWER_CMD_COPY_PATH, string("$.authors[1].name"))
+        );
+        // Leaving goes back to the parent, then collapses it
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(component.state(), string("$.authors[1]"));
+        component.perform(Cmd::Move(Direction::Left));
+        component.perform(Cmd::Move(Direction::Left));
+        assert_eq!(component.state(), string("$.authors"));
+        assert_eq!(
+            component.perform(Cmd::Toggle),
+            CmdResult::Changed(string("$.authors"))
+        );
+        assert_eq!(component.states.visible.len(), 7);
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(string("$[\"build-deps\"]"))
+        );
+        // Empty objects can't be expanded
+        assert_eq!(component.perform(Cmd::Toggle), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(string("$.stars"))
+        );
+        assert_eq!(component.perform(Cmd::GoTo(Position::End)), CmdResult::None);
+        // Invalid JSON
+        component.attr(Attribute::Text, AttrValue::String(String::from("{")));
+        assert_eq!(component.state(), State::None);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+    }
+
+    #[test]
+    fn test_components_json_viewer_render() {
+        let mut component = JsonViewer::default().value(&serde_json::from_str(JSON).unwrap());
+        component.perform(Cmd::Move(Direction::Down));
+        component.perform(Cmd::Move(Direction::Right));
+        let area = Rect::new(0, 0, 24, 5);
+        let mut buf = Buffer::empty(area);
+        component.render_tree(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(row(&buf, 0), "▾ {6 keys}");
+        assert_eq!(row(&buf, 1), "  ▾ authors: [2 items]");
+        assert_eq!(row(&buf, 2), "    ▸ 0: {1 key}");
+        assert_eq!(row(&buf, 3), "    ▸ 1: {1 key}");
+        assert_eq!(row(&buf, 4), "$.authors");
+        assert_eq!(buf[(4, 1)].fg, Color::Blue);
+        // Selection is kept in view
+        component.perform(Cmd::GoTo(Position::End));
+        let mut buf = Buffer::empty(area);
+        component.render_tree(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(row(&buf, 3), "    stars: 800");
+        assert_eq!(buf[(11, 3)].fg, Color::Yellow);
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buf = Buffer::empty(area);
+        component.render_tree(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(row(&buf, 1), "…stars");
+    }
+}
@@ -0,0 +1,558 @@
+//! ## Kanban
+//!
+//! `Kanban` renders a board of cards in columns
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, BorderSides, BorderType, Borders, Color, PropPayload,
+    PropValue, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Block, Widget},
+};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::TOOLTIP;
+
+// -- Props
+
+use super::commands::{
+    KANBAN_CMD_MOVE_CARD_DOWN, KANBAN_CMD_MOVE_CARD_LEFT, KANBAN_CMD_MOVE_CARD_RIGHT,
+    KANBAN_CMD_MOVE_CARD_UP,
+};
+
+/// Rows of a card
+const CARD_HEIGHT: u16 = 3;
+
+/// ## KanbanColumn
+///
+/// A column of the `Kanban` board, with its cards
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct KanbanColumn {
+    pub title: String,
+    pub cards: Vec<String>,
+}
+
+impl KanbanColumn {
+    /// ### new
+    ///
+    /// Make a new empty column
+    pub fn new<S: Into<String>>(title: S) -> Self {
+        Self {
+            title: title.into(),
+            cards: Vec::new(),
+        }
+    }
+
+    pub fn card<S: Into<String>>(mut self, card: S) -> Self {
+        self.cards.push(card.into());
+        self
+    }
+
+    fn to_payload(&self) -> PropPayload {
+        PropPayload::Vec(
+            std::iter::once(&self.title)
+                .chain(self.cards.iter())
+                .map(|x| PropValue::Str(x.clone()))
+                .collect(),
+        )
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        let mut values = payload.unwrap_vec().into_iter().map(|x| x.unwrap_str());
+        Self {
+            title: values.next().unwrap_or_default(),
+            cards: values.collect(),
+        }
+    }
+}
+
+// -- states
+
+/// ## KanbanStates
+///
+/// KanbanStates contains states for this component
+#[derive(Default)]
+pub struct KanbanStates {
+    pub columns: Vec<KanbanColumn>,
+    /// Index of the selected column
+    pub column: usize,
+    /// Index of the selected card in the selected column
+    pub card: usize,
+}
+
+impl KanbanStates {
+    /// ### set_columns
+    ///
+    /// Replace the columns, keeping the selection in range
+    pub fn set_columns(&mut self, columns: Vec<KanbanColumn>) {
+        self.columns = columns;
+        self.select(self.column, self.card);
+    }
+
+    /// ### select
+    ///
+    /// Select the card at `card` in `column`, clamped to the board
+    pub fn select(&mut self, column: usize, card: usize) {
+        self.column = column.min(self.columns.len().saturating_sub(1));
+        let cards = self
+            .columns
+            .get(self.column)
+            .map(|x| x.cards.len())
+            .unwrap_or(0);
+        self.card = card.min(cards.saturating_sub(1));
+    }
+
+    /// ### has_card
+    ///
+    /// Whether a card is selected
+    pub fn has_card(&self) -> bool {
+        self.columns
+            .get(self.column)
+            .is_some_and(|x| self.card < x.cards.len())
+    }
+
+    /// ### move_card
+    ///
+    /// Move the selected card to `index` in `column`, and keep it selected.
+    /// Returns the previous and new position of the card, as `(column, index)`, if it was moved
+    pub fn move_card(
+        &mut self,
+        column: usize,
+        index: usize,
+    ) -> Option<((usize, usize), (usize, usize))> {
+        if !self.has_card() || column >= self.columns.len() {
+            return None;
+        }
+        let from = (self.column, self.card);
+        let card = self.columns[from.0].cards.remove(from.1);
+        let index = index.min(self.columns[column].cards.len());
+        self.columns[column].cards.insert(index, card);
+        self.select(column, index);
+        match (column, index) == from {
+            true => None,
+            false => Some((from, (column, index))),
+        }
+    }
+}
+
+// -- component
+
+/// ## Kanban
+///
+/// A board of cards in columns, such as tasks by status. The selection moves across columns and cards, and the
+/// selected card can be moved to another column or reordered
+#[derive(Default)]
+pub struct Kanban {
+    props: Props,
+    pub states: KanbanStates,
+}
+
+impl Kanban {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `kanban` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("kanban", self);
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn columns(mut self, columns: &[KanbanColumn]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(
+                columns.iter().map(|x| x.to_payload()).collect(),
+            )),
+        );
+        self
+    }
+
+    /// ### move_card
+    ///
+    /// Move the selected card and keep the content in sync with the board
+    fn move_card(&mut self, column: usize, index: usize) -> CmdResult {
+        match self.states.move_card(column, index) {
+            Some(((from_column, from_index), (to_column, to_index))) => {
+                self.props.set(
+                    Attribute::Content,
+                    AttrValue::Payload(PropPayload::Linked(
+                        self.states.columns.iter().map(|x| x.to_payload()).collect(),
+                    )),
+                );
+                CmdResult::Changed(State::Tup4((
+                    StateValue::Usize(from_column),
+                    StateValue::Usize(from_index),
+                    StateValue::Usize(to_column),
+                    StateValue::Usize(to_index),
+                )))
+            }
+            None => CmdResult::None,
+        }
+    }
+
+    /// ### render_board
+    ///
+    /// Render the columns side by side in `area`, scrolling the selected column to the selected card
+    fn render_board(&self, buf: &mut Buffer, area: Rect, style: Style, highlight: Style) {
+        let columns = &self.states.columns;
+        // Borders of the selected column and card aren't reversed
+        let border_highlight = highlight
+            .remove_modifier(TextModifiers::REVERSED)
+            .add_modifier(TextModifiers::BOLD);
+        for (i, column) in columns.iter().enumerate() {
+            let x = area.x + (area.width as usize * i / columns.len()) as u16;
+            let right = area.x + (area.width as usize * (i + 1) / columns.len()) as u16;
+            let column_area = Rect::new(x, area.y, right - x, area.height);
+            let selected_column = i == self.states.column;
+            let block = Block::default()
+                .borders(BorderSides::ALL)
+                .border_style(match selected_column {
+                    true => style.patch(border_highlight),
+                    false => style,
+                })
+                .title(format!("{} ({})", column.title, column.cards.len()))
+                .style(style);
+            let inner = block.inner(column_area);
+            block.render(column_area, buf);
+            let visible = (inner.height / CARD_HEIGHT) as usize;
+            let offset = match selected_column {
+                true => self.states.card.saturating_sub(visible.saturating_sub(1)),
+                false => 0,
+            };
+            for (n, (j, card)) in column
+                .cards
+                .iter()
+                .enumerate()
+                .skip(offset)
+                .take(visible)
+                .enumerate()
+            {
+                let card_area = Rect::new(
+                    inner.x,
+                    inner.y + n as u16 * CARD_HEIGHT,
+                    inner.width,
+                    CARD_HEIGHT,
+                );
+                let selected = selected_column && j == self.states.card;
+                let card_block = Block::default()
+                    .borders(BorderSides::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(match selected {
+                        true => style.patch(border_highlight),
+                        false => style,
+                    });
+                let card_inner = card_block.inner(card_area);
+                card_block.render(card_area, buf);
+                let card_style = match selected {
+                    true => style.patch(highlight),
+                    false => style,
+                };
+                buf.set_style(card_inner, card_style);
+                buf.set_stringn(
+                    card_inner.x,
+                    card_inner.y,
+                    card,
+                    card_inner.width as usize,
+                    card_style,
+                );
+            }
+        }
+    }
+}
+
+impl MockComponent for Kanban {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let mut highlight = Style::default();
+            if let Some(color) = self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color())
+            {
+                highlight = highlight.fg(color);
+            }
+            if focus {
+                highlight = highlight.add_modifier(TextModifiers::REVERSED);
+            }
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_board(render.buffer_mut(), inner, style, highlight);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if attr == Attribute::Content {
+            let columns = match self
+                .props
+                .get(Attribute::Content)
+                .map(|x| x.unwrap_payload())
+            {
+                Some(PropPayload::Linked(columns)) => columns
+                    .into_iter()
+                    .map(KanbanColumn::from_payload)
+                    .collect(),
+                _ => Vec::new(),
+            };
+            self.states.set_columns(columns);
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.has_card() {
+            true => State::Tup2((
+                StateValue::Usize(self.states.column),
+                StateValue::Usize(self.states.card),
+            )),
+            false => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let (column, card) = (self.states.column, self.states.card);
+        match cmd {
+            Cmd::Move(Direction::Left) => self.states.select(column.saturating_sub(1), card),
+            Cmd::Move(Direction::Right) => self.states.select(column + 1, card),
+            Cmd::Move(Direction::Up) => self.states.select(column, card.saturating_sub(1)),
+            Cmd::Move(Direction::Down) => self.states.select(column, card + 1),
+            Cmd::GoTo(Position::Begin) => self.states.select(column, 0),
+            Cmd::GoTo(Position::End) => self.states.select(column, usize::MAX),
+            Cmd::Custom(KANBAN_CMD_MOVE_CARD_LEFT) if column > 0 => {
+                return self.move_card(column - 1, card)
+            }
+            Cmd::Custom(KANBAN_CMD_MOVE_CARD_RIGHT) => return self.move_card(column + 1, card),
+            Cmd::Custom(KANBAN_CMD_MOVE_CARD_UP) if card > 0 => {
+                return self.move_card(column, card - 1)
+            }
+            Cmd::Custom(KANBAN_CMD_MOVE_CARD_DOWN) => return self.move_card(column, card + 1),
+            Cmd::Submit => {
+                return match self.state() {
+                    State::None => CmdResult::None,
+                    state => CmdResult::Submit(state),
+                }
+            }
+            _ => return CmdResult::None,
+        }
+        match (self.states.column, self.states.card) == (column, card) {
+            true => CmdResult::None,
+            false => CmdResult::Changed(self.state()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn component() -> Kanban {
+        Kanban::default().columns(&[
+            KanbanColumn::new("To do")
+                .card("Write docs")
+                .card("Fix bug"),
+            KanbanColumn::new("Doing"),
+            KanbanColumn::new("Done").card("Release"),
+        ])
+    }
+
+    fn position(column: usize, card: usize) -> State {
+        State::Tup2((StateValue::Usize(column), StateValue::Usize(card)))
+    }
+
+    #[test]
+    fn test_components_kanban() {
+        let mut component = component();
+        assert_eq!(component.state(), position(0, 0));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(position(0, 1))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        // Empty column
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Custom(KANBAN_CMD_MOVE_CARD_RIGHT)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(position(2, 0))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(position(2, 0))
+        );
+    }
+
+    #[test]
+    fn test_components_kanban_move_card() {
+        let mut component = component();
+        component.perform(Cmd::Move(Direction::Down));
+        // Fix bug: (0, 1) -> (1, 0)
+        assert_eq!(
+            component.perform(Cmd::Custom(KANBAN_CMD_MOVE_CARD_RIGHT)),
+            CmdResult::Changed(State::Tup4((
+                StateValue::Usize(0),
+                StateValue::Usize(1),
+                StateValue::Usize(1),
+                StateValue::Usize(0)
+            )))
+        );
+        assert_eq!(component.state(), position(1, 0));
+        // (1, 0) -> (2, 0)
+        component.perform(Cmd::Custom(KANBAN_CMD_MOVE_CARD_RIGHT));
+        assert_eq!(
+            component.states.columns[2].cards,
+            vec![String::from("Fix bug"), String::from("Release")]
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(KANBAN_CMD_MOVE_CARD_RIGHT)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(KANBAN_CMD_MOVE_CARD_UP)),
+            CmdResult::None
+        );
+        // Reorder
+        assert_eq!(
+            component.perform(Cmd::Custom(KANBAN_CMD_MOVE_CARD_DOWN)),
+            CmdResult::Changed(State::Tup4((
+                StateValue::Usize(2),
+                StateValue::Usize(0),
+                StateValue::Usize(2),
+                StateValue::Usize(1)
+            )))
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(KANBAN_CMD_MOVE_CARD_DOWN)),
+            CmdResult::None
+        );
+        // Content is in sync
+        let columns = match component.query(Attribute::Content) {
+            Some(AttrValue::Payload(PropPayload::Linked(columns))) => columns
+                .into_iter()
+                .map(KanbanColumn::from_payload)
+                .collect::<Vec<KanbanColumn>>(),
+            _ => Vec::new(),
+        };
+        assert_eq!(columns, component.states.columns);
+        assert_eq!(columns[0].cards, vec![String::from("Write docs")]);
+    }
+
+    #[test]
+    fn test_components_kanban_render() {
+        let mut component = component();
+        component.perform(Cmd::Move(Direction::Down));
+        let area = Rect::new(0, 0, 36, 6);
+        let mut buf = Buffer::empty(area);
+        component.render_board(&mut buf, area, Style::default(), Style::default());
+        let row = |y: u16| -> String { (0..36).map(|x| buf[(x, y)].symbol()).collect() };
+        // Only one card fits: the selected column is scrolled to the selected card
+        assert_eq!(row(0), "┌To do (2)─┐┌Doing (0)─┐┌Done (1)──┐");
+        assert_eq!(row(1), "│╭────────╮││          ││╭────────╮│");
+        assert_eq!(row(2), "││Fix bug │││          │││Release ││");
+        assert_eq!(row(3), "│╰────────╯││          ││╰────────╯│");
+        assert_eq!(row(5), "└──────────┘└──────────┘└──────────┘");
+    }
+}
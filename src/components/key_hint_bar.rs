@@ -0,0 +1,307 @@
+//! ## KeyHintBar
+//!
+//! `KeyHintBar` renders a footer of key bindings, such as `^Q Quit  ^S Save`
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    AttrValue, Attribute, Color, PropPayload, PropValue, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{KEY_HINT_BAR_KEY_STYLE, KEY_HINT_BAR_SEPARATOR, TOOLTIP};
+
+/// ## KeyHint
+///
+/// A key binding displayed by the `KeyHintBar`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default))]
+pub struct KeyHint {
+    pub key: String,
+    pub action: String,
+    /// Hints with a lower priority are elided first when the bar is too narrow
+    pub priority: usize,
+}
+
+impl KeyHint {
+    /// ### new
+    ///
+    /// Make a new hint with priority 0
+    pub fn new<K: Into<String>, A: Into<String>>(key: K, action: A) -> Self {
+        Self {
+            key: key.into(),
+            action: action.into(),
+            priority: 0,
+        }
+    }
+
+    pub fn priority(mut self, priority: usize) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// ### width
+    ///
+    /// Returns the width of the hint, as `key action`
+    fn width(&self) -> usize {
+        self.key.width() + 1 + self.action.width()
+    }
+
+    fn to_payload(&self) -> PropPayload {
+        PropPayload::Tup3((
+            PropValue::Str(self.key.clone()),
+            PropValue::Str(self.action.clone()),
+            PropValue::Usize(self.priority),
+        ))
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        let (key, action, priority) = payload.unwrap_tup3();
+        Self {
+            key: key.unwrap_str(),
+            action: action.unwrap_str(),
+            priority: priority.unwrap_usize(),
+        }
+    }
+}
+
+/// ### visible_hints
+///
+/// Returns the indexes of the hints which fit in `width` columns, keeping their order.
+/// Hints with the lowest priority are elided first; among equals, the rightmost one
+fn visible_hints(hints: &[KeyHint], separator: usize, width: usize) -> Vec<usize> {
+    let mut visible: Vec<usize> = (0..hints.len()).collect();
+    let total = |visible: &[usize]| -> usize {
+        visible.iter().map(|i| hints[*i].width()).sum::<usize>()
+            + separator * visible.len().saturating_sub(1)
+    };
+    while total(&visible) > width {
+        let lowest = visible
+            .iter()
+            .enumerate()
+            .rev()
+            .min_by_key(|(_, i)| hints[**i].priority)
+            .map(|(pos, _)| pos);
+        match lowest {
+            Some(pos) => visible.remove(pos),
+            None => break,
+        };
+    }
+    visible
+}
+
+/// ## KeyHintBar
+///
+/// A footer displaying key bindings and their action, with the key and the label styled differently.
+/// When the bar is too narrow, hints with a low priority are elided
+#[derive(Default)]
+pub struct KeyHintBar {
+    props: Props,
+}
+
+impl KeyHintBar {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `key_hint_bar` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("key_hint_bar", self);
+    }
+
+    pub fn hints(mut self, hints: &[KeyHint]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(
+                hints.iter().map(|x| x.to_payload()).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the style of keys (default: reversed)
+    pub fn key_style(mut self, s: Style) -> Self {
+        self.attr(
+            Attribute::Custom(KEY_HINT_BAR_KEY_STYLE),
+            AttrValue::Style(s),
+        );
+        self
+    }
+
+    /// Set the separator between hints (default: two spaces)
+    pub fn separator<S: Into<String>>(mut self, s: S) -> Self {
+        self.attr(
+            Attribute::Custom(KEY_HINT_BAR_SEPARATOR),
+            AttrValue::String(s.into()),
+        );
+        self
+    }
+
+    fn get_hints(&self) -> Vec<KeyHint> {
+        match self
+            .props
+            .get(Attribute::Content)
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Linked(hints)) => {
+                hints.into_iter().map(KeyHint::from_payload).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### render_hints
+    ///
+    /// Render the hints which fit on the first row of `area`
+    fn render_hints(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        if area.height == 0 {
+            return;
+        }
+        let key_style = self
+            .props
+            .get_or(
+                Attribute::Custom(KEY_HINT_BAR_KEY_STYLE),
+                AttrValue::Style(Style::default().add_modifier(TextModifiers::REVERSED)),
+            )
+            .unwrap_style();
+        let key_style = style.patch(key_style);
+        let separator = self
+            .props
+            .get_or(
+                Attribute::Custom(KEY_HINT_BAR_SEPARATOR),
+                AttrValue::String(String::from("  ")),
+            )
+            .unwrap_string();
+        let hints = self.get_hints();
+        let mut x = area.x;
+        for (n, i) in visible_hints(&hints, separator.width(), area.width as usize)
+            .into_iter()
+            .enumerate()
+        {
+            let max_width = |x: u16| area.right().saturating_sub(x) as usize;
+            if n > 0 {
+                (x, _) = buf.set_stringn(x, area.y, &separator, max_width(x), style);
+            }
+            let hint = &hints[i];
+            (x, _) = buf.set_stringn(x, area.y, &hint.key, max_width(x), key_style);
+            (x, _) = buf.set_stringn(x, area.y, " ", max_width(x), style);
+            (x, _) = buf.set_stringn(x, area.y, &hint.action, max_width(x), style);
+        }
+    }
+}
+
+impl MockComponent for KeyHintBar {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let style = Style::default().fg(foreground).bg(background);
+            let buf = render.buffer_mut();
+            buf.set_style(area, style);
+            self.render_hints(buf, area, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_key_hint_bar_visible_hints() {
+        let hints = [
+            KeyHint::new("^Q", "Quit").priority(2),
+            KeyHint::new("^S", "Save").priority(1),
+            KeyHint::new("^F", "Find"),
+            KeyHint::new("^H", "Help"),
+        ];
+        // 4 * 7 + 3 * 2
+        assert_eq!(visible_hints(&hints, 2, 34), vec![0, 1, 2, 3]);
+        assert_eq!(visible_hints(&hints, 2, 33), vec![0, 1, 2]);
+        assert_eq!(visible_hints(&hints, 2, 20), vec![0, 1]);
+        assert_eq!(visible_hints(&hints, 2, 10), vec![0]);
+        assert_eq!(visible_hints(&hints, 2, 6), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_components_key_hint_bar() {
+        let component = KeyHintBar::default()
+            .hints(&[
+                KeyHint::new("^Q", "Quit").priority(1),
+                KeyHint::new("^S", "Save"),
+                KeyHint::new("F1", "Help").priority(1),
+            ])
+            .key_style(Style::default().fg(Color::Yellow))
+            .separator(" | ");
+        assert_eq!(component.state(), State::None);
+        assert_eq!(
+            component.get_hints()[2],
+            KeyHint::new("F1", "Help").priority(1)
+        );
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_hints(&mut buf, area, Style::default());
+        let row: String = (0..area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect();
+        assert_eq!(row, "^Q Quit | F1 Help   ");
+        assert_eq!(buf[(0, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(3, 0)].fg, Color::Reset);
+    }
+}
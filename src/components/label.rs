@@ -1,11 +1,69 @@
 //! ## Label
 //!
 //! `Label` represents a read-only text component without any container.
+//! When the text is wider than the area, it's clipped, truncated with an ellipsis or scrolled, according to its
+//! `Truncation`.
+//! With `markup`, parts of the text can be styled with tags, such as `<green bold>OK</> 12 tests`.
+//! The text can link to a URL, which is activated by clicking it, and rendered as a hyperlink on supporting terminals.
 
 use tuirealm::command::{Cmd, CmdResult};
-use tuirealm::props::{Alignment, AttrValue, Attribute, Color, Props, Style, TextModifiers};
+use tuirealm::event::MouseEvent;
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Color, PropPayload, PropValue, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::text::{Line as Spans, Span};
 use tuirealm::ratatui::{layout::Rect, widgets::Paragraph};
-use tuirealm::{Frame, MockComponent, State};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use super::states::MarqueeState;
+use crate::theme::Theme;
+use crate::utils::{
+    clicked, graphemes_width, is_disabled, parse_markup, render_disabled, render_hyperlink,
+    render_tooltip, span_areas, truncate_spans,
+};
+
+// -- Props
+
+use super::props::{LABEL_MARKUP, LINKS, LINKS_OSC8, TOOLTIP, TRUNCATION};
+
+/// ## Truncation
+///
+/// How a text wider than its area is displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Truncation {
+    /// The end of the text is cut
+    #[default]
+    Clip,
+    /// The end of the text is replaced by an ellipsis: `long_fi…`
+    End,
+    /// The middle of the text is replaced by an ellipsis: `long…txt`
+    Middle,
+    /// The text scrolls by a grapheme on `Cmd::Tick`, which should be performed on `Event::Tick`
+    Marquee,
+}
+
+impl Truncation {
+    const ALL: [Self; 4] = [Self::Clip, Self::End, Self::Middle, Self::Marquee];
+
+    /// ### from_props
+    ///
+    /// Get the truncation set in `props` with `Custom($TRUNCATION)`
+    pub(crate) fn from_props(props: &Props) -> Self {
+        match props
+            .get(Attribute::Custom(TRUNCATION))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::Usize(x))) => {
+                Self::ALL.get(x).copied().unwrap_or_default()
+            }
+            _ => Self::default(),
+        }
+    }
+
+    pub(crate) fn to_attr_value(self) -> AttrValue {
+        AttrValue::Payload(PropPayload::One(PropValue::Usize(self as usize)))
+    }
+}
 
 // -- Component
 
@@ -15,6 +73,9 @@ use tuirealm::{Frame, MockComponent, State};
 #[derive(Default)]
 pub struct Label {
     props: Props,
+    pub states: MarqueeState,
+    /// Area of the text in the last render
+    text_area: Rect,
 }
 
 impl Label {
@@ -28,6 +89,28 @@ impl Label {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `label` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("label", self);
+    }
+
     pub fn modifiers(mut self, m: TextModifiers) -> Self {
         self.attr(Attribute::TextProps, AttrValue::TextModifiers(m));
         self
@@ -42,6 +125,44 @@ impl Label {
         self.attr(Attribute::Alignment, AttrValue::Alignment(alignment));
         self
     }
+
+    /// Parse the text as markup with `utils::parse_markup`, where tags such as `<green bold>` and `<white on red>` style
+    /// the text up to `</>`
+    pub fn markup(mut self, markup: bool) -> Self {
+        self.attr(Attribute::Custom(LABEL_MARKUP), AttrValue::Flag(markup));
+        self
+    }
+
+    /// Set how the text is displayed when it's wider than the area (default: `Clip`)
+    pub fn truncation(mut self, truncation: Truncation) -> Self {
+        self.attr(Attribute::Custom(TRUNCATION), truncation.to_attr_value());
+        self
+    }
+
+    /// Link the text to `url`, activated by clicking it
+    pub fn link<S: Into<String>>(mut self, url: S) -> Self {
+        self.attr(Attribute::Custom(LINKS), AttrValue::String(url.into()));
+        self
+    }
+
+    /// Render the link as an OSC 8 hyperlink, which supporting terminals make clickable, e.g. to open it in a browser
+    pub fn hyperlink(mut self, osc8: bool) -> Self {
+        self.attr(Attribute::Custom(LINKS_OSC8), AttrValue::Flag(osc8));
+        self
+    }
+
+    /// ### on_mouse
+    ///
+    /// Handle a mouse event: clicking the text, if it has a link, returns `Submit(One(Usize(0)))`
+    pub fn on_mouse(&mut self, ev: MouseEvent) -> CmdResult {
+        if is_disabled(&self.props) || self.props.get(Attribute::Custom(LINKS)).is_none() {
+            return CmdResult::None;
+        }
+        match clicked(&ev, self.text_area) {
+            Some(_) => CmdResult::Submit(State::One(StateValue::Usize(0))),
+            None => CmdResult::None,
+        }
+    }
 }
 
 impl MockComponent for Label {
@@ -72,8 +193,42 @@ impl MockComponent for Label {
                     AttrValue::TextModifiers(TextModifiers::empty()),
                 )
                 .unwrap_text_modifiers();
+            let markup = self
+                .props
+                .get_or(Attribute::Custom(LABEL_MARKUP), AttrValue::Flag(false))
+                .unwrap_flag();
+            let spans: Vec<Span> = match markup {
+                true => parse_markup(&text)
+                    .into_iter()
+                    .map(|x| {
+                        // Spans without colors and modifiers take the ones of the label
+                        let (fg, bg, modifiers) =
+                            crate::utils::use_or_default_styles(&self.props, &x);
+                        Span::styled(
+                            x.content,
+                            Style::default().add_modifier(modifiers).fg(fg).bg(bg),
+                        )
+                    })
+                    .collect(),
+                false => vec![Span::raw(text)],
+            };
+            let truncation = Truncation::from_props(&self.props);
+            let width = area.width as usize;
+            self.states.set_overflows(
+                truncation == Truncation::Marquee
+                    && spans
+                        .iter()
+                        .map(|x| graphemes_width(&x.content))
+                        .sum::<usize>()
+                        > width,
+            );
+            let text = truncate_spans(spans, width, truncation, self.states.frame);
+            self.text_area = span_areas(&text, area, alignment)
+                .into_iter()
+                .reduce(|a, b| a.union(b))
+                .unwrap_or_default();
             render.render_widget(
-                Paragraph::new(text)
+                Paragraph::new(Spans::from(text))
                     .style(
                         Style::default()
                             .fg(foreground)
@@ -83,7 +238,17 @@ impl MockComponent for Label {
                     .alignment(alignment),
                 area,
             );
+            let link = self.props.get(Attribute::Custom(LINKS));
+            let osc8 = self
+                .props
+                .get_or(Attribute::Custom(LINKS_OSC8), AttrValue::Flag(false))
+                .unwrap_flag();
+            if let (Some(AttrValue::String(url)), true) = (link, osc8) {
+                render_hyperlink(render.buffer_mut(), self.text_area, &url);
+            }
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -98,8 +263,14 @@ impl MockComponent for Label {
         State::None
     }
 
-    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
-        CmdResult::None
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Tick if self.states.tick() => CmdResult::Changed(State::None),
+            _ => CmdResult::None,
+        }
     }
 }
 
@@ -130,4 +301,70 @@ mod tests {
         let _ = Label::default().text(&String::from("&String"));
         let _ = Label::default().text(format!("Format"));
     }
+
+    #[test]
+    fn test_components_label_truncation() {
+        use crate::testing::{assert_lines, render};
+
+        let mut component = Label::default()
+            .text("long_filename.txt")
+            .truncation(Truncation::Middle);
+        assert_lines(&render(&mut component, 8, 1), &["long…txt"]);
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        // The marquee scrolls on tick, only if the text overflows
+        component = component.truncation(Truncation::Marquee);
+        assert_lines(&render(&mut component, 8, 1), &["long_fil"]);
+        assert_eq!(
+            component.perform(Cmd::Tick),
+            CmdResult::Changed(State::None)
+        );
+        assert_lines(&render(&mut component, 8, 1), &["ong_file"]);
+        assert_lines(&render(&mut component, 20, 1), &["long_filename.txt"]);
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        assert_eq!(component.states.frame, 0);
+    }
+
+    #[test]
+    fn test_components_label_markup() {
+        let mut component = Label::default()
+            .text("<bold>OK</> done")
+            .markup(true)
+            .truncation(Truncation::End);
+        let buf = crate::testing::render(&mut component, 6, 1);
+        crate::testing::assert_lines(&buf, &["OK do…"]);
+        assert!(buf[(0, 0)].modifier.contains(TextModifiers::BOLD));
+        assert!(!buf[(2, 0)].modifier.contains(TextModifiers::BOLD));
+    }
+
+    #[test]
+    fn test_components_label_link() {
+        use tuirealm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+        let click = |column| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        };
+        let mut component = Label::default()
+            .text("docs")
+            .alignment(Alignment::Right)
+            .link("https://docs.rs")
+            .hyperlink(true);
+        let buf = crate::testing::render(&mut component, 6, 1);
+        // The escape sequences are written around every other cell
+        assert_eq!(
+            buf[(2, 0)].symbol(),
+            "\x1B]8;;https://docs.rs\x1B\\do\x1B]8;;\x1B\\"
+        );
+        assert_eq!(
+            buf[(4, 0)].symbol(),
+            "\x1B]8;;https://docs.rs\x1B\\cs\x1B]8;;\x1B\\"
+        );
+        assert_eq!(component.on_mouse(click(1)), CmdResult::None);
+        assert_eq!(
+            component.on_mouse(click(3)),
+            CmdResult::Submit(State::One(StateValue::Usize(0)))
+        );
+    }
 }
@@ -4,7 +4,7 @@
 
 use super::props::{
     LINE_GAUGE_STYLE_DOUBLE, LINE_GAUGE_STYLE_NORMAL, LINE_GAUGE_STYLE_ROUND,
-    LINE_GAUGE_STYLE_THICK,
+    LINE_GAUGE_STYLE_THICK, TOOLTIP,
 };
 
 use tuirealm::command::{Cmd, CmdResult};
@@ -19,6 +19,9 @@ use tuirealm::ratatui::{
 };
 use tuirealm::{Frame, MockComponent, State};
 
+use crate::theme::Theme;
+use crate::utils::{get_title_style, render_disabled, render_tooltip, FocusStyles};
+
 // -- Component
 
 /// ## LineGauge
@@ -55,6 +58,28 @@ impl LineGauge {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `line_gauge` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("line_gauge", self);
+    }
+
     pub fn label<S: Into<String>>(mut self, s: S) -> Self {
         self.attr(Attribute::Text, AttrValue::String(s.into()));
         self
@@ -154,23 +179,29 @@ impl MockComponent for LineGauge {
                 .unwrap_payload()
                 .unwrap_one()
                 .unwrap_f64();
-            let div = crate::utils::get_block(borders, title, true, None);
+            let styles = FocusStyles::display(
+                &self.props,
+                Style::default()
+                    .fg(foreground)
+                    .bg(background)
+                    .add_modifier(modifiers),
+            );
+            let div = styles
+                .block(borders, title)
+                .title_style(get_title_style(&self.props));
             // Make progress bar
             render.render_widget(
                 TuiLineGauge::default()
                     .block(div)
-                    .filled_style(
-                        Style::default()
-                            .fg(foreground)
-                            .bg(background)
-                            .add_modifier(modifiers),
-                    )
+                    .filled_style(styles.style)
                     .line_set(self.line_set())
                     .label(label)
                     .ratio(percentage),
                 area,
             );
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -2,7 +2,10 @@
 //!
 //! `List` represents a read-only textual list component which can be scrollable through arrows or inactive
 
+use std::sync::Arc;
+
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::event::MouseEvent;
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style, Table,
     TextModifiers,
@@ -15,101 +18,20 @@ use tuirealm::ratatui::{
 };
 use tuirealm::{Frame, MockComponent, State, StateValue};
 
-// -- States
-
-#[derive(Default)]
-pub struct ListStates {
-    pub list_index: usize, // Index of selected item in list
-    pub list_len: usize,   // Lines in text area
-}
-
-impl ListStates {
-    /// ### set_list_len
-    ///
-    /// Set list length
-    pub fn set_list_len(&mut self, len: usize) {
-        self.list_len = len;
-    }
-
-    /// ### incr_list_index
-    ///
-    /// Incremenet list index
-    pub fn incr_list_index(&mut self, rewind: bool) {
-        // Check if index is at last element
-        if self.list_index + 1 < self.list_len {
-            self.list_index += 1;
-        } else if rewind {
-            self.list_index = 0;
-        }
-    }
-
-    /// ### decr_list_index
-    ///
-    /// Decrement list index
-    pub fn decr_list_index(&mut self, rewind: bool) {
-        // Check if index is bigger than 0
-        if self.list_index > 0 {
-            self.list_index -= 1;
-        } else if rewind && self.list_len > 0 {
-            self.list_index = self.list_len - 1;
-        }
-    }
-
-    /// ### fix_list_index
-    ///
-    /// Keep index if possible, otherwise set to lenght - 1
-    pub fn fix_list_index(&mut self) {
-        if self.list_index >= self.list_len && self.list_len > 0 {
-            self.list_index = self.list_len - 1;
-        } else if self.list_len == 0 {
-            self.list_index = 0;
-        }
-    }
+use crate::theme::Theme;
+use crate::utils::{clicked, get_title_style, is_disabled, render_disabled, render_tooltip, wheel};
 
-    /// ### list_index_at_first
-    ///
-    /// Set list index to the first item in the list
-    pub fn list_index_at_first(&mut self) {
-        self.list_index = 0;
-    }
+// -- Props
 
-    /// ### list_index_at_last
-    ///
-    /// Set list index at the last item of the list
-    pub fn list_index_at_last(&mut self) {
-        if self.list_len > 0 {
-            self.list_index = self.list_len - 1;
-        } else {
-            self.list_index = 0;
-        }
-    }
+use super::props::{MOUSE_WHEEL_STEP, ROWS_APPEND, ROWS_PATCH, ROWS_REMOVE, TOOLTIP};
+use super::states::{RenderCache, ScrollableState};
 
-    /// ### calc_max_step_ahead
-    ///
-    /// Calculate the max step ahead to scroll list
-    pub fn calc_max_step_ahead(&self, max: usize) -> usize {
-        let remaining: usize = match self.list_len {
-            0 => 0,
-            len => len - 1 - self.list_index,
-        };
-        if remaining > max {
-            max
-        } else {
-            remaining
-        }
-    }
+// -- States
 
-    /// ### calc_max_step_ahead
-    ///
-    /// Calculate the max step ahead to scroll list
-    pub fn calc_max_step_behind(&self, max: usize) -> usize {
-        if self.list_index > max {
-            max
-        } else {
-            self.list_index
-        }
-    }
-}
+/// ## ListStates
+///
+/// The states of `List`, shared with the other scrollable components
+pub type ListStates = ScrollableState;
 
 // -- Component
 
@@ -121,6 +43,8 @@ pub struct List {
     props: Props,
     pub states: ListStates,
     hg_str: Option<String>, // CRAP CRAP CRAP. Thanks to the author of tui-realm for using references every f time
+    rows: RenderCache<Vec<Vec<Span<'static>>>>,
+    content: Option<Arc<Table>>,
 }
 
 impl List {
@@ -154,6 +78,28 @@ impl List {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `list` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("list", self);
+    }
+
     pub fn rewind(mut self, r: bool) -> Self {
         self.attr(Attribute::Rewind, AttrValue::Flag(r));
         self
@@ -164,6 +110,15 @@ impl List {
         self
     }
 
+    /// Set the lines scrolled by each notch of the mouse wheel (default: 3)
+    pub fn wheel_step(mut self, lines: usize) -> Self {
+        self.attr(
+            Attribute::Custom(MOUSE_WHEEL_STEP),
+            AttrValue::Length(lines),
+        );
+        self
+    }
+
     pub fn scroll(mut self, scrollable: bool) -> Self {
         self.attr(Attribute::Scroll, AttrValue::Flag(scrollable));
         self
@@ -184,6 +139,24 @@ impl List {
         self
     }
 
+    /// Set the rows shared with the application, which aren't copied. See `set_shared_rows`
+    pub fn shared_rows(mut self, rows: Arc<Table>) -> Self {
+        self.set_shared_rows(rows);
+        self
+    }
+
+    /// ### set_shared_rows
+    ///
+    /// Set the rows shared with the application. Unlike `Attribute::Content`, the rows aren't cloned when set and
+    /// when rendered: to update them, set a new `Arc`, or update single rows with `Custom($ROWS_APPEND)`,
+    /// `Custom($ROWS_PATCH)` and `Custom($ROWS_REMOVE)`, which copy them only if the application still holds them
+    pub fn set_shared_rows(&mut self, rows: Arc<Table>) {
+        self.states.set_list_len(rows.len());
+        self.states.fix_list_index();
+        self.content = Some(rows);
+        self.rows.invalidate();
+    }
+
     /// Set initial selected line
     /// This method must be called after `rows` and `scrollable` in order to work
     pub fn selected_line(mut self, line: usize) -> Self {
@@ -205,6 +178,50 @@ impl List {
             .get_or(Attribute::Rewind, AttrValue::Flag(false))
             .unwrap_flag()
     }
+
+    /// ### on_mouse
+    ///
+    /// Handle a mouse event: if the list is scrollable, clicking a row selects it and the wheel moves the selection
+    pub fn on_mouse(&mut self, ev: MouseEvent) -> CmdResult {
+        if is_disabled(&self.props) || !self.scrollable() {
+            return CmdResult::None;
+        }
+        if let Some((direction, step)) = wheel(&ev, self.states.area, &self.props) {
+            let prev = self.states.list_index;
+            match direction {
+                Direction::Down => {
+                    self.states.scroll_down(step);
+                }
+                _ => {
+                    self.states.scroll_up(step);
+                }
+            }
+            return match prev != self.states.list_index {
+                true => CmdResult::Changed(self.state()),
+                false => CmdResult::None,
+            };
+        }
+        match clicked(&ev, self.states.area).and_then(|(_, row)| self.states.row_at(row)) {
+            Some(index) => {
+                let prev = self.states.list_index;
+                self.states.list_index = index;
+                match prev != self.states.list_index {
+                    true => CmdResult::Changed(self.state()),
+                    false => CmdResult::None,
+                }
+            }
+            None => CmdResult::None,
+        }
+    }
+
+    /// ### make_rows
+    ///
+    /// Make the styled spans of each row of the content
+    fn make_rows(props: &Props, content: Option<&Table>) -> Vec<Vec<Span<'static>>> {
+        content
+            .map(|table| crate::utils::styled_rows(props, table))
+            .unwrap_or_default()
+    }
 }
 
 impl MockComponent for List {
@@ -248,29 +265,24 @@ impl MockComponent for List {
                 true => focus,
                 false => true,
             };
-            let div = crate::utils::get_block(borders, Some(title), active, inactive_style);
-            // Make list entries
-            let list_items: Vec<ListItem> =
-                match self.props.get(Attribute::Content).map(|x| x.unwrap_table()) {
-                    Some(table) => table
+            let div = crate::utils::get_block(borders, Some(title), active, inactive_style)
+                .title_style(get_title_style(&self.props));
+            self.states.area = div.inner(area);
+            // Make list entries; rows are rebuilt only when props have changed
+            let (props, content) = (&self.props, self.content.as_deref());
+            self.rows.refresh(|| Self::make_rows(props, content));
+            let list_items: Vec<ListItem> = self
+                .rows
+                .value()
+                .iter()
+                .map(|row| {
+                    let columns: Vec<Span> = row
                         .iter()
-                        .map(|row| {
-                            let columns: Vec<Span> = row
-                                .iter()
-                                .map(|col| {
-                                    let (fg, bg, modifiers) =
-                                        crate::utils::use_or_default_styles(&self.props, col);
-                                    Span::styled(
-                                        col.content.clone(),
-                                        Style::default().add_modifier(modifiers).fg(fg).bg(bg),
-                                    )
-                                })
-                                .collect();
-                            ListItem::new(Spans::from(columns))
-                        })
-                        .collect(), // Make List item from TextSpan
-                    _ => Vec::new(),
-                };
+                        .map(|col| Span::styled(col.content.as_ref(), col.style))
+                        .collect();
+                    ListItem::new(Spans::from(columns))
+                })
+                .collect();
             let highlighted_color = self
                 .props
                 .get(Attribute::HighlightedColor)
@@ -301,31 +313,41 @@ impl MockComponent for List {
                 list = list.highlight_symbol(hg_str);
             }
             if self.scrollable() {
-                let mut state: ListState = ListState::default();
+                let mut state: ListState = ListState::default().with_offset(self.states.offset);
                 state.select(Some(self.states.list_index));
                 render.render_stateful_widget(list, area, &mut state);
+                self.states.offset = state.offset();
             } else {
                 render.render_widget(list, area);
             }
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
-        self.props.get(attr)
+        match attr {
+            Attribute::Content => self
+                .content
+                .as_ref()
+                .map(|x| AttrValue::Table(x.as_ref().clone())),
+            attr => self.props.get(attr),
+        }
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
-        self.props.set(attr, value);
-        if matches!(attr, Attribute::Content) {
+        self.rows.invalidate();
+        match (attr, value) {
             // Update list len and fix index
-            self.states.set_list_len(
-                match self.props.get(Attribute::Content).map(|x| x.unwrap_table()) {
-                    Some(spans) => spans.len(),
-                    _ => 0,
-                },
-            );
-            self.states.fix_list_index();
-        } else if matches!(attr, Attribute::Value) && self.scrollable() {
+            (Attribute::Content, AttrValue::Table(table)) => self.set_shared_rows(Arc::new(table)),
+            // Update single rows, keeping the selected one; rows are copied if shared
+            (Attribute::Custom(ROWS_APPEND | ROWS_PATCH | ROWS_REMOVE), value) => {
+                let rows = Arc::make_mut(self.content.get_or_insert_with(Default::default));
+                crate::utils::update_rows(rows, &mut self.states, attr, value);
+            }
+            (attr, value) => self.props.set(attr, value),
+        }
+        if matches!(attr, Attribute::Value) && self.scrollable() {
             self.states.list_index = self
                 .props
                 .get(Attribute::Value)
@@ -343,6 +365,9 @@ impl MockComponent for List {
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
         match cmd {
             Cmd::Move(Direction::Down) => {
                 let prev = self.states.list_index;
@@ -368,8 +393,7 @@ impl MockComponent for List {
                     .props
                     .get_or(Attribute::ScrollStep, AttrValue::Length(8))
                     .unwrap_length();
-                let step: usize = self.states.calc_max_step_ahead(step);
-                (0..step).for_each(|_| self.states.incr_list_index(false));
+                self.states.scroll_down(step);
                 if prev != self.states.list_index {
                     CmdResult::Changed(self.state())
                 } else {
@@ -382,8 +406,7 @@ impl MockComponent for List {
                     .props
                     .get_or(Attribute::ScrollStep, AttrValue::Length(8))
                     .unwrap_length();
-                let step: usize = self.states.calc_max_step_behind(step);
-                (0..step).for_each(|_| self.states.decr_list_index(false));
+                self.states.scroll_up(step);
                 if prev != self.states.list_index {
                     CmdResult::Changed(self.state())
                 } else {
@@ -617,6 +640,112 @@ mod tests {
         assert_eq!(component.state(), State::None);
     }
 
+    #[test]
+    fn test_components_list_render_cache() {
+        use crate::testing::{assert_lines, render};
+        use tuirealm::props::BorderSides;
+
+        let mut component = List::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .rows(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("omar"))
+                    .build(),
+            );
+        // The first line is taken by the title
+        assert_lines(&render(&mut component, 6, 2), &["", "omar"]);
+        assert!(!component.rows.is_dirty());
+        // Rows are rebuilt when props change
+        component.attr(
+            Attribute::Content,
+            AttrValue::Table(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("hello"))
+                    .build(),
+            ),
+        );
+        assert!(component.rows.is_dirty());
+        assert_lines(&render(&mut component, 6, 2), &["", "hello"]);
+    }
+
+    #[test]
+    fn test_components_list_shared_rows() {
+        let rows = Arc::new(
+            TableBuilder::default()
+                .add_col(TextSpan::from("omar"))
+                .add_row()
+                .add_col(TextSpan::from("hello"))
+                .build(),
+        );
+        let mut component = List::default()
+            .scroll(true)
+            .shared_rows(Arc::clone(&rows))
+            .selected_line(1);
+        assert_eq!(component.states.list_len, 2);
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+        assert_eq!(Arc::strong_count(&rows), 2);
+        // Content replaces shared rows
+        component.attr(
+            Attribute::Content,
+            AttrValue::Table(TableBuilder::default().build()),
+        );
+        assert_eq!(component.states.list_len, 1);
+        assert_eq!(Arc::strong_count(&rows), 1);
+    }
+
+    #[test]
+    fn test_components_list_rows_update() {
+        use crate::utils::{patch_row, remove_row};
+
+        let rows = Arc::new(
+            TableBuilder::default()
+                .add_col(TextSpan::from("a"))
+                .add_row()
+                .add_col(TextSpan::from("b"))
+                .add_row()
+                .add_col(TextSpan::from("c"))
+                .build(),
+        );
+        let mut component = List::default()
+            .scroll(true)
+            .shared_rows(Arc::clone(&rows))
+            .selected_line(2);
+        // Append
+        component.attr(
+            Attribute::Custom(ROWS_APPEND),
+            AttrValue::Table(TableBuilder::default().add_col(TextSpan::from("d")).build()),
+        );
+        assert_eq!(component.states.list_len, 4);
+        assert_eq!(component.state(), State::One(StateValue::Usize(2)));
+        // Shared rows are copied on update
+        assert_eq!(rows.len(), 3);
+        // Patch
+        component.attr(
+            Attribute::Custom(ROWS_PATCH),
+            patch_row(3, vec![TextSpan::from("e")]),
+        );
+        component.attr(
+            Attribute::Custom(ROWS_PATCH),
+            patch_row(10, vec![TextSpan::from("f")]),
+        );
+        // Remove a row before the selected one, which stays selected
+        component.attr(Attribute::Custom(ROWS_REMOVE), remove_row(0));
+        component.attr(Attribute::Custom(ROWS_REMOVE), remove_row(10));
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+        assert_eq!(
+            component.query(Attribute::Content),
+            Some(AttrValue::Table(
+                TableBuilder::default()
+                    .add_col(TextSpan::from("b"))
+                    .add_row()
+                    .add_col(TextSpan::from("c"))
+                    .add_row()
+                    .add_col(TextSpan::from("e"))
+                    .build()
+            ))
+        );
+    }
+
     #[test]
     fn should_init_list_value() {
         let mut component = List::default()
@@ -668,4 +797,64 @@ mod tests {
         );
         assert_eq!(component.states.list_index, 6);
     }
+
+    #[test]
+    fn test_components_list_mouse() {
+        use tuirealm::event::{KeyModifiers, MouseButton, MouseEventKind};
+        use tuirealm::ratatui::{backend::TestBackend, Terminal};
+
+        let click = |column, row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+            column,
+            row,
+        };
+        let mut rows = TableBuilder::default();
+        for i in 0..10 {
+            if i > 0 {
+                rows.add_row();
+            }
+            rows.add_col(TextSpan::from(format!("row {i}")));
+        }
+        let mut component = List::default()
+            .borders(Borders::default())
+            .scroll(true)
+            .rows(rows.build())
+            .selected_line(6);
+        let mut terminal = Terminal::new(TestBackend::new(12, 5)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 5)))
+            .unwrap();
+        // Three rows are displayed, from the fifth one
+        assert_eq!(component.states.area, Rect::new(1, 1, 10, 3));
+        assert_eq!(component.states.offset, 4);
+        assert_eq!(
+            component.on_mouse(click(3, 2)),
+            CmdResult::Changed(State::One(StateValue::Usize(5)))
+        );
+        assert_eq!(component.on_mouse(click(3, 2)), CmdResult::None);
+        // Borders
+        assert_eq!(component.on_mouse(click(0, 2)), CmdResult::None);
+        assert_eq!(component.states.list_index, 5);
+        // Wheel
+        let scroll = |kind| MouseEvent {
+            kind,
+            modifiers: KeyModifiers::NONE,
+            column: 3,
+            row: 2,
+        };
+        assert_eq!(
+            component.on_mouse(scroll(MouseEventKind::ScrollDown)),
+            CmdResult::Changed(State::One(StateValue::Usize(8)))
+        );
+        assert_eq!(
+            component.on_mouse(scroll(MouseEventKind::ScrollDown)),
+            CmdResult::Changed(State::One(StateValue::Usize(9)))
+        );
+        component.attr(Attribute::Custom(MOUSE_WHEEL_STEP), AttrValue::Length(1));
+        assert_eq!(
+            component.on_mouse(scroll(MouseEventKind::ScrollUp)),
+            CmdResult::Changed(State::One(StateValue::Usize(8)))
+        );
+    }
 }
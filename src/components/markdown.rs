@@ -0,0 +1,942 @@
+//! ## Markdown
+//!
+//! `Markdown` renders a markdown document, with scrolling and link navigation
+
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag};
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{MARKDOWN_CMD_NEXT_LINK, MARKDOWN_CMD_PREV_LINK};
+use super::props::{
+    MARKDOWN_CODE_STYLE, MARKDOWN_HEADING_STYLE, MARKDOWN_LINK_STYLE, MARKDOWN_QUOTE_STYLE, TOOLTIP,
+};
+
+// -- document
+
+/// A run of text sharing the same style
+#[derive(Debug, Clone, PartialEq)]
+struct MdSpan {
+    text: String,
+    style: Style,
+    /// Index of the link the text belongs to
+    link: Option<usize>,
+}
+
+impl MdSpan {
+    fn new<S: Into<String>>(text: S, style: Style) -> Self {
+        Self {
+            text: text.into(),
+            style,
+            link: None,
+        }
+    }
+}
+
+/// A logical line of the document, wrapped to the area width when rendered
+#[derive(Debug, Clone, Default, PartialEq)]
+struct MdLine {
+    /// Prefix of the first row, such as the list bullet
+    first_prefix: String,
+    /// Prefix of the following rows
+    prefix: String,
+    prefix_style: Style,
+    spans: Vec<MdSpan>,
+    /// Code lines are not wrapped
+    wrap: bool,
+    /// Horizontal rule
+    rule: bool,
+}
+
+impl MdLine {
+    fn is_blank(&self) -> bool {
+        self.spans.is_empty() && !self.rule
+    }
+
+    /// ### rows
+    ///
+    /// Wrap the line in rows of `width` columns, prefixes included
+    fn rows(&self, width: usize) -> Vec<Vec<MdSpan>> {
+        let mut rows: Vec<Vec<MdSpan>> = Vec::new();
+        let available = |rows: &Vec<Vec<MdSpan>>| {
+            let prefix = match rows.is_empty() {
+                true => &self.first_prefix,
+                false => &self.prefix,
+            };
+            width.saturating_sub(prefix.width()).max(1)
+        };
+        if self.rule {
+            let width = available(&rows);
+            rows.push(vec![MdSpan::new("─".repeat(width), self.prefix_style)]);
+        } else if !self.wrap {
+            rows.push(self.spans.clone());
+        } else {
+            let mut row: Vec<MdSpan> = Vec::new();
+            let mut row_width = 0;
+            for span in self.spans.iter() {
+                for word in span.text.split_inclusive(' ') {
+                    let mut word = word;
+                    while !word.is_empty() {
+                        // Rows don't start with spaces
+                        if row_width == 0 {
+                            word = word.trim_start();
+                            if word.is_empty() {
+                                break;
+                            }
+                        }
+                        let limit = available(&rows);
+                        if row_width + word.trim_end().width() <= limit {
+                            push_text(&mut row, word, span);
+                            row_width += word.width();
+                            word = "";
+                        } else if row_width > 0 {
+                            rows.push(std::mem::take(&mut row));
+                            row_width = 0;
+                        } else {
+                            // The word is longer than the row
+                            let (head, tail) = split_at_width(word, limit);
+                            push_text(&mut row, head, span);
+                            rows.push(std::mem::take(&mut row));
+                            word = tail;
+                        }
+                    }
+                }
+            }
+            if !row.is_empty() || rows.is_empty() {
+                rows.push(row);
+            }
+        }
+        for (i, row) in rows.iter_mut().enumerate() {
+            let prefix = match i {
+                0 => &self.first_prefix,
+                _ => &self.prefix,
+            };
+            if !prefix.is_empty() {
+                row.insert(0, MdSpan::new(prefix.as_str(), self.prefix_style));
+            }
+        }
+        rows
+    }
+}
+
+/// ### push_text
+///
+/// Push `text` with the style of `span` to `row`, merging it with the last span if they look the same
+fn push_text(row: &mut Vec<MdSpan>, text: &str, span: &MdSpan) {
+    match row.last_mut() {
+        Some(last) if last.style == span.style && last.link == span.link => {
+            last.text.push_str(text)
+        }
+        _ => row.push(MdSpan {
+            text: text.to_string(),
+            style: span.style,
+            link: span.link,
+        }),
+    }
+}
+
+/// ### split_at_width
+///
+/// Split `text` after `width` columns; the head contains at least a character
+fn split_at_width(text: &str, width: usize) -> (&str, &str) {
+    let mut columns = 0;
+    for (i, ch) in text.char_indices() {
+        columns += ch.width().unwrap_or(0);
+        if columns > width && i > 0 {
+            return text.split_at(i);
+        }
+    }
+    (text, "")
+}
+
+/// Styles of markdown elements
+struct MdStyles {
+    code: Style,
+    heading: Style,
+    link: Style,
+    quote: Style,
+}
+
+#[derive(Debug, Default)]
+struct Document {
+    lines: Vec<MdLine>,
+    /// Links destination, in the order they appear
+    links: Vec<String>,
+}
+
+enum Container {
+    Quote,
+    List(Option<u64>),
+    Item { marker: String, used: bool },
+}
+
+/// What has to be closed when the matching `End` event comes
+enum Open {
+    Paragraph,
+    Heading,
+    Quote,
+    Code,
+    List,
+    Item,
+    Link,
+    Style,
+    Other,
+}
+
+/// ## DocumentBuilder
+///
+/// Builds the document lines out of markdown events
+struct DocumentBuilder<'a> {
+    styles: &'a MdStyles,
+    document: Document,
+    spans: Vec<MdSpan>,
+    style_stack: Vec<Style>,
+    containers: Vec<Container>,
+    open: Vec<Open>,
+    link: Option<usize>,
+    code: bool,
+}
+
+impl<'a> DocumentBuilder<'a> {
+    fn new(styles: &'a MdStyles) -> Self {
+        Self {
+            styles,
+            document: Document::default(),
+            spans: Vec::new(),
+            style_stack: Vec::new(),
+            containers: Vec::new(),
+            open: Vec::new(),
+            link: None,
+            code: false,
+        }
+    }
+
+    fn build(mut self, text: &str) -> Document {
+        let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+        for event in Parser::new_ext(text, options) {
+            match event {
+                Event::Start(tag) => self.start(tag),
+                Event::End(_) => self.end(),
+                Event::Text(text) if self.code => {
+                    for line in text.lines() {
+                        self.push(line, self.styles.code);
+                        self.flush_line(false);
+                    }
+                }
+                Event::Text(text) => self.push(&text, self.style()),
+                Event::Code(text) => self.push(&text, self.style().patch(self.styles.code)),
+                Event::SoftBreak => self.push(" ", self.style()),
+                Event::HardBreak => self.flush_line(true),
+                Event::Rule => {
+                    self.flush();
+                    let (_, prefix) = self.prefixes(false);
+                    self.document.lines.push(MdLine {
+                        first_prefix: prefix.clone(),
+                        prefix,
+                        prefix_style: self.prefix_style(),
+                        rule: true,
+                        ..MdLine::default()
+                    });
+                    self.blank();
+                }
+                Event::TaskListMarker(checked) => {
+                    let marker = match checked {
+                        true => "[x] ",
+                        false => "[ ] ",
+                    };
+                    self.push(marker, self.style());
+                }
+                _ => {}
+            }
+        }
+        self.flush();
+        while self.document.lines.last().is_some_and(|x| x.is_blank()) {
+            self.document.lines.pop();
+        }
+        self.document
+    }
+
+    fn start(&mut self, tag: Tag) {
+        let open = match tag {
+            Tag::Paragraph => Open::Paragraph,
+            Tag::Heading { level, .. } => {
+                self.flush();
+                let style = match level {
+                    HeadingLevel::H1 => self.styles.heading.add_modifier(TextModifiers::UNDERLINED),
+                    _ => self.styles.heading,
+                };
+                self.push_style(style);
+                Open::Heading
+            }
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.containers.push(Container::Quote);
+                self.push_style(self.styles.quote);
+                Open::Quote
+            }
+            Tag::CodeBlock(_) => {
+                self.flush();
+                self.code = true;
+                Open::Code
+            }
+            Tag::List(start) => {
+                self.flush();
+                self.containers.push(Container::List(start));
+                Open::List
+            }
+            Tag::Item => {
+                self.flush();
+                let marker = match self.containers.last_mut() {
+                    Some(Container::List(Some(n))) => {
+                        *n += 1;
+                        format!("{}. ", *n - 1)
+                    }
+                    _ => String::from("• "),
+                };
+                self.containers.push(Container::Item {
+                    marker,
+                    used: false,
+                });
+                Open::Item
+            }
+            Tag::Emphasis => self.open_style(Style::default().add_modifier(TextModifiers::ITALIC)),
+            Tag::Strong => self.open_style(Style::default().add_modifier(TextModifiers::BOLD)),
+            Tag::Strikethrough => {
+                self.open_style(Style::default().add_modifier(TextModifiers::CROSSED_OUT))
+            }
+            Tag::Link { dest_url, .. } => {
+                self.document.links.push(dest_url.to_string());
+                self.link = Some(self.document.links.len() - 1);
+                self.push_style(self.styles.link);
+                Open::Link
+            }
+            _ => Open::Other,
+        };
+        self.open.push(open);
+    }
+
+    fn end(&mut self) {
+        match self.open.pop() {
+            Some(Open::Paragraph) => {
+                self.flush();
+                self.blank();
+            }
+            Some(Open::Heading) => {
+                self.style_stack.pop();
+                self.flush();
+                self.blank();
+            }
+            Some(Open::Quote) => {
+                self.flush();
+                self.style_stack.pop();
+                self.containers.pop();
+                // The blank line after the last paragraph is outside of the quote
+                let (_, prefix) = self.prefixes(false);
+                match self.document.lines.last_mut() {
+                    Some(line) if line.is_blank() => {
+                        line.first_prefix = prefix.clone();
+                        line.prefix = prefix;
+                    }
+                    _ => self.blank(),
+                }
+            }
+            Some(Open::Code) => {
+                self.code = false;
+                self.blank();
+            }
+            Some(Open::List) => {
+                self.flush();
+                self.containers.pop();
+                // Nested lists don't need a blank line
+                if !self
+                    .containers
+                    .iter()
+                    .any(|x| matches!(x, Container::List(_)))
+                {
+                    self.blank();
+                }
+            }
+            Some(Open::Item) => {
+                self.flush();
+                self.containers.pop();
+            }
+            Some(Open::Link) => {
+                self.style_stack.pop();
+                self.link = None;
+            }
+            Some(Open::Style) => {
+                self.style_stack.pop();
+            }
+            Some(Open::Other) | None => {}
+        }
+    }
+
+    fn style(&self) -> Style {
+        self.style_stack
+            .iter()
+            .fold(Style::default(), |acc, x| acc.patch(*x))
+    }
+
+    fn push_style(&mut self, style: Style) {
+        self.style_stack.push(style);
+    }
+
+    fn open_style(&mut self, style: Style) -> Open {
+        self.push_style(style);
+        Open::Style
+    }
+
+    fn push(&mut self, text: &str, style: Style) {
+        self.spans.push(MdSpan {
+            text: text.to_string(),
+            style,
+            link: self.link,
+        });
+    }
+
+    fn prefix_style(&self) -> Style {
+        match self
+            .containers
+            .iter()
+            .any(|x| matches!(x, Container::Quote))
+        {
+            true => self.styles.quote,
+            false => Style::default(),
+        }
+    }
+
+    /// ### prefixes
+    ///
+    /// Returns the prefixes of the first and of the following rows of a line.
+    /// If `first` is true, the bullet of the current list item is used
+    fn prefixes(&mut self, first: bool) -> (String, String) {
+        let (mut first_prefix, mut prefix) = (String::new(), String::new());
+        for container in self.containers.iter_mut() {
+            match container {
+                Container::Quote => {
+                    first_prefix.push_str("│ ");
+                    prefix.push_str("│ ");
+                }
+                Container::List(_) => {}
+                Container::Item { marker, used } => {
+                    let indent = " ".repeat(marker.width());
+                    match first && !*used {
+                        true => first_prefix.push_str(marker),
+                        false => first_prefix.push_str(&indent),
+                    }
+                    *used |= first;
+                    prefix.push_str(&indent);
+                }
+            }
+        }
+        (first_prefix, prefix)
+    }
+
+    /// ### flush
+    ///
+    /// Make a line out of the pending spans, if any
+    fn flush(&mut self) {
+        if !self.spans.is_empty() {
+            self.flush_line(true);
+        }
+    }
+
+    fn flush_line(&mut self, wrap: bool) {
+        let (first_prefix, prefix) = self.prefixes(true);
+        let line = MdLine {
+            first_prefix,
+            prefix,
+            prefix_style: self.prefix_style(),
+            spans: std::mem::take(&mut self.spans),
+            wrap,
+            rule: false,
+        };
+        self.document.lines.push(line);
+    }
+
+    /// ### blank
+    ///
+    /// Push a blank line to separate blocks, unless there's already one
+    fn blank(&mut self) {
+        if self.document.lines.last().is_some_and(|x| !x.is_blank()) {
+            let (_, prefix) = self.prefixes(false);
+            self.document.lines.push(MdLine {
+                first_prefix: prefix.clone(),
+                prefix,
+                prefix_style: self.prefix_style(),
+                ..MdLine::default()
+            });
+        }
+    }
+}
+
+// -- states
+
+/// ## MarkdownStates
+///
+/// MarkdownStates contains states for this component
+#[derive(Default)]
+pub struct MarkdownStates {
+    /// First visible row
+    pub scroll: usize,
+    /// Amount of rows of the document, as of the last render
+    pub rows: usize,
+    /// Height of the viewport, as of the last render
+    pub height: usize,
+    /// Selected link
+    pub link: Option<usize>,
+    pub links: usize,
+    /// Whether the selected link has to be scrolled into view on the next render
+    reveal_link: bool,
+}
+
+impl MarkdownStates {
+    /// ### max_scroll
+    ///
+    /// Returns the scroll which shows the last row at the bottom of the viewport
+    pub fn max_scroll(&self) -> usize {
+        self.rows.saturating_sub(self.height)
+    }
+
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.scroll = (self.scroll + rows).min(self.max_scroll());
+    }
+
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.scroll = self.scroll.saturating_sub(rows);
+    }
+
+    /// ### next_link
+    ///
+    /// Select the next link, wrapping around
+    pub fn next_link(&mut self) {
+        if self.links > 0 {
+            self.link = Some(self.link.map(|x| (x + 1) % self.links).unwrap_or(0));
+            self.reveal_link = true;
+        }
+    }
+
+    /// ### prev_link
+    ///
+    /// Select the previous link, wrapping around
+    pub fn prev_link(&mut self) {
+        if self.links > 0 {
+            self.link = Some(match self.link {
+                Some(0) | None => self.links - 1,
+                Some(x) => x - 1,
+            });
+            self.reveal_link = true;
+        }
+    }
+
+    /// ### set_links
+    ///
+    /// Set the amount of links of a new document, resetting the selection
+    pub fn set_links(&mut self, links: usize) {
+        self.links = links;
+        self.link = None;
+        self.reveal_link = false;
+    }
+}
+
+// -- component
+
+/// ## Markdown
+///
+/// A scrollable markdown document: headings, emphasis, lists, code blocks, block quotes and rules are rendered with
+/// styled spans, and links can be selected and submitted
+#[derive(Default)]
+pub struct Markdown {
+    props: Props,
+    pub states: MarkdownStates,
+    document: Document,
+}
+
+impl Markdown {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `markdown` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("markdown", self);
+    }
+
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    /// Set the markdown source
+    pub fn text<S: Into<String>>(mut self, s: S) -> Self {
+        self.attr(Attribute::Text, AttrValue::String(s.into()));
+        self
+    }
+
+    /// Set the style of inline code and code blocks (default: yellow)
+    pub fn code_style(mut self, s: Style) -> Self {
+        self.attr(Attribute::Custom(MARKDOWN_CODE_STYLE), AttrValue::Style(s));
+        self
+    }
+
+    /// Set the style of headings (default: bold); level 1 headings are also underlined
+    pub fn heading_style(mut self, s: Style) -> Self {
+        self.attr(
+            Attribute::Custom(MARKDOWN_HEADING_STYLE),
+            AttrValue::Style(s),
+        );
+        self
+    }
+
+    /// Set the style of links (default: cyan, underlined)
+    pub fn link_style(mut self, s: Style) -> Self {
+        self.attr(Attribute::Custom(MARKDOWN_LINK_STYLE), AttrValue::Style(s));
+        self
+    }
+
+    /// Set the style of block quotes (default: italic)
+    pub fn quote_style(mut self, s: Style) -> Self {
+        self.attr(Attribute::Custom(MARKDOWN_QUOTE_STYLE), AttrValue::Style(s));
+        self
+    }
+
+    fn get_style(&self, attr: &'static str, default: Style) -> Style {
+        self.props
+            .get_or(Attribute::Custom(attr), AttrValue::Style(default))
+            .unwrap_style()
+    }
+
+    /// ### parse
+    ///
+    /// Parse the markdown source into the document
+    fn parse(&mut self) {
+        let styles = MdStyles {
+            code: self.get_style(MARKDOWN_CODE_STYLE, Style::default().fg(Color::Yellow)),
+            heading: self.get_style(
+                MARKDOWN_HEADING_STYLE,
+                Style::default().add_modifier(TextModifiers::BOLD),
+            ),
+            link: self.get_style(
+                MARKDOWN_LINK_STYLE,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(TextModifiers::UNDERLINED),
+            ),
+            quote: self.get_style(
+                MARKDOWN_QUOTE_STYLE,
+                Style::default().add_modifier(TextModifiers::ITALIC),
+            ),
+        };
+        let text = self
+            .props
+            .get(Attribute::Text)
+            .map(|x| x.unwrap_string())
+            .unwrap_or_default();
+        self.document = DocumentBuilder::new(&styles).build(&text);
+        self.states.set_links(self.document.links.len());
+    }
+
+    /// ### rows
+    ///
+    /// Returns the document rows, wrapped to `width`
+    fn rows(&self, width: usize) -> Vec<Vec<MdSpan>> {
+        self.document
+            .lines
+            .iter()
+            .flat_map(|x| x.rows(width))
+            .collect()
+    }
+
+    /// ### render_document
+    ///
+    /// Render the visible rows in `area`, updating the scroll states
+    fn render_document(&mut self, buf: &mut Buffer, area: Rect, style: Style) {
+        let rows = self.rows(area.width as usize);
+        self.states.rows = rows.len();
+        self.states.height = area.height as usize;
+        if std::mem::take(&mut self.states.reveal_link) {
+            let link = self.states.link;
+            if let Some(row) = rows
+                .iter()
+                .position(|x| x.iter().any(|x| x.link.is_some() && x.link == link))
+            {
+                if row < self.states.scroll || row >= self.states.scroll + self.states.height {
+                    self.states.scroll = row;
+                }
+            }
+        }
+        self.states.scroll = self.states.scroll.min(self.states.max_scroll());
+        for (y, row) in (area.y..area.bottom()).zip(rows.iter().skip(self.states.scroll)) {
+            let mut x = area.x;
+            for span in row.iter() {
+                let mut span_style = style.patch(span.style);
+                if span.link.is_some() && span.link == self.states.link {
+                    span_style = span_style.add_modifier(TextModifiers::REVERSED);
+                }
+                let width = area.right().saturating_sub(x) as usize;
+                (x, _) = buf.set_stringn(x, y, &span.text, width, span_style);
+            }
+        }
+    }
+}
+
+impl MockComponent for Markdown {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_document(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if matches!(
+            attr,
+            Attribute::Text
+                | Attribute::Custom(MARKDOWN_CODE_STYLE)
+                | Attribute::Custom(MARKDOWN_HEADING_STYLE)
+                | Attribute::Custom(MARKDOWN_LINK_STYLE)
+                | Attribute::Custom(MARKDOWN_QUOTE_STYLE)
+        ) {
+            self.parse();
+            if attr == Attribute::Text {
+                self.states.scroll = 0;
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.link.and_then(|x| self.document.links.get(x)) {
+            Some(url) => State::One(StateValue::String(url.clone())),
+            None => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length();
+        match cmd {
+            Cmd::Move(Direction::Down) => self.states.scroll_down(1),
+            Cmd::Move(Direction::Up) => self.states.scroll_up(1),
+            Cmd::Scroll(Direction::Down) => self.states.scroll_down(step),
+            Cmd::Scroll(Direction::Up) => self.states.scroll_up(step),
+            Cmd::GoTo(Position::Begin) => self.states.scroll = 0,
+            Cmd::GoTo(Position::End) => self.states.scroll = self.states.max_scroll(),
+            Cmd::Custom(MARKDOWN_CMD_NEXT_LINK) => {
+                self.states.next_link();
+                return CmdResult::Changed(self.state());
+            }
+            Cmd::Custom(MARKDOWN_CMD_PREV_LINK) => {
+                self.states.prev_link();
+                return CmdResult::Changed(self.state());
+            }
+            Cmd::Submit => {
+                return match self.state() {
+                    State::None => CmdResult::None,
+                    state => CmdResult::Submit(state),
+                }
+            }
+            _ => {}
+        }
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn text(rows: &[Vec<MdSpan>]) -> Vec<String> {
+        rows.iter()
+            .map(|x| x.iter().map(|x| x.text.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_components_markdown_wrap() {
+        let bold = Style::default().add_modifier(TextModifiers::BOLD);
+        let line = MdLine {
+            first_prefix: String::from("• "),
+            prefix: String::from("  "),
+            spans: vec![
+                MdSpan::new("the quick ", Style::default()),
+                MdSpan::new("brown", bold),
+                MdSpan::new(" fox jumps over", Style::default()),
+            ],
+            wrap: true,
+            ..MdLine::default()
+        };
+        let rows = line.rows(12);
+        assert_eq!(
+            text(&rows),
+            vec!["• the quick ", "  brown fox ", "  jumps over"]
+        );
+        assert_eq!(rows[1][1], MdSpan::new("brown", bold));
+        // Long words are split
+        let line = MdLine {
+            spans: vec![MdSpan::new("abcdefghij", Style::default())],
+            wrap: true,
+            ..MdLine::default()
+        };
+        assert_eq!(text(&line.rows(4)), vec!["abcd", "efgh", "ij"]);
+        // Code is not wrapped
+        let line = MdLine {
+            spans: vec![MdSpan::new("let x = 1;", Style::default())],
+            ..MdLine::default()
+        };
+        assert_eq!(text(&line.rows(4)), vec!["let x = 1;"]);
+    }
+
+    #[test]
+    fn test_components_markdown() {
+        let mut component = Markdown::default()
+            .borders(Borders::default())
+            .title("README.md", Alignment::Left)
+            .step(4)
+            .text(
+                "# Title\n\nSome *emphasis* and a [link](https://example.com).\n\n- one\n- two\n\n> quote\n\n```\nfn main() {}\n```\n\nSee [docs](https://docs.rs).",
+            );
+        assert_eq!(
+            text(&component.rows(80)),
+            vec![
+                "Title",
+                "",
+                "Some emphasis and a link.",
+                "",
+                "• one",
+                "• two",
+                "",
+                "│ quote",
+                "",
+                "fn main() {}",
+                "",
+                "See docs.",
+            ]
+        );
+        // Scroll
+        let area = Rect::new(0, 0, 80, 4);
+        let mut buf = Buffer::empty(area);
+        component.render_document(&mut buf, area, Style::default());
+        assert_eq!(component.states.max_scroll(), 8);
+        component.perform(Cmd::Scroll(Direction::Down));
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.states.scroll, 5);
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.scroll, 8);
+        component.perform(Cmd::Scroll(Direction::Up));
+        assert_eq!(component.states.scroll, 4);
+        // Links
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Custom(MARKDOWN_CMD_PREV_LINK)),
+            CmdResult::Changed(State::One(StateValue::String(String::from(
+                "https://docs.rs"
+            ))))
+        );
+        component.perform(Cmd::Custom(MARKDOWN_CMD_NEXT_LINK));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::String(String::from(
+                "https://example.com"
+            ))))
+        );
+        // The selected link is scrolled into view and highlighted
+        let mut buf = Buffer::empty(area);
+        component.render_document(&mut buf, area, Style::default());
+        assert_eq!(component.states.scroll, 2);
+        assert_eq!(buf[(0, 0)].symbol(), "S");
+        assert!(buf[(20, 0)].modifier.contains(TextModifiers::REVERSED));
+        assert!(!buf[(0, 0)].modifier.contains(TextModifiers::REVERSED));
+    }
+}
@@ -0,0 +1,607 @@
+//! ## MenuBar
+//!
+//! `MenuBar` is a horizontal bar of menus, each one opening a dropdown of actions
+
+use std::collections::LinkedList;
+
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::props::{
+    AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Clear, Widget},
+};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{
+    get_block, get_title_style, is_disabled, popup_rect, render_disabled, render_tooltip,
+};
+
+// -- Props
+
+use super::props::TOOLTIP;
+
+/// Space between the label and the accelerator hint of items
+const HINT_SPACING: u16 = 3;
+
+// -- menu item
+
+/// ## MenuItem
+///
+/// An item of a dropdown menu: either an action, identified by its id, or a separator
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MenuItem {
+    pub id: String,
+    pub label: String,
+    /// Accelerator key hint, displayed on the right of the label
+    pub hint: Option<String>,
+    pub disabled: bool,
+    pub separator: bool,
+}
+
+impl MenuItem {
+    /// ### new
+    ///
+    /// Make a new action item
+    pub fn new<S: Into<String>, L: Into<String>>(id: S, label: L) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            ..Self::default()
+        }
+    }
+
+    /// ### separator
+    ///
+    /// Make a separator line
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn hint<S: Into<String>>(mut self, hint: S) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// ### is_selectable
+    ///
+    /// Returns whether the item can be selected: separators and disabled items can't
+    pub fn is_selectable(&self) -> bool {
+        !self.separator && !self.disabled
+    }
+
+    fn to_payload(&self) -> PropPayload {
+        match self.separator {
+            true => PropPayload::None,
+            false => PropPayload::Tup4((
+                PropValue::Str(self.id.clone()),
+                PropValue::Str(self.label.clone()),
+                PropValue::Str(self.hint.clone().unwrap_or_default()),
+                PropValue::Bool(self.disabled),
+            )),
+        }
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        match payload {
+            PropPayload::Tup4((id, label, hint, disabled)) => {
+                let hint = hint.unwrap_str();
+                Self {
+                    id: id.unwrap_str(),
+                    label: label.unwrap_str(),
+                    hint: (!hint.is_empty()).then_some(hint),
+                    disabled: disabled.unwrap_bool(),
+                    separator: false,
+                }
+            }
+            _ => Self::separator(),
+        }
+    }
+}
+
+// -- states
+
+/// ## MenuBarStates
+///
+/// MenuBarStates contains the menus, the active menu and the cursor in its dropdown, which is set only while it is open
+#[derive(Default)]
+pub struct MenuBarStates {
+    pub menus: Vec<(String, Vec<MenuItem>)>,
+    pub active: usize,
+    pub cursor: Option<usize>,
+}
+
+impl MenuBarStates {
+    /// ### set_menus
+    ///
+    /// Set menus, closing the dropdown
+    pub fn set_menus(&mut self, menus: Vec<(String, Vec<MenuItem>)>) {
+        self.menus = menus;
+        self.cursor = None;
+        if self.active >= self.menus.len() {
+            self.active = 0;
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// ### open
+    ///
+    /// Open the dropdown of the active menu, with the cursor on the first selectable item
+    pub fn open(&mut self) {
+        self.cursor = Some(0);
+        if !self.items().first().is_some_and(|x| x.is_selectable()) {
+            self.move_cursor(true);
+        }
+    }
+
+    /// ### close
+    ///
+    /// Close the dropdown
+    pub fn close(&mut self) {
+        self.cursor = None;
+    }
+
+    /// ### items
+    ///
+    /// Get the items of the active menu
+    pub fn items(&self) -> &[MenuItem] {
+        self.menus
+            .get(self.active)
+            .map(|(_, items)| items.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// ### selected
+    ///
+    /// Get the item under the cursor, if the dropdown is open
+    pub fn selected(&self) -> Option<&MenuItem> {
+        self.cursor.and_then(|x| self.items().get(x))
+    }
+
+    /// ### move_active
+    ///
+    /// Move to the next or previous menu, wrapping around. If the dropdown is open, the new menu is opened
+    pub fn move_active(&mut self, forward: bool) {
+        if self.menus.is_empty() {
+            return;
+        }
+        self.active = match forward {
+            true => (self.active + 1) % self.menus.len(),
+            false => (self.active + self.menus.len() - 1) % self.menus.len(),
+        };
+        if self.is_open() {
+            self.open();
+        }
+    }
+
+    /// ### move_cursor
+    ///
+    /// Move the cursor to the next or previous selectable item, wrapping around.
+    /// The cursor doesn't move if there are no selectable items
+    pub fn move_cursor(&mut self, forward: bool) {
+        let Some(cursor) = self.cursor else {
+            return;
+        };
+        let len = self.items().len();
+        for offset in 1..=len {
+            let index = match forward {
+                true => (cursor + offset) % len,
+                false => (cursor + len - offset % len) % len,
+            };
+            if self.items()[index].is_selectable() {
+                self.cursor = Some(index);
+                return;
+            }
+        }
+    }
+}
+
+// -- component
+
+/// ## MenuBar
+///
+/// A horizontal bar of menus, each one opening a dropdown of actions, which can be disabled and display an accelerator
+/// hint. Submitting an action returns its id.
+///
+/// The bar is rendered on the first row of the area and dropdowns below it: render it with the whole view as area,
+/// after the other components.
+#[derive(Default)]
+pub struct MenuBar {
+    props: Props,
+    pub states: MenuBarStates,
+}
+
+impl MenuBar {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    /// Set the borders of dropdowns
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `menu_bar` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("menu_bar", self);
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Set the menus, as their title and items
+    pub fn menus<S: AsRef<str>>(mut self, menus: &[(S, Vec<MenuItem>)]) -> Self {
+        let menus: LinkedList<PropPayload> = menus
+            .iter()
+            .map(|(title, items)| {
+                let mut menu: LinkedList<PropPayload> =
+                    items.iter().map(|x| x.to_payload()).collect();
+                menu.push_front(PropPayload::One(PropValue::Str(title.as_ref().to_string())));
+                PropPayload::Linked(menu)
+            })
+            .collect();
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(menus)),
+        );
+        self
+    }
+
+    /// ### render_menu_bar
+    ///
+    /// Render the bar on the first row of `area` and the dropdown of the active menu below it, if open
+    fn render_menu_bar(&self, buf: &mut Buffer, area: Rect, focus: bool) {
+        if area.height == 0 {
+            return;
+        }
+        let foreground = self
+            .props
+            .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+            .unwrap_color();
+        let background = self
+            .props
+            .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+            .unwrap_color();
+        let highlighted = self
+            .props
+            .get_or(Attribute::HighlightedColor, AttrValue::Color(foreground))
+            .unwrap_color();
+        let style = Style::default().fg(foreground).bg(background);
+        let highlighted_style = Style::default()
+            .fg(highlighted)
+            .add_modifier(TextModifiers::REVERSED);
+        // Bar
+        let bar = Rect { height: 1, ..area };
+        buf.set_style(bar, style);
+        let mut x = area.x;
+        let mut dropdown_x = area.x;
+        for (i, (title, _)) in self.states.menus.iter().enumerate() {
+            if i == self.states.active {
+                dropdown_x = x;
+            }
+            let title_style = match focus && i == self.states.active {
+                true => highlighted_style,
+                false => style,
+            };
+            x = buf
+                .set_stringn(
+                    x,
+                    area.y,
+                    format!(" {title} "),
+                    area.right().saturating_sub(x) as usize,
+                    title_style,
+                )
+                .0;
+        }
+        // Dropdown
+        if !self.states.is_open() {
+            return;
+        }
+        let items = self.states.items();
+        let labels_width = items.iter().map(|x| x.label.width()).max().unwrap_or(0) as u16;
+        let hints_width = items
+            .iter()
+            .filter_map(|x| x.hint.as_ref().map(|x| x.width() as u16 + HINT_SPACING))
+            .max()
+            .unwrap_or(0);
+        let borders = self
+            .props
+            .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+            .unwrap_borders();
+        let block = get_block(borders, None, true, None)
+            .title_style(get_title_style(&self.props))
+            .style(style);
+        let dropdown = popup_rect(
+            Rect::new(dropdown_x, area.y, 0, 1),
+            labels_width + hints_width + 4,
+            items.len() as u16 + 2,
+            area,
+        );
+        Clear.render(dropdown, buf);
+        let inner = block.inner(dropdown);
+        block.render(dropdown, buf);
+        for (i, item) in items.iter().enumerate() {
+            let y = inner.y + i as u16;
+            if y >= inner.bottom() {
+                break;
+            }
+            let row = Rect::new(inner.x, y, inner.width, 1);
+            if item.separator {
+                buf.set_string(
+                    row.x,
+                    y,
+                    "─".repeat(row.width as usize),
+                    style.add_modifier(TextModifiers::DIM),
+                );
+                continue;
+            }
+            let item_style = if Some(i) == self.states.cursor {
+                highlighted_style
+            } else if item.disabled {
+                style.add_modifier(TextModifiers::DIM)
+            } else {
+                style
+            };
+            buf.set_style(row, item_style);
+            buf.set_stringn(
+                row.x + 1,
+                y,
+                &item.label,
+                row.width.saturating_sub(1) as usize,
+                item_style,
+            );
+            if let Some(hint) = &item.hint {
+                let hint_x = row.right().saturating_sub(hint.width() as u16 + 1);
+                buf.set_stringn(
+                    hint_x,
+                    y,
+                    hint,
+                    row.right().saturating_sub(hint_x) as usize,
+                    item_style,
+                );
+            }
+        }
+    }
+}
+
+impl MockComponent for MenuBar {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            self.render_menu_bar(render.buffer_mut(), area, focus);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Content => {
+                let menus = match value.unwrap_payload() {
+                    PropPayload::Linked(menus) => menus
+                        .into_iter()
+                        .filter_map(|menu| match menu {
+                            PropPayload::Linked(mut menu) => {
+                                let title = menu.pop_front()?.unwrap_one().unwrap_str();
+                                let items = menu.into_iter().map(MenuItem::from_payload).collect();
+                                Some((title, items))
+                            }
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                self.states.set_menus(menus);
+            }
+            attr => {
+                self.props.set(attr, value);
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.selected() {
+            Some(item) => State::One(StateValue::String(item.id.clone())),
+            None => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Move(Direction::Left) => {
+                self.states.move_active(false);
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Move(Direction::Right) => {
+                self.states.move_active(true);
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Move(Direction::Down) if !self.states.is_open() => {
+                self.states.open();
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Move(Direction::Down) => {
+                self.states.move_cursor(true);
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Move(Direction::Up) => {
+                self.states.move_cursor(false);
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Submit if !self.states.is_open() => {
+                self.states.open();
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Submit => match self.states.selected().filter(|x| x.is_selectable()) {
+                Some(item) => {
+                    let id = item.id.clone();
+                    self.states.close();
+                    CmdResult::Submit(State::One(StateValue::String(id)))
+                }
+                None => CmdResult::None,
+            },
+            Cmd::Cancel if self.states.is_open() => {
+                self.states.close();
+                CmdResult::Changed(self.state())
+            }
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn menu_bar() -> MenuBar {
+        MenuBar::default().menus(&[
+            (
+                "File",
+                vec![
+                    MenuItem::new("new", "New").hint("Ctrl+N"),
+                    MenuItem::new("save", "Save").hint("Ctrl+S").disabled(true),
+                    MenuItem::separator(),
+                    MenuItem::new("quit", "Quit").hint("Ctrl+Q"),
+                ],
+            ),
+            (
+                "Edit",
+                vec![
+                    MenuItem::new("undo", "Undo").disabled(true),
+                    MenuItem::new("copy", "Copy"),
+                ],
+            ),
+        ])
+    }
+
+    fn id(s: &str) -> State {
+        State::One(StateValue::String(String::from(s)))
+    }
+
+    #[test]
+    fn test_components_menu_bar() {
+        let mut component = menu_bar();
+        assert_eq!(component.states.menus.len(), 2);
+        assert_eq!(component.state(), State::None);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::None)
+        );
+        // Open
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Changed(id("new"))
+        );
+        // Disabled items and separators are skipped
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(id("quit"))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(id("new"))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(id("quit"))
+        );
+        // Switch menu, keeping the dropdown open
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(id("copy"))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(id("copy"))
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(id("copy"))
+        );
+        assert!(!component.states.is_open());
+        // Cancel
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(
+            component.perform(Cmd::Cancel),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(component.perform(Cmd::Cancel), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_menu_bar_render() {
+        let mut component = menu_bar().borders(Borders::default());
+        component.perform(Cmd::Submit);
+        component.perform(Cmd::Move(Direction::Down));
+        let area = Rect::new(0, 0, 20, 8);
+        let mut buf = Buffer::empty(area);
+        component.render_menu_bar(&mut buf, area, true);
+        let row =
+            |y: u16| -> String { (0..20).map(|x| buf[(x, y)].symbol().to_string()).collect() };
+        assert_eq!(row(0), " File  Edit         ");
+        assert_eq!(row(1), "┌───────────────┐   ");
+        assert_eq!(row(2), "│ New    Ctrl+N │   ");
+        assert_eq!(row(3), "│ Save   Ctrl+S │   ");
+        assert_eq!(row(4), "│───────────────│   ");
+        assert_eq!(row(5), "│ Quit   Ctrl+Q │   ");
+        assert_eq!(row(6), "└───────────────┘   ");
+        assert!(buf[(1, 0)].modifier.contains(TextModifiers::REVERSED));
+        assert!(buf[(2, 3)].modifier.contains(TextModifiers::DIM));
+        assert!(buf[(2, 5)].modifier.contains(TextModifiers::REVERSED));
+        assert!(!buf[(2, 2)].modifier.contains(TextModifiers::REVERSED));
+    }
+}
@@ -0,0 +1,506 @@
+//! ## Meter
+//!
+//! `Meter` displays level bars with peak-hold markers, for audio levels, CPU usage per core and similar live values
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, is_disabled, render_disabled, render_tooltip, FocusStyles};
+
+// -- Props
+
+use super::props::{
+    METER_BAR_WIDTH, METER_HORIZONTAL, METER_LABELS, METER_PEAK_HOLD, METER_RANGE, METER_ZONES,
+    TOOLTIP,
+};
+
+/// Symbols filling a cell from the bottom, by eighths
+const VERTICAL_EIGHTHS: [&str; 9] = [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+/// Symbols filling a cell from the left, by eighths
+const HORIZONTAL_EIGHTHS: [&str; 9] = [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"];
+/// Symbols of the peak marker, at the end of the cell
+const VERTICAL_PEAK: &str = "▔";
+const HORIZONTAL_PEAK: &str = "▕";
+/// Bounds of the default zones, as ratio of the range
+const DEFAULT_ZONES: [(f64, Color); 3] = [
+    (0.6, Color::Green),
+    (0.85, Color::Yellow),
+    (1.0, Color::Red),
+];
+
+/// ### ratio
+///
+/// Get the position of `value` in `range`, from 0 to 1
+fn ratio(value: f64, range: (f64, f64)) -> f64 {
+    let (min, max) = range;
+    match max > min {
+        true => ((value - min) / (max - min)).clamp(0.0, 1.0),
+        false => 0.0,
+    }
+}
+
+// -- states
+
+/// ## MeterStates
+///
+/// MeterStates contains states for this component
+#[derive(Default)]
+pub struct MeterStates {
+    /// Current value of each bar
+    pub values: Vec<f64>,
+    /// Peak of each bar, and the number of ticks it has been held for
+    pub peaks: Vec<(f64, usize)>,
+}
+
+impl MeterStates {
+    /// ### update
+    ///
+    /// Set the values of the bars, raising the peaks they exceed
+    pub fn update(&mut self, values: Vec<f64>) {
+        self.peaks.resize(values.len(), (f64::MIN, 0));
+        for (peak, value) in self.peaks.iter_mut().zip(values.iter().copied()) {
+            if value >= peak.0 {
+                *peak = (value, 0);
+            }
+        }
+        self.values = values;
+    }
+
+    /// ### tick
+    ///
+    /// Age the peaks, dropping the ones held for more than `hold` ticks to their value. Returns whether a peak dropped
+    pub fn tick(&mut self, hold: usize) -> bool {
+        let mut dropped = false;
+        for (peak, value) in self.peaks.iter_mut().zip(self.values.iter().copied()) {
+            peak.1 += 1;
+            if peak.1 > hold && peak.0 > value {
+                *peak = (value, 0);
+                dropped = true;
+            }
+        }
+        dropped
+    }
+}
+
+// -- component
+
+/// ## Meter
+///
+/// One or more level bars, vertical or horizontal, colored by zone: the part of a bar in the first zone is drawn with
+/// its color, and so on. The peak of each bar is held for some ticks and marked, then drops to the current value;
+/// ticks are performed with `Cmd::Tick`, which should be performed on `Event::Tick`
+#[derive(Default)]
+pub struct Meter {
+    props: Props,
+    pub states: MeterStates,
+}
+
+impl Meter {
+    /// Set the color of the bars above the last zone
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `meter` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("meter", self);
+    }
+
+    /// Set the value of each bar
+    pub fn values(mut self, values: &[f64]) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::Vec(
+                values.iter().copied().map(PropValue::F64).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the label of each bar
+    pub fn labels<S: AsRef<str>>(mut self, labels: &[S]) -> Self {
+        self.attr(
+            Attribute::Custom(METER_LABELS),
+            AttrValue::Payload(PropPayload::Vec(
+                labels
+                    .iter()
+                    .map(|x| PropValue::Str(x.as_ref().to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the range of values (default: 0 to 100)
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.attr(
+            Attribute::Custom(METER_RANGE),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::F64(min),
+                PropValue::F64(max),
+            ))),
+        );
+        self
+    }
+
+    /// Set colored zones as upper bound and color, sorted by bound. Values above the last bound use the foreground
+    /// color. By default, the zones are green up to 60% of the range, yellow up to 85% and red above
+    pub fn zones(mut self, zones: &[(f64, Color)]) -> Self {
+        self.attr(
+            Attribute::Custom(METER_ZONES),
+            AttrValue::Payload(PropPayload::Linked(
+                zones
+                    .iter()
+                    .map(|(bound, color)| {
+                        PropPayload::Tup2((PropValue::F64(*bound), PropValue::Color(*color)))
+                    })
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Display the bars horizontally, one per row
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.attr(
+            Attribute::Custom(METER_HORIZONTAL),
+            AttrValue::Flag(horizontal),
+        );
+        self
+    }
+
+    /// Set the width of vertical bars (default: 2)
+    pub fn bar_width(mut self, width: u16) -> Self {
+        self.attr(Attribute::Custom(METER_BAR_WIDTH), AttrValue::Size(width));
+        self
+    }
+
+    /// Set the number of ticks peaks are held for (default: 10); 0 disables the peak markers
+    pub fn peak_hold(mut self, ticks: usize) -> Self {
+        self.attr(Attribute::Custom(METER_PEAK_HOLD), AttrValue::Length(ticks));
+        self
+    }
+
+    fn get_range(&self) -> (f64, f64) {
+        self.props
+            .get(Attribute::Custom(METER_RANGE))
+            .map(|x| {
+                let (min, max) = x.unwrap_payload().unwrap_tup2();
+                (min.unwrap_f64(), max.unwrap_f64())
+            })
+            .unwrap_or((0.0, 100.0))
+    }
+
+    fn get_zones(&self) -> Vec<(f64, Color)> {
+        match self.props.get(Attribute::Custom(METER_ZONES)) {
+            Some(zones) => zones
+                .unwrap_payload()
+                .unwrap_linked()
+                .into_iter()
+                .map(|x| {
+                    let (bound, color) = x.unwrap_tup2();
+                    (bound.unwrap_f64(), color.unwrap_color())
+                })
+                .collect(),
+            None => {
+                let (min, max) = self.get_range();
+                DEFAULT_ZONES
+                    .iter()
+                    .map(|(ratio, color)| (min + (max - min) * ratio, *color))
+                    .collect()
+            }
+        }
+    }
+
+    fn get_labels(&self) -> Vec<String> {
+        match self
+            .props
+            .get(Attribute::Custom(METER_LABELS))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(labels)) => labels.into_iter().map(|x| x.unwrap_str()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_peak_hold(&self) -> usize {
+        self.props
+            .get_or(Attribute::Custom(METER_PEAK_HOLD), AttrValue::Length(10))
+            .unwrap_length()
+    }
+
+    /// ### bar_cells
+    ///
+    /// Get the cells of the bar of `value` along `cells` cells, from the bottom or the left, as how many eighths of
+    /// the cell are filled and its color; and the cell of the peak, if above the value
+    fn bar_cells(
+        &self,
+        value: f64,
+        peak: f64,
+        cells: u16,
+        zones: &[(f64, Color)],
+        foreground: Color,
+    ) -> (Vec<(usize, Color)>, Option<usize>) {
+        let range = self.get_range();
+        let (min, max) = range;
+        let eighths = (ratio(value, range) * cells as f64 * 8.0).round() as usize;
+        let fill = (0..cells as usize)
+            .map(|i| {
+                // Color of the cell by the value at its end
+                let bound = min + (max - min) * (i + 1) as f64 / cells as f64;
+                let color = zones
+                    .iter()
+                    .find(|(zone, _)| bound <= *zone)
+                    .map(|(_, x)| *x)
+                    .unwrap_or(foreground);
+                (eighths.saturating_sub(i * 8).min(8), color)
+            })
+            .collect();
+        let peak_cell = (ratio(peak, range) * cells as f64).ceil() as usize;
+        let peak_cell = match self.get_peak_hold() > 0 && peak > value && peak_cell > 0 {
+            true => Some(peak_cell.min(cells as usize) - 1),
+            false => None,
+        };
+        (fill, peak_cell)
+    }
+
+    /// ### render_meter
+    ///
+    /// Render the bars in `area`, with their labels
+    fn render_meter(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let zones = self.get_zones();
+        let labels = self.get_labels();
+        let foreground = style.fg.unwrap_or(Color::Reset);
+        let horizontal = self
+            .props
+            .get_or(Attribute::Custom(METER_HORIZONTAL), AttrValue::Flag(false))
+            .unwrap_flag();
+        let bars = self.states.values.iter().zip(self.states.peaks.iter());
+        if horizontal {
+            let label_width = match labels.iter().map(|x| x.width()).max() {
+                Some(width) if width > 0 => width as u16 + 1,
+                _ => 0,
+            };
+            let cells = area.width.saturating_sub(label_width);
+            for (i, (y, (value, (peak, _)))) in area.rows().map(|x| x.y).zip(bars).enumerate() {
+                if let Some(label) = labels.get(i) {
+                    buf.set_stringn(area.x, y, label, label_width as usize, style);
+                }
+                let x = area.x + label_width.min(area.width);
+                let (fill, peak) = self.bar_cells(*value, *peak, cells, &zones, foreground);
+                for (j, (eighths, color)) in fill.into_iter().enumerate() {
+                    let symbol = match Some(j) == peak && eighths == 0 {
+                        true => HORIZONTAL_PEAK,
+                        false => HORIZONTAL_EIGHTHS[eighths],
+                    };
+                    buf[(x + j as u16, y)]
+                        .set_symbol(symbol)
+                        .set_style(style.fg(color));
+                }
+            }
+        } else {
+            let bar_width = self
+                .props
+                .get_or(Attribute::Custom(METER_BAR_WIDTH), AttrValue::Size(2))
+                .unwrap_size()
+                .max(1);
+            let label_height = u16::from(!labels.is_empty()).min(area.height);
+            let cells = area.height - label_height;
+            let bottom = area.y + cells;
+            for (i, (value, (peak, _))) in bars.enumerate() {
+                let x = area.x.saturating_add(i as u16 * (bar_width + 1));
+                if x >= area.right() {
+                    break;
+                }
+                let width = bar_width.min(area.right() - x);
+                if let Some(label) = labels.get(i).filter(|_| label_height > 0) {
+                    buf.set_stringn(x, bottom, label, width as usize, style);
+                }
+                let (fill, peak) = self.bar_cells(*value, *peak, cells, &zones, foreground);
+                for (j, (eighths, color)) in fill.into_iter().enumerate() {
+                    let symbol = match Some(j) == peak && eighths == 0 {
+                        true => VERTICAL_PEAK,
+                        false => VERTICAL_EIGHTHS[eighths],
+                    };
+                    for x in x..x + width {
+                        buf[(x, bottom - 1 - j as u16)]
+                            .set_symbol(symbol)
+                            .set_style(style.fg(color));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl MockComponent for Meter {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let style = Style::default().fg(foreground).bg(background);
+            let block = FocusStyles::new(&self.props, style)
+                .block(borders, title)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            let buf = render.buffer_mut();
+            block.render(area, buf);
+            self.render_meter(buf, inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if attr == Attribute::Value {
+            let values = match self.props.get(attr).map(|x| x.unwrap_payload()) {
+                Some(PropPayload::Vec(values)) => {
+                    values.into_iter().map(|x| x.unwrap_f64()).collect()
+                }
+                _ => Vec::new(),
+            };
+            self.states.update(values);
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Tick if self.states.tick(self.get_peak_hold()) => CmdResult::Changed(State::None),
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.area
+            .rows()
+            .map(|row| {
+                row.positions()
+                    .map(|pos| buf[pos].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_components_meter_peaks() {
+        let mut states = MeterStates::default();
+        states.update(vec![50.0, 20.0]);
+        states.update(vec![25.0, 30.0]);
+        assert_eq!(states.peaks, vec![(50.0, 0), (30.0, 0)]);
+        assert!(!states.tick(2));
+        assert!(!states.tick(2));
+        assert!(states.tick(2));
+        assert_eq!(states.peaks, vec![(25.0, 0), (30.0, 3)]);
+    }
+
+    #[test]
+    fn test_components_meter() {
+        let mut component = Meter::default().values(&[50.0, 100.0, 0.0]);
+        let area = Rect::new(0, 0, 8, 4);
+        let mut buf = Buffer::empty(area);
+        component.render_meter(&mut buf, area, Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec!["   ██   ", "   ██   ", "██ ██   ", "██ ██   "]
+        );
+        // Colored by zone
+        assert_eq!(buf[(3, 3)].fg, Color::Green);
+        assert_eq!(buf[(3, 1)].fg, Color::Yellow);
+        assert_eq!(buf[(3, 0)].fg, Color::Red);
+        // Peak is held
+        component.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::Vec(vec![
+                PropValue::F64(25.0),
+                PropValue::F64(100.0),
+                PropValue::F64(0.0),
+            ])),
+        );
+        let mut buf = Buffer::empty(area);
+        component.render_meter(&mut buf, area, Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec!["   ██   ", "   ██   ", "▔▔ ██   ", "██ ██   "]
+        );
+        // Horizontal, with labels
+        let component = component
+            .horizontal(true)
+            .labels(&["l", "r"])
+            .values(&[50.0, 62.5]);
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buf = Buffer::empty(area);
+        component.render_meter(&mut buf, area, Style::default());
+        assert_eq!(rows(&buf), vec!["l ██  ", "r ██▌▕"]);
+    }
+}
@@ -0,0 +1,351 @@
+//! ## Minimap
+//!
+//! `Minimap` is a compressed overview of a text, to display next to the view of the text
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    AttrValue, Attribute, Color, PropPayload, PropValue, Props, Style, TextSpan,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+
+use crate::theme::Theme;
+use crate::utils::{render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{
+    MINIMAP_CHARS_PER_CELL, MINIMAP_LINES_PER_CELL, MINIMAP_LINE_COLORS, MINIMAP_POSITION,
+    MINIMAP_VIEWPORT_LENGTH, TOOLTIP,
+};
+
+/// Symbols of the cells, from empty to full
+const DENSITY_SYMBOLS: [&str; 5] = [" ", "░", "▒", "▓", "█"];
+
+/// ### lines_per_cell
+///
+/// Get the number of lines represented by a row of the minimap, to fit `lines` lines in `height` rows
+fn lines_per_cell(lines: usize, height: u16) -> usize {
+    lines.div_ceil(height.max(1) as usize).max(1)
+}
+
+/// ### first_row
+///
+/// Get the first row of the minimap to display, when it has `rows` rows but only `height` fit, keeping the viewport,
+/// which starts at `viewport_row` and spans `viewport_rows` rows, centered
+fn first_row(rows: usize, height: u16, viewport_row: usize, viewport_rows: usize) -> usize {
+    let height = height as usize;
+    viewport_row
+        .saturating_sub(height.saturating_sub(viewport_rows) / 2)
+        .min(rows.saturating_sub(height))
+}
+
+/// ### density_symbol
+///
+/// Get the symbol of a cell with `count` non-blank characters out of `capacity`
+fn density_symbol(count: usize, capacity: usize) -> &'static str {
+    let levels = DENSITY_SYMBOLS.len() - 1;
+    DENSITY_SYMBOLS[(count * levels).div_ceil(capacity.max(1)).min(levels)]
+}
+
+// -- Component
+
+/// ## Minimap
+///
+/// A compressed overview of a text, where each cell represents a block of lines and characters, drawn according to
+/// the density of its content. Lines are colored with the provided per-line colors, or with the color of their
+/// span. The rows of the lines displayed by the view of the text (the viewport) are highlighted
+#[derive(Default)]
+pub struct Minimap {
+    props: Props,
+}
+
+impl Minimap {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `minimap` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("minimap", self);
+    }
+
+    /// Set the background color of the viewport (default: `DarkGray`)
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn text_rows(mut self, rows: &[TextSpan]) -> Self {
+        self.attr(
+            Attribute::Text,
+            AttrValue::Payload(PropPayload::Vec(
+                rows.iter().cloned().map(PropValue::TextSpan).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the color of each line, overriding the color of the spans; `Color::Reset` keeps the color of the span
+    pub fn line_colors(mut self, colors: &[Color]) -> Self {
+        self.attr(
+            Attribute::Custom(MINIMAP_LINE_COLORS),
+            AttrValue::Payload(PropPayload::Vec(
+                colors.iter().copied().map(PropValue::Color).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the number of lines represented by a row; by default the text is compressed to fit the height
+    pub fn lines_per_cell(mut self, lines: usize) -> Self {
+        self.attr(
+            Attribute::Custom(MINIMAP_LINES_PER_CELL),
+            AttrValue::Length(lines),
+        );
+        self
+    }
+
+    /// Set the number of characters represented by a column (default: 4)
+    pub fn chars_per_cell(mut self, chars: usize) -> Self {
+        self.attr(
+            Attribute::Custom(MINIMAP_CHARS_PER_CELL),
+            AttrValue::Length(chars),
+        );
+        self
+    }
+
+    /// Set the first line displayed by the view of the text
+    pub fn position(mut self, position: usize) -> Self {
+        self.attr(
+            Attribute::Custom(MINIMAP_POSITION),
+            AttrValue::Length(position),
+        );
+        self
+    }
+
+    /// Set the number of lines displayed by the view of the text
+    pub fn viewport_length(mut self, len: usize) -> Self {
+        self.attr(
+            Attribute::Custom(MINIMAP_VIEWPORT_LENGTH),
+            AttrValue::Length(len),
+        );
+        self
+    }
+
+    fn get_length(&self, attr: &'static str, default: usize) -> usize {
+        self.props
+            .get_or(Attribute::Custom(attr), AttrValue::Length(default))
+            .unwrap_length()
+    }
+
+    fn get_lines(&self) -> Vec<TextSpan> {
+        match self.props.get(Attribute::Text).map(|x| x.unwrap_payload()) {
+            Some(PropPayload::Vec(spans)) => {
+                spans.into_iter().map(|x| x.unwrap_text_span()).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_line_colors(&self) -> Vec<Color> {
+        match self
+            .props
+            .get(Attribute::Custom(MINIMAP_LINE_COLORS))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(colors)) => {
+                colors.into_iter().map(|x| x.unwrap_color()).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### render_minimap
+    ///
+    /// Render the minimap in `area`, with `style` as the base style and `viewport_color` as background of the viewport
+    fn render_minimap(&self, buf: &mut Buffer, area: Rect, style: Style, viewport_color: Color) {
+        if area.is_empty() {
+            return;
+        }
+        let spans = self.get_lines();
+        let lines: Vec<Vec<char>> = spans.iter().map(|x| x.content.chars().collect()).collect();
+        let line_colors = self.get_line_colors();
+        let line_color = |i: usize| {
+            line_colors
+                .get(i)
+                .copied()
+                .filter(|x| *x != Color::Reset)
+                .or(Some(spans[i].fg).filter(|x| *x != Color::Reset))
+        };
+        let per_row = match self.get_length(MINIMAP_LINES_PER_CELL, 0) {
+            0 => lines_per_cell(lines.len(), area.height),
+            n => n,
+        };
+        let per_column = self.get_length(MINIMAP_CHARS_PER_CELL, 4).max(1);
+        let position = self.get_length(MINIMAP_POSITION, 0);
+        let viewport = position..position + self.get_length(MINIMAP_VIEWPORT_LENGTH, 0);
+        let rows = lines.len().div_ceil(per_row);
+        let offset = first_row(
+            rows,
+            area.height,
+            position / per_row,
+            viewport.len().div_ceil(per_row),
+        );
+        for (y, row) in area.rows().zip(offset..rows) {
+            let block = row * per_row..((row + 1) * per_row).min(lines.len());
+            let mut row_style = match block.clone().find_map(line_color) {
+                Some(color) => style.fg(color),
+                None => style,
+            };
+            if block.start < viewport.end && viewport.start < block.end {
+                row_style = row_style.bg(viewport_color);
+            }
+            for (i, x) in (y.left()..y.right()).enumerate() {
+                let count: usize = lines[block.clone()]
+                    .iter()
+                    .map(|line| {
+                        line.iter()
+                            .skip(i * per_column)
+                            .take(per_column)
+                            .filter(|x| !x.is_whitespace())
+                            .count()
+                    })
+                    .sum();
+                buf[(x, y.y)]
+                    .set_symbol(density_symbol(count, per_row * per_column))
+                    .set_style(row_style);
+            }
+        }
+    }
+}
+
+impl MockComponent for Minimap {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let viewport_color = self
+                .props
+                .get_or(
+                    Attribute::HighlightedColor,
+                    AttrValue::Color(Color::DarkGray),
+                )
+                .unwrap_color();
+            let style = Style::default().fg(foreground).bg(background);
+            self.render_minimap(render.buffer_mut(), area, style, viewport_color);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.area
+            .rows()
+            .map(|row| {
+                row.positions()
+                    .map(|pos| buf[pos].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_components_minimap_scale() {
+        assert_eq!(lines_per_cell(0, 10), 1);
+        assert_eq!(lines_per_cell(8, 4), 2);
+        assert_eq!(lines_per_cell(9, 4), 3);
+        assert_eq!(first_row(10, 20, 5, 2), 0);
+        assert_eq!(first_row(40, 10, 0, 2), 0);
+        assert_eq!(first_row(40, 10, 20, 2), 16);
+        assert_eq!(first_row(40, 10, 38, 2), 30);
+        assert_eq!(density_symbol(0, 8), " ");
+        assert_eq!(density_symbol(1, 8), "░");
+        assert_eq!(density_symbol(8, 8), "█");
+    }
+
+    #[test]
+    fn test_components_minimap() {
+        let component = Minimap::default()
+            .text_rows(&[
+                TextSpan::from("fn main() {"),
+                TextSpan::from("    let x = 1;").fg(Color::Yellow),
+                TextSpan::from(""),
+                TextSpan::from("}"),
+            ])
+            .line_colors(&[Color::Reset, Color::Reset, Color::Reset, Color::Red])
+            .chars_per_cell(4)
+            .position(1)
+            .viewport_length(2);
+        assert_eq!(component.state(), State::None);
+        let area = Rect::new(0, 0, 4, 4);
+        let mut buf = Buffer::empty(area);
+        component.render_minimap(&mut buf, area, Style::default(), Color::Blue);
+        assert_eq!(rows(&buf), vec!["▓█▒ ", " ▓▒▒", "    ", "░   "]);
+        assert_eq!(buf[(0, 0)].bg, Color::Reset);
+        assert_eq!(buf[(0, 1)].bg, Color::Blue);
+        assert_eq!(buf[(0, 1)].fg, Color::Yellow);
+        assert_eq!(buf[(0, 2)].bg, Color::Blue);
+        assert_eq!(buf[(0, 3)].fg, Color::Red);
+        // Compress to fit the height
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+        component.render_minimap(&mut buf, area, Style::default(), Color::Blue);
+        assert_eq!(rows(&buf), vec!["▒█▒░", "░   "]);
+        assert_eq!(buf[(0, 0)].bg, Color::Blue);
+        assert_eq!(buf[(0, 1)].bg, Color::Blue);
+    }
+}
@@ -3,46 +3,169 @@
 //! `Components` provides a "standard" library of components.
 
 // Modules
+mod accordion;
 mod bar_chart;
+mod big_text;
+mod breadcrumbs;
+mod calendar;
+mod candlestick_chart;
 mod canvas;
 mod chart;
+mod chat_view;
 mod checkbox;
+mod clock;
+mod color_picker;
 mod container;
+mod date_picker;
+mod dial;
+mod diff_viewer;
+mod dual_list_transfer;
+mod file_explorer;
+mod form;
+mod gantt;
+mod grid;
+mod heatmap;
+mod help_overlay;
+mod histogram;
 mod input;
+mod kanban;
+mod key_hint_bar;
 mod label;
 mod line_gauge;
 mod list;
+mod menu_bar;
+mod meter;
+mod minimap;
+mod modal;
+mod network_graph;
+mod number_spinner;
+mod outline;
+mod paginator;
 mod paragraph;
+mod password_strength;
 mod phantom;
+mod pie_chart;
 mod progress_bar;
 mod radio;
+mod rating;
+mod scatter_plot;
+mod scroll_view;
+mod scrollbar;
+mod search_box;
 mod select;
+mod skeleton;
 mod span;
 mod sparkline;
 mod spinner;
+mod split_pane;
+mod status_bar;
+mod stepper;
 mod table;
+mod tabs;
+mod tag_input;
 mod textarea;
+mod time_picker;
+mod timeline;
+mod timer;
+mod toggle_switch;
 
+pub mod commands;
 pub mod props;
 pub mod states;
 
+#[cfg(feature = "vt100")]
+mod terminal_view;
+
+#[cfg(feature = "image")]
+mod image;
+
+#[cfg(feature = "json")]
+mod json_viewer;
+
+#[cfg(feature = "markdown")]
+mod markdown;
+
+#[cfg(feature = "qrcode")]
+mod qr_code;
+
 // Exports
+pub use accordion::{Accordion, AccordionSection};
 pub use bar_chart::BarChart;
-pub use canvas::Canvas;
+pub use big_text::{BigText, BigTextFont};
+pub use breadcrumbs::Breadcrumbs;
+pub use calendar::Calendar;
+pub use candlestick_chart::CandlestickChart;
+pub use canvas::{Canvas, CanvasShapes};
 pub use chart::Chart;
+pub use chat_view::{ChatMessage, ChatView};
 pub use checkbox::Checkbox;
-pub use container::Container;
+pub use clock::Clock;
+pub use color_picker::ColorPicker;
+pub use container::{Container, ContainerBreakpoint};
+pub use date_picker::DatePicker;
+pub use dial::Dial;
+pub use diff_viewer::{DiffLine, DiffLineKind, DiffViewer};
+pub use dual_list_transfer::DualListTransfer;
+pub use file_explorer::{FileEntry, FileExplorer, FileKind};
+pub use form::{Form, FormField, FormValidator};
+pub use gantt::{Gantt, GanttTask};
+pub use grid::{Grid, GridCell};
+pub use heatmap::Heatmap;
+pub use help_overlay::{HelpOverlay, HelpSection};
+pub use histogram::Histogram;
 pub use input::Input;
-pub use label::Label;
+pub use kanban::{Kanban, KanbanColumn};
+pub use key_hint_bar::{KeyHint, KeyHintBar};
+pub use label::{Label, Truncation};
 pub use line_gauge::LineGauge;
 pub use list::List;
+pub use menu_bar::{MenuBar, MenuItem};
+pub use meter::Meter;
+pub use minimap::Minimap;
+pub use modal::Modal;
+pub use network_graph::{EdgeStyle, GraphEdge, NetworkGraph};
+pub use number_spinner::NumberSpinner;
+pub use outline::{Outline, OutlineEntry};
+pub use paginator::Paginator;
 pub use paragraph::Paragraph;
+pub use password_strength::{PasswordRule, PasswordStrength};
 pub use phantom::Phantom;
+pub use pie_chart::PieChart;
 pub use progress_bar::ProgressBar;
 pub use radio::Radio;
+pub use rating::Rating;
+pub use scatter_plot::ScatterPlot;
+pub use scroll_view::ScrollView;
+pub use scrollbar::Scrollbar;
+pub use search_box::SearchBox;
 pub use select::Select;
+pub use skeleton::{Skeleton, SkeletonShape};
 pub use span::Span;
-pub use sparkline::Sparkline;
+pub use sparkline::{Downsample, Sparkline};
 pub use spinner::Spinner;
+pub use split_pane::SplitPane;
+pub use status_bar::{StatusBar, StatusBarSection};
+pub use stepper::Stepper;
 pub use table::Table;
+pub use tabs::Tabs;
+pub use tag_input::TagInput;
 pub use textarea::Textarea;
+pub use time_picker::{TimePicker, TimeSegment};
+pub use timeline::{Timeline, TimelineEvent};
+pub use timer::{Timer, TimerMode};
+pub use toggle_switch::ToggleSwitch;
+
+#[cfg(feature = "image")]
+pub use self::image::{Image, ImageProtocol, VerticalAlignment};
+
+#[cfg(feature = "json")]
+pub use json_viewer::JsonViewer;
+
+#[cfg(feature = "markdown")]
+pub use markdown::Markdown;
+
+#[cfg(feature = "qrcode")]
+pub use qr_code::{QrCode, QrErrorCorrection};
+
+#[cfg(feature = "vt100")]
+pub use terminal_view::TerminalView;
@@ -0,0 +1,494 @@
+//! ## Modal
+//!
+//! `Modal` is a dialog box displayed over the rest of the view, with a message and a row of buttons
+
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers, TextSpan,
+};
+use tuirealm::ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line as Spans, Span},
+    widgets::{Clear, Paragraph as TuiParagraph, Widget},
+};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{
+    centered_rect_fixed, get_block, get_title_style, is_disabled, render_disabled, render_tooltip,
+    use_or_default_styles,
+};
+
+// -- Props
+
+use super::props::{MODAL_BACKDROP, TOOLTIP};
+
+/// Space between buttons
+const BUTTON_SPACING: u16 = 2;
+/// Horizontal padding between the borders and the content, on each side
+const PADDING: u16 = 2;
+
+// -- states
+
+/// ## ModalStates
+///
+/// ModalStates contains states for this component
+#[derive(Default)]
+pub struct ModalStates {
+    pub selected: usize,      // Selected button
+    pub buttons: Vec<String>, // Button labels
+}
+
+impl ModalStates {
+    /// ### next_button
+    ///
+    /// Select next button
+    pub fn next_button(&mut self, rewind: bool) {
+        if rewind && self.selected + 1 >= self.buttons.len() {
+            self.selected = 0;
+        } else if self.selected + 1 < self.buttons.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// ### prev_button
+    ///
+    /// Select previous button
+    pub fn prev_button(&mut self, rewind: bool) {
+        if rewind && self.selected == 0 && !self.buttons.is_empty() {
+            self.selected = self.buttons.len() - 1;
+        } else if self.selected > 0 {
+            self.selected -= 1;
+        }
+    }
+
+    /// ### set_buttons
+    ///
+    /// Set button labels, keeping the selected index if possible or setting it to the last button
+    pub fn set_buttons(&mut self, buttons: &[String]) {
+        self.buttons = buttons.to_vec();
+        if self.selected >= self.buttons.len() {
+            self.selected = self.buttons.len().saturating_sub(1);
+        }
+    }
+
+    pub fn select(&mut self, i: usize) {
+        if i < self.buttons.len() {
+            self.selected = i;
+        }
+    }
+}
+
+// -- component
+
+/// ## Modal
+///
+/// A dialog box, centered in the area it is rendered into, over a dimmed backdrop. It displays a title, body rows and
+/// a row of buttons; the submitted button is returned by its index.
+///
+/// Render it with the whole view as area, after the other components.
+#[derive(Default)]
+pub struct Modal {
+    props: Props,
+    pub states: ModalStates,
+}
+
+impl Modal {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `modal` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("modal", self);
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Set the body of the dialog; each span is a row
+    pub fn text(mut self, s: &[TextSpan]) -> Self {
+        self.attr(
+            Attribute::Text,
+            AttrValue::Payload(PropPayload::Vec(
+                s.iter().cloned().map(PropValue::TextSpan).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the alignment of body rows; defaults to center
+    pub fn alignment(mut self, a: Alignment) -> Self {
+        self.attr(Attribute::Alignment, AttrValue::Alignment(a));
+        self
+    }
+
+    pub fn buttons<S: AsRef<str>>(mut self, buttons: &[S]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(
+                buttons
+                    .iter()
+                    .map(|x| PropValue::Str(x.as_ref().to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    pub fn value(mut self, i: usize) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(i))),
+        );
+        self
+    }
+
+    pub fn rewind(mut self, r: bool) -> Self {
+        self.attr(Attribute::Rewind, AttrValue::Flag(r));
+        self
+    }
+
+    /// Set the width of the dialog, including borders; by default it fits the content
+    pub fn width(mut self, w: u16) -> Self {
+        self.attr(Attribute::Width, AttrValue::Size(w));
+        self
+    }
+
+    /// Set the height of the dialog, including borders; by default it fits the content
+    pub fn height(mut self, h: u16) -> Self {
+        self.attr(Attribute::Height, AttrValue::Size(h));
+        self
+    }
+
+    /// Set whether to dim the area around the dialog; defaults to true
+    pub fn backdrop(mut self, backdrop: bool) -> Self {
+        self.attr(Attribute::Custom(MODAL_BACKDROP), AttrValue::Flag(backdrop));
+        self
+    }
+
+    fn is_rewind(&self) -> bool {
+        self.props
+            .get_or(Attribute::Rewind, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    fn get_text(&self) -> Vec<TextSpan> {
+        match self.props.get(Attribute::Text).map(|x| x.unwrap_payload()) {
+            Some(PropPayload::Vec(spans)) => {
+                spans.into_iter().map(|x| x.unwrap_text_span()).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### buttons_width
+    ///
+    /// Get the width of the buttons row
+    fn buttons_width(&self) -> u16 {
+        let labels: u16 = self
+            .states
+            .buttons
+            .iter()
+            .map(|x| x.width() as u16 + 4)
+            .sum();
+        labels + BUTTON_SPACING * (self.states.buttons.len() as u16).saturating_sub(1)
+    }
+
+    /// ### dialog_size
+    ///
+    /// Get the size of the dialog: the width and height properties if set, otherwise the size fitting the content
+    fn dialog_size(&self) -> (u16, u16) {
+        let text = self.get_text();
+        let title = self
+            .props
+            .get(Attribute::Title)
+            .map(|x| x.unwrap_title().0.width() as u16)
+            .unwrap_or(0);
+        let body = text
+            .iter()
+            .map(|x| x.content.width() as u16)
+            .max()
+            .unwrap_or(0);
+        let width = title.max(body).max(self.buttons_width()) + PADDING * 2 + 2;
+        let buttons_height = match self.states.buttons.is_empty() {
+            true => 0,
+            false => 2,
+        };
+        let height = text.len() as u16 + buttons_height + 2;
+        (
+            self.props
+                .get(Attribute::Width)
+                .map(|x| x.unwrap_size())
+                .unwrap_or(width),
+            self.props
+                .get(Attribute::Height)
+                .map(|x| x.unwrap_size())
+                .unwrap_or(height),
+        )
+    }
+
+    /// ### render_modal
+    ///
+    /// Render the backdrop over `area` and the dialog centered in it
+    fn render_modal(&self, buf: &mut Buffer, area: Rect, focus: bool) {
+        if self
+            .props
+            .get_or(Attribute::Custom(MODAL_BACKDROP), AttrValue::Flag(true))
+            .unwrap_flag()
+        {
+            buf.set_style(area, Style::default().add_modifier(TextModifiers::DIM));
+        }
+        let foreground = self
+            .props
+            .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+            .unwrap_color();
+        let background = self
+            .props
+            .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+            .unwrap_color();
+        let highlighted = self
+            .props
+            .get_or(Attribute::HighlightedColor, AttrValue::Color(foreground))
+            .unwrap_color();
+        let borders = self
+            .props
+            .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+            .unwrap_borders();
+        let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+        let alignment = self
+            .props
+            .get_or(
+                Attribute::Alignment,
+                AttrValue::Alignment(Alignment::Center),
+            )
+            .unwrap_alignment();
+        let style = Style::default().fg(foreground).bg(background);
+        let (width, height) = self.dialog_size();
+        let dialog = centered_rect_fixed(width, height, area);
+        Clear.render(dialog, buf);
+        let block = get_block(borders, title, true, None)
+            .title_style(get_title_style(&self.props))
+            .style(style);
+        let inner = block.inner(dialog);
+        block.render(dialog, buf);
+        // Body
+        let buttons_height = match self.states.buttons.is_empty() {
+            true => 0,
+            false => 2,
+        };
+        let body = Rect {
+            x: inner.x + PADDING.min(inner.width / 2),
+            width: inner.width.saturating_sub(PADDING * 2),
+            height: inner.height.saturating_sub(buttons_height),
+            ..inner
+        };
+        let text: Vec<Spans> = self
+            .get_text()
+            .into_iter()
+            .map(|x| {
+                let (fg, bg, modifiers) = use_or_default_styles(&self.props, &x);
+                Spans::from(Span::styled(
+                    x.content,
+                    Style::default().add_modifier(modifiers).fg(fg).bg(bg),
+                ))
+            })
+            .collect();
+        TuiParagraph::new(text)
+            .style(style)
+            .alignment(alignment)
+            .render(body, buf);
+        // Buttons
+        if buttons_height == 0 || inner.height == 0 {
+            return;
+        }
+        let y = inner.bottom() - 1;
+        let mut x = inner.x + inner.width.saturating_sub(self.buttons_width()) / 2;
+        for (i, button) in self.states.buttons.iter().enumerate() {
+            let button_style = match focus && i == self.states.selected {
+                true => Style::default()
+                    .fg(highlighted)
+                    .add_modifier(TextModifiers::REVERSED),
+                false => style,
+            };
+            x =
+                buf.set_stringn(
+                    x,
+                    y,
+                    format!("[ {button} ]"),
+                    inner.right().saturating_sub(x) as usize,
+                    button_style,
+                )
+                .0 + BUTTON_SPACING;
+            if x >= inner.right() {
+                break;
+            }
+        }
+    }
+}
+
+impl MockComponent for Modal {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            self.render_modal(render.buffer_mut(), area, focus);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Content => {
+                let buttons: Vec<String> = value
+                    .unwrap_payload()
+                    .unwrap_vec()
+                    .iter()
+                    .map(|x| x.clone().unwrap_str())
+                    .collect();
+                self.states.set_buttons(&buttons);
+            }
+            Attribute::Value => {
+                self.states
+                    .select(value.unwrap_payload().unwrap_one().unwrap_usize());
+            }
+            attr => {
+                self.props.set(attr, value);
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.buttons.is_empty() {
+            true => State::None,
+            false => State::One(StateValue::Usize(self.states.selected)),
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let before = self.states.selected;
+        match cmd {
+            Cmd::Move(Direction::Right) => self.states.next_button(self.is_rewind()),
+            Cmd::Move(Direction::Left) => self.states.prev_button(self.is_rewind()),
+            Cmd::Submit => return CmdResult::Submit(self.state()),
+            _ => return CmdResult::None,
+        }
+        match self.states.selected != before {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_modal() {
+        let mut component = Modal::default()
+            .title("Quit", Alignment::Center)
+            .borders(Borders::default())
+            .text(&[TextSpan::from("Are you sure you want to quit?")])
+            .buttons(&["Yes", "No"])
+            .value(1);
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        component.attr(Attribute::Rewind, AttrValue::Flag(true));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(1)))
+        );
+        // Message only
+        let component = Modal::default().text(&[TextSpan::from("Saving...")]);
+        assert_eq!(component.state(), State::None);
+    }
+
+    #[test]
+    fn test_components_modal_render() {
+        let component = Modal::default()
+            .title("Quit", Alignment::Center)
+            .borders(Borders::default())
+            .text(&[TextSpan::from("Quit now?")])
+            .buttons(&["Yes", "No"]);
+        assert_eq!(component.dialog_size(), (21, 5));
+        let area = Rect::new(0, 0, 25, 7);
+        let mut buf = Buffer::empty(area);
+        component.render_modal(&mut buf, area, true);
+        let row =
+            |y: u16| -> String { (0..25).map(|x| buf[(x, y)].symbol().to_string()).collect() };
+        assert_eq!(row(0), "                         ");
+        assert_eq!(row(1), "  ┌───────Quit────────┐  ");
+        assert_eq!(row(2), "  │     Quit now?     │  ");
+        assert_eq!(row(3), "  │                   │  ");
+        assert_eq!(row(4), "  │  [ Yes ]  [ No ]  │  ");
+        assert_eq!(row(5), "  └───────────────────┘  ");
+        // Backdrop is dimmed, dialog isn't
+        assert!(buf[(0, 0)].modifier.contains(TextModifiers::DIM));
+        assert!(!buf[(8, 2)].modifier.contains(TextModifiers::DIM));
+        assert!(buf[(6, 4)].modifier.contains(TextModifiers::REVERSED));
+        assert!(!buf[(14, 4)].modifier.contains(TextModifiers::REVERSED));
+    }
+}
@@ -0,0 +1,622 @@
+//! ## NetworkGraph
+//!
+//! `NetworkGraph` displays nodes connected by edges, for dependency and topology views
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::symbols::Marker;
+use tuirealm::ratatui::text::{Line as Spans, Span};
+use tuirealm::ratatui::{
+    layout::Rect,
+    widgets::canvas::{Canvas as TuiCanvas, Context, Line, Points},
+};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{NETWORK_GRAPH_EDGES, TOOLTIP};
+
+/// Length of the dashes of dashed edges, and of the gaps between them, in layers
+const DASH_LENGTH: f64 = 0.05;
+/// Space left on the left of the first layer and on the right of the last one, in layers
+const MARGIN: f64 = 0.25;
+
+/// ## EdgeStyle
+///
+/// How an edge is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeStyle {
+    #[default]
+    Solid,
+    Dashed,
+}
+
+impl EdgeStyle {
+    const ALL: [Self; 2] = [Self::Solid, Self::Dashed];
+}
+
+/// ## GraphEdge
+///
+/// An edge of the `NetworkGraph`, from a node to another, identified by their index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphEdge {
+    pub from: usize,
+    pub to: usize,
+    /// Color of the edge; `Color::Reset` uses the foreground color
+    pub color: Color,
+    pub style: EdgeStyle,
+}
+
+impl GraphEdge {
+    pub fn new(from: usize, to: usize) -> Self {
+        Self {
+            from,
+            to,
+            color: Color::Reset,
+            style: EdgeStyle::default(),
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn style(mut self, style: EdgeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn to_payload(self) -> PropPayload {
+        PropPayload::Vec(vec![
+            PropValue::Usize(self.from),
+            PropValue::Usize(self.to),
+            PropValue::Color(self.color),
+            PropValue::U8(self.style as u8),
+        ])
+    }
+
+    fn from_payload(payload: PropPayload) -> Option<Self> {
+        match payload.unwrap_vec().as_slice() {
+            [PropValue::Usize(from), PropValue::Usize(to), PropValue::Color(color), PropValue::U8(style)] => {
+                Some(Self {
+                    from: *from,
+                    to: *to,
+                    color: *color,
+                    style: EdgeStyle::ALL
+                        .get(*style as usize)
+                        .copied()
+                        .unwrap_or_default(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// ### layered_layout
+///
+/// Arrange `nodes` nodes in layers, so that edges go from a layer to a following one, with the longest path
+/// layering; edges closing a cycle go backward. In each layer, nodes are sorted by the average position of their
+/// predecessors, to limit edge crossings. Returns the layer and the position in the layer of each node
+fn layered_layout(nodes: usize, edges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let edges: Vec<(usize, usize)> = edges
+        .iter()
+        .copied()
+        .filter(|(from, to)| from != to && *from < nodes && *to < nodes)
+        .collect();
+    let mut layers = vec![0; nodes];
+    // A node can't be deeper than the number of nodes, which stops relaxing along cycles
+    for _ in 0..nodes {
+        let mut changed = false;
+        for (from, to) in edges.iter().copied() {
+            if layers[to] <= layers[from] && layers[from] + 1 < nodes {
+                layers[to] = layers[from] + 1;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let mut positions = vec![0; nodes];
+    for layer in 0..=layers.iter().copied().max().unwrap_or(0) {
+        let mut members: Vec<(f64, usize)> = (0..nodes)
+            .filter(|x| layers[*x] == layer)
+            .map(|node| {
+                let parents: Vec<f64> = edges
+                    .iter()
+                    .filter(|(from, to)| *to == node && layers[*from] < layer)
+                    .map(|(from, _)| positions[*from] as f64)
+                    .collect();
+                match parents.is_empty() {
+                    true => (node as f64, node),
+                    false => (parents.iter().sum::<f64>() / parents.len() as f64, node),
+                }
+            })
+            .collect();
+        members.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        for (position, (_, node)) in members.into_iter().enumerate() {
+            positions[node] = position;
+        }
+    }
+    layers.into_iter().zip(positions).collect()
+}
+
+// -- states
+
+/// ## NetworkGraphStates
+///
+/// NetworkGraphStates contains states for this component
+#[derive(Default)]
+pub struct NetworkGraphStates {
+    /// Index of the selected node
+    pub selected: usize,
+    /// Layer and position in the layer of each node
+    pub layout: Vec<(usize, usize)>,
+}
+
+impl NetworkGraphStates {
+    /// ### set_layout
+    ///
+    /// Set the layout of the nodes, keeping the selection in the graph
+    pub fn set_layout(&mut self, layout: Vec<(usize, usize)>) {
+        self.layout = layout;
+        self.selected = self.selected.min(self.layout.len().saturating_sub(1));
+    }
+
+    /// ### coordinates
+    ///
+    /// Get the coordinates of a node: its layer on x, and its position on y, with layers centered on 0
+    pub fn coordinates(&self, node: usize) -> (f64, f64) {
+        let (layer, position) = self.layout[node];
+        let count = self.layout.iter().filter(|(x, _)| *x == layer).count();
+        (layer as f64, (count - 1) as f64 / 2.0 - position as f64)
+    }
+
+    /// ### select_in_layer
+    ///
+    /// Select the node at `offset` positions from the selected one, in the same layer. Returns whether it moved
+    pub fn select_in_layer(&mut self, offset: isize) -> bool {
+        let Some((layer, position)) = self.layout.get(self.selected).copied() else {
+            return false;
+        };
+        let target = position.saturating_add_signed(offset);
+        match self.layout.iter().position(|x| *x == (layer, target)) {
+            Some(node) if node != self.selected => {
+                self.selected = node;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// ### select_layer
+    ///
+    /// Select the node of the previous or following layer closest to the selected one. Returns whether it moved
+    pub fn select_layer(&mut self, forward: bool) -> bool {
+        let Some((layer, _)) = self.layout.get(self.selected).copied() else {
+            return false;
+        };
+        let layer = match forward {
+            true => layer + 1,
+            false if layer > 0 => layer - 1,
+            false => return false,
+        };
+        let y = self.coordinates(self.selected).1;
+        let closest = (0..self.layout.len())
+            .filter(|x| self.layout[*x].0 == layer)
+            .min_by(|a, b| {
+                let (a, b) = (self.coordinates(*a).1, self.coordinates(*b).1);
+                (a - y).abs().total_cmp(&(b - y).abs())
+            });
+        match closest {
+            Some(node) => {
+                self.selected = node;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// -- component
+
+/// ## NetworkGraph
+///
+/// A graph of nodes connected by edges, drawn on a canvas. Nodes are arranged in layers, from left to right, following
+/// the direction of the edges. The selected node is moved with arrows, and its edges are highlighted
+#[derive(Default)]
+pub struct NetworkGraph {
+    props: Props,
+    pub states: NetworkGraphStates,
+}
+
+impl NetworkGraph {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `network_graph` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("network_graph", self);
+    }
+
+    /// Set the color of the selected node and of its edges
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Set the labels of the nodes
+    pub fn nodes<S: AsRef<str>>(mut self, nodes: &[S]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(
+                nodes
+                    .iter()
+                    .map(|x| PropValue::Str(x.as_ref().to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    pub fn edges(mut self, edges: &[GraphEdge]) -> Self {
+        self.attr(
+            Attribute::Custom(NETWORK_GRAPH_EDGES),
+            AttrValue::Payload(PropPayload::Linked(
+                edges.iter().map(|x| x.to_payload()).collect(),
+            )),
+        );
+        self
+    }
+
+    pub fn selected(mut self, node: usize) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(node))),
+        );
+        self
+    }
+
+    fn get_nodes(&self) -> Vec<String> {
+        match self
+            .props
+            .get(Attribute::Content)
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(nodes)) => nodes.into_iter().map(|x| x.unwrap_str()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_edges(&self) -> Vec<GraphEdge> {
+        match self
+            .props
+            .get(Attribute::Custom(NETWORK_GRAPH_EDGES))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Linked(edges)) => edges
+                .into_iter()
+                .filter_map(GraphEdge::from_payload)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### draw_edge
+    ///
+    /// Draw a line from `from` to `to`, split in dashes if the style is dashed
+    fn draw_edge(
+        ctx: &mut Context,
+        from: (f64, f64),
+        to: (f64, f64),
+        style: EdgeStyle,
+        color: Color,
+    ) {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let segments = match style {
+            EdgeStyle::Solid => 1,
+            EdgeStyle::Dashed => ((dx.hypot(dy) / DASH_LENGTH).ceil() as usize).max(1),
+        };
+        for i in (0..segments).step_by(match style {
+            EdgeStyle::Solid => 1,
+            EdgeStyle::Dashed => 2,
+        }) {
+            let (start, end) = (i as f64 / segments as f64, (i + 1) as f64 / segments as f64);
+            ctx.draw(&Line {
+                x1: from.0 + dx * start,
+                y1: from.1 + dy * start,
+                x2: from.0 + dx * end,
+                y2: from.1 + dy * end,
+                color,
+            });
+        }
+    }
+}
+
+impl MockComponent for NetworkGraph {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let highlighted = self
+                .props
+                .get_or(Attribute::HighlightedColor, AttrValue::Color(foreground))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let mut selected_style = Style::default().fg(highlighted);
+            if focus {
+                selected_style = selected_style.add_modifier(TextModifiers::REVERSED);
+            }
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let nodes = self.get_nodes();
+            let edges = self.get_edges();
+            let states = &self.states;
+            let layers = states.layout.iter().map(|(x, _)| *x).max().unwrap_or(0);
+            let height = (0..=layers)
+                .map(|layer| states.layout.iter().filter(|(x, _)| *x == layer).count())
+                .max()
+                .unwrap_or(0)
+                .max(1) as f64;
+            let canvas = TuiCanvas::default()
+                .background_color(background)
+                .block(block)
+                .marker(Marker::Braille)
+                .x_bounds([-MARGIN, layers as f64 + 1.0 - MARGIN])
+                .y_bounds([-height / 2.0, height / 2.0])
+                .paint(|ctx| {
+                    for edge in edges
+                        .iter()
+                        .filter(|x| x.from < nodes.len() && x.to < nodes.len())
+                    {
+                        let color = match (edge.color, states.selected) {
+                            (_, x) if x == edge.from || x == edge.to => highlighted,
+                            (Color::Reset, _) => foreground,
+                            (color, _) => color,
+                        };
+                        Self::draw_edge(
+                            ctx,
+                            states.coordinates(edge.from),
+                            states.coordinates(edge.to),
+                            edge.style,
+                            color,
+                        );
+                    }
+                    ctx.layer();
+                    for (node, label) in nodes.iter().enumerate() {
+                        let (x, y) = states.coordinates(node);
+                        let style = match node == states.selected {
+                            true => selected_style,
+                            false => Style::default().fg(foreground),
+                        };
+                        ctx.draw(&Points {
+                            coords: &[(x, y)],
+                            color: style.fg.unwrap_or(foreground),
+                        });
+                        ctx.print(x, y, Spans::from(Span::styled(label.clone(), style)));
+                    }
+                });
+            render.render_widget(canvas, area);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Content | Attribute::Custom(NETWORK_GRAPH_EDGES) => {
+                let edges: Vec<(usize, usize)> =
+                    self.get_edges().iter().map(|x| (x.from, x.to)).collect();
+                self.states
+                    .set_layout(layered_layout(self.get_nodes().len(), &edges));
+            }
+            Attribute::Value => {
+                if let Some(PropPayload::One(PropValue::Usize(node))) =
+                    self.props.get(Attribute::Value).map(|x| x.unwrap_payload())
+                {
+                    self.states.selected = node;
+                    if !self.states.layout.is_empty() {
+                        self.states.selected = node.min(self.states.layout.len() - 1);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.layout.is_empty() {
+            true => State::None,
+            false => State::One(StateValue::Usize(self.states.selected)),
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let moved = match cmd {
+            Cmd::Move(Direction::Down) => self.states.select_in_layer(1),
+            Cmd::Move(Direction::Up) => self.states.select_in_layer(-1),
+            Cmd::Move(Direction::Right) => self.states.select_layer(true),
+            Cmd::Move(Direction::Left) => self.states.select_layer(false),
+            Cmd::GoTo(Position::Begin) => {
+                let moved = self.states.selected != 0;
+                self.states.selected = 0;
+                moved
+            }
+            Cmd::GoTo(Position::End) => {
+                let last = self.states.layout.len().saturating_sub(1);
+                let moved = self.states.selected != last;
+                self.states.selected = last;
+                moved
+            }
+            Cmd::Submit if !self.states.layout.is_empty() => {
+                return CmdResult::Submit(self.state())
+            }
+            _ => false,
+        };
+        match moved {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_network_graph_layout() {
+        // Diamond
+        assert_eq!(
+            layered_layout(4, &[(0, 1), (0, 2), (1, 3), (2, 3)]),
+            vec![(0, 0), (1, 0), (1, 1), (2, 0)]
+        );
+        // Predecessors order the layer
+        assert_eq!(
+            layered_layout(4, &[(1, 2), (0, 3)]),
+            vec![(0, 0), (0, 1), (1, 1), (1, 0)]
+        );
+        // Cycles and invalid edges
+        assert_eq!(
+            layered_layout(3, &[(0, 1), (1, 2), (2, 0), (1, 1), (0, 9)]),
+            vec![(0, 0), (1, 0), (2, 0)]
+        );
+        let edge = GraphEdge::new(1, 2)
+            .color(Color::Red)
+            .style(EdgeStyle::Dashed);
+        assert_eq!(GraphEdge::from_payload(edge.to_payload()), Some(edge));
+    }
+
+    #[test]
+    fn test_components_network_graph() {
+        let mut component = NetworkGraph::default()
+            .nodes(&["app", "core", "ui", "std"])
+            .edges(&[
+                GraphEdge::new(0, 1),
+                GraphEdge::new(0, 2),
+                GraphEdge::new(1, 3),
+                GraphEdge::new(2, 3).style(EdgeStyle::Dashed),
+            ]);
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        assert_eq!(component.states.coordinates(1), (1.0, 0.5));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(3)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(1)))
+        );
+        // Set selection
+        component.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(9))),
+        );
+        assert_eq!(component.state(), State::One(StateValue::Usize(3)));
+        // Empty graph
+        let mut component = NetworkGraph::default();
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
+}
@@ -0,0 +1,533 @@
+//! ## NumberSpinner
+//!
+//! `NumberSpinner` is a compact numeric input, adjusted by steps or by typing the value
+
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{NUMBER_SPINNER_PRECISION, NUMBER_SPINNER_RANGE, NUMBER_SPINNER_STEP, TOOLTIP};
+
+/// ### round
+///
+/// Round `value` to `precision` decimals, to get rid of the errors accumulated by float steps
+fn round(value: f64, precision: usize) -> f64 {
+    let factor = 10_f64.powi(precision as i32);
+    (value * factor).round() / factor
+}
+
+/// ### sort_range
+///
+/// Get the range from `min` to `max`, swapping them if inverted. A NaN bound is ignored, leaving that side unbounded
+fn sort_range(min: f64, max: f64) -> (f64, f64) {
+    let min = match min.is_nan() {
+        true => f64::NEG_INFINITY,
+        false => min,
+    };
+    let max = match max.is_nan() {
+        true => f64::INFINITY,
+        false => max,
+    };
+    match min > max {
+        true => (max, min),
+        false => (min, max),
+    }
+}
+
+// -- states
+
+/// ## NumberSpinnerStates
+///
+/// NumberSpinner states: the value and the text typed to replace it
+#[derive(Default)]
+pub struct NumberSpinnerStates {
+    pub value: f64,
+    pub input: String,
+}
+
+impl NumberSpinnerStates {
+    /// ### step
+    ///
+    /// Add `delta` to the value, keeping it in `range`; if `rewind` is true, the value wraps around the range.
+    /// Returns whether the value has changed
+    pub fn step(
+        &mut self,
+        delta: f64,
+        range: Option<(f64, f64)>,
+        rewind: bool,
+        precision: usize,
+    ) -> bool {
+        self.input.clear();
+        let mut value = round(self.value + delta, precision);
+        if let Some((min, max)) = range.map(|(min, max)| sort_range(min, max)) {
+            value = match (value < min, value > max, rewind) {
+                (true, _, true) => max,
+                (_, true, true) => min,
+                _ => value.clamp(min, max),
+            };
+        }
+        let changed = value != self.value;
+        self.value = value;
+        changed
+    }
+
+    /// ### type_char
+    ///
+    /// Type a character of the value: digits, a leading `-` and, if `float` is true, a decimal point
+    pub fn type_char(&mut self, ch: char, float: bool) {
+        let accepted = match ch {
+            '0'..='9' => true,
+            '-' => self.input.is_empty(),
+            '.' => float && !self.input.contains('.'),
+            _ => false,
+        };
+        if accepted {
+            self.input.push(ch);
+        }
+    }
+
+    /// ### backspace
+    ///
+    /// Delete the last typed character
+    pub fn backspace(&mut self) {
+        self.input.pop();
+    }
+
+    /// ### commit_input
+    ///
+    /// Parse the typed text and set it as value, if it's a valid number in range. Returns whether the value has been set
+    pub fn commit_input(&mut self, range: Option<(f64, f64)>, precision: usize) -> bool {
+        let value = self
+            .input
+            .parse::<f64>()
+            .ok()
+            .map(|x| round(x, precision))
+            .filter(|x| {
+                range
+                    .map(|(min, max)| sort_range(min, max))
+                    .map(|(min, max)| (min..=max).contains(x))
+                    .unwrap_or(true)
+            });
+        match value {
+            Some(value) => {
+                self.value = value;
+                self.input.clear();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// -- component
+
+/// ## NumberSpinner
+///
+/// A compact numeric input, displayed as `‹ 42 ›`. The value is adjusted by steps with arrows or typed directly,
+/// and can be an integer or a float with a fixed amount of decimals.
+#[derive(Default)]
+pub struct NumberSpinner {
+    props: Props,
+    pub states: NumberSpinnerStates,
+}
+
+impl NumberSpinner {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `number_spinner` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("number_spinner", self);
+    }
+
+    pub fn rewind(mut self, r: bool) -> Self {
+        self.attr(Attribute::Rewind, AttrValue::Flag(r));
+        self
+    }
+
+    /// Set the value. Values out of range are clamped
+    pub fn value(mut self, v: f64) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::F64(v))),
+        );
+        self
+    }
+
+    /// Set the range of values; by default values are unbounded. An inverted range is swapped
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        let (min, max) = sort_range(min, max);
+        self.attr(
+            Attribute::Custom(NUMBER_SPINNER_RANGE),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::F64(min),
+                PropValue::F64(max),
+            ))),
+        );
+        self
+    }
+
+    /// Set the step of arrows (default: 1)
+    pub fn step(mut self, step: f64) -> Self {
+        self.attr(
+            Attribute::Custom(NUMBER_SPINNER_STEP),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(step))),
+        );
+        self
+    }
+
+    /// Set the amount of decimals; with 0, the default, the spinner is in integer mode
+    pub fn precision(mut self, decimals: usize) -> Self {
+        self.attr(
+            Attribute::Custom(NUMBER_SPINNER_PRECISION),
+            AttrValue::Length(decimals),
+        );
+        self
+    }
+
+    fn get_range(&self) -> Option<(f64, f64)> {
+        self.props
+            .get(Attribute::Custom(NUMBER_SPINNER_RANGE))
+            .map(|x| {
+                let (min, max) = x.unwrap_payload().unwrap_tup2();
+                sort_range(min.unwrap_f64(), max.unwrap_f64())
+            })
+    }
+
+    fn get_step(&self) -> f64 {
+        self.props
+            .get(Attribute::Custom(NUMBER_SPINNER_STEP))
+            .map(|x| x.unwrap_payload().unwrap_one().unwrap_f64())
+            .unwrap_or(1.0)
+    }
+
+    fn get_precision(&self) -> usize {
+        self.props
+            .get_or(
+                Attribute::Custom(NUMBER_SPINNER_PRECISION),
+                AttrValue::Length(0),
+            )
+            .unwrap_length()
+    }
+
+    fn is_rewind(&self) -> bool {
+        self.props
+            .get_or(Attribute::Rewind, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    fn step_by(&mut self, delta: f64) -> CmdResult {
+        let (range, rewind, precision) = (self.get_range(), self.is_rewind(), self.get_precision());
+        match self.states.step(delta, range, rewind, precision) {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+
+    /// ### render_spinner
+    ///
+    /// Render the value between arrows, centered in `area`. Arrows are dimmed when the value can't go further
+    fn render_spinner(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let (value, value_style) = match self.states.input.is_empty() {
+            true => (
+                format!("{:.*}", self.get_precision(), self.states.value),
+                style,
+            ),
+            false => (
+                self.states.input.clone(),
+                style.add_modifier(TextModifiers::UNDERLINED),
+            ),
+        };
+        let arrow_style = |enabled: bool| match enabled {
+            true => style,
+            false => style.add_modifier(TextModifiers::DIM),
+        };
+        let (can_decrement, can_increment) = match (self.get_range(), self.is_rewind()) {
+            (Some((min, max)), false) => (self.states.value > min, self.states.value < max),
+            _ => (true, true),
+        };
+        let width = value.width() as u16 + 4;
+        let x = area.x + area.width.saturating_sub(width) / 2;
+        let y = area.y + area.height.saturating_sub(1) / 2;
+        let max_width = |x: u16| area.right().saturating_sub(x) as usize;
+        let (x, _) = buf.set_stringn(x, y, "‹ ", max_width(x), arrow_style(can_decrement));
+        let (x, _) = buf.set_stringn(x, y, value, max_width(x), value_style);
+        buf.set_stringn(x, y, " ›", max_width(x), arrow_style(can_increment));
+    }
+}
+
+impl MockComponent for NumberSpinner {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_spinner(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if matches!(
+            attr,
+            Attribute::Value
+                | Attribute::Custom(NUMBER_SPINNER_RANGE)
+                | Attribute::Custom(NUMBER_SPINNER_PRECISION)
+        ) {
+            let value = self
+                .props
+                .get(Attribute::Value)
+                .map(|x| x.unwrap_payload().unwrap_one().unwrap_f64())
+                .unwrap_or(self.states.value);
+            let value = match self.get_range() {
+                Some((min, max)) => value.clamp(min, max),
+                None => value,
+            };
+            self.states.value = round(value, self.get_precision());
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.get_precision() {
+            0 => State::One(StateValue::Isize(self.states.value as isize)),
+            _ => State::One(StateValue::F64(self.states.value)),
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Move(Direction::Right) | Cmd::Move(Direction::Up) => self.step_by(self.get_step()),
+            Cmd::Move(Direction::Left) | Cmd::Move(Direction::Down) => {
+                self.step_by(-self.get_step())
+            }
+            Cmd::Type(ch) => {
+                let float = self.get_precision() > 0;
+                self.states.type_char(ch, float);
+                CmdResult::None
+            }
+            Cmd::Delete => {
+                self.states.backspace();
+                CmdResult::None
+            }
+            Cmd::Cancel => {
+                self.states.input.clear();
+                CmdResult::None
+            }
+            Cmd::Submit if self.states.input.is_empty() => CmdResult::Submit(self.state()),
+            Cmd::Submit => {
+                let (range, precision) = (self.get_range(), self.get_precision());
+                match self.states.commit_input(range, precision) {
+                    true => CmdResult::Submit(self.state()),
+                    false => CmdResult::None,
+                }
+            }
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_number_spinner() {
+        let mut component = NumberSpinner::default()
+            .title("Threads", Alignment::Center)
+            .borders(Borders::default())
+            .range(1.0, 16.0)
+            .step(2.0)
+            .value(14.0);
+        assert_eq!(component.state(), State::One(StateValue::Isize(14)));
+        // Clamped to max
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Isize(16)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        // Rewind
+        component.attr(Attribute::Rewind, AttrValue::Flag(true));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Isize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Isize(16)))
+        );
+        // Digits
+        for ch in "1.2".chars() {
+            component.perform(Cmd::Type(ch));
+        }
+        assert_eq!(component.states.input, "12");
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Isize(12)))
+        );
+        // Out of range
+        for ch in "-3".chars() {
+            component.perform(Cmd::Type(ch));
+        }
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        component.perform(Cmd::Cancel);
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Isize(12)))
+        );
+    }
+
+    #[test]
+    fn test_components_number_spinner_range() {
+        // Inverted range is swapped
+        let mut component = NumberSpinner::default().range(10.0, 0.0).value(12.0);
+        assert_eq!(component.get_range(), Some((0.0, 10.0)));
+        assert_eq!(component.state(), State::One(StateValue::Isize(10)));
+        component.attr(
+            Attribute::Custom(NUMBER_SPINNER_RANGE),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::F64(5.0),
+                PropValue::F64(2.0),
+            ))),
+        );
+        assert_eq!(component.state(), State::One(StateValue::Isize(5)));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Isize(4)))
+        );
+        // NaN bounds are ignored
+        let mut component = NumberSpinner::default().range(f64::NAN, 3.0).value(-7.0);
+        assert_eq!(component.state(), State::One(StateValue::Isize(-7)));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Isize(-6)))
+        );
+        let mut states = NumberSpinnerStates::default();
+        assert!(states.step(5.0, Some((3.0, 1.0)), false, 0));
+        assert_eq!(states.value, 3.0);
+    }
+
+    #[test]
+    fn test_components_number_spinner_float() {
+        let mut component = NumberSpinner::default().precision(2).step(0.1).value(0.125);
+        assert_eq!(component.state(), State::One(StateValue::F64(0.13)));
+        for _ in 0..3 {
+            component.perform(Cmd::Move(Direction::Down));
+        }
+        assert_eq!(component.state(), State::One(StateValue::F64(-0.17)));
+        for ch in "-2.5-.0".chars() {
+            component.perform(Cmd::Type(ch));
+        }
+        assert_eq!(component.states.input, "-2.50");
+        component.perform(Cmd::Delete);
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::F64(-2.5)))
+        );
+    }
+
+    #[test]
+    fn test_components_number_spinner_render() {
+        let component = NumberSpinner::default()
+            .range(0.0, 10.0)
+            .precision(1)
+            .value(10.0);
+        let area = Rect::new(0, 0, 12, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_spinner(&mut buf, area, Style::default());
+        let row: String = (0..12).map(|x| buf[(x, 0)].symbol().to_string()).collect();
+        assert_eq!(row, "  ‹ 10.0 ›  ");
+        assert!(!buf[(2, 0)].modifier.contains(TextModifiers::DIM));
+        assert!(buf[(9, 0)].modifier.contains(TextModifiers::DIM));
+    }
+}
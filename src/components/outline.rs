@@ -0,0 +1,600 @@
+//! ## Outline
+//!
+//! `Outline` displays the headings of a document as a collapsible table of contents
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{OUTLINE_CMD_COLLAPSE_LEVEL, OUTLINE_CMD_EXPAND_ALL};
+use super::props::{OUTLINE_ACTIVE, TOOLTIP};
+
+/// Indentation of each level
+const INDENT: usize = 2;
+
+/// ## OutlineEntry
+///
+/// A heading of the `Outline`, with its level, starting from 1, and its title
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineEntry {
+    pub level: usize,
+    pub title: String,
+}
+
+impl OutlineEntry {
+    pub fn new<S: Into<String>>(level: usize, title: S) -> Self {
+        Self {
+            level,
+            title: title.into(),
+        }
+    }
+
+    fn to_payload(&self) -> PropPayload {
+        PropPayload::Tup2((
+            PropValue::Usize(self.level),
+            PropValue::Str(self.title.clone()),
+        ))
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        let (level, title) = payload.unwrap_tup2();
+        Self {
+            level: level.unwrap_usize(),
+            title: title.unwrap_str(),
+        }
+    }
+}
+
+// -- states
+
+/// ## OutlineStates
+///
+/// OutlineStates contains states for this component
+#[derive(Default)]
+pub struct OutlineStates {
+    /// Level of each entry
+    pub levels: Vec<usize>,
+    /// Whether the children of each entry are hidden
+    pub collapsed: Vec<bool>,
+    /// Index of the selected entry
+    pub selected: usize,
+    /// First row displayed
+    pub scroll: usize,
+}
+
+impl OutlineStates {
+    /// ### set_levels
+    ///
+    /// Set the levels of the entries, expanding all of them
+    pub fn set_levels(&mut self, levels: Vec<usize>) {
+        self.collapsed = vec![false; levels.len()];
+        self.levels = levels;
+        self.selected = self.selected.min(self.levels.len().saturating_sub(1));
+        self.scroll = 0;
+    }
+
+    /// ### has_children
+    ///
+    /// Whether the entry at `index` is followed by entries of a deeper level
+    pub fn has_children(&self, index: usize) -> bool {
+        match (self.levels.get(index), self.levels.get(index + 1)) {
+            (Some(level), Some(next)) => next > level,
+            _ => false,
+        }
+    }
+
+    /// ### parent
+    ///
+    /// Get the entry the entry at `index` is nested in
+    pub fn parent(&self, index: usize) -> Option<usize> {
+        let level = *self.levels.get(index)?;
+        (0..index).rev().find(|x| self.levels[*x] < level)
+    }
+
+    /// ### visible
+    ///
+    /// Get the indexes of the entries which aren't hidden by a collapsed entry
+    pub fn visible(&self) -> Vec<usize> {
+        let mut visible = Vec::new();
+        let mut hidden_below: Option<usize> = None;
+        for (i, level) in self.levels.iter().copied().enumerate() {
+            match hidden_below {
+                Some(parent) if level > parent => continue,
+                _ => hidden_below = None,
+            }
+            visible.push(i);
+            if self.collapsed[i] {
+                hidden_below = Some(level);
+            }
+        }
+        visible
+    }
+
+    /// ### shown
+    ///
+    /// Get the visible entry standing for the entry at `index`: itself, or the entry it is hidden in
+    pub fn shown(&self, index: usize) -> usize {
+        let mut shown = index;
+        let mut current = index;
+        while let Some(parent) = self.parent(current) {
+            if self.collapsed[parent] {
+                shown = parent;
+            }
+            current = parent;
+        }
+        shown
+    }
+
+    /// ### select
+    ///
+    /// Select the visible entry at `offset` from the selected one, clamped to the visible entries
+    pub fn select(&mut self, offset: isize) {
+        let visible = self.visible();
+        if let Some(position) = visible.iter().position(|x| *x == self.selected) {
+            let position = position
+                .saturating_add_signed(offset)
+                .min(visible.len() - 1);
+            self.selected = visible[position];
+        }
+    }
+
+    /// ### set_collapsed
+    ///
+    /// Collapse or expand the entry at `index`, if it has children. Returns whether it changed
+    pub fn set_collapsed(&mut self, index: usize, collapsed: bool) -> bool {
+        if !self.has_children(index) || self.collapsed[index] == collapsed {
+            return false;
+        }
+        self.collapsed[index] = collapsed;
+        // Keep the selection visible
+        self.selected = self.shown(self.selected);
+        true
+    }
+
+    /// ### expand_or_enter
+    ///
+    /// Expand the selected entry, or select its first child if already expanded
+    pub fn expand_or_enter(&mut self) {
+        if !self.set_collapsed(self.selected, false) && self.has_children(self.selected) {
+            self.selected += 1;
+        }
+    }
+
+    /// ### collapse_or_leave
+    ///
+    /// Collapse the selected entry, or select its parent if already collapsed or without children
+    pub fn collapse_or_leave(&mut self) {
+        if self.set_collapsed(self.selected, true) {
+            return;
+        }
+        if let Some(parent) = self.parent(self.selected) {
+            self.selected = parent;
+        }
+    }
+
+    /// ### collapse_level
+    ///
+    /// Collapse the entries of `level` and of the deeper levels, and expand the other ones, so that only the levels up
+    /// to `level` are displayed
+    pub fn collapse_level(&mut self, level: usize) {
+        for i in 0..self.levels.len() {
+            self.collapsed[i] = self.levels[i] >= level && self.has_children(i);
+        }
+        self.selected = self.shown(self.selected);
+    }
+
+    /// ### expand_all
+    ///
+    /// Expand all the entries
+    pub fn expand_all(&mut self) {
+        self.collapsed.iter_mut().for_each(|x| *x = false);
+    }
+
+    /// ### fit
+    ///
+    /// Scroll so that `row` is visible in `height` rows
+    fn fit(&mut self, row: usize, total: usize, height: usize) {
+        if row < self.scroll {
+            self.scroll = row;
+        } else if row >= self.scroll + height {
+            self.scroll = row + 1 - height;
+        }
+        self.scroll = self.scroll.min(total.saturating_sub(height));
+    }
+}
+
+// -- component
+
+/// ## Outline
+///
+/// A table of contents, displaying the headings of a document indented by level. Entries with children can be
+/// collapsed. The active entry, which should mirror the position of the viewer of the document, is set with the
+/// `OUTLINE_ACTIVE` attribute and marked; if it's hidden, the collapsed entry containing it is marked instead.
+/// Submitting returns the selected entry
+#[derive(Default)]
+pub struct Outline {
+    props: Props,
+    pub states: OutlineStates,
+}
+
+impl Outline {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `outline` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("outline", self);
+    }
+
+    /// Set the color of the selected entry and of the active entry marker
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn entries(mut self, entries: &[OutlineEntry]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(
+                entries.iter().map(|x| x.to_payload()).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the active entry
+    pub fn active(mut self, index: usize) -> Self {
+        self.attr(
+            Attribute::Custom(OUTLINE_ACTIVE),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(index))),
+        );
+        self
+    }
+
+    fn get_entries(&self) -> Vec<OutlineEntry> {
+        match self
+            .props
+            .get(Attribute::Content)
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Linked(entries)) => entries
+                .into_iter()
+                .map(OutlineEntry::from_payload)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_active(&self) -> Option<usize> {
+        match self
+            .props
+            .get(Attribute::Custom(OUTLINE_ACTIVE))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::Usize(index))) if index < self.states.levels.len() => {
+                Some(index)
+            }
+            _ => None,
+        }
+    }
+
+    /// ### render_entries
+    ///
+    /// Render the visible entries in `area`, scrolled to the selected one
+    fn render_entries(&mut self, buf: &mut Buffer, area: Rect, style: Style, highlight: Style) {
+        let entries = self.get_entries();
+        let visible = self.states.visible();
+        let active = self.get_active().map(|x| self.states.shown(x));
+        let min_level = entries.iter().map(|x| x.level).min().unwrap_or(0);
+        if let Some(row) = visible.iter().position(|x| *x == self.states.selected) {
+            self.states.fit(row, visible.len(), area.height as usize);
+        }
+        let marker_style = match highlight.fg {
+            Some(color) => style.fg(color),
+            None => style,
+        };
+        for (y, i) in (area.y..area.bottom()).zip(visible.into_iter().skip(self.states.scroll)) {
+            let entry = &entries[i];
+            let (marker, title_style) = match Some(i) == active {
+                true => ("▌", style.add_modifier(TextModifiers::BOLD)),
+                false => (" ", style),
+            };
+            let (x, _) = buf.set_stringn(area.x, y, marker, area.width as usize, marker_style);
+            let symbol = match (self.states.has_children(i), self.states.collapsed[i]) {
+                (true, true) => "▸ ",
+                (true, false) => "▾ ",
+                (false, _) => "  ",
+            };
+            let indent = " ".repeat((entry.level - min_level) * INDENT);
+            let (x, _) = buf.set_stringn(
+                x,
+                y,
+                format!("{indent}{symbol}"),
+                area.right().saturating_sub(x) as usize,
+                style,
+            );
+            buf.set_stringn(
+                x,
+                y,
+                &entry.title,
+                area.right().saturating_sub(x) as usize,
+                title_style,
+            );
+            if i == self.states.selected {
+                let start = area.x + 1;
+                buf.set_style(
+                    Rect::new(start, y, area.right().saturating_sub(start), 1),
+                    highlight,
+                );
+            }
+        }
+    }
+}
+
+impl MockComponent for Outline {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let mut highlight = Style::default();
+            if let Some(color) = self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color())
+            {
+                highlight = highlight.fg(color);
+            }
+            if focus {
+                highlight = highlight.add_modifier(TextModifiers::REVERSED);
+            }
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_entries(render.buffer_mut(), inner, style, highlight);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if attr == Attribute::Content {
+            let levels = self.get_entries().into_iter().map(|x| x.level).collect();
+            self.states.set_levels(levels);
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.levels.is_empty() {
+            true => State::None,
+            false => State::One(StateValue::Usize(self.states.selected)),
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        if self.states.levels.is_empty() {
+            return CmdResult::None;
+        }
+        let prev = self.states.selected;
+        let selected = self.states.selected;
+        match cmd {
+            Cmd::Move(Direction::Down) => self.states.select(1),
+            Cmd::Move(Direction::Up) => self.states.select(-1),
+            Cmd::Scroll(Direction::Down) => {
+                let step = self
+                    .props
+                    .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+                    .unwrap_length();
+                self.states.select(step as isize);
+            }
+            Cmd::Scroll(Direction::Up) => {
+                let step = self
+                    .props
+                    .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+                    .unwrap_length();
+                self.states.select(-(step as isize));
+            }
+            Cmd::GoTo(Position::Begin) => self.states.select(isize::MIN),
+            Cmd::GoTo(Position::End) => self.states.select(isize::MAX),
+            Cmd::Move(Direction::Right) => self.states.expand_or_enter(),
+            Cmd::Move(Direction::Left) => self.states.collapse_or_leave(),
+            Cmd::Toggle => {
+                let collapsed = self.states.collapsed[selected];
+                self.states.set_collapsed(selected, !collapsed);
+            }
+            Cmd::Custom(OUTLINE_CMD_COLLAPSE_LEVEL) => {
+                self.states.collapse_level(self.states.levels[selected])
+            }
+            Cmd::Custom(OUTLINE_CMD_EXPAND_ALL) => self.states.expand_all(),
+            Cmd::Submit => return CmdResult::Submit(self.state()),
+            _ => {}
+        }
+        match self.states.selected != prev {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn entries() -> Vec<OutlineEntry> {
+        vec![
+            OutlineEntry::new(1, "Intro"),
+            OutlineEntry::new(2, "Goals"),
+            OutlineEntry::new(3, "Scope"),
+            OutlineEntry::new(2, "Setup"),
+            OutlineEntry::new(1, "Usage"),
+        ]
+    }
+
+    #[test]
+    fn test_components_outline_states() {
+        let mut states = OutlineStates::default();
+        states.set_levels(vec![1, 2, 3, 2, 1]);
+        assert!(states.has_children(0));
+        assert!(!states.has_children(3));
+        assert_eq!(states.parent(2), Some(1));
+        assert_eq!(states.parent(3), Some(0));
+        assert_eq!(states.parent(4), None);
+        assert!(states.set_collapsed(1, true));
+        assert!(!states.set_collapsed(3, true));
+        assert_eq!(states.visible(), vec![0, 1, 3, 4]);
+        assert_eq!(states.shown(2), 1);
+        states.collapse_level(1);
+        assert_eq!(states.visible(), vec![0, 4]);
+        assert_eq!(states.shown(2), 0);
+        states.expand_all();
+        assert_eq!(states.visible(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_components_outline() {
+        let mut component = Outline::default().entries(&entries()).active(2);
+        let area = Rect::new(0, 0, 14, 5);
+        let mut buf = Buffer::empty(area);
+        component.render_entries(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![
+                " ▾ Intro",
+                "   ▾ Goals",
+                "▌      Scope",
+                "     Setup",
+                "   Usage",
+            ]
+        );
+        // Navigate
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.states.collapsed,
+            vec![false, true, false, false, false]
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(3)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        // The active entry is hidden in its collapsed ancestor
+        let mut buf = Buffer::empty(area);
+        component.render_entries(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![" ▾ Intro", "▌  ▸ Goals", "     Setup", "   Usage", ""]
+        );
+        // Collapse the level of the selected entry
+        component.perform(Cmd::Custom(OUTLINE_CMD_COLLAPSE_LEVEL));
+        assert_eq!(component.states.visible(), vec![0, 4]);
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(0)))
+        );
+    }
+}
@@ -0,0 +1,450 @@
+//! ## Paginator
+//!
+//! `Paginator` displays the page buttons of a paged view, such as a table or a list
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{PAGINATOR_PAGES, PAGINATOR_SIBLINGS, TOOLTIP};
+
+/// Symbols displayed before and after the page buttons
+const PREV: &str = "«";
+const NEXT: &str = "»";
+const ELLIPSIS: &str = "…";
+
+/// ### page_buttons
+///
+/// Get the page buttons displayed with the page `center` surrounded by `siblings` pages on each side; the first and
+/// the last pages are always displayed, and the gaps are replaced by an ellipsis (`None`). The number of buttons
+/// doesn't change while moving through the pages
+fn page_buttons(pages: usize, center: usize, siblings: usize) -> Vec<Option<usize>> {
+    // First, last, center, siblings and two ellipsis
+    let slots = 2 * siblings + 5;
+    if pages <= slots {
+        return (0..pages).map(Some).collect();
+    }
+    let last = pages - 1;
+    // Buttons on the side without ellipsis, the first or the last one excluded
+    let side = slots - 2;
+    if center.saturating_sub(siblings) <= 2 {
+        (0..side).map(Some).chain([None, Some(last)]).collect()
+    } else if center + siblings >= last - 2 {
+        [Some(0), None]
+            .into_iter()
+            .chain((pages - side..pages).map(Some))
+            .collect()
+    } else {
+        [Some(0), None]
+            .into_iter()
+            .chain((center - siblings..=center + siblings).map(Some))
+            .chain([None, Some(last)])
+            .collect()
+    }
+}
+
+// -- states
+
+/// ## PaginatorStates
+///
+/// PaginatorStates contains states for this component
+#[derive(Default)]
+pub struct PaginatorStates {
+    /// Index of the current page
+    pub current: usize,
+    /// Index of the page under the cursor
+    pub cursor: usize,
+    /// Number of pages
+    pub pages: usize,
+}
+
+impl PaginatorStates {
+    /// ### set_pages
+    ///
+    /// Set the number of pages, keeping the current page and the cursor in range
+    pub fn set_pages(&mut self, pages: usize) {
+        self.pages = pages;
+        self.current = self.current.min(pages.saturating_sub(1));
+        self.cursor = self.cursor.min(pages.saturating_sub(1));
+    }
+
+    /// ### select
+    ///
+    /// Make the page at `index`, clamped to the pages, the current one, moving the cursor on it
+    pub fn select(&mut self, index: usize) {
+        self.current = index.min(self.pages.saturating_sub(1));
+        self.cursor = self.current;
+    }
+
+    /// ### next_page
+    ///
+    /// Move the cursor to the next page
+    pub fn next_page(&mut self) {
+        if self.cursor + 1 < self.pages {
+            self.cursor += 1;
+        }
+    }
+
+    /// ### prev_page
+    ///
+    /// Move the cursor to the previous page
+    pub fn prev_page(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+}
+
+// -- component
+
+/// ## Paginator
+///
+/// A row of page buttons, such as `« 1 2 3 4 5 … 10 »`, where the cursor is moved through the pages and `Cmd::Submit`
+/// makes the page under the cursor the current one. When there are many pages, only the first and the last pages and
+/// the ones around the cursor are displayed
+#[derive(Default)]
+pub struct Paginator {
+    props: Props,
+    pub states: PaginatorStates,
+}
+
+impl Paginator {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `paginator` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("paginator", self);
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn alignment(mut self, a: Alignment) -> Self {
+        self.attr(Attribute::Alignment, AttrValue::Alignment(a));
+        self
+    }
+
+    /// Set the color of the page under the cursor
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Set the number of pages
+    pub fn pages(mut self, pages: usize) -> Self {
+        self.attr(Attribute::Custom(PAGINATOR_PAGES), AttrValue::Length(pages));
+        self
+    }
+
+    /// Set the number of pages displayed on each side of the page under the cursor (default: 1)
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.attr(
+            Attribute::Custom(PAGINATOR_SIBLINGS),
+            AttrValue::Length(siblings),
+        );
+        self
+    }
+
+    /// Set the index of the current page
+    pub fn current_page(mut self, index: usize) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(index))),
+        );
+        self
+    }
+
+    /// ### render_buttons
+    ///
+    /// Render the page buttons in the first row of `area`
+    fn render_buttons(&self, buf: &mut Buffer, area: Rect, style: Style, highlight_style: Style) {
+        if area.height == 0 || self.states.pages == 0 {
+            return;
+        }
+        let siblings = self
+            .props
+            .get_or(Attribute::Custom(PAGINATOR_SIBLINGS), AttrValue::Length(1))
+            .unwrap_length();
+        let states = &self.states;
+        let disabled_style = style.add_modifier(TextModifiers::DIM);
+        let mut buttons: Vec<(String, Style)> = vec![(
+            PREV.to_string(),
+            match states.cursor == 0 {
+                true => disabled_style,
+                false => style,
+            },
+        )];
+        buttons.extend(
+            page_buttons(states.pages, states.cursor, siblings)
+                .into_iter()
+                .map(|page| match page {
+                    Some(page) if page == states.cursor => {
+                        ((page + 1).to_string(), highlight_style)
+                    }
+                    Some(page) if page == states.current => (
+                        (page + 1).to_string(),
+                        style.add_modifier(TextModifiers::BOLD | TextModifiers::UNDERLINED),
+                    ),
+                    Some(page) => ((page + 1).to_string(), style),
+                    None => (ELLIPSIS.to_string(), style),
+                }),
+        );
+        buttons.push((
+            NEXT.to_string(),
+            match states.cursor + 1 >= states.pages {
+                true => disabled_style,
+                false => style,
+            },
+        ));
+        let width = buttons.iter().map(|(x, _)| x.width()).sum::<usize>() + buttons.len() - 1;
+        let width = width as u16;
+        let mut x = match self
+            .props
+            .get_or(
+                Attribute::Alignment,
+                AttrValue::Alignment(Alignment::Center),
+            )
+            .unwrap_alignment()
+        {
+            Alignment::Left => area.x,
+            Alignment::Center => area.x + area.width.saturating_sub(width) / 2,
+            Alignment::Right => area.x + area.width.saturating_sub(width),
+        };
+        for (i, (text, button_style)) in buttons.iter().enumerate() {
+            if i > 0 {
+                x += 1;
+            }
+            if x >= area.right() {
+                break;
+            }
+            (x, _) = buf.set_stringn(
+                x,
+                area.y,
+                text,
+                area.right().saturating_sub(x) as usize,
+                *button_style,
+            );
+        }
+    }
+}
+
+impl MockComponent for Paginator {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let mut highlight_style = match self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color())
+            {
+                Some(color) => style.fg(color),
+                None => style,
+            };
+            if focus {
+                highlight_style = highlight_style.add_modifier(TextModifiers::REVERSED);
+            }
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_buttons(render.buffer_mut(), inner, style, highlight_style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Custom(PAGINATOR_PAGES) => {
+                let pages = self
+                    .props
+                    .get_or(attr, AttrValue::Length(0))
+                    .unwrap_length();
+                self.states.set_pages(pages);
+            }
+            Attribute::Value => {
+                if let Some(PropPayload::One(PropValue::Usize(index))) =
+                    self.props.get(attr).map(|x| x.unwrap_payload())
+                {
+                    self.states.select(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.pages {
+            0 => State::None,
+            _ => State::One(StateValue::Usize(self.states.current)),
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Move(Direction::Right) => self.states.next_page(),
+            Cmd::Move(Direction::Left) => self.states.prev_page(),
+            Cmd::GoTo(Position::Begin) => self.states.cursor = 0,
+            Cmd::GoTo(Position::End) => self.states.cursor = self.states.pages.saturating_sub(1),
+            Cmd::Submit if self.states.pages > 0 => {
+                self.states.current = self.states.cursor;
+                return CmdResult::Submit(self.state());
+            }
+            _ => {}
+        }
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn row(buf: &Buffer) -> String {
+        (0..buf.area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_components_paginator_buttons() {
+        assert_eq!(
+            page_buttons(4, 0, 1),
+            vec![Some(0), Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            page_buttons(10, 3, 1),
+            vec![Some(0), Some(1), Some(2), Some(3), Some(4), None, Some(9)]
+        );
+        assert_eq!(
+            page_buttons(10, 4, 1),
+            vec![Some(0), None, Some(3), Some(4), Some(5), None, Some(9)]
+        );
+        assert_eq!(
+            page_buttons(10, 6, 1),
+            vec![Some(0), None, Some(5), Some(6), Some(7), Some(8), Some(9)]
+        );
+        assert_eq!(
+            page_buttons(20, 10, 0),
+            vec![Some(0), None, Some(10), None, Some(19)]
+        );
+    }
+
+    #[test]
+    fn test_components_paginator() {
+        let mut component = Paginator::default().pages(10).current_page(12);
+        assert_eq!(component.state(), State::One(StateValue::Usize(9)));
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::None
+        );
+        component.perform(Cmd::Move(Direction::Left));
+        component.perform(Cmd::Move(Direction::Right));
+        // Moving the cursor doesn't change the current page
+        assert_eq!(component.state(), State::One(StateValue::Usize(9)));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(1)))
+        );
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.cursor, 9);
+        // Without pages
+        component.attr(Attribute::Custom(PAGINATOR_PAGES), AttrValue::Length(0));
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_paginator_render() {
+        let mut component = Paginator::default().pages(10);
+        let area = Rect::new(0, 0, 20, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_buttons(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(row(&buf), " « 1 2 3 4 5 … 10 » ");
+        component.perform(Cmd::GoTo(Position::End));
+        let mut buf = Buffer::empty(area);
+        component.render_buttons(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(row(&buf), " « 1 … 6 7 8 9 10 » ");
+    }
+}
@@ -1,24 +1,71 @@
 //! ## Paragraph
 //!
 //! `Paragraph` represents a read-only text component inside a container, the text is wrapped inside the container automatically
-//! using the [textwrap](https://docs.rs/textwrap/0.13.4/textwrap/) crate.
+//! with `utils::wrap_text`, using the [textwrap](https://docs.rs/textwrap/0.16.1/textwrap/) crate.
 //! The textarea supports multi-style spans.
-//! The component is not scrollable and doesn't handle any input. The text must then fit into the area.
-//! If you want scroll support, use a `Textarea` instead.
+//! When the text doesn't fit into the area, it can be scrolled by lines and, if the lines aren't wrapped, by columns.
 
-use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
     TextModifiers, TextSpan,
 };
 use tuirealm::ratatui::text::Line as Spans;
-use tuirealm::ratatui::{
-    layout::Rect,
-    text::Span,
-    widgets::{Paragraph as TuiParagraph, Wrap},
-};
+use tuirealm::ratatui::{layout::Rect, text::Span, widgets::Paragraph as TuiParagraph};
 use tuirealm::{Frame, MockComponent, State};
 
+use crate::theme::Theme;
+use crate::utils::{
+    get_title_style, is_disabled, justify_line, render_disabled, render_tooltip,
+    wrap_text_indented, FocusStyles,
+};
+
+// -- Props
+
+use super::props::{
+    PARAGRAPH_HORIZONTAL_SCROLL, PARAGRAPH_INDENT, PARAGRAPH_JUSTIFY, PARAGRAPH_SCROLL,
+    PARAGRAPH_SCROLL_INDICATOR, TOOLTIP,
+};
+
+// -- States
+
+/// ## ParagraphStates
+///
+/// ParagraphStates contains states for this component
+#[derive(Default)]
+pub struct ParagraphStates {
+    /// First column of the text displayed
+    pub offset_x: u16,
+    /// First line of the text displayed
+    pub offset_y: u16,
+    /// Maximum first column, according to the last render
+    pub max_x: u16,
+    /// Maximum first line, according to the last render
+    pub max_y: u16,
+}
+
+impl ParagraphStates {
+    /// ### set_bounds
+    ///
+    /// Set the maximum offsets to display `content` in `viewport`, keeping the offsets within them
+    pub fn set_bounds(&mut self, content: (u16, u16), viewport: (u16, u16)) {
+        self.max_x = content.0.saturating_sub(viewport.0);
+        self.max_y = content.1.saturating_sub(viewport.1);
+        self.scroll_by(0, 0);
+    }
+
+    /// ### scroll_by
+    ///
+    /// Move the offsets by `x` columns and `y` lines, within the bounds
+    pub fn scroll_by(&mut self, x: isize, y: isize) {
+        let scroll = |offset: u16, delta: isize, max: u16| {
+            ((offset as isize).saturating_add(delta)).clamp(0, max as isize) as u16
+        };
+        self.offset_x = scroll(self.offset_x, x, self.max_x);
+        self.offset_y = scroll(self.offset_y, y, self.max_y);
+    }
+}
+
 // -- Component
 
 /// ## Paragraph
@@ -27,6 +74,7 @@ use tuirealm::{Frame, MockComponent, State};
 #[derive(Default)]
 pub struct Paragraph {
     props: Props,
+    pub states: ParagraphStates,
 }
 
 impl Paragraph {
@@ -60,6 +108,28 @@ impl Paragraph {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `paragraph` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("paragraph", self);
+    }
+
     pub fn text(mut self, s: &[TextSpan]) -> Self {
         self.attr(
             Attribute::Text,
@@ -74,30 +144,126 @@ impl Paragraph {
         self.attr(Attribute::TextWrap, AttrValue::Flag(wrap));
         self
     }
+
+    /// Set the number of lines scrolled by `Cmd::Scroll` (default: 8)
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    /// Don't wrap the lines, which can then be scrolled horizontally
+    pub fn horizontal_scroll(mut self, horizontal: bool) -> Self {
+        self.attr(
+            Attribute::Custom(PARAGRAPH_HORIZONTAL_SCROLL),
+            AttrValue::Flag(horizontal),
+        );
+        self
+    }
+
+    /// Stretch the wrapped lines to the width of the area, by widening the spaces between words. The last line of
+    /// each span follows the alignment
+    pub fn justify(mut self, justify: bool) -> Self {
+        self.attr(
+            Attribute::Custom(PARAGRAPH_JUSTIFY),
+            AttrValue::Flag(justify),
+        );
+        self
+    }
+
+    /// Indent the first line of each span by `first` columns, and the following lines by `hanging` columns
+    pub fn indent(mut self, first: u16, hanging: u16) -> Self {
+        self.attr(
+            Attribute::Custom(PARAGRAPH_INDENT),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::U16(first),
+                PropValue::U16(hanging),
+            ))),
+        );
+        self
+    }
+
+    /// Set the first column and the first line displayed
+    pub fn scroll(mut self, x: u16, y: u16) -> Self {
+        self.attr(
+            Attribute::Custom(PARAGRAPH_SCROLL),
+            AttrValue::Payload(PropPayload::Tup2((PropValue::U16(x), PropValue::U16(y)))),
+        );
+        self
+    }
+
+    /// Display the first line shown and the number of lines, as `3/42`, at the bottom right of the block
+    pub fn scroll_indicator(mut self, indicator: bool) -> Self {
+        self.attr(
+            Attribute::Custom(PARAGRAPH_SCROLL_INDICATOR),
+            AttrValue::Flag(indicator),
+        );
+        self
+    }
+
+    /// ### lines
+    ///
+    /// Get the lines of the text, wrapped to `width` unless `horizontal`. Each span of the text is a line
+    fn lines(&self, width: u16, horizontal: bool, trim: bool) -> Vec<Spans<'static>> {
+        let spans = match self.props.get(Attribute::Text).map(|x| x.unwrap_payload()) {
+            Some(PropPayload::Vec(spans)) => spans,
+            _ => return Vec::new(),
+        };
+        let width = width as usize;
+        let (first, hanging) = match self
+            .props
+            .get(Attribute::Custom(PARAGRAPH_INDENT))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Tup2((PropValue::U16(first), PropValue::U16(hanging)))) => {
+                (first as usize, hanging as usize)
+            }
+            _ => (0, 0),
+        };
+        // Leave at least a column for the text
+        let first = " ".repeat(first.min(width.saturating_sub(1)));
+        let hanging = " ".repeat(hanging.min(width.saturating_sub(1)));
+        let justify = self
+            .props
+            .get_or(Attribute::Custom(PARAGRAPH_JUSTIFY), AttrValue::Flag(false))
+            .unwrap_flag();
+        let mut lines = Vec::with_capacity(spans.len());
+        for span in spans.into_iter().map(|x| x.unwrap_text_span()) {
+            let (fg, bg, modifiers) = crate::utils::use_or_default_styles(&self.props, &span);
+            let style = Style::default().add_modifier(modifiers).fg(fg).bg(bg);
+            if horizontal || width == 0 {
+                lines.push(Spans::from(Span::styled(
+                    format!("{first}{}", span.content),
+                    style,
+                )));
+                continue;
+            }
+            let wrapped = wrap_text_indented(&span.content, width, first.len(), hanging.len());
+            let last = wrapped.len().saturating_sub(1);
+            for (i, line) in wrapped.iter().enumerate() {
+                let indent = match i {
+                    0 => &first,
+                    _ => &hanging,
+                };
+                let line = line.strip_prefix(indent.as_str()).unwrap_or(line);
+                let line = match trim {
+                    true => line.trim_start(),
+                    false => line,
+                };
+                let line = match justify && i < last {
+                    true => justify_line(line, width - indent.len()),
+                    false => line.to_string(),
+                };
+                lines.push(Spans::from(Span::styled(format!("{indent}{line}"), style)));
+            }
+        }
+        lines
+    }
 }
 
 impl MockComponent for Paragraph {
     fn view(&mut self, render: &mut Frame, area: Rect) {
         // Make a Span
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
-            // Make text items
-            let text: Vec<Spans> = match self.props.get(Attribute::Text).map(|x| x.unwrap_payload())
-            {
-                Some(PropPayload::Vec(spans)) => spans
-                    .iter()
-                    .cloned()
-                    .map(|x| x.unwrap_text_span())
-                    .map(|x| {
-                        let (fg, bg, modifiers) =
-                            crate::utils::use_or_default_styles(&self.props, &x);
-                        Spans::from(vec![Span::styled(
-                            x.content,
-                            Style::default().add_modifier(modifiers).fg(fg).bg(bg),
-                        )])
-                    })
-                    .collect(),
-                _ => Vec::new(),
-            };
             // Text properties
             let alignment: Alignment = self
                 .props
@@ -128,36 +294,115 @@ impl MockComponent for Paragraph {
                 .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
                 .unwrap_borders();
             let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
-            let div = crate::utils::get_block(borders, title, true, None);
+            let styles = FocusStyles::display(
+                &self.props,
+                Style::default()
+                    .fg(foreground)
+                    .bg(background)
+                    .add_modifier(modifiers),
+            );
+            let div = styles
+                .block(borders, title)
+                .title_style(get_title_style(&self.props));
+            let indicator = self
+                .props
+                .get_or(
+                    Attribute::Custom(PARAGRAPH_SCROLL_INDICATOR),
+                    AttrValue::Flag(false),
+                )
+                .unwrap_flag();
+            // The indicator takes the bottom row if there's no bottom border
+            let inner = match indicator {
+                true => div.clone().title_bottom(Spans::default()).inner(area),
+                false => div.inner(area),
+            };
+            let horizontal = self
+                .props
+                .get_or(
+                    Attribute::Custom(PARAGRAPH_HORIZONTAL_SCROLL),
+                    AttrValue::Flag(false),
+                )
+                .unwrap_flag();
+            let text = self.lines(inner.width, horizontal, trim);
+            let height = u16::try_from(text.len()).unwrap_or(u16::MAX);
+            let width = text.iter().map(Spans::width).max().unwrap_or(0);
+            self.states.set_bounds(
+                (u16::try_from(width).unwrap_or(u16::MAX), height),
+                (inner.width, inner.height),
+            );
+            let div = match indicator {
+                true => div.title_bottom(
+                    Spans::from(format!(
+                        " {}/{} ",
+                        (self.states.offset_y + 1).min(height),
+                        height
+                    ))
+                    .right_aligned(),
+                ),
+                false => div,
+            };
             render.render_widget(
                 TuiParagraph::new(text)
                     .block(div)
-                    .style(
-                        Style::default()
-                            .fg(foreground)
-                            .bg(background)
-                            .add_modifier(modifiers),
-                    )
+                    .style(styles.style)
                     .alignment(alignment)
-                    .wrap(Wrap { trim }),
+                    .scroll((self.states.offset_y, self.states.offset_x)),
                 area,
             );
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
-        self.props.get(attr)
+        match attr {
+            Attribute::Custom(PARAGRAPH_SCROLL) => Some(AttrValue::Payload(PropPayload::Tup2((
+                PropValue::U16(self.states.offset_x),
+                PropValue::U16(self.states.offset_y),
+            )))),
+            attr => self.props.get(attr),
+        }
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
-        self.props.set(attr, value)
+        match (attr, value) {
+            (
+                Attribute::Custom(PARAGRAPH_SCROLL),
+                AttrValue::Payload(PropPayload::Tup2((PropValue::U16(x), PropValue::U16(y)))),
+            ) => {
+                // Kept within the bounds on the next render
+                self.states.offset_x = x;
+                self.states.offset_y = y;
+            }
+            (attr, value) => self.props.set(attr, value),
+        }
     }
 
     fn state(&self) -> State {
         State::None
     }
 
-    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length() as isize;
+        match cmd {
+            Cmd::Move(Direction::Up) => self.states.scroll_by(0, -1),
+            Cmd::Move(Direction::Down) => self.states.scroll_by(0, 1),
+            Cmd::Move(Direction::Left) => self.states.scroll_by(-1, 0),
+            Cmd::Move(Direction::Right) => self.states.scroll_by(1, 0),
+            Cmd::Scroll(Direction::Up) => self.states.scroll_by(0, -step),
+            Cmd::Scroll(Direction::Down) => self.states.scroll_by(0, step),
+            Cmd::Scroll(Direction::Left) => self.states.scroll_by(-step, 0),
+            Cmd::Scroll(Direction::Right) => self.states.scroll_by(step, 0),
+            Cmd::GoTo(Position::Begin) => self.states.scroll_by(isize::MIN, isize::MIN),
+            Cmd::GoTo(Position::End) => self.states.scroll_by(0, isize::MAX),
+            _ => {}
+        }
         CmdResult::None
     }
 }
@@ -186,4 +431,101 @@ mod tests {
         // Get value
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_components_paragraph_scroll() {
+        use crate::testing::{assert_lines, render};
+        use tuirealm::props::BorderSides;
+
+        let mut component = Paragraph::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .text(&[
+                TextSpan::from("one"),
+                TextSpan::from("two"),
+                TextSpan::from("three four"),
+            ]);
+        // The first line is taken by the title; the last span is wrapped
+        assert_lines(&render(&mut component, 6, 3), &["", "one", "two"]);
+        assert_eq!(component.states.max_y, 2);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        assert_lines(&render(&mut component, 6, 3), &["", "two", "three"]);
+        component.perform(Cmd::GoTo(Position::End));
+        component.perform(Cmd::Move(Direction::Down));
+        assert_lines(&render(&mut component, 6, 3), &["", "three", "four"]);
+        assert_eq!(
+            component.query(Attribute::Custom(PARAGRAPH_SCROLL)),
+            Some(AttrValue::Payload(PropPayload::Tup2((
+                PropValue::U16(0),
+                PropValue::U16(2)
+            ))))
+        );
+        // Lines can't be scrolled horizontally when wrapped
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.offset_x, 0);
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(component.states.offset_y, 0);
+        // Offsets set through attributes are kept within the bounds on render
+        component = component.scroll(0, 10);
+        assert_lines(&render(&mut component, 6, 3), &["", "three", "four"]);
+        // Disabled components don't scroll
+        component = component.disabled(true);
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(component.states.offset_y, 2);
+    }
+
+    #[test]
+    fn test_components_paragraph_horizontal_scroll() {
+        use crate::testing::{assert_lines, render};
+        use tuirealm::props::BorderSides;
+
+        let mut component = Paragraph::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .text(&[TextSpan::from("hello world")])
+            .horizontal_scroll(true)
+            .scroll_indicator(true);
+        // The indicator takes the last line
+        assert_lines(&render(&mut component, 8, 3), &["", "hello wo", "    1/1"]);
+        component.perform(Cmd::Scroll(Direction::Right));
+        assert_eq!(component.states.offset_x, 3);
+        assert_lines(&render(&mut component, 8, 3), &["", "lo world", "    1/1"]);
+        component.perform(Cmd::Move(Direction::Left));
+        assert_lines(&render(&mut component, 8, 3), &["", "llo worl", "    1/1"]);
+    }
+
+    #[test]
+    fn test_components_paragraph_justify() {
+        use crate::testing::{assert_lines, render};
+        use tuirealm::props::BorderSides;
+
+        let mut component = Paragraph::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .text(&[TextSpan::from("a b c d e"), TextSpan::from("f g")])
+            .justify(true);
+        // The last line of each span isn't justified
+        assert_lines(&render(&mut component, 6, 4), &["", "a  b c", "d e", "f g"]);
+    }
+
+    #[test]
+    fn test_components_paragraph_indent() {
+        use crate::testing::{assert_lines, render};
+        use tuirealm::props::BorderSides;
+
+        let mut component = Paragraph::default()
+            .borders(Borders::default().sides(BorderSides::NONE))
+            .text(&[TextSpan::from("aa bb cc dd"), TextSpan::from("ee")])
+            .indent(2, 4);
+        assert_lines(
+            &render(&mut component, 8, 5),
+            &["", "  aa bb", "    cc", "    dd", "  ee"],
+        );
+        // Indents are applied before justifying
+        component = component.justify(true).indent(2, 2);
+        assert_lines(
+            &render(&mut component, 8, 4),
+            &["", "  aa  bb", "  cc dd", "  ee"],
+        );
+    }
 }
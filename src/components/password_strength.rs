@@ -0,0 +1,524 @@
+//! ## PasswordStrength
+//!
+//! `PasswordStrength` is a password field which displays the strength of the password while it is typed
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, InputType, PropPayload, PropValue, Props,
+    Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use super::input::InputStates;
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{PASSWORD_STRENGTH_RULES, PASSWORD_STRENGTH_THRESHOLDS, TOOLTIP};
+
+/// Names and colors of the strength levels, from the weakest
+const LEVELS: [(&str, Color); 4] = [
+    ("Weak", Color::Red),
+    ("Fair", Color::Yellow),
+    ("Good", Color::LightGreen),
+    ("Strong", Color::Green),
+];
+/// Default number of rules to satisfy to reach each level above the weakest
+const DEFAULT_THRESHOLDS: [usize; 3] = [3, 4, 5];
+
+/// ## PasswordRule
+///
+/// A rule the password should satisfy; the more rules are satisfied, the stronger the password is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordRule {
+    /// At least this number of characters
+    MinLength(usize),
+    /// At least a lowercase letter
+    Lowercase,
+    /// At least an uppercase letter
+    Uppercase,
+    /// At least a digit
+    Digit,
+    /// At least a character which is neither a letter nor a digit
+    Symbol,
+}
+
+impl PasswordRule {
+    /// Rules used when none are set
+    const DEFAULT: [Self; 5] = [
+        Self::MinLength(8),
+        Self::Lowercase,
+        Self::Uppercase,
+        Self::Digit,
+        Self::Symbol,
+    ];
+
+    /// ### check
+    ///
+    /// Checks whether `password` satisfies the rule
+    pub fn check(&self, password: &[char]) -> bool {
+        match self {
+            Self::MinLength(len) => password.len() >= *len,
+            Self::Lowercase => password.iter().any(|x| x.is_lowercase()),
+            Self::Uppercase => password.iter().any(|x| x.is_uppercase()),
+            Self::Digit => password.iter().any(|x| x.is_numeric()),
+            Self::Symbol => password.iter().any(|x| !x.is_alphanumeric()),
+        }
+    }
+
+    /// ### description
+    ///
+    /// Get the text displayed in the checklist
+    pub fn description(&self) -> String {
+        match self {
+            Self::MinLength(len) => format!("At least {len} characters"),
+            Self::Lowercase => String::from("A lowercase letter"),
+            Self::Uppercase => String::from("An uppercase letter"),
+            Self::Digit => String::from("A digit"),
+            Self::Symbol => String::from("A symbol"),
+        }
+    }
+
+    fn to_payload(self) -> PropPayload {
+        let (kind, len) = match self {
+            Self::MinLength(len) => (0, len),
+            Self::Lowercase => (1, 0),
+            Self::Uppercase => (2, 0),
+            Self::Digit => (3, 0),
+            Self::Symbol => (4, 0),
+        };
+        PropPayload::Tup2((PropValue::U8(kind), PropValue::Usize(len)))
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        let (kind, len) = payload.unwrap_tup2();
+        match kind.unwrap_u8() {
+            0 => Self::MinLength(len.unwrap_usize()),
+            1 => Self::Lowercase,
+            2 => Self::Uppercase,
+            3 => Self::Digit,
+            _ => Self::Symbol,
+        }
+    }
+}
+
+// -- component
+
+/// ## PasswordStrength
+///
+/// A masked password field, with a meter displaying the strength of the password and a checklist of the rules it
+/// satisfies. The strength level goes from 0 (weak) to 3 (strong), according to the number of rules satisfied
+#[derive(Default)]
+pub struct PasswordStrength {
+    props: Props,
+    pub states: InputStates,
+}
+
+impl PasswordStrength {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `password_strength` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("password_strength", self);
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn input_len(mut self, ilen: usize) -> Self {
+        self.attr(Attribute::InputLength, AttrValue::Length(ilen));
+        self
+    }
+
+    /// Set the character displayed in place of the password characters (default: `*`)
+    pub fn mask(mut self, ch: char) -> Self {
+        self.attr(
+            Attribute::InputType,
+            AttrValue::InputType(InputType::Password(ch)),
+        );
+        self
+    }
+
+    /// Set the rules the password should satisfy (default: 8 characters, lowercase, uppercase, digit and symbol)
+    pub fn rules(mut self, rules: &[PasswordRule]) -> Self {
+        self.attr(
+            Attribute::Custom(PASSWORD_STRENGTH_RULES),
+            AttrValue::Payload(PropPayload::Linked(
+                rules.iter().map(|x| x.to_payload()).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the number of rules to satisfy to reach the fair, good and strong levels (default: 3, 4 and 5)
+    pub fn thresholds(mut self, fair: usize, good: usize, strong: usize) -> Self {
+        self.attr(
+            Attribute::Custom(PASSWORD_STRENGTH_THRESHOLDS),
+            AttrValue::Payload(PropPayload::Vec(vec![
+                PropValue::Usize(fair),
+                PropValue::Usize(good),
+                PropValue::Usize(strong),
+            ])),
+        );
+        self
+    }
+
+    fn get_rules(&self) -> Vec<PasswordRule> {
+        match self
+            .props
+            .get(Attribute::Custom(PASSWORD_STRENGTH_RULES))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Linked(rules)) => {
+                rules.into_iter().map(PasswordRule::from_payload).collect()
+            }
+            _ => PasswordRule::DEFAULT.to_vec(),
+        }
+    }
+
+    fn get_thresholds(&self) -> Vec<usize> {
+        match self
+            .props
+            .get(Attribute::Custom(PASSWORD_STRENGTH_THRESHOLDS))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(thresholds)) => {
+                thresholds.into_iter().map(|x| x.unwrap_usize()).collect()
+            }
+            _ => DEFAULT_THRESHOLDS.to_vec(),
+        }
+    }
+
+    fn get_input_type(&self) -> InputType {
+        self.props
+            .get_or(
+                Attribute::InputType,
+                AttrValue::InputType(InputType::Password('*')),
+            )
+            .unwrap_input_type()
+    }
+
+    /// ### score
+    ///
+    /// Get the number of rules satisfied by the password
+    fn score(&self) -> usize {
+        self.get_rules()
+            .iter()
+            .filter(|x| x.check(&self.states.input))
+            .count()
+    }
+
+    /// ### level
+    ///
+    /// Get the strength level of the password, which is the number of thresholds reached by the score
+    fn level(&self) -> usize {
+        let score = self.score();
+        self.get_thresholds()
+            .into_iter()
+            .take(LEVELS.len() - 1)
+            .filter(|x| score >= *x)
+            .count()
+    }
+
+    /// ### render_meter
+    ///
+    /// Render the strength meter, followed by the name of the level, in the first row of `area`
+    fn render_meter(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        if area.height == 0 {
+            return;
+        }
+        let (name, color) = LEVELS[self.level()];
+        let label_width = LEVELS.iter().map(|(x, _)| x.len()).max().unwrap_or(0) as u16 + 1;
+        let bar_width = area.width.saturating_sub(label_width);
+        let rules = self.get_rules().len().max(1);
+        let filled = match self.states.input.is_empty() {
+            true => 0,
+            false => (bar_width as usize * self.score() / rules) as u16,
+        };
+        for x in 0..bar_width {
+            let (symbol, cell_style) = match x < filled {
+                true => ("█", style.fg(color)),
+                false => ("░", style.fg(Color::DarkGray)),
+            };
+            buf[(area.x + x, area.y)]
+                .set_symbol(symbol)
+                .set_style(cell_style);
+        }
+        if !self.states.input.is_empty() && area.width > bar_width {
+            buf.set_stringn(
+                area.x + bar_width + 1,
+                area.y,
+                name,
+                (area.width - bar_width - 1) as usize,
+                style.fg(color),
+            );
+        }
+    }
+
+    /// ### render_checklist
+    ///
+    /// Render a row for each rule, telling whether it is satisfied
+    fn render_checklist(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        for (rule, y) in self.get_rules().iter().zip(area.rows().map(|x| x.y)) {
+            let (symbol, rule_style) = match rule.check(&self.states.input) {
+                true => ("✔", style.fg(Color::Green)),
+                false => ("✘", style.fg(Color::DarkGray)),
+            };
+            buf.set_stringn(
+                area.x,
+                y,
+                format!("{symbol} {}", rule.description()),
+                area.width as usize,
+                rule_style,
+            );
+        }
+    }
+}
+
+impl MockComponent for PasswordStrength {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            if inner.height == 0 {
+                render_disabled(&self.props, render.buffer_mut(), area);
+                render_tooltip(&self.props, render.buffer_mut(), area);
+                return;
+            }
+            let itype = self.get_input_type();
+            let buf = render.buffer_mut();
+            buf.set_stringn(
+                inner.x,
+                inner.y,
+                self.states.render_value(itype),
+                inner.width as usize,
+                style,
+            );
+            let mut rows = inner;
+            rows.y += 1;
+            rows.height -= 1;
+            self.render_meter(buf, rows, style);
+            rows.y += rows.height.min(1);
+            rows.height = rows.height.saturating_sub(1);
+            self.render_checklist(buf, rows, style);
+            // Set cursor, if focus
+            if focus {
+                let x = inner.x + self.states.cursor_width(itype);
+                if x < inner.right() {
+                    render.set_cursor_position(tuirealm::ratatui::prelude::Position {
+                        x,
+                        y: inner.y,
+                    });
+                }
+            }
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::Tup2((
+            StateValue::String(self.states.get_value()),
+            StateValue::Usize(self.level()),
+        ))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let prev = self.states.input.clone();
+        match cmd {
+            Cmd::Type(ch) => {
+                let max_len = self
+                    .props
+                    .get(Attribute::InputLength)
+                    .map(|x| x.unwrap_length());
+                self.states.append(ch, &self.get_input_type(), max_len);
+            }
+            Cmd::Delete => self.states.backspace(),
+            Cmd::Cancel => self.states.delete(),
+            Cmd::Move(Direction::Left) => self.states.decr_cursor(),
+            Cmd::Move(Direction::Right) => self.states.incr_cursor(),
+            Cmd::GoTo(Position::Begin) => self.states.cursor_at_begin(),
+            Cmd::GoTo(Position::End) => self.states.cursor_at_end(),
+            Cmd::Submit => return CmdResult::Submit(self.state()),
+            _ => {}
+        }
+        match prev != self.states.input {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn type_str(component: &mut PasswordStrength, s: &str) {
+        s.chars().for_each(|ch| {
+            component.perform(Cmd::Type(ch));
+        });
+    }
+
+    #[test]
+    fn test_components_password_strength() {
+        let mut component = PasswordStrength::default();
+        assert_eq!(
+            component.state(),
+            State::Tup2((StateValue::String(String::new()), StateValue::Usize(0)))
+        );
+        type_str(&mut component, "secret");
+        assert_eq!(component.score(), 1);
+        assert_eq!(
+            component.perform(Cmd::Type('1')),
+            CmdResult::Changed(State::Tup2((
+                StateValue::String(String::from("secret1")),
+                StateValue::Usize(0)
+            )))
+        );
+        assert_eq!(
+            component.perform(Cmd::Type('!')),
+            CmdResult::Changed(State::Tup2((
+                StateValue::String(String::from("secret1!")),
+                StateValue::Usize(2)
+            )))
+        );
+        component.perform(Cmd::GoTo(Position::Begin));
+        component.perform(Cmd::Type('S'));
+        assert_eq!(component.level(), 3);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        // Custom rules and thresholds
+        let mut component = PasswordStrength::default()
+            .rules(&[PasswordRule::MinLength(4), PasswordRule::Digit])
+            .thresholds(1, 1, 2)
+            .input_len(6);
+        type_str(&mut component, "abcdefgh");
+        assert_eq!(component.states.get_value(), "abcdef");
+        assert_eq!(component.level(), 2);
+        component.perform(Cmd::Delete);
+        component.perform(Cmd::Type('7'));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::Tup2((
+                StateValue::String(String::from("abcde7")),
+                StateValue::Usize(3)
+            )))
+        );
+    }
+
+    #[test]
+    fn test_components_password_strength_render() {
+        let mut component = PasswordStrength::default()
+            .rules(&[PasswordRule::MinLength(8), PasswordRule::Digit])
+            .thresholds(1, 2, 2);
+        let area = Rect::new(0, 0, 17, 3);
+        let mut buf = Buffer::empty(area);
+        component.render_meter(&mut buf, area, Style::default());
+        assert_eq!(rows(&buf)[0], "░░░░░░░░░░       ");
+        type_str(&mut component, "pass1");
+        let mut buf = Buffer::empty(area);
+        component.render_meter(&mut buf, area, Style::default());
+        component.render_checklist(&mut buf, Rect::new(0, 1, 17, 2), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![
+                "█████░░░░░ Fair  ",
+                "✘ At least 8 char",
+                "✔ A digit        ",
+            ]
+        );
+    }
+}
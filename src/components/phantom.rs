@@ -5,11 +5,16 @@
 //!
 //! An example would be a listener for `<ESC>` key to terminate the application.
 //! The Phantom allows you not to write a listener for each component for the `ESC` key, but just to subscribe the phantom to it.
+//! The subscriptions for the most common global events are generated by its associated functions, e.g.
+//! `Phantom::quit_subscriptions()`, so that the phantom can be mounted with a single line.
+
+use std::hash::Hash;
 
 use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::{Event, Key, KeyEvent, KeyModifiers};
 use tuirealm::props::{AttrValue, Attribute, Props};
 use tuirealm::ratatui::layout::Rect;
-use tuirealm::{Frame, MockComponent, State};
+use tuirealm::{Frame, MockComponent, State, Sub, SubClause, SubEventClause};
 
 // -- Component
 
@@ -22,6 +27,78 @@ pub struct Phantom {
     props: Props,
 }
 
+impl Phantom {
+    /// ### QUIT_KEYS
+    ///
+    /// The keys usually terminating an application: `<ESC>` and `<CTRL+C>`
+    pub const QUIT_KEYS: [KeyEvent; 2] = [
+        KeyEvent {
+            code: Key::Esc,
+            modifiers: KeyModifiers::NONE,
+        },
+        KeyEvent {
+            code: Key::Char('c'),
+            modifiers: KeyModifiers::CONTROL,
+        },
+    ];
+
+    /// ### quit_subscriptions
+    ///
+    /// Get the subscriptions to the keys in `QUIT_KEYS`, to terminate the application
+    pub fn quit_subscriptions<Id, UserEvent>() -> Vec<Sub<Id, UserEvent>>
+    where
+        Id: Eq + PartialEq + Clone + Hash,
+        UserEvent: Eq + PartialEq + Clone + PartialOrd,
+    {
+        Self::key_subscriptions(&Self::QUIT_KEYS)
+    }
+
+    /// ### resize_subscriptions
+    ///
+    /// Get the subscription to the resize of the terminal, to redraw the application
+    pub fn resize_subscriptions<Id, UserEvent>() -> Vec<Sub<Id, UserEvent>>
+    where
+        Id: Eq + PartialEq + Clone + Hash,
+        UserEvent: Eq + PartialEq + Clone + PartialOrd,
+    {
+        vec![Sub::new(SubEventClause::WindowResize, SubClause::Always)]
+    }
+
+    /// ### key_subscriptions
+    ///
+    /// Get the subscriptions to `keys`, which are always forwarded to the phantom, whichever component is active
+    pub fn key_subscriptions<Id, UserEvent>(keys: &[KeyEvent]) -> Vec<Sub<Id, UserEvent>>
+    where
+        Id: Eq + PartialEq + Clone + Hash,
+        UserEvent: Eq + PartialEq + Clone + PartialOrd,
+    {
+        keys.iter()
+            .map(|key| Sub::new(SubEventClause::Keyboard(*key), SubClause::Always))
+            .collect()
+    }
+
+    /// ### user_subscriptions
+    ///
+    /// Get the subscriptions to the user events `events`
+    pub fn user_subscriptions<Id, UserEvent>(events: &[UserEvent]) -> Vec<Sub<Id, UserEvent>>
+    where
+        Id: Eq + PartialEq + Clone + Hash,
+        UserEvent: Eq + PartialEq + Clone + PartialOrd,
+    {
+        events
+            .iter()
+            .map(|ev| Sub::new(SubEventClause::User(ev.clone()), SubClause::Always))
+            .collect()
+    }
+
+    /// ### is_quit
+    ///
+    /// Returns whether `ev` is one of the `QUIT_KEYS`, for the `on` method of the component wrapping the phantom
+    pub fn is_quit<UserEvent>(ev: &Event<UserEvent>) -> bool {
+        matches!(ev, Event::Keyboard(key) if Self::QUIT_KEYS.contains(key))
+    }
+}
+
 impl MockComponent for Phantom {
     fn view(&mut self, _render: &mut Frame, _area: Rect) {}
 
@@ -48,6 +125,7 @@ mod tests {
     use super::*;
 
     use pretty_assertions::assert_eq;
+    use tuirealm::event::NoUserEvent;
 
     #[test]
     fn test_components_phantom() {
@@ -55,4 +133,31 @@ mod tests {
         // Get value
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_components_phantom_subscriptions() {
+        let subs: Vec<Sub<&str, NoUserEvent>> = Phantom::quit_subscriptions();
+        assert_eq!(subs.len(), 2);
+        let subs: Vec<Sub<&str, NoUserEvent>> = Phantom::resize_subscriptions();
+        assert_eq!(subs.len(), 1);
+        let subs: Vec<Sub<&str, NoUserEvent>> = Phantom::key_subscriptions(&[
+            KeyEvent::from(Key::Function(1)),
+            KeyEvent::from(Key::Function(2)),
+            KeyEvent::new(Key::Char('q'), KeyModifiers::CONTROL),
+        ]);
+        assert_eq!(subs.len(), 3);
+        let subs: Vec<Sub<&str, u8>> = Phantom::user_subscriptions(&[1, 2]);
+        assert_eq!(subs.len(), 2);
+        // Quit events
+        assert!(Phantom::is_quit::<NoUserEvent>(&Event::Keyboard(
+            KeyEvent::from(Key::Esc)
+        )));
+        assert!(Phantom::is_quit::<NoUserEvent>(&Event::Keyboard(
+            KeyEvent::new(Key::Char('c'), KeyModifiers::CONTROL)
+        )));
+        assert!(!Phantom::is_quit::<NoUserEvent>(&Event::Keyboard(
+            KeyEvent::from(Key::Char('c'))
+        )));
+        assert!(!Phantom::is_quit::<NoUserEvent>(&Event::Tick));
+    }
 }
@@ -0,0 +1,356 @@
+//! ## PieChart
+//!
+//! A chart to display proportions as slices of a pie or of a donut
+
+use std::f64::consts::TAU;
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{
+    layout::Rect,
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{
+        canvas::{Canvas as TuiCanvas, Points},
+        Paragraph,
+    },
+};
+use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, render_disabled, render_tooltip, FocusStyles};
+
+// -- Props
+
+use super::props::{PIE_CHART_DONUT, PIE_CHART_LEGEND, TOOLTIP};
+
+// -- component
+
+/// ## PieChart
+///
+/// A component to display slices proportions on a pie drawn with braille characters.
+/// Set a donut hole to make it a donut chart. A legend with labels and percentages is displayed on the right.
+#[derive(Default)]
+pub struct PieChart {
+    props: Props,
+}
+
+impl PieChart {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `pie_chart` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("pie_chart", self);
+    }
+
+    /// Set slices as label, value and color
+    pub fn data(mut self, slices: &[(&str, f64, Color)]) -> Self {
+        self.attr(
+            Attribute::Dataset,
+            AttrValue::Payload(PropPayload::Linked(
+                slices
+                    .iter()
+                    .map(|(label, value, color)| {
+                        PropPayload::Tup3((
+                            PropValue::Str(label.to_string()),
+                            PropValue::F64(*value),
+                            PropValue::Color(*color),
+                        ))
+                    })
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the radius of the donut hole, as a fraction of the pie radius (e.g. `0.5`). `0.0` draws a full pie
+    pub fn donut(mut self, hole: f64) -> Self {
+        self.attr(
+            Attribute::Custom(PIE_CHART_DONUT),
+            AttrValue::Payload(PropPayload::One(PropValue::F64(hole))),
+        );
+        self
+    }
+
+    /// Show or hide the legend (shown by default)
+    pub fn legend(mut self, show: bool) -> Self {
+        self.attr(Attribute::Custom(PIE_CHART_LEGEND), AttrValue::Flag(show));
+        self
+    }
+
+    /// ### get_data
+    ///
+    /// Get slices from properties. Slices with non-positive values are ignored
+    fn get_data(&self) -> Vec<(String, f64, Color)> {
+        self.props
+            .get(Attribute::Dataset)
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_linked()
+                    .into_iter()
+                    .map(|x| {
+                        let (label, value, color) = x.unwrap_tup3();
+                        (label.unwrap_str(), value.unwrap_f64(), color.unwrap_color())
+                    })
+                    .filter(|(_, value, _)| *value > 0.0)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// ### legend_lines
+    ///
+    /// Make legend entries with label and percentage for each slice
+    fn legend_lines(data: &[(String, f64, Color)]) -> Vec<Line<'static>> {
+        let total: f64 = data.iter().map(|(_, value, _)| value).sum();
+        data.iter()
+            .map(|(label, value, color)| {
+                Line::from(vec![
+                    Span::styled("■ ", Style::default().fg(*color)),
+                    Span::raw(format!("{label} {:.1}%", value / total * 100.0)),
+                ])
+            })
+            .collect()
+    }
+
+    /// ### slices_points
+    ///
+    /// Get the dots of each slice on a grid of `dots` (columns, rows), in the `[-1, 1]` coordinate space.
+    /// Slices start from the top and proceed clockwise; dots inside the `hole` radius are not drawn
+    fn slices_points(values: &[f64], dots: (u16, u16), hole: f64) -> Vec<Vec<(f64, f64)>> {
+        let total: f64 = values.iter().sum();
+        let mut slices: Vec<Vec<(f64, f64)>> = vec![Vec::new(); values.len()];
+        if total <= 0.0 {
+            return slices;
+        }
+        // Upper angle of each slice, as fraction of the whole pie
+        let ends: Vec<f64> = values
+            .iter()
+            .scan(0.0, |acc, value| {
+                *acc += value / total;
+                Some(*acc)
+            })
+            .collect();
+        let (columns, rows) = dots;
+        for column in 0..columns {
+            for row in 0..rows {
+                let x = -1.0 + (column as f64 + 0.5) * 2.0 / columns as f64;
+                let y = 1.0 - (row as f64 + 0.5) * 2.0 / rows as f64;
+                let radius = x.hypot(y);
+                if radius > 1.0 || radius < hole {
+                    continue;
+                }
+                let angle = x.atan2(y).rem_euclid(TAU) / TAU;
+                let slice = ends
+                    .iter()
+                    .position(|end| angle < *end)
+                    .unwrap_or(values.len() - 1);
+                slices[slice].push((x, y));
+            }
+        }
+        slices
+    }
+
+    /// ### pie_area
+    ///
+    /// Get the largest area in `area` where the pie is drawn round, given that each cell has 2x4 braille dots
+    fn pie_area(area: Rect) -> Rect {
+        let diameter = (area.width * 2).min(area.height * 4);
+        let width = diameter / 2;
+        let height = diameter / 4;
+        Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        )
+    }
+}
+
+impl MockComponent for PieChart {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let styles =
+                FocusStyles::display(&self.props, Style::default().fg(foreground).bg(background));
+            let style = styles.style;
+            let div = styles
+                .block(borders, title)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let mut inner = div.inner(area);
+            render.render_widget(div, area);
+            let data = self.get_data();
+            // Legend on the right
+            if self
+                .props
+                .get_or(Attribute::Custom(PIE_CHART_LEGEND), AttrValue::Flag(true))
+                .unwrap_flag()
+            {
+                let legend = Self::legend_lines(&data);
+                let width = legend
+                    .iter()
+                    .map(|x| x.to_string().width() as u16 + 1)
+                    .max()
+                    .unwrap_or(0)
+                    .min(inner.width / 2);
+                inner.width -= width;
+                let height = (legend.len() as u16).min(inner.height);
+                render.render_widget(
+                    Paragraph::new(legend).style(style),
+                    Rect::new(
+                        inner.right() + 1,
+                        inner.y + (inner.height - height) / 2,
+                        width.saturating_sub(1),
+                        height,
+                    ),
+                );
+            }
+            // Pie
+            let hole = self
+                .props
+                .get(Attribute::Custom(PIE_CHART_DONUT))
+                .map(|x| x.unwrap_payload().unwrap_one().unwrap_f64())
+                .unwrap_or(0.0);
+            let pie = Self::pie_area(inner);
+            let values: Vec<f64> = data.iter().map(|(_, value, _)| *value).collect();
+            let slices = Self::slices_points(&values, (pie.width * 2, pie.height * 4), hole);
+            let canvas = TuiCanvas::default()
+                .background_color(background)
+                .marker(Marker::Braille)
+                .x_bounds([-1.0, 1.0])
+                .y_bounds([-1.0, 1.0])
+                .paint(|ctx| {
+                    for (coords, (_, _, color)) in slices.iter().zip(data.iter()) {
+                        ctx.draw(&Points {
+                            coords,
+                            color: *color,
+                        });
+                    }
+                });
+            render.render_widget(canvas, pie);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_pie_chart() {
+        let component = PieChart::default()
+            .title("disk usage", Alignment::Center)
+            .borders(Borders::default())
+            .donut(0.4)
+            .legend(true)
+            .data(&[
+                ("system", 30.0, Color::Red),
+                ("home", 10.0, Color::Green),
+                ("empty", 0.0, Color::Gray),
+            ]);
+        assert_eq!(component.state(), State::None);
+        assert_eq!(
+            component.get_data(),
+            vec![
+                (String::from("system"), 30.0, Color::Red),
+                (String::from("home"), 10.0, Color::Green)
+            ]
+        );
+        let legend = PieChart::legend_lines(&component.get_data());
+        assert_eq!(legend[0].to_string(), "■ system 75.0%");
+        assert_eq!(legend[1].to_string(), "■ home 25.0%");
+    }
+
+    #[test]
+    fn test_components_pie_chart_slices() {
+        // Two halves: right half to the first slice, left half to the second one
+        let slices = PieChart::slices_points(&[1.0, 1.0], (8, 8), 0.0);
+        assert!(slices[0].iter().all(|(x, _)| *x > 0.0));
+        assert!(slices[1].iter().all(|(x, _)| *x < 0.0));
+        assert_eq!(slices[0].len(), slices[1].len());
+        // Donut hole
+        let slices = PieChart::slices_points(&[1.0], (8, 8), 0.5);
+        assert!(slices[0].iter().all(|(x, y)| x.hypot(*y) >= 0.5));
+        let full = PieChart::slices_points(&[1.0], (8, 8), 0.0);
+        assert!(full[0].len() > slices[0].len());
+        // No data
+        assert!(PieChart::slices_points(&[], (8, 8), 0.0).is_empty());
+        // Round area
+        assert_eq!(
+            PieChart::pie_area(Rect::new(0, 0, 40, 10)),
+            Rect::new(10, 0, 20, 10)
+        );
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! `ProgressBar` provides a component which shows the progress. It is possible to set the style for the progress bar and the text shown above it.
 
+use std::time::Duration;
+
 use tuirealm::command::{Cmd, CmdResult};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
@@ -10,14 +12,39 @@ use tuirealm::props::{
 use tuirealm::ratatui::{layout::Rect, widgets::Gauge};
 use tuirealm::{Frame, MockComponent, State};
 
+use crate::animation::Animated;
+use crate::theme::Theme;
+use crate::utils::{
+    format_duration, format_percent, get_title_style, render_disabled, render_tooltip, FocusStyles,
+};
+
+// -- Props
+
+use super::props::{ANIMATION_TICKS, PROGRESS_BAR_ETA, TOOLTIP};
+
+// -- states
+
+/// ## ProgressBarStates
+///
+/// ProgressBarStates contains states for this component
+#[derive(Default)]
+pub struct ProgressBarStates {
+    /// Progress displayed, filling towards the progress set
+    pub progress: Animated<f64>,
+}
+
 // -- Component
 
 /// ## ProgressBar
 ///
 /// provides a component which shows the progress. It is possible to set the style for the progress bar and the text shown above it.
+///
+/// When an animation is set, changes of the progress fill the bar smoothly on `Cmd::Tick`, which should be performed
+/// on `Event::Tick`
 #[derive(Default)]
 pub struct ProgressBar {
     props: Props,
+    pub states: ProgressBarStates,
 }
 
 impl ProgressBar {
@@ -46,6 +73,28 @@ impl ProgressBar {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `progress_bar` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("progress_bar", self);
+    }
+
     pub fn label<S: Into<String>>(mut self, s: S) -> Self {
         self.attr(Attribute::Text, AttrValue::String(s.into()));
         self
@@ -60,6 +109,49 @@ impl ProgressBar {
         self
     }
 
+    /// Set the estimated time left, displayed after the label, or after the percentage if there's no label, as
+    /// `60% - ETA 2m 13s`
+    pub fn eta(mut self, eta: Duration) -> Self {
+        self.attr(
+            Attribute::Custom(PROGRESS_BAR_ETA),
+            AttrValue::Payload(PropPayload::One(PropValue::U64(eta.as_millis() as u64))),
+        );
+        self
+    }
+
+    /// Fill the bar smoothly in `ticks` ticks when the progress changes. 0 disables the animation
+    pub fn animation(mut self, ticks: usize) -> Self {
+        self.attr(Attribute::Custom(ANIMATION_TICKS), AttrValue::Length(ticks));
+        self
+    }
+
+    /// ### get_label
+    ///
+    /// Get the label, followed by the ETA if set
+    fn get_label(&self) -> String {
+        let label = self
+            .props
+            .get_or(Attribute::Text, AttrValue::String(String::default()))
+            .unwrap_string();
+        match self
+            .props
+            .get(Attribute::Custom(PROGRESS_BAR_ETA))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::U64(eta))) => {
+                let label = match label.is_empty() {
+                    true => format_percent(self.states.progress.value()),
+                    false => label,
+                };
+                format!(
+                    "{label} - ETA {}",
+                    format_duration(Duration::from_millis(eta))
+                )
+            }
+            _ => label,
+        }
+    }
+
     fn assert_progress(p: f64) {
         if !(0.0..=1.0).contains(&p) {
             panic!("Progress value must be in range [0.0, 1.0]");
@@ -72,10 +164,7 @@ impl MockComponent for ProgressBar {
         // Make a Span
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
             // Text
-            let label = self
-                .props
-                .get_or(Attribute::Text, AttrValue::String(String::default()))
-                .unwrap_string();
+            let label = self.get_label();
             let foreground = self
                 .props
                 .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
@@ -97,31 +186,29 @@ impl MockComponent for ProgressBar {
                 .unwrap_borders();
             let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
             // Get percentage
-            let percentage = self
-                .props
-                .get_or(
-                    Attribute::Value,
-                    AttrValue::Payload(PropPayload::One(PropValue::F64(0.0))),
-                )
-                .unwrap_payload()
-                .unwrap_one()
-                .unwrap_f64();
-            let div = crate::utils::get_block(borders, title, true, None);
+            let percentage = self.states.progress.value();
+            let styles = FocusStyles::display(
+                &self.props,
+                Style::default()
+                    .fg(foreground)
+                    .bg(background)
+                    .add_modifier(modifiers),
+            );
+            let div = styles
+                .block(borders, title)
+                .title_style(get_title_style(&self.props));
             // Make progress bar
             render.render_widget(
                 Gauge::default()
                     .block(div)
-                    .gauge_style(
-                        Style::default()
-                            .fg(foreground)
-                            .bg(background)
-                            .add_modifier(modifiers),
-                    )
+                    .gauge_style(styles.style)
                     .label(label)
                     .ratio(percentage),
                 area,
             );
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -129,10 +216,16 @@ impl MockComponent for ProgressBar {
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
-        if let Attribute::Value = attr {
-            if let AttrValue::Payload(p) = value.clone() {
-                Self::assert_progress(p.unwrap_one().unwrap_f64());
+        match (attr, &value) {
+            (Attribute::Value, AttrValue::Payload(p)) => {
+                let p = p.clone().unwrap_one().unwrap_f64();
+                Self::assert_progress(p);
+                self.states.progress.set(p);
+            }
+            (Attribute::Custom(ANIMATION_TICKS), AttrValue::Length(ticks)) => {
+                self.states.progress.set_ticks(*ticks);
             }
+            _ => {}
         }
         self.props.set(attr, value)
     }
@@ -141,8 +234,11 @@ impl MockComponent for ProgressBar {
         State::None
     }
 
-    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
-        CmdResult::None
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        match cmd {
+            Cmd::Tick if self.states.progress.tick() => CmdResult::Changed(State::None),
+            _ => CmdResult::None,
+        }
     }
 }
 
@@ -166,6 +262,16 @@ mod test {
         assert_eq!(component.state(), State::None);
     }
 
+    #[test]
+    fn test_components_progress_bar_eta() {
+        let component = ProgressBar::default()
+            .progress(0.60)
+            .eta(Duration::from_secs(133));
+        assert_eq!(component.get_label(), "60% - ETA 2m 13s");
+        let component = component.label("3 of 5 files");
+        assert_eq!(component.get_label(), "3 of 5 files - ETA 2m 13s");
+    }
+
     #[test]
     #[should_panic]
     fn test_components_progress_bar_bad_prog() {
@@ -177,4 +283,26 @@ mod test {
             .label("60% - ETA 00:20")
             .borders(Borders::default());
     }
+
+    #[test]
+    fn test_components_progress_bar_animation() {
+        let mut component = ProgressBar::default().progress(0.2).animation(2);
+        assert_eq!(component.states.progress.value(), 0.2);
+        component.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::F64(0.6))),
+        );
+        assert_eq!(component.states.progress.value(), 0.2);
+        assert_eq!(
+            component.perform(Cmd::Tick),
+            CmdResult::Changed(State::None)
+        );
+        assert!(component.states.progress.value() > 0.2);
+        assert_eq!(
+            component.perform(Cmd::Tick),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(component.states.progress.value(), 0.6);
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+    }
 }
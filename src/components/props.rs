@@ -2,14 +2,50 @@
 //!
 //! This module exposes components props name
 
+// -- accordion
+
+pub const ACCORDION_MULTIPLE: &str = "accordion-multiple";
+
+// -- animation
+
+pub const ANIMATION_TICKS: &str = "animation-ticks";
+
 // -- bar-chart
 
 pub const BAR_CHART_BARS_GAP: &str = "bar-chart-bars-gap";
 pub const BAR_CHART_BARS_STYLE: &str = "bar-chart-bars-style";
+pub const BAR_CHART_GROUPS: &str = "bar-chart-groups";
+pub const BAR_CHART_HORIZONTAL: &str = "bar-chart-horizontal";
 pub const BAR_CHART_LABEL_STYLE: &str = "bar-chart-label-style";
 pub const BAR_CHART_MAX_BARS: &str = "bar-chart-max-bars";
+pub const BAR_CHART_SERIES: &str = "bar-chart-series";
+pub const BAR_CHART_STACKED: &str = "bar-chart-stacked";
 pub const BAR_CHART_VALUES_STYLE: &str = "bar-chart-values-style";
 
+// -- big text
+
+pub const BIG_TEXT_COLORS: &str = "big-text-colors";
+pub const BIG_TEXT_FIGLET_FONT: &str = "big-text-figlet-font";
+pub const BIG_TEXT_FONT: &str = "big-text-font";
+pub const BIG_TEXT_GRADIENT: &str = "big-text-gradient";
+
+// -- breadcrumbs
+
+pub const BREADCRUMBS_SEPARATOR: &str = "breadcrumbs-separator";
+
+// -- calendar
+
+pub const CALENDAR_EVENTS: &str = "calendar-events";
+pub const CALENDAR_INTERACTIVE: &str = "calendar-interactive";
+pub const CALENDAR_MONTHS: &str = "calendar-months";
+pub const CALENDAR_TODAY_STYLE: &str = "calendar-today-style";
+
+// -- candlestick chart
+
+pub const CANDLESTICK_CHART_DOWN_COLOR: &str = "candlestick-chart-down-color";
+pub const CANDLESTICK_CHART_TIME_FORMAT: &str = "candlestick-chart-time-format";
+pub const CANDLESTICK_CHART_UP_COLOR: &str = "candlestick-chart-up-color";
+
 // -- canvas
 
 pub const CANVAS_X_BOUNDS: &str = "x-bounds";
@@ -21,6 +57,13 @@ pub const CANVAS_MARKER_BLOCK: isize = 2;
 pub const CANVAS_MARKER_BAR: isize = 3;
 pub const CANVAS_MARKER_HALF_BLOCK: isize = 4;
 
+// -- chat view
+
+pub const CHAT_VIEW_APPEND: &str = "chat-view-append";
+pub const CHAT_VIEW_FOLLOW: &str = "chat-view-follow";
+pub const CHAT_VIEW_OTHER_COLOR: &str = "chat-view-other-color";
+pub const CHAT_VIEW_OWN_COLOR: &str = "chat-view-own-color";
+
 // -- chart
 
 pub const CHART_X_BOUNDS: &str = "x-bounds";
@@ -31,13 +74,130 @@ pub const CHART_X_STYLE: &str = "x-style";
 pub const CHART_Y_STYLE: &str = "y-style";
 pub const CHART_X_TITLE: &str = "x-title";
 pub const CHART_Y_TITLE: &str = "y-titles";
+pub const CHART_X_AUTO_BOUNDS: &str = "x-auto-bounds";
+pub const CHART_Y_AUTO_BOUNDS: &str = "y-auto-bounds";
+pub const CHART_AUTO_BOUNDS_PADDING: &str = "auto-bounds-padding";
+pub const CHART_AUTO_BOUNDS_NICE: &str = "auto-bounds-nice";
+pub const CHART_CROSSHAIR: &str = "crosshair";
+pub const CHART_CROSSHAIR_STYLE: &str = "crosshair-style";
+pub const CHART_DECIMATION: &str = "decimation";
+pub const CHART_X_TIME_FORMAT: &str = "x-time-format";
+pub const CHART_X_LOG_SCALE: &str = "x-log-scale";
+pub const CHART_Y_LOG_SCALE: &str = "y-log-scale";
+
+// -- clock
+
+pub const CLOCK_BIG: &str = "clock-big";
+pub const CLOCK_BLINK: &str = "clock-blink";
+pub const CLOCK_FONT: &str = "clock-font";
+pub const CLOCK_FORMAT: &str = "clock-format";
+pub const CLOCK_UTC_OFFSET: &str = "clock-utc-offset";
+
+// -- color picker
+
+pub const COLOR_PICKER_HEX_INPUT: &str = "color-picker-hex-input";
+
+// -- container
+
+pub const CONTAINER_COLLAPSED: &str = "container-collapsed";
+pub const CONTAINER_FOCUS: &str = "container-focus";
+
+// -- date picker
+
+pub const DATE_PICKER_MAX_DATE: &str = "date-picker-max-date";
+pub const DATE_PICKER_MIN_DATE: &str = "date-picker-min-date";
+
+// -- dial
+
+pub const DIAL_NEEDLE_COLOR: &str = "dial-needle-color";
+pub const DIAL_RANGE: &str = "dial-range";
+pub const DIAL_ZONES: &str = "dial-zones";
+
+// -- diff viewer
+
+pub const DIFF_VIEWER_ADDED_COLOR: &str = "diff-viewer-added-color";
+pub const DIFF_VIEWER_HUNK_COLOR: &str = "diff-viewer-hunk-color";
+pub const DIFF_VIEWER_REMOVED_COLOR: &str = "diff-viewer-removed-color";
+pub const DIFF_VIEWER_SIDE_BY_SIDE: &str = "diff-viewer-side-by-side";
+
+// -- dual list transfer
+
+pub const DUAL_LIST_TRANSFER_TITLES: &str = "dual-list-transfer-titles";
+
+// -- file explorer
+
+pub const FILE_EXPLORER_DIRECTORY: &str = "file-explorer-directory";
+pub const FILE_EXPLORER_EXTENSIONS: &str = "file-explorer-extensions";
+pub const FILE_EXPLORER_SHOW_HIDDEN: &str = "file-explorer-show-hidden";
+
+// -- form
+
+pub const FORM_ERROR_COLOR: &str = "form-error-color";
+
+// -- gantt
+
+pub const GANTT_AXIS_FORMAT: &str = "gantt-axis-format";
+pub const GANTT_SCALE: &str = "gantt-scale";
+pub const GANTT_START: &str = "gantt-start";
+pub const GANTT_TODAY: &str = "gantt-today";
+pub const GANTT_TODAY_COLOR: &str = "gantt-today-color";
+
+// -- grid
+
+pub const GRID_GAP: &str = "grid-gap";
+pub const GRID_SIZE: &str = "grid-size";
+
+// -- heatmap
+
+pub const HEATMAP_CELL_LABELS: &str = "heatmap-cell-labels";
+pub const HEATMAP_COLORS: &str = "heatmap-colors";
+pub const HEATMAP_RANGE: &str = "heatmap-range";
+pub const HEATMAP_X_LABELS: &str = "heatmap-x-labels";
+pub const HEATMAP_Y_LABELS: &str = "heatmap-y-labels";
+
+// -- help overlay
+
+pub const HELP_OVERLAY_COLUMNS: &str = "help-overlay-columns";
+
+// -- histogram
+
+pub const HISTOGRAM_BARS_STYLE: &str = "histogram-bars-style";
+pub const HISTOGRAM_BINS: &str = "histogram-bins";
+pub const HISTOGRAM_BIN_WIDTH: &str = "histogram-bin-width";
+pub const HISTOGRAM_CUMULATIVE: &str = "histogram-cumulative";
+pub const HISTOGRAM_CUMULATIVE_STYLE: &str = "histogram-cumulative-style";
+
+// -- image
+
+pub const IMAGE_CELL_SIZE: &str = "image-cell-size";
+pub const IMAGE_PROTOCOL: &str = "image-protocol";
+pub const IMAGE_VERTICAL_ALIGNMENT: &str = "image-vertical-alignment";
 
 // -- input
 
+pub const INPUT_CURSOR_BLINK: &str = "cursor-blink";
 pub const INPUT_INVALID_STYLE: &str = "invalid-style";
 pub const INPUT_PLACEHOLDER: &str = "placeholder";
 pub const INPUT_PLACEHOLDER_STYLE: &str = "placeholder-style";
 
+// -- json viewer
+
+pub const JSON_VIEWER_BOOL_COLOR: &str = "json-viewer-bool-color";
+pub const JSON_VIEWER_BREADCRUMB: &str = "json-viewer-breadcrumb";
+pub const JSON_VIEWER_KEY_COLOR: &str = "json-viewer-key-color";
+pub const JSON_VIEWER_NULL_COLOR: &str = "json-viewer-null-color";
+pub const JSON_VIEWER_NUMBER_COLOR: &str = "json-viewer-number-color";
+pub const JSON_VIEWER_STRING_COLOR: &str = "json-viewer-string-color";
+
+// -- key hint bar
+
+pub const KEY_HINT_BAR_KEY_STYLE: &str = "key-hint-bar-key-style";
+pub const KEY_HINT_BAR_SEPARATOR: &str = "key-hint-bar-separator";
+
+// -- label
+
+pub const LABEL_MARKUP: &str = "label-markup";
+
 // -- line gauge
 
 pub const LINE_GAUGE_STYLE_NORMAL: u8 = 0;
@@ -45,6 +205,213 @@ pub const LINE_GAUGE_STYLE_DOUBLE: u8 = 1;
 pub const LINE_GAUGE_STYLE_ROUND: u8 = 2;
 pub const LINE_GAUGE_STYLE_THICK: u8 = 3;
 
+// -- links
+
+pub const LINKS: &str = "links";
+pub const LINKS_OSC8: &str = "links-osc8";
+
+// -- markdown
+
+pub const MARKDOWN_CODE_STYLE: &str = "markdown-code-style";
+pub const MARKDOWN_HEADING_STYLE: &str = "markdown-heading-style";
+pub const MARKDOWN_LINK_STYLE: &str = "markdown-link-style";
+pub const MARKDOWN_QUOTE_STYLE: &str = "markdown-quote-style";
+
+// -- meter
+
+pub const METER_BAR_WIDTH: &str = "meter-bar-width";
+pub const METER_HORIZONTAL: &str = "meter-horizontal";
+pub const METER_LABELS: &str = "meter-labels";
+pub const METER_PEAK_HOLD: &str = "meter-peak-hold";
+pub const METER_RANGE: &str = "meter-range";
+pub const METER_ZONES: &str = "meter-zones";
+
+// -- minimap
+
+pub const MINIMAP_CHARS_PER_CELL: &str = "minimap-chars-per-cell";
+pub const MINIMAP_LINES_PER_CELL: &str = "minimap-lines-per-cell";
+pub const MINIMAP_LINE_COLORS: &str = "minimap-line-colors";
+pub const MINIMAP_POSITION: &str = "minimap-position";
+pub const MINIMAP_VIEWPORT_LENGTH: &str = "minimap-viewport-length";
+
+// -- modal
+
+pub const MODAL_BACKDROP: &str = "modal-backdrop";
+
+// -- mouse
+
+pub const MOUSE_WHEEL_STEP: &str = "mouse-wheel-step";
+
+// -- network graph
+
+pub const NETWORK_GRAPH_EDGES: &str = "network-graph-edges";
+
+// -- number spinner
+
+pub const NUMBER_SPINNER_PRECISION: &str = "number-spinner-precision";
+pub const NUMBER_SPINNER_RANGE: &str = "number-spinner-range";
+pub const NUMBER_SPINNER_STEP: &str = "number-spinner-step";
+
+// -- outline
+
+pub const OUTLINE_ACTIVE: &str = "outline-active";
+
+// -- paginator
+
+pub const PAGINATOR_PAGES: &str = "paginator-pages";
+pub const PAGINATOR_SIBLINGS: &str = "paginator-siblings";
+
+// -- paragraph
+
+pub const PARAGRAPH_HORIZONTAL_SCROLL: &str = "paragraph-horizontal-scroll";
+pub const PARAGRAPH_INDENT: &str = "paragraph-indent";
+pub const PARAGRAPH_JUSTIFY: &str = "paragraph-justify";
+pub const PARAGRAPH_SCROLL: &str = "paragraph-scroll";
+pub const PARAGRAPH_SCROLL_INDICATOR: &str = "paragraph-scroll-indicator";
+
+// -- password strength
+
+pub const PASSWORD_STRENGTH_RULES: &str = "password-strength-rules";
+pub const PASSWORD_STRENGTH_THRESHOLDS: &str = "password-strength-thresholds";
+
+// -- pie chart
+
+pub const PIE_CHART_DONUT: &str = "pie-chart-donut";
+pub const PIE_CHART_LEGEND: &str = "pie-chart-legend";
+
+// -- progress bar
+
+pub const PROGRESS_BAR_ETA: &str = "progress-bar-eta";
+
+// -- qr code
+
+pub const QR_CODE_ERROR_CORRECTION: &str = "qr-code-error-correction";
+pub const QR_CODE_INVERTED: &str = "qr-code-inverted";
+pub const QR_CODE_QUIET_ZONE: &str = "qr-code-quiet-zone";
+
+// -- rating
+
+pub const RATING_HALF_STEPS: &str = "rating-half-steps";
+pub const RATING_MAX: &str = "rating-max";
+pub const RATING_READ_ONLY: &str = "rating-read-only";
+pub const RATING_SYMBOLS: &str = "rating-symbols";
+
+// -- rows
+
+pub const ROWS_APPEND: &str = "rows-append";
+pub const ROWS_PATCH: &str = "rows-patch";
+pub const ROWS_REMOVE: &str = "rows-remove";
+
+// -- scatter plot
+
+pub const SCATTER_PLOT_TREND_LINE: &str = "scatter-plot-trend-line";
+pub const SCATTER_PLOT_X_BOUNDS: &str = "scatter-plot-x-bounds";
+pub const SCATTER_PLOT_X_TITLE: &str = "scatter-plot-x-title";
+pub const SCATTER_PLOT_Y_BOUNDS: &str = "scatter-plot-y-bounds";
+pub const SCATTER_PLOT_Y_TITLE: &str = "scatter-plot-y-title";
+
+// -- scroll view
+
+pub const SCROLL_VIEW_CONTENT_SIZE: &str = "scroll-view-content-size";
+
+// -- scrollbar
+
+pub const SCROLLBAR_CONTENT_LENGTH: &str = "scrollbar-content-length";
+pub const SCROLLBAR_HORIZONTAL: &str = "scrollbar-horizontal";
+pub const SCROLLBAR_POSITION: &str = "scrollbar-position";
+pub const SCROLLBAR_THUMB_SYMBOL: &str = "scrollbar-thumb-symbol";
+pub const SCROLLBAR_TRACK_SYMBOL: &str = "scrollbar-track-symbol";
+pub const SCROLLBAR_VIEWPORT_LENGTH: &str = "scrollbar-viewport-length";
+
+// -- search box
+
+pub const SEARCH_BOX_DEBOUNCE: &str = "search-box-debounce";
+pub const SEARCH_BOX_MATCHES: &str = "search-box-matches";
+
+// -- skeleton
+
+pub const SKELETON_SHAPE: &str = "skeleton-shape";
+pub const SKELETON_SHIMMER_WIDTH: &str = "skeleton-shimmer-width";
+
+// -- sparkline
+
+pub const SPARKLINE_BASELINE: &str = "sparkline-baseline";
+pub const SPARKLINE_BASELINE_STYLE: &str = "sparkline-baseline-style";
+pub const SPARKLINE_CURRENT_VALUE: &str = "sparkline-current-value";
+pub const SPARKLINE_DOWNSAMPLE: &str = "sparkline-downsample";
+pub const SPARKLINE_MAX_COLOR: &str = "sparkline-max-color";
+pub const SPARKLINE_MIN_COLOR: &str = "sparkline-min-color";
+
+// -- split pane
+
+pub const SPLIT_PANE_POSITION: &str = "split-pane-position";
+pub const SPLIT_PANE_VERTICAL: &str = "split-pane-vertical";
+
+// -- status bar
+
+pub const STATUS_BAR_CENTER: &str = "status-bar-center";
+pub const STATUS_BAR_LEFT: &str = "status-bar-left";
+pub const STATUS_BAR_PRIORITY: &str = "status-bar-priority";
+pub const STATUS_BAR_RIGHT: &str = "status-bar-right";
+
+// -- stepper
+
+pub const STEPPER_DONE_STYLE: &str = "stepper-done-style";
+pub const STEPPER_UPCOMING_STYLE: &str = "stepper-upcoming-style";
+
 // -- table
 
 pub const TABLE_COLUMN_SPACING: &str = "col-spacing";
+
+// -- tabs
+
+pub const TABS_ACTIVE_MODIFIERS: &str = "tabs-active-modifiers";
+pub const TABS_BADGES: &str = "tabs-badges";
+pub const TABS_BADGE_STYLE: &str = "tabs-badge-style";
+
+// -- tag input
+
+pub const TAG_INPUT_SUGGESTIONS: &str = "tag-input-suggestions";
+
+// -- terminal view
+
+pub const TERMINAL_VIEW_INPUT: &str = "terminal-view-input";
+pub const TERMINAL_VIEW_SCROLLBACK: &str = "terminal-view-scrollback";
+
+// -- time picker
+
+pub const TIME_PICKER_SECONDS: &str = "time-picker-seconds";
+pub const TIME_PICKER_TWELVE_HOUR: &str = "time-picker-twelve-hour";
+
+// -- timeline
+
+pub const TIMELINE_TIME_COLOR: &str = "timeline-time-color";
+
+// -- timer
+
+pub const TIMER_BIG: &str = "timer-big";
+pub const TIMER_DURATION: &str = "timer-duration";
+pub const TIMER_FONT: &str = "timer-font";
+pub const TIMER_FORMAT: &str = "timer-format";
+pub const TIMER_MODE: &str = "timer-mode";
+pub const TIMER_WARNING_STYLE: &str = "timer-warning-style";
+pub const TIMER_WARNING_THRESHOLD: &str = "timer-warning-threshold";
+
+// -- title
+
+pub const TITLE_STYLE: &str = "title-style";
+
+// -- toggle switch
+
+pub const TOGGLE_SWITCH_OFF_COLOR: &str = "toggle-switch-off-color";
+pub const TOGGLE_SWITCH_ON_COLOR: &str = "toggle-switch-on-color";
+pub const TOGGLE_SWITCH_TEXTS: &str = "toggle-switch-texts";
+
+// -- tooltip
+
+pub const TOOLTIP: &str = "tooltip";
+pub const TOOLTIP_STYLE: &str = "tooltip-style";
+
+// -- truncation
+
+pub const TRUNCATION: &str = "truncation";
@@ -0,0 +1,373 @@
+//! ## QrCode
+//!
+//! `QrCode` encodes a string and renders it as a QR code
+
+use qrcode::{EcLevel, QrCode as Code};
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{QR_CODE_ERROR_CORRECTION, QR_CODE_INVERTED, QR_CODE_QUIET_ZONE, TOOLTIP};
+
+/// ## QrErrorCorrection
+///
+/// Error correction level of the code: a higher level makes the code readable when damaged, but larger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QrErrorCorrection {
+    /// Recovers 7% of the code
+    Low,
+    /// Recovers 15% of the code
+    #[default]
+    Medium,
+    /// Recovers 25% of the code
+    Quartile,
+    /// Recovers 30% of the code
+    High,
+}
+
+impl QrErrorCorrection {
+    const ALL: [Self; 4] = [Self::Low, Self::Medium, Self::Quartile, Self::High];
+
+    fn level(self) -> EcLevel {
+        match self {
+            Self::Low => EcLevel::L,
+            Self::Medium => EcLevel::M,
+            Self::Quartile => EcLevel::Q,
+            Self::High => EcLevel::H,
+        }
+    }
+}
+
+// -- states
+
+/// ## QrCodeStates
+///
+/// QrCodeStates contains states for this component
+#[derive(Default)]
+pub struct QrCodeStates {
+    /// Modules of the code, by row; `true` for dark modules
+    pub modules: Vec<bool>,
+    /// Modules per side
+    pub width: usize,
+}
+
+impl QrCodeStates {
+    /// ### encode
+    ///
+    /// Encode `data`; if it's too long for a QR code, the code is empty
+    pub fn encode(&mut self, data: &str, error_correction: QrErrorCorrection) {
+        match Code::with_error_correction_level(data, error_correction.level()) {
+            Ok(code) => {
+                self.width = code.width();
+                self.modules = code
+                    .to_colors()
+                    .into_iter()
+                    .map(|x| x.select(true, false))
+                    .collect();
+            }
+            Err(_) => {
+                self.width = 0;
+                self.modules.clear();
+            }
+        }
+    }
+
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.width && self.modules[y * self.width + x]
+    }
+}
+
+// -- component
+
+/// ## QrCode
+///
+/// A QR code, handy to share a URL or pair a device. Each cell draws two modules with half blocks, so that modules
+/// are square. The code is surrounded by a quiet zone and centered in the area
+#[derive(Default)]
+pub struct QrCode {
+    props: Props,
+    pub states: QrCodeStates,
+}
+
+impl QrCode {
+    /// Set the color of dark modules (default: black)
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    /// Set the color of light modules and of the quiet zone (default: white)
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `qr_code` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("qr_code", self);
+    }
+
+    /// Set the data to encode
+    pub fn data<S: Into<String>>(mut self, data: S) -> Self {
+        self.attr(Attribute::Text, AttrValue::String(data.into()));
+        self
+    }
+
+    /// Set the error correction level (default: medium)
+    pub fn error_correction(mut self, level: QrErrorCorrection) -> Self {
+        self.attr(
+            Attribute::Custom(QR_CODE_ERROR_CORRECTION),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(level as usize))),
+        );
+        self
+    }
+
+    /// Swap the colors of dark and light modules
+    pub fn inverted(mut self, inverted: bool) -> Self {
+        self.attr(
+            Attribute::Custom(QR_CODE_INVERTED),
+            AttrValue::Flag(inverted),
+        );
+        self
+    }
+
+    /// Set the width of the quiet zone around the code, in modules (default: 4)
+    pub fn quiet_zone(mut self, modules: usize) -> Self {
+        self.attr(
+            Attribute::Custom(QR_CODE_QUIET_ZONE),
+            AttrValue::Length(modules),
+        );
+        self
+    }
+
+    fn get_error_correction(&self) -> QrErrorCorrection {
+        match self
+            .props
+            .get(Attribute::Custom(QR_CODE_ERROR_CORRECTION))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::Usize(x))) => {
+                QrErrorCorrection::ALL.get(x).copied().unwrap_or_default()
+            }
+            _ => QrErrorCorrection::default(),
+        }
+    }
+
+    fn encode(&mut self) {
+        let data = self
+            .props
+            .get(Attribute::Text)
+            .map(|x| x.unwrap_string())
+            .unwrap_or_default();
+        let error_correction = self.get_error_correction();
+        self.states.encode(&data, error_correction);
+    }
+
+    /// ### render_code
+    ///
+    /// Render the code centered in `area`, two modules per cell. Nothing is rendered if it doesn't fit
+    fn render_code(&self, buf: &mut Buffer, area: Rect) {
+        if self.states.width == 0 {
+            return;
+        }
+        let quiet_zone = self
+            .props
+            .get_or(Attribute::Custom(QR_CODE_QUIET_ZONE), AttrValue::Length(4))
+            .unwrap_length();
+        let mut dark = self
+            .props
+            .get_or(Attribute::Foreground, AttrValue::Color(Color::Black))
+            .unwrap_color();
+        let mut light = self
+            .props
+            .get_or(Attribute::Background, AttrValue::Color(Color::White))
+            .unwrap_color();
+        if self
+            .props
+            .get_or(Attribute::Custom(QR_CODE_INVERTED), AttrValue::Flag(false))
+            .unwrap_flag()
+        {
+            std::mem::swap(&mut dark, &mut light);
+        }
+        let size = self.states.width + quiet_zone * 2;
+        let (cols, rows) = (size, size.div_ceil(2));
+        if cols > area.width as usize || rows > area.height as usize {
+            return;
+        }
+        let x = area.x + (area.width - cols as u16) / 2;
+        let y = area.y + (area.height - rows as u16) / 2;
+        // Modules out of the code are in the quiet zone
+        let color = |col: usize, row: usize| -> Color {
+            let dark_module = col >= quiet_zone
+                && row >= quiet_zone
+                && self.states.is_dark(col - quiet_zone, row - quiet_zone);
+            match dark_module {
+                true => dark,
+                false => light,
+            }
+        };
+        for row in 0..rows {
+            for col in 0..cols {
+                let top = color(col, row * 2);
+                // With an odd size, the lower half of the last row is outside the code
+                let cell = &mut buf[(x + col as u16, y + row as u16)];
+                match row * 2 + 1 < size {
+                    true => cell
+                        .set_symbol("▀")
+                        .set_style(Style::default().fg(top).bg(color(col, row * 2 + 1))),
+                    false => cell.set_symbol("▀").set_style(Style::default().fg(top)),
+                };
+            }
+        }
+    }
+}
+
+impl MockComponent for QrCode {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props));
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_code(render.buffer_mut(), inner);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if matches!(
+            attr,
+            Attribute::Text | Attribute::Custom(QR_CODE_ERROR_CORRECTION)
+        ) {
+            self.encode();
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_qr_code() {
+        let component = QrCode::default()
+            .title("Scan me", Alignment::Center)
+            .error_correction(QrErrorCorrection::Low)
+            .quiet_zone(2)
+            .data("https://x.org");
+        assert_eq!(component.state(), State::None);
+        // A version 1 code has 21 modules per side
+        assert_eq!(component.states.width, 21);
+        assert_eq!(component.states.modules.len(), 21 * 21);
+        // 25 modules, in 25 x 13 cells
+        let area = Rect::new(0, 0, 27, 14);
+        let mut buf = Buffer::empty(area);
+        component.render_code(&mut buf, area);
+        assert_eq!(buf[(0, 0)].symbol(), " ");
+        // Quiet zone
+        assert_eq!(buf[(1, 0)].symbol(), "▀");
+        assert_eq!(buf[(1, 0)].fg, Color::White);
+        assert_eq!(buf[(1, 0)].bg, Color::White);
+        // Corner of the finder pattern
+        assert_eq!(buf[(3, 1)].fg, Color::Black);
+        assert_eq!(buf[(3, 1)].bg, Color::Black);
+        // Last row only has the quiet zone in the upper half
+        assert_eq!(buf[(3, 12)].fg, Color::White);
+        assert_eq!(buf[(3, 12)].bg, Color::Reset);
+        // Too small
+        let area = Rect::new(0, 0, 24, 14);
+        let mut buf = Buffer::empty(area);
+        component.render_code(&mut buf, area);
+        assert_eq!(buf[(3, 1)].symbol(), " ");
+    }
+
+    #[test]
+    fn test_components_qr_code_inverted() {
+        let mut component = QrCode::default()
+            .foreground(Color::Blue)
+            .inverted(true)
+            .quiet_zone(0)
+            .data("hello");
+        let area = Rect::new(0, 0, 21, 11);
+        let mut buf = Buffer::empty(area);
+        component.render_code(&mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, Color::White);
+        assert_eq!(buf[(0, 10)].bg, Color::Reset);
+        // Data too long
+        component.attr(Attribute::Text, AttrValue::String("x".repeat(8000)));
+        assert_eq!(component.states.width, 0);
+        let mut buf = Buffer::empty(area);
+        component.render_code(&mut buf, area);
+        assert_eq!(buf[(0, 0)].symbol(), " ");
+    }
+}
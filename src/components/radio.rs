@@ -1,6 +1,7 @@
 //! ## Radio
 //!
-//! `Radio` component renders a radio group
+//! `Radio` component renders a radio group. When the choices don't fit in the available width, the strip is scrolled
+//! horizontally to keep the selected choice visible, with `‹` and `›` at the edges when there are choices beyond them.
 
 /**
  * MIT License
@@ -25,7 +26,10 @@
  * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
  * SOFTWARE.
  */
+use std::ops::{Deref, DerefMut};
+
 use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::event::MouseEvent;
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
     TextModifiers,
@@ -33,6 +37,18 @@ use tuirealm::props::{
 use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::{layout::Rect, widgets::Tabs};
 use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{
+    clicked, get_title_style, is_disabled, render_disabled, render_tabs_overflow, render_tooltip,
+    scroll_tabs, tab_at, tabs_strip,
+};
+
+// -- Props
+
+use super::props::TOOLTIP;
+use super::states::{Choice, ChoiceState};
 
 // -- states
 
@@ -41,53 +57,31 @@ use tuirealm::{Frame, MockComponent, State, StateValue};
 /// RadioStates contains states for this component
 #[derive(Default)]
 pub struct RadioStates {
-    pub choice: usize,        // Selected option
-    pub choices: Vec<String>, // Available choices
+    pub options: ChoiceState, // Available choices and selected option
+    pub area: Rect,           // Area of the options in the last render
+    pub offset: usize,        // First visible choice
 }
 
 impl RadioStates {
-    /// ### next_choice
+    /// ### widths
     ///
-    /// Move choice index to next choice
-    pub fn next_choice(&mut self, rewind: bool) {
-        if rewind && self.choice + 1 >= self.choices.len() {
-            self.choice = 0;
-        } else if self.choice + 1 < self.choices.len() {
-            self.choice += 1;
-        }
+    /// Get the widths of the labels of the choices
+    fn widths(&self) -> Vec<usize> {
+        self.choices.iter().map(|x| x.label.width()).collect()
     }
+}
 
-    /// ### prev_choice
-    ///
-    /// Move choice index to previous choice
-    pub fn prev_choice(&mut self, rewind: bool) {
-        if rewind && self.choice == 0 && !self.choices.is_empty() {
-            self.choice = self.choices.len() - 1;
-        } else if self.choice > 0 {
-            self.choice -= 1;
-        }
-    }
+impl Deref for RadioStates {
+    type Target = ChoiceState;
 
-    /// ### set_choices
-    ///
-    /// Set RadioStates choices from a vector of text spans
-    /// In addition resets current selection and keep index if possible or set it to the first value
-    /// available
-    pub fn set_choices(&mut self, spans: &[String]) {
-        self.choices = spans.to_vec();
-        // Keep index if possible
-        if self.choice >= self.choices.len() {
-            self.choice = match self.choices.len() {
-                0 => 0,
-                l => l - 1,
-            };
-        }
+    fn deref(&self) -> &Self::Target {
+        &self.options
     }
+}
 
-    pub fn select(&mut self, i: usize) {
-        if i < self.choices.len() {
-            self.choice = i;
-        }
+impl DerefMut for RadioStates {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
     }
 }
 
@@ -128,6 +122,28 @@ impl Radio {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `radio` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("radio", self);
+    }
+
     pub fn rewind(mut self, r: bool) -> Self {
         self.attr(Attribute::Rewind, AttrValue::Flag(r));
         self
@@ -146,6 +162,12 @@ impl Radio {
         self
     }
 
+    /// Set the choices as entries, which can be disabled and styled
+    pub fn entries(mut self, entries: &[Choice]) -> Self {
+        self.states.set_choices(entries);
+        self
+    }
+
     pub fn value(mut self, i: usize) -> Self {
         // Set state
         self.attr(
@@ -160,18 +182,30 @@ impl Radio {
             .get_or(Attribute::Rewind, AttrValue::Flag(false))
             .unwrap_flag()
     }
+
+    /// ### on_mouse
+    ///
+    /// Handle a mouse event: clicking an option selects it
+    pub fn on_mouse(&mut self, ev: MouseEvent) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let widths = self.states.widths();
+        let offset = self.states.offset.min(widths.len());
+        match clicked(&ev, self.states.area).and_then(|(x, _)| tab_at(&widths[offset..], x)) {
+            Some(choice) if self.states.is_enabled(offset + choice) => {
+                let choice = offset + choice;
+                self.states.select(choice);
+                CmdResult::Changed(self.state())
+            }
+            _ => CmdResult::None,
+        }
+    }
 }
 
 impl MockComponent for Radio {
     fn view(&mut self, render: &mut Frame, area: Rect) {
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
-            // Make choices
-            let choices: Vec<Spans> = self
-                .states
-                .choices
-                .iter()
-                .map(|x| Spans::from(x.clone()))
-                .collect();
             let foreground = self
                 .props
                 .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
@@ -193,7 +227,31 @@ impl MockComponent for Radio {
                 .props
                 .get(Attribute::FocusStyle)
                 .map(|x| x.unwrap_style());
-            let div = crate::utils::get_block(borders, title, focus, inactive_style);
+            let div = crate::utils::get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props));
+            // Scroll the choices if they don't fit, leaving a column on both sides for the indicators
+            let inner = div.inner(area);
+            let widths = self.states.widths();
+            let strip = tabs_strip(&widths, inner);
+            self.states.offset = match strip == inner {
+                true => 0,
+                false => scroll_tabs(
+                    &widths,
+                    self.states.choice,
+                    self.states.offset,
+                    strip.width as usize,
+                ),
+            };
+            self.states.area = strip;
+            let offset = self.states.offset;
+            // Make choices
+            let choices: Vec<Spans> = self
+                .states
+                .choices
+                .iter()
+                .skip(offset)
+                .map(|x| Spans::styled(x.label.clone(), x.patch_style(Style::default())))
+                .collect();
             // Make colors
             let (fg, block_color): (Color, Color) = match focus {
                 true => (foreground, foreground),
@@ -203,13 +261,25 @@ impl MockComponent for Radio {
                 true => TextModifiers::REVERSED,
                 false => TextModifiers::empty(),
             };
+            let style = Style::default().fg(block_color).bg(background);
             let radio: Tabs = Tabs::new(choices)
-                .block(div)
-                .select(self.states.choice)
-                .style(Style::default().fg(block_color).bg(background))
+                .select(self.states.choice.saturating_sub(offset))
+                .style(style)
                 .highlight_style(Style::default().fg(fg).add_modifier(modifiers));
-            render.render_widget(radio, area);
+            render.buffer_mut().set_style(area, style);
+            render.render_widget(div, area);
+            render.render_widget(radio, strip);
+            render_tabs_overflow(
+                render.buffer_mut(),
+                inner,
+                strip,
+                &widths,
+                offset,
+                Style::default().fg(foreground).bg(background),
+            );
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -243,16 +313,21 @@ impl MockComponent for Radio {
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
         match cmd {
             Cmd::Move(Direction::Right) => {
                 // Increment choice
-                self.states.next_choice(self.is_rewind());
+                let rewind = self.is_rewind();
+                self.states.next_choice(rewind);
                 // Return CmdResult On Change
                 CmdResult::Changed(self.state())
             }
             Cmd::Move(Direction::Left) => {
                 // Decrement choice
-                self.states.prev_choice(self.is_rewind());
+                let rewind = self.is_rewind();
+                self.states.prev_choice(rewind);
                 // Return CmdResult On Change
                 CmdResult::Changed(self.state())
             }
@@ -386,4 +461,138 @@ mod test {
             CmdResult::Submit(State::One(StateValue::Usize(2))),
         );
     }
+
+    #[test]
+    fn test_components_radio_entries() {
+        let mut component = Radio::default()
+            .entries(&[
+                Choice::new("Oui!").disabled(true),
+                Choice::new("Non"),
+                Choice::new("Peut-être").disabled(true),
+                Choice::new("Jamais"),
+            ])
+            .rewind(false);
+        // Disabled entries can't be selected
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(3))),
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(1))),
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(1))),
+        );
+    }
+
+    #[test]
+    fn test_components_radio_overflow() {
+        use crate::testing::{assert_lines, render};
+        use tuirealm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+        let click = |column| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+            column,
+            row: 1,
+        };
+        let mut component = Radio::default()
+            .borders(Borders::default())
+            .choices(&["one", "two", "three", "four", "five"])
+            .rewind(false);
+        let buf = render(&mut component, 22, 3);
+        assert_lines(
+            &buf,
+            &[
+                "┌────────────────────┐",
+                "│  one │ two │ three›│",
+                "└────────────────────┘",
+            ],
+        );
+        // The selected choice is kept visible
+        component.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(4))),
+        );
+        let buf = render(&mut component, 22, 3);
+        assert_eq!(component.states.offset, 3);
+        assert_lines(
+            &buf,
+            &[
+                "┌────────────────────┐",
+                "│‹ four │ five       │",
+                "└────────────────────┘",
+            ],
+        );
+        // Clicks are mapped to the visible choices
+        assert_eq!(
+            component.on_mouse(click(3)),
+            CmdResult::Changed(State::One(StateValue::Usize(3)))
+        );
+        assert_eq!(
+            component.on_mouse(click(10)),
+            CmdResult::Changed(State::One(StateValue::Usize(4)))
+        );
+        // Scroll back
+        component.perform(Cmd::Move(Direction::Left));
+        component.perform(Cmd::Move(Direction::Left));
+        let buf = render(&mut component, 22, 3);
+        assert_eq!(component.states.offset, 2);
+        assert_lines(
+            &buf,
+            &[
+                "┌────────────────────┐",
+                "│‹ three │ four │ fi›│",
+                "└────────────────────┘",
+            ],
+        );
+        // Choices which fit aren't scrolled
+        let mut component = Radio::default().choices(&["one", "two"]).value(1);
+        let buf = render(&mut component, 22, 3);
+        assert_eq!(component.states.offset, 0);
+        assert_lines(
+            &buf,
+            &[
+                "┌────────────────────┐",
+                "│ one │ two          │",
+                "└────────────────────┘",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_components_radio_mouse() {
+        use tuirealm::event::{KeyModifiers, MouseButton, MouseEventKind};
+        use tuirealm::ratatui::{backend::TestBackend, Terminal};
+
+        let click = |column, row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+            column,
+            row,
+        };
+        let mut component = Radio::default()
+            .borders(Borders::default())
+            .choices(&["Oui!", "Non", "Peut-être"])
+            .value(1);
+        let mut terminal = Terminal::new(TestBackend::new(30, 3)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 30, 3)))
+            .unwrap();
+        // " Oui! │ Non │ Peut-être "
+        assert_eq!(
+            component.on_mouse(click(16, 1)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            component.on_mouse(click(2, 1)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        // Divider
+        assert_eq!(component.on_mouse(click(7, 1)), CmdResult::None);
+        assert_eq!(component.states.choice, 0);
+    }
 }
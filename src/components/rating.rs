@@ -0,0 +1,388 @@
+//! ## Rating
+//!
+//! `Rating` renders a rating as a row of stars
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{RATING_HALF_STEPS, RATING_MAX, RATING_READ_ONLY, RATING_SYMBOLS, TOOLTIP};
+
+// -- states
+
+/// ## RatingStates
+///
+/// RatingStates contains states for this component
+#[derive(Default)]
+pub struct RatingStates {
+    pub value: f64,
+}
+
+impl RatingStates {
+    /// ### set
+    ///
+    /// Set the value, rounded to `step` and kept between 0 and `max`
+    pub fn set(&mut self, value: f64, max: usize, step: f64) {
+        self.value = ((value / step).round() * step).clamp(0.0, max as f64);
+    }
+
+    /// ### incr
+    ///
+    /// Add `delta` to the value. Returns whether the value has changed
+    pub fn incr(&mut self, delta: f64, max: usize, step: f64) -> bool {
+        let prev = self.value;
+        self.set(self.value + delta, max, step);
+        self.value != prev
+    }
+}
+
+// -- component
+
+/// ## Rating
+///
+/// A row of N symbols, filled up to the value. The value can be adjusted by whole or half steps, or just displayed
+#[derive(Default)]
+pub struct Rating {
+    props: Props,
+    pub states: RatingStates,
+}
+
+impl Rating {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `rating` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("rating", self);
+    }
+
+    /// Set the color of filled symbols; `foreground` by default
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Set the amount of symbols, which is the maximum rating (default: 5)
+    pub fn max(mut self, max: usize) -> Self {
+        self.attr(Attribute::Custom(RATING_MAX), AttrValue::Length(max));
+        self
+    }
+
+    /// Set the full, half and empty symbols (default: `★`, `⯨`, `☆`)
+    pub fn symbols<S: Into<String>>(mut self, full: S, half: S, empty: S) -> Self {
+        self.attr(
+            Attribute::Custom(RATING_SYMBOLS),
+            AttrValue::Payload(PropPayload::Tup3((
+                PropValue::Str(full.into()),
+                PropValue::Str(half.into()),
+                PropValue::Str(empty.into()),
+            ))),
+        );
+        self
+    }
+
+    /// Adjust the rating by half steps
+    pub fn half_steps(mut self, half: bool) -> Self {
+        self.attr(Attribute::Custom(RATING_HALF_STEPS), AttrValue::Flag(half));
+        self
+    }
+
+    /// Only display the rating; commands won't change it
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.attr(
+            Attribute::Custom(RATING_READ_ONLY),
+            AttrValue::Flag(read_only),
+        );
+        self
+    }
+
+    pub fn value(mut self, v: f64) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::F64(v))),
+        );
+        self
+    }
+
+    fn get_max(&self) -> usize {
+        self.props
+            .get_or(Attribute::Custom(RATING_MAX), AttrValue::Length(5))
+            .unwrap_length()
+    }
+
+    fn get_step(&self) -> f64 {
+        match self
+            .props
+            .get_or(Attribute::Custom(RATING_HALF_STEPS), AttrValue::Flag(false))
+            .unwrap_flag()
+        {
+            true => 0.5,
+            false => 1.0,
+        }
+    }
+
+    fn is_read_only(&self) -> bool {
+        self.props
+            .get_or(Attribute::Custom(RATING_READ_ONLY), AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    fn get_symbols(&self) -> (String, String, String) {
+        self.props
+            .get(Attribute::Custom(RATING_SYMBOLS))
+            .map(|x| {
+                let (full, half, empty) = x.unwrap_payload().unwrap_tup3();
+                (full.unwrap_str(), half.unwrap_str(), empty.unwrap_str())
+            })
+            .unwrap_or_else(|| (String::from("★"), String::from("⯨"), String::from("☆")))
+    }
+
+    fn set_value(&mut self, value: f64) -> CmdResult {
+        let (max, step) = (self.get_max(), self.get_step());
+        match self.states.incr(value - self.states.value, max, step) {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+
+    /// ### render_rating
+    ///
+    /// Render the symbols separated by a space on the first row of `area`; filled symbols are colored with `color`
+    fn render_rating(&self, buf: &mut Buffer, area: Rect, style: Style, color: Color) {
+        let (full, half, empty) = self.get_symbols();
+        let mut x = area.x;
+        for i in 0..self.get_max() {
+            if x >= area.right() || area.height == 0 {
+                break;
+            }
+            let (symbol, style) = match self.states.value - i as f64 {
+                v if v >= 1.0 => (full.as_str(), style.fg(color)),
+                v if v >= 0.5 => (half.as_str(), style.fg(color)),
+                _ => (empty.as_str(), style),
+            };
+            let width = area.right().saturating_sub(x) as usize;
+            (x, _) = buf.set_stringn(x, area.y, symbol, width, style);
+            x += 1;
+        }
+    }
+}
+
+impl MockComponent for Rating {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let highlighted_color = self
+                .props
+                .get_or(Attribute::HighlightedColor, AttrValue::Color(foreground))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            // A read only rating doesn't look inactive
+            let (style, color) = match focus || self.is_read_only() {
+                true => (
+                    Style::default().fg(foreground).bg(background),
+                    highlighted_color,
+                ),
+                false => {
+                    let style = inactive_style.unwrap_or_default();
+                    (style, style.fg.unwrap_or(Color::Reset))
+                }
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_rating(render.buffer_mut(), inner, style, color);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Value => {
+                let value = value.unwrap_payload().unwrap_one().unwrap_f64();
+                let (max, step) = (self.get_max(), self.get_step());
+                self.states.set(value, max, step);
+            }
+            attr => {
+                self.props.set(attr, value);
+                // Keep the value in the new bounds
+                let (max, step) = (self.get_max(), self.get_step());
+                self.states.set(self.states.value, max, step);
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::F64(self.states.value))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        if self.is_read_only() {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Move(Direction::Right) => self.set_value(self.states.value + self.get_step()),
+            Cmd::Move(Direction::Left) => self.set_value(self.states.value - self.get_step()),
+            Cmd::GoTo(Position::Begin) => self.set_value(0.0),
+            Cmd::GoTo(Position::End) => self.set_value(self.get_max() as f64),
+            Cmd::Submit => CmdResult::Submit(self.state()),
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_rating() {
+        let mut component = Rating::default()
+            .borders(Borders::default())
+            .title("Your review", Alignment::Left)
+            .max(5)
+            .value(3.7);
+        assert_eq!(component.state(), State::One(StateValue::F64(4.0)));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::F64(5.0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::Changed(State::One(StateValue::F64(0.0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        // Half steps
+        component.attr(Attribute::Custom(RATING_HALF_STEPS), AttrValue::Flag(true));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::F64(0.5)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::F64(0.5)))
+        );
+        // Max shrinks the value
+        component.attr(Attribute::Custom(RATING_MAX), AttrValue::Length(3));
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.state(), State::One(StateValue::F64(3.0)));
+        // Read only
+        component.attr(Attribute::Custom(RATING_READ_ONLY), AttrValue::Flag(true));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(component.state(), State::One(StateValue::F64(3.0)));
+    }
+
+    #[test]
+    fn test_components_rating_render() {
+        let component = Rating::default()
+            .half_steps(true)
+            .read_only(true)
+            .value(2.5);
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_rating(&mut buf, area, Style::default(), Color::Yellow);
+        let row: String = (0..area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect();
+        assert_eq!(row, "★ ★ ⯨ ☆ ☆ ");
+        assert_eq!(buf[(4, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(6, 0)].fg, Color::Reset);
+        // Custom symbols
+        let component = Rating::default().symbols("●", "◐", "○").max(3).value(1.0);
+        let mut buf = Buffer::empty(area);
+        component.render_rating(&mut buf, area, Style::default(), Color::Yellow);
+        let row: String = (0..area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect();
+        assert_eq!(row, "● ○ ○     ");
+    }
+}
@@ -0,0 +1,408 @@
+//! ## ScatterPlot
+//!
+//! A plot for clouds of (x, y) points
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, Dataset, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{
+    layout::Rect,
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Chart as TuiChart, Dataset as TuiDataset, GraphType},
+};
+use tuirealm::{Frame, MockComponent, State};
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, render_disabled, render_tooltip, FocusStyles};
+
+// -- Props
+
+use super::props::{
+    SCATTER_PLOT_TREND_LINE, SCATTER_PLOT_X_BOUNDS, SCATTER_PLOT_X_TITLE, SCATTER_PLOT_Y_BOUNDS,
+    SCATTER_PLOT_Y_TITLE, TOOLTIP,
+};
+
+/// Padding added on both sides of bounds computed from data, as a fraction of the data range
+const AUTO_BOUNDS_PADDING: f64 = 0.05;
+
+// -- component
+
+/// ## ScatterPlot
+///
+/// A component to plot unordered clouds of points. Each series is a `Dataset` with its own marker and style;
+/// the graph type of datasets is ignored, since points are never connected.
+/// Bounds are computed from data unless set, and a trend line can be displayed for each series.
+#[derive(Default)]
+pub struct ScatterPlot {
+    props: Props,
+}
+
+impl ScatterPlot {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `scatter_plot` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("scatter_plot", self);
+    }
+
+    /// Set series to plot
+    pub fn data(mut self, series: &[Dataset]) -> Self {
+        self.attr(
+            Attribute::Dataset,
+            AttrValue::Payload(PropPayload::Vec(
+                series.iter().cloned().map(PropValue::Dataset).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set x axis bounds. If not set, they're computed from data
+    pub fn x_bounds(mut self, bounds: (f64, f64)) -> Self {
+        self.attr(
+            Attribute::Custom(SCATTER_PLOT_X_BOUNDS),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::F64(bounds.0),
+                PropValue::F64(bounds.1),
+            ))),
+        );
+        self
+    }
+
+    /// Set y axis bounds. If not set, they're computed from data
+    pub fn y_bounds(mut self, bounds: (f64, f64)) -> Self {
+        self.attr(
+            Attribute::Custom(SCATTER_PLOT_Y_BOUNDS),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::F64(bounds.0),
+                PropValue::F64(bounds.1),
+            ))),
+        );
+        self
+    }
+
+    pub fn x_title<S: Into<String>>(mut self, t: S) -> Self {
+        self.attr(
+            Attribute::Custom(SCATTER_PLOT_X_TITLE),
+            AttrValue::String(t.into()),
+        );
+        self
+    }
+
+    pub fn y_title<S: Into<String>>(mut self, t: S) -> Self {
+        self.attr(
+            Attribute::Custom(SCATTER_PLOT_Y_TITLE),
+            AttrValue::String(t.into()),
+        );
+        self
+    }
+
+    /// Display the linear regression line of each series
+    pub fn trend_line(mut self, show: bool) -> Self {
+        self.attr(
+            Attribute::Custom(SCATTER_PLOT_TREND_LINE),
+            AttrValue::Flag(show),
+        );
+        self
+    }
+
+    fn get_data(&self) -> Vec<Dataset> {
+        self.props
+            .get(Attribute::Dataset)
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_vec()
+                    .into_iter()
+                    .map(|x| x.unwrap_dataset())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// ### axis_bounds
+    ///
+    /// Get bounds for an axis from properties or, if not set, from data with some padding.
+    /// `coord` extracts the coordinate of the axis from a point
+    fn axis_bounds(
+        &self,
+        attr: &'static str,
+        data: &[Dataset],
+        coord: fn(&(f64, f64)) -> f64,
+    ) -> (f64, f64) {
+        if let Some((floor, ceil)) = self
+            .props
+            .get(Attribute::Custom(attr))
+            .map(|x| x.unwrap_payload().unwrap_tup2())
+        {
+            return (floor.unwrap_f64(), ceil.unwrap_f64());
+        }
+        let (floor, ceil) = data
+            .iter()
+            .flat_map(|x| x.get_data().iter().map(coord).collect::<Vec<f64>>())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
+                (min.min(x), max.max(x))
+            });
+        if floor > ceil {
+            return (0.0, 1.0);
+        }
+        let padding = match ceil - floor {
+            range if range > 0.0 => range * AUTO_BOUNDS_PADDING,
+            _ => 1.0,
+        };
+        (floor - padding, ceil + padding)
+    }
+
+    /// ### labels
+    ///
+    /// Make labels for an axis: lower bound, middle and upper bound
+    fn labels(bounds: (f64, f64)) -> Vec<Line<'static>> {
+        let (floor, ceil) = bounds;
+        [floor, (floor + ceil) / 2.0, ceil]
+            .into_iter()
+            .map(|x| Line::from(format!("{x:.1}")))
+            .collect()
+    }
+
+    /// ### linear_regression
+    ///
+    /// Get slope and intercept of the least squares line through points.
+    /// Returns `None` if there are less than two points or all points have the same x
+    fn linear_regression(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+        let n = points.len() as f64;
+        if points.len() < 2 {
+            return None;
+        }
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let (covariance, variance) = points.iter().fold((0.0, 0.0), |(cov, var), (x, y)| {
+            (
+                cov + (x - mean_x) * (y - mean_y),
+                var + (x - mean_x) * (x - mean_x),
+            )
+        });
+        if variance == 0.0 {
+            return None;
+        }
+        let slope = covariance / variance;
+        Some((slope, mean_y - slope * mean_x))
+    }
+}
+
+impl MockComponent for ScatterPlot {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let styles =
+                FocusStyles::display(&self.props, Style::default().fg(foreground).bg(background));
+            let style = styles.style;
+            let div = styles
+                .block(borders, title)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let data = self.get_data();
+            let x_bounds = self.axis_bounds(SCATTER_PLOT_X_BOUNDS, &data, |(x, _)| *x);
+            let y_bounds = self.axis_bounds(SCATTER_PLOT_Y_BOUNDS, &data, |(_, y)| *y);
+            // Trend lines
+            let trend_lines: Vec<(Vec<(f64, f64)>, Style)> = match self
+                .props
+                .get_or(
+                    Attribute::Custom(SCATTER_PLOT_TREND_LINE),
+                    AttrValue::Flag(false),
+                )
+                .unwrap_flag()
+            {
+                true => data
+                    .iter()
+                    .filter_map(|series| {
+                        Self::linear_regression(series.get_data()).map(|(slope, intercept)| {
+                            (
+                                vec![
+                                    (x_bounds.0, slope * x_bounds.0 + intercept),
+                                    (x_bounds.1, slope * x_bounds.1 + intercept),
+                                ],
+                                series.style,
+                            )
+                        })
+                    })
+                    .collect(),
+                false => Vec::new(),
+            };
+            // Datasets
+            let mut datasets: Vec<TuiDataset> = data
+                .iter()
+                .map(|series| {
+                    TuiDataset::default()
+                        .name(series.name.clone())
+                        .marker(series.marker)
+                        .graph_type(GraphType::Scatter)
+                        .style(series.style)
+                        .data(series.get_data())
+                })
+                .collect();
+            datasets.extend(trend_lines.iter().map(|(points, style)| {
+                TuiDataset::default()
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(*style)
+                    .data(points)
+            }));
+            // Axes
+            let mut x_axis = Axis::default()
+                .bounds([x_bounds.0, x_bounds.1])
+                .labels(Self::labels(x_bounds))
+                .style(style);
+            if let Some(title) = self
+                .props
+                .get(Attribute::Custom(SCATTER_PLOT_X_TITLE))
+                .map(|x| x.unwrap_string())
+            {
+                x_axis = x_axis.title(Span::styled(title, style));
+            }
+            let mut y_axis = Axis::default()
+                .bounds([y_bounds.0, y_bounds.1])
+                .labels(Self::labels(y_bounds))
+                .style(style);
+            if let Some(title) = self
+                .props
+                .get(Attribute::Custom(SCATTER_PLOT_Y_TITLE))
+                .map(|x| x.unwrap_string())
+            {
+                y_axis = y_axis.title(Span::styled(title, style));
+            }
+            let widget = TuiChart::new(datasets)
+                .block(div)
+                .style(style)
+                .x_axis(x_axis)
+                .y_axis(y_axis);
+            render.render_widget(widget, area);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_scatter_plot() {
+        let component = ScatterPlot::default()
+            .title("height vs weight", Alignment::Center)
+            .borders(Borders::default())
+            .x_title("height")
+            .y_title("weight")
+            .trend_line(true)
+            .data(&[
+                Dataset::default().name("a").marker(Marker::Dot).data(vec![
+                    (0.0, 1.0),
+                    (10.0, 21.0),
+                    (5.0, 11.0),
+                ]),
+                Dataset::default()
+                    .name("b")
+                    .marker(Marker::Block)
+                    .data(vec![(20.0, -9.0)]),
+            ]);
+        assert_eq!(component.state(), State::None);
+        let data = component.get_data();
+        assert_eq!(data.len(), 2);
+        // Auto bounds with padding
+        assert_eq!(
+            component.axis_bounds(SCATTER_PLOT_X_BOUNDS, &data, |(x, _)| *x),
+            (-1.0, 21.0)
+        );
+        assert_eq!(
+            component.axis_bounds(SCATTER_PLOT_Y_BOUNDS, &data, |(_, y)| *y),
+            (-10.5, 22.5)
+        );
+        // Explicit bounds
+        let component = component.x_bounds((0.0, 100.0));
+        assert_eq!(
+            component.axis_bounds(SCATTER_PLOT_X_BOUNDS, &data, |(x, _)| *x),
+            (0.0, 100.0)
+        );
+        // No data
+        assert_eq!(
+            ScatterPlot::default().axis_bounds(SCATTER_PLOT_Y_BOUNDS, &[], |(_, y)| *y),
+            (0.0, 1.0)
+        );
+        // Regression
+        assert_eq!(
+            ScatterPlot::linear_regression(data[0].get_data()),
+            Some((2.0, 1.0))
+        );
+        assert_eq!(ScatterPlot::linear_regression(data[1].get_data()), None);
+        assert_eq!(
+            ScatterPlot::linear_regression(&[(1.0, 1.0), (1.0, 2.0)]),
+            None
+        );
+    }
+}
@@ -0,0 +1,479 @@
+//! ## ScrollView
+//!
+//! `ScrollView` gives scrolling to a component, rendering it in an area larger than the viewport
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::backend::TestBackend;
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect, Terminal};
+use tuirealm::{Frame, MockComponent, State};
+
+use super::scrollbar::thumb;
+use crate::animation::Animated;
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{ANIMATION_TICKS, SCROLL_VIEW_CONTENT_SIZE, TOOLTIP};
+
+// -- states
+
+/// ## ScrollViewStates
+///
+/// ScrollViewStates contains states for this component
+#[derive(Default)]
+pub struct ScrollViewStates {
+    /// First column of the content displayed
+    pub offset_x: u16,
+    /// First row of the content displayed
+    pub offset_y: u16,
+    /// Maximum first column, according to the last render
+    pub max_x: u16,
+    /// Maximum first row, according to the last render
+    pub max_y: u16,
+    /// First column displayed, scrolling towards `offset_x`
+    pub scroll_x: Animated<u16>,
+    /// First row displayed, scrolling towards `offset_y`
+    pub scroll_y: Animated<u16>,
+}
+
+impl ScrollViewStates {
+    /// ### set_bounds
+    ///
+    /// Set the maximum offsets to display `content` in `viewport`, keeping the offsets within them
+    pub fn set_bounds(&mut self, content: (u16, u16), viewport: (u16, u16)) {
+        self.max_x = content.0.saturating_sub(viewport.0);
+        self.max_y = content.1.saturating_sub(viewport.1);
+        self.scroll_by(0, 0);
+    }
+
+    /// ### scroll_by
+    ///
+    /// Move the offsets by `x` columns and `y` rows, within the bounds
+    pub fn scroll_by(&mut self, x: isize, y: isize) {
+        let scroll = |offset: u16, delta: isize, max: u16| {
+            ((offset as isize).saturating_add(delta)).clamp(0, max as isize) as u16
+        };
+        self.offset_x = scroll(self.offset_x, x, self.max_x);
+        self.offset_y = scroll(self.offset_y, y, self.max_y);
+        self.scroll_x.set(self.offset_x);
+        self.scroll_y.set(self.offset_y);
+    }
+}
+
+// -- component
+
+/// ## ScrollView
+///
+/// A wrapper giving scrolling to a component which doesn't implement it: the child is rendered off-screen, in an area
+/// of the content size, and the part of it in the viewport is displayed. Scrollbars are added when the content doesn't
+/// fit. The cursor set by the child, if any, is not displayed.
+///
+/// Scrolling commands are handled by the scroll view, and the others forwarded to the child, whose state is the state
+/// of the scroll view. When an animation is set, the content scrolls smoothly on `Cmd::Tick`, which should be
+/// performed on `Event::Tick`
+#[derive(Default)]
+pub struct ScrollView {
+    props: Props,
+    pub states: ScrollViewStates,
+    pub child: Option<Box<dyn MockComponent>>,
+    /// Off-screen terminal the child is rendered in, the size of the content
+    terminal: Option<Terminal<TestBackend>>,
+}
+
+impl ScrollView {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `scroll_view` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("scroll_view", self);
+    }
+
+    /// Set the color of the scrollbar thumbs (default: foreground)
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Set the size of the area the child is rendered in. A size of 0 is the size of the viewport
+    pub fn content_size(mut self, width: u16, height: u16) -> Self {
+        self.attr(
+            Attribute::Custom(SCROLL_VIEW_CONTENT_SIZE),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::U16(width),
+                PropValue::U16(height),
+            ))),
+        );
+        self
+    }
+
+    /// Scroll smoothly in `ticks` ticks. 0 disables the animation
+    pub fn animation(mut self, ticks: usize) -> Self {
+        self.attr(Attribute::Custom(ANIMATION_TICKS), AttrValue::Length(ticks));
+        self
+    }
+
+    pub fn child(mut self, child: Box<dyn MockComponent>) -> Self {
+        self.child = Some(child);
+        let focus = self.props.get(Attribute::Focus);
+        if let (Some(child), Some(focus)) = (self.child.as_mut(), focus) {
+            child.attr(Attribute::Focus, focus);
+        }
+        self
+    }
+
+    /// ### layout
+    ///
+    /// Get the size of the content, the viewport in `area`, and whether there are a vertical and a horizontal
+    /// scrollbar, taking a column on the right and a row at the bottom
+    fn layout(&self, area: Rect) -> ((u16, u16), Rect, bool, bool) {
+        let (width, height) = match self
+            .props
+            .get(Attribute::Custom(SCROLL_VIEW_CONTENT_SIZE))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Tup2((PropValue::U16(width), PropValue::U16(height)))) => {
+                (width, height)
+            }
+            _ => (0, 0),
+        };
+        let fits = |length: u16, viewport: u16| length == 0 || length <= viewport;
+        // A scrollbar can take the room the other one needed
+        let mut vertical = !fits(height, area.height);
+        let horizontal = !fits(width, area.width.saturating_sub(u16::from(vertical)));
+        vertical = !fits(height, area.height.saturating_sub(u16::from(horizontal)));
+        let viewport = Rect {
+            width: area.width.saturating_sub(u16::from(vertical)),
+            height: area.height.saturating_sub(u16::from(horizontal)),
+            ..area
+        };
+        let content = (width.max(viewport.width), height.max(viewport.height));
+        (content, viewport, vertical, horizontal)
+    }
+
+    /// ### render_content
+    ///
+    /// Render the child off-screen, then the part of it in the viewport and the scrollbars in `area`
+    fn render_content(&mut self, buf: &mut Buffer, area: Rect, style: Style, thumb_style: Style) {
+        if area.is_empty() {
+            return;
+        }
+        let (content, viewport, vertical, horizontal) = self.layout(area);
+        self.states
+            .set_bounds(content, (viewport.width, viewport.height));
+        // The animation may start from offsets out of the new bounds
+        let scroll_x = self.states.scroll_x.value().min(self.states.max_x);
+        let scroll_y = self.states.scroll_y.value().min(self.states.max_y);
+        // The off-screen terminal is made again only when the content size changes
+        let size = Rect::new(0, 0, content.0, content.1);
+        if self.terminal.as_ref().map(|x| x.backend().buffer().area) != Some(size) {
+            self.terminal = Terminal::new(TestBackend::new(content.0, content.1)).ok();
+        }
+        if let (Some(child), Some(terminal)) = (
+            self.child.as_mut().filter(|_| !viewport.is_empty()),
+            self.terminal.as_mut(),
+        ) {
+            let _ = terminal.draw(|f| child.view(f, f.area()));
+            let content = terminal.backend().buffer();
+            for pos in viewport.positions() {
+                let x = pos.x - viewport.x + scroll_x;
+                let y = pos.y - viewport.y + scroll_y;
+                buf[pos] = content[(x, y)].clone();
+            }
+        }
+        if vertical {
+            let (offset, len) = thumb(
+                viewport.height,
+                content.1 as usize,
+                viewport.height as usize,
+                scroll_y as usize,
+            );
+            for i in 0..viewport.height {
+                let (symbol, cell_style) = match (offset..offset + len).contains(&i) {
+                    true => ("█", thumb_style),
+                    false => ("│", style),
+                };
+                buf[(viewport.right(), viewport.y + i)]
+                    .set_symbol(symbol)
+                    .set_style(cell_style);
+            }
+        }
+        if horizontal {
+            let (offset, len) = thumb(
+                viewport.width,
+                content.0 as usize,
+                viewport.width as usize,
+                scroll_x as usize,
+            );
+            for i in 0..viewport.width {
+                let (symbol, cell_style) = match (offset..offset + len).contains(&i) {
+                    true => ("█", thumb_style),
+                    false => ("─", style),
+                };
+                buf[(viewport.x + i, viewport.bottom())]
+                    .set_symbol(symbol)
+                    .set_style(cell_style);
+            }
+        }
+    }
+}
+
+impl MockComponent for ScrollView {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let thumb_color = self
+                .props
+                .get_or(Attribute::HighlightedColor, AttrValue::Color(foreground))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_content(render.buffer_mut(), inner, style, style.fg(thumb_color));
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        if attr == Attribute::Focus {
+            if let Some(child) = self.child.as_mut() {
+                child.attr(attr, value.clone());
+            }
+        }
+        if let (Attribute::Custom(ANIMATION_TICKS), AttrValue::Length(ticks)) = (attr, &value) {
+            self.states.scroll_x.set_ticks(*ticks);
+            self.states.scroll_y.set_ticks(*ticks);
+        }
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        match &self.child {
+            Some(child) => child.state(),
+            None => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length() as isize;
+        match cmd {
+            Cmd::Move(Direction::Up) => self.states.scroll_by(0, -1),
+            Cmd::Move(Direction::Down) => self.states.scroll_by(0, 1),
+            Cmd::Move(Direction::Left) => self.states.scroll_by(-1, 0),
+            Cmd::Move(Direction::Right) => self.states.scroll_by(1, 0),
+            Cmd::Scroll(Direction::Up) => self.states.scroll_by(0, -step),
+            Cmd::Scroll(Direction::Down) => self.states.scroll_by(0, step),
+            Cmd::Scroll(Direction::Left) => self.states.scroll_by(-step, 0),
+            Cmd::Scroll(Direction::Right) => self.states.scroll_by(step, 0),
+            Cmd::GoTo(Position::Begin) => self.states.scroll_by(0, isize::MIN),
+            Cmd::GoTo(Position::End) => self.states.scroll_by(0, isize::MAX),
+            Cmd::Tick => {
+                let scrolled = self.states.scroll_x.tick() | self.states.scroll_y.tick();
+                let result = match self.child.as_mut() {
+                    Some(child) => child.perform(cmd),
+                    None => CmdResult::None,
+                };
+                return match (scrolled, result) {
+                    (true, CmdResult::None) => CmdResult::Changed(self.state()),
+                    (_, result) => result,
+                };
+            }
+            cmd => {
+                return match self.child.as_mut() {
+                    Some(child) => child.perform(cmd),
+                    None => CmdResult::None,
+                };
+            }
+        }
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::components::Paragraph;
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::props::{BorderSides, TextSpan};
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.area
+            .rows()
+            .map(|row| {
+                row.positions()
+                    .map(|pos| buf[pos].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// A paragraph of 5 lines, after the row of its title
+    fn component() -> ScrollView {
+        ScrollView::default().content_size(8, 6).child(Box::new(
+            Paragraph::default()
+                .borders(Borders::default().sides(BorderSides::NONE))
+                .text(&[
+                    TextSpan::from("line 0 a"),
+                    TextSpan::from("line 1 b"),
+                    TextSpan::from("line 2 c"),
+                    TextSpan::from("line 3 d"),
+                    TextSpan::from("line 4 e"),
+                ]),
+        ))
+    }
+
+    fn render(component: &mut ScrollView, area: Rect) -> Vec<String> {
+        let mut buf = Buffer::empty(area);
+        component.render_content(&mut buf, area, Style::default(), Style::default());
+        rows(&buf)
+    }
+
+    #[test]
+    fn test_components_scroll_view() {
+        let mut component = component();
+        assert_eq!(component.state(), State::None);
+        let area = Rect::new(0, 0, 6, 4);
+        assert_eq!(
+            render(&mut component, area),
+            vec!["     █", "line │", "line │", "███── "]
+        );
+        assert_eq!((component.states.max_x, component.states.max_y), (3, 3));
+        component.perform(Cmd::Move(Direction::Right));
+        component.perform(Cmd::Scroll(Direction::Down));
+        assert_eq!(
+            (component.states.offset_x, component.states.offset_y),
+            (1, 3)
+        );
+        assert_eq!(
+            render(&mut component, area),
+            vec!["ine 2│", "ine 3│", "ine 4█", "███── "]
+        );
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(component.states.offset_y, 0);
+    }
+
+    #[test]
+    fn test_components_scroll_view_animation() {
+        let mut component = component().animation(2);
+        let area = Rect::new(0, 0, 6, 4);
+        render(&mut component, area);
+        component.perform(Cmd::Scroll(Direction::Down));
+        assert_eq!(component.states.offset_y, 3);
+        // Not scrolled yet
+        assert_eq!(render(&mut component, area)[0], "     █");
+        assert_eq!(
+            component.perform(Cmd::Tick),
+            CmdResult::Changed(State::None)
+        );
+        assert_eq!(component.states.scroll_y.value(), 2);
+        component.perform(Cmd::Tick);
+        assert_eq!(
+            render(&mut component, area),
+            vec!["line │", "line │", "line █", "███── "]
+        );
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_scroll_view_fits() {
+        let mut component = component();
+        let area = Rect::new(0, 0, 8, 6);
+        assert_eq!(
+            render(&mut component, area),
+            vec!["        ", "line 0 a", "line 1 b", "line 2 c", "line 3 d", "line 4 e"]
+        );
+        assert_eq!((component.states.max_x, component.states.max_y), (0, 0));
+        // The vertical scrollbar takes the room of the content, which then needs a horizontal scrollbar
+        let area = Rect::new(0, 0, 8, 5);
+        assert_eq!(render(&mut component, area)[1], "line 0 █");
+        assert!(component.layout(area).3);
+        // The off-screen terminal is kept between frames, and made again when the content size changes
+        let terminal_area = |x: &ScrollView| x.terminal.as_ref().map(|x| x.backend().buffer().area);
+        assert_eq!(terminal_area(&component), Some(Rect::new(0, 0, 8, 6)));
+        let area = Rect::new(0, 0, 10, 7);
+        assert_eq!(render(&mut component, area)[1], "line 0 a  ");
+        assert_eq!(terminal_area(&component), Some(Rect::new(0, 0, 10, 7)));
+    }
+}
@@ -0,0 +1,288 @@
+//! ## Scrollbar
+//!
+//! `Scrollbar` is a standalone scrollbar, driven by props, to attach to any scrollable view
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{AttrValue, Attribute, Color, Props, Style};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+
+use crate::theme::Theme;
+use crate::utils::{render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{
+    SCROLLBAR_CONTENT_LENGTH, SCROLLBAR_HORIZONTAL, SCROLLBAR_POSITION, SCROLLBAR_THUMB_SYMBOL,
+    SCROLLBAR_TRACK_SYMBOL, SCROLLBAR_VIEWPORT_LENGTH, TOOLTIP,
+};
+
+/// ### thumb
+///
+/// Get the offset and the length of the thumb in a track of `track` cells, for a content of `content` rows of which
+/// `viewport` rows are displayed, starting from `position`. The thumb fills the track if the content fits the viewport
+pub(crate) fn thumb(track: u16, content: usize, viewport: usize, position: usize) -> (u16, u16) {
+    if content <= viewport || track == 0 {
+        return (0, track);
+    }
+    let len = ((track as usize * viewport) / content).clamp(1, track as usize);
+    let max_position = content - viewport;
+    let offset = (track as usize - len) * position.min(max_position) / max_position;
+    (offset as u16, len as u16)
+}
+
+// -- Component
+
+/// ## Scrollbar
+///
+/// A vertical or horizontal scrollbar, which fills its area. The thumb size and offset are computed from the length
+/// of the content, the length of the viewport and the scroll position, set as props by the application
+#[derive(Default)]
+pub struct Scrollbar {
+    props: Props,
+}
+
+impl Scrollbar {
+    /// Set the color of the track
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `scrollbar` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("scrollbar", self);
+    }
+
+    /// Set the color of the thumb (default: foreground)
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Display the scrollbar horizontally
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.attr(
+            Attribute::Custom(SCROLLBAR_HORIZONTAL),
+            AttrValue::Flag(horizontal),
+        );
+        self
+    }
+
+    /// Set the length of the content, in rows (or columns for a horizontal scrollbar)
+    pub fn content_length(mut self, len: usize) -> Self {
+        self.attr(
+            Attribute::Custom(SCROLLBAR_CONTENT_LENGTH),
+            AttrValue::Length(len),
+        );
+        self
+    }
+
+    /// Set the length of the viewport, which is the part of the content displayed
+    pub fn viewport_length(mut self, len: usize) -> Self {
+        self.attr(
+            Attribute::Custom(SCROLLBAR_VIEWPORT_LENGTH),
+            AttrValue::Length(len),
+        );
+        self
+    }
+
+    /// Set the scroll position, which is the first row of the content displayed
+    pub fn position(mut self, position: usize) -> Self {
+        self.attr(
+            Attribute::Custom(SCROLLBAR_POSITION),
+            AttrValue::Length(position),
+        );
+        self
+    }
+
+    /// Set the symbol of the thumb (default: `█`)
+    pub fn thumb_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.attr(
+            Attribute::Custom(SCROLLBAR_THUMB_SYMBOL),
+            AttrValue::String(symbol.into()),
+        );
+        self
+    }
+
+    /// Set the symbol of the track (default: `│`, or `─` for a horizontal scrollbar)
+    pub fn track_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.attr(
+            Attribute::Custom(SCROLLBAR_TRACK_SYMBOL),
+            AttrValue::String(symbol.into()),
+        );
+        self
+    }
+
+    fn get_length(&self, attr: &'static str) -> usize {
+        self.props
+            .get_or(Attribute::Custom(attr), AttrValue::Length(0))
+            .unwrap_length()
+    }
+
+    fn is_horizontal(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(SCROLLBAR_HORIZONTAL),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    /// ### render_scrollbar
+    ///
+    /// Render the track and the thumb along the first column of `area`, or its first row if horizontal
+    fn render_scrollbar(&self, buf: &mut Buffer, area: Rect, style: Style, thumb_style: Style) {
+        if area.is_empty() {
+            return;
+        }
+        let horizontal = self.is_horizontal();
+        let track_symbol = self
+            .props
+            .get_or(
+                Attribute::Custom(SCROLLBAR_TRACK_SYMBOL),
+                AttrValue::String(String::from(match horizontal {
+                    true => "─",
+                    false => "│",
+                })),
+            )
+            .unwrap_string();
+        let thumb_symbol = self
+            .props
+            .get_or(
+                Attribute::Custom(SCROLLBAR_THUMB_SYMBOL),
+                AttrValue::String(String::from("█")),
+            )
+            .unwrap_string();
+        let track = match horizontal {
+            true => area.width,
+            false => area.height,
+        };
+        let (offset, len) = thumb(
+            track,
+            self.get_length(SCROLLBAR_CONTENT_LENGTH),
+            self.get_length(SCROLLBAR_VIEWPORT_LENGTH),
+            self.get_length(SCROLLBAR_POSITION),
+        );
+        for i in 0..track {
+            let (symbol, cell_style) = match (offset..offset + len).contains(&i) {
+                true => (thumb_symbol.as_str(), thumb_style),
+                false => (track_symbol.as_str(), style),
+            };
+            let cell = match horizontal {
+                true => (area.x + i, area.y),
+                false => (area.x, area.y + i),
+            };
+            buf[cell].set_symbol(symbol).set_style(cell_style);
+        }
+    }
+}
+
+impl MockComponent for Scrollbar {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let thumb_color = self
+                .props
+                .get_or(Attribute::HighlightedColor, AttrValue::Color(foreground))
+                .unwrap_color();
+            let style = Style::default().fg(foreground).bg(background);
+            self.render_scrollbar(render.buffer_mut(), area, style, style.fg(thumb_color));
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn symbols(buf: &Buffer) -> String {
+        buf.area
+            .positions()
+            .map(|pos| buf[pos].symbol().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_components_scrollbar_thumb() {
+        // Content fits
+        assert_eq!(thumb(10, 5, 10, 0), (0, 10));
+        assert_eq!(thumb(10, 100, 10, 0), (0, 1));
+        assert_eq!(thumb(10, 100, 10, 90), (9, 1));
+        assert_eq!(thumb(10, 100, 10, 200), (9, 1));
+        assert_eq!(thumb(10, 40, 20, 10), (2, 5));
+        assert_eq!(thumb(0, 40, 20, 10), (0, 0));
+    }
+
+    #[test]
+    fn test_components_scrollbar() {
+        let component = Scrollbar::default()
+            .content_length(40)
+            .viewport_length(20)
+            .position(20);
+        assert_eq!(component.state(), State::None);
+        let area = Rect::new(0, 0, 1, 6);
+        let mut buf = Buffer::empty(area);
+        component.render_scrollbar(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(symbols(&buf), "│││███");
+        let component = component
+            .horizontal(true)
+            .position(0)
+            .thumb_symbol("=")
+            .track_symbol(" ");
+        let area = Rect::new(0, 0, 8, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_scrollbar(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(symbols(&buf), "====    ");
+    }
+}
@@ -0,0 +1,488 @@
+//! ## SearchBox
+//!
+//! `SearchBox` is an input field specialized for search, which reports the query only once the user stops typing
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, InputType, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use super::input::InputStates;
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::SEARCH_BOX_CMD_CLEAR;
+use super::props::{
+    INPUT_PLACEHOLDER, INPUT_PLACEHOLDER_STYLE, SEARCH_BOX_DEBOUNCE, SEARCH_BOX_MATCHES, TOOLTIP,
+};
+
+/// Prefix displayed before the query
+const PREFIX: &str = "🔍 ";
+/// Default number of ticks without keystrokes before reporting the query
+const DEFAULT_DEBOUNCE: usize = 3;
+
+// -- states
+
+/// ## SearchBoxStates
+///
+/// SearchBoxStates contains states for this component
+#[derive(Default)]
+pub struct SearchBoxStates {
+    /// Query being edited
+    pub query: InputStates,
+    /// Ticks elapsed since the last keystroke
+    pub idle_ticks: usize,
+    /// Whether the query changed since it was last reported
+    pub pending: bool,
+    /// Last query reported
+    pub reported: String,
+}
+
+impl SearchBoxStates {
+    /// ### set_query
+    ///
+    /// Replace the query, considering it as already reported
+    pub fn set_query(&mut self, query: &str) {
+        self.query = InputStates::default();
+        for ch in query.chars() {
+            self.query.append(ch, &InputType::Text, None);
+        }
+        self.reported = self.query.get_value();
+        self.pending = false;
+        self.idle_ticks = 0;
+    }
+
+    /// ### edited
+    ///
+    /// Mark the query as edited, restarting the idle count
+    pub fn edited(&mut self) {
+        self.pending = true;
+        self.idle_ticks = 0;
+    }
+
+    /// ### tick
+    ///
+    /// Count a tick without keystrokes; returns whether the query should be reported, which happens once `debounce`
+    /// ticks elapsed since the last keystroke and the query differs from the last one reported
+    pub fn tick(&mut self, debounce: usize) -> bool {
+        if !self.pending {
+            return false;
+        }
+        self.idle_ticks += 1;
+        match self.idle_ticks >= debounce {
+            true => self.flush(),
+            false => false,
+        }
+    }
+
+    /// ### flush
+    ///
+    /// Stop waiting for the idle ticks; returns whether the query differs from the last one reported
+    pub fn flush(&mut self) -> bool {
+        self.pending = false;
+        self.idle_ticks = 0;
+        let query = self.query.get_value();
+        match query != self.reported {
+            true => {
+                self.reported = query;
+                true
+            }
+            false => false,
+        }
+    }
+}
+
+// -- component
+
+/// ## SearchBox
+///
+/// An input field for search queries, displayed after a magnifier, with the number of matches on the right.
+/// Edits are not reported immediately: the query is reported with a `Changed` result on `Cmd::Tick`, once the
+/// configured number of ticks elapsed without keystrokes
+#[derive(Default)]
+pub struct SearchBox {
+    props: Props,
+    pub states: SearchBoxStates,
+}
+
+impl SearchBox {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `search_box` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("search_box", self);
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn value<S: Into<String>>(mut self, s: S) -> Self {
+        self.attr(Attribute::Value, AttrValue::String(s.into()));
+        self
+    }
+
+    pub fn placeholder<S: Into<String>>(mut self, placeholder: S, style: Style) -> Self {
+        self.attr(
+            Attribute::Custom(INPUT_PLACEHOLDER),
+            AttrValue::String(placeholder.into()),
+        );
+        self.attr(
+            Attribute::Custom(INPUT_PLACEHOLDER_STYLE),
+            AttrValue::Style(style),
+        );
+        self
+    }
+
+    /// Set the number of ticks without keystrokes after which the query is reported (default: 3).
+    /// With 0, the query is reported on every keystroke
+    pub fn debounce(mut self, ticks: usize) -> Self {
+        self.attr(
+            Attribute::Custom(SEARCH_BOX_DEBOUNCE),
+            AttrValue::Length(ticks),
+        );
+        self
+    }
+
+    /// Set the number of matches displayed on the right of the query
+    pub fn matches(mut self, matches: usize) -> Self {
+        self.attr(
+            Attribute::Custom(SEARCH_BOX_MATCHES),
+            AttrValue::Length(matches),
+        );
+        self
+    }
+
+    fn get_debounce(&self) -> usize {
+        self.props
+            .get_or(
+                Attribute::Custom(SEARCH_BOX_DEBOUNCE),
+                AttrValue::Length(DEFAULT_DEBOUNCE),
+            )
+            .unwrap_length()
+    }
+
+    /// ### counter
+    ///
+    /// Get the text of the match counter, if the number of matches is set and there is a query
+    fn counter(&self) -> Option<String> {
+        if self.states.query.input.is_empty() {
+            return None;
+        }
+        self.props
+            .get(Attribute::Custom(SEARCH_BOX_MATCHES))
+            .map(|x| match x.unwrap_length() {
+                1 => String::from("1 match"),
+                n => format!("{n} matches"),
+            })
+    }
+
+    /// ### edit
+    ///
+    /// Apply an edit to the query; when it changes, report it immediately if debounce is disabled, otherwise wait for
+    /// the idle ticks
+    fn edit<F: FnOnce(&mut InputStates)>(&mut self, f: F) -> CmdResult {
+        let prev = self.states.query.input.clone();
+        f(&mut self.states.query);
+        if prev == self.states.query.input {
+            return CmdResult::None;
+        }
+        self.states.edited();
+        match self.get_debounce() == 0 && self.states.flush() {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+
+    /// ### render_query
+    ///
+    /// Render the magnifier, the query (or the placeholder) and the match counter in the first row of `area`
+    fn render_query(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        if area.height == 0 {
+            return;
+        }
+        let counter = self.counter();
+        let counter_width = counter.as_ref().map(|x| x.width() as u16 + 1).unwrap_or(0);
+        let (x, _) = buf.set_stringn(area.x, area.y, PREFIX, area.width as usize, style);
+        let query_width = area.right().saturating_sub(x + counter_width) as usize;
+        match self.states.query.input.is_empty() {
+            true => {
+                let placeholder = self
+                    .props
+                    .get_or(
+                        Attribute::Custom(INPUT_PLACEHOLDER),
+                        AttrValue::String(String::new()),
+                    )
+                    .unwrap_string();
+                let placeholder_style = self
+                    .props
+                    .get_or(
+                        Attribute::Custom(INPUT_PLACEHOLDER_STYLE),
+                        AttrValue::Style(style),
+                    )
+                    .unwrap_style();
+                buf.set_stringn(x, area.y, placeholder, query_width, placeholder_style);
+            }
+            false => {
+                buf.set_stringn(x, area.y, self.states.query.get_value(), query_width, style);
+            }
+        }
+        if let Some(counter) = counter {
+            if counter_width <= area.width.saturating_sub(x - area.x) {
+                buf.set_string(
+                    area.right() - counter_width + 1,
+                    area.y,
+                    counter,
+                    style.add_modifier(TextModifiers::DIM),
+                );
+            }
+        }
+    }
+}
+
+impl MockComponent for SearchBox {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_query(render.buffer_mut(), inner, style);
+            // Set cursor, if focus
+            if focus && inner.height > 0 {
+                let x = inner.x
+                    + PREFIX.width() as u16
+                    + self.states.query.cursor_width(InputType::Text);
+                if x < inner.right() {
+                    render.set_cursor_position(tuirealm::ratatui::prelude::Position {
+                        x,
+                        y: inner.y,
+                    });
+                }
+            }
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        if attr == Attribute::Value {
+            self.states.set_query(&value.clone().unwrap_string());
+        }
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::String(self.states.query.get_value()))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Type(ch) => self.edit(|query| query.append(ch, &InputType::Text, None)),
+            Cmd::Delete => self.edit(|query| query.backspace()),
+            Cmd::Cancel => self.edit(|query| query.delete()),
+            Cmd::Move(Direction::Left) => {
+                self.states.query.decr_cursor();
+                CmdResult::None
+            }
+            Cmd::Move(Direction::Right) => {
+                self.states.query.incr_cursor();
+                CmdResult::None
+            }
+            Cmd::GoTo(Position::Begin) => {
+                self.states.query.cursor_at_begin();
+                CmdResult::None
+            }
+            Cmd::GoTo(Position::End) => {
+                self.states.query.cursor_at_end();
+                CmdResult::None
+            }
+            Cmd::Tick => match self.states.tick(self.get_debounce()) {
+                true => CmdResult::Changed(self.state()),
+                false => CmdResult::None,
+            },
+            Cmd::Custom(SEARCH_BOX_CMD_CLEAR) => {
+                // Clearing is reported immediately
+                self.states.query = InputStates::default();
+                match self.states.flush() {
+                    true => CmdResult::Changed(self.state()),
+                    false => CmdResult::None,
+                }
+            }
+            Cmd::Submit => {
+                // Report the query being typed, without waiting for the idle ticks
+                self.states.flush();
+                CmdResult::Submit(self.state())
+            }
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn row(buf: &Buffer) -> String {
+        (0..buf.area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect()
+    }
+
+    fn query(s: &str) -> State {
+        State::One(StateValue::String(s.to_string()))
+    }
+
+    #[test]
+    fn test_components_search_box() {
+        let mut component = SearchBox::default().debounce(2).value("fo");
+        assert_eq!(component.state(), query("fo"));
+        // Setting the value is not reported
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        assert_eq!(component.perform(Cmd::Type('o')), CmdResult::None);
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        // A keystroke restarts the idle count
+        assert_eq!(component.perform(Cmd::Type('d')), CmdResult::None);
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Tick),
+            CmdResult::Changed(query("food"))
+        );
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        // Edits going back to the reported query are not reported
+        component.perform(Cmd::Type('s'));
+        component.perform(Cmd::Delete);
+        component.perform(Cmd::Tick);
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        // Moving the cursor is not an edit
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(component.perform(Cmd::Cancel), CmdResult::None);
+        component.perform(Cmd::Tick);
+        assert_eq!(
+            component.perform(Cmd::Tick),
+            CmdResult::Changed(query("ood"))
+        );
+        // Submit reports the pending query
+        component.perform(Cmd::Type('g'));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(query("good"))
+        );
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+        // Clear
+        assert_eq!(
+            component.perform(Cmd::Custom(SEARCH_BOX_CMD_CLEAR)),
+            CmdResult::Changed(query(""))
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(SEARCH_BOX_CMD_CLEAR)),
+            CmdResult::None
+        );
+        // Without debounce, every keystroke is reported
+        component.attr(Attribute::Custom(SEARCH_BOX_DEBOUNCE), AttrValue::Length(0));
+        assert_eq!(
+            component.perform(Cmd::Type('a')),
+            CmdResult::Changed(query("a"))
+        );
+    }
+
+    #[test]
+    fn test_components_search_box_render() {
+        let component = SearchBox::default()
+            .placeholder("Search...", Style::default())
+            .matches(12);
+        let area = Rect::new(0, 0, 22, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_query(&mut buf, area, Style::default());
+        // The counter is displayed only with a query
+        assert_eq!(row(&buf), "🔍  Search...          ");
+        let mut component = component.value("rust");
+        let area = Rect::new(0, 0, 22, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_query(&mut buf, area, Style::default());
+        assert_eq!(row(&buf), "🔍  rust     12 matches");
+        component.attr(Attribute::Custom(SEARCH_BOX_MATCHES), AttrValue::Length(1));
+        let area = Rect::new(0, 0, 22, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_query(&mut buf, area, Style::default());
+        assert_eq!(row(&buf), "🔍  rust        1 match");
+    }
+}
@@ -3,18 +3,31 @@
 //! `Select` represents a select field, like in HTML. The size for the component must be 3 (border + selected) + the quantity of rows
 //! you want to display other options when opened (at least 3)
 
-use tuirealm::command::{Cmd, CmdResult, Direction};
+use std::ops::{Deref, DerefMut};
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::event::{MouseButton, MouseEvent, MouseEventKind};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, BorderSides, Borders, Color, PropPayload, PropValue, Props,
     Style, TextModifiers,
 };
 use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::{
-    layout::{Constraint, Direction as LayoutDirection, Layout, Rect},
+    layout::Rect,
     widgets::{Block, List, ListItem, ListState, Paragraph},
 };
 use tuirealm::{Frame, MockComponent, State, StateValue};
 
+use crate::theme::Theme;
+use crate::utils::{
+    clicked, get_title_style, is_disabled, popup_rect, render_disabled, render_tooltip, wheel,
+};
+
+// -- Props
+
+use super::props::{MOUSE_WHEEL_STEP, TOOLTIP};
+use super::states::{Choice, ChoiceState};
+
 // -- states
 
 /// ## SelectStates
@@ -22,61 +35,49 @@ use tuirealm::{Frame, MockComponent, State, StateValue};
 /// Component states
 #[derive(Default)]
 pub struct SelectStates {
-    /// Available choices
-    pub choices: Vec<String>,
-    /// Currently selected choice
-    pub selected: usize,
+    /// Available choices and selected choice
+    pub options: ChoiceState,
     /// Choice selected before opening the tab
     pub previously_selected: usize,
     pub tab_open: bool,
+    /// Area of the component in the last render
+    pub area: Rect,
+    /// Area of the choices in the dropdown, in the last render
+    pub dropdown: Rect,
+    /// First choice displayed in the dropdown, in the last render
+    pub offset: usize,
+}
+
+impl Deref for SelectStates {
+    type Target = ChoiceState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.options
+    }
+}
+
+impl DerefMut for SelectStates {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.options
+    }
 }
 
 impl SelectStates {
     /// ### next_choice
     ///
-    /// Move choice index to next choice
+    /// Move choice index to next choice, if the tab is open
     pub fn next_choice(&mut self, rewind: bool) {
         if self.tab_open {
-            if rewind && self.selected + 1 >= self.choices.len() {
-                self.selected = 0;
-            } else if self.selected + 1 < self.choices.len() {
-                self.selected += 1;
-            }
+            self.options.next_choice(rewind);
         }
     }
 
     /// ### prev_choice
     ///
-    /// Move choice index to previous choice
+    /// Move choice index to previous choice, if the tab is open
     pub fn prev_choice(&mut self, rewind: bool) {
         if self.tab_open {
-            if rewind && self.selected == 0 && !self.choices.is_empty() {
-                self.selected = self.choices.len() - 1;
-            } else if self.selected > 0 {
-                self.selected -= 1;
-            }
-        }
-    }
-
-    /// ### set_choices
-    ///
-    /// Set SelectStates choices from a vector of str
-    /// In addition resets current selection and keep index if possible or set it to the first value
-    /// available
-    pub fn set_choices(&mut self, choices: &[String]) {
-        self.choices = choices.to_vec();
-        // Keep index if possible
-        if self.selected >= self.choices.len() {
-            self.selected = match self.choices.len() {
-                0 => 0,
-                l => l - 1,
-            };
-        }
-    }
-
-    pub fn select(&mut self, i: usize) {
-        if i < self.choices.len() {
-            self.selected = i;
+            self.options.prev_choice(rewind);
         }
     }
 
@@ -91,14 +92,14 @@ impl SelectStates {
     ///
     /// Open tab
     pub fn open_tab(&mut self) {
-        self.previously_selected = self.selected;
+        self.previously_selected = self.choice;
         self.tab_open = true;
     }
 
     /// Cancel tab open
     pub fn cancel_tab(&mut self) {
         self.close_tab();
-        self.selected = self.previously_selected;
+        self.choice = self.previously_selected;
     }
 
     /// ### is_tab_open
@@ -154,11 +155,48 @@ impl Select {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `select` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("select", self);
+    }
+
     pub fn rewind(mut self, r: bool) -> Self {
         self.attr(Attribute::Rewind, AttrValue::Flag(r));
         self
     }
 
+    /// Set the choices skipped by `Cmd::Scroll` (default: 8)
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    /// Set the lines scrolled by each notch of the mouse wheel (default: 3)
+    pub fn wheel_step(mut self, lines: usize) -> Self {
+        self.attr(
+            Attribute::Custom(MOUSE_WHEEL_STEP),
+            AttrValue::Length(lines),
+        );
+        self
+    }
+
     pub fn choices<S: AsRef<str>>(mut self, choices: &[S]) -> Self {
         self.attr(
             Attribute::Content,
@@ -172,6 +210,12 @@ impl Select {
         self
     }
 
+    /// Set the choices as entries, which can be disabled and styled
+    pub fn entries(mut self, entries: &[Choice]) -> Self {
+        self.states.set_choices(entries);
+        self
+    }
+
     pub fn value(mut self, i: usize) -> Self {
         // Set state
         self.attr(
@@ -190,7 +234,12 @@ impl Select {
             .states
             .choices
             .iter()
-            .map(|x| ListItem::new(Spans::from(x.clone())))
+            .map(|x| {
+                ListItem::new(Spans::styled(
+                    x.label.clone(),
+                    x.patch_style(Style::default()),
+                ))
+            })
             .collect();
         let foreground = self
             .props
@@ -204,16 +253,16 @@ impl Select {
             .props
             .get_or(Attribute::HighlightedColor, AttrValue::Color(foreground))
             .unwrap_color();
-        // Prepare layout
-        let chunks = Layout::default()
-            .direction(LayoutDirection::Vertical)
-            .margin(0)
-            .constraints([Constraint::Length(2), Constraint::Min(1)].as_ref())
-            .split(area);
-        // Render like "closed" tab in chunk 0
-        let selected_text: String = match self.states.choices.get(self.states.selected) {
+        // Prepare layout: the field, then the list of choices, with its bottom border, below it
+        let field = Rect {
+            height: area.height.min(2),
+            ..area
+        };
+        let dropdown = popup_rect(field, field.width, choices.len() as u16 + 1, area);
+        // Render like "closed" tab in the field
+        let selected_text: String = match self.states.current() {
             None => String::default(),
-            Some(s) => s.clone(),
+            Some(choice) => choice.label.clone(),
         };
         let borders = self
             .props
@@ -226,7 +275,10 @@ impl Select {
             .style(Style::default().bg(background));
         let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
         let block = match title {
-            Some((text, alignment)) => block.title(text).title_alignment(alignment),
+            Some((text, alignment)) => block
+                .title(text)
+                .title_alignment(alignment)
+                .title_style(get_title_style(&self.props)),
             None => block,
         };
         let focus = self
@@ -243,20 +295,20 @@ impl Select {
                 false => inactive_style.unwrap_or_default(),
             })
             .block(block);
-        render.render_widget(p, chunks[0]);
-        // Render the list of elements in chunks [1]
+        render.render_widget(p, field);
+        // Render the list of elements in the dropdown
         // Make list
+        let dropdown_block = Block::default()
+            .borders(BorderSides::LEFT | BorderSides::BOTTOM | BorderSides::RIGHT)
+            .border_style(match focus {
+                true => borders.style(),
+                false => Style::default(),
+            })
+            .border_type(borders.modifiers)
+            .style(Style::default().bg(background));
+        self.states.dropdown = dropdown_block.inner(dropdown);
         let mut list = List::new(choices)
-            .block(
-                Block::default()
-                    .borders(BorderSides::LEFT | BorderSides::BOTTOM | BorderSides::RIGHT)
-                    .border_style(match focus {
-                        true => borders.style(),
-                        false => Style::default(),
-                    })
-                    .border_type(borders.modifiers)
-                    .style(Style::default().bg(background)),
-            )
+            .block(dropdown_block)
             .direction(tuirealm::ratatui::widgets::ListDirection::TopToBottom)
             .style(Style::default().fg(foreground).bg(background))
             .highlight_style(
@@ -273,8 +325,9 @@ impl Select {
             list = list.highlight_symbol(hg_str);
         }
         let mut state: ListState = ListState::default();
-        state.select(Some(self.states.selected));
-        render.render_stateful_widget(list, chunks[1], &mut state);
+        state.select(Some(self.states.choice));
+        render.render_stateful_widget(list, dropdown, &mut state);
+        self.states.offset = state.offset();
     }
 
     /// ### render_closed_tab
@@ -316,12 +369,15 @@ impl Select {
             .style(style);
         let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
         let block = match title {
-            Some((text, alignment)) => block.title(text).title_alignment(alignment),
+            Some((text, alignment)) => block
+                .title(text)
+                .title_alignment(alignment)
+                .title_style(get_title_style(&self.props)),
             None => block,
         };
-        let selected_text: String = match self.states.choices.get(self.states.selected) {
+        let selected_text: String = match self.states.current() {
             None => String::default(),
-            Some(s) => s.clone(),
+            Some(choice) => choice.label.clone(),
         };
         let p: Paragraph = Paragraph::new(selected_text).style(style).block(block);
         render.render_widget(p, area);
@@ -332,16 +388,75 @@ impl Select {
             .get_or(Attribute::Rewind, AttrValue::Flag(false))
             .unwrap_flag()
     }
+
+    /// ### changed
+    ///
+    /// Returns `Changed` with the current choice if it isn't `prev`, otherwise `None`
+    fn changed(&self, prev: usize) -> CmdResult {
+        match self.states.choice != prev {
+            true => CmdResult::Changed(State::One(StateValue::Usize(self.states.choice))),
+            false => CmdResult::None,
+        }
+    }
+
+    /// ### on_mouse
+    ///
+    /// Handle a mouse event: clicking the select opens the dropdown; then clicking a choice selects it and closes the
+    /// dropdown, as `Cmd::Submit`, while clicking elsewhere closes it, as `Cmd::Cancel`. While open, the wheel moves
+    /// through the choices
+    pub fn on_mouse(&mut self, ev: MouseEvent) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        if let (true, Some((direction, step))) = (
+            self.states.is_tab_open(),
+            wheel(&ev, self.states.area, &self.props),
+        ) {
+            for _ in 0..step {
+                match direction {
+                    Direction::Down => self.states.next_choice(false),
+                    _ => self.states.prev_choice(false),
+                }
+            }
+            return CmdResult::Changed(State::One(StateValue::Usize(self.states.choice)));
+        }
+        if !self.states.is_tab_open() {
+            if clicked(&ev, self.states.area).is_some() {
+                self.states.open_tab();
+            }
+            return CmdResult::None;
+        }
+        if let Some((_, row)) = clicked(&ev, self.states.dropdown) {
+            let choice = self.states.offset + row as usize;
+            if !self.states.is_enabled(choice) {
+                return CmdResult::None;
+            }
+            self.states.select(choice);
+            self.states.close_tab();
+            CmdResult::Submit(self.state())
+        } else if clicked(&ev, self.states.area).is_some() {
+            self.states.close_tab();
+            CmdResult::Submit(self.state())
+        } else if ev.kind == MouseEventKind::Down(MouseButton::Left) {
+            self.states.cancel_tab();
+            CmdResult::Changed(self.state())
+        } else {
+            CmdResult::None
+        }
+    }
 }
 
 impl MockComponent for Select {
     fn view(&mut self, render: &mut Frame, area: Rect) {
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            self.states.area = area;
             match self.states.is_tab_open() {
                 true => self.render_open_tab(render, area),
                 false => self.render_closed_tab(render, area),
             }
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -380,29 +495,67 @@ impl MockComponent for Select {
         if self.states.is_tab_open() {
             State::None
         } else {
-            State::One(StateValue::Usize(self.states.selected))
+            State::One(StateValue::Usize(self.states.choice))
         }
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
         match cmd {
             Cmd::Move(Direction::Down) => {
                 // Increment choice
-                self.states.next_choice(self.rewindable());
+                let rewind = self.rewindable();
+                self.states.next_choice(rewind);
                 // Return CmdResult On Change or None if tab is closed
                 match self.states.is_tab_open() {
                     false => CmdResult::None,
-                    true => CmdResult::Changed(State::One(StateValue::Usize(self.states.selected))),
+                    true => CmdResult::Changed(State::One(StateValue::Usize(self.states.choice))),
                 }
             }
             Cmd::Move(Direction::Up) => {
                 // Increment choice
-                self.states.prev_choice(self.rewindable());
+                let rewind = self.rewindable();
+                self.states.prev_choice(rewind);
                 // Return CmdResult On Change or None if tab is closed
                 match self.states.is_tab_open() {
                     false => CmdResult::None,
-                    true => CmdResult::Changed(State::One(StateValue::Usize(self.states.selected))),
+                    true => CmdResult::Changed(State::One(StateValue::Usize(self.states.choice))),
+                }
+            }
+            Cmd::Scroll(Direction::Down) => {
+                // Move by a step, even if the tab is closed
+                let prev = self.states.choice;
+                let step = self
+                    .props
+                    .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+                    .unwrap_length();
+                for _ in 0..step {
+                    self.states.options.next_choice(false);
+                }
+                self.changed(prev)
+            }
+            Cmd::Scroll(Direction::Up) => {
+                let prev = self.states.choice;
+                let step = self
+                    .props
+                    .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+                    .unwrap_length();
+                for _ in 0..step {
+                    self.states.options.prev_choice(false);
                 }
+                self.changed(prev)
+            }
+            Cmd::GoTo(Position::Begin) => {
+                let prev = self.states.choice;
+                self.states.first_choice();
+                self.changed(prev)
+            }
+            Cmd::GoTo(Position::End) => {
+                let prev = self.states.choice;
+                self.states.last_choice();
+                self.changed(prev)
             }
             Cmd::Cancel => {
                 self.states.cancel_tab();
@@ -435,7 +588,7 @@ mod test {
     #[test]
     fn test_components_select_states() {
         let mut states: SelectStates = SelectStates::default();
-        assert_eq!(states.selected, 0);
+        assert_eq!(states.choice, 0);
         assert_eq!(states.choices.len(), 0);
         assert_eq!(states.tab_open, false);
         let choices: &[String] = &[
@@ -445,40 +598,40 @@ mod test {
             "chocolate".to_string(),
         ];
         states.set_choices(&choices);
-        assert_eq!(states.selected, 0);
+        assert_eq!(states.choice, 0);
         assert_eq!(states.choices.len(), 4);
         // Move
         states.prev_choice(false);
-        assert_eq!(states.selected, 0);
+        assert_eq!(states.choice, 0);
         states.next_choice(false);
         // Tab is closed!!!
-        assert_eq!(states.selected, 0);
+        assert_eq!(states.choice, 0);
         states.open_tab();
         assert_eq!(states.is_tab_open(), true);
         // Now we can move
         states.next_choice(false);
-        assert_eq!(states.selected, 1);
+        assert_eq!(states.choice, 1);
         states.next_choice(false);
-        assert_eq!(states.selected, 2);
+        assert_eq!(states.choice, 2);
         // Forward overflow
         states.next_choice(false);
         states.next_choice(false);
-        assert_eq!(states.selected, 3);
+        assert_eq!(states.choice, 3);
         states.prev_choice(false);
-        assert_eq!(states.selected, 2);
+        assert_eq!(states.choice, 2);
         // Close tab
         states.close_tab();
         assert_eq!(states.is_tab_open(), false);
         states.prev_choice(false);
-        assert_eq!(states.selected, 2);
+        assert_eq!(states.choice, 2);
         // Update
         let choices: &[String] = &["lemon".to_string(), "strawberry".to_string()];
         states.set_choices(&choices);
-        assert_eq!(states.selected, 1); // Move to first index available
+        assert_eq!(states.choice, 1); // Move to first index available
         assert_eq!(states.choices.len(), 2);
-        let choices = vec![];
+        let choices: Vec<String> = vec![];
         states.set_choices(&choices);
-        assert_eq!(states.selected, 0); // Move to first index available
+        assert_eq!(states.choice, 0); // Move to first index available
         assert_eq!(states.choices.len(), 0);
         // Rewind
         let choices: &[String] = &[
@@ -489,24 +642,24 @@ mod test {
         ];
         states.set_choices(choices);
         states.open_tab();
-        assert_eq!(states.selected, 0);
+        assert_eq!(states.choice, 0);
         states.prev_choice(true);
-        assert_eq!(states.selected, 3);
+        assert_eq!(states.choice, 3);
         states.next_choice(true);
-        assert_eq!(states.selected, 0);
+        assert_eq!(states.choice, 0);
         states.next_choice(true);
-        assert_eq!(states.selected, 1);
+        assert_eq!(states.choice, 1);
         states.prev_choice(true);
-        assert_eq!(states.selected, 0);
+        assert_eq!(states.choice, 0);
         // Cancel tab
         states.close_tab();
         states.select(2);
         states.open_tab();
         states.prev_choice(true);
         states.prev_choice(true);
-        assert_eq!(states.selected, 0);
+        assert_eq!(states.choice, 0);
         states.cancel_tab();
-        assert_eq!(states.selected, 2);
+        assert_eq!(states.choice, 2);
         assert_eq!(states.is_tab_open(), false);
     }
 
@@ -588,4 +741,129 @@ mod test {
         );
         assert_eq!(component.perform(Cmd::Move(Direction::Up)), CmdResult::None);
     }
+
+    #[test]
+    fn test_components_select_entries() {
+        let mut component = Select::default()
+            .entries(&[
+                Choice::new("bronze").disabled(true),
+                Choice::new("silver"),
+                Choice::new("gold").disabled(true),
+                Choice::new("platinum"),
+            ])
+            .rewind(false);
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+        component.perform(Cmd::Submit);
+        // Disabled entries are skipped
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(3))),
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(1))),
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(1))),
+        );
+    }
+
+    #[test]
+    fn test_components_select_goto_and_scroll() {
+        let choices: Vec<String> = (0..20).map(|x| format!("choice {x}")).collect();
+        let mut component = Select::default().choices(&choices).step(5);
+        // The selection moves while the tab is closed
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::One(StateValue::Usize(19))),
+        );
+        assert_eq!(component.perform(Cmd::GoTo(Position::End)), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(14))),
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::Changed(State::One(StateValue::Usize(0))),
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(5))),
+        );
+        assert_eq!(component.state(), State::One(StateValue::Usize(5)));
+        // And while it's open, where cancel restores the previous choice
+        component.perform(Cmd::Submit);
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(10))),
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::One(StateValue::Usize(19))),
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Down)),
+            CmdResult::None,
+        );
+        component.perform(Cmd::Cancel);
+        assert_eq!(component.state(), State::One(StateValue::Usize(5)));
+        // Disabled choices are skipped
+        let mut component = Select::default().entries(&[
+            Choice::new("bronze").disabled(true),
+            Choice::new("silver"),
+            Choice::new("gold"),
+            Choice::new("platinum").disabled(true),
+        ]);
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::One(StateValue::Usize(2))),
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::Changed(State::One(StateValue::Usize(1))),
+        );
+    }
+
+    #[test]
+    fn test_components_select_mouse() {
+        use tuirealm::event::KeyModifiers;
+        use tuirealm::ratatui::{backend::TestBackend, Terminal};
+
+        let click = |column, row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+            column,
+            row,
+        };
+        let mut component = Select::default()
+            .borders(Borders::default())
+            .choices(&["Oui!", "Non", "Peut-être"])
+            .value(1);
+        let mut terminal = Terminal::new(TestBackend::new(12, 8)).unwrap();
+        let area = Rect::new(0, 0, 12, 8);
+        terminal.draw(|f| component.view(f, area)).unwrap();
+        // Open the dropdown
+        assert_eq!(component.on_mouse(click(3, 1)), CmdResult::None);
+        assert!(component.states.is_tab_open());
+        terminal.draw(|f| component.view(f, area)).unwrap();
+        assert_eq!(component.states.dropdown, Rect::new(1, 2, 10, 3));
+        // Pick an option
+        assert_eq!(
+            component.on_mouse(click(3, 4)),
+            CmdResult::Submit(State::One(StateValue::Usize(2)))
+        );
+        assert!(!component.states.is_tab_open());
+        // Click outside to cancel
+        component.on_mouse(click(3, 1));
+        assert_eq!(
+            component.on_mouse(click(20, 20)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert!(!component.states.is_tab_open());
+    }
 }
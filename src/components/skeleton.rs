@@ -0,0 +1,377 @@
+//! ## Skeleton
+//!
+//! `Skeleton` is an animated placeholder, to display while the data of a component is being loaded
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+use tuirealm::{Frame, MockComponent, State};
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, is_disabled, render_disabled, render_tooltip, FocusStyles};
+
+// -- Props
+
+use super::props::{SKELETON_SHAPE, SKELETON_SHIMMER_WIDTH, TOOLTIP};
+
+/// Width of the lines, in percent of the area; the last line is always shorter
+const LINE_WIDTHS: [u16; 4] = [100, 80, 90, 70];
+/// Width of the last line, in percent of the area
+const LAST_LINE_WIDTH: u16 = 60;
+
+/// ## SkeletonShape
+///
+/// The shape of the placeholder, which should match the component it stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkeletonShape {
+    /// Lines of text, separated by an empty row
+    Lines(usize),
+    /// A grid of blocks, separated by an empty row or column
+    Grid { rows: usize, columns: usize },
+}
+
+impl Default for SkeletonShape {
+    fn default() -> Self {
+        Self::Lines(3)
+    }
+}
+
+impl SkeletonShape {
+    fn to_payload(self) -> PropPayload {
+        let (kind, a, b) = match self {
+            Self::Lines(lines) => (0, lines, 0),
+            Self::Grid { rows, columns } => (1, rows, columns),
+        };
+        PropPayload::Tup3((
+            PropValue::U8(kind),
+            PropValue::Usize(a),
+            PropValue::Usize(b),
+        ))
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        match payload.unwrap_tup3() {
+            (PropValue::U8(0), PropValue::Usize(lines), _) => Self::Lines(lines),
+            (PropValue::U8(1), PropValue::Usize(rows), PropValue::Usize(columns)) => {
+                Self::Grid { rows, columns }
+            }
+            _ => Self::default(),
+        }
+    }
+
+    /// ### blocks
+    ///
+    /// Get the blocks to draw in `area`
+    fn blocks(self, area: Rect) -> Vec<Rect> {
+        let blocks: Vec<Rect> = match self {
+            Self::Lines(lines) => (0..lines)
+                .map(|i| {
+                    let percent = match i + 1 == lines && lines > 1 {
+                        true => LAST_LINE_WIDTH,
+                        false => LINE_WIDTHS[i % LINE_WIDTHS.len()],
+                    };
+                    Rect {
+                        y: area.y.saturating_add((i as u16).saturating_mul(2)),
+                        width: (area.width as u32 * percent as u32 / 100) as u16,
+                        height: 1,
+                        ..area
+                    }
+                })
+                .collect(),
+            Self::Grid { rows, columns } => {
+                let (rows, columns) = (rows as u16, columns as u16);
+                if rows == 0 || columns == 0 {
+                    return Vec::new();
+                }
+                let width = area.width.saturating_sub(columns - 1) / columns;
+                let height = area.height.saturating_sub(rows - 1) / rows;
+                (0..rows)
+                    .flat_map(|row| {
+                        (0..columns).map(move |column| Rect {
+                            x: area.x + column * (width + 1),
+                            y: area.y + row * (height + 1),
+                            width,
+                            height,
+                        })
+                    })
+                    .collect()
+            }
+        };
+        blocks
+            .into_iter()
+            .map(|x| x.intersection(area))
+            .filter(|x| !x.is_empty())
+            .collect()
+    }
+}
+
+// -- states
+
+/// ## SkeletonStates
+///
+/// SkeletonStates contains states for this component
+#[derive(Default)]
+pub struct SkeletonStates {
+    /// Number of ticks since the animation started
+    pub frame: usize,
+}
+
+impl SkeletonStates {
+    /// ### tick
+    ///
+    /// Advance the animation
+    pub fn tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+    }
+}
+
+// -- component
+
+/// ## Skeleton
+///
+/// A placeholder drawn as blocks, in the shape of lines or of a grid, with a shimmer going through them. The shimmer
+/// moves on `Cmd::Tick`, which should be performed on `Event::Tick`
+#[derive(Default)]
+pub struct Skeleton {
+    props: Props,
+    pub states: SkeletonStates,
+}
+
+impl Skeleton {
+    /// Set the color of the blocks (default: `DarkGray`)
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `skeleton` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("skeleton", self);
+    }
+
+    /// Set the color of the shimmer (default: `Gray`)
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn shape(mut self, shape: SkeletonShape) -> Self {
+        self.attr(
+            Attribute::Custom(SKELETON_SHAPE),
+            AttrValue::Payload(shape.to_payload()),
+        );
+        self
+    }
+
+    /// Set the width of the shimmer, in cells (default: 6); 0 disables the animation
+    pub fn shimmer_width(mut self, width: usize) -> Self {
+        self.attr(
+            Attribute::Custom(SKELETON_SHIMMER_WIDTH),
+            AttrValue::Length(width),
+        );
+        self
+    }
+
+    fn get_shape(&self) -> SkeletonShape {
+        self.props
+            .get(Attribute::Custom(SKELETON_SHAPE))
+            .map(|x| SkeletonShape::from_payload(x.unwrap_payload()))
+            .unwrap_or_default()
+    }
+
+    fn get_shimmer_width(&self) -> usize {
+        self.props
+            .get_or(
+                Attribute::Custom(SKELETON_SHIMMER_WIDTH),
+                AttrValue::Length(6),
+            )
+            .unwrap_length()
+    }
+
+    /// ### render_skeleton
+    ///
+    /// Render the blocks of the shape in `area`. The shimmer is a diagonal band, crossing the area from left to right
+    fn render_skeleton(&self, buf: &mut Buffer, area: Rect, style: Style, shimmer: Style) {
+        let band = self.get_shimmer_width();
+        let cycle = area.width as usize + area.height as usize + band;
+        let end = match band {
+            0 => 0,
+            _ => self.states.frame % cycle,
+        };
+        for block in self.get_shape().blocks(area) {
+            for (x, y) in block.positions().map(|pos| (pos.x, pos.y)) {
+                let diagonal = (x - area.x) as usize + (y - area.y) as usize;
+                let cell_style = match diagonal < end && diagonal + band >= end {
+                    true => shimmer,
+                    false => style,
+                };
+                buf[(x, y)].set_symbol("█").set_style(cell_style);
+            }
+        }
+    }
+}
+
+impl MockComponent for Skeleton {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::DarkGray))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let shimmer = self
+                .props
+                .get_or(Attribute::HighlightedColor, AttrValue::Color(Color::Gray))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let style = Style::default().fg(foreground).bg(background);
+            let block = FocusStyles::new(&self.props, style)
+                .block(borders, title)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            let buf = render.buffer_mut();
+            block.render(area, buf);
+            self.render_skeleton(buf, inner, style, style.fg(shimmer));
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Tick if self.get_shimmer_width() > 0 => {
+                self.states.tick();
+                CmdResult::Changed(State::None)
+            }
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.area
+            .rows()
+            .map(|row| {
+                row.positions()
+                    .map(|pos| buf[pos].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_components_skeleton_shape() {
+        let shape = SkeletonShape::Grid {
+            rows: 2,
+            columns: 2,
+        };
+        assert_eq!(SkeletonShape::from_payload(shape.to_payload()), shape);
+        let area = Rect::new(0, 0, 9, 5);
+        let mut buf = Buffer::empty(area);
+        let component = Skeleton::default().shape(shape);
+        component.render_skeleton(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![
+                "████ ████",
+                "████ ████",
+                "         ",
+                "████ ████",
+                "████ ████",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_components_skeleton() {
+        let mut component = Skeleton::default()
+            .shape(SkeletonShape::Lines(2))
+            .shimmer_width(3);
+        let area = Rect::new(0, 0, 10, 3);
+        let mut buf = Buffer::empty(area);
+        let shimmer = Style::default().fg(Color::Gray);
+        component.render_skeleton(&mut buf, area, Style::default(), shimmer);
+        assert_eq!(rows(&buf), vec!["██████████", "          ", "██████    "]);
+        assert_eq!(buf[(0, 0)].fg, Color::Reset);
+        // Move the shimmer
+        for _ in 0..3 {
+            assert_eq!(
+                component.perform(Cmd::Tick),
+                CmdResult::Changed(State::None)
+            );
+        }
+        let mut buf = Buffer::empty(area);
+        component.render_skeleton(&mut buf, area, Style::default(), shimmer);
+        assert_eq!(buf[(2, 0)].fg, Color::Gray);
+        assert_eq!(buf[(3, 0)].fg, Color::Reset);
+        assert_eq!(buf[(0, 2)].fg, Color::Gray);
+        assert_eq!(buf[(1, 2)].fg, Color::Reset);
+        // No animation
+        let mut component = component.shimmer_width(0);
+        assert_eq!(component.perform(Cmd::Tick), CmdResult::None);
+    }
+}
@@ -2,8 +2,11 @@
 //!
 //! `Span` represents a read-only text component without any container, but with the possibility to define multiple text parts.
 //! The main difference with `Label` is that the Span allows different styles inside the same component for the texsts.
+//! Like in `Label`, a text wider than the area is displayed according to its `Truncation`.
+//! Each part can link to a URL, which is activated by clicking it, and rendered as a hyperlink on supporting terminals.
 
 use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::MouseEvent;
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Color, PropPayload, PropValue, Props, Style, TextModifiers,
     TextSpan,
@@ -14,7 +17,19 @@ use tuirealm::ratatui::{
     text::{Span as TuiSpan, Text},
     widgets::Paragraph,
 };
-use tuirealm::{Frame, MockComponent, State};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use super::states::MarqueeState;
+use super::Truncation;
+use crate::theme::Theme;
+use crate::utils::{
+    clicked, is_disabled, render_disabled, render_hyperlink, render_tooltip, span_areas,
+    truncate_indexed_spans,
+};
+
+// -- Props
+
+use super::props::{LINKS, LINKS_OSC8, TOOLTIP, TRUNCATION};
 
 // -- Component
 
@@ -24,6 +39,9 @@ use tuirealm::{Frame, MockComponent, State};
 #[derive(Default)]
 pub struct Span {
     props: Props,
+    pub states: MarqueeState,
+    /// Index and area of each part in the last render
+    span_areas: Vec<(usize, Rect)>,
 }
 
 impl Span {
@@ -37,6 +55,28 @@ impl Span {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `span` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("span", self);
+    }
+
     pub fn modifiers(mut self, m: TextModifiers) -> Self {
         self.attr(Attribute::TextProps, AttrValue::TextModifiers(m));
         self
@@ -56,6 +96,69 @@ impl Span {
         );
         self
     }
+
+    /// Set how the text is displayed when it's wider than the area (default: `Clip`)
+    pub fn truncation(mut self, truncation: Truncation) -> Self {
+        self.attr(Attribute::Custom(TRUNCATION), truncation.to_attr_value());
+        self
+    }
+
+    /// Set the URL each span links to, if any, activated by clicking it
+    pub fn links<S: AsRef<str>>(mut self, links: &[Option<S>]) -> Self {
+        self.attr(
+            Attribute::Custom(LINKS),
+            AttrValue::Payload(PropPayload::Vec(
+                links
+                    .iter()
+                    .map(|x| {
+                        PropValue::Str(
+                            x.as_ref()
+                                .map(|x| x.as_ref())
+                                .unwrap_or_default()
+                                .to_string(),
+                        )
+                    })
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    /// Render the links as OSC 8 hyperlinks, which supporting terminals make clickable, e.g. to open them in a browser
+    pub fn hyperlinks(mut self, osc8: bool) -> Self {
+        self.attr(Attribute::Custom(LINKS_OSC8), AttrValue::Flag(osc8));
+        self
+    }
+
+    /// ### get_links
+    ///
+    /// Get the URL of each span, empty if it has no link
+    fn get_links(&self) -> Vec<String> {
+        match self
+            .props
+            .get(Attribute::Custom(LINKS))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(links)) => links.into_iter().map(|x| x.unwrap_str()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### on_mouse
+    ///
+    /// Handle a mouse event: clicking a span with a link returns `Submit(One(Usize(index)))`, with the index of the span
+    pub fn on_mouse(&mut self, ev: MouseEvent) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let links = self.get_links();
+        match self.span_areas.iter().find(|(i, area)| {
+            links.get(*i).is_some_and(|x| !x.is_empty()) && clicked(&ev, *area).is_some()
+        }) {
+            Some((i, _)) => CmdResult::Submit(State::One(StateValue::Usize(*i))),
+            None => CmdResult::None,
+        }
+    }
 }
 
 impl MockComponent for Span {
@@ -89,19 +192,47 @@ impl MockComponent for Span {
                         .collect(),
                     _ => Vec::new(),
                 };
-            let text: Text = Text::from(Spans::from(spans));
+            let truncation = Truncation::from_props(&self.props);
+            let width = area.width as usize;
+            self.states.set_overflows(
+                truncation == Truncation::Marquee
+                    && spans.iter().map(TuiSpan::width).sum::<usize>() > width,
+            );
+            let (indexes, spans): (Vec<usize>, Vec<TuiSpan>) =
+                truncate_indexed_spans(spans, width, truncation, self.states.frame)
+                    .into_iter()
+                    .unzip();
             // Text properties
             let alignment: Alignment = self
                 .props
                 .get_or(Attribute::Alignment, AttrValue::Alignment(Alignment::Left))
                 .unwrap_alignment();
+            self.span_areas = indexes
+                .into_iter()
+                .zip(span_areas(&spans, area, alignment))
+                .collect();
+            let text: Text = Text::from(Spans::from(spans));
             render.render_widget(
                 Paragraph::new(text)
                     .alignment(alignment)
                     .style(Style::default().bg(background).fg(foreground)),
                 area,
             );
+            if self
+                .props
+                .get_or(Attribute::Custom(LINKS_OSC8), AttrValue::Flag(false))
+                .unwrap_flag()
+            {
+                let links = self.get_links();
+                for (i, area) in self.span_areas.iter() {
+                    if let Some(url) = links.get(*i).filter(|x| !x.is_empty()) {
+                        render_hyperlink(render.buffer_mut(), *area, url);
+                    }
+                }
+            }
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -116,8 +247,14 @@ impl MockComponent for Span {
         State::None
     }
 
-    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
-        CmdResult::None
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Tick if self.states.tick() => CmdResult::Changed(State::None),
+            _ => CmdResult::None,
+        }
     }
 }
 
@@ -143,4 +280,57 @@ mod tests {
         // Get value
         assert_eq!(component.state(), State::None);
     }
+
+    #[test]
+    fn test_components_span_truncation() {
+        use crate::testing::{assert_lines, render};
+
+        let mut component = Span::default()
+            .spans(&[TextSpan::from("Press "), TextSpan::from("<ESC>").bold()])
+            .truncation(Truncation::End);
+        assert_lines(&render(&mut component, 8, 1), &["Press <…"]);
+        assert_lines(&render(&mut component, 12, 1), &["Press <ESC>"]);
+    }
+
+    #[test]
+    fn test_components_span_links() {
+        use tuirealm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+        let click = |column| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+            column,
+            row: 0,
+        };
+        let mut component = Span::default()
+            .spans(&[
+                TextSpan::from("see "),
+                TextSpan::from("docs"),
+                TextSpan::from(" or "),
+                TextSpan::from("repo"),
+            ])
+            .links(&[
+                None,
+                Some("https://docs.rs"),
+                None,
+                Some("https://github.com"),
+            ])
+            .hyperlinks(true);
+        let buf = crate::testing::render(&mut component, 16, 1);
+        assert_eq!(buf[(3, 0)].symbol(), " ");
+        assert_eq!(
+            buf[(4, 0)].symbol(),
+            "\x1B]8;;https://docs.rs\x1B\\do\x1B]8;;\x1B\\"
+        );
+        assert_eq!(component.on_mouse(click(2)), CmdResult::None);
+        assert_eq!(
+            component.on_mouse(click(5)),
+            CmdResult::Submit(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.on_mouse(click(12)),
+            CmdResult::Submit(State::One(StateValue::Usize(3)))
+        );
+        assert_eq!(component.on_mouse(click(9)), CmdResult::None);
+    }
 }
@@ -6,14 +6,66 @@ use tuirealm::command::{Cmd, CmdResult};
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
 };
-use tuirealm::ratatui::{layout::Rect, widgets::Sparkline as TuiSparkline};
+use tuirealm::ratatui::{
+    layout::Rect,
+    text::Span,
+    widgets::{Sparkline as TuiSparkline, SparklineBar},
+};
 use tuirealm::{Frame, MockComponent, State};
 
+use crate::theme::Theme;
+use crate::utils::{get_title_style, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{
+    SPARKLINE_BASELINE, SPARKLINE_BASELINE_STYLE, SPARKLINE_CURRENT_VALUE, SPARKLINE_DOWNSAMPLE,
+    SPARKLINE_MAX_COLOR, SPARKLINE_MIN_COLOR, TOOLTIP,
+};
+
+/// ## Downsample
+///
+/// How the entries of a sparkline wider than its area are aggregated into each column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Downsample {
+    /// The highest entry of the column
+    #[default]
+    Max,
+    /// The average of the entries of the column
+    Avg,
+    /// The last entry of the column
+    Last,
+}
+
+impl Downsample {
+    const ALL: [Self; 3] = [Self::Max, Self::Avg, Self::Last];
+
+    /// ### from_props
+    ///
+    /// Get the downsampling set in `props` with `Custom($SPARKLINE_DOWNSAMPLE)`
+    pub(crate) fn from_props(props: &Props) -> Self {
+        match props
+            .get(Attribute::Custom(SPARKLINE_DOWNSAMPLE))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::Usize(x))) => {
+                Self::ALL.get(x).copied().unwrap_or_default()
+            }
+            _ => Self::default(),
+        }
+    }
+
+    pub(crate) fn to_attr_value(self) -> AttrValue {
+        AttrValue::Payload(PropPayload::One(PropValue::Usize(self as usize)))
+    }
+}
+
 // -- component
 
 /// ## Sparkline
 ///
-/// A sparkline over more lines
+/// A sparkline over more lines.
+/// If there are more entries than columns available, data is downsampled to fit the area width
 #[derive(Default)]
 pub struct Sparkline {
     props: Props,
@@ -40,6 +92,28 @@ impl Sparkline {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `sparkline` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("sparkline", self);
+    }
+
     pub fn max_entries(mut self, max: usize) -> Self {
         self.attr(Attribute::Width, AttrValue::Length(max));
         self
@@ -55,6 +129,53 @@ impl Sparkline {
         self
     }
 
+    /// Draw a horizontal line at the provided value
+    pub fn baseline(mut self, value: u64) -> Self {
+        self.attr(
+            Attribute::Custom(SPARKLINE_BASELINE),
+            AttrValue::Payload(PropPayload::One(PropValue::U64(value))),
+        );
+        self
+    }
+
+    pub fn baseline_style(mut self, s: Style) -> Self {
+        self.attr(
+            Attribute::Custom(SPARKLINE_BASELINE_STYLE),
+            AttrValue::Style(s),
+        );
+        self
+    }
+
+    /// Highlight the lowest value with the provided color
+    pub fn min_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::Custom(SPARKLINE_MIN_COLOR), AttrValue::Color(c));
+        self
+    }
+
+    /// Highlight the highest value with the provided color
+    pub fn max_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::Custom(SPARKLINE_MAX_COLOR), AttrValue::Color(c));
+        self
+    }
+
+    /// Display the last value as a label on the right of the sparkline
+    pub fn current_value(mut self, show: bool) -> Self {
+        self.attr(
+            Attribute::Custom(SPARKLINE_CURRENT_VALUE),
+            AttrValue::Flag(show),
+        );
+        self
+    }
+
+    /// Set how entries are aggregated when data is wider than the area (default: `Downsample::Max`)
+    pub fn downsample(mut self, mode: Downsample) -> Self {
+        self.attr(
+            Attribute::Custom(SPARKLINE_DOWNSAMPLE),
+            mode.to_attr_value(),
+        );
+        self
+    }
+
     /// ### data_len
     ///
     /// Retrieve current data len from properties
@@ -86,6 +207,68 @@ impl Sparkline {
             _ => Vec::new(),
         }
     }
+
+    /// ### get_bars
+    ///
+    /// Make bars from data, highlighting the lowest and the highest values if colors are set
+    fn get_bars(&self, data: &[u64]) -> Vec<SparklineBar> {
+        let min_color = self
+            .props
+            .get(Attribute::Custom(SPARKLINE_MIN_COLOR))
+            .map(|x| x.unwrap_color());
+        let max_color = self
+            .props
+            .get(Attribute::Custom(SPARKLINE_MAX_COLOR))
+            .map(|x| x.unwrap_color());
+        let min = data.iter().min().copied();
+        let max = data.iter().max().copied();
+        data.iter()
+            .map(|x| {
+                let color = match (Some(*x) == max, Some(*x) == min) {
+                    (true, _) if max_color.is_some() => max_color,
+                    (_, true) => min_color,
+                    _ => None,
+                };
+                SparklineBar::from(*x).style(color.map(|c| Style::default().fg(c)))
+            })
+            .collect()
+    }
+
+    /// ### downsample_data
+    ///
+    /// Reduce data to `width` entries, aggregating each bucket of entries with `mode`.
+    /// Data is returned as is if it already fits
+    fn downsample_data(data: &[u64], width: usize, mode: Downsample) -> Vec<u64> {
+        if data.len() <= width || width == 0 {
+            return data.to_vec();
+        }
+        (0..width)
+            .map(|i| {
+                let bucket = &data[i * data.len() / width..(i + 1) * data.len() / width];
+                match mode {
+                    Downsample::Max => bucket.iter().max().copied().unwrap_or(0),
+                    // Summed in u128, so that it can't overflow
+                    Downsample::Avg => {
+                        (bucket.iter().map(|x| u128::from(*x)).sum::<u128>() / bucket.len() as u128)
+                            as u64
+                    }
+                    Downsample::Last => bucket.last().copied().unwrap_or(0),
+                }
+            })
+            .collect()
+    }
+
+    /// ### baseline_row
+    ///
+    /// Get the row, from the bottom of an area with `height`, where the baseline must be drawn
+    /// for a sparkline scaled on `max`
+    fn baseline_row(baseline: u64, max: u64, height: u16) -> Option<u16> {
+        if max == 0 || baseline > max || height == 0 {
+            return None;
+        }
+        let row = (baseline * u64::from(height)).div_ceil(max) as u16;
+        Some(row.saturating_sub(1))
+    }
 }
 
 impl MockComponent for Sparkline {
@@ -116,15 +299,71 @@ impl MockComponent for Sparkline {
                 .unwrap_length();
             // Get data
             let data: Vec<u64> = self.get_data(max_entries);
+            let baseline = self
+                .props
+                .get(Attribute::Custom(SPARKLINE_BASELINE))
+                .map(|x| x.unwrap_payload().unwrap_one().unwrap_u64());
+            // Bars are scaled on the amount of entries, as they've always been
+            let max = max_entries as u64;
+            let style = Style::default().fg(foreground).bg(background);
+            let div = crate::utils::get_block(borders, Some(title), false, None)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let mut inner = div.inner(area);
+            render.render_widget(div, area);
+            // Current value label
+            if let (true, Some(last)) = (
+                self.props
+                    .get_or(
+                        Attribute::Custom(SPARKLINE_CURRENT_VALUE),
+                        AttrValue::Flag(false),
+                    )
+                    .unwrap_flag(),
+                data.last(),
+            ) {
+                let label = format!(" {last}");
+                let width = (label.len() as u16).min(inner.width);
+                inner.width -= width;
+                render.render_widget(
+                    Span::styled(label, style),
+                    Rect::new(
+                        inner.right(),
+                        inner.y + inner.height.saturating_sub(1) / 2,
+                        width,
+                        inner.height.min(1),
+                    ),
+                );
+            }
+            // Fit data into area
+            let mode = Downsample::from_props(&self.props);
+            let data = Self::downsample_data(&data, inner.width as usize, mode);
             // Create widget
             let widget: TuiSparkline = TuiSparkline::default()
-                .block(crate::utils::get_block(borders, Some(title), false, None))
-                .data(data.as_slice())
-                .max(max_entries as u64)
-                .style(Style::default().fg(foreground).bg(background));
+                .data(self.get_bars(&data))
+                .max(max)
+                .style(style);
             // Render
-            render.render_widget(widget, area);
+            render.render_widget(widget, inner);
+            // Baseline; drawn only on empty cells
+            if let Some(row) = baseline.and_then(|x| Self::baseline_row(x, max, inner.height)) {
+                let baseline_style = self
+                    .props
+                    .get(Attribute::Custom(SPARKLINE_BASELINE_STYLE))
+                    .map(|x| x.unwrap_style())
+                    .unwrap_or(style);
+                let y = inner.bottom() - 1 - row;
+                let buf = render.buffer_mut();
+                for x in inner.left()..inner.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        if cell.symbol() == " " {
+                            cell.set_symbol("─").set_style(baseline_style);
+                        }
+                    }
+                }
+            }
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -168,4 +407,70 @@ mod test {
         assert_eq!(component.data_len(), 18);
         assert_eq!(component.get_data(4), vec![60, 80, 90, 88]);
     }
+
+    #[test]
+    fn test_components_sparkline_markers() {
+        let component = Sparkline::default()
+            .min_color(Color::Red)
+            .max_color(Color::Green)
+            .baseline(50)
+            .current_value(true)
+            .data(&[60, 20, 90, 20]);
+        let bars = component.get_bars(&[60, 20, 90, 20]);
+        assert_eq!(bars[0], SparklineBar::from(60));
+        assert_eq!(
+            bars[1],
+            SparklineBar::from(20).style(Some(Style::default().fg(Color::Red)))
+        );
+        assert_eq!(
+            bars[2],
+            SparklineBar::from(90).style(Some(Style::default().fg(Color::Green)))
+        );
+        assert_eq!(bars[1], bars[3]);
+        // Baseline
+        assert_eq!(Sparkline::baseline_row(50, 100, 4), Some(1));
+        assert_eq!(Sparkline::baseline_row(100, 100, 4), Some(3));
+        assert_eq!(Sparkline::baseline_row(0, 100, 4), Some(0));
+        assert_eq!(Sparkline::baseline_row(120, 100, 4), None);
+        assert_eq!(Sparkline::baseline_row(10, 0, 4), None);
+    }
+
+    #[test]
+    fn test_components_sparkline_downsample() {
+        let data = [1, 5, 2, 2, 9, 3, 4, 0];
+        assert_eq!(
+            Sparkline::downsample_data(&data, 4, Downsample::Max),
+            vec![5, 2, 9, 4]
+        );
+        assert_eq!(
+            Sparkline::downsample_data(&data, 4, Downsample::Avg),
+            vec![3, 2, 6, 2]
+        );
+        assert_eq!(
+            Sparkline::downsample_data(&data, 3, Downsample::Last),
+            vec![5, 9, 0]
+        );
+        // Large values don't overflow
+        assert_eq!(
+            Sparkline::downsample_data(&[u64::MAX, u64::MAX, 0, 2], 2, Downsample::Avg),
+            vec![u64::MAX, 1]
+        );
+        // Fits
+        assert_eq!(
+            Sparkline::downsample_data(&data, 16, Downsample::Max),
+            data.to_vec()
+        );
+        let mut component = Sparkline::default().downsample(Downsample::Avg);
+        assert_eq!(
+            component.query(Attribute::Custom(SPARKLINE_DOWNSAMPLE)),
+            Some(AttrValue::Payload(PropPayload::One(PropValue::Usize(1))))
+        );
+        assert_eq!(Downsample::from_props(&component.props), Downsample::Avg);
+        // Unknown values fall back to the default
+        component.attr(
+            Attribute::Custom(SPARKLINE_DOWNSAMPLE),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(254))),
+        );
+        assert_eq!(Downsample::from_props(&component.props), Downsample::Max);
+    }
 }
@@ -12,6 +12,13 @@ use tuirealm::ratatui::{
 };
 use tuirealm::{Frame, MockComponent, State};
 
+use crate::theme::Theme;
+use crate::utils::{render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::TOOLTIP;
+
 // -- states
 
 #[derive(Default)]
@@ -66,6 +73,28 @@ impl Spinner {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `spinner` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("spinner", self);
+    }
+
     pub fn sequence<S: Into<String>>(mut self, s: S) -> Self {
         self.attr(Attribute::Text, AttrValue::String(s.into()));
         self
@@ -94,6 +123,8 @@ impl MockComponent for Spinner {
                 area,
             );
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -0,0 +1,496 @@
+//! ## SplitPane
+//!
+//! `SplitPane` is a container displaying two components side by side, or one above the other, with a movable divider
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{
+    SPLIT_PANE_CMD_COLLAPSE_FIRST, SPLIT_PANE_CMD_COLLAPSE_SECOND, SPLIT_PANE_CMD_GROW_FIRST,
+    SPLIT_PANE_CMD_SHRINK_FIRST, SPLIT_PANE_CMD_SWITCH_PANE,
+};
+use super::props::{SPLIT_PANE_POSITION, SPLIT_PANE_VERTICAL, TOOLTIP};
+
+// -- states
+
+/// ## SplitPaneStates
+///
+/// SplitPaneStates contains states for this component
+pub struct SplitPaneStates {
+    /// Size of the first pane, as a percentage of the area
+    pub position: u16,
+    /// Index of the active pane, receiving commands and focus
+    pub active: usize,
+    /// Index of the collapsed pane, if any
+    pub collapsed: Option<usize>,
+}
+
+impl Default for SplitPaneStates {
+    fn default() -> Self {
+        Self {
+            position: 50,
+            active: 0,
+            collapsed: None,
+        }
+    }
+}
+
+impl SplitPaneStates {
+    /// ### set_position
+    ///
+    /// Set the size of the first pane, as a percentage, clamped to 100
+    pub fn set_position(&mut self, position: u16) {
+        self.position = position.min(100);
+    }
+
+    /// ### toggle_collapse
+    ///
+    /// Collapse the pane at `pane`, or expand it if collapsed. The other pane becomes active, when the active pane
+    /// is collapsed
+    pub fn toggle_collapse(&mut self, pane: usize) {
+        match self.collapsed == Some(pane) {
+            true => self.collapsed = None,
+            false => {
+                self.collapsed = Some(pane);
+                if self.active == pane {
+                    self.active = 1 - pane;
+                }
+            }
+        }
+    }
+
+    /// ### switch_pane
+    ///
+    /// Make the other pane active, unless it is collapsed. Returns whether the active pane changed
+    pub fn switch_pane(&mut self) -> bool {
+        let other = 1 - self.active;
+        match self.collapsed == Some(other) {
+            true => false,
+            false => {
+                self.active = other;
+                true
+            }
+        }
+    }
+}
+
+// -- component
+
+/// ## SplitPane
+///
+/// A container displaying two components, side by side or one above the other, separated by a divider which can be
+/// moved. A pane can be collapsed, leaving the whole area to the other one. Commands not handled by the split pane
+/// are forwarded to the active pane, which is the only one receiving focus
+#[derive(Default)]
+pub struct SplitPane {
+    props: Props,
+    pub states: SplitPaneStates,
+    /// The first pane (left or top) and the second pane (right or bottom)
+    pub panes: [Option<Box<dyn MockComponent>>; 2],
+}
+
+impl SplitPane {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `split_pane` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("split_pane", self);
+    }
+
+    /// Set the percentage of the size of the area to move the divider by (default: 5)
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    /// Stack the panes one above the other, instead of side by side
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.attr(
+            Attribute::Custom(SPLIT_PANE_VERTICAL),
+            AttrValue::Flag(vertical),
+        );
+        self
+    }
+
+    /// Set the size of the first pane, as a percentage of the area (default: 50)
+    pub fn position(mut self, percentage: u16) -> Self {
+        self.attr(
+            Attribute::Custom(SPLIT_PANE_POSITION),
+            AttrValue::Payload(PropPayload::One(PropValue::U16(percentage))),
+        );
+        self
+    }
+
+    pub fn first(mut self, pane: Box<dyn MockComponent>) -> Self {
+        self.panes[0] = Some(pane);
+        self.focus_panes();
+        self
+    }
+
+    pub fn second(mut self, pane: Box<dyn MockComponent>) -> Self {
+        self.panes[1] = Some(pane);
+        self.focus_panes();
+        self
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.props
+            .get_or(
+                Attribute::Custom(SPLIT_PANE_VERTICAL),
+                AttrValue::Flag(false),
+            )
+            .unwrap_flag()
+    }
+
+    /// ### focus_panes
+    ///
+    /// Give focus to the active pane, if the split pane is focused, and remove it from the other one
+    fn focus_panes(&mut self) {
+        let focus = self
+            .props
+            .get_or(Attribute::Focus, AttrValue::Flag(false))
+            .unwrap_flag();
+        for (i, pane) in self.panes.iter_mut().enumerate() {
+            if let Some(pane) = pane {
+                pane.attr(
+                    Attribute::Focus,
+                    AttrValue::Flag(focus && i == self.states.active),
+                );
+            }
+        }
+    }
+
+    /// ### split
+    ///
+    /// Get the areas of the first pane, of the divider and of the second pane in `area`, if displayed
+    fn split(&self, area: Rect) -> [Option<Rect>; 3] {
+        match self.states.collapsed {
+            Some(0) => return [None, None, Some(area)],
+            Some(_) => return [Some(area), None, None],
+            None => {}
+        }
+        let vertical = self.is_vertical();
+        let size = match vertical {
+            true => area.height,
+            false => area.width,
+        };
+        // The divider takes a row or a column
+        let available = size.saturating_sub(1);
+        let first = (u32::from(available) * u32::from(self.states.position) / 100) as u16;
+        let second = available - first;
+        let divider = size.min(1);
+        match vertical {
+            true => [
+                Rect::new(area.x, area.y, area.width, first),
+                Rect::new(area.x, area.y + first, area.width, divider),
+                Rect::new(area.x, area.y + first + divider, area.width, second),
+            ],
+            false => [
+                Rect::new(area.x, area.y, first, area.height),
+                Rect::new(area.x + first, area.y, divider, area.height),
+                Rect::new(area.x + first + divider, area.y, second, area.height),
+            ],
+        }
+        .map(Some)
+    }
+
+    /// ### render_divider
+    ///
+    /// Render the divider between the panes in `area`
+    fn render_divider(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let symbol = match self.is_vertical() {
+            true => "─",
+            false => "│",
+        };
+        for position in area.positions() {
+            buf[position].set_symbol(symbol).set_style(style);
+        }
+    }
+
+    /// ### layout_state
+    ///
+    /// Get the position of the divider, whether each pane is collapsed and the active pane
+    fn layout_state(&self) -> State {
+        State::Tup4((
+            StateValue::U16(self.states.position),
+            StateValue::Bool(self.states.collapsed == Some(0)),
+            StateValue::Bool(self.states.collapsed == Some(1)),
+            StateValue::Usize(self.states.active),
+        ))
+    }
+}
+
+impl MockComponent for SplitPane {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            let [first, divider, second] = self.split(inner);
+            if let Some(divider) = divider {
+                self.render_divider(render.buffer_mut(), divider, style);
+            }
+            for (pane, area) in self.panes.iter_mut().zip([first, second]) {
+                if let (Some(pane), Some(area)) = (pane, area) {
+                    pane.view(render, area);
+                }
+            }
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Focus => self.focus_panes(),
+            Attribute::Custom(SPLIT_PANE_POSITION) => {
+                if let Some(PropPayload::One(PropValue::U16(position))) =
+                    self.props.get(attr).map(|x| x.unwrap_payload())
+                {
+                    self.states.set_position(position);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        match &self.panes[self.states.active] {
+            Some(pane) => pane.state(),
+            None => State::None,
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(5))
+            .unwrap_length() as u16;
+        let prev = self.layout_state();
+        match cmd {
+            Cmd::Custom(SPLIT_PANE_CMD_GROW_FIRST) => self
+                .states
+                .set_position(self.states.position.saturating_add(step)),
+            Cmd::Custom(SPLIT_PANE_CMD_SHRINK_FIRST) => self
+                .states
+                .set_position(self.states.position.saturating_sub(step)),
+            Cmd::Custom(SPLIT_PANE_CMD_COLLAPSE_FIRST) => self.states.toggle_collapse(0),
+            Cmd::Custom(SPLIT_PANE_CMD_COLLAPSE_SECOND) => self.states.toggle_collapse(1),
+            Cmd::Custom(SPLIT_PANE_CMD_SWITCH_PANE) => {
+                self.states.switch_pane();
+            }
+            cmd => {
+                return match &mut self.panes[self.states.active] {
+                    Some(pane) => pane.perform(cmd),
+                    None => CmdResult::None,
+                }
+            }
+        }
+        self.focus_panes();
+        match self.layout_state() != prev {
+            true => CmdResult::Changed(self.layout_state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::components::Radio;
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::command::Direction;
+
+    fn component() -> SplitPane {
+        SplitPane::default()
+            .first(Box::new(Radio::default().choices(&["a", "b"])))
+            .second(Box::new(Radio::default().choices(&["c", "d", "e"])))
+    }
+
+    fn is_focused(pane: &Option<Box<dyn MockComponent>>) -> bool {
+        pane.as_ref()
+            .and_then(|x| x.query(Attribute::Focus))
+            .map(|x| x.unwrap_flag())
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_components_split_pane() {
+        let mut component = component();
+        component.attr(Attribute::Focus, AttrValue::Flag(true));
+        assert!(is_focused(&component.panes[0]));
+        assert!(!is_focused(&component.panes[1]));
+        // Commands are forwarded to the active pane
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(SPLIT_PANE_CMD_SWITCH_PANE)),
+            CmdResult::Changed(State::Tup4((
+                StateValue::U16(50),
+                StateValue::Bool(false),
+                StateValue::Bool(false),
+                StateValue::Usize(1),
+            )))
+        );
+        assert!(!is_focused(&component.panes[0]));
+        assert!(is_focused(&component.panes[1]));
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        // Divider
+        assert_eq!(
+            component.perform(Cmd::Custom(SPLIT_PANE_CMD_GROW_FIRST)),
+            CmdResult::Changed(State::Tup4((
+                StateValue::U16(55),
+                StateValue::Bool(false),
+                StateValue::Bool(false),
+                StateValue::Usize(1),
+            )))
+        );
+        component.attr(
+            Attribute::Custom(SPLIT_PANE_POSITION),
+            AttrValue::Payload(PropPayload::One(PropValue::U16(100))),
+        );
+        assert_eq!(
+            component.perform(Cmd::Custom(SPLIT_PANE_CMD_GROW_FIRST)),
+            CmdResult::None
+        );
+        // Collapsing the active pane activates the other one
+        assert_eq!(
+            component.perform(Cmd::Custom(SPLIT_PANE_CMD_COLLAPSE_SECOND)),
+            CmdResult::Changed(State::Tup4((
+                StateValue::U16(100),
+                StateValue::Bool(false),
+                StateValue::Bool(true),
+                StateValue::Usize(0),
+            )))
+        );
+        assert!(is_focused(&component.panes[0]));
+        assert_eq!(
+            component.perform(Cmd::Custom(SPLIT_PANE_CMD_SWITCH_PANE)),
+            CmdResult::None
+        );
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+        component.perform(Cmd::Custom(SPLIT_PANE_CMD_COLLAPSE_SECOND));
+        assert_eq!(component.states.collapsed, None);
+    }
+
+    #[test]
+    fn test_components_split_pane_split() {
+        let mut component = component().position(30);
+        let area = Rect::new(0, 0, 21, 10);
+        assert_eq!(
+            component.split(area),
+            [
+                Some(Rect::new(0, 0, 6, 10)),
+                Some(Rect::new(6, 0, 1, 10)),
+                Some(Rect::new(7, 0, 14, 10)),
+            ]
+        );
+        component.attr(
+            Attribute::Custom(SPLIT_PANE_VERTICAL),
+            AttrValue::Flag(true),
+        );
+        assert_eq!(
+            component.split(area),
+            [
+                Some(Rect::new(0, 0, 21, 2)),
+                Some(Rect::new(0, 2, 21, 1)),
+                Some(Rect::new(0, 3, 21, 7)),
+            ]
+        );
+        let divider = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::empty(divider);
+        component.render_divider(&mut buf, divider, Style::default());
+        assert_eq!(buf[(1, 0)].symbol(), "─");
+        // Collapsed
+        component.states.toggle_collapse(0);
+        assert_eq!(component.split(area), [None, None, Some(area)]);
+    }
+}
@@ -1,9 +1,599 @@
 //! # States
 //!
-//! This module exposes component states
+//! This module exposes component states, and the states shared by the components, which custom components can use as
+//! well: `ScrollableState`, the state of the scrollable lists of rows of `List`, `Table` and `Textarea`, and
+//! `ChoiceState`, the choices of `Select`, `Radio` and `Checkbox`, `RenderCache`, keeping what is rendered by `List`
+//! and `Table` between frames, and `MarqueeState`, the position of the text scrolling in `Label` and `Span`
+
+use std::ops::Range;
+
+use tuirealm::props::{PropValue, Style, TextModifiers};
+use tuirealm::ratatui::layout::Rect;
 
 pub use super::{
-    bar_chart::BarChartStates, chart::ChartStates, checkbox::CheckboxStates, input::InputStates,
-    list::ListStates, radio::RadioStates, select::SelectStates, spinner::SpinnerStates,
-    table::TableStates, textarea::TextareaStates,
+    accordion::AccordionStates, bar_chart::BarChartStates, breadcrumbs::BreadcrumbsStates,
+    calendar::CalendarStates, candlestick_chart::CandlestickChartStates, canvas::CanvasStates,
+    chart::ChartStates, chat_view::ChatViewStates, checkbox::CheckboxStates, clock::ClockStates,
+    color_picker::ColorPickerStates, container::ContainerStates, date_picker::DatePickerStates,
+    diff_viewer::DiffViewerStates, dual_list_transfer::DualListTransferStates,
+    file_explorer::FileExplorerStates, form::FormStates, gantt::GanttStates, grid::GridStates,
+    help_overlay::HelpOverlayStates, input::InputStates, kanban::KanbanStates, list::ListStates,
+    menu_bar::MenuBarStates, meter::MeterStates, modal::ModalStates,
+    network_graph::NetworkGraphStates, number_spinner::NumberSpinnerStates, outline::OutlineStates,
+    paginator::PaginatorStates, paragraph::ParagraphStates, radio::RadioStates,
+    rating::RatingStates, scroll_view::ScrollViewStates, search_box::SearchBoxStates,
+    select::SelectStates, skeleton::SkeletonStates, spinner::SpinnerStates,
+    split_pane::SplitPaneStates, stepper::StepperStates, table::TableStates, tabs::TabsStates,
+    tag_input::TagInputStates, textarea::TextareaStates, time_picker::TimePickerStates,
+    timeline::TimelineStates, timer::TimerStates, toggle_switch::ToggleSwitchStates,
 };
+
+#[cfg(feature = "image")]
+pub use super::image::ImageStates;
+
+#[cfg(feature = "json")]
+pub use super::json_viewer::JsonViewerStates;
+
+#[cfg(feature = "markdown")]
+pub use super::markdown::MarkdownStates;
+
+#[cfg(feature = "qrcode")]
+pub use super::qr_code::QrCodeStates;
+
+#[cfg(feature = "vt100")]
+pub use super::terminal_view::TerminalViewStates;
+
+// -- scrollable
+
+/// ## ScrollableState
+///
+/// The state of a scrollable list of rows: the selected row, the amount of rows and the window of rows displayed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScrollableState {
+    pub list_index: usize, // Index of the selected row
+    pub list_len: usize,   // Amount of rows
+    pub area: Rect,        // Area of the rows in the last render
+    pub offset: usize,     // First row displayed in the last render
+}
+
+impl ScrollableState {
+    /// ### set_list_len
+    ///
+    /// Set list length and fix list index
+    pub fn set_list_len(&mut self, len: usize) {
+        self.list_len = len;
+        self.fix_list_index();
+    }
+
+    /// ### remove_row
+    ///
+    /// Update the states after the row at `index` has been removed, keeping the selected row and the first row
+    /// displayed
+    pub fn remove_row(&mut self, index: usize) {
+        if index < self.list_index {
+            self.list_index -= 1;
+        }
+        if index < self.offset {
+            self.offset -= 1;
+        }
+        self.set_list_len(self.list_len.saturating_sub(1));
+    }
+
+    /// ### incr_list_index
+    ///
+    /// Increment list index. If `rewind`, the last row moves to the first one
+    pub fn incr_list_index(&mut self, rewind: bool) {
+        // Check if index is at last element
+        if self.list_index + 1 < self.list_len {
+            self.list_index += 1;
+        } else if rewind {
+            self.list_index = 0;
+        }
+    }
+
+    /// ### decr_list_index
+    ///
+    /// Decrement list index. If `rewind`, the first row moves to the last one
+    pub fn decr_list_index(&mut self, rewind: bool) {
+        // Check if index is bigger than 0
+        if self.list_index > 0 {
+            self.list_index -= 1;
+        } else if rewind && self.list_len > 0 {
+            self.list_index = self.list_len - 1;
+        }
+    }
+
+    /// ### fix_list_index
+    ///
+    /// Keep index if possible, otherwise set to lenght - 1
+    pub fn fix_list_index(&mut self) {
+        if self.list_index >= self.list_len && self.list_len > 0 {
+            self.list_index = self.list_len - 1;
+        } else if self.list_len == 0 {
+            self.list_index = 0;
+        }
+    }
+
+    /// ### list_index_at_first
+    ///
+    /// Set list index to the first item in the list
+    pub fn list_index_at_first(&mut self) {
+        self.list_index = 0;
+    }
+
+    /// ### list_index_at_last
+    ///
+    /// Set list index at the last item of the list
+    pub fn list_index_at_last(&mut self) {
+        if self.list_len > 0 {
+            self.list_index = self.list_len - 1;
+        } else {
+            self.list_index = 0;
+        }
+    }
+
+    /// ### calc_max_step_ahead
+    ///
+    /// Calculate the max step ahead to scroll list
+    pub fn calc_max_step_ahead(&self, max: usize) -> usize {
+        match self.list_len {
+            0 => 0,
+            len => (len - 1 - self.list_index).min(max),
+        }
+    }
+
+    /// ### calc_max_step_behind
+    ///
+    /// Calculate the max step behind to scroll list
+    pub fn calc_max_step_behind(&self, max: usize) -> usize {
+        self.list_index.min(max)
+    }
+
+    /// ### scroll_down
+    ///
+    /// Move the selection down by `step` rows, stopping at the last one
+    pub fn scroll_down(&mut self, step: usize) {
+        self.list_index += self.calc_max_step_ahead(step);
+    }
+
+    /// ### scroll_up
+    ///
+    /// Move the selection up by `step` rows, stopping at the first one
+    pub fn scroll_up(&mut self, step: usize) {
+        self.list_index -= self.calc_max_step_behind(step);
+    }
+
+    /// ### page_size
+    ///
+    /// Get the amount of rows displayed in the last render, at least 1
+    pub fn page_size(&self) -> usize {
+        (self.area.height as usize).max(1)
+    }
+
+    /// ### page_down
+    ///
+    /// Move the selection down by a page
+    pub fn page_down(&mut self) {
+        self.scroll_down(self.page_size());
+    }
+
+    /// ### page_up
+    ///
+    /// Move the selection up by a page
+    pub fn page_up(&mut self) {
+        self.scroll_up(self.page_size());
+    }
+
+    /// ### window
+    ///
+    /// Get the rows to display in `height` rows: the window starts at the last offset, and moves the least to
+    /// include the selected row
+    pub fn window(&self, height: usize) -> Range<usize> {
+        let height = height.max(1);
+        let mut start = self.offset.min(self.list_len.saturating_sub(height));
+        if self.list_index < start {
+            start = self.list_index;
+        } else if self.list_index >= start + height {
+            start = self.list_index + 1 - height;
+        }
+        start..(start + height).min(self.list_len)
+    }
+
+    /// ### row_at
+    ///
+    /// Get the index of the row displayed at `row`, relative to the area of the rows, if any
+    pub fn row_at(&self, row: u16) -> Option<usize> {
+        let index = self.offset + row as usize;
+        (index < self.list_len).then_some(index)
+    }
+}
+
+// -- choices
+
+/// ## Choice
+///
+/// A choice of `Select`, `Radio` or `Checkbox`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Choice {
+    /// Text displayed
+    pub label: String,
+    /// Value associated to the choice, for the application
+    pub value: Option<PropValue>,
+    /// Whether the choice can't be selected
+    pub disabled: bool,
+    /// Style of the choice, patched on the style of the component
+    pub style: Option<Style>,
+}
+
+impl Choice {
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Self {
+            label: label.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn value(mut self, value: PropValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// ### patch_style
+    ///
+    /// Get `style` patched with the style of the choice. Disabled choices are dimmed
+    pub fn patch_style(&self, style: Style) -> Style {
+        let style = match self.style {
+            Some(s) => style.patch(s),
+            None => style,
+        };
+        match self.disabled {
+            true => style.add_modifier(TextModifiers::DIM),
+            false => style,
+        }
+    }
+}
+
+impl From<&str> for Choice {
+    fn from(label: &str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for Choice {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<&String> for Choice {
+    fn from(label: &String) -> Self {
+        Self::new(label.as_str())
+    }
+}
+
+/// ## ChoiceState
+///
+/// A list of choices and the current one. Disabled choices are skipped when moving through them
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChoiceState {
+    pub choice: usize,        // Current choice
+    pub choices: Vec<Choice>, // Available choices
+}
+
+impl ChoiceState {
+    /// ### set_choices
+    ///
+    /// Set the choices, keeping the current index if possible, otherwise moving it to the last choice.
+    /// If it's disabled, the current choice moves to the closest enabled one
+    pub fn set_choices<C: Into<Choice> + Clone>(&mut self, choices: &[C]) {
+        self.choices = choices.iter().cloned().map(Into::into).collect();
+        self.choice = self.choice.min(self.choices.len().saturating_sub(1));
+        if !self.is_enabled(self.choice) {
+            self.next_choice(false);
+        }
+        if !self.is_enabled(self.choice) {
+            self.prev_choice(false);
+        }
+    }
+
+    /// ### next_choice
+    ///
+    /// Move to the next enabled choice. If `rewind`, the last choice moves to the first one
+    pub fn next_choice(&mut self, rewind: bool) {
+        let len = self.choices.len();
+        let mut i = self.choice;
+        for _ in 0..len {
+            i = match i + 1 < len {
+                true => i + 1,
+                false if rewind => 0,
+                false => return,
+            };
+            if self.is_enabled(i) {
+                self.choice = i;
+                return;
+            }
+        }
+    }
+
+    /// ### prev_choice
+    ///
+    /// Move to the previous enabled choice. If `rewind`, the first choice moves to the last one
+    pub fn prev_choice(&mut self, rewind: bool) {
+        let len = self.choices.len();
+        let mut i = self.choice;
+        for _ in 0..len {
+            i = match i {
+                0 if rewind => len - 1,
+                0 => return,
+                i => i - 1,
+            };
+            if self.is_enabled(i) {
+                self.choice = i;
+                return;
+            }
+        }
+    }
+
+    /// ### first_choice
+    ///
+    /// Move to the first enabled choice
+    pub fn first_choice(&mut self) {
+        if let Some(i) = (0..self.choices.len()).find(|i| self.is_enabled(*i)) {
+            self.choice = i;
+        }
+    }
+
+    /// ### last_choice
+    ///
+    /// Move to the last enabled choice
+    pub fn last_choice(&mut self) {
+        if let Some(i) = (0..self.choices.len()).rev().find(|i| self.is_enabled(*i)) {
+            self.choice = i;
+        }
+    }
+
+    /// ### select
+    ///
+    /// Move to the choice at `i`, if it exists and it's enabled
+    pub fn select(&mut self, i: usize) {
+        if self.is_enabled(i) {
+            self.choice = i;
+        }
+    }
+
+    /// ### is_enabled
+    ///
+    /// Whether the choice at `i` exists and it isn't disabled
+    pub fn is_enabled(&self, i: usize) -> bool {
+        self.choices.get(i).is_some_and(|x| !x.disabled)
+    }
+
+    /// ### current
+    ///
+    /// Get the current choice
+    pub fn current(&self) -> Option<&Choice> {
+        self.choices.get(self.choice)
+    }
+}
+
+// -- render cache
+
+/// ## RenderCache
+///
+/// Keeps what a component builds from its properties to be rendered, such as the rows of `List` and `Table`, so that
+/// it isn't rebuilt on every frame. Components invalidate it whenever their properties change, in `attr()`
+#[derive(Debug, Clone)]
+pub struct RenderCache<T> {
+    value: T,
+    dirty: bool,
+}
+
+impl<T: Default> Default for RenderCache<T> {
+    fn default() -> Self {
+        Self {
+            value: T::default(),
+            dirty: true,
+        }
+    }
+}
+
+impl<T> RenderCache<T> {
+    /// ### invalidate
+    ///
+    /// Mark the cached value as dirty, so that it's rebuilt on the next `refresh`
+    pub fn invalidate(&mut self) {
+        self.dirty = true;
+    }
+
+    /// ### is_dirty
+    ///
+    /// Whether the cached value must be rebuilt
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// ### refresh
+    ///
+    /// Rebuild the cached value with `build` if it's dirty. Returns whether it has been rebuilt
+    pub fn refresh<F: FnOnce() -> T>(&mut self, build: F) -> bool {
+        if self.dirty {
+            self.value = build();
+            self.dirty = false;
+            return true;
+        }
+        false
+    }
+
+    /// ### value
+    ///
+    /// Get the cached value
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+// -- marquee
+
+/// ## MarqueeState
+///
+/// The position of a text scrolling in a marquee, because it's wider than its area
+#[derive(Debug, Clone, Default)]
+pub struct MarqueeState {
+    /// Number of ticks since the text started scrolling
+    pub frame: usize,
+    /// Whether the text was wider than its area on the last render
+    pub overflows: bool,
+}
+
+impl MarqueeState {
+    /// ### set_overflows
+    ///
+    /// Set whether the text is wider than its area, restarting the marquee when it fits
+    pub fn set_overflows(&mut self, overflows: bool) {
+        self.overflows = overflows;
+        if !overflows {
+            self.frame = 0;
+        }
+    }
+
+    /// ### tick
+    ///
+    /// Scroll the text, if it's wider than its area. Returns whether it has scrolled
+    pub fn tick(&mut self) -> bool {
+        if self.overflows {
+            self.frame = self.frame.wrapping_add(1);
+        }
+        self.overflows
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_states_scrollable() {
+        let mut states = ScrollableState::default();
+        states.set_list_len(20);
+        states.area = Rect::new(0, 0, 10, 5);
+        // Scroll
+        states.scroll_down(8);
+        assert_eq!(states.list_index, 8);
+        states.scroll_up(10);
+        assert_eq!(states.list_index, 0);
+        states.page_down();
+        assert_eq!(states.list_index, 5);
+        states.list_index_at_last();
+        states.page_down();
+        assert_eq!(states.list_index, 19);
+        states.page_up();
+        assert_eq!(states.list_index, 14);
+        // Window
+        assert_eq!(states.window(5), 10..15);
+        states.offset = 12;
+        assert_eq!(states.window(5), 12..17);
+        states.list_index = 3;
+        assert_eq!(states.window(5), 3..8);
+        states.offset = 18;
+        states.list_index = 19;
+        assert_eq!(states.window(5), 15..20);
+        assert_eq!(states.window(50), 0..20);
+        // Rows
+        states.offset = 10;
+        assert_eq!(states.row_at(2), Some(12));
+        assert_eq!(states.row_at(10), None);
+        // Fix
+        states.set_list_len(3);
+        assert_eq!(states.list_index, 2);
+        states.set_list_len(0);
+        assert_eq!(states.list_index, 0);
+        assert_eq!(states.window(5), 0..0);
+        // Remove rows
+        states.set_list_len(10);
+        states.list_index = 6;
+        states.offset = 4;
+        states.remove_row(2);
+        assert_eq!(
+            (states.list_index, states.offset, states.list_len),
+            (5, 3, 9)
+        );
+        states.remove_row(5);
+        assert_eq!(
+            (states.list_index, states.offset, states.list_len),
+            (5, 3, 8)
+        );
+        states.remove_row(7);
+        assert_eq!(
+            (states.list_index, states.offset, states.list_len),
+            (5, 3, 7)
+        );
+    }
+
+    #[test]
+    fn test_components_states_choices() {
+        let mut states = ChoiceState::default();
+        assert_eq!(states.current(), None);
+        states.set_choices(&[
+            Choice::new("lemon").disabled(true),
+            Choice::new("strawberry").value(PropValue::U8(2)),
+            Choice::new("vanilla").disabled(true),
+            Choice::new("chocolate"),
+        ]);
+        // Disabled choices are skipped
+        assert_eq!(states.choice, 1);
+        assert_eq!(states.current().unwrap().value, Some(PropValue::U8(2)));
+        states.next_choice(false);
+        assert_eq!(states.choice, 3);
+        states.next_choice(false);
+        assert_eq!(states.choice, 3);
+        states.next_choice(true);
+        assert_eq!(states.choice, 1);
+        states.prev_choice(false);
+        assert_eq!(states.choice, 1);
+        states.prev_choice(true);
+        assert_eq!(states.choice, 3);
+        states.select(2);
+        assert_eq!(states.choice, 3);
+        assert!(!states.is_enabled(4));
+        // First and last enabled choices
+        states.first_choice();
+        assert_eq!(states.choice, 1);
+        states.last_choice();
+        assert_eq!(states.choice, 3);
+        // Keep the index
+        states.set_choices(&["a", "b"]);
+        assert_eq!(states.choice, 1);
+        assert_eq!(states.current(), Some(&Choice::new("b")));
+        // All disabled
+        states.set_choices(&[Choice::new("a").disabled(true)]);
+        states.next_choice(true);
+        assert_eq!(states.choice, 0);
+    }
+
+    #[test]
+    fn test_components_states_render_cache() {
+        let mut cache: RenderCache<Vec<&str>> = RenderCache::default();
+        assert!(cache.is_dirty());
+        assert!(cache.refresh(|| vec!["omar"]));
+        assert!(!cache.is_dirty());
+        // Not rebuilt while clean
+        assert!(!cache.refresh(|| vec!["hello"]));
+        assert_eq!(cache.value(), &vec!["omar"]);
+        cache.invalidate();
+        assert!(cache.refresh(|| vec!["hello"]));
+        assert_eq!(cache.value(), &vec!["hello"]);
+    }
+}
@@ -0,0 +1,340 @@
+//! ## StatusBar
+//!
+//! `StatusBar` renders a single line bar with a left, a center and a right section
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    AttrValue, Attribute, Color, PropPayload, PropValue, Props, Style, TextSpan,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{render_disabled, render_tooltip, use_or_default_styles};
+
+// -- Props
+
+use super::props::{
+    STATUS_BAR_CENTER, STATUS_BAR_LEFT, STATUS_BAR_PRIORITY, STATUS_BAR_RIGHT, TOOLTIP,
+};
+
+const ELLIPSIS: &str = "…";
+
+/// ## StatusBarSection
+///
+/// A section of the status bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusBarSection {
+    Left,
+    Center,
+    Right,
+}
+
+impl StatusBarSection {
+    const ALL: [Self; 3] = [Self::Left, Self::Center, Self::Right];
+
+    fn attr(self) -> Attribute {
+        Attribute::Custom(match self {
+            Self::Left => STATUS_BAR_LEFT,
+            Self::Center => STATUS_BAR_CENTER,
+            Self::Right => STATUS_BAR_RIGHT,
+        })
+    }
+}
+
+/// ### allocate
+///
+/// Share `width` columns among sections, given their `widths` (left, center, right), in `priority` order.
+/// A section gets as many columns as it needs, if available, plus a column to separate it from the others
+fn allocate(widths: [usize; 3], priority: &[StatusBarSection], width: usize) -> [usize; 3] {
+    let mut allocated = [0; 3];
+    let mut available = width;
+    let mut placed = false;
+    for section in priority {
+        let i = *section as usize;
+        if widths[i] == 0 || allocated[i] > 0 {
+            continue;
+        }
+        let gap = usize::from(placed);
+        let columns = widths[i].min(available.saturating_sub(gap));
+        if columns > 0 {
+            allocated[i] = columns;
+            available -= columns + gap;
+            placed = true;
+        }
+    }
+    allocated
+}
+
+/// ## StatusBar
+///
+/// A single line bar made of three sections of styled spans: left aligned, centered and right aligned.
+/// When space is tight, sections are truncated according to their priority
+#[derive(Default)]
+pub struct StatusBar {
+    props: Props,
+}
+
+impl StatusBar {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `status_bar` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("status_bar", self);
+    }
+
+    /// Set the spans of the left section
+    pub fn left(self, spans: &[TextSpan]) -> Self {
+        self.section(StatusBarSection::Left, spans)
+    }
+
+    /// Set the spans of the center section
+    pub fn center(self, spans: &[TextSpan]) -> Self {
+        self.section(StatusBarSection::Center, spans)
+    }
+
+    /// Set the spans of the right section
+    pub fn right(self, spans: &[TextSpan]) -> Self {
+        self.section(StatusBarSection::Right, spans)
+    }
+
+    /// Set the spans of `section`
+    pub fn section(mut self, section: StatusBarSection, spans: &[TextSpan]) -> Self {
+        self.attr(
+            section.attr(),
+            AttrValue::Payload(PropPayload::Vec(
+                spans.iter().cloned().map(PropValue::TextSpan).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set which sections keep their space when the bar is too narrow, from the most important.
+    /// By default the left section comes first, then the right one, then the center one
+    pub fn priority(mut self, sections: &[StatusBarSection]) -> Self {
+        self.attr(
+            Attribute::Custom(STATUS_BAR_PRIORITY),
+            AttrValue::Payload(PropPayload::Vec(
+                sections
+                    .iter()
+                    .map(|x| PropValue::Usize(*x as usize))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    fn get_spans(&self, section: StatusBarSection) -> Vec<TextSpan> {
+        match self.props.get(section.attr()).map(|x| x.unwrap_payload()) {
+            Some(PropPayload::Vec(spans)) => {
+                spans.into_iter().map(|x| x.unwrap_text_span()).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_priority(&self) -> Vec<StatusBarSection> {
+        match self
+            .props
+            .get(Attribute::Custom(STATUS_BAR_PRIORITY))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(sections)) => sections
+                .into_iter()
+                .filter_map(|x| StatusBarSection::ALL.get(x.unwrap_usize()).copied())
+                .collect(),
+            _ => vec![
+                StatusBarSection::Left,
+                StatusBarSection::Right,
+                StatusBarSection::Center,
+            ],
+        }
+    }
+
+    /// ### render_spans
+    ///
+    /// Render `spans` from `x`, in at most `width` columns. If they don't fit, they're truncated with an ellipsis
+    fn render_spans(&self, buf: &mut Buffer, x: u16, y: u16, spans: &[TextSpan], width: usize) {
+        let total: usize = spans.iter().map(|x| x.content.width()).sum();
+        let truncated = total > width;
+        let mut available = match truncated {
+            true => width.saturating_sub(ELLIPSIS.width()),
+            false => width,
+        };
+        let mut x = x;
+        let mut last_style = Style::default();
+        for span in spans {
+            // The ellipsis takes the style of the truncated span
+            let (fg, bg, modifiers) = use_or_default_styles(&self.props, span);
+            last_style = Style::default().fg(fg).bg(bg).add_modifier(modifiers);
+            if available == 0 {
+                break;
+            }
+            let (next_x, _) = buf.set_stringn(x, y, &span.content, available, last_style);
+            available = available.saturating_sub((next_x - x) as usize);
+            x = next_x;
+        }
+        if truncated && width > 0 {
+            buf.set_stringn(x, y, ELLIPSIS, 1, last_style);
+        }
+    }
+
+    /// ### render_bar
+    ///
+    /// Render the sections on the first row of `area`
+    fn render_bar(&self, buf: &mut Buffer, area: Rect) {
+        if area.height == 0 {
+            return;
+        }
+        let spans = StatusBarSection::ALL.map(|x| self.get_spans(x));
+        let widths = spans
+            .each_ref()
+            .map(|x| x.iter().map(|x| x.content.width()).sum::<usize>());
+        let [left, center, right] =
+            allocate(widths, &self.get_priority(), area.width as usize).map(|x| x as u16);
+        let [left_spans, center_spans, right_spans] = &spans;
+        self.render_spans(buf, area.x, area.y, left_spans, left as usize);
+        self.render_spans(
+            buf,
+            area.right() - right,
+            area.y,
+            right_spans,
+            right as usize,
+        );
+        // Center in the bar, without overlapping the other sections
+        let min_x = area.x + left + u16::from(left > 0);
+        let max_x = (area.right() - right)
+            .saturating_sub(u16::from(right > 0))
+            .saturating_sub(center)
+            .max(min_x);
+        let x = (area.x + (area.width - center) / 2).clamp(min_x, max_x);
+        self.render_spans(buf, x, area.y, center_spans, center as usize);
+    }
+}
+
+impl MockComponent for StatusBar {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let buf = render.buffer_mut();
+            buf.set_style(area, Style::default().fg(foreground).bg(background));
+            self.render_bar(buf, area);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value)
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn row(buf: &Buffer) -> String {
+        (0..buf.area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_components_status_bar_allocate() {
+        use StatusBarSection::*;
+        let priority = [Left, Right, Center];
+        assert_eq!(allocate([6, 8, 5], &priority, 30), [6, 8, 5]);
+        // Center gets what's left, minus the gaps
+        assert_eq!(allocate([6, 8, 5], &priority, 16), [6, 3, 5]);
+        assert_eq!(allocate([6, 8, 5], &priority, 8), [6, 0, 1]);
+        assert_eq!(allocate([6, 8, 5], &[Center, Left], 10), [1, 8, 0]);
+        assert_eq!(allocate([0, 8, 5], &priority, 10), [0, 4, 5]);
+    }
+
+    #[test]
+    fn test_components_status_bar() {
+        let mut component = StatusBar::default()
+            .left(&[
+                TextSpan::from("NORMAL").fg(Color::Black).bg(Color::Green),
+                TextSpan::from(" main"),
+            ])
+            .center(&[TextSpan::from("lib.rs")])
+            .right(&[TextSpan::from("12:4")]);
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        let area = Rect::new(0, 0, 30, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_bar(&mut buf, area);
+        assert_eq!(row(&buf), "NORMAL main lib.rs        12:4");
+        assert_eq!(buf[(0, 0)].bg, Color::Green);
+        assert_eq!(buf[(6, 0)].bg, Color::Reset);
+        // Narrow: center is truncated first
+        let area = Rect::new(0, 0, 18, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_bar(&mut buf, area);
+        assert_eq!(row(&buf), "NORMAL main … 12:4");
+        let area = Rect::new(0, 0, 9, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_bar(&mut buf, area);
+        assert_eq!(row(&buf), "NORMAL m…");
+        // Priority
+        component.attr(
+            Attribute::Custom(STATUS_BAR_PRIORITY),
+            AttrValue::Payload(PropPayload::Vec(vec![
+                PropValue::Usize(StatusBarSection::Right as usize),
+                PropValue::Usize(StatusBarSection::Left as usize),
+            ])),
+        );
+        let mut buf = Buffer::empty(area);
+        component.render_bar(&mut buf, area);
+        assert_eq!(row(&buf), "NOR… 12:4");
+    }
+}
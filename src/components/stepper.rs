@@ -0,0 +1,468 @@
+//! ## Stepper
+//!
+//! `Stepper` displays the progress through a sequence of steps
+
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{STEPPER_DONE_STYLE, STEPPER_UPCOMING_STYLE, TOOLTIP};
+
+/// Connector between two steps
+const CONNECTOR: &str = " ─── ";
+
+// -- states
+
+/// ## StepperStates
+///
+/// StepperStates contains states for this component
+#[derive(Default)]
+pub struct StepperStates {
+    /// Index of the current step
+    pub current: usize,
+    /// Number of steps
+    pub len: usize,
+    /// Whether all the steps are done
+    pub completed: bool,
+}
+
+impl StepperStates {
+    /// ### set_len
+    ///
+    /// Set the number of steps, keeping the current step in range
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len;
+        self.current = self.current.min(len.saturating_sub(1));
+    }
+
+    /// ### next_step
+    ///
+    /// Go to the next step; on the last step, complete the sequence
+    pub fn next_step(&mut self) {
+        if self.current + 1 < self.len {
+            self.current += 1;
+        } else if self.len > 0 {
+            self.completed = true;
+        }
+    }
+
+    /// ### prev_step
+    ///
+    /// Go back to the previous step; if completed, go back to the last step
+    pub fn prev_step(&mut self) {
+        match self.completed {
+            true => self.completed = false,
+            false => self.current = self.current.saturating_sub(1),
+        }
+    }
+
+    /// ### select
+    ///
+    /// Go to the step at `index`, clamped to the steps
+    pub fn select(&mut self, index: usize) {
+        self.current = index.min(self.len.saturating_sub(1));
+        self.completed = false;
+    }
+}
+
+// -- component
+
+/// ## Stepper
+///
+/// A sequence of named steps, such as a setup wizard, in a row. Steps before the current one are done, and the ones
+/// after it are upcoming; each kind has its own style. The sequence is completed going past the last step
+#[derive(Default)]
+pub struct Stepper {
+    props: Props,
+    pub states: StepperStates,
+}
+
+impl Stepper {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `stepper` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("stepper", self);
+    }
+
+    pub fn alignment(mut self, a: Alignment) -> Self {
+        self.attr(Attribute::Alignment, AttrValue::Alignment(a));
+        self
+    }
+
+    /// Set the color of the current step
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    /// Set the style of the steps done (default: green)
+    pub fn done_style(mut self, s: Style) -> Self {
+        self.attr(Attribute::Custom(STEPPER_DONE_STYLE), AttrValue::Style(s));
+        self
+    }
+
+    /// Set the style of the upcoming steps (default: dark gray)
+    pub fn upcoming_style(mut self, s: Style) -> Self {
+        self.attr(
+            Attribute::Custom(STEPPER_UPCOMING_STYLE),
+            AttrValue::Style(s),
+        );
+        self
+    }
+
+    pub fn steps<S: AsRef<str>>(mut self, steps: &[S]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(
+                steps
+                    .iter()
+                    .map(|x| PropValue::Str(x.as_ref().to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    pub fn current_step(mut self, index: usize) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(index))),
+        );
+        self
+    }
+
+    fn get_steps(&self) -> Vec<String> {
+        match self
+            .props
+            .get(Attribute::Content)
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(steps)) => steps.into_iter().map(|x| x.unwrap_str()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### render_steps
+    ///
+    /// Render the steps in the first row of `area`. When the names don't fit, only the name of the current step is
+    /// displayed
+    fn render_steps(&self, buf: &mut Buffer, area: Rect, style: Style, current_style: Style) {
+        if area.height == 0 {
+            return;
+        }
+        let done_style = self
+            .props
+            .get_or(
+                Attribute::Custom(STEPPER_DONE_STYLE),
+                AttrValue::Style(style.fg(Color::Green)),
+            )
+            .unwrap_style();
+        let upcoming_style = self
+            .props
+            .get_or(
+                Attribute::Custom(STEPPER_UPCOMING_STYLE),
+                AttrValue::Style(style.fg(Color::DarkGray)),
+            )
+            .unwrap_style();
+        let states = &self.states;
+        let is_done = |i: usize| states.completed || i < states.current;
+        let steps: Vec<(String, Style)> = self
+            .get_steps()
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| match i {
+                i if is_done(i) => (format!("✔ {name}"), done_style),
+                i if i == states.current => (format!("● {name}"), current_style),
+                _ => (format!("○ {name}"), upcoming_style),
+            })
+            .collect();
+        let width = |steps: &[(String, Style)]| {
+            steps.iter().map(|(x, _)| x.width()).sum::<usize>()
+                + CONNECTOR.width() * steps.len().saturating_sub(1)
+        };
+        let steps: Vec<(String, Style)> = match width(&steps) > area.width as usize {
+            true => steps
+                .into_iter()
+                .enumerate()
+                .map(|(i, (text, style))| {
+                    let current = i == states.current && !states.completed;
+                    match current {
+                        true => (text, style),
+                        false => (text.chars().take(1).collect(), style),
+                    }
+                })
+                .collect(),
+            false => steps,
+        };
+        let width = width(&steps) as u16;
+        let mut x = match self
+            .props
+            .get_or(
+                Attribute::Alignment,
+                AttrValue::Alignment(Alignment::Center),
+            )
+            .unwrap_alignment()
+        {
+            Alignment::Left => area.x,
+            Alignment::Center => area.x + area.width.saturating_sub(width) / 2,
+            Alignment::Right => area.x + area.width.saturating_sub(width),
+        };
+        for (i, (text, step_style)) in steps.iter().enumerate() {
+            if i > 0 {
+                // Connectors up to the current step are done
+                let connector_style = match is_done(i) || i == states.current {
+                    true => done_style,
+                    false => upcoming_style,
+                };
+                (x, _) = buf.set_stringn(
+                    x,
+                    area.y,
+                    CONNECTOR,
+                    area.right().saturating_sub(x) as usize,
+                    connector_style,
+                );
+            }
+            (x, _) = buf.set_stringn(
+                x,
+                area.y,
+                text,
+                area.right().saturating_sub(x) as usize,
+                *step_style,
+            );
+        }
+    }
+}
+
+impl MockComponent for Stepper {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let mut current_style = style.add_modifier(TextModifiers::BOLD);
+            if let Some(color) = self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color())
+            {
+                current_style = current_style.fg(color);
+            }
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_steps(render.buffer_mut(), inner, style, current_style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Content => {
+                let len = self.get_steps().len();
+                self.states.set_len(len);
+            }
+            Attribute::Value => {
+                if let Some(PropPayload::One(PropValue::Usize(index))) =
+                    self.props.get(attr).map(|x| x.unwrap_payload())
+                {
+                    self.states.select(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.len {
+            0 => State::None,
+            _ => State::Tup2((
+                StateValue::Usize(self.states.current),
+                StateValue::Bool(self.states.completed),
+            )),
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let prev = self.state();
+        match cmd {
+            Cmd::Move(Direction::Right) => self.states.next_step(),
+            Cmd::Move(Direction::Left) => self.states.prev_step(),
+            Cmd::Submit => {
+                return match self.state() {
+                    State::None => CmdResult::None,
+                    state => CmdResult::Submit(state),
+                }
+            }
+            _ => return CmdResult::None,
+        }
+        match self.state() != prev {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn row(buf: &Buffer) -> String {
+        (0..buf.area.width)
+            .map(|x| buf[(x, 0)].symbol().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_components_stepper() {
+        let mut component = Stepper::default().steps(&["Account", "Profile", "Confirm"]);
+        assert_eq!(
+            component.state(),
+            State::Tup2((StateValue::Usize(0), StateValue::Bool(false)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::Tup2((StateValue::Usize(1), StateValue::Bool(false))))
+        );
+        component.perform(Cmd::Move(Direction::Right));
+        // Going past the last step completes the sequence
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::Tup2((StateValue::Usize(2), StateValue::Bool(true))))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::Tup2((StateValue::Usize(2), StateValue::Bool(true))))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::Tup2((StateValue::Usize(2), StateValue::Bool(false))))
+        );
+        component.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(0))),
+        );
+        assert_eq!(component.states.current, 0);
+        // No steps
+        component.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(Vec::new())),
+        );
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_stepper_render() {
+        let component = Stepper::default()
+            .steps(&["Account", "Profile", "Confirm"])
+            .current_step(1)
+            .alignment(Alignment::Left);
+        let area = Rect::new(0, 0, 42, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_steps(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(row(&buf), "✔ Account ─── ● Profile ─── ○ Confirm     ");
+        assert_eq!(buf[(0, 0)].fg, Color::Green);
+        assert_eq!(buf[(10, 0)].fg, Color::Green);
+        assert_eq!(buf[(28, 0)].fg, Color::DarkGray);
+        // Names of other steps are hidden, when they don't fit
+        let area = Rect::new(0, 0, 24, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_steps(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(row(&buf), "✔ ─── ● Profile ─── ○   ");
+    }
+}
@@ -2,10 +2,15 @@
 //!
 //! `Table` represents a read-only textual table component which can be scrollable through arrows or inactive
 
-use super::props::TABLE_COLUMN_SPACING;
+use super::props::{
+    MOUSE_WHEEL_STEP, ROWS_APPEND, ROWS_PATCH, ROWS_REMOVE, TABLE_COLUMN_SPACING, TOOLTIP,
+};
+use super::states::{RenderCache, ScrollableState};
 use std::cmp::max;
+use std::sync::Arc;
 
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::event::MouseEvent;
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
     Table as PropTable, TextModifiers,
@@ -17,101 +22,15 @@ use tuirealm::ratatui::{
 };
 use tuirealm::{Frame, MockComponent, State, StateValue};
 
-// -- States
+use crate::theme::Theme;
+use crate::utils::{clicked, get_title_style, is_disabled, render_disabled, render_tooltip, wheel};
 
-#[derive(Default)]
-pub struct TableStates {
-    pub list_index: usize, // Index of selected item in textarea
-    pub list_len: usize,   // Lines in text area
-}
-
-impl TableStates {
-    /// ### set_list_len
-    ///
-    /// Set list length
-    pub fn set_list_len(&mut self, len: usize) {
-        self.list_len = len;
-    }
-
-    /// ### incr_list_index
-    ///
-    /// Incremenet list index
-    pub fn incr_list_index(&mut self, rewind: bool) {
-        // Check if index is at last element
-        if self.list_index + 1 < self.list_len {
-            self.list_index += 1;
-        } else if rewind {
-            self.list_index = 0;
-        }
-    }
-
-    /// ### decr_list_index
-    ///
-    /// Decrement list index
-    pub fn decr_list_index(&mut self, rewind: bool) {
-        // Check if index is bigger than 0
-        if self.list_index > 0 {
-            self.list_index -= 1;
-        } else if rewind && self.list_len > 0 {
-            self.list_index = self.list_len - 1;
-        }
-    }
-
-    /// ### fix_list_index
-    ///
-    /// Keep index if possible, otherwise set to lenght - 1
-    pub fn fix_list_index(&mut self) {
-        if self.list_index >= self.list_len && self.list_len > 0 {
-            self.list_index = self.list_len - 1;
-        } else if self.list_len == 0 {
-            self.list_index = 0;
-        }
-    }
-
-    /// ### list_index_at_first
-    ///
-    /// Set list index to the first item in the list
-    pub fn list_index_at_first(&mut self) {
-        self.list_index = 0;
-    }
-
-    /// ### list_index_at_last
-    ///
-    /// Set list index at the last item of the list
-    pub fn list_index_at_last(&mut self) {
-        if self.list_len > 0 {
-            self.list_index = self.list_len - 1;
-        } else {
-            self.list_index = 0;
-        }
-    }
-
-    /// ### calc_max_step_ahead
-    ///
-    /// Calculate the max step ahead to scroll list
-    pub fn calc_max_step_ahead(&self, max: usize) -> usize {
-        let remaining: usize = match self.list_len {
-            0 => 0,
-            len => len - 1 - self.list_index,
-        };
-        if remaining > max {
-            max
-        } else {
-            remaining
-        }
-    }
+// -- States
 
-    /// ### calc_max_step_ahead
-    ///
-    /// Calculate the max step ahead to scroll list
-    pub fn calc_max_step_behind(&self, max: usize) -> usize {
-        if self.list_index > max {
-            max
-        } else {
-            self.list_index
-        }
-    }
-}
+/// ## TableStates
+///
+/// The states of `Table`, shared with the other scrollable components
+pub type TableStates = ScrollableState;
 
 // -- Component
 
@@ -124,6 +43,8 @@ pub struct Table {
     pub states: TableStates,
     hg_str: Option<String>, // CRAP CRAP CRAP
     headers: Vec<String>,   // CRAP CRAP CRAP
+    rows: RenderCache<Vec<Vec<Span<'static>>>>,
+    content: Option<Arc<PropTable>>,
 }
 
 impl Table {
@@ -142,6 +63,28 @@ impl Table {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `table` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("table", self);
+    }
+
     pub fn modifiers(mut self, m: TextModifiers) -> Self {
         self.attr(Attribute::TextProps, AttrValue::TextModifiers(m));
         self
@@ -162,6 +105,15 @@ impl Table {
         self
     }
 
+    /// Set the lines scrolled by each notch of the mouse wheel (default: 3)
+    pub fn wheel_step(mut self, lines: usize) -> Self {
+        self.attr(
+            Attribute::Custom(MOUSE_WHEEL_STEP),
+            AttrValue::Length(lines),
+        );
+        self
+    }
+
     pub fn scroll(mut self, scrollable: bool) -> Self {
         self.attr(Attribute::Scroll, AttrValue::Flag(scrollable));
         self
@@ -215,6 +167,24 @@ impl Table {
         self
     }
 
+    /// Set the rows shared with the application, which aren't copied. See `set_shared_rows`
+    pub fn shared_rows(mut self, rows: Arc<PropTable>) -> Self {
+        self.set_shared_rows(rows);
+        self
+    }
+
+    /// ### set_shared_rows
+    ///
+    /// Set the rows shared with the application. Unlike `Attribute::Content`, the rows aren't cloned when set and
+    /// when rendered: to update them, set a new `Arc`, or update single rows with `Custom($ROWS_APPEND)`,
+    /// `Custom($ROWS_PATCH)` and `Custom($ROWS_REMOVE)`, which copy them only if the application still holds them
+    pub fn set_shared_rows(&mut self, rows: Arc<PropTable>) {
+        self.states.set_list_len(rows.len());
+        self.states.fix_list_index();
+        self.content = Some(rows);
+        self.rows.invalidate();
+    }
+
     pub fn rewind(mut self, r: bool) -> Self {
         self.attr(Attribute::Rewind, AttrValue::Flag(r));
         self
@@ -245,6 +215,46 @@ impl Table {
             .unwrap_flag()
     }
 
+    /// ### on_mouse
+    ///
+    /// Handle a mouse event: if the table is scrollable, clicking a row selects it and the wheel moves the selection
+    pub fn on_mouse(&mut self, ev: MouseEvent) -> CmdResult {
+        if is_disabled(&self.props) || !self.is_scrollable() {
+            return CmdResult::None;
+        }
+        if let Some((direction, step)) = wheel(&ev, self.states.area, &self.props) {
+            let prev = self.states.list_index;
+            match direction {
+                Direction::Down => {
+                    self.states.scroll_down(step);
+                }
+                _ => {
+                    self.states.scroll_up(step);
+                }
+            }
+            return match prev != self.states.list_index {
+                true => CmdResult::Changed(self.state()),
+                false => CmdResult::None,
+            };
+        }
+        let row_height = self
+            .props
+            .get_or(Attribute::Height, AttrValue::Size(1))
+            .unwrap_size()
+            .max(1);
+        match clicked(&ev, self.states.area) {
+            Some((_, row)) => {
+                let index = self.states.offset + (row / row_height) as usize;
+                if index >= self.states.list_len || index == self.states.list_index {
+                    return CmdResult::None;
+                }
+                self.states.list_index = index;
+                CmdResult::Changed(self.state())
+            }
+            None => CmdResult::None,
+        }
+    }
+
     /// ### layout
     ///
     /// Returns layout based on properties.
@@ -259,11 +269,10 @@ impl Table {
                 .collect(),
             _ => {
                 // Get amount of columns (maximum len of row elements)
-                let columns: usize =
-                    match self.props.get(Attribute::Content).map(|x| x.unwrap_table()) {
-                        Some(rows) => rows.iter().map(|col| col.len()).max().unwrap_or(1),
-                        _ => 1,
-                    };
+                let columns: usize = match &self.content {
+                    Some(rows) => rows.iter().map(|col| col.len()).max().unwrap_or(1),
+                    _ => 1,
+                };
                 // Calc width in equal way, make sure not to divide by zero (this can happen when rows is [[]])
                 let width: u16 = (100 / max(columns, 1)) as u16;
                 (0..columns)
@@ -272,6 +281,15 @@ impl Table {
             }
         }
     }
+
+    /// ### make_rows
+    ///
+    /// Make the styled spans of the cells of each row of the content
+    fn make_rows(props: &Props, content: Option<&PropTable>) -> Vec<Vec<Span<'static>>> {
+        content
+            .map(|table| crate::utils::styled_rows(props, table))
+            .unwrap_or_default()
+    }
 }
 
 impl MockComponent for Table {
@@ -315,40 +333,31 @@ impl MockComponent for Table {
                 .props
                 .get_or(Attribute::Height, AttrValue::Size(1))
                 .unwrap_size();
-            // Make rows
-            let rows: Vec<Row> = match self.props.get(Attribute::Content).map(|x| x.unwrap_table())
-            {
-                Some(table) => table
-                    .iter()
-                    .map(|row| {
-                        let columns: Vec<Cell> = row
-                            .iter()
-                            .map(|col| {
-                                let (fg, bg, modifiers) =
-                                    crate::utils::use_or_default_styles(&self.props, col);
-                                Cell::from(Span::styled(
-                                    col.content.clone(),
-                                    Style::default().add_modifier(modifiers).fg(fg).bg(bg),
-                                ))
-                            })
-                            .collect();
-                        Row::new(columns).height(row_height)
-                    })
-                    .collect(), // Make List item from TextSpan
-                _ => Vec::new(),
-            };
+            // Make rows; cells are rebuilt only when props have changed
+            let (props, content) = (&self.props, self.content.as_deref());
+            self.rows.refresh(|| Self::make_rows(props, content));
+            let rows: Vec<Row> = self
+                .rows
+                .value()
+                .iter()
+                .map(|row| {
+                    let columns: Vec<Cell> = row
+                        .iter()
+                        .map(|col| Cell::from(Span::styled(col.content.as_ref(), col.style)))
+                        .collect();
+                    Row::new(columns).height(row_height)
+                })
+                .collect();
             let highlighted_color = self
                 .props
                 .get(Attribute::HighlightedColor)
                 .map(|x| x.unwrap_color());
             let widths: Vec<Constraint> = self.layout();
 
-            let mut table = TuiTable::new(rows, &widths).block(crate::utils::get_block(
-                borders,
-                Some(title),
-                focus,
-                inactive_style,
-            ));
+            let div = crate::utils::get_block(borders, Some(title), focus, inactive_style)
+                .title_style(get_title_style(&self.props));
+            let mut rows_area = div.inner(area);
+            let mut table = TuiTable::new(rows, &widths).block(div);
             if let Some(highlighted_color) = highlighted_color {
                 table = table.highlight_style(Style::default().fg(highlighted_color).add_modifier(
                     match focus {
@@ -397,33 +406,47 @@ impl MockComponent for Table {
                         )
                         .height(row_height),
                 );
+                let header_height = row_height.min(rows_area.height);
+                rows_area.y += header_height;
+                rows_area.height -= header_height;
             }
+            self.states.area = rows_area;
             if self.is_scrollable() {
-                let mut state: TableState = TableState::default();
+                let mut state: TableState = TableState::default().with_offset(self.states.offset);
                 state.select(Some(self.states.list_index));
                 render.render_stateful_widget(table, area, &mut state);
+                self.states.offset = state.offset();
             } else {
                 render.render_widget(table, area);
             }
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
-        self.props.get(attr)
+        match attr {
+            Attribute::Content => self
+                .content
+                .as_ref()
+                .map(|x| AttrValue::Table(x.as_ref().clone())),
+            attr => self.props.get(attr),
+        }
     }
 
     fn attr(&mut self, attr: Attribute, value: AttrValue) {
-        self.props.set(attr, value);
-        if matches!(attr, Attribute::Content) {
+        self.rows.invalidate();
+        match (attr, value) {
             // Update list len and fix index
-            self.states.set_list_len(
-                match self.props.get(Attribute::Content).map(|x| x.unwrap_table()) {
-                    Some(spans) => spans.len(),
-                    _ => 0,
-                },
-            );
-            self.states.fix_list_index();
-        } else if matches!(attr, Attribute::Value) && self.is_scrollable() {
+            (Attribute::Content, AttrValue::Table(table)) => self.set_shared_rows(Arc::new(table)),
+            // Update single rows, keeping the selected one; rows are copied if shared
+            (Attribute::Custom(ROWS_APPEND | ROWS_PATCH | ROWS_REMOVE), value) => {
+                let rows = Arc::make_mut(self.content.get_or_insert_with(Default::default));
+                crate::utils::update_rows(rows, &mut self.states, attr, value);
+            }
+            (attr, value) => self.props.set(attr, value),
+        }
+        if matches!(attr, Attribute::Value) && self.is_scrollable() {
             self.states.list_index = self
                 .props
                 .get(Attribute::Value)
@@ -441,6 +464,9 @@ impl MockComponent for Table {
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
         match cmd {
             Cmd::Move(Direction::Down) => {
                 let prev = self.states.list_index;
@@ -466,8 +492,7 @@ impl MockComponent for Table {
                     .props
                     .get_or(Attribute::ScrollStep, AttrValue::Length(8))
                     .unwrap_length();
-                let step: usize = self.states.calc_max_step_ahead(step);
-                (0..step).for_each(|_| self.states.incr_list_index(false));
+                self.states.scroll_down(step);
                 if prev != self.states.list_index {
                     CmdResult::Changed(self.state())
                 } else {
@@ -480,8 +505,7 @@ impl MockComponent for Table {
                     .props
                     .get_or(Attribute::ScrollStep, AttrValue::Length(8))
                     .unwrap_length();
-                let step: usize = self.states.calc_max_step_behind(step);
-                (0..step).for_each(|_| self.states.decr_list_index(false));
+                self.states.scroll_up(step);
                 if prev != self.states.list_index {
                     CmdResult::Changed(self.state())
                 } else {
@@ -788,4 +812,45 @@ mod tests {
         );
         assert_eq!(component.states.list_index, 6);
     }
+
+    #[test]
+    fn test_components_table_mouse() {
+        use tuirealm::event::{KeyModifiers, MouseButton, MouseEventKind};
+        use tuirealm::props::{TableBuilder, TextSpan};
+        use tuirealm::ratatui::{backend::TestBackend, Terminal};
+
+        let click = |column, row| MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            modifiers: KeyModifiers::NONE,
+            column,
+            row,
+        };
+        let mut rows = TableBuilder::default();
+        for i in 0..10 {
+            if i > 0 {
+                rows.add_row();
+            }
+            rows.add_col(TextSpan::from(format!("row {i}")));
+        }
+        let mut component = Table::default()
+            .borders(Borders::default())
+            .headers(&["Rows"])
+            .scroll(true)
+            .table(rows.build())
+            .selected_line(6);
+        let mut terminal = Terminal::new(TestBackend::new(12, 6)).unwrap();
+        terminal
+            .draw(|f| component.view(f, Rect::new(0, 0, 12, 6)))
+            .unwrap();
+        // Three rows are displayed under the header, from the fifth one
+        assert_eq!(component.states.area, Rect::new(1, 2, 10, 3));
+        assert_eq!(component.states.offset, 4);
+        assert_eq!(
+            component.on_mouse(click(3, 2)),
+            CmdResult::Changed(State::One(StateValue::Usize(4)))
+        );
+        // Header
+        assert_eq!(component.on_mouse(click(3, 1)), CmdResult::None);
+        assert_eq!(component.states.list_index, 4);
+    }
 }
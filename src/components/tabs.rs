@@ -0,0 +1,441 @@
+//! ## Tabs
+//!
+//! `Tabs` component renders a horizontal tab strip
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::text::{Line as Spans, Span};
+use tuirealm::ratatui::{layout::Rect, widgets::Tabs as TuiTabs};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{TABS_ACTIVE_MODIFIERS, TABS_BADGES, TABS_BADGE_STYLE, TOOLTIP};
+
+/// Badge counters above this value are displayed as `99+`
+const BADGE_MAX: usize = 99;
+
+// -- states
+
+/// ## TabsStates
+///
+/// TabsStates contains states for this component
+#[derive(Default)]
+pub struct TabsStates {
+    pub active: usize,     // Active tab
+    pub tabs: Vec<String>, // Tab titles
+}
+
+impl TabsStates {
+    /// ### next_tab
+    ///
+    /// Move to next tab
+    pub fn next_tab(&mut self, rewind: bool) {
+        if rewind && self.active + 1 >= self.tabs.len() {
+            self.active = 0;
+        } else if self.active + 1 < self.tabs.len() {
+            self.active += 1;
+        }
+    }
+
+    /// ### prev_tab
+    ///
+    /// Move to previous tab
+    pub fn prev_tab(&mut self, rewind: bool) {
+        if rewind && self.active == 0 && !self.tabs.is_empty() {
+            self.active = self.tabs.len() - 1;
+        } else if self.active > 0 {
+            self.active -= 1;
+        }
+    }
+
+    /// ### set_tabs
+    ///
+    /// Set tab titles, keeping the active index if possible or setting it to the last tab
+    pub fn set_tabs(&mut self, tabs: &[String]) {
+        self.tabs = tabs.to_vec();
+        if self.active >= self.tabs.len() {
+            self.active = self.tabs.len().saturating_sub(1);
+        }
+    }
+
+    pub fn select(&mut self, i: usize) {
+        if i < self.tabs.len() {
+            self.active = i;
+        }
+    }
+}
+
+// -- component
+
+/// ## Tabs
+///
+/// A horizontal tab strip. The active tab is highlighted and each tab can display a badge counter
+#[derive(Default)]
+pub struct Tabs {
+    props: Props,
+    pub states: TabsStates,
+}
+
+impl Tabs {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `tabs` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("tabs", self);
+    }
+
+    pub fn rewind(mut self, r: bool) -> Self {
+        self.attr(Attribute::Rewind, AttrValue::Flag(r));
+        self
+    }
+
+    pub fn tabs<S: AsRef<str>>(mut self, tabs: &[S]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(
+                tabs.iter()
+                    .map(|x| PropValue::Str(x.as_ref().to_string()))
+                    .collect(),
+            )),
+        );
+        self
+    }
+
+    pub fn value(mut self, i: usize) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(i))),
+        );
+        self
+    }
+
+    /// Set the modifiers of the active tab (e.g. `UNDERLINED` or `REVERSED`); defaults to `REVERSED`
+    pub fn active_modifiers(mut self, m: TextModifiers) -> Self {
+        self.attr(
+            Attribute::Custom(TABS_ACTIVE_MODIFIERS),
+            AttrValue::TextModifiers(m),
+        );
+        self
+    }
+
+    /// Set the badge counters of tabs, by index. Badges with a zero counter are not displayed
+    pub fn badges(mut self, badges: &[usize]) -> Self {
+        self.attr(
+            Attribute::Custom(TABS_BADGES),
+            AttrValue::Payload(PropPayload::Vec(
+                badges.iter().map(|x| PropValue::Usize(*x)).collect(),
+            )),
+        );
+        self
+    }
+
+    /// Set the style of badges; defaults to bold
+    pub fn badge_style(mut self, s: Style) -> Self {
+        self.attr(Attribute::Custom(TABS_BADGE_STYLE), AttrValue::Style(s));
+        self
+    }
+
+    fn is_rewind(&self) -> bool {
+        self.props
+            .get_or(Attribute::Rewind, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    fn get_badges(&self) -> Vec<usize> {
+        self.props
+            .get(Attribute::Custom(TABS_BADGES))
+            .map(|x| {
+                x.unwrap_payload()
+                    .unwrap_vec()
+                    .into_iter()
+                    .map(|x| x.unwrap_usize())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn changed(&self, before: usize) -> CmdResult {
+        match self.states.active != before {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+
+    /// ### make_tabs
+    ///
+    /// Make the tab strip widget, with the titles of tabs followed by their badge
+    fn make_tabs(&self, style: Style) -> TuiTabs<'static> {
+        let badges = self.get_badges();
+        let badge_style = self
+            .props
+            .get_or(
+                Attribute::Custom(TABS_BADGE_STYLE),
+                AttrValue::Style(Style::default().add_modifier(TextModifiers::BOLD)),
+            )
+            .unwrap_style();
+        let highlighted = self
+            .props
+            .get_or(
+                Attribute::HighlightedColor,
+                AttrValue::Color(style.fg.unwrap_or(Color::Reset)),
+            )
+            .unwrap_color();
+        let modifiers = self
+            .props
+            .get_or(
+                Attribute::Custom(TABS_ACTIVE_MODIFIERS),
+                AttrValue::TextModifiers(TextModifiers::REVERSED),
+            )
+            .unwrap_text_modifiers();
+        // The active tab is styled by spans, so that the badge keeps its own color
+        let titles: Vec<Spans> = self
+            .states
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(i, tab)| {
+                let (title_style, badge_style) = match i == self.states.active {
+                    true => (
+                        Style::default().fg(highlighted).add_modifier(modifiers),
+                        badge_style.add_modifier(modifiers),
+                    ),
+                    false => (Style::default(), badge_style),
+                };
+                let badge = match badges.get(i).copied().unwrap_or(0) {
+                    0 => None,
+                    n if n > BADGE_MAX => Some(format!(" {BADGE_MAX}+")),
+                    n => Some(format!(" {n}")),
+                };
+                match badge {
+                    Some(badge) => Spans::from(vec![
+                        Span::styled(tab.clone(), title_style),
+                        Span::styled(badge, badge_style),
+                    ]),
+                    None => Spans::from(Span::styled(tab.clone(), title_style)),
+                }
+            })
+            .collect();
+        TuiTabs::new(titles)
+            .select(self.states.active)
+            .style(style)
+            .highlight_style(Style::default())
+    }
+}
+
+impl MockComponent for Tabs {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let div = crate::utils::get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props));
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            // The active tab is highlighted even if not focused, since it tells which view is displayed
+            render.render_widget(self.make_tabs(style).block(div), area);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Content => {
+                let tabs: Vec<String> = value
+                    .unwrap_payload()
+                    .unwrap_vec()
+                    .iter()
+                    .map(|x| x.clone().unwrap_str())
+                    .collect();
+                self.states.set_tabs(&tabs);
+            }
+            Attribute::Value => {
+                self.states
+                    .select(value.unwrap_payload().unwrap_one().unwrap_usize());
+            }
+            attr => {
+                self.props.set(attr, value);
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::Usize(self.states.active))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let before = self.states.active;
+        match cmd {
+            Cmd::Move(Direction::Right) => {
+                self.states.next_tab(self.is_rewind());
+                self.changed(before)
+            }
+            Cmd::Move(Direction::Left) => {
+                self.states.prev_tab(self.is_rewind());
+                self.changed(before)
+            }
+            Cmd::GoTo(Position::Begin) => {
+                self.states.select(0);
+                self.changed(before)
+            }
+            Cmd::GoTo(Position::End) => {
+                self.states.select(self.states.tabs.len().saturating_sub(1));
+                self.changed(before)
+            }
+            Cmd::Submit => CmdResult::Submit(self.state()),
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::ratatui::buffer::Buffer;
+    use tuirealm::ratatui::widgets::Widget;
+
+    #[test]
+    fn test_components_tabs() {
+        let mut component = Tabs::default()
+            .foreground(Color::Cyan)
+            .borders(Borders::default())
+            .title("Views", Alignment::Left)
+            .tabs(&["Home", "Inbox", "Settings"])
+            .value(1);
+        assert_eq!(component.state(), State::One(StateValue::Usize(1)));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Right)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::Begin)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::None
+        );
+        // Rewind
+        component.attr(Attribute::Rewind, AttrValue::Flag(true));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Left)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        // Keep index in range
+        component.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Vec(vec![PropValue::Str(String::from("Home"))])),
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(0)))
+        );
+    }
+
+    #[test]
+    fn test_components_tabs_render() {
+        let component = Tabs::default()
+            .tabs(&["Home", "Inbox", "Chat"])
+            .badges(&[0, 3, 120])
+            .badge_style(Style::default().fg(Color::Red))
+            .active_modifiers(TextModifiers::UNDERLINED)
+            .value(1);
+        let area = Rect::new(0, 0, 30, 1);
+        let mut buf = Buffer::empty(area);
+        component.make_tabs(Style::default()).render(area, &mut buf);
+        let row: String = (0..30).map(|x| buf[(x, 0)].symbol().to_string()).collect();
+        assert_eq!(row, " Home │ Inbox 3 │ Chat 99+    ");
+        assert!(!buf[(1, 0)].modifier.contains(TextModifiers::UNDERLINED));
+        assert!(buf[(8, 0)].modifier.contains(TextModifiers::UNDERLINED));
+        assert_eq!(buf[(14, 0)].fg, Color::Red);
+        assert!(buf[(14, 0)].modifier.contains(TextModifiers::UNDERLINED));
+        assert_eq!(buf[(12, 0)].fg, Color::Reset);
+    }
+}
@@ -0,0 +1,500 @@
+//! ## TagInput
+//!
+//! `TagInput` is an input field where the typed text becomes a list of tags
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, InputType, PropPayload, PropValue, Props,
+    Style, TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use super::input::InputStates;
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::TAG_INPUT_CMD_COMPLETE;
+use super::props::{TAG_INPUT_SUGGESTIONS, TOOLTIP};
+
+/// Character which commits the typed text as a tag, in addition to `Cmd::Submit`
+const TAG_SEPARATOR: char = ',';
+
+// -- states
+
+/// ## TagInputStates
+///
+/// TagInputStates contains states for this component
+#[derive(Default)]
+pub struct TagInputStates {
+    /// Tags committed
+    pub tags: Vec<String>,
+    /// Text being typed
+    pub input: InputStates,
+}
+
+impl TagInputStates {
+    /// ### set_tags
+    ///
+    /// Replace the tags, skipping the empty and the duplicated ones
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags.clear();
+        for tag in tags {
+            self.push_tag(&tag);
+        }
+    }
+
+    /// ### push_tag
+    ///
+    /// Add a tag, trimmed; returns whether it has been added, which doesn't happen if it is empty or a duplicate
+    pub fn push_tag(&mut self, tag: &str) -> bool {
+        let tag = tag.trim();
+        match tag.is_empty() || self.tags.iter().any(|x| x == tag) {
+            true => false,
+            false => {
+                self.tags.push(tag.to_string());
+                true
+            }
+        }
+    }
+
+    /// ### commit
+    ///
+    /// Turn the typed text into a tag, clearing the input; returns whether a tag has been added
+    pub fn commit(&mut self) -> bool {
+        let text = self.input.get_value();
+        self.input = InputStates::default();
+        self.push_tag(&text)
+    }
+
+    /// ### pop_tag
+    ///
+    /// Remove the last tag; returns whether there was a tag to remove
+    pub fn pop_tag(&mut self) -> bool {
+        self.tags.pop().is_some()
+    }
+
+    /// ### set_input
+    ///
+    /// Replace the typed text, placing the cursor at the end
+    pub fn set_input(&mut self, text: &str) {
+        self.input = InputStates::default();
+        for ch in text.chars() {
+            self.input.append(ch, &InputType::Text, None);
+        }
+    }
+}
+
+// -- component
+
+/// ## TagInput
+///
+/// An input field where the typed text becomes a tag, displayed as a chip, when `Cmd::Submit` or a comma is typed;
+/// deleting with an empty input removes the last tag. Duplicated tags are not added. When suggestions are set, the
+/// first suggestion completing the typed text is displayed after it, and can be accepted with the complete command
+#[derive(Default)]
+pub struct TagInput {
+    props: Props,
+    pub states: TagInputStates,
+}
+
+impl TagInput {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `tag_input` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("tag_input", self);
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    /// Set the color of the chips
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn tags<S: AsRef<str>>(mut self, tags: &[S]) -> Self {
+        self.attr(Attribute::Value, Self::to_payload(tags));
+        self
+    }
+
+    /// Set the suggestions used to complete the typed text
+    pub fn suggestions<S: AsRef<str>>(mut self, suggestions: &[S]) -> Self {
+        self.attr(
+            Attribute::Custom(TAG_INPUT_SUGGESTIONS),
+            Self::to_payload(suggestions),
+        );
+        self
+    }
+
+    fn to_payload<S: AsRef<str>>(values: &[S]) -> AttrValue {
+        AttrValue::Payload(PropPayload::Vec(
+            values
+                .iter()
+                .map(|x| PropValue::Str(x.as_ref().to_string()))
+                .collect(),
+        ))
+    }
+
+    /// ### suggestion
+    ///
+    /// Get the first suggestion starting with the typed text, ignoring case, which is not a tag yet
+    fn suggestion(&self) -> Option<String> {
+        let text = self.states.input.get_value().to_lowercase();
+        if text.is_empty() {
+            return None;
+        }
+        match self
+            .props
+            .get(Attribute::Custom(TAG_INPUT_SUGGESTIONS))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Vec(suggestions)) => {
+                suggestions.into_iter().map(|x| x.unwrap_str()).find(|x| {
+                    x.to_lowercase().starts_with(&text)
+                        && x.len() > text.len()
+                        && !self.states.tags.contains(x)
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn tags_changed(&self) -> CmdResult {
+        CmdResult::Changed(self.state())
+    }
+
+    /// ### render_tags
+    ///
+    /// Render the chips followed by the typed text, wrapping them on the rows of `area`. Returns the position of the
+    /// cursor, if visible
+    fn render_tags(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        style: Style,
+        chip_style: Style,
+    ) -> Option<(u16, u16)> {
+        let (mut x, mut y) = (area.x, area.y);
+        for tag in self.states.tags.iter() {
+            let chip = format!(" {tag} ");
+            let width = chip.width() as u16;
+            // Wrap on the next row, unless at the beginning of a row
+            if x > area.x && x + width > area.right() {
+                x = area.x;
+                y += 1;
+            }
+            if y >= area.bottom() {
+                return None;
+            }
+            (x, _) = buf.set_stringn(
+                x,
+                y,
+                chip,
+                area.right().saturating_sub(x) as usize,
+                chip_style,
+            );
+            x = (x + 1).min(area.right());
+        }
+        let text = self.states.input.get_value();
+        if x > area.x && x + text.width() as u16 >= area.right() {
+            x = area.x;
+            y += 1;
+        }
+        if y >= area.bottom() {
+            return None;
+        }
+        let cursor = x + self.states.input.cursor_width(InputType::Text);
+        (x, _) = buf.set_stringn(x, y, text, area.right().saturating_sub(x) as usize, style);
+        if let Some(suggestion) = self.suggestion() {
+            let completion: String = suggestion
+                .chars()
+                .skip(self.states.input.input.len())
+                .collect();
+            buf.set_stringn(
+                x,
+                y,
+                completion,
+                area.right().saturating_sub(x) as usize,
+                style.add_modifier(TextModifiers::DIM),
+            );
+        }
+        match cursor < area.right() {
+            true => Some((cursor, y)),
+            false => None,
+        }
+    }
+}
+
+impl MockComponent for TagInput {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let chip_color = self
+                .props
+                .get_or(Attribute::HighlightedColor, AttrValue::Color(foreground))
+                .unwrap_color();
+            let chip_style = style.fg(chip_color).add_modifier(TextModifiers::REVERSED);
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            let cursor = self.render_tags(render.buffer_mut(), inner, style, chip_style);
+            // Set cursor, if focus
+            if let (true, Some((x, y))) = (focus, cursor) {
+                render.set_cursor_position(tuirealm::ratatui::prelude::Position { x, y });
+            }
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        if let (Attribute::Value, AttrValue::Payload(PropPayload::Vec(tags))) = (attr, &value) {
+            self.states
+                .set_tags(tags.iter().cloned().map(|x| x.unwrap_str()).collect());
+        }
+        self.props.set(attr, value);
+    }
+
+    fn state(&self) -> State {
+        State::Vec(
+            self.states
+                .tags
+                .iter()
+                .cloned()
+                .map(StateValue::String)
+                .collect(),
+        )
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Type(TAG_SEPARATOR) => match self.states.commit() {
+                true => self.tags_changed(),
+                false => CmdResult::None,
+            },
+            Cmd::Type(ch) => {
+                self.states.input.append(ch, &InputType::Text, None);
+                CmdResult::None
+            }
+            Cmd::Submit => match self.states.input.input.is_empty() {
+                // Nothing typed: submit the tags
+                true => CmdResult::Submit(self.state()),
+                false => match self.states.commit() {
+                    true => self.tags_changed(),
+                    false => CmdResult::None,
+                },
+            },
+            Cmd::Delete => match self.states.input.input.is_empty() {
+                // Nothing typed: remove the last tag
+                true => match self.states.pop_tag() {
+                    true => self.tags_changed(),
+                    false => CmdResult::None,
+                },
+                false => {
+                    self.states.input.backspace();
+                    CmdResult::None
+                }
+            },
+            Cmd::Cancel => {
+                self.states.input.delete();
+                CmdResult::None
+            }
+            Cmd::Move(Direction::Left) => {
+                self.states.input.decr_cursor();
+                CmdResult::None
+            }
+            Cmd::Move(Direction::Right) => {
+                self.states.input.incr_cursor();
+                CmdResult::None
+            }
+            Cmd::GoTo(Position::Begin) => {
+                self.states.input.cursor_at_begin();
+                CmdResult::None
+            }
+            Cmd::GoTo(Position::End) => {
+                self.states.input.cursor_at_end();
+                CmdResult::None
+            }
+            Cmd::Custom(TAG_INPUT_CMD_COMPLETE) => {
+                if let Some(suggestion) = self.suggestion() {
+                    self.states.set_input(&suggestion);
+                }
+                CmdResult::None
+            }
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn tags(tags: &[&str]) -> State {
+        State::Vec(
+            tags.iter()
+                .map(|x| StateValue::String(x.to_string()))
+                .collect(),
+        )
+    }
+
+    fn type_str(component: &mut TagInput, s: &str) {
+        s.chars().for_each(|ch| {
+            component.perform(Cmd::Type(ch));
+        });
+    }
+
+    #[test]
+    fn test_components_tag_input() {
+        let mut component = TagInput::default().tags(&["bug", " ", "bug"]);
+        assert_eq!(component.state(), tags(&["bug"]));
+        type_str(&mut component, "ui");
+        assert_eq!(component.state(), tags(&["bug"]));
+        assert_eq!(
+            component.perform(Cmd::Type(',')),
+            CmdResult::Changed(tags(&["bug", "ui"]))
+        );
+        type_str(&mut component, " docs ");
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Changed(tags(&["bug", "ui", "docs"]))
+        );
+        // Duplicates are not added
+        type_str(&mut component, "ui");
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+        assert_eq!(component.states.input.get_value(), "");
+        // Submit with nothing typed
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(tags(&["bug", "ui", "docs"]))
+        );
+        // Backspace removes the last tag only with nothing typed
+        type_str(&mut component, "x");
+        assert_eq!(component.perform(Cmd::Delete), CmdResult::None);
+        assert_eq!(
+            component.perform(Cmd::Delete),
+            CmdResult::Changed(tags(&["bug", "ui"]))
+        );
+    }
+
+    #[test]
+    fn test_components_tag_input_suggestions() {
+        let mut component = TagInput::default()
+            .tags(&["feature"])
+            .suggestions(&["Feature", "feature", "fix", "frontend"]);
+        component.perform(Cmd::Type('F'));
+        // Tags are skipped
+        assert_eq!(component.suggestion(), Some(String::from("Feature")));
+        component.perform(Cmd::Type('r'));
+        assert_eq!(component.suggestion(), Some(String::from("frontend")));
+        component.perform(Cmd::Custom(TAG_INPUT_CMD_COMPLETE));
+        assert_eq!(component.states.input.get_value(), "frontend");
+        assert_eq!(component.states.input.cursor, 8);
+        assert_eq!(component.suggestion(), None);
+    }
+
+    #[test]
+    fn test_components_tag_input_render() {
+        let mut component = TagInput::default()
+            .tags(&["bug", "ui", "documentation"])
+            .suggestions(&["design"]);
+        type_str(&mut component, "de");
+        let area = Rect::new(0, 0, 16, 3);
+        let mut buf = Buffer::empty(area);
+        let cursor = component.render_tags(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![" bug   ui       ", " documentation  ", "design          ",]
+        );
+        assert_eq!(cursor, Some((2, 2)));
+    }
+}
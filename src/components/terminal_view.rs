@@ -0,0 +1,373 @@
+//! ## TerminalView
+//!
+//! `TerminalView` emulates a terminal, displaying the output of a command run in a pseudo terminal
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State};
+use vt100::Parser;
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{TERMINAL_VIEW_INPUT, TERMINAL_VIEW_SCROLLBACK, TOOLTIP};
+
+/// Rows kept in the scrollback when none is set
+const DEFAULT_SCROLLBACK: usize = 1000;
+
+/// ### color
+///
+/// Convert a color of the terminal; the default color is `None`, to keep the color of the component
+fn color(color: vt100::Color) -> Option<Color> {
+    match color {
+        vt100::Color::Default => None,
+        vt100::Color::Idx(i) => Some(Color::Indexed(i)),
+        vt100::Color::Rgb(r, g, b) => Some(Color::Rgb(r, g, b)),
+    }
+}
+
+// -- states
+
+/// ## TerminalViewStates
+///
+/// TerminalViewStates contains states for this component
+pub struct TerminalViewStates {
+    /// Parser of the output, holding the screen and the scrollback
+    pub parser: Parser,
+}
+
+impl Default for TerminalViewStates {
+    fn default() -> Self {
+        Self {
+            parser: Parser::new(24, 80, DEFAULT_SCROLLBACK),
+        }
+    }
+}
+
+impl TerminalViewStates {
+    /// ### process
+    ///
+    /// Feed output of the command to the terminal
+    pub fn process(&mut self, bytes: &[u8]) {
+        self.parser.process(bytes);
+    }
+
+    /// ### resize
+    ///
+    /// Resize the screen of the terminal to `rows` and `cols`, if its size is different
+    pub fn resize(&mut self, rows: u16, cols: u16) {
+        if self.parser.screen().size() != (rows, cols) {
+            self.parser.set_size(rows, cols);
+        }
+    }
+
+    /// ### reset
+    ///
+    /// Clear the terminal, keeping up to `scrollback` rows in the scrollback
+    pub fn reset(&mut self, scrollback: usize) {
+        let (rows, cols) = self.parser.screen().size();
+        self.parser = Parser::new(rows, cols, scrollback);
+    }
+
+    /// ### scroll
+    ///
+    /// Scroll the view back in the scrollback by `offset` rows, or forward if negative
+    pub fn scroll(&mut self, offset: isize) {
+        let scrollback = self.parser.screen().scrollback();
+        self.parser
+            .set_scrollback(scrollback.saturating_add_signed(offset));
+    }
+}
+
+// -- component
+
+/// ## TerminalView
+///
+/// A terminal emulator: the output of a command, usually read from a pseudo terminal, is parsed as a VT100 stream,
+/// and the resulting screen is displayed with its colors and its cursor. The screen is resized to the area of the
+/// component, so the size of the pseudo terminal should follow it. Rows scrolled off the screen are kept in the
+/// scrollback, which can be scrolled through.
+///
+/// The output is fed with the `TERMINAL_VIEW_INPUT` attribute, or with `TerminalViewStates::process`; it is
+/// processed right away, and not stored in the properties
+#[derive(Default)]
+pub struct TerminalView {
+    props: Props,
+    pub states: TerminalViewStates,
+}
+
+impl TerminalView {
+    /// Set the default color of the text
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    /// Set the default background color
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `terminal_view` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("terminal_view", self);
+    }
+
+    /// Set the number of rows kept in the scrollback (default: 1000). Setting it clears the terminal
+    pub fn scrollback(mut self, rows: usize) -> Self {
+        self.attr(
+            Attribute::Custom(TERMINAL_VIEW_SCROLLBACK),
+            AttrValue::Length(rows),
+        );
+        self
+    }
+
+    /// Feed output of the command to the terminal
+    pub fn input(mut self, bytes: &[u8]) -> Self {
+        self.attr(
+            Attribute::Custom(TERMINAL_VIEW_INPUT),
+            AttrValue::Payload(PropPayload::Vec(
+                bytes.iter().copied().map(PropValue::U8).collect(),
+            )),
+        );
+        self
+    }
+
+    /// ### render_screen
+    ///
+    /// Render the visible rows of the terminal in `area`, with the cursor if `cursor` and the view isn't scrolled back
+    fn render_screen(&self, buf: &mut Buffer, area: Rect, style: Style, cursor: bool) {
+        let screen = self.states.parser.screen();
+        for (row, y) in area.rows().map(|x| x.y).enumerate() {
+            for (col, x) in (area.left()..area.right()).enumerate() {
+                let Some(cell) = screen.cell(row as u16, col as u16) else {
+                    continue;
+                };
+                // The cell is drawn by the wide character before it
+                if cell.is_wide_continuation() {
+                    continue;
+                }
+                let mut cell_style = style;
+                if let Some(fg) = color(cell.fgcolor()) {
+                    cell_style = cell_style.fg(fg);
+                }
+                if let Some(bg) = color(cell.bgcolor()) {
+                    cell_style = cell_style.bg(bg);
+                }
+                for (enabled, modifier) in [
+                    (cell.bold(), TextModifiers::BOLD),
+                    (cell.italic(), TextModifiers::ITALIC),
+                    (cell.underline(), TextModifiers::UNDERLINED),
+                    (cell.inverse(), TextModifiers::REVERSED),
+                ] {
+                    if enabled {
+                        cell_style = cell_style.add_modifier(modifier);
+                    }
+                }
+                let symbol = match cell.has_contents() {
+                    true => cell.contents(),
+                    false => String::from(" "),
+                };
+                buf[(x, y)].set_symbol(&symbol).set_style(cell_style);
+            }
+        }
+        let (row, col) = screen.cursor_position();
+        if cursor
+            && !screen.hide_cursor()
+            && screen.scrollback() == 0
+            && row < area.height
+            && col < area.width
+        {
+            let cell = &mut buf[(area.x + col, area.y + row)];
+            let cursor_style = cell.style().add_modifier(TextModifiers::REVERSED);
+            cell.set_style(cursor_style);
+        }
+    }
+}
+
+impl MockComponent for TerminalView {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = Style::default().fg(foreground).bg(background);
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.states.resize(inner.height, inner.width);
+            self.render_screen(render.buffer_mut(), inner, style, focus);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match (attr, value) {
+            (
+                Attribute::Custom(TERMINAL_VIEW_INPUT),
+                AttrValue::Payload(PropPayload::Vec(bytes)),
+            ) => {
+                let bytes: Vec<u8> = bytes.into_iter().map(|x| x.unwrap_u8()).collect();
+                self.states.process(&bytes);
+            }
+            (Attribute::Custom(TERMINAL_VIEW_SCROLLBACK), value) => {
+                self.states.reset(value.clone().unwrap_length());
+                self.props.set(attr, value);
+            }
+            (attr, value) => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::None
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Move(Direction::Up) => self.states.scroll(1),
+            Cmd::Move(Direction::Down) => self.states.scroll(-1),
+            Cmd::Scroll(Direction::Up) => {
+                let step = self
+                    .props
+                    .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+                    .unwrap_length();
+                self.states.scroll(step as isize);
+            }
+            Cmd::Scroll(Direction::Down) => {
+                let step = self
+                    .props
+                    .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+                    .unwrap_length();
+                self.states.scroll(-(step as isize));
+            }
+            Cmd::GoTo(Position::Begin) => self.states.scroll(isize::MAX),
+            Cmd::GoTo(Position::End) => self.states.parser.set_scrollback(0),
+            _ => {}
+        }
+        CmdResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.area
+            .rows()
+            .map(|row| {
+                row.positions()
+                    .map(|pos| buf[pos].symbol().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_components_terminal_view() {
+        let mut component = TerminalView::default();
+        component.states.resize(3, 8);
+        let component = component.input(b"hello\r\n\x1b[31mred\x1b[0m");
+        assert_eq!(component.state(), State::None);
+        let area = Rect::new(0, 0, 8, 3);
+        let mut buf = Buffer::empty(area);
+        component.render_screen(&mut buf, area, Style::default(), true);
+        assert_eq!(rows(&buf), vec!["hello   ", "red     ", "        "]);
+        assert_eq!(buf[(0, 1)].fg, Color::Indexed(1));
+        assert_eq!(buf[(0, 0)].fg, Color::Reset);
+        // Cursor
+        assert!(buf[(3, 1)].modifier.contains(TextModifiers::REVERSED));
+        assert!(!buf[(2, 1)].modifier.contains(TextModifiers::REVERSED));
+    }
+
+    #[test]
+    fn test_components_terminal_view_scrollback() {
+        let mut component = TerminalView::default().scrollback(10);
+        component.states.resize(2, 4);
+        component.states.process(b"1\r\n2\r\n3\r\n4");
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+        component.render_screen(&mut buf, area, Style::default(), true);
+        assert_eq!(rows(&buf), vec!["3   ", "4   "]);
+        component.perform(Cmd::Move(Direction::Up));
+        let mut buf = Buffer::empty(area);
+        component.render_screen(&mut buf, area, Style::default(), true);
+        assert_eq!(rows(&buf), vec!["2   ", "3   "]);
+        // The cursor is hidden while scrolled back
+        assert!(!buf[(1, 1)].modifier.contains(TextModifiers::REVERSED));
+        component.perform(Cmd::GoTo(Position::Begin));
+        assert_eq!(component.states.parser.screen().scrollback(), 2);
+        component.perform(Cmd::GoTo(Position::End));
+        assert_eq!(component.states.parser.screen().scrollback(), 0);
+    }
+}
@@ -6,7 +6,10 @@
 
 extern crate unicode_width;
 
+use std::ops::{Deref, DerefMut};
+
 use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::event::MouseEvent;
 use tuirealm::props::{
     Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
     TextModifiers, TextSpan,
@@ -18,96 +21,54 @@ use tuirealm::ratatui::{
 use tuirealm::{Frame, MockComponent, State};
 use unicode_width::UnicodeWidthStr;
 
+use crate::clipboard::{default_clipboard, Clipboard};
+use crate::theme::Theme;
+use crate::utils::{get_title_style, is_disabled, render_disabled, render_tooltip, wheel};
+
+// -- Props
+
+use super::commands::TEXTAREA_CMD_COPY;
+use super::props::{MOUSE_WHEEL_STEP, TOOLTIP};
+use super::states::ScrollableState;
+
 // -- States
 
-#[derive(Default)]
+/// ## TextareaStates
+///
+/// The states of `Textarea`: a `ScrollableState`, as in the other scrollable components, whose index moves
+/// without rewinding
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct TextareaStates {
-    pub list_index: usize, // Index of selected item in textarea
-    pub list_len: usize,   // Lines in text area
+    pub rows: ScrollableState,
 }
 
 impl TextareaStates {
-    /// ### set_list_len
-    ///
-    /// Set list length and fix list index
-    pub fn set_list_len(&mut self, len: usize) {
-        self.list_len = len;
-        self.fix_list_index();
-    }
-
     /// ### incr_list_index
     ///
-    /// Incremenet list index
+    /// Increment list index
     pub fn incr_list_index(&mut self) {
-        // Check if index is at last element
-        if self.list_index + 1 < self.list_len {
-            self.list_index += 1;
-        }
+        self.rows.incr_list_index(false);
     }
 
     /// ### decr_list_index
     ///
     /// Decrement list index
     pub fn decr_list_index(&mut self) {
-        // Check if index is bigger than 0
-        if self.list_index > 0 {
-            self.list_index -= 1;
-        }
-    }
-
-    /// ### fix_list_index
-    ///
-    /// Keep index if possible, otherwise set to lenght - 1
-    pub fn fix_list_index(&mut self) {
-        if self.list_index >= self.list_len && self.list_len > 0 {
-            self.list_index = self.list_len - 1;
-        } else if self.list_len == 0 {
-            self.list_index = 0;
-        }
-    }
-
-    /// ### list_index_at_first
-    ///
-    /// Set list index to the first item in the list
-    pub fn list_index_at_first(&mut self) {
-        self.list_index = 0;
+        self.rows.decr_list_index(false);
     }
+}
 
-    /// ### list_index_at_last
-    ///
-    /// Set list index at the last item of the list
-    pub fn list_index_at_last(&mut self) {
-        if self.list_len > 0 {
-            self.list_index = self.list_len - 1;
-        } else {
-            self.list_index = 0;
-        }
-    }
+impl Deref for TextareaStates {
+    type Target = ScrollableState;
 
-    /// ### calc_max_step_ahead
-    ///
-    /// Calculate the max step ahead to scroll list
-    fn calc_max_step_ahead(&self, max: usize) -> usize {
-        let remaining: usize = match self.list_len {
-            0 => 0,
-            len => len - 1 - self.list_index,
-        };
-        if remaining > max {
-            max
-        } else {
-            remaining
-        }
+    fn deref(&self) -> &Self::Target {
+        &self.rows
     }
+}
 
-    /// ### calc_max_step_ahead
-    ///
-    /// Calculate the max step ahead to scroll list
-    fn calc_max_step_behind(&self, max: usize) -> usize {
-        if self.list_index > max {
-            max
-        } else {
-            self.list_index
-        }
+impl DerefMut for TextareaStates {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rows
     }
 }
 
@@ -121,6 +82,7 @@ pub struct Textarea {
     props: Props,
     pub states: TextareaStates,
     hg_str: Option<String>, // CRAP CRAP CRAP
+    clipboard: Option<Box<dyn Clipboard>>,
 }
 
 impl Textarea {
@@ -139,6 +101,28 @@ impl Textarea {
         self
     }
 
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `textarea` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("textarea", self);
+    }
+
     pub fn modifiers(mut self, m: TextModifiers) -> Self {
         self.attr(Attribute::TextProps, AttrValue::TextModifiers(m));
         self
@@ -159,11 +143,26 @@ impl Textarea {
         self
     }
 
+    /// Set the lines scrolled by each notch of the mouse wheel (default: 3)
+    pub fn wheel_step(mut self, lines: usize) -> Self {
+        self.attr(
+            Attribute::Custom(MOUSE_WHEEL_STEP),
+            AttrValue::Length(lines),
+        );
+        self
+    }
+
     pub fn highlighted_str<S: Into<String>>(mut self, s: S) -> Self {
         self.attr(Attribute::HighlightedStr, AttrValue::String(s.into()));
         self
     }
 
+    /// Set the clipboard used to copy the text. By default, the one of `default_clipboard`
+    pub fn clipboard(mut self, clipboard: Box<dyn Clipboard>) -> Self {
+        self.clipboard = Some(clipboard);
+        self
+    }
+
     pub fn text_rows(mut self, rows: &[TextSpan]) -> Self {
         self.states.set_list_len(rows.len());
         self.attr(
@@ -174,12 +173,33 @@ impl Textarea {
         );
         self
     }
+
+    /// ### on_mouse
+    ///
+    /// Handle a mouse event: the wheel scrolls the text
+    pub fn on_mouse(&mut self, ev: MouseEvent) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        if let Some((direction, step)) = wheel(&ev, self.states.area, &self.props) {
+            match direction {
+                Direction::Down => {
+                    self.states.scroll_down(step);
+                }
+                _ => {
+                    self.states.scroll_up(step);
+                }
+            }
+        }
+        CmdResult::None
+    }
 }
 
 impl MockComponent for Textarea {
     fn view(&mut self, render: &mut Frame, area: Rect) {
         // Make a Span
         if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            self.states.area = area;
             // Make text items
             // Highlighted symbol
             self.hg_str = self
@@ -241,12 +261,10 @@ impl MockComponent for Textarea {
             // Make component
 
             let mut list = List::new(lines)
-                .block(crate::utils::get_block(
-                    borders,
-                    Some(title),
-                    focus,
-                    inactive_style,
-                ))
+                .block(
+                    crate::utils::get_block(borders, Some(title), focus, inactive_style)
+                        .title_style(get_title_style(&self.props)),
+                )
                 .direction(tuirealm::ratatui::widgets::ListDirection::TopToBottom)
                 .style(
                     Style::default()
@@ -260,6 +278,8 @@ impl MockComponent for Textarea {
             }
             render.render_stateful_widget(list, area, &mut state);
         }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
     }
 
     fn query(&self, attr: Attribute) -> Option<AttrValue> {
@@ -283,6 +303,9 @@ impl MockComponent for Textarea {
     }
 
     fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
         match cmd {
             Cmd::Move(Direction::Down) => {
                 self.states.incr_list_index();
@@ -295,16 +318,14 @@ impl MockComponent for Textarea {
                     .props
                     .get_or(Attribute::ScrollStep, AttrValue::Length(8))
                     .unwrap_length();
-                let step = self.states.calc_max_step_ahead(step);
-                (0..step).for_each(|_| self.states.incr_list_index());
+                self.states.scroll_down(step);
             }
             Cmd::Scroll(Direction::Up) => {
                 let step = self
                     .props
                     .get_or(Attribute::ScrollStep, AttrValue::Length(8))
                     .unwrap_length();
-                let step = self.states.calc_max_step_behind(step);
-                (0..step).for_each(|_| self.states.decr_list_index());
+                self.states.scroll_up(step);
             }
             Cmd::GoTo(Position::Begin) => {
                 self.states.list_index_at_first();
@@ -312,6 +333,19 @@ impl MockComponent for Textarea {
             Cmd::GoTo(Position::End) => {
                 self.states.list_index_at_last();
             }
+            Cmd::Custom(TEXTAREA_CMD_COPY) => {
+                if let Some(PropPayload::Vec(rows)) =
+                    self.props.get(Attribute::Text).map(|x| x.unwrap_payload())
+                {
+                    let text: Vec<String> = rows
+                        .into_iter()
+                        .map(|x| x.unwrap_text_span().content)
+                        .collect();
+                    self.clipboard
+                        .get_or_insert_with(default_clipboard)
+                        .set(&text.join("\n"));
+                }
+            }
             _ => {}
         }
         CmdResult::None
@@ -322,6 +356,7 @@ impl MockComponent for Textarea {
 mod tests {
 
     use super::*;
+    use crate::clipboard::MemoryClipboard;
 
     use pretty_assertions::assert_eq;
 
@@ -382,6 +417,9 @@ mod tests {
         // End
         assert_eq!(component.perform(Cmd::GoTo(Position::End)), CmdResult::None);
         assert_eq!(component.states.list_index, 2);
+        // The index doesn't rewind
+        component.states.incr_list_index();
+        assert_eq!(component.states.list_index, 2);
         // Home
         assert_eq!(
             component.perform(Cmd::GoTo(Position::Begin)),
@@ -392,4 +430,48 @@ mod tests {
         // On key
         assert_eq!(component.perform(Cmd::Delete), CmdResult::None);
     }
+
+    #[test]
+    fn test_components_textarea_copy() {
+        let mut clipboard = MemoryClipboard::new();
+        let mut component = Textarea::default()
+            .clipboard(Box::new(clipboard.clone()))
+            .text_rows(&[TextSpan::from("welcome to"), TextSpan::from("tui-realm")]);
+        assert_eq!(
+            component.perform(Cmd::Custom(TEXTAREA_CMD_COPY)),
+            CmdResult::None
+        );
+        assert_eq!(clipboard.get().as_deref(), Some("welcome to\ntui-realm"));
+    }
+
+    #[test]
+    fn test_components_textarea_mouse() {
+        use tuirealm::event::{KeyModifiers, MouseEventKind};
+
+        let scroll = |kind| MouseEvent {
+            kind,
+            modifiers: KeyModifiers::NONE,
+            column: 2,
+            row: 2,
+        };
+        let mut component = Textarea::default()
+            .text_rows(
+                &(0..10)
+                    .map(|x| TextSpan::from(x.to_string()))
+                    .collect::<Vec<_>>(),
+            )
+            .wheel_step(4);
+        component.states.area = Rect::new(0, 0, 10, 5);
+        component.on_mouse(scroll(MouseEventKind::ScrollDown));
+        assert_eq!(component.states.list_index, 4);
+        component.on_mouse(scroll(MouseEventKind::ScrollDown));
+        component.on_mouse(scroll(MouseEventKind::ScrollDown));
+        assert_eq!(component.states.list_index, 9);
+        component.on_mouse(scroll(MouseEventKind::ScrollUp));
+        assert_eq!(component.states.list_index, 5);
+        // Outside of the component
+        component.states.area = Rect::new(4, 4, 10, 5);
+        component.on_mouse(scroll(MouseEventKind::ScrollUp));
+        assert_eq!(component.states.list_index, 5);
+    }
 }
@@ -0,0 +1,529 @@
+//! ## TimePicker
+//!
+//! `TimePicker` is a field to enter a time of day, made of segments which can be adjusted one by one
+
+use tuirealm::command::{Cmd, CmdResult, Direction};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, Props, Style, TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{
+    get_block, get_title_style, is_disabled, render_disabled, render_tooltip, Time,
+};
+
+// -- Props
+
+use super::props::{TIME_PICKER_SECONDS, TIME_PICKER_TWELVE_HOUR, TOOLTIP};
+
+// -- states
+
+/// ## TimeSegment
+///
+/// A segment of the time picker, which can be focused and adjusted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeSegment {
+    Hour,
+    Minute,
+    Second,
+    /// AM/PM, displayed only in the 12-hour mode
+    Meridiem,
+}
+
+/// ### segments
+///
+/// Get the segments displayed with the provided options
+fn segments(seconds: bool, twelve_hour: bool) -> Vec<TimeSegment> {
+    let mut segments = vec![TimeSegment::Hour, TimeSegment::Minute];
+    if seconds {
+        segments.push(TimeSegment::Second);
+    }
+    if twelve_hour {
+        segments.push(TimeSegment::Meridiem);
+    }
+    segments
+}
+
+/// ## TimePickerStates
+///
+/// TimePicker states: the selected time, the focused segment and the first digit typed into it, if any
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimePickerStates {
+    pub time: Time,
+    pub segment: TimeSegment,
+    pub pending: Option<u8>,
+}
+
+impl Default for TimePickerStates {
+    fn default() -> Self {
+        Self {
+            time: Time::default(),
+            segment: TimeSegment::Hour,
+            pending: None,
+        }
+    }
+}
+
+impl TimePickerStates {
+    /// ### focus_segment
+    ///
+    /// Focus the segment at `offset` from the current one, in `segments`. Returns whether the focus has moved
+    pub fn focus_segment(&mut self, offset: isize, segments: &[TimeSegment]) -> bool {
+        self.pending = None;
+        let index = segments
+            .iter()
+            .position(|x| *x == self.segment)
+            .unwrap_or(0) as isize;
+        let target = (index + offset).clamp(0, segments.len() as isize - 1) as usize;
+        let moved = segments[target] != self.segment;
+        self.segment = segments[target];
+        moved
+    }
+
+    /// ### increment
+    ///
+    /// Add `step` to the focused segment, wrapping around its range
+    pub fn increment(&mut self, step: i32) {
+        self.pending = None;
+        let wrap = |value: u8, modulo: i32| (i32::from(value) + step).rem_euclid(modulo) as u8;
+        match self.segment {
+            TimeSegment::Hour => self.time.hour = wrap(self.time.hour, 24),
+            TimeSegment::Minute => self.time.minute = wrap(self.time.minute, 60),
+            TimeSegment::Second => self.time.second = wrap(self.time.second, 60),
+            TimeSegment::Meridiem => self.time.hour = (self.time.hour + 12) % 24,
+        }
+    }
+
+    /// ### type_char
+    ///
+    /// Type a character into the focused segment. Digits are validated against the segment range; once the segment
+    /// can't take more digits, the next segment is focused. `a` and `p` set the meridiem.
+    /// Returns whether the time has changed
+    pub fn type_char(&mut self, ch: char, segments: &[TimeSegment], twelve_hour: bool) -> bool {
+        let before = self.time;
+        if self.segment == TimeSegment::Meridiem {
+            match ch.to_ascii_lowercase() {
+                'a' if self.time.hour >= 12 => self.time.hour -= 12,
+                'p' if self.time.hour < 12 => self.time.hour += 12,
+                _ => {}
+            }
+            return self.time != before;
+        }
+        let Some(digit) = ch.to_digit(10).map(|x| x as u8) else {
+            return false;
+        };
+        let (min, max) = match (self.segment, twelve_hour) {
+            (TimeSegment::Hour, true) => (1, 12),
+            (TimeSegment::Hour, false) => (0, 23),
+            _ => (0, 59),
+        };
+        // Complete the pending digit, if the value is valid; otherwise start over
+        let value = match self.pending.take() {
+            Some(pending) if (min..=max).contains(&(pending * 10 + digit)) => {
+                self.set_segment(pending * 10 + digit, twelve_hour);
+                self.focus_segment(1, segments);
+                return self.time != before;
+            }
+            _ => digit,
+        };
+        if (min..=max).contains(&value) {
+            self.set_segment(value, twelve_hour);
+        }
+        match value * 10 <= max {
+            true => self.pending = Some(value),
+            false => {
+                self.focus_segment(1, segments);
+            }
+        }
+        self.time != before
+    }
+
+    fn set_segment(&mut self, value: u8, twelve_hour: bool) {
+        match self.segment {
+            TimeSegment::Hour if twelve_hour => {
+                let (_, pm) = self.time.hour12();
+                self.time.hour = value % 12 + if pm { 12 } else { 0 };
+            }
+            TimeSegment::Hour => self.time.hour = value,
+            TimeSegment::Minute => self.time.minute = value,
+            TimeSegment::Second => self.time.second = value,
+            TimeSegment::Meridiem => {}
+        }
+    }
+}
+
+// -- component
+
+/// ## TimePicker
+///
+/// A field to enter a time of day. The hour, minute and, optionally, second segments are focused with left and right,
+/// and adjusted with up and down or by typing digits. Times can be displayed either in the 24-hour or 12-hour clock.
+#[derive(Default)]
+pub struct TimePicker {
+    props: Props,
+    pub states: TimePickerStates,
+}
+
+impl TimePicker {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `time_picker` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("time_picker", self);
+    }
+
+    /// Set the selected time
+    pub fn value(mut self, time: Time) -> Self {
+        self.attr(Attribute::Value, AttrValue::String(time.to_string()));
+        self
+    }
+
+    /// Set whether to display the seconds segment
+    pub fn seconds(mut self, seconds: bool) -> Self {
+        self.attr(
+            Attribute::Custom(TIME_PICKER_SECONDS),
+            AttrValue::Flag(seconds),
+        );
+        self
+    }
+
+    /// Set whether to display the time in the 12-hour clock, with the AM/PM segment
+    pub fn twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.attr(
+            Attribute::Custom(TIME_PICKER_TWELVE_HOUR),
+            AttrValue::Flag(twelve_hour),
+        );
+        self
+    }
+
+    fn get_flag(&self, attr: Attribute) -> bool {
+        self.props
+            .get_or(attr, AttrValue::Flag(false))
+            .unwrap_flag()
+    }
+
+    fn get_segments(&self) -> Vec<TimeSegment> {
+        segments(
+            self.get_flag(Attribute::Custom(TIME_PICKER_SECONDS)),
+            self.get_flag(Attribute::Custom(TIME_PICKER_TWELVE_HOUR)),
+        )
+    }
+
+    /// ### render_time
+    ///
+    /// Render the time segments into `area`; the focused segment is highlighted if `focus` is true
+    fn render_time(&self, buf: &mut Buffer, area: Rect, style: Style, focus: bool) {
+        let highlighted = self
+            .props
+            .get_or(
+                Attribute::HighlightedColor,
+                AttrValue::Color(style.fg.unwrap_or(Color::Reset)),
+            )
+            .unwrap_color();
+        let time = self.states.time;
+        let (hour12, pm) = time.hour12();
+        let mut x = area.x;
+        for (i, segment) in self.get_segments().into_iter().enumerate() {
+            let (separator, text) = match segment {
+                TimeSegment::Hour if self.get_flag(Attribute::Custom(TIME_PICKER_TWELVE_HOUR)) => {
+                    ("", format!("{hour12:02}"))
+                }
+                TimeSegment::Hour => ("", format!("{:02}", time.hour)),
+                TimeSegment::Minute => (":", format!("{:02}", time.minute)),
+                TimeSegment::Second => (":", format!("{:02}", time.second)),
+                TimeSegment::Meridiem => (" ", String::from(if pm { "PM" } else { "AM" })),
+            };
+            let segment_style = match focus && segment == self.states.segment {
+                true => Style::default()
+                    .fg(highlighted)
+                    .add_modifier(TextModifiers::REVERSED),
+                false => style,
+            };
+            let width = area.right().saturating_sub(x) as usize;
+            if i > 0 {
+                x = buf.set_stringn(x, area.y, separator, width, style).0;
+            }
+            let width = area.right().saturating_sub(x) as usize;
+            x = buf.set_stringn(x, area.y, text, width, segment_style).0;
+        }
+    }
+}
+
+impl MockComponent for TimePicker {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self.get_flag(Attribute::Focus);
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_time(render.buffer_mut(), inner, style, focus);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Value => {
+                // Invalid times are ignored
+                if let Some(time) = self
+                    .props
+                    .get(Attribute::Value)
+                    .and_then(|x| Time::parse(&x.unwrap_string()))
+                {
+                    self.states.time = time;
+                }
+            }
+            Attribute::Custom(TIME_PICKER_SECONDS) | Attribute::Custom(TIME_PICKER_TWELVE_HOUR) => {
+                // Keep the focus on a displayed segment
+                if !self.get_segments().contains(&self.states.segment) {
+                    self.states.segment = TimeSegment::Hour;
+                }
+                self.states.pending = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        let time = self.states.time;
+        let value = match self.get_flag(Attribute::Custom(TIME_PICKER_SECONDS)) {
+            true => time.to_string(),
+            false => format!("{:02}:{:02}", time.hour, time.minute),
+        };
+        State::One(StateValue::String(value))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let segments = self.get_segments();
+        match cmd {
+            Cmd::Move(Direction::Left) => {
+                self.states.focus_segment(-1, &segments);
+                CmdResult::None
+            }
+            Cmd::Move(Direction::Right) => {
+                self.states.focus_segment(1, &segments);
+                CmdResult::None
+            }
+            Cmd::Move(Direction::Up) => {
+                self.states.increment(1);
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Move(Direction::Down) => {
+                self.states.increment(-1);
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Type(ch) => {
+                let twelve_hour = self.get_flag(Attribute::Custom(TIME_PICKER_TWELVE_HOUR));
+                match self.states.type_char(ch, &segments, twelve_hour) {
+                    true => CmdResult::Changed(self.state()),
+                    false => CmdResult::None,
+                }
+            }
+            Cmd::Submit => CmdResult::Submit(self.state()),
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn time(s: &str) -> State {
+        State::One(StateValue::String(String::from(s)))
+    }
+
+    #[test]
+    fn test_components_time_picker() {
+        let mut component = TimePicker::default()
+            .title("Alarm", Alignment::Left)
+            .borders(Borders::default())
+            .highlighted_color(Color::LightYellow)
+            .value(Time::new(23, 59, 30).unwrap());
+        assert_eq!(component.state(), time("23:59"));
+        // Wrap around
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(time("00:59"))
+        );
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.segment, TimeSegment::Minute);
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Up)),
+            CmdResult::Changed(time("00:00"))
+        );
+        // No seconds segment
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.segment, TimeSegment::Minute);
+        component.perform(Cmd::Move(Direction::Down));
+        assert_eq!(component.state(), time("00:59"));
+        // Seconds
+        component.attr(
+            Attribute::Custom(TIME_PICKER_SECONDS),
+            AttrValue::Flag(true),
+        );
+        assert_eq!(component.state(), time("00:59:30"));
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.segment, TimeSegment::Second);
+        // Invalid values are ignored
+        component.attr(Attribute::Value, AttrValue::String(String::from("25:00")));
+        assert_eq!(component.state(), time("00:59:30"));
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(time("00:59:30"))
+        );
+    }
+
+    #[test]
+    fn test_components_time_picker_digits() {
+        let mut component = TimePicker::default().seconds(true);
+        for ch in "1745".chars() {
+            component.perform(Cmd::Type(ch));
+        }
+        assert_eq!(component.state(), time("17:45:00"));
+        assert_eq!(component.states.segment, TimeSegment::Second);
+        // 7 can't be followed by another digit
+        assert_eq!(
+            component.perform(Cmd::Type('7')),
+            CmdResult::Changed(time("17:45:07"))
+        );
+        assert_eq!(component.states.pending, None);
+        // 2 followed by 7 is out of range: start over
+        component.perform(Cmd::Move(Direction::Left));
+        component.perform(Cmd::Move(Direction::Left));
+        component.perform(Cmd::Type('2'));
+        assert_eq!(component.state(), time("02:45:07"));
+        assert_eq!(component.states.pending, Some(2));
+        component.perform(Cmd::Type('7'));
+        assert_eq!(component.state(), time("07:45:07"));
+        assert_eq!(component.states.segment, TimeSegment::Minute);
+        assert_eq!(component.perform(Cmd::Type('x')), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_time_picker_twelve_hour() {
+        let mut component = TimePicker::default()
+            .twelve_hour(true)
+            .value(Time::new(14, 5, 0).unwrap());
+        assert_eq!(component.state(), time("14:05"));
+        // 12 PM is noon
+        component.perform(Cmd::Type('1'));
+        component.perform(Cmd::Type('2'));
+        assert_eq!(component.state(), time("12:05"));
+        component.perform(Cmd::Move(Direction::Right));
+        assert_eq!(component.states.segment, TimeSegment::Meridiem);
+        assert_eq!(
+            component.perform(Cmd::Type('a')),
+            CmdResult::Changed(time("00:05"))
+        );
+        assert_eq!(component.perform(Cmd::Type('a')), CmdResult::None);
+        component.perform(Cmd::Move(Direction::Up));
+        assert_eq!(component.state(), time("12:05"));
+        // Back to 24 hours: meridiem is not displayed anymore
+        component.attr(
+            Attribute::Custom(TIME_PICKER_TWELVE_HOUR),
+            AttrValue::Flag(false),
+        );
+        assert_eq!(component.states.segment, TimeSegment::Hour);
+    }
+
+    #[test]
+    fn test_components_time_picker_render() {
+        let mut component = TimePicker::default()
+            .seconds(true)
+            .twelve_hour(true)
+            .value(Time::new(21, 7, 9).unwrap());
+        component.perform(Cmd::Move(Direction::Right));
+        let area = Rect::new(0, 0, 14, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_time(&mut buf, area, Style::default(), true);
+        let row: String = (0..14).map(|x| buf[(x, 0)].symbol().to_string()).collect();
+        assert_eq!(row, "09:07:09 PM   ");
+        assert!(!buf[(1, 0)].modifier.contains(TextModifiers::REVERSED));
+        assert!(buf[(3, 0)].modifier.contains(TextModifiers::REVERSED));
+        assert!(buf[(4, 0)].modifier.contains(TextModifiers::REVERSED));
+        assert!(!buf[(5, 0)].modifier.contains(TextModifiers::REVERSED));
+    }
+}
@@ -0,0 +1,555 @@
+//! ## Timeline
+//!
+//! `Timeline` renders a vertical sequence of events, such as an activity feed
+
+use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{TIMELINE_TIME_COLOR, TOOLTIP};
+
+/// ## TimelineEvent
+///
+/// An event displayed by the `Timeline`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineEvent {
+    pub time: String,
+    /// Symbol on the connector line (default: `●`)
+    pub marker: String,
+    pub title: String,
+    pub description: String,
+    /// Color of the marker; `Reset` uses the foreground
+    pub color: Color,
+}
+
+impl TimelineEvent {
+    /// ### new
+    ///
+    /// Make a new event without description
+    pub fn new<T: Into<String>, S: Into<String>>(time: T, title: S) -> Self {
+        Self {
+            time: time.into(),
+            marker: String::from("●"),
+            title: title.into(),
+            description: String::new(),
+            color: Color::Reset,
+        }
+    }
+
+    pub fn marker<S: Into<String>>(mut self, marker: S) -> Self {
+        self.marker = marker.into();
+        self
+    }
+
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn to_payload(&self) -> PropPayload {
+        PropPayload::Vec(vec![
+            PropValue::Str(self.time.clone()),
+            PropValue::Str(self.marker.clone()),
+            PropValue::Str(self.title.clone()),
+            PropValue::Str(self.description.clone()),
+            PropValue::Color(self.color),
+        ])
+    }
+
+    fn from_payload(payload: PropPayload) -> Self {
+        let mut values = payload.unwrap_vec().into_iter();
+        let mut next_str = || values.next().map(|x| x.unwrap_str()).unwrap_or_default();
+        let (time, marker, title, description) = (next_str(), next_str(), next_str(), next_str());
+        Self {
+            time,
+            marker,
+            title,
+            description,
+            color: values
+                .next()
+                .map(|x| x.unwrap_color())
+                .unwrap_or(Color::Reset),
+        }
+    }
+}
+
+/// ## TimelineRow
+///
+/// A row of the timeline
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TimelineRow {
+    /// Time, marker and title of an event
+    Title(usize),
+    /// A line of the description of an event
+    Description(String),
+    /// Space between two events
+    Spacer,
+}
+
+/// ### layout_rows
+///
+/// Lay out the rows of `events`, wrapping descriptions to `width`.
+/// Returns the rows, and the range of rows of each event
+fn layout_rows(
+    events: &[TimelineEvent],
+    width: usize,
+) -> (Vec<TimelineRow>, Vec<std::ops::Range<usize>>) {
+    let mut rows = Vec::new();
+    let mut ranges = Vec::with_capacity(events.len());
+    for (i, event) in events.iter().enumerate() {
+        if i > 0 {
+            rows.push(TimelineRow::Spacer);
+        }
+        let start = rows.len();
+        rows.push(TimelineRow::Title(i));
+        if !event.description.is_empty() {
+            rows.extend(
+                textwrap::wrap(&event.description, width.max(1))
+                    .into_iter()
+                    .map(|x| TimelineRow::Description(x.into_owned())),
+            );
+        }
+        ranges.push(start..rows.len());
+    }
+    (rows, ranges)
+}
+
+// -- states
+
+/// ## TimelineStates
+///
+/// TimelineStates contains states for this component
+#[derive(Default)]
+pub struct TimelineStates {
+    /// Index of the selected event
+    pub selected: usize,
+    /// Number of events
+    pub len: usize,
+    /// First row displayed
+    pub scroll: usize,
+}
+
+impl TimelineStates {
+    /// ### set_len
+    ///
+    /// Set the number of events, keeping the selection in range
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len;
+        self.selected = self.selected.min(len.saturating_sub(1));
+    }
+
+    /// ### select
+    ///
+    /// Select the event at `index`, clamped to the events
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.len.saturating_sub(1));
+    }
+
+    /// ### fit
+    ///
+    /// Scroll so that the rows in `range` are visible in `height` rows, or at least the first one
+    fn fit(&mut self, range: std::ops::Range<usize>, total: usize, height: usize) {
+        if range.start < self.scroll {
+            self.scroll = range.start;
+        } else if range.end > self.scroll + height {
+            self.scroll = range.start.min(range.end.saturating_sub(height));
+        }
+        self.scroll = self.scroll.min(total.saturating_sub(height));
+    }
+}
+
+// -- component
+
+/// ## Timeline
+///
+/// A vertical sequence of events, such as an activity feed or a deployment history. Each event has a time, a marker
+/// on the connector line, a title and a description. An event can be selected; the timeline scrolls to keep it
+/// visible
+#[derive(Default)]
+pub struct Timeline {
+    props: Props,
+    pub states: TimelineStates,
+}
+
+impl Timeline {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `timeline` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("timeline", self);
+    }
+
+    pub fn highlighted_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::HighlightedColor, AttrValue::Color(c));
+        self
+    }
+
+    pub fn step(mut self, step: usize) -> Self {
+        self.attr(Attribute::ScrollStep, AttrValue::Length(step));
+        self
+    }
+
+    /// Set the color of times (default: dark gray)
+    pub fn time_color(mut self, c: Color) -> Self {
+        self.attr(Attribute::Custom(TIMELINE_TIME_COLOR), AttrValue::Color(c));
+        self
+    }
+
+    pub fn events(mut self, events: &[TimelineEvent]) -> Self {
+        self.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(
+                events.iter().map(|x| x.to_payload()).collect(),
+            )),
+        );
+        self
+    }
+
+    pub fn selected_event(mut self, index: usize) -> Self {
+        self.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(index))),
+        );
+        self
+    }
+
+    fn get_events(&self) -> Vec<TimelineEvent> {
+        match self
+            .props
+            .get(Attribute::Content)
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::Linked(events)) => events
+                .into_iter()
+                .map(TimelineEvent::from_payload)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// ### render_events
+    ///
+    /// Render the events in `area`, scrolled to the selected one
+    fn render_events(&mut self, buf: &mut Buffer, area: Rect, style: Style, highlight: Style) {
+        let events = self.get_events();
+        let time_width = events.iter().map(|x| x.time.width()).max().unwrap_or(0);
+        // Time, space, marker, space
+        let text_x = time_width + 3;
+        let (rows, ranges) = layout_rows(&events, (area.width as usize).saturating_sub(text_x));
+        if let Some(range) = ranges.get(self.states.selected) {
+            self.states
+                .fit(range.clone(), rows.len(), area.height as usize);
+        }
+        let time_style = style.fg(self
+            .props
+            .get_or(
+                Attribute::Custom(TIMELINE_TIME_COLOR),
+                AttrValue::Color(Color::DarkGray),
+            )
+            .unwrap_color());
+        let max_width = |x: u16| area.right().saturating_sub(x) as usize;
+        let connector_x = area.x + (time_width as u16 + 1).min(area.width);
+        let text_x = area.x + (text_x as u16).min(area.width);
+        for (y, row) in (area.y..area.bottom()).zip(rows.iter().skip(self.states.scroll)) {
+            match row {
+                TimelineRow::Title(i) => {
+                    let event = &events[*i];
+                    let time_x = area.x + (time_width - event.time.width()) as u16;
+                    buf.set_stringn(time_x, y, &event.time, max_width(time_x), time_style);
+                    let marker_style = match event.color {
+                        Color::Reset => style,
+                        color => style.fg(color),
+                    };
+                    buf.set_stringn(
+                        connector_x,
+                        y,
+                        &event.marker,
+                        max_width(connector_x),
+                        marker_style,
+                    );
+                    buf.set_stringn(
+                        text_x,
+                        y,
+                        &event.title,
+                        max_width(text_x),
+                        style.add_modifier(TextModifiers::BOLD),
+                    );
+                    if *i == self.states.selected {
+                        buf.set_style(Rect::new(area.x, y, area.width, 1), highlight);
+                    }
+                }
+                TimelineRow::Description(line) => {
+                    buf.set_stringn(connector_x, y, "│", max_width(connector_x), style);
+                    buf.set_stringn(text_x, y, line, max_width(text_x), style);
+                }
+                TimelineRow::Spacer => {
+                    buf.set_stringn(connector_x, y, "│", max_width(connector_x), style);
+                }
+            }
+        }
+    }
+}
+
+impl MockComponent for Timeline {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default().fg(foreground).bg(background),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let mut highlight = Style::default();
+            if let Some(color) = self
+                .props
+                .get(Attribute::HighlightedColor)
+                .map(|x| x.unwrap_color())
+            {
+                highlight = highlight.fg(color);
+            }
+            if focus {
+                highlight = highlight.add_modifier(TextModifiers::REVERSED);
+            }
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_events(render.buffer_mut(), inner, style, highlight);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        match attr {
+            Attribute::Content => {
+                let len = self.get_events().len();
+                self.states.set_len(len);
+            }
+            Attribute::Value => {
+                if let Some(PropPayload::One(PropValue::Usize(index))) =
+                    self.props.get(attr).map(|x| x.unwrap_payload())
+                {
+                    self.states.select(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.states.len {
+            0 => State::None,
+            _ => State::One(StateValue::Usize(self.states.selected)),
+        }
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let step = self
+            .props
+            .get_or(Attribute::ScrollStep, AttrValue::Length(8))
+            .unwrap_length();
+        let prev = self.states.selected;
+        match cmd {
+            Cmd::Move(Direction::Down) => self.states.select(prev + 1),
+            Cmd::Move(Direction::Up) => self.states.select(prev.saturating_sub(1)),
+            Cmd::Scroll(Direction::Down) => self.states.select(prev + step),
+            Cmd::Scroll(Direction::Up) => self.states.select(prev.saturating_sub(step)),
+            Cmd::GoTo(Position::Begin) => self.states.select(0),
+            Cmd::GoTo(Position::End) => self.states.select(usize::MAX),
+            Cmd::Submit => {
+                return match self.state() {
+                    State::None => CmdResult::None,
+                    state => CmdResult::Submit(state),
+                }
+            }
+            _ => return CmdResult::None,
+        }
+        match self.states.selected != prev {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn component() -> Timeline {
+        Timeline::default().events(&[
+            TimelineEvent::new("10:32", "Deploy v1.2.0")
+                .marker("✔")
+                .color(Color::Green)
+                .description("Rolled out to production"),
+            TimelineEvent::new("9:15", "Build #1234"),
+            TimelineEvent::new("Yesterday", "Merged #42").description("Add timeline"),
+        ])
+    }
+
+    #[test]
+    fn test_components_timeline() {
+        let mut component = component();
+        assert_eq!(component.states.len, 3);
+        assert_eq!(component.state(), State::One(StateValue::Usize(0)));
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::Changed(State::One(StateValue::Usize(1)))
+        );
+        assert_eq!(
+            component.perform(Cmd::GoTo(Position::End)),
+            CmdResult::Changed(State::One(StateValue::Usize(2)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Move(Direction::Down)),
+            CmdResult::None
+        );
+        assert_eq!(
+            component.perform(Cmd::Scroll(Direction::Up)),
+            CmdResult::Changed(State::One(StateValue::Usize(0)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Usize(0)))
+        );
+        component.attr(
+            Attribute::Value,
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(1))),
+        );
+        assert_eq!(component.states.selected, 1);
+        // Fewer events
+        component.attr(
+            Attribute::Content,
+            AttrValue::Payload(PropPayload::Linked(Default::default())),
+        );
+        assert_eq!(component.states.selected, 0);
+        assert_eq!(component.state(), State::None);
+        assert_eq!(component.perform(Cmd::Submit), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_timeline_render() {
+        let mut component = component();
+        let area = Rect::new(0, 0, 30, 7);
+        let mut buf = Buffer::empty(area);
+        component.render_events(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(
+            rows(&buf),
+            vec![
+                "    10:32 ✔ Deploy v1.2.0",
+                "          │ Rolled out to",
+                "          │ production",
+                "          │",
+                "     9:15 ● Build #1234",
+                "          │",
+                "Yesterday ● Merged #42",
+            ]
+        );
+        assert_eq!(buf[(10, 0)].fg, Color::Green);
+        assert_eq!(buf[(0, 6)].fg, Color::DarkGray);
+        // Scroll to the last event
+        component.states.select(2);
+        let mut buf = Buffer::empty(area);
+        component.render_events(&mut buf, area, Style::default(), Style::default());
+        assert_eq!(component.states.scroll, 1);
+        assert_eq!(rows(&buf)[6], "          │ Add timeline");
+    }
+}
@@ -0,0 +1,608 @@
+//! ## Timer
+//!
+//! `Timer` is a countdown or a stopwatch
+
+use std::time::{Duration, Instant};
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+    TextModifiers,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect, widgets::Paragraph, widgets::Widget};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use super::big_text::{render_big_text, BigTextFont, Coloring, Glyphs};
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::commands::{TIMER_CMD_PAUSE, TIMER_CMD_RESET, TIMER_CMD_START, TIMER_EXPIRED};
+use super::props::{
+    TIMER_BIG, TIMER_DURATION, TIMER_FONT, TIMER_FORMAT, TIMER_MODE, TIMER_WARNING_STYLE,
+    TIMER_WARNING_THRESHOLD, TOOLTIP,
+};
+
+/// Format used when none is set
+const DEFAULT_FORMAT: &str = "%H:%M:%S";
+
+/// ## TimerMode
+///
+/// Whether the timer counts down or up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimerMode {
+    /// Counts up from zero
+    #[default]
+    Stopwatch,
+    /// Counts down from a duration to zero, then expires
+    Countdown,
+}
+
+impl TimerMode {
+    const ALL: [Self; 2] = [Self::Stopwatch, Self::Countdown];
+}
+
+/// ### format_duration
+///
+/// Format `millis` using a strftime-like format string.
+/// Supported specifiers are `%H` (hours), `%M` (minutes), `%S` (seconds), `%f` (milliseconds) and `%%`.
+/// Unknown specifiers are kept as they are.
+fn format_duration(millis: u64, fmt: &str) -> String {
+    let secs = millis / 1000;
+    let mut out = String::with_capacity(fmt.len());
+    let mut chars = fmt.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('H') => out.push_str(&format!("{:02}", secs / 3600)),
+            Some('M') => out.push_str(&format!("{:02}", (secs % 3600) / 60)),
+            Some('S') => out.push_str(&format!("{:02}", secs % 60)),
+            Some('f') => out.push_str(&format!("{:03}", millis % 1000)),
+            Some('%') => out.push('%'),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
+
+// -- states
+
+/// ## TimerStates
+///
+/// TimerStates contains states for this component
+#[derive(Default)]
+pub struct TimerStates {
+    /// Time elapsed while running
+    pub elapsed: Duration,
+    /// Last time the elapsed time was updated, while running
+    pub since: Option<Instant>,
+    /// Whether the countdown has expired
+    pub expired: bool,
+}
+
+impl TimerStates {
+    /// ### is_running
+    ///
+    /// Whether the timer is running
+    pub fn is_running(&self) -> bool {
+        self.since.is_some()
+    }
+
+    /// ### start
+    ///
+    /// Start the timer at `now`; returns whether it wasn't running
+    pub fn start(&mut self, now: Instant) -> bool {
+        match self.is_running() || self.expired {
+            true => false,
+            false => {
+                self.since = Some(now);
+                true
+            }
+        }
+    }
+
+    /// ### pause
+    ///
+    /// Pause the timer at `now`; returns whether it was running
+    pub fn pause(&mut self, now: Instant) -> bool {
+        self.update(now);
+        self.since.take().is_some()
+    }
+
+    /// ### reset
+    ///
+    /// Stop the timer and set the elapsed time to zero
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.since = None;
+        self.expired = false;
+    }
+
+    /// ### update
+    ///
+    /// Add the time elapsed until `now`, if running
+    pub fn update(&mut self, now: Instant) {
+        if let Some(since) = self.since {
+            self.elapsed += now.saturating_duration_since(since);
+            self.since = Some(now);
+        }
+    }
+}
+
+// -- component
+
+/// ## Timer
+///
+/// A countdown or a stopwatch. The time is updated with `Cmd::Tick`, which should be performed on `Event::Tick`.
+/// When the countdown reaches zero, the timer stops and `CmdResult::Custom(TIMER_EXPIRED)` is returned
+#[derive(Default)]
+pub struct Timer {
+    props: Props,
+    pub states: TimerStates,
+}
+
+impl Timer {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn modifiers(mut self, m: TextModifiers) -> Self {
+        self.attr(Attribute::TextProps, AttrValue::TextModifiers(m));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `timer` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("timer", self);
+    }
+
+    pub fn alignment(mut self, a: Alignment) -> Self {
+        self.attr(Attribute::Alignment, AttrValue::Alignment(a));
+        self
+    }
+
+    /// Set the mode of the timer (default: stopwatch)
+    pub fn mode(mut self, mode: TimerMode) -> Self {
+        self.attr(
+            Attribute::Custom(TIMER_MODE),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(mode as usize))),
+        );
+        self
+    }
+
+    /// Set the duration of the countdown
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.attr(
+            Attribute::Custom(TIMER_DURATION),
+            AttrValue::Payload(PropPayload::One(
+                PropValue::U64(duration.as_millis() as u64),
+            )),
+        );
+        self
+    }
+
+    /// Set the format of the time (default: `%H:%M:%S`). Supported specifiers are `%H`, `%M`, `%S`, `%f` and `%%`
+    pub fn format<S: Into<String>>(mut self, fmt: S) -> Self {
+        self.attr(
+            Attribute::Custom(TIMER_FORMAT),
+            AttrValue::String(fmt.into()),
+        );
+        self
+    }
+
+    /// Render the time with big digits
+    pub fn big(mut self, big: bool) -> Self {
+        self.attr(Attribute::Custom(TIMER_BIG), AttrValue::Flag(big));
+        self
+    }
+
+    /// Set the font of big digits (default: block)
+    pub fn font(mut self, font: BigTextFont) -> Self {
+        self.attr(
+            Attribute::Custom(TIMER_FONT),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(font as usize))),
+        );
+        self
+    }
+
+    /// Render the countdown with `style` when the time left is under `threshold`
+    pub fn warning(mut self, threshold: Duration, style: Style) -> Self {
+        self.attr(
+            Attribute::Custom(TIMER_WARNING_THRESHOLD),
+            AttrValue::Payload(PropPayload::One(PropValue::U64(
+                threshold.as_millis() as u64
+            ))),
+        );
+        self.attr(
+            Attribute::Custom(TIMER_WARNING_STYLE),
+            AttrValue::Style(style),
+        );
+        self
+    }
+
+    fn get_mode(&self) -> TimerMode {
+        match self
+            .props
+            .get(Attribute::Custom(TIMER_MODE))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::Usize(x))) => {
+                TimerMode::ALL.get(x).copied().unwrap_or_default()
+            }
+            _ => TimerMode::default(),
+        }
+    }
+
+    fn get_millis(&self, attr: &'static str) -> u64 {
+        match self
+            .props
+            .get(Attribute::Custom(attr))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::U64(x))) => x,
+            _ => 0,
+        }
+    }
+
+    fn get_font(&self) -> BigTextFont {
+        match self
+            .props
+            .get(Attribute::Custom(TIMER_FONT))
+            .map(|x| x.unwrap_payload())
+        {
+            Some(PropPayload::One(PropValue::Usize(x))) => {
+                BigTextFont::ALL.get(x).copied().unwrap_or_default()
+            }
+            _ => BigTextFont::default(),
+        }
+    }
+
+    /// ### millis
+    ///
+    /// Get the time to display, in milliseconds: the time left of the countdown, or the time elapsed
+    fn millis(&self) -> u64 {
+        let elapsed = self.states.elapsed.as_millis() as u64;
+        match self.get_mode() {
+            TimerMode::Countdown => self.get_millis(TIMER_DURATION).saturating_sub(elapsed),
+            TimerMode::Stopwatch => elapsed,
+        }
+    }
+
+    /// ### text
+    ///
+    /// Get the time, formatted. Unless milliseconds are displayed, the countdown is rounded up to the second
+    fn text(&self) -> String {
+        let fmt = self
+            .props
+            .get(Attribute::Custom(TIMER_FORMAT))
+            .map(|x| x.unwrap_string())
+            .unwrap_or_else(|| DEFAULT_FORMAT.to_string());
+        let millis = match self.get_mode() {
+            TimerMode::Countdown if !fmt.contains("%f") => self.millis().div_ceil(1000) * 1000,
+            _ => self.millis(),
+        };
+        format_duration(millis, &fmt)
+    }
+
+    /// ### is_warning
+    ///
+    /// Whether the countdown is under the warning threshold
+    fn is_warning(&self) -> bool {
+        self.get_mode() == TimerMode::Countdown
+            && self
+                .props
+                .get(Attribute::Custom(TIMER_WARNING_STYLE))
+                .is_some()
+            && self.millis() < self.get_millis(TIMER_WARNING_THRESHOLD)
+    }
+
+    /// ### tick
+    ///
+    /// Update the time at `now`
+    fn tick(&mut self, now: Instant) -> CmdResult {
+        if !self.states.is_running() {
+            return CmdResult::None;
+        }
+        let prev = (self.text(), self.is_warning());
+        self.states.update(now);
+        if self.get_mode() == TimerMode::Countdown
+            && self.states.elapsed.as_millis() as u64 >= self.get_millis(TIMER_DURATION)
+        {
+            self.states.elapsed = Duration::from_millis(self.get_millis(TIMER_DURATION));
+            self.states.since = None;
+            self.states.expired = true;
+            return CmdResult::Custom(TIMER_EXPIRED, self.state());
+        }
+        match (self.text(), self.is_warning()) == prev {
+            true => CmdResult::None,
+            false => CmdResult::Changed(self.state()),
+        }
+    }
+
+    /// ### render_time
+    ///
+    /// Render the time, centered vertically in `area`
+    fn render_time(&self, buf: &mut Buffer, area: Rect, style: Style) {
+        let text = self.text();
+        let alignment = self
+            .props
+            .get_or(
+                Attribute::Alignment,
+                AttrValue::Alignment(Alignment::Center),
+            )
+            .unwrap_alignment();
+        let style = match self.is_warning() {
+            true => style.patch(
+                self.props
+                    .get(Attribute::Custom(TIMER_WARNING_STYLE))
+                    .map(|x| x.unwrap_style())
+                    .unwrap_or_default(),
+            ),
+            false => style,
+        };
+        if self
+            .props
+            .get_or(Attribute::Custom(TIMER_BIG), AttrValue::Flag(false))
+            .unwrap_flag()
+        {
+            let glyphs = Glyphs::Builtin(self.get_font());
+            let height = (glyphs.height() as u16).min(area.height);
+            let area = Rect::new(
+                area.x,
+                area.y + (area.height - height) / 2,
+                area.width,
+                height,
+            );
+            render_big_text(
+                buf,
+                area,
+                &text,
+                &glyphs,
+                alignment,
+                &Coloring::Letters(&[]),
+                style,
+            );
+        } else {
+            let area = Rect::new(
+                area.x,
+                area.y + area.height / 2,
+                area.width,
+                area.height.min(1),
+            );
+            Paragraph::new(text)
+                .style(style)
+                .alignment(alignment)
+                .render(area, buf);
+        }
+    }
+}
+
+impl MockComponent for Timer {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let modifiers = self
+                .props
+                .get_or(
+                    Attribute::TextProps,
+                    AttrValue::TextModifiers(TextModifiers::empty()),
+                )
+                .unwrap_text_modifiers();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let style = match focus {
+                true => Style::default()
+                    .fg(foreground)
+                    .bg(background)
+                    .add_modifier(modifiers),
+                false => inactive_style.unwrap_or_default(),
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_time(render.buffer_mut(), inner, style);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        self.props.set(attr, value);
+        if attr == Attribute::Custom(TIMER_MODE) {
+            self.states.reset();
+        }
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::U64(self.millis()))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        let now = Instant::now();
+        let changed = match cmd {
+            Cmd::Tick => return self.tick(now),
+            Cmd::Custom(TIMER_CMD_START) => self.states.start(now),
+            Cmd::Custom(TIMER_CMD_PAUSE) => self.states.pause(now),
+            Cmd::Custom(TIMER_CMD_RESET) => {
+                self.states.reset();
+                true
+            }
+            Cmd::Toggle => self.states.pause(now) || self.states.start(now),
+            _ => false,
+        };
+        match changed {
+            true => CmdResult::Changed(self.state()),
+            false => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_timer_format_duration() {
+        assert_eq!(format_duration(0, "%H:%M:%S"), "00:00:00");
+        assert_eq!(format_duration(3_723_045, "%H:%M:%S.%f"), "01:02:03.045");
+        assert_eq!(format_duration(360_000_000, "%Hh %Mm"), "100h 00m");
+        assert_eq!(format_duration(0, "100%% %q%"), "100% %q%");
+    }
+
+    #[test]
+    fn test_components_timer_stopwatch() {
+        let mut component = Timer::default().format("%M:%S.%f");
+        let t0 = Instant::now();
+        assert_eq!(component.tick(t0), CmdResult::None);
+        assert!(component.states.start(t0));
+        assert!(!component.states.start(t0));
+        assert_eq!(
+            component.tick(t0 + Duration::from_millis(1500)),
+            CmdResult::Changed(State::One(StateValue::U64(1500)))
+        );
+        assert!(component.states.pause(t0 + Duration::from_millis(2000)));
+        assert!(!component.states.is_running());
+        assert_eq!(
+            component.tick(t0 + Duration::from_secs(10)),
+            CmdResult::None
+        );
+        assert_eq!(component.text(), "00:02.000");
+        // Resume
+        assert!(component.states.start(t0 + Duration::from_secs(10)));
+        component.tick(t0 + Duration::from_millis(71_250));
+        assert_eq!(component.text(), "01:03.250");
+        assert_eq!(
+            component.perform(Cmd::Custom(TIMER_CMD_RESET)),
+            CmdResult::Changed(State::One(StateValue::U64(0)))
+        );
+        assert!(!component.states.is_running());
+        // Toggle
+        assert_eq!(
+            component.perform(Cmd::Toggle),
+            CmdResult::Changed(State::One(StateValue::U64(0)))
+        );
+        assert!(component.states.is_running());
+        component.perform(Cmd::Toggle);
+        assert!(!component.states.is_running());
+    }
+
+    #[test]
+    fn test_components_timer_countdown() {
+        let mut component = Timer::default()
+            .mode(TimerMode::Countdown)
+            .duration(Duration::from_secs(90))
+            .format("%M:%S")
+            .warning(Duration::from_secs(10), Style::default().fg(Color::Red));
+        assert_eq!(component.state(), State::One(StateValue::U64(90_000)));
+        let t0 = Instant::now();
+        component.states.start(t0);
+        // Rounded up to the second
+        assert_eq!(
+            component.tick(t0 + Duration::from_millis(200)),
+            CmdResult::None
+        );
+        assert_eq!(component.text(), "01:30");
+        assert_eq!(
+            component.tick(t0 + Duration::from_millis(1200)),
+            CmdResult::Changed(State::One(StateValue::U64(88_800)))
+        );
+        assert_eq!(component.text(), "01:29");
+        // Warning
+        assert!(!component.is_warning());
+        component.tick(t0 + Duration::from_millis(85_000));
+        assert!(component.is_warning());
+        let area = Rect::new(0, 0, 7, 1);
+        let mut buf = Buffer::empty(area);
+        component.render_time(&mut buf, area, Style::default());
+        assert_eq!(buf[(1, 0)].symbol(), "0");
+        assert_eq!(buf[(5, 0)].symbol(), "5");
+        assert_eq!(buf[(1, 0)].fg, Color::Red);
+        // Expired
+        assert_eq!(
+            component.tick(t0 + Duration::from_secs(95)),
+            CmdResult::Custom(TIMER_EXPIRED, State::One(StateValue::U64(0)))
+        );
+        assert!(component.states.expired);
+        assert!(!component.states.is_running());
+        assert!(!component.states.start(t0 + Duration::from_secs(96)));
+        assert_eq!(component.text(), "00:00");
+        component.states.reset();
+        assert_eq!(component.state(), State::One(StateValue::U64(90_000)));
+    }
+}
@@ -0,0 +1,306 @@
+//! ## ToggleSwitch
+//!
+//! `ToggleSwitch` renders an on/off switch
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, Borders, Color, PropPayload, PropValue, Props, Style,
+};
+use tuirealm::ratatui::{buffer::Buffer, layout::Rect};
+use tuirealm::{Frame, MockComponent, State, StateValue};
+
+use crate::theme::Theme;
+use crate::utils::{get_block, get_title_style, is_disabled, render_disabled, render_tooltip};
+
+// -- Props
+
+use super::props::{TOGGLE_SWITCH_OFF_COLOR, TOGGLE_SWITCH_ON_COLOR, TOGGLE_SWITCH_TEXTS, TOOLTIP};
+
+const SYMBOL_ON: &str = "◉";
+const SYMBOL_OFF: &str = "○";
+
+// -- states
+
+/// ## ToggleSwitchStates
+///
+/// ToggleSwitchStates contains states for this component
+#[derive(Default)]
+pub struct ToggleSwitchStates {
+    pub on: bool,
+}
+
+impl ToggleSwitchStates {
+    /// ### toggle
+    ///
+    /// Switch on or off
+    pub fn toggle(&mut self) {
+        self.on = !self.on;
+    }
+}
+
+// -- component
+
+/// ## ToggleSwitch
+///
+/// An on/off switch, rendered as `◉ ON` or `○ OFF` after an optional label
+#[derive(Default)]
+pub struct ToggleSwitch {
+    props: Props,
+    pub states: ToggleSwitchStates,
+}
+
+impl ToggleSwitch {
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.attr(Attribute::Foreground, AttrValue::Color(fg));
+        self
+    }
+
+    pub fn background(mut self, bg: Color) -> Self {
+        self.attr(Attribute::Background, AttrValue::Color(bg));
+        self
+    }
+
+    pub fn borders(mut self, b: Borders) -> Self {
+        self.attr(Attribute::Borders, AttrValue::Borders(b));
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, t: S, a: Alignment) -> Self {
+        self.attr(Attribute::Title, AttrValue::Title((t.into(), a)));
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.attr(Attribute::FocusStyle, AttrValue::Style(s));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.attr(Attribute::Disabled, AttrValue::Flag(disabled));
+        self
+    }
+
+    pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+        self.attr(Attribute::Custom(TOOLTIP), AttrValue::String(text.into()));
+        self
+    }
+
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.apply_theme(theme);
+        self
+    }
+
+    /// ### apply_theme
+    ///
+    /// Apply the styles of `theme` for `toggle_switch` to the component
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        theme.apply("toggle_switch", self);
+    }
+
+    /// Set the label displayed before the switch
+    pub fn label<S: Into<String>>(mut self, s: S) -> Self {
+        self.attr(Attribute::Text, AttrValue::String(s.into()));
+        self
+    }
+
+    /// Set the color of the switch when on; `foreground` by default
+    pub fn on_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(TOGGLE_SWITCH_ON_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    /// Set the color of the switch when off; `foreground` by default
+    pub fn off_color(mut self, c: Color) -> Self {
+        self.attr(
+            Attribute::Custom(TOGGLE_SWITCH_OFF_COLOR),
+            AttrValue::Color(c),
+        );
+        self
+    }
+
+    /// Set the texts displayed when on and off (default: `ON` and `OFF`)
+    pub fn texts<S: Into<String>>(mut self, on: S, off: S) -> Self {
+        self.attr(
+            Attribute::Custom(TOGGLE_SWITCH_TEXTS),
+            AttrValue::Payload(PropPayload::Tup2((
+                PropValue::Str(on.into()),
+                PropValue::Str(off.into()),
+            ))),
+        );
+        self
+    }
+
+    pub fn value(mut self, on: bool) -> Self {
+        self.attr(Attribute::Value, AttrValue::Flag(on));
+        self
+    }
+
+    fn get_texts(&self) -> (String, String) {
+        self.props
+            .get(Attribute::Custom(TOGGLE_SWITCH_TEXTS))
+            .map(|x| {
+                let (on, off) = x.unwrap_payload().unwrap_tup2();
+                (on.unwrap_str(), off.unwrap_str())
+            })
+            .unwrap_or_else(|| (String::from("ON"), String::from("OFF")))
+    }
+
+    /// ### render_switch
+    ///
+    /// Render the label and the switch on the first row of `area`; the switch is colored with `color`
+    fn render_switch(&self, buf: &mut Buffer, area: Rect, style: Style, color: Color) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let (on, off) = self.get_texts();
+        let switch = match self.states.on {
+            true => format!("{SYMBOL_ON} {on}"),
+            false => format!("{SYMBOL_OFF} {off}"),
+        };
+        let mut x = area.x;
+        if let Some(label) = self.props.get(Attribute::Text).map(|x| x.unwrap_string()) {
+            (x, _) = buf.set_stringn(x, area.y, label + " ", area.width as usize, style);
+        }
+        let width = area.right().saturating_sub(x) as usize;
+        buf.set_stringn(x, area.y, switch, width, style.fg(color));
+    }
+}
+
+impl MockComponent for ToggleSwitch {
+    fn view(&mut self, render: &mut Frame, area: Rect) {
+        if self.props.get_or(Attribute::Display, AttrValue::Flag(true)) == AttrValue::Flag(true) {
+            let foreground = self
+                .props
+                .get_or(Attribute::Foreground, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let background = self
+                .props
+                .get_or(Attribute::Background, AttrValue::Color(Color::Reset))
+                .unwrap_color();
+            let borders = self
+                .props
+                .get_or(Attribute::Borders, AttrValue::Borders(Borders::default()))
+                .unwrap_borders();
+            let title = self.props.get(Attribute::Title).map(|x| x.unwrap_title());
+            let focus = self
+                .props
+                .get_or(Attribute::Focus, AttrValue::Flag(false))
+                .unwrap_flag();
+            let inactive_style = self
+                .props
+                .get(Attribute::FocusStyle)
+                .map(|x| x.unwrap_style());
+            let (style, color) = match focus {
+                true => {
+                    let color_attr = match self.states.on {
+                        true => TOGGLE_SWITCH_ON_COLOR,
+                        false => TOGGLE_SWITCH_OFF_COLOR,
+                    };
+                    let color = self
+                        .props
+                        .get_or(Attribute::Custom(color_attr), AttrValue::Color(foreground))
+                        .unwrap_color();
+                    (Style::default().fg(foreground).bg(background), color)
+                }
+                false => {
+                    let style = inactive_style.unwrap_or_default();
+                    (style, style.fg.unwrap_or(Color::Reset))
+                }
+            };
+            let block = get_block(borders, title, focus, inactive_style)
+                .title_style(get_title_style(&self.props))
+                .style(style);
+            let inner = block.inner(area);
+            render.render_widget(block, area);
+            self.render_switch(render.buffer_mut(), inner, style, color);
+        }
+        render_disabled(&self.props, render.buffer_mut(), area);
+        render_tooltip(&self.props, render.buffer_mut(), area);
+    }
+
+    fn query(&self, attr: Attribute) -> Option<AttrValue> {
+        self.props.get(attr)
+    }
+
+    fn attr(&mut self, attr: Attribute, value: AttrValue) {
+        match attr {
+            Attribute::Value => self.states.on = value.unwrap_flag(),
+            attr => self.props.set(attr, value),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::One(StateValue::Bool(self.states.on))
+    }
+
+    fn perform(&mut self, cmd: Cmd) -> CmdResult {
+        if is_disabled(&self.props) {
+            return CmdResult::None;
+        }
+        match cmd {
+            Cmd::Toggle => {
+                self.states.toggle();
+                CmdResult::Changed(self.state())
+            }
+            Cmd::Submit => CmdResult::Submit(self.state()),
+            _ => CmdResult::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_components_toggle_switch() {
+        let mut component = ToggleSwitch::default()
+            .borders(Borders::default())
+            .title("Settings", Alignment::Left)
+            .label("Dark mode")
+            .on_color(Color::LightGreen)
+            .off_color(Color::DarkGray)
+            .value(true);
+        assert_eq!(component.state(), State::One(StateValue::Bool(true)));
+        assert_eq!(
+            component.perform(Cmd::Toggle),
+            CmdResult::Changed(State::One(StateValue::Bool(false)))
+        );
+        assert_eq!(
+            component.perform(Cmd::Submit),
+            CmdResult::Submit(State::One(StateValue::Bool(false)))
+        );
+        component.attr(Attribute::Value, AttrValue::Flag(true));
+        assert_eq!(component.state(), State::One(StateValue::Bool(true)));
+        assert_eq!(component.perform(Cmd::Cancel), CmdResult::None);
+    }
+
+    #[test]
+    fn test_components_toggle_switch_render() {
+        let mut component = ToggleSwitch::default()
+            .label("Wi-Fi")
+            .texts("enabled", "disabled");
+        let area = Rect::new(0, 0, 18, 1);
+        let row = |buf: &Buffer| -> String {
+            (0..area.width)
+                .map(|x| buf[(x, 0)].symbol().to_string())
+                .collect()
+        };
+        let mut buf = Buffer::empty(area);
+        component.render_switch(&mut buf, area, Style::default(), Color::Red);
+        assert_eq!(row(&buf), "Wi-Fi ○ disabled  ");
+        assert_eq!(buf[(0, 0)].fg, Color::Reset);
+        assert_eq!(buf[(6, 0)].fg, Color::Red);
+        component.perform(Cmd::Toggle);
+        let mut buf = Buffer::empty(area);
+        component.render_switch(&mut buf, area, Style::default(), Color::Green);
+        assert_eq!(row(&buf), "Wi-Fi ◉ enabled   ");
+        assert_eq!(buf[(8, 0)].fg, Color::Green);
+    }
+}
@@ -0,0 +1,289 @@
+//! ## Config
+//!
+//! `config` deserializes the styles of the components from configuration files, such as TOML, JSON or YAML,
+//! with any serde format. `Theme`, `ThemeStyle` and `KeyHint` implement `Deserialize` when the `serde` feature is
+//! enabled, while these functions can be used with `#[serde(deserialize_with = "...")]` in the configuration of
+//! the application.
+//!
+//! - colors are written as names (`"red"`, `"light-blue"`), hex (`"#ff8800"`) or indexes (`"42"`);
+//! - styles as `{ fg = "white", bg = "black", modifiers = ["bold", "italic"] }`;
+//! - borders as `{ sides = ["top", "bottom"], color = "cyan", type = "rounded" }`, where sides can also be `"all"` or
+//!   `"none"`;
+//! - border types as `"plain"`, `"rounded"`, `"double"`, `"thick"`, `"quadrant-inside"` or `"quadrant-outside"`;
+//! - alignments as `"left"`, `"center"` or `"right"`.
+
+use std::str::FromStr;
+
+use serde::de::{Deserializer, Error};
+use serde::Deserialize;
+use tuirealm::props::{Alignment, BorderSides, BorderType, Borders, Color, Style, TextModifiers};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SidesConfig {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct BordersConfig {
+    sides: Option<SidesConfig>,
+    color: Option<String>,
+    #[serde(rename = "type")]
+    border_type: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct StyleConfig {
+    fg: Option<String>,
+    bg: Option<String>,
+    modifiers: Vec<String>,
+}
+
+fn parse_color<E: Error>(s: &str) -> Result<Color, E> {
+    Color::from_str(s).map_err(|_| E::custom(format!("invalid color '{s}'")))
+}
+
+fn parse_modifier<E: Error>(s: &str) -> Result<TextModifiers, E> {
+    match s.to_lowercase().replace('-', "_").as_str() {
+        "bold" => Ok(TextModifiers::BOLD),
+        "dim" => Ok(TextModifiers::DIM),
+        "italic" => Ok(TextModifiers::ITALIC),
+        "underlined" => Ok(TextModifiers::UNDERLINED),
+        "slow_blink" => Ok(TextModifiers::SLOW_BLINK),
+        "rapid_blink" => Ok(TextModifiers::RAPID_BLINK),
+        "reversed" => Ok(TextModifiers::REVERSED),
+        "hidden" => Ok(TextModifiers::HIDDEN),
+        "crossed_out" => Ok(TextModifiers::CROSSED_OUT),
+        _ => Err(E::custom(format!("invalid text modifier '{s}'"))),
+    }
+}
+
+fn parse_border_type<E: Error>(s: &str) -> Result<BorderType, E> {
+    match s.to_lowercase().replace('_', "-").as_str() {
+        "plain" => Ok(BorderType::Plain),
+        "rounded" => Ok(BorderType::Rounded),
+        "double" => Ok(BorderType::Double),
+        "thick" => Ok(BorderType::Thick),
+        "quadrant-inside" => Ok(BorderType::QuadrantInside),
+        "quadrant-outside" => Ok(BorderType::QuadrantOutside),
+        _ => Err(E::custom(format!("invalid border type '{s}'"))),
+    }
+}
+
+fn parse_side<E: Error>(s: &str) -> Result<BorderSides, E> {
+    match s.to_lowercase().as_str() {
+        "all" => Ok(BorderSides::ALL),
+        "none" => Ok(BorderSides::NONE),
+        "top" => Ok(BorderSides::TOP),
+        "right" => Ok(BorderSides::RIGHT),
+        "bottom" => Ok(BorderSides::BOTTOM),
+        "left" => Ok(BorderSides::LEFT),
+        _ => Err(E::custom(format!("invalid border side '{s}'"))),
+    }
+}
+
+fn parse_style<E: Error>(config: StyleConfig) -> Result<Style, E> {
+    let mut style = Style::default();
+    if let Some(fg) = config.fg {
+        style = style.fg(parse_color(&fg)?);
+    }
+    if let Some(bg) = config.bg {
+        style = style.bg(parse_color(&bg)?);
+    }
+    for modifier in config.modifiers {
+        style = style.add_modifier(parse_modifier(&modifier)?);
+    }
+    Ok(style)
+}
+
+/// ### color
+///
+/// Deserialize a `Color` from its name, hex code or index
+pub fn color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    parse_color(&String::deserialize(deserializer)?)
+}
+
+/// ### opt_color
+///
+/// Deserialize an optional `Color`
+pub fn opt_color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Color>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|s| parse_color(&s))
+        .transpose()
+}
+
+/// ### style
+///
+/// Deserialize a `Style` from its foreground, background and modifiers
+pub fn style<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Style, D::Error> {
+    parse_style(StyleConfig::deserialize(deserializer)?)
+}
+
+/// ### opt_style
+///
+/// Deserialize an optional `Style`
+pub fn opt_style<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Style>, D::Error> {
+    Option::<StyleConfig>::deserialize(deserializer)?
+        .map(parse_style)
+        .transpose()
+}
+
+/// ### text_modifiers
+///
+/// Deserialize `TextModifiers` from a list of names
+pub fn text_modifiers<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<TextModifiers, D::Error> {
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .try_fold(
+            TextModifiers::empty(),
+            |acc, s| Ok(acc | parse_modifier(s)?),
+        )
+}
+
+/// ### border_type
+///
+/// Deserialize a `BorderType` from its name
+pub fn border_type<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BorderType, D::Error> {
+    parse_border_type(&String::deserialize(deserializer)?)
+}
+
+/// ### opt_border_type
+///
+/// Deserialize an optional `BorderType`
+pub fn opt_border_type<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<BorderType>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|s| parse_border_type(&s))
+        .transpose()
+}
+
+/// ### borders
+///
+/// Deserialize `Borders` from their sides, color and type. Values which aren't set are the default ones
+pub fn borders<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Borders, D::Error> {
+    let config = BordersConfig::deserialize(deserializer)?;
+    let mut borders = Borders::default();
+    match config.sides {
+        Some(SidesConfig::One(side)) => borders = borders.sides(parse_side(&side)?),
+        Some(SidesConfig::Many(sides)) => {
+            let sides = sides
+                .iter()
+                .try_fold(BorderSides::NONE, |acc, s| Ok(acc | parse_side(s)?))?;
+            borders = borders.sides(sides);
+        }
+        None => {}
+    }
+    if let Some(color) = config.color {
+        borders = borders.color(parse_color(&color)?);
+    }
+    if let Some(border_type) = config.border_type {
+        borders = borders.modifiers(parse_border_type(&border_type)?);
+    }
+    Ok(borders)
+}
+
+/// ### alignment
+///
+/// Deserialize an `Alignment` from its name
+pub fn alignment<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Alignment, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    match s.to_lowercase().as_str() {
+        "left" => Ok(Alignment::Left),
+        "center" => Ok(Alignment::Center),
+        "right" => Ok(Alignment::Right),
+        _ => Err(D::Error::custom(format!("invalid alignment '{s}'"))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::theme::{Theme, ThemeStyle};
+    use crate::KeyHint;
+
+    use pretty_assertions::assert_eq;
+
+    #[derive(Deserialize)]
+    struct Config {
+        #[serde(deserialize_with = "borders")]
+        borders: Borders,
+        #[serde(deserialize_with = "alignment")]
+        alignment: Alignment,
+        #[serde(deserialize_with = "text_modifiers")]
+        modifiers: TextModifiers,
+        hints: Vec<KeyHint>,
+    }
+
+    #[test]
+    fn test_config_deserialize() {
+        let config: Config = serde_json::from_str(
+            r#"{
+                "borders": { "sides": ["top", "bottom"], "color": "light-blue", "type": "rounded" },
+                "alignment": "center",
+                "modifiers": ["bold", "crossed-out"],
+                "hints": [{ "key": "^Q", "action": "Quit", "priority": 2 }, { "key": "^S", "action": "Save" }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.borders.sides, BorderSides::TOP | BorderSides::BOTTOM);
+        assert_eq!(config.borders.color, Color::LightBlue);
+        assert_eq!(config.borders.modifiers, BorderType::Rounded);
+        assert_eq!(config.alignment, Alignment::Center);
+        assert_eq!(
+            config.modifiers,
+            TextModifiers::BOLD | TextModifiers::CROSSED_OUT
+        );
+        assert_eq!(
+            config.hints,
+            vec![
+                KeyHint::new("^Q", "Quit").priority(2),
+                KeyHint::new("^S", "Save")
+            ]
+        );
+        // Errors
+        assert!(serde_json::from_str::<Config>(
+            r#"{ "borders": { "sides": "diagonal" }, "alignment": "left", "modifiers": [], "hints": [] }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_config_theme() {
+        let theme: Theme = serde_json::from_str(
+            r##"{
+                "palette": {
+                    "foreground": "white",
+                    "border_type": "double",
+                    "title": { "fg": "#ff8800", "modifiers": ["bold"] }
+                },
+                "components": {
+                    "list": { "highlight": "yellow", "highlight_symbol": ">> " }
+                }
+            }"##,
+        )
+        .unwrap();
+        assert_eq!(
+            theme.palette,
+            ThemeStyle::default()
+                .foreground(Color::White)
+                .border_type(BorderType::Double)
+                .title(
+                    Style::default()
+                        .fg(Color::Rgb(0xff, 0x88, 0x00))
+                        .add_modifier(TextModifiers::BOLD)
+                )
+        );
+        assert_eq!(theme.style("list").highlight, Some(Color::Yellow));
+        assert_eq!(theme.style("list").highlight_symbol.as_deref(), Some(">> "));
+        assert!(
+            serde_json::from_str::<Theme>(r#"{ "palette": { "foreground": "nope" } }"#).is_err()
+        );
+    }
+}
@@ -0,0 +1,436 @@
+//! ## KeyMap
+//!
+//! `KeyMap` maps key events to the commands of a component, so that `Component::on` can resolve the commands with
+//! `keymap.resolve(&ev)` instead of matching every key. Each component has its own default keymap, which can be
+//! overridden at runtime with `bind`, `unbind` and `extend`, or by keymaps parsed from strings such as
+//! `"ctrl+n" = "move-down"`.
+
+use std::collections::HashMap;
+
+use tuirealm::command::{Cmd, Direction, Position};
+use tuirealm::event::{Event, Key, KeyEvent, KeyModifiers};
+
+use crate::commands::{INPUT_CMD_COPY, INPUT_CMD_CUT, INPUT_CMD_PASTE, TEXTAREA_CMD_COPY};
+
+/// ## KeyMap
+///
+/// Bindings of key events to commands.
+///
+/// A key event is resolved to the command bound to the key with the same modifiers or, for keys other than
+/// characters, to the command bound to the key without modifiers; then, if typing is enabled, characters
+/// typed without modifiers, or with shift, are resolved to `Cmd::Type`. Keys bound to `Cmd::None` are ignored
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(try_from = "HashMap<String, String>")
+)]
+pub struct KeyMap {
+    bindings: HashMap<KeyEvent, Cmd>,
+    typing: bool,
+}
+
+impl KeyMap {
+    /// ### binding
+    ///
+    /// Bind `key` to `cmd`
+    pub fn binding<K: Into<KeyEvent>>(mut self, key: K, cmd: Cmd) -> Self {
+        self.bind(key, cmd);
+        self
+    }
+
+    /// ### typing
+    ///
+    /// Set whether characters which aren't bound are resolved to `Cmd::Type`
+    pub fn typing(mut self, typing: bool) -> Self {
+        self.typing = typing;
+        self
+    }
+
+    /// ### bind
+    ///
+    /// Bind `key` to `cmd`, replacing its current binding
+    pub fn bind<K: Into<KeyEvent>>(&mut self, key: K, cmd: Cmd) {
+        self.bindings.insert(key.into(), cmd);
+    }
+
+    /// ### unbind
+    ///
+    /// Remove the binding of `key`
+    pub fn unbind<K: Into<KeyEvent>>(&mut self, key: K) {
+        self.bindings.remove(&key.into());
+    }
+
+    /// ### extend
+    ///
+    /// Add the bindings of `other`, which override the current ones
+    pub fn extend(&mut self, other: KeyMap) {
+        self.bindings.extend(other.bindings);
+        self.typing |= other.typing;
+    }
+
+    /// ### get
+    ///
+    /// Get the command bound to `key`, exactly as bound
+    pub fn get<K: Into<KeyEvent>>(&self, key: K) -> Option<Cmd> {
+        self.bindings.get(&key.into()).copied()
+    }
+
+    /// ### resolve
+    ///
+    /// Resolve the command of `ev`, if it's a key event bound to a command
+    pub fn resolve<U>(&self, ev: &Event<U>) -> Option<Cmd> {
+        match ev {
+            Event::Keyboard(key) => self.resolve_key(key),
+            _ => None,
+        }
+    }
+
+    /// ### resolve_key
+    ///
+    /// Resolve the command bound to `key`
+    pub fn resolve_key(&self, key: &KeyEvent) -> Option<Cmd> {
+        let cmd = match (self.bindings.get(key), key.code) {
+            (Some(cmd), _) => Some(*cmd),
+            (None, Key::Char(ch)) => (self.typing
+                && (key.modifiers == KeyModifiers::NONE || key.modifiers == KeyModifiers::SHIFT))
+                .then_some(Cmd::Type(ch)),
+            (None, code) => self.bindings.get(&KeyEvent::from(code)).copied(),
+        };
+        cmd.filter(|cmd| *cmd != Cmd::None)
+    }
+
+    /// ### navigation
+    ///
+    /// Arrows move up and down, page up and down scroll, home and end go to the first and last item, enter submits
+    pub fn navigation() -> Self {
+        Self::default()
+            .binding(Key::Down, Cmd::Move(Direction::Down))
+            .binding(Key::Up, Cmd::Move(Direction::Up))
+            .binding(Key::PageDown, Cmd::Scroll(Direction::Down))
+            .binding(Key::PageUp, Cmd::Scroll(Direction::Up))
+            .binding(Key::Home, Cmd::GoTo(Position::Begin))
+            .binding(Key::End, Cmd::GoTo(Position::End))
+            .binding(Key::Enter, Cmd::Submit)
+    }
+
+    /// ### list
+    ///
+    /// Default keymap of `List`
+    pub fn list() -> Self {
+        Self::navigation()
+    }
+
+    /// ### table
+    ///
+    /// Default keymap of `Table`
+    pub fn table() -> Self {
+        Self::navigation()
+    }
+
+    /// ### paragraph
+    ///
+    /// Default keymap of `Paragraph`, where left and right arrows scroll horizontally
+    pub fn paragraph() -> Self {
+        Self::navigation()
+            .binding(Key::Left, Cmd::Move(Direction::Left))
+            .binding(Key::Right, Cmd::Move(Direction::Right))
+    }
+
+    /// ### textarea
+    ///
+    /// Default keymap of `Textarea`, where ctrl+c copies the text
+    pub fn textarea() -> Self {
+        Self::navigation().binding(
+            KeyEvent::new(Key::Char('c'), KeyModifiers::CONTROL),
+            Cmd::Custom(TEXTAREA_CMD_COPY),
+        )
+    }
+
+    /// ### select
+    ///
+    /// Default keymap of `Select`: arrows move, page up and down scroll, home and end go to the first and last choice,
+    /// enter submits and esc, backspace and delete close the choices
+    pub fn select() -> Self {
+        Self::default()
+            .binding(Key::Down, Cmd::Move(Direction::Down))
+            .binding(Key::Up, Cmd::Move(Direction::Up))
+            .binding(Key::PageDown, Cmd::Scroll(Direction::Down))
+            .binding(Key::PageUp, Cmd::Scroll(Direction::Up))
+            .binding(Key::Home, Cmd::GoTo(Position::Begin))
+            .binding(Key::End, Cmd::GoTo(Position::End))
+            .binding(Key::Enter, Cmd::Submit)
+            .binding(Key::Esc, Cmd::Cancel)
+            .binding(Key::Backspace, Cmd::Cancel)
+            .binding(Key::Delete, Cmd::Cancel)
+    }
+
+    /// ### input
+    ///
+    /// Default keymap of `Input`: arrows, home and end move the cursor, backspace and delete remove characters,
+    /// enter submits, ctrl+c, ctrl+x and ctrl+v copy, cut and paste, and characters are typed
+    pub fn input() -> Self {
+        Self::default()
+            .binding(Key::Left, Cmd::Move(Direction::Left))
+            .binding(Key::Right, Cmd::Move(Direction::Right))
+            .binding(Key::Home, Cmd::GoTo(Position::Begin))
+            .binding(Key::End, Cmd::GoTo(Position::End))
+            .binding(Key::Backspace, Cmd::Delete)
+            .binding(Key::Delete, Cmd::Cancel)
+            .binding(Key::Enter, Cmd::Submit)
+            .binding(
+                KeyEvent::new(Key::Char('c'), KeyModifiers::CONTROL),
+                Cmd::Custom(INPUT_CMD_COPY),
+            )
+            .binding(
+                KeyEvent::new(Key::Char('x'), KeyModifiers::CONTROL),
+                Cmd::Custom(INPUT_CMD_CUT),
+            )
+            .binding(
+                KeyEvent::new(Key::Char('v'), KeyModifiers::CONTROL),
+                Cmd::Custom(INPUT_CMD_PASTE),
+            )
+            .typing(true)
+    }
+
+    /// ### checkbox
+    ///
+    /// Default keymap of `Checkbox`: arrows move, page up and down move by a page, home and end go to the first and
+    /// last option, space toggles and enter submits
+    pub fn checkbox() -> Self {
+        Self::default()
+            .binding(Key::Left, Cmd::Move(Direction::Left))
+            .binding(Key::Right, Cmd::Move(Direction::Right))
+            .binding(Key::PageUp, Cmd::Scroll(Direction::Left))
+            .binding(Key::PageDown, Cmd::Scroll(Direction::Right))
+            .binding(Key::Home, Cmd::GoTo(Position::Begin))
+            .binding(Key::End, Cmd::GoTo(Position::End))
+            .binding(Key::Char(' '), Cmd::Toggle)
+            .binding(Key::Enter, Cmd::Submit)
+    }
+
+    /// ### radio
+    ///
+    /// Default keymap of `Radio`: arrows move and enter submits
+    pub fn radio() -> Self {
+        Self::default()
+            .binding(Key::Left, Cmd::Move(Direction::Left))
+            .binding(Key::Right, Cmd::Move(Direction::Right))
+            .binding(Key::Enter, Cmd::Submit)
+    }
+}
+
+impl TryFrom<HashMap<String, String>> for KeyMap {
+    type Error = String;
+
+    /// Parse the bindings of a keymap, as `key = command`, with `parse_key` and `parse_cmd`
+    fn try_from(bindings: HashMap<String, String>) -> Result<Self, Self::Error> {
+        let mut keymap = KeyMap::default();
+        for (key, cmd) in bindings.iter() {
+            let key = parse_key(key).ok_or_else(|| format!("invalid key '{key}'"))?;
+            let cmd = parse_cmd(cmd).ok_or_else(|| format!("invalid command '{cmd}'"))?;
+            keymap.bind(key, cmd);
+        }
+        Ok(keymap)
+    }
+}
+
+/// ### parse_key
+///
+/// Parse a key event, written as its modifiers and its key separated by `+`, such as `ctrl+shift+down`, `alt+x`,
+/// `f5` or `space`
+pub fn parse_key(s: &str) -> Option<KeyEvent> {
+    let mut parts: Vec<&str> = s.split('+').collect();
+    // `+` itself, optionally with modifiers
+    if s.ends_with("++") || s == "+" {
+        parts.truncate(parts.len() - 2);
+        parts.push("+");
+    }
+    let (key, modifiers) = parts.split_last()?;
+    let modifiers = modifiers.iter().try_fold(KeyModifiers::NONE, |acc, m| {
+        match m.to_lowercase().as_str() {
+            "ctrl" | "control" => Some(KeyModifiers::CONTROL),
+            "alt" => Some(KeyModifiers::ALT),
+            "shift" => Some(KeyModifiers::SHIFT),
+            _ => None,
+        }
+        .map(|m| acc | m)
+    })?;
+    let code = match key.to_lowercase().as_str() {
+        "backspace" => Key::Backspace,
+        "enter" => Key::Enter,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        "tab" => Key::Tab,
+        "backtab" => Key::BackTab,
+        "delete" => Key::Delete,
+        "insert" => Key::Insert,
+        "esc" => Key::Esc,
+        "space" => Key::Char(' '),
+        f if f.len() > 1 && f.starts_with('f') => Key::Function(f[1..].parse().ok()?),
+        _ => {
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => Key::Char(ch),
+                _ => return None,
+            }
+        }
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// ### parse_cmd
+///
+/// Parse a command, written as `move-<direction>`, `scroll-<direction>`, `goto-begin`, `goto-end`, `goto-<index>`,
+/// `type-<char>`, `submit`, `delete`, `cancel`, `toggle`, `change`, `tick` or `none`
+pub fn parse_cmd(s: &str) -> Option<Cmd> {
+    let direction = |d: &str| match d {
+        "down" => Some(Direction::Down),
+        "left" => Some(Direction::Left),
+        "right" => Some(Direction::Right),
+        "up" => Some(Direction::Up),
+        _ => None,
+    };
+    if let Some(ch) = s.strip_prefix("type-") {
+        let mut chars = ch.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(ch), None) => Some(Cmd::Type(ch)),
+            _ => None,
+        };
+    }
+    let s = s.to_lowercase();
+    match s.split_once('-') {
+        Some(("move", d)) => direction(d).map(Cmd::Move),
+        Some(("scroll", d)) => direction(d).map(Cmd::Scroll),
+        Some(("goto", "begin")) => Some(Cmd::GoTo(Position::Begin)),
+        Some(("goto", "end")) => Some(Cmd::GoTo(Position::End)),
+        Some(("goto", i)) => i.parse().ok().map(|i| Cmd::GoTo(Position::At(i))),
+        Some(_) => None,
+        None => match s.as_str() {
+            "submit" => Some(Cmd::Submit),
+            "delete" => Some(Cmd::Delete),
+            "cancel" => Some(Cmd::Cancel),
+            "toggle" => Some(Cmd::Toggle),
+            "change" => Some(Cmd::Change),
+            "tick" => Some(Cmd::Tick),
+            "none" => Some(Cmd::None),
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::event::NoUserEvent;
+
+    #[test]
+    fn test_keymap_resolve() {
+        let keymap = KeyMap::input();
+        let ev = |code, modifiers| Event::<NoUserEvent>::Keyboard(KeyEvent::new(code, modifiers));
+        assert_eq!(
+            keymap.resolve(&ev(Key::Left, KeyModifiers::NONE)),
+            Some(Cmd::Move(Direction::Left))
+        );
+        // Modifiers are ignored for keys bound without them
+        assert_eq!(
+            keymap.resolve(&ev(Key::Home, KeyModifiers::SHIFT)),
+            Some(Cmd::GoTo(Position::Begin))
+        );
+        // Typing
+        assert_eq!(
+            keymap.resolve(&ev(Key::Char('a'), KeyModifiers::NONE)),
+            Some(Cmd::Type('a'))
+        );
+        assert_eq!(
+            keymap.resolve(&ev(Key::Char('A'), KeyModifiers::SHIFT)),
+            Some(Cmd::Type('A'))
+        );
+        assert_eq!(
+            keymap.resolve(&ev(Key::Char('a'), KeyModifiers::CONTROL)),
+            None
+        );
+        assert_eq!(
+            keymap.resolve(&ev(Key::Char('v'), KeyModifiers::CONTROL)),
+            Some(Cmd::Custom(INPUT_CMD_PASTE))
+        );
+        assert_eq!(keymap.resolve(&ev(Key::Tab, KeyModifiers::NONE)), None);
+        assert_eq!(keymap.resolve(&Event::<NoUserEvent>::Tick), None);
+        assert_eq!(
+            KeyMap::list().resolve(&ev(Key::Char('a'), KeyModifiers::NONE)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_keymap_overrides() {
+        let mut keymap = KeyMap::list();
+        keymap.bind(
+            KeyEvent::new(Key::Char('j'), KeyModifiers::NONE),
+            Cmd::Move(Direction::Down),
+        );
+        keymap.unbind(Key::Enter);
+        assert_eq!(
+            keymap.resolve_key(&KeyEvent::from(Key::Char('j'))),
+            Some(Cmd::Move(Direction::Down))
+        );
+        assert_eq!(keymap.resolve_key(&KeyEvent::from(Key::Enter)), None);
+        // Overrides parsed from strings
+        let overrides = KeyMap::try_from(HashMap::from([
+            (String::from("ctrl+n"), String::from("move-down")),
+            (String::from("end"), String::from("none")),
+            (String::from("f2"), String::from("goto-3")),
+        ]))
+        .unwrap();
+        keymap.extend(overrides);
+        assert_eq!(
+            keymap.resolve_key(&KeyEvent::new(Key::Char('n'), KeyModifiers::CONTROL)),
+            Some(Cmd::Move(Direction::Down))
+        );
+        assert_eq!(keymap.resolve_key(&KeyEvent::from(Key::End)), None);
+        assert_eq!(
+            keymap.get(Key::Function(2)),
+            Some(Cmd::GoTo(Position::At(3)))
+        );
+        assert!(KeyMap::try_from(HashMap::from([(
+            String::from("hyper+x"),
+            String::from("submit")
+        )]))
+        .is_err());
+    }
+
+    #[test]
+    fn test_keymap_parse() {
+        assert_eq!(
+            parse_key("ctrl+shift+down"),
+            Some(KeyEvent::new(
+                Key::Down,
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            ))
+        );
+        assert_eq!(parse_key("space"), Some(KeyEvent::from(Key::Char(' '))));
+        assert_eq!(parse_key("F12"), Some(KeyEvent::from(Key::Function(12))));
+        assert_eq!(parse_key("f"), Some(KeyEvent::from(Key::Char('f'))));
+        assert_eq!(
+            parse_key("alt++"),
+            Some(KeyEvent::new(Key::Char('+'), KeyModifiers::ALT))
+        );
+        assert_eq!(parse_key("+"), Some(KeyEvent::from(Key::Char('+'))));
+        assert_eq!(parse_key("ctrl+xy"), None);
+        assert_eq!(parse_cmd("scroll-up"), Some(Cmd::Scroll(Direction::Up)));
+        assert_eq!(parse_cmd("goto-begin"), Some(Cmd::GoTo(Position::Begin)));
+        assert_eq!(parse_cmd("type-X"), Some(Cmd::Type('X')));
+        assert_eq!(parse_cmd("Toggle"), Some(Cmd::Toggle));
+        assert_eq!(parse_cmd("move-sideways"), None);
+        assert_eq!(parse_cmd("explode"), None);
+    }
+}
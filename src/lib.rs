@@ -9,7 +9,7 @@
 //!
 //! ```toml
 //! tuirealm = "2"
-//! tui-realm-stdlib = "2"
+//! tui-realm-stdlib = "3"
 //! ```
 //!
 
@@ -21,7 +21,21 @@
     html_logo_url = "https://raw.githubusercontent.com/veeso/tui-realm-stdlib/main/docs/images/cargo/tui-realm-512.png"
 )]
 
+pub mod animation;
+pub mod clipboard;
 mod components;
+#[cfg(feature = "serde")]
+pub mod config;
+pub mod keymap;
+mod macros;
+pub mod prelude;
+pub mod testing;
+pub mod theme;
 pub mod utils;
+pub use components::commands;
 pub use components::props;
 pub use components::*;
+
+// the versions of tuirealm and ratatui the components are built with
+pub use tuirealm;
+pub use tuirealm::ratatui;
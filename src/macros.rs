@@ -0,0 +1,321 @@
+//! ## Macros
+//!
+//! `macros` generates the boilerplate of custom components written like the ones of the library: the builders
+//! setting their properties, and the `query` and `attr` methods of `MockComponent` storing them in `Props`.
+
+/// ## component_builders
+///
+/// Generate the builders of a component, which must implement `MockComponent`.
+///
+/// The builders of the common properties are named as in the components of the library: `foreground`, `background`,
+/// `inactive`, `disabled`, `tooltip`, `borders`, `title`, `modifiers`, `alignment`, `highlighted_color`,
+/// `highlighted_str`, `step` and `rewind`; `theme("name")` generates `theme` and `apply_theme`, applying the styles
+/// of the theme for `name`. Other properties are written as `name(Type) => Variant(attribute)`, where `Variant` is the
+/// `AttrValue` holding the value.
+///
+/// ```
+/// use tui_realm_stdlib::{component_builders, component_props};
+/// use tuirealm::command::{Cmd, CmdResult};
+/// use tuirealm::props::{Attribute, Color, Props};
+/// use tuirealm::ratatui::layout::Rect;
+/// use tuirealm::{Frame, MockComponent, State};
+///
+/// #[derive(Default)]
+/// pub struct Gauge {
+///     props: Props,
+/// }
+///
+/// component_builders! {
+///     Gauge {
+///         foreground,
+///         borders,
+///         title,
+///         theme("gauge"),
+///         percent(isize) => Number(Attribute::Custom("percent")),
+///     }
+/// }
+///
+/// impl MockComponent for Gauge {
+///     component_props!(props);
+///
+///     fn view(&mut self, _frame: &mut Frame, _area: Rect) {}
+///
+///     fn state(&self) -> State {
+///         State::None
+///     }
+///
+///     fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+///         CmdResult::None
+///     }
+/// }
+///
+/// let gauge = Gauge::default().foreground(Color::Red).percent(50);
+/// ```
+#[macro_export]
+macro_rules! component_builders {
+    (@builders) => {};
+    (@builders , $($rest:tt)*) => {
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders foreground $($rest:tt)*) => {
+        pub fn foreground(mut self, fg: ::tuirealm::props::Color) -> Self {
+            $crate::component_builders!(@set self, Foreground, Color(fg))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders background $($rest:tt)*) => {
+        pub fn background(mut self, bg: ::tuirealm::props::Color) -> Self {
+            $crate::component_builders!(@set self, Background, Color(bg))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders inactive $($rest:tt)*) => {
+        pub fn inactive(mut self, s: ::tuirealm::props::Style) -> Self {
+            $crate::component_builders!(@set self, FocusStyle, Style(s))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders disabled $($rest:tt)*) => {
+        pub fn disabled(mut self, disabled: bool) -> Self {
+            $crate::component_builders!(@set self, Disabled, Flag(disabled))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders tooltip $($rest:tt)*) => {
+        pub fn tooltip<S: Into<String>>(mut self, text: S) -> Self {
+            $crate::component_builders!(@set self, Custom($crate::props::TOOLTIP), String(text.into()))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders borders $($rest:tt)*) => {
+        pub fn borders(mut self, b: ::tuirealm::props::Borders) -> Self {
+            $crate::component_builders!(@set self, Borders, Borders(b))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders title $($rest:tt)*) => {
+        pub fn title<S: Into<String>>(mut self, t: S, a: ::tuirealm::props::Alignment) -> Self {
+            $crate::component_builders!(@set self, Title, Title((t.into(), a)))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders modifiers $($rest:tt)*) => {
+        pub fn modifiers(mut self, m: ::tuirealm::props::TextModifiers) -> Self {
+            $crate::component_builders!(@set self, TextProps, TextModifiers(m))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders alignment $($rest:tt)*) => {
+        pub fn alignment(mut self, alignment: ::tuirealm::props::Alignment) -> Self {
+            $crate::component_builders!(@set self, Alignment, Alignment(alignment))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders highlighted_color $($rest:tt)*) => {
+        pub fn highlighted_color(mut self, c: ::tuirealm::props::Color) -> Self {
+            $crate::component_builders!(@set self, HighlightedColor, Color(c))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders highlighted_str $($rest:tt)*) => {
+        pub fn highlighted_str<S: Into<String>>(mut self, s: S) -> Self {
+            $crate::component_builders!(@set self, HighlightedStr, String(s.into()))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders step $($rest:tt)*) => {
+        pub fn step(mut self, step: usize) -> Self {
+            $crate::component_builders!(@set self, ScrollStep, Length(step))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders rewind $($rest:tt)*) => {
+        pub fn rewind(mut self, r: bool) -> Self {
+            $crate::component_builders!(@set self, Rewind, Flag(r))
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders theme($name:literal) $($rest:tt)*) => {
+        pub fn theme(mut self, theme: &$crate::theme::Theme) -> Self {
+            self.apply_theme(theme);
+            self
+        }
+
+        #[doc = concat!("Apply the styles of `theme` for `", $name, "` to the component")]
+        pub fn apply_theme(&mut self, theme: &$crate::theme::Theme) {
+            theme.apply($name, self);
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@builders $name:ident($ty:ty) => $variant:ident($attr:expr) $($rest:tt)*) => {
+        pub fn $name(mut self, value: $ty) -> Self {
+            ::tuirealm::MockComponent::attr(
+                &mut self,
+                $attr,
+                ::tuirealm::props::AttrValue::$variant(value),
+            );
+            self
+        }
+
+        $crate::component_builders!(@builders $($rest)*);
+    };
+    (@set $self:ident, $attr:ident $(($key:expr))?, $variant:ident($value:expr)) => {{
+        ::tuirealm::MockComponent::attr(
+            &mut $self,
+            ::tuirealm::props::Attribute::$attr $(($key))?,
+            ::tuirealm::props::AttrValue::$variant($value),
+        );
+        $self
+    }};
+    ($component:ty { $($builders:tt)* }) => {
+        impl $component {
+            $crate::component_builders!(@builders $($builders)*);
+        }
+    };
+}
+
+/// ## component_props
+///
+/// Generate the `query` and `attr` methods of `MockComponent`, storing the properties in the given `Props` field.
+/// To be used in the implementation of `MockComponent`, as shown in `component_builders`
+#[macro_export]
+macro_rules! component_props {
+    ($props:ident) => {
+        fn query(
+            &self,
+            attr: ::tuirealm::props::Attribute,
+        ) -> Option<::tuirealm::props::AttrValue> {
+            self.$props.get(attr)
+        }
+
+        fn attr(
+            &mut self,
+            attr: ::tuirealm::props::Attribute,
+            value: ::tuirealm::props::AttrValue,
+        ) {
+            self.$props.set(attr, value);
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::props::TOOLTIP;
+    use crate::theme::Theme;
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::command::{Cmd, CmdResult};
+    use tuirealm::props::{
+        Alignment, AttrValue, Attribute, Borders, Color, Props, Style, TextModifiers,
+    };
+    use tuirealm::ratatui::layout::Rect;
+    use tuirealm::{Frame, MockComponent, State};
+
+    #[derive(Default)]
+    struct Gauge {
+        props: Props,
+    }
+
+    component_builders! {
+        Gauge {
+            foreground,
+            background,
+            inactive,
+            disabled,
+            tooltip,
+            borders,
+            title,
+            modifiers,
+            alignment,
+            highlighted_color,
+            highlighted_str,
+            step,
+            rewind,
+            theme("gauge"),
+            percent(isize) => Number(Attribute::Custom("percent")),
+            label(String) => String(Attribute::Text)
+        }
+    }
+
+    impl MockComponent for Gauge {
+        component_props!(props);
+
+        fn view(&mut self, _frame: &mut Frame, _area: Rect) {}
+
+        fn state(&self) -> State {
+            State::None
+        }
+
+        fn perform(&mut self, _cmd: Cmd) -> CmdResult {
+            CmdResult::None
+        }
+    }
+
+    #[test]
+    fn test_macros_component_builders() {
+        let component = Gauge::default()
+            .foreground(Color::Red)
+            .background(Color::Blue)
+            .inactive(Style::default())
+            .disabled(true)
+            .tooltip("tip")
+            .borders(Borders::default())
+            .title("gauge", Alignment::Center)
+            .modifiers(TextModifiers::BOLD)
+            .alignment(Alignment::Right)
+            .highlighted_color(Color::Yellow)
+            .highlighted_str(">")
+            .step(4)
+            .rewind(true)
+            .percent(50)
+            .label(String::from("50%"));
+        assert_eq!(
+            component.query(Attribute::Foreground),
+            Some(AttrValue::Color(Color::Red))
+        );
+        assert_eq!(
+            component.query(Attribute::Custom(TOOLTIP)),
+            Some(AttrValue::String(String::from("tip")))
+        );
+        assert_eq!(
+            component.query(Attribute::Title),
+            Some(AttrValue::Title((String::from("gauge"), Alignment::Center)))
+        );
+        assert_eq!(
+            component.query(Attribute::ScrollStep),
+            Some(AttrValue::Length(4))
+        );
+        assert_eq!(
+            component.query(Attribute::Rewind),
+            Some(AttrValue::Flag(true))
+        );
+        assert_eq!(
+            component.query(Attribute::Custom("percent")),
+            Some(AttrValue::Number(50))
+        );
+        assert_eq!(
+            component.query(Attribute::Text),
+            Some(AttrValue::String(String::from("50%")))
+        );
+        let component = component.theme(&Theme::dark());
+        assert_eq!(
+            component.query(Attribute::Foreground),
+            Some(AttrValue::Color(Color::White))
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! ## Prelude
+//!
+//! `prelude` re-exports the components of the library together with the types their builders expect, so they can be
+//! used with a single import:
+//!
+//! ```
+//! use tui_realm_stdlib::prelude::*;
+//!
+//! let label = Label::default()
+//!     .foreground(Color::Yellow)
+//!     .modifiers(TextModifiers::BOLD)
+//!     .alignment(Alignment::Center)
+//!     .text("Hello, world!");
+//! ```
+//!
+//! The `Table` of `tuirealm::props` isn't re-exported, since it would shadow the `Table` component: it's built with
+//! `TableBuilder`.
+//!
+//! The types come from the `tuirealm` and `ratatui` versions the library is built with, also re-exported at the root
+//! of the crate: using them instead of depending on `ratatui` directly avoids the mismatches between two versions of
+//! the same type, e.g. `expected ratatui::style::Color, found a different ratatui::style::Color`.
+
+pub use crate::components::*;
+pub use crate::theme::Theme;
+pub use crate::{ratatui, tuirealm};
+
+pub use tuirealm::command::{Cmd, CmdResult, Direction, Position};
+pub use tuirealm::props::{
+    Alignment, AttrValue, Attribute, BorderSides, BorderType, Borders, Color, Dataset, InputType,
+    PropPayload, PropValue, Shape, Style, TableBuilder, TextModifiers, TextSpan,
+};
+pub use tuirealm::{MockComponent, State, StateValue};
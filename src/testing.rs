@@ -0,0 +1,454 @@
+//! ## Testing
+//!
+//! `testing` provides helpers to write golden tests for components: a component is rendered into a `Buffer` of a
+//! given size, whose text and styles are then compared against grids of strings.
+//!
+//! Interactions are tested by playing a `Script` of commands, or a sequence of events with `play_events`, which
+//! record the result and the state of the component after each step. Scripts can be parsed from text, so the steps
+//! to reproduce a bug can be pasted into a test.
+//!
+//! ```
+//! use tui_realm_stdlib::testing::{assert_lines, assert_styles, render};
+//! use tui_realm_stdlib::Label;
+//! use tuirealm::props::{Color, Style};
+//!
+//! let mut label = Label::default().text("hello").foreground(Color::Red);
+//! let buf = render(&mut label, 8, 1);
+//! assert_lines(&buf, &["hello"]);
+//! assert_styles(&buf, &["rrrrr"], &[('r', Style::default().fg(Color::Red))]);
+//! ```
+
+use tuirealm::command::{Cmd, CmdResult};
+use tuirealm::event::Event;
+use tuirealm::props::{Color, Style};
+use tuirealm::ratatui::backend::TestBackend;
+use tuirealm::ratatui::buffer::Buffer;
+use tuirealm::ratatui::layout::Rect;
+use tuirealm::ratatui::Terminal;
+use tuirealm::{Component, MockComponent, State};
+use unicode_width::UnicodeWidthStr;
+
+use crate::keymap::{parse_cmd, parse_key};
+
+/// ### render
+///
+/// Render `component` in an area of `width` x `height` cells and get the rendered buffer
+pub fn render(component: &mut dyn MockComponent, width: u16, height: u16) -> Buffer {
+    let mut terminal =
+        Terminal::new(TestBackend::new(width, height)).expect("failed to create test terminal");
+    terminal
+        .draw(|f| component.view(f, Rect::new(0, 0, width, height)))
+        .expect("failed to render component");
+    terminal.backend().buffer().clone()
+}
+
+/// ### lines
+///
+/// Get the text of each line of `buf`, without trailing whitespace.
+/// The cells covered by wide characters are skipped, so each line reads as it's displayed
+pub fn lines(buf: &Buffer) -> Vec<String> {
+    (buf.area.top()..buf.area.bottom())
+        .map(|y| {
+            let mut line = String::new();
+            let mut skip = 0;
+            for x in buf.area.left()..buf.area.right() {
+                if skip > 0 {
+                    skip -= 1;
+                    continue;
+                }
+                let symbol = buf[(x, y)].symbol();
+                skip = symbol.width().saturating_sub(1);
+                line.push_str(symbol);
+            }
+            line.trim_end().to_string()
+        })
+        .collect()
+}
+
+/// ### assert_lines
+///
+/// Assert that the lines of `buf` are `expected`. Trailing whitespace is ignored
+///
+/// # Panics
+///
+/// Panics if the lines differ, showing both grids
+pub fn assert_lines(buf: &Buffer, expected: &[&str]) {
+    let expected: Vec<String> = expected.iter().map(|x| x.trim_end().to_string()).collect();
+    let actual = lines(buf);
+    assert!(
+        actual == expected,
+        "rendered lines differ\n\nexpected:\n{}\n\nactual:\n{}\n",
+        grid(&expected),
+        grid(&actual)
+    );
+}
+
+/// ### assert_styles
+///
+/// Assert the styles of the cells of `buf`: each character of `expected` is looked up in `legend`, and the style of the
+/// cell at its position must have the same foreground, background and modifiers, where unset colors are `Color::Reset`.
+/// Characters which aren't in the legend, and cells past the end of the lines, aren't checked.
+/// Each character is a cell, so wide characters take two of them
+///
+/// # Panics
+///
+/// Panics if the style of a cell differs
+pub fn assert_styles(buf: &Buffer, expected: &[&str], legend: &[(char, Style)]) {
+    for (y, row) in expected.iter().enumerate() {
+        for (x, ch) in row.chars().enumerate() {
+            let Some((_, style)) = legend.iter().find(|(c, _)| *c == ch) else {
+                continue;
+            };
+            let (x, y) = (buf.area.x + x as u16, buf.area.y + y as u16);
+            let cell = &buf[(x, y)];
+            let expected = (
+                style.fg.unwrap_or(Color::Reset),
+                style.bg.unwrap_or(Color::Reset),
+                style.add_modifier,
+            );
+            assert!(
+                (cell.fg, cell.bg, cell.modifier) == expected,
+                "style of cell ({x}, {y}) '{}' differs: expected '{ch}' {:?}, got {:?}",
+                cell.symbol(),
+                expected,
+                (cell.fg, cell.bg, cell.modifier)
+            );
+        }
+    }
+}
+
+/// ## Record
+///
+/// A command performed by a `Script`, with its result and the state of the component after it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub cmd: Cmd,
+    pub result: CmdResult,
+    pub state: State,
+}
+
+/// ## Script
+///
+/// A sequence of commands to perform on a component
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Script {
+    cmds: Vec<Cmd>,
+}
+
+impl Script {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ### cmd
+    ///
+    /// Add a step performing `cmd`
+    pub fn cmd(mut self, cmd: Cmd) -> Self {
+        self.cmds.push(cmd);
+        self
+    }
+
+    /// ### typing
+    ///
+    /// Add a `Cmd::Type` step for each character of `text`
+    pub fn typing(mut self, text: &str) -> Self {
+        self.cmds.extend(text.chars().map(Cmd::Type));
+        self
+    }
+
+    /// ### parse
+    ///
+    /// Parse a script with a command on each line, written as in `keymap::parse_cmd`, or `type <text>` to type the
+    /// rest of the line. Empty lines and lines starting with `#` are skipped
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut script = Self::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            script = match line.strip_prefix("type ") {
+                Some(text) => script.typing(text),
+                None => script.cmd(
+                    parse_cmd(line)
+                        .ok_or_else(|| format!("invalid command '{line}' at line {}", i + 1))?,
+                ),
+            };
+        }
+        Ok(script)
+    }
+
+    /// ### cmds
+    ///
+    /// Get the commands of the script
+    pub fn cmds(&self) -> &[Cmd] {
+        &self.cmds
+    }
+
+    /// ### play
+    ///
+    /// Perform the commands on `component`, recording each step
+    pub fn play(&self, component: &mut dyn MockComponent) -> Vec<Record> {
+        self.cmds
+            .iter()
+            .map(|cmd| {
+                let result = component.perform(*cmd);
+                Record {
+                    cmd: *cmd,
+                    result,
+                    state: component.state(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// ## EventRecord
+///
+/// An event handled by a component, with the message it returned and its state after it
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventRecord<Msg, UserEvent> {
+    pub event: Event<UserEvent>,
+    pub msg: Option<Msg>,
+    pub state: State,
+}
+
+/// ### play_events
+///
+/// Send `events` to `component`, recording each step
+pub fn play_events<Msg, UserEvent, C>(
+    component: &mut C,
+    events: impl IntoIterator<Item = Event<UserEvent>>,
+) -> Vec<EventRecord<Msg, UserEvent>>
+where
+    C: Component<Msg, UserEvent>,
+    Msg: PartialEq,
+    UserEvent: Eq + PartialEq + Clone + PartialOrd,
+{
+    events
+        .into_iter()
+        .map(|event| {
+            let msg = component.on(event.clone());
+            EventRecord {
+                event,
+                msg,
+                state: component.state(),
+            }
+        })
+        .collect()
+}
+
+/// ### parse_keys
+///
+/// Parse a sequence of key events separated by whitespace, written as in `keymap::parse_key`, where `tick` is a
+/// tick event
+pub fn parse_keys<UserEvent>(s: &str) -> Result<Vec<Event<UserEvent>>, String> {
+    s.split_whitespace()
+        .map(|key| match key {
+            "tick" => Ok(Event::Tick),
+            _ => parse_key(key)
+                .map(Event::Keyboard)
+                .ok_or_else(|| format!("invalid key '{key}'")),
+        })
+        .collect()
+}
+
+fn grid(lines: &[String]) -> String {
+    lines
+        .iter()
+        .map(|x| format!("|{x}|"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::components::{Input, Label, Paragraph, Select};
+    use crate::keymap::KeyMap;
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::command::Direction;
+    use tuirealm::event::{Key, KeyEvent, KeyModifiers, NoUserEvent};
+    use tuirealm::props::{Alignment, AttrValue, Attribute, Borders, TextModifiers, TextSpan};
+    use tuirealm::{Frame, StateValue};
+
+    struct TextInput {
+        component: Input,
+        keymap: KeyMap,
+    }
+
+    impl MockComponent for TextInput {
+        fn view(&mut self, frame: &mut Frame, area: Rect) {
+            self.component.view(frame, area);
+        }
+
+        fn query(&self, attr: Attribute) -> Option<AttrValue> {
+            self.component.query(attr)
+        }
+
+        fn attr(&mut self, attr: Attribute, value: AttrValue) {
+            self.component.attr(attr, value);
+        }
+
+        fn state(&self) -> State {
+            self.component.state()
+        }
+
+        fn perform(&mut self, cmd: Cmd) -> CmdResult {
+            self.component.perform(cmd)
+        }
+    }
+
+    impl Component<String, NoUserEvent> for TextInput {
+        fn on(&mut self, ev: Event<NoUserEvent>) -> Option<String> {
+            match self.keymap.resolve(&ev).map(|cmd| self.perform(cmd)) {
+                Some(CmdResult::Submit(State::One(StateValue::String(s)))) => Some(s),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_testing_render() {
+        let mut component = Label::default()
+            .text("日本 text")
+            .alignment(Alignment::Right)
+            .foreground(Color::Yellow)
+            .modifiers(TextModifiers::BOLD);
+        let buf = render(&mut component, 12, 2);
+        assert_eq!(buf.area, Rect::new(0, 0, 12, 2));
+        assert_eq!(
+            lines(&buf),
+            vec![String::from("   日本 text"), String::new()]
+        );
+        assert_lines(&buf, &["   日本 text  ", ""]);
+        let bold = Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(TextModifiers::BOLD);
+        // The cells covered by wide characters aren't styled
+        assert_styles(
+            &buf,
+            &["bbbb_b_bbbbb", "bbbbbbbbbbbb"],
+            &[('b', bold), ('_', Style::default())],
+        );
+    }
+
+    #[test]
+    fn test_testing_borders() {
+        let mut component = Paragraph::default()
+            .borders(Borders::default())
+            .text(&[TextSpan::from("hi")]);
+        let buf = render(&mut component, 6, 3);
+        assert_lines(&buf, &["┌────┐", "│hi  │", "└────┘"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "rendered lines differ")]
+    fn test_testing_assert_lines_fails() {
+        let buf = render(&mut Label::default().text("hello"), 8, 1);
+        assert_lines(&buf, &["world"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "style of cell (0, 0)")]
+    fn test_testing_assert_styles_fails() {
+        let buf = render(&mut Label::default().text("hello"), 8, 1);
+        assert_styles(&buf, &["r"], &[('r', Style::default().fg(Color::Red))]);
+    }
+
+    #[test]
+    fn test_testing_script() {
+        let script = Script::parse(
+            "
+            # Type and fix a typo
+            type helo
+            move-left
+            type-l
+            submit
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            script,
+            Script::new()
+                .typing("helo")
+                .cmd(Cmd::Move(Direction::Left))
+                .cmd(Cmd::Type('l'))
+                .cmd(Cmd::Submit)
+        );
+        assert_eq!(script.cmds().len(), 7);
+        let mut component = Input::default();
+        let records = script.play(&mut component);
+        let hello = State::One(StateValue::String(String::from("hello")));
+        assert_eq!(
+            records.last(),
+            Some(&Record {
+                cmd: Cmd::Submit,
+                result: CmdResult::Submit(hello.clone()),
+                state: hello,
+            })
+        );
+        assert_eq!(records[4].result, CmdResult::None);
+        assert_eq!(
+            Script::parse("submit\nfly-away"),
+            Err(String::from("invalid command 'fly-away' at line 2"))
+        );
+    }
+
+    #[test]
+    fn test_testing_script_select() {
+        let mut component = Select::default().choices(&["a", "b", "c"]);
+        let records = Script::parse("submit\nmove-down\nmove-down\nsubmit")
+            .unwrap()
+            .play(&mut component);
+        // The state is only available when the choices are closed
+        let states: Vec<State> = records.iter().map(|x| x.state.clone()).collect();
+        assert_eq!(
+            states,
+            vec![
+                State::None,
+                State::None,
+                State::None,
+                State::One(StateValue::Usize(2))
+            ]
+        );
+        let results: Vec<CmdResult> = records.into_iter().map(|x| x.result).collect();
+        assert_eq!(
+            results,
+            vec![
+                CmdResult::None,
+                CmdResult::Changed(State::One(StateValue::Usize(1))),
+                CmdResult::Changed(State::One(StateValue::Usize(2))),
+                CmdResult::Submit(State::One(StateValue::Usize(2))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_testing_play_events() {
+        let mut component = TextInput {
+            component: Input::default(),
+            keymap: KeyMap::input(),
+        };
+        let events = parse_keys::<NoUserEvent>("h i left shift+I tick enter").unwrap();
+        assert_eq!(
+            events[3],
+            Event::Keyboard(KeyEvent::new(Key::Char('I'), KeyModifiers::SHIFT))
+        );
+        let records = play_events(&mut component, events);
+        assert_eq!(records.len(), 6);
+        assert_eq!(
+            records[3].state,
+            State::One(StateValue::String(String::from("hIi")))
+        );
+        assert_eq!(
+            records[5].event,
+            Event::Keyboard(KeyEvent::from(Key::Enter))
+        );
+        assert_eq!(records[5].msg.as_deref(), Some("hIi"));
+        assert!(records[..5].iter().all(|x| x.msg.is_none()));
+        assert!(parse_keys::<NoUserEvent>("ctrl+nope").is_err());
+    }
+}
@@ -0,0 +1,319 @@
+//! ## Theme
+//!
+//! `Theme` keeps the styles of the components in one place: a palette, applied to every component, and the style
+//! overrides of each kind of component
+
+use std::collections::HashMap;
+
+use tuirealm::props::{AttrValue, Attribute, BorderType, Color, Style, TextModifiers};
+use tuirealm::MockComponent;
+
+use crate::props::TITLE_STYLE;
+
+/// ## ThemeStyle
+///
+/// The styles a theme sets on a component. Styles which aren't set are left unchanged
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default))]
+pub struct ThemeStyle {
+    /// Background color
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::config::opt_color")
+    )]
+    pub background: Option<Color>,
+    /// Color of the borders
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::config::opt_color")
+    )]
+    pub border_color: Option<Color>,
+    /// Type of the borders
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::config::opt_border_type")
+    )]
+    pub border_type: Option<BorderType>,
+    /// Foreground color
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::config::opt_color")
+    )]
+    pub foreground: Option<Color>,
+    /// Color of highlighted items
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::config::opt_color")
+    )]
+    pub highlight: Option<Color>,
+    /// Symbol displayed before highlighted items
+    pub highlight_symbol: Option<String>,
+    /// Style of the component when not focused
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::config::opt_style")
+    )]
+    pub inactive: Option<Style>,
+    /// Style of the title of the block
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::config::opt_style")
+    )]
+    pub title: Option<Style>,
+}
+
+impl ThemeStyle {
+    pub fn background(mut self, bg: Color) -> Self {
+        self.background = Some(bg);
+        self
+    }
+
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    pub fn border_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = Some(border_type);
+        self
+    }
+
+    pub fn foreground(mut self, fg: Color) -> Self {
+        self.foreground = Some(fg);
+        self
+    }
+
+    pub fn highlight(mut self, color: Color) -> Self {
+        self.highlight = Some(color);
+        self
+    }
+
+    pub fn highlight_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.highlight_symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn inactive(mut self, s: Style) -> Self {
+        self.inactive = Some(s);
+        self
+    }
+
+    pub fn title(mut self, s: Style) -> Self {
+        self.title = Some(s);
+        self
+    }
+
+    /// ### or
+    ///
+    /// Get the styles of `self`, using the ones of `other` where not set
+    pub fn or(&self, other: &ThemeStyle) -> ThemeStyle {
+        ThemeStyle {
+            background: self.background.or(other.background),
+            border_color: self.border_color.or(other.border_color),
+            border_type: self.border_type.or(other.border_type),
+            foreground: self.foreground.or(other.foreground),
+            highlight: self.highlight.or(other.highlight),
+            highlight_symbol: self
+                .highlight_symbol
+                .clone()
+                .or_else(|| other.highlight_symbol.clone()),
+            inactive: self.inactive.or(other.inactive),
+            title: self.title.or(other.title),
+        }
+    }
+}
+
+/// ## Theme
+///
+/// A theme for the components: the palette is applied to every component, while the styles of a kind of component,
+/// named after its module (e.g. `list` or `bar_chart`), override the palette for it.
+/// Themes are applied with the `theme` builder, or the `apply_theme` method, of the components
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default))]
+pub struct Theme {
+    /// Styles of every component
+    pub palette: ThemeStyle,
+    /// Styles of each kind of component, by name
+    pub components: HashMap<String, ThemeStyle>,
+}
+
+impl Theme {
+    pub fn new(palette: ThemeStyle) -> Self {
+        Self {
+            palette,
+            components: HashMap::new(),
+        }
+    }
+
+    /// Override the palette for the kind of component `name`
+    pub fn component<S: Into<String>>(mut self, name: S, style: ThemeStyle) -> Self {
+        self.components.insert(name.into(), style);
+        self
+    }
+
+    /// ### style
+    ///
+    /// Get the styles of the kind of component `name`
+    pub fn style(&self, name: &str) -> ThemeStyle {
+        match self.components.get(name) {
+            Some(style) => style.or(&self.palette),
+            None => self.palette.clone(),
+        }
+    }
+
+    /// ### apply
+    ///
+    /// Set the styles of the kind of component `name` on `component`. The sides of its borders are kept
+    pub fn apply(&self, name: &str, component: &mut dyn MockComponent) {
+        let style = self.style(name);
+        if style.border_color.is_some() || style.border_type.is_some() {
+            let mut borders = component
+                .query(Attribute::Borders)
+                .map(|x| x.unwrap_borders())
+                .unwrap_or_default();
+            if let Some(color) = style.border_color {
+                borders = borders.color(color);
+            }
+            if let Some(border_type) = style.border_type {
+                borders = borders.modifiers(border_type);
+            }
+            component.attr(Attribute::Borders, AttrValue::Borders(borders));
+        }
+        if let Some(symbol) = style.highlight_symbol {
+            component.attr(Attribute::HighlightedStr, AttrValue::String(symbol));
+        }
+        let attrs = [
+            (
+                Attribute::Background,
+                style.background.map(AttrValue::Color),
+            ),
+            (
+                Attribute::Foreground,
+                style.foreground.map(AttrValue::Color),
+            ),
+            (
+                Attribute::HighlightedColor,
+                style.highlight.map(AttrValue::Color),
+            ),
+            (Attribute::FocusStyle, style.inactive.map(AttrValue::Style)),
+            (
+                Attribute::Custom(TITLE_STYLE),
+                style.title.map(AttrValue::Style),
+            ),
+        ];
+        for (attr, value) in attrs {
+            if let Some(value) = value {
+                component.attr(attr, value);
+            }
+        }
+    }
+
+    /// ### dark
+    ///
+    /// A theme for terminals with a dark background
+    pub fn dark() -> Self {
+        Self::new(
+            ThemeStyle::default()
+                .foreground(Color::White)
+                .background(Color::Reset)
+                .border_color(Color::Cyan)
+                .border_type(BorderType::Rounded)
+                .highlight(Color::LightCyan)
+                .inactive(Style::default().fg(Color::DarkGray))
+                .title(
+                    Style::default()
+                        .fg(Color::LightCyan)
+                        .add_modifier(TextModifiers::BOLD),
+                ),
+        )
+        .component(
+            "input",
+            ThemeStyle::default().border_color(Color::LightYellow),
+        )
+        .component(
+            "progress_bar",
+            ThemeStyle::default().foreground(Color::LightGreen),
+        )
+    }
+
+    /// ### light
+    ///
+    /// A theme for terminals with a light background
+    pub fn light() -> Self {
+        Self::new(
+            ThemeStyle::default()
+                .foreground(Color::Black)
+                .background(Color::Reset)
+                .border_color(Color::Blue)
+                .border_type(BorderType::Plain)
+                .highlight(Color::Blue)
+                .inactive(Style::default().fg(Color::Gray))
+                .title(
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(TextModifiers::BOLD),
+                ),
+        )
+        .component("input", ThemeStyle::default().border_color(Color::Magenta))
+        .component(
+            "progress_bar",
+            ThemeStyle::default().foreground(Color::Green),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::components::Label;
+
+    use pretty_assertions::assert_eq;
+    use tuirealm::props::{BorderSides, Borders};
+
+    #[test]
+    fn test_theme_style() {
+        let theme = Theme::new(
+            ThemeStyle::default()
+                .foreground(Color::White)
+                .border_color(Color::Cyan),
+        )
+        .component("list", ThemeStyle::default().foreground(Color::Yellow));
+        assert_eq!(
+            theme.style("list"),
+            ThemeStyle::default()
+                .foreground(Color::Yellow)
+                .border_color(Color::Cyan)
+        );
+        assert_eq!(theme.style("label"), theme.palette);
+    }
+
+    #[test]
+    fn test_theme_apply() {
+        let mut component = Label::default();
+        component.attr(
+            Attribute::Borders,
+            AttrValue::Borders(Borders::default().sides(BorderSides::TOP)),
+        );
+        Theme::dark().apply("label", &mut component);
+        assert_eq!(
+            component.query(Attribute::Foreground),
+            Some(AttrValue::Color(Color::White))
+        );
+        let borders = component
+            .query(Attribute::Borders)
+            .unwrap()
+            .unwrap_borders();
+        assert_eq!(borders.sides, BorderSides::TOP);
+        assert_eq!(borders.color, Color::Cyan);
+        assert_eq!(borders.modifiers, BorderType::Rounded);
+        assert!(component.query(Attribute::Custom(TITLE_STYLE)).is_some());
+        // Unset styles are kept
+        let mut component = Label::default().foreground(Color::Red);
+        Theme::default().apply("label", &mut component);
+        assert_eq!(
+            component.query(Attribute::Foreground),
+            Some(AttrValue::Color(Color::Red))
+        );
+    }
+}
@@ -5,16 +5,36 @@
 // deps
 extern crate textwrap;
 extern crate unicode_width;
+use std::str::FromStr;
+use std::time::Duration;
+
 // local
-use tuirealm::props::{Alignment, AttrValue, Attribute, Borders, TextModifiers, TextSpan};
+use tuirealm::command::Direction;
+use tuirealm::event::{MouseButton, MouseEvent, MouseEventKind};
+use tuirealm::props::{
+    Alignment, AttrValue, Attribute, BorderSides, BorderType, Borders, PropPayload, PropValue,
+    Table, TextModifiers, TextSpan,
+};
 use tuirealm::Props;
 // ext
+use tuirealm::ratatui::buffer::Buffer;
+use tuirealm::ratatui::layout::{Position, Rect};
 use tuirealm::ratatui::style::{Color, Modifier, Style};
 use tuirealm::ratatui::text::Line as Spans;
 use tuirealm::ratatui::text::Span;
-use tuirealm::ratatui::widgets::Block;
+use tuirealm::ratatui::widgets::{Block, Clear, Paragraph, Widget};
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+use crate::props::{
+    MOUSE_WHEEL_STEP, ROWS_APPEND, ROWS_PATCH, ROWS_REMOVE, TITLE_STYLE, TOOLTIP, TOOLTIP_STYLE,
+};
+use crate::states::ScrollableState;
+use crate::Truncation;
+
+/// Columns between the end and the start of a text scrolling in a marquee
+const MARQUEE_GAP: &str = "   ";
+
 /// ### wrap_spans
 ///
 /// Given a vector of `TextSpans`, it creates a list of `Spans` which mustn't exceed the provided width parameter.
@@ -105,6 +125,545 @@ pub fn use_or_default_styles(props: &Props, span: &TextSpan) -> (Color, Color, M
     )
 }
 
+/// ### styled_rows
+///
+/// Make the styled spans of each row of `table`, using the styles of props for the spans without styles
+pub fn styled_rows(props: &Props, table: &Table) -> Vec<Vec<Span<'static>>> {
+    table
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|col| {
+                    let (fg, bg, modifiers) = use_or_default_styles(props, col);
+                    Span::styled(
+                        col.content.clone(),
+                        Style::default().add_modifier(modifiers).fg(fg).bg(bg),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// ### patch_row
+///
+/// Make the value of `Custom($ROWS_PATCH)`, replacing the row at `index` with `row`
+pub fn patch_row(index: usize, row: Vec<TextSpan>) -> AttrValue {
+    AttrValue::Payload(PropPayload::Vec(
+        std::iter::once(PropValue::Usize(index))
+            .chain(row.into_iter().map(PropValue::TextSpan))
+            .collect(),
+    ))
+}
+
+/// ### remove_row
+///
+/// Make the value of `Custom($ROWS_REMOVE)`, removing the row at `index`
+pub fn remove_row(index: usize) -> AttrValue {
+    AttrValue::Payload(PropPayload::One(PropValue::Usize(index)))
+}
+
+/// ### update_rows
+///
+/// Apply to `rows` the update of `Custom($ROWS_APPEND)`, `Custom($ROWS_PATCH)` or `Custom($ROWS_REMOVE)`, keeping
+/// the selected row and the first row displayed in `states`. Other attributes and indexes out of range are ignored
+pub fn update_rows(
+    rows: &mut Table,
+    states: &mut ScrollableState,
+    attr: Attribute,
+    value: AttrValue,
+) {
+    match (attr, value) {
+        (Attribute::Custom(ROWS_APPEND), AttrValue::Table(appended)) => rows.extend(appended),
+        (Attribute::Custom(ROWS_PATCH), AttrValue::Payload(PropPayload::Vec(values))) => {
+            let mut values = values.into_iter();
+            if let Some(PropValue::Usize(index)) = values.next() {
+                if let Some(row) = rows.get_mut(index) {
+                    *row = values.map(|x| x.unwrap_text_span()).collect();
+                }
+            }
+        }
+        (
+            Attribute::Custom(ROWS_REMOVE),
+            AttrValue::Payload(PropPayload::One(PropValue::Usize(index))),
+        ) if index < rows.len() => {
+            rows.remove(index);
+            states.remove_row(index);
+        }
+        _ => {}
+    }
+    states.set_list_len(rows.len());
+}
+
+/// ### truncate_spans
+///
+/// Fit `spans` into `width` columns as told by `truncation`, if they're wider; `frame` is the position of the text in
+/// a marquee. Spans are cut between graphemes, and the ellipsis takes the style of the span it follows
+pub fn truncate_spans(
+    spans: Vec<Span<'static>>,
+    width: usize,
+    truncation: Truncation,
+    frame: usize,
+) -> Vec<Span<'static>> {
+    truncate_indexed_spans(spans, width, truncation, frame)
+        .into_iter()
+        .map(|(_, x)| x)
+        .collect()
+}
+
+/// ### truncate_indexed_spans
+///
+/// Like `truncate_spans`, getting the index in `spans` of each truncated span as well
+pub fn truncate_indexed_spans(
+    spans: Vec<Span<'static>>,
+    width: usize,
+    truncation: Truncation,
+    frame: usize,
+) -> Vec<(usize, Span<'static>)> {
+    let graphemes: Vec<(usize, &str)> = spans
+        .iter()
+        .enumerate()
+        .flat_map(|(i, x)| x.content.graphemes(true).map(move |g| (i, g)))
+        .collect();
+    if truncation == Truncation::Clip
+        || graphemes.iter().map(|(_, g)| g.width()).sum::<usize>() <= width
+    {
+        return spans.into_iter().enumerate().collect();
+    }
+    if width == 0 {
+        return Vec::new();
+    }
+    let last = spans.len() - 1;
+    let kept: Vec<(usize, &str)> = match truncation {
+        Truncation::Clip => unreachable!(),
+        Truncation::End => {
+            let mut head = take_width(graphemes.iter().copied(), width - 1);
+            head.push((head.last().map(|(i, _)| *i).unwrap_or(0), "…"));
+            head
+        }
+        Truncation::Middle => {
+            let tail_width = (width - 1) / 2;
+            let mut head = take_width(graphemes.iter().copied(), width - 1 - tail_width);
+            let mut tail = take_width(graphemes.iter().rev().copied(), tail_width);
+            tail.reverse();
+            head.push((head.last().map(|(i, _)| *i).unwrap_or(0), "…"));
+            head.extend(tail);
+            head
+        }
+        Truncation::Marquee => {
+            let cycle: Vec<(usize, &str)> = graphemes
+                .iter()
+                .copied()
+                .chain(MARQUEE_GAP.graphemes(true).map(|g| (last, g)))
+                .collect();
+            let start = frame % cycle.len();
+            take_width(cycle.iter().cycle().skip(start).copied(), width)
+        }
+    };
+    // Group the graphemes back into spans
+    let mut truncated: Vec<(usize, Span<'static>)> = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+    for (i, grapheme) in kept {
+        match current.as_mut() {
+            Some((span, content)) if *span == i => content.push_str(grapheme),
+            _ => {
+                if let Some((span, content)) = current.take() {
+                    truncated.push((span, Span::styled(content, spans[span].style)));
+                }
+                current = Some((i, grapheme.to_string()));
+            }
+        }
+    }
+    if let Some((span, content)) = current {
+        truncated.push((span, Span::styled(content, spans[span].style)));
+    }
+    truncated
+}
+
+/// ### span_areas
+///
+/// Get the area of each span of a line rendered in the first row of `area` with `alignment`, as by `Paragraph`
+pub fn span_areas(spans: &[Span], area: Rect, alignment: Alignment) -> Vec<Rect> {
+    let width = spans.iter().map(Span::width).sum::<usize>();
+    let mut x = area.x + line_offset(width, area.width as usize, alignment) as u16;
+    spans
+        .iter()
+        .map(|span| {
+            let span_area = Rect::new(x, area.y, span.width() as u16, area.height.min(1));
+            x = x.saturating_add(span_area.width);
+            span_area.intersection(area)
+        })
+        .collect()
+}
+
+/// ### line_offset
+///
+/// Get the column a line `width` columns wide starts at, when aligned in `area_width` columns as by `Paragraph`
+fn line_offset(width: usize, area_width: usize, alignment: Alignment) -> usize {
+    match alignment {
+        Alignment::Center => (area_width / 2).saturating_sub(width / 2),
+        Alignment::Right => area_width.saturating_sub(width),
+        Alignment::Left => 0,
+    }
+}
+
+/// ### wrap_text
+///
+/// Wrap `text` into lines at most `width` columns wide, as `Paragraph` does: lines are broken at whitespace, which is
+/// removed at the end of the lines, and words wider than a line are broken as well
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    wrap_text_indented(text, width, 0, 0)
+}
+
+/// ### wrap_text_indented
+///
+/// Like `wrap_text`, indenting the first line by `first` columns and the following lines by `hanging` columns.
+/// Indents leave at least a column for the text
+pub fn wrap_text_indented(text: &str, width: usize, first: usize, hanging: usize) -> Vec<String> {
+    let width = width.max(1);
+    let first = " ".repeat(first.min(width - 1));
+    let hanging = " ".repeat(hanging.min(width - 1));
+    let options = textwrap::Options::new(width)
+        .initial_indent(&first)
+        .subsequent_indent(&hanging);
+    textwrap::wrap(text, options)
+        .into_iter()
+        .map(|x| x.into_owned())
+        .collect()
+}
+
+/// ### justify_line
+///
+/// Widen the spaces between the words of `line` so that it's `width` columns wide, as `Paragraph` does with
+/// `Custom($PARAGRAPH_JUSTIFY)`. Leading whitespace is kept, and lines with a single word are left as they are
+pub fn justify_line(line: &str, width: usize) -> String {
+    let content = line.trim_start();
+    let leading = &line[..line.len() - content.len()];
+    let words: Vec<&str> = content.split_whitespace().collect();
+    let gaps = words.len().saturating_sub(1);
+    let used = graphemes_width(leading) + words.iter().map(|x| graphemes_width(x)).sum::<usize>();
+    if gaps == 0 || used + gaps > width {
+        return line.to_string();
+    }
+    let spaces = width - used;
+    let mut justified = leading.to_string();
+    for (i, word) in words.iter().enumerate() {
+        if i > 0 {
+            // The first gaps take the spaces left over
+            let n = spaces / gaps + usize::from(i <= spaces % gaps);
+            justified.push_str(&" ".repeat(n));
+        }
+        justified.push_str(word);
+    }
+    justified
+}
+
+/// ### truncate_text
+///
+/// Truncate `text` to `width` columns, replacing its end with an ellipsis if it's wider, as `Label` does with
+/// `Truncation::End`: `long_fi…`. The text is cut between graphemes
+pub fn truncate_text(text: &str, width: usize) -> String {
+    truncate_spans(vec![Span::raw(text.to_string())], width, Truncation::End, 0)
+        .into_iter()
+        .map(|x| x.content.into_owned())
+        .collect()
+}
+
+/// ### pad_text
+///
+/// Pad `text` with spaces to `width` columns, placing it as `Paragraph` does with `alignment`. Texts wider than
+/// `width` are left as they are
+pub fn pad_text(text: &str, width: usize, alignment: Alignment) -> String {
+    let text_width = graphemes_width(text);
+    let left = line_offset(text_width, width, alignment);
+    let right = width.saturating_sub(left + text_width);
+    format!("{}{text}{}", " ".repeat(left), " ".repeat(right))
+}
+
+/// ### render_hyperlink
+///
+/// Turn the text rendered in the first row of `area` into a hyperlink to `url`, with the OSC 8 escape sequence, which
+/// supporting terminals make clickable while the others ignore it.
+/// Since the width of the escape sequences is counted in the width of the symbol of a cell, which hides the cell
+/// after it, the escape sequences are written in every other cell, around the text of two cells. A last cell which
+/// can't be paired isn't linked
+pub fn render_hyperlink(buf: &mut Buffer, area: Rect, url: &str) {
+    let area = area.intersection(buf.area);
+    let y = area.y;
+    let mut x = area.x;
+    while x < area.right() {
+        let first = buf[(x, y)].symbol().to_string();
+        let (text, cells) = match first.width() {
+            // Wide characters take two cells on their own
+            2 => (first, 2),
+            _ if x + 1 < area.right() && buf[(x + 1, y)].symbol().width() == 1 => {
+                (format!("{first}{}", buf[(x + 1, y)].symbol()), 2)
+            }
+            _ => break,
+        };
+        buf[(x, y)].set_symbol(&format!("\x1B]8;;{url}\x1B\\{text}\x1B]8;;\x1B\\"));
+        x += cells;
+    }
+}
+
+/// ### take_width
+///
+/// Take the graphemes fitting into `width` columns
+fn take_width<'a, I: Iterator<Item = (usize, &'a str)>>(
+    graphemes: I,
+    width: usize,
+) -> Vec<(usize, &'a str)> {
+    let mut used = 0;
+    graphemes
+        .take_while(|(_, g)| {
+            used += g.width();
+            used <= width
+        })
+        .collect()
+}
+
+/// The 16 colors of the SGR escape sequences, in the order of their codes
+const ANSI_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// ### parse_ansi
+///
+/// Parse a text containing ANSI escape sequences, such as the output of a command, into spans styled by its SGR
+/// sequences (`ESC[...m`): modifiers, and the 16 colors, the 256 indexed colors and the RGB colors of the foreground
+/// and of the background. Other escape sequences are removed, while line breaks are kept in the spans
+pub fn parse_ansi(text: &str) -> Vec<TextSpan> {
+    let mut spans: Vec<TextSpan> = Vec::new();
+    let mut style = TextSpan::from("");
+    let mut content = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            content.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters, up to a final byte
+            Some('[') => {
+                let mut params = String::new();
+                let final_byte = chars
+                    .by_ref()
+                    .find(|c| match ('\x40'..='\x7e').contains(c) {
+                        true => true,
+                        false => {
+                            params.push(*c);
+                            false
+                        }
+                    });
+                if final_byte == Some('m') {
+                    let mut next = style.clone();
+                    apply_sgr(&mut next, &params);
+                    if next != style {
+                        push_span(&mut spans, &mut content, &style);
+                        style = next;
+                    }
+                }
+            }
+            // OSC: up to BEL or ST
+            Some(']') => {
+                let mut escape = false;
+                for c in chars.by_ref() {
+                    if c == '\x07' || (escape && c == '\\') {
+                        break;
+                    }
+                    escape = c == '\x1b';
+                }
+            }
+            // Other escape sequences end with the first character which isn't an intermediate byte
+            Some(c) if ('\x20'..='\x2f').contains(&c) => {
+                chars.by_ref().find(|c| !('\x20'..='\x2f').contains(c));
+            }
+            _ => {}
+        }
+    }
+    push_span(&mut spans, &mut content, &style);
+    spans
+}
+
+/// ### apply_sgr
+///
+/// Apply the parameters of an SGR escape sequence to the style of `span`
+fn apply_sgr(span: &mut TextSpan, params: &str) {
+    let mut params = params.split(';').map(|x| x.parse::<u16>().unwrap_or(0));
+    while let Some(param) = params.next() {
+        match param {
+            0 => {
+                span.fg = Color::Reset;
+                span.bg = Color::Reset;
+                span.modifiers = TextModifiers::empty();
+            }
+            1 => span.modifiers |= TextModifiers::BOLD,
+            2 => span.modifiers |= TextModifiers::DIM,
+            3 => span.modifiers |= TextModifiers::ITALIC,
+            4 => span.modifiers |= TextModifiers::UNDERLINED,
+            5 => span.modifiers |= TextModifiers::SLOW_BLINK,
+            6 => span.modifiers |= TextModifiers::RAPID_BLINK,
+            7 => span.modifiers |= TextModifiers::REVERSED,
+            8 => span.modifiers |= TextModifiers::HIDDEN,
+            9 => span.modifiers |= TextModifiers::CROSSED_OUT,
+            22 => span.modifiers -= TextModifiers::BOLD | TextModifiers::DIM,
+            23 => span.modifiers -= TextModifiers::ITALIC,
+            24 => span.modifiers -= TextModifiers::UNDERLINED,
+            25 => span.modifiers -= TextModifiers::SLOW_BLINK | TextModifiers::RAPID_BLINK,
+            27 => span.modifiers -= TextModifiers::REVERSED,
+            28 => span.modifiers -= TextModifiers::HIDDEN,
+            29 => span.modifiers -= TextModifiers::CROSSED_OUT,
+            30..=37 => span.fg = ANSI_COLORS[param as usize - 30],
+            39 => span.fg = Color::Reset,
+            40..=47 => span.bg = ANSI_COLORS[param as usize - 40],
+            49 => span.bg = Color::Reset,
+            90..=97 => span.fg = ANSI_COLORS[param as usize - 90 + 8],
+            100..=107 => span.bg = ANSI_COLORS[param as usize - 100 + 8],
+            38 | 48 => {
+                let color = match params.next() {
+                    Some(5) => params.next().map(|x| Color::Indexed(x as u8)),
+                    Some(2) => match (params.next(), params.next(), params.next()) {
+                        (Some(r), Some(g), Some(b)) => Some(Color::Rgb(r as u8, g as u8, b as u8)),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                match (param, color) {
+                    (38, Some(color)) => span.fg = color,
+                    (_, Some(color)) => span.bg = color,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// ### parse_markup
+///
+/// Parse a text styled with tags into spans, such as `<green bold>OK</> 12 tests`. A tag sets the style of the text
+/// up to its closing tag, `</>` or `</name>`, which closes the innermost tag; tags can be nested. The words of a tag
+/// are:
+///
+/// - the modifiers: `bold` (`b`), `dim`, `italic` (`i`), `underlined` (`u`), `blink`, `reversed`, `hidden` and
+///   `crossed-out` (`s`);
+/// - the foreground color, as `red` or `fg=red`;
+/// - the background color, as `on red` or `bg=red`;
+///
+/// where colors are names, hex codes (`#ff8800`) or indexes (`42`). Tags which can't be parsed are kept as text, and
+/// `\<` and `\\` are a literal `<` and `\`
+pub fn parse_markup(markup: &str) -> Vec<TextSpan> {
+    let mut spans: Vec<TextSpan> = Vec::new();
+    let mut styles: Vec<TextSpan> = vec![TextSpan::from("")];
+    let mut content = String::new();
+    let mut rest = markup;
+    while let Some(start) = rest.find(['<', '\\']) {
+        content.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(escaped) = rest.strip_prefix('\\') {
+            match escaped.chars().next() {
+                Some(c @ ('<' | '\\')) => {
+                    content.push(c);
+                    rest = &escaped[1..];
+                }
+                _ => {
+                    content.push('\\');
+                    rest = escaped;
+                }
+            }
+            continue;
+        }
+        let style = &styles[styles.len() - 1];
+        match rest[1..].find('>').map(|end| &rest[1..end + 1]) {
+            Some(tag) if tag.starts_with('/') && styles.len() > 1 => {
+                push_span(&mut spans, &mut content, style);
+                styles.pop();
+                rest = &rest[tag.len() + 2..];
+            }
+            Some(tag) => match parse_tag(tag, style) {
+                Some(next) => {
+                    push_span(&mut spans, &mut content, style);
+                    styles.push(next);
+                    rest = &rest[tag.len() + 2..];
+                }
+                None => {
+                    content.push('<');
+                    rest = &rest[1..];
+                }
+            },
+            None => {
+                content.push('<');
+                rest = &rest[1..];
+            }
+        }
+    }
+    content.push_str(rest);
+    push_span(&mut spans, &mut content, &styles[styles.len() - 1]);
+    spans
+}
+
+/// ### parse_tag
+///
+/// Parse the words of a markup tag into the style of its text, patching the style of the enclosing text
+fn parse_tag(tag: &str, style: &TextSpan) -> Option<TextSpan> {
+    let mut style = style.clone();
+    let mut words = tag.split_whitespace().peekable();
+    words.peek()?;
+    while let Some(word) = words.next() {
+        let modifier = match word.to_lowercase().as_str() {
+            "bold" | "b" => TextModifiers::BOLD,
+            "dim" => TextModifiers::DIM,
+            "italic" | "i" => TextModifiers::ITALIC,
+            "underlined" | "u" => TextModifiers::UNDERLINED,
+            "blink" => TextModifiers::SLOW_BLINK,
+            "reversed" => TextModifiers::REVERSED,
+            "hidden" => TextModifiers::HIDDEN,
+            "crossed-out" | "s" => TextModifiers::CROSSED_OUT,
+            "on" => {
+                style.bg = Color::from_str(words.next()?).ok()?;
+                continue;
+            }
+            _ => {
+                match word.split_once('=') {
+                    Some(("fg", color)) => style.fg = Color::from_str(color).ok()?,
+                    Some(("bg", color)) => style.bg = Color::from_str(color).ok()?,
+                    Some(_) => return None,
+                    None => style.fg = Color::from_str(word).ok()?,
+                }
+                continue;
+            }
+        };
+        style.modifiers |= modifier;
+    }
+    Some(style)
+}
+
+/// ### push_span
+///
+/// Push `content`, if any, to `spans` with the style of `style`
+fn push_span(spans: &mut Vec<TextSpan>, content: &mut String, style: &TextSpan) {
+    if !content.is_empty() {
+        let mut span = style.clone();
+        span.content = std::mem::take(content);
+        spans.push(span);
+    }
+}
+
 /// ### get_block
 ///
 /// Construct a block for widget using block properties.
@@ -129,12 +688,690 @@ pub fn get_block<'a>(
         .title_alignment(title.1)
 }
 
+/// ### get_title_style
+///
+/// Get the style of the title of the block, set with `Custom($TITLE_STYLE)` (default: the style of the block)
+pub fn get_title_style(props: &Props) -> Style {
+    props
+        .get(Attribute::Custom(TITLE_STYLE))
+        .map(|x| x.unwrap_style())
+        .unwrap_or_default()
+}
+
+/// ## FocusStyles
+///
+/// The styles of a component according to its focus, following the convention of the components of this library:
+/// the border color and the active style are used when the component is focused, otherwise the inactive style, set
+/// with `Attribute::FocusStyle`. Highlighted items are reversed only while focused
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FocusStyles {
+    /// Whether the component is rendered as focused
+    pub focus: bool,
+    /// Style of the content: the active style when focused, otherwise the inactive style (default: none)
+    pub style: Style,
+    /// Inactive style, if set
+    pub inactive: Option<Style>,
+}
+
+impl FocusStyles {
+    /// ### new
+    ///
+    /// Get the styles of an interactive component from its props, with `active` as style when focused
+    pub fn new(props: &Props, active: Style) -> Self {
+        let focus = props
+            .get_or(Attribute::Focus, AttrValue::Flag(false))
+            .unwrap_flag();
+        Self::with_focus(props, active, focus)
+    }
+
+    /// ### display
+    ///
+    /// Get the styles of a component which only displays data, and so is usually never focused, from its props: it is
+    /// rendered as focused unless an inactive style is set
+    pub fn display(props: &Props, active: Style) -> Self {
+        let focus = props
+            .get_or(Attribute::Focus, AttrValue::Flag(false))
+            .unwrap_flag();
+        let inactive = props.get(Attribute::FocusStyle).is_some();
+        Self::with_focus(props, active, focus || !inactive)
+    }
+
+    fn with_focus(props: &Props, active: Style, focus: bool) -> Self {
+        let inactive = props.get(Attribute::FocusStyle).map(|x| x.unwrap_style());
+        Self {
+            focus,
+            style: match focus {
+                true => active,
+                false => inactive.unwrap_or_default(),
+            },
+            inactive,
+        }
+    }
+
+    /// ### block
+    ///
+    /// Construct the block of the component, with its border color if focused, otherwise the inactive style
+    pub fn block<'a>(&self, borders: Borders, title: Option<(String, Alignment)>) -> Block<'a> {
+        get_block(borders, title, self.focus, self.inactive)
+    }
+
+    /// ### highlight
+    ///
+    /// Get the style of highlighted items: `color`, reversed if focused
+    pub fn highlight(&self, color: Color) -> Style {
+        match self.focus {
+            true => self.style.fg(color).add_modifier(Modifier::REVERSED),
+            false => self.style.fg(color),
+        }
+    }
+}
+
+/// ### is_disabled
+///
+/// Returns whether the component is disabled, through `Attribute::Disabled`. Disabled components are dimmed and
+/// ignore commands
+pub fn is_disabled(props: &Props) -> bool {
+    props
+        .get_or(Attribute::Disabled, AttrValue::Flag(false))
+        .unwrap_flag()
+}
+
+/// ### render_disabled
+///
+/// Dim the area where the component has been rendered, if it is displayed and disabled
+pub fn render_disabled(props: &Props, buf: &mut Buffer, area: Rect) {
+    let display = props
+        .get_or(Attribute::Display, AttrValue::Flag(true))
+        .unwrap_flag();
+    if display && is_disabled(props) {
+        buf.set_style(
+            area.intersection(buf.area),
+            Style::default().add_modifier(Modifier::DIM),
+        );
+    }
+}
+
 /// ### calc_utf8_cursor_position
 ///
 /// Calculate the UTF8 compliant position for the cursor given the characters preceeding the cursor position.
 /// Use this function to calculate cursor position whenever you want to handle UTF8 texts with cursors
 pub fn calc_utf8_cursor_position(chars: &[char]) -> u16 {
-    chars.iter().collect::<String>().width() as u16
+    graphemes_width(&chars.iter().collect::<String>()) as u16
+}
+
+/// ### graphemes_width
+///
+/// Get the width of `text` as it's rendered: the sum of the widths of its grapheme clusters, so that emoji sequences,
+/// combining characters and wide characters take the cells they're drawn in
+pub fn graphemes_width(text: &str) -> usize {
+    text.graphemes(true).map(|x| x.width()).sum()
+}
+
+/// ### centered_rect
+///
+/// Get a rect taking `width` and `height` percent of `area`, centered in it.
+/// Useful to place popups over the rest of the view
+pub fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = (u32::from(area.width) * u32::from(width.min(100)) / 100) as u16;
+    let height = (u32::from(area.height) * u32::from(height.min(100)) / 100) as u16;
+    centered_rect_fixed(width, height, area)
+}
+
+/// ### centered_rect_fixed
+///
+/// Get a rect of `width` x `height` cells, centered in `area`. The rect is shrunk to fit `area`, if larger
+pub fn centered_rect_fixed(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// ### popup_rect
+///
+/// Get a rect of `width` x `height` cells anchored to `anchor`, such as the field opening a dropdown, within `bounds`.
+/// The rect is placed below the anchor, aligned to its left edge; it is flipped above if it doesn't fit below and there
+/// is more room above. It is shifted left to fit `bounds`, and shrunk to the room there is
+pub fn popup_rect(anchor: Rect, width: u16, height: u16, bounds: Rect) -> Rect {
+    let below = bounds.bottom().saturating_sub(anchor.bottom());
+    let above = anchor.y.saturating_sub(bounds.y);
+    let (y, height) = match height > below && above > below {
+        true => {
+            let height = height.min(above);
+            (anchor.y - height, height)
+        }
+        false => (anchor.bottom().min(bounds.bottom()), height.min(below)),
+    };
+    let width = width.min(bounds.width);
+    let x = anchor.x.clamp(bounds.x, bounds.right() - width);
+    Rect::new(x, y, width, height)
+}
+
+/// ### render_tooltip
+///
+/// Render the tooltip of the component, set with `Custom($TOOLTIP)`, in a box next to its `area`, if the component is
+/// displayed and focused. The box is placed with `popup_rect` within the buffer, so components rendered afterwards
+/// may cover it
+pub fn render_tooltip(props: &Props, buf: &mut Buffer, area: Rect) {
+    let display = props
+        .get_or(Attribute::Display, AttrValue::Flag(true))
+        .unwrap_flag();
+    let focus = props
+        .get_or(Attribute::Focus, AttrValue::Flag(false))
+        .unwrap_flag();
+    let Some(text) = props
+        .get(Attribute::Custom(TOOLTIP))
+        .map(|x| x.unwrap_string())
+    else {
+        return;
+    };
+    if !display || !focus || text.is_empty() {
+        return;
+    }
+    let style = props
+        .get(Attribute::Custom(TOOLTIP_STYLE))
+        .map(|x| x.unwrap_style())
+        .unwrap_or_default();
+    let width = text.lines().map(|x| x.width()).max().unwrap_or(0) as u16;
+    let height = text.lines().count() as u16;
+    let tooltip = popup_rect(area, width + 2, height + 2, buf.area);
+    Clear.render(tooltip, buf);
+    Paragraph::new(text)
+        .style(style)
+        .block(
+            Block::default()
+                .borders(BorderSides::ALL)
+                .border_type(BorderType::Rounded)
+                .style(style),
+        )
+        .render(tooltip, buf);
+}
+
+/// ### clicked
+///
+/// If the mouse event is a click with the left button inside of `area`, get its position relative to the area.
+/// Components handling mouse events use it for hit-testing against the area of their last render
+pub fn clicked(ev: &MouseEvent, area: Rect) -> Option<(u16, u16)> {
+    match ev.kind {
+        MouseEventKind::Down(MouseButton::Left)
+            if area.contains(Position::new(ev.column, ev.row)) =>
+        {
+            Some((ev.column - area.x, ev.row - area.y))
+        }
+        _ => None,
+    }
+}
+
+/// ### wheel
+///
+/// If the mouse event is a scroll of the wheel inside of `area`, get its direction and the amount of lines to scroll,
+/// set with `Custom($MOUSE_WHEEL_STEP)` (default: 3)
+pub fn wheel(ev: &MouseEvent, area: Rect, props: &Props) -> Option<(Direction, usize)> {
+    if !area.contains(Position::new(ev.column, ev.row)) {
+        return None;
+    }
+    let direction = match ev.kind {
+        MouseEventKind::ScrollDown => Direction::Down,
+        MouseEventKind::ScrollUp => Direction::Up,
+        _ => return None,
+    };
+    let step = props
+        .get_or(Attribute::Custom(MOUSE_WHEEL_STEP), AttrValue::Length(3))
+        .unwrap_length();
+    Some((direction, step))
+}
+
+/// ### tab_at
+///
+/// Get the index of the tab at column `x` of tabs rendered by the `Tabs` widget, given the width of their titles.
+/// Each tab takes its title, padded with a space on both sides, followed by a divider
+pub fn tab_at(widths: &[usize], x: u16) -> Option<usize> {
+    let x = x as usize;
+    let mut start = 0;
+    for (i, width) in widths.iter().enumerate() {
+        let end = start + width + 2;
+        if x < start {
+            break;
+        }
+        if x < end {
+            return Some(i);
+        }
+        // Skip the divider
+        start = end + 1;
+    }
+    None
+}
+
+/// ### tabs_width
+///
+/// Get the width of tabs rendered by the `Tabs` widget, given the width of their titles
+pub(crate) fn tabs_width(widths: &[usize]) -> usize {
+    widths.iter().map(|x| x + 2).sum::<usize>() + widths.len().saturating_sub(1)
+}
+
+/// ### visible_tabs
+///
+/// Get the number of tabs which are fully visible in `width` columns, starting from the tab at `offset`
+pub(crate) fn visible_tabs(widths: &[usize], offset: usize, width: usize) -> usize {
+    let widths = &widths[offset.min(widths.len())..];
+    (1..=widths.len())
+        .take_while(|n| tabs_width(&widths[..*n]) <= width)
+        .count()
+}
+
+/// ### scroll_tabs
+///
+/// Get the index of the first tab to render, so that the tab at `selected` is visible in `width` columns.
+/// The first tab moves from `offset` as little as possible, then back while there's room left for the previous tabs
+pub(crate) fn scroll_tabs(widths: &[usize], selected: usize, offset: usize, width: usize) -> usize {
+    if widths.is_empty() {
+        return 0;
+    }
+    let selected = selected.min(widths.len() - 1);
+    let mut offset = offset.min(selected);
+    while offset < selected && tabs_width(&widths[offset..=selected]) > width {
+        offset += 1;
+    }
+    while offset > 0 && tabs_width(&widths[offset - 1..]) <= width {
+        offset -= 1;
+    }
+    offset
+}
+
+/// ### tabs_strip
+///
+/// Get the area where tabs are rendered in `area`: when they don't fit, a column is left on both sides for the
+/// overflow indicators rendered by `render_tabs_overflow`
+pub(crate) fn tabs_strip(widths: &[usize], area: Rect) -> Rect {
+    match tabs_width(widths) > area.width as usize {
+        true => Rect {
+            x: area.x + 1.min(area.width),
+            width: area.width.saturating_sub(2),
+            ..area
+        },
+        false => area,
+    }
+}
+
+/// ### render_tabs_overflow
+///
+/// Render `‹` and `›` on the sides of `area` if there are tabs before the one at `offset` or beyond `strip`, as
+/// returned by `tabs_strip`
+pub(crate) fn render_tabs_overflow(
+    buf: &mut Buffer,
+    area: Rect,
+    strip: Rect,
+    widths: &[usize],
+    offset: usize,
+    style: Style,
+) {
+    if strip == area || area.width < 2 || area.height == 0 {
+        return;
+    }
+    if offset > 0 {
+        buf.set_string(area.x, area.y, "‹", style);
+    }
+    if tabs_width(&widths[offset.min(widths.len())..]) > strip.width as usize {
+        buf.set_string(area.right() - 1, area.y, "›", style);
+    }
+}
+
+/// ### format_timestamp
+///
+/// Format a unix timestamp (seconds, UTC) using a strftime-like format string.
+/// Supported specifiers are `%Y`, `%y`, `%m`, `%d`, `%e`, `%H`, `%I`, `%p`, `%M`, `%S`, `%b`, `%a` and `%%`.
+/// Unknown specifiers are kept as they are.
+pub fn format_timestamp(timestamp: f64, fmt: &str) -> String {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    let secs = timestamp.floor() as i64;
+    let days = secs.div_euclid(86400);
+    let secs_of_day = secs.rem_euclid(86400);
+    let (hour, minute, second) = (
+        secs_of_day / 3600,
+        (secs_of_day % 3600) / 60,
+        secs_of_day % 60,
+    );
+    let Date { year, month, day } = Date::from_days(days);
+    // Format
+    let mut out = String::with_capacity(fmt.len());
+    let mut chars = fmt.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => out.push_str(&year.to_string()),
+            Some('y') => out.push_str(&format!("{:02}", year.rem_euclid(100))),
+            Some('m') => out.push_str(&format!("{month:02}")),
+            Some('d') => out.push_str(&format!("{day:02}")),
+            Some('e') => out.push_str(&day.to_string()),
+            Some('H') => out.push_str(&format!("{hour:02}")),
+            Some('I') => out.push_str(&format!("{:02}", (hour + 11) % 12 + 1)),
+            Some('p') => out.push_str(if hour < 12 { "AM" } else { "PM" }),
+            Some('M') => out.push_str(&format!("{minute:02}")),
+            Some('S') => out.push_str(&format!("{second:02}")),
+            Some('b') => out.push_str(MONTHS[month as usize - 1]),
+            Some('a') => out.push_str(WEEKDAYS[days.rem_euclid(7) as usize]),
+            Some('%') => out.push('%'),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
+
+/// ### format_bytes
+///
+/// Format a size in bytes with binary units, with a decimal from KiB on: `512 B`, `1.4 GiB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    // Round before choosing the unit, so that 1023.96 KiB is 1.0 MiB
+    while (size * 10.0).round() / 10.0 >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// ### format_duration
+///
+/// Format a duration with its two largest units among days, hours, minutes and seconds, omitting the second one if
+/// zero: `2m 13s`, `1h`, `3d 4h`. Durations shorter than a second are in milliseconds: `850ms`
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs == 0 {
+        return format!("{}ms", duration.subsec_millis());
+    }
+    let units = [
+        (secs / 86400, "d"),
+        (secs % 86400 / 3600, "h"),
+        (secs % 3600 / 60, "m"),
+        (secs % 60, "s"),
+    ];
+    let first = units.iter().position(|(x, _)| *x > 0).unwrap_or(3);
+    units[first..]
+        .iter()
+        .take(2)
+        .enumerate()
+        .filter(|(i, (x, _))| *i == 0 || *x > 0)
+        .map(|(_, (x, unit))| format!("{x}{unit}"))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// ### format_number
+///
+/// Format a number with the `k`, `M`, `B` and `T` suffixes from a thousand on, with at most a decimal: `999`,
+/// `12.3k`, `4M`
+pub fn format_number(number: f64) -> String {
+    const SUFFIXES: [&str; 5] = ["", "k", "M", "B", "T"];
+    let mut value = number.abs();
+    let mut suffix = 0;
+    // Round before choosing the suffix, so that 999 960 is 1M
+    while (value * 10.0).round() / 10.0 >= 1000.0 && suffix < SUFFIXES.len() - 1 {
+        value /= 1000.0;
+        suffix += 1;
+    }
+    let sign = match number < 0.0 && (value * 10.0).round() > 0.0 {
+        true => "-",
+        false => "",
+    };
+    let value = format!("{value:.1}");
+    let value = value.strip_suffix(".0").unwrap_or(&value);
+    format!("{sign}{value}{}", SUFFIXES[suffix])
+}
+
+/// ### format_percent
+///
+/// Format a ratio, from 0 to 1, as a rounded percentage: `0.606` is `61%`
+pub fn format_percent(ratio: f64) -> String {
+    format!("{:.0}%", ratio * 100.0)
+}
+
+/// ## Date
+///
+/// A calendar date, in the proleptic gregorian calendar.
+/// Dates are ordered chronologically and displayed in the ISO 8601 format (`YYYY-MM-DD`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    /// Month, from 1 to 12
+    pub month: u8,
+    /// Day of month, from 1
+    pub day: u8,
+}
+
+impl Date {
+    /// ### new
+    ///
+    /// Make a new date. Returns `None` if the date doesn't exist
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        match (1..=12).contains(&month) && day >= 1 && day <= Self::days_in_month(year, month) {
+            true => Some(Self { year, month, day }),
+            false => None,
+        }
+    }
+
+    /// ### today
+    ///
+    /// Get the current date (UTC)
+    pub fn today() -> Self {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|x| x.as_secs() as i64)
+            .unwrap_or(0);
+        Self::from_days(secs.div_euclid(86400))
+    }
+
+    /// ### parse
+    ///
+    /// Parse a date in the ISO 8601 format (`YYYY-MM-DD`)
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        Self::new(year, month, day)
+    }
+
+    /// ### from_days
+    ///
+    /// Make a date from the days since the UNIX epoch
+    pub fn from_days(days: i64) -> Self {
+        // <http://howardhinnant.github.io/date_algorithms.html>
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    /// ### to_days
+    ///
+    /// Get the days since the UNIX epoch
+    pub fn to_days(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let mp = if month > 2 { month - 3 } else { month + 9 };
+        let doy = (153 * mp + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    /// ### weekday
+    ///
+    /// Get the day of the week, from 0 (Monday) to 6 (Sunday)
+    pub fn weekday(&self) -> u8 {
+        // 1970-01-01 was a Thursday
+        (self.to_days() + 3).rem_euclid(7) as u8
+    }
+
+    /// ### add_days
+    ///
+    /// Get the date `days` after this one; `days` can be negative
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// ### add_months
+    ///
+    /// Get the date `months` after this one; `months` can be negative.
+    /// The day is clamped to the last day of the resulting month
+    pub fn add_months(&self, months: i32) -> Self {
+        let index = self.year * 12 + i32::from(self.month) - 1 + months;
+        let (year, month) = (index.div_euclid(12), index.rem_euclid(12) as u8 + 1);
+        Self {
+            year,
+            month,
+            day: self.day.min(Self::days_in_month(year, month)),
+        }
+    }
+
+    /// ### first_of_month
+    ///
+    /// Get the first day of the month of this date
+    pub fn first_of_month(&self) -> Self {
+        Self { day: 1, ..*self }
+    }
+
+    /// ### days_in_month
+    ///
+    /// Get the number of days in `month` of `year`
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// ### is_leap_year
+    ///
+    /// Check whether `year` is a leap year
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// ## Time
+///
+/// A time of day, in the 24-hour clock.
+/// Times are ordered chronologically and displayed in the ISO 8601 format (`HH:MM:SS`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    /// Hour, from 0 to 23
+    pub hour: u8,
+    /// Minute, from 0 to 59
+    pub minute: u8,
+    /// Second, from 0 to 59
+    pub second: u8,
+}
+
+impl Time {
+    /// ### new
+    ///
+    /// Make a new time. Returns `None` if the time doesn't exist
+    pub fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        match hour < 24 && minute < 60 && second < 60 {
+            true => Some(Self {
+                hour,
+                minute,
+                second,
+            }),
+            false => None,
+        }
+    }
+
+    /// ### now
+    ///
+    /// Get the current time (UTC)
+    pub fn now() -> Self {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|x| x.as_secs())
+            .unwrap_or(0);
+        Self::from_seconds(secs)
+    }
+
+    /// ### parse
+    ///
+    /// Parse a time in the ISO 8601 format (`HH:MM` or `HH:MM:SS`)
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().splitn(3, ':');
+        let hour = parts.next()?.parse().ok()?;
+        let minute = parts.next()?.parse().ok()?;
+        let second = match parts.next() {
+            Some(second) => second.parse().ok()?,
+            None => 0,
+        };
+        Self::new(hour, minute, second)
+    }
+
+    /// ### from_seconds
+    ///
+    /// Make a time from the seconds since midnight; days in excess are discarded
+    pub fn from_seconds(seconds: u64) -> Self {
+        let seconds = seconds % 86400;
+        Self {
+            hour: (seconds / 3600) as u8,
+            minute: ((seconds % 3600) / 60) as u8,
+            second: (seconds % 60) as u8,
+        }
+    }
+
+    /// ### to_seconds
+    ///
+    /// Get the seconds since midnight
+    pub fn to_seconds(&self) -> u64 {
+        u64::from(self.hour) * 3600 + u64::from(self.minute) * 60 + u64::from(self.second)
+    }
+
+    /// ### hour12
+    ///
+    /// Get the hour in the 12-hour clock, from 1 to 12, and whether it's past noon
+    pub fn hour12(&self) -> (u8, bool) {
+        ((self.hour + 11) % 12 + 1, self.hour >= 12)
+    }
+}
+
+impl std::fmt::Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
 }
 
 #[cfg(test)]
@@ -221,6 +1458,226 @@ mod test {
         get_block(props, None, false, None);
     }
 
+    #[test]
+    fn test_components_utils_centered_rect() {
+        let area = Rect::new(10, 5, 100, 40);
+        assert_eq!(centered_rect(50, 50, area), Rect::new(35, 15, 50, 20));
+        assert_eq!(centered_rect(200, 100, area), area);
+        assert_eq!(centered_rect_fixed(31, 9, area), Rect::new(44, 20, 31, 9));
+        assert_eq!(centered_rect_fixed(120, 9, area), Rect::new(10, 20, 100, 9));
+    }
+
+    #[test]
+    fn test_components_utils_focus_styles() {
+        let active = Style::default().fg(Color::Yellow);
+        let inactive = Style::default().fg(Color::Gray);
+        let mut props = Props::default();
+        // Interactive component
+        let styles = FocusStyles::new(&props, active);
+        assert!(!styles.focus);
+        assert_eq!(styles.style, Style::default());
+        assert_eq!(
+            styles.highlight(Color::Red),
+            Style::default().fg(Color::Red)
+        );
+        props.set(Attribute::Focus, AttrValue::Flag(true));
+        let styles = FocusStyles::new(&props, active);
+        assert_eq!(styles.style, active);
+        assert_eq!(
+            styles.highlight(Color::Red),
+            Style::default()
+                .fg(Color::Red)
+                .add_modifier(Modifier::REVERSED)
+        );
+        // Display component, focused unless an inactive style is set
+        let mut props = Props::default();
+        assert_eq!(FocusStyles::display(&props, active).style, active);
+        props.set(Attribute::FocusStyle, AttrValue::Style(inactive));
+        let styles = FocusStyles::display(&props, active);
+        assert!(!styles.focus);
+        assert_eq!(styles.style, inactive);
+        assert_eq!(styles.inactive, Some(inactive));
+        props.set(Attribute::Focus, AttrValue::Flag(true));
+        assert_eq!(FocusStyles::display(&props, active).style, active);
+    }
+
+    #[test]
+    fn test_components_utils_disabled() {
+        let mut props = Props::default();
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+        assert!(!is_disabled(&props));
+        render_disabled(&props, &mut buf, area);
+        assert!(!buf[(0, 0)].modifier.contains(Modifier::DIM));
+        props.set(Attribute::Disabled, AttrValue::Flag(true));
+        assert!(is_disabled(&props));
+        render_disabled(&props, &mut buf, Rect::new(2, 1, 10, 10));
+        assert!(!buf[(1, 1)].modifier.contains(Modifier::DIM));
+        assert!(buf[(3, 1)].modifier.contains(Modifier::DIM));
+        // Hidden components aren't rendered
+        let mut buf = Buffer::empty(area);
+        props.set(Attribute::Display, AttrValue::Flag(false));
+        render_disabled(&props, &mut buf, area);
+        assert!(!buf[(0, 0)].modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn test_components_utils_tooltip() {
+        let rows = |buf: &Buffer| -> Vec<String> {
+            buf.area
+                .rows()
+                .map(|row| {
+                    row.positions()
+                        .map(|pos| buf[pos].symbol().to_string())
+                        .collect()
+                })
+                .collect()
+        };
+        let mut props = Props::default();
+        props.set(Attribute::Custom(TOOLTIP), AttrValue::String("hint".into()));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        // Not focused
+        render_tooltip(&props, &mut buf, Rect::new(2, 0, 6, 1));
+        assert_eq!(rows(&buf), vec!["        "; 5]);
+        // Under the component, shifted left to fit
+        props.set(Attribute::Focus, AttrValue::Flag(true));
+        render_tooltip(&props, &mut buf, Rect::new(2, 0, 6, 1));
+        assert_eq!(
+            rows(&buf),
+            vec!["        ", "  ╭────╮", "  │hint│", "  ╰────╯", "        "]
+        );
+        // Above the component
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        render_tooltip(&props, &mut buf, Rect::new(4, 4, 4, 1));
+        assert_eq!(
+            rows(&buf),
+            vec!["        ", "  ╭────╮", "  │hint│", "  ╰────╯", "        "]
+        );
+    }
+
+    #[test]
+    fn test_components_utils_clicked() {
+        let area = Rect::new(2, 2, 4, 2);
+        let click = |kind, column, row| MouseEvent {
+            kind,
+            modifiers: tuirealm::event::KeyModifiers::NONE,
+            column,
+            row,
+        };
+        let left = MouseEventKind::Down(MouseButton::Left);
+        assert_eq!(clicked(&click(left, 3, 3), area), Some((1, 1)));
+        assert_eq!(clicked(&click(left, 6, 3), area), None);
+        assert_eq!(clicked(&click(left, 1, 2), area), None);
+        assert_eq!(
+            clicked(&click(MouseEventKind::Down(MouseButton::Right), 3, 3), area),
+            None
+        );
+        assert_eq!(clicked(&click(MouseEventKind::Moved, 3, 3), area), None);
+    }
+
+    #[test]
+    fn test_components_utils_wheel() {
+        let area = Rect::new(2, 2, 4, 2);
+        let scroll = |kind, column, row| MouseEvent {
+            kind,
+            modifiers: tuirealm::event::KeyModifiers::NONE,
+            column,
+            row,
+        };
+        let mut props = Props::default();
+        assert_eq!(
+            wheel(&scroll(MouseEventKind::ScrollDown, 3, 3), area, &props),
+            Some((Direction::Down, 3))
+        );
+        props.set(Attribute::Custom(MOUSE_WHEEL_STEP), AttrValue::Length(1));
+        assert_eq!(
+            wheel(&scroll(MouseEventKind::ScrollUp, 3, 3), area, &props),
+            Some((Direction::Up, 1))
+        );
+        assert_eq!(
+            wheel(&scroll(MouseEventKind::ScrollUp, 0, 0), area, &props),
+            None
+        );
+        assert_eq!(
+            wheel(
+                &scroll(MouseEventKind::Down(MouseButton::Left), 3, 3),
+                area,
+                &props
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_components_utils_tab_at() {
+        // " a │ bcd │ ef "
+        let widths = [1, 3, 2];
+        assert_eq!(tab_at(&widths, 0), Some(0));
+        assert_eq!(tab_at(&widths, 2), Some(0));
+        assert_eq!(tab_at(&widths, 3), None);
+        assert_eq!(tab_at(&widths, 4), Some(1));
+        assert_eq!(tab_at(&widths, 8), Some(1));
+        assert_eq!(tab_at(&widths, 10), Some(2));
+        assert_eq!(tab_at(&widths, 14), None);
+    }
+
+    #[test]
+    fn test_components_utils_scroll_tabs() {
+        // " a │ bcd │ ef "
+        let widths = [1, 3, 2];
+        assert_eq!(tabs_width(&widths), 14);
+        assert_eq!(tabs_width(&[]), 0);
+        assert_eq!(visible_tabs(&widths, 0, 14), 3);
+        assert_eq!(visible_tabs(&widths, 0, 9), 2);
+        assert_eq!(visible_tabs(&widths, 1, 9), 1);
+        assert_eq!(visible_tabs(&widths, 3, 9), 0);
+        // Scroll forward to the selected tab, then back
+        assert_eq!(scroll_tabs(&widths, 2, 0, 10), 1);
+        assert_eq!(scroll_tabs(&widths, 2, 0, 4), 2);
+        assert_eq!(scroll_tabs(&widths, 0, 2, 9), 0);
+        assert_eq!(scroll_tabs(&widths, 1, 1, 14), 0);
+        assert_eq!(scroll_tabs(&[], 1, 1, 14), 0);
+        // Strip
+        let area = Rect::new(2, 1, 14, 1);
+        assert_eq!(tabs_strip(&widths, area), area);
+        let area = Rect::new(2, 1, 10, 1);
+        assert_eq!(tabs_strip(&widths, area), Rect::new(3, 1, 8, 1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 2));
+        render_tabs_overflow(
+            &mut buf,
+            area,
+            Rect::new(3, 1, 8, 1),
+            &widths,
+            1,
+            Style::default(),
+        );
+        assert_eq!(buf[(2, 1)].symbol(), "‹");
+        assert_eq!(buf[(11, 1)].symbol(), "›");
+    }
+
+    #[test]
+    fn test_components_utils_popup_rect() {
+        let bounds = Rect::new(0, 0, 40, 20);
+        // Below
+        let anchor = Rect::new(4, 2, 10, 3);
+        assert_eq!(popup_rect(anchor, 10, 6, bounds), Rect::new(4, 5, 10, 6));
+        // Shrunk below, with more room than above
+        assert_eq!(popup_rect(anchor, 10, 30, bounds), Rect::new(4, 5, 10, 15));
+        // Flipped above
+        let anchor = Rect::new(4, 15, 10, 3);
+        assert_eq!(popup_rect(anchor, 10, 6, bounds), Rect::new(4, 9, 10, 6));
+        assert_eq!(popup_rect(anchor, 10, 30, bounds), Rect::new(4, 0, 10, 15));
+        // Fits below, even if there is more room above
+        assert_eq!(popup_rect(anchor, 10, 2, bounds), Rect::new(4, 18, 10, 2));
+        // Shifted left
+        let anchor = Rect::new(36, 2, 4, 1);
+        assert_eq!(popup_rect(anchor, 10, 2, bounds), Rect::new(30, 3, 10, 2));
+        assert_eq!(popup_rect(anchor, 50, 2, bounds), Rect::new(0, 3, 40, 2));
+        // No room
+        let anchor = Rect::new(0, 0, 40, 20);
+        assert_eq!(popup_rect(anchor, 10, 2, bounds), Rect::new(0, 20, 10, 0));
+    }
+
     #[test]
     fn test_components_utils_calc_utf8_cursor_position() {
         let chars: Vec<char> = vec!['v', 'e', 'e', 's', 'o'];
@@ -235,4 +1692,289 @@ mod test {
         let chars: Vec<char> = vec!['我', '之', '😄'];
         assert_eq!(calc_utf8_cursor_position(chars.as_slice()), 6);
     }
+
+    #[test]
+    fn test_components_utils_graphemes_width() {
+        assert_eq!(graphemes_width("veeso"), 5);
+        assert_eq!(graphemes_width("日本"), 4);
+        // Combining characters
+        assert_eq!(graphemes_width("cafe\u{301}"), 4);
+        // Emoji sequences
+        assert_eq!(graphemes_width("👩\u{200d}💻!"), 3);
+        assert_eq!(graphemes_width(""), 0);
+    }
+
+    #[test]
+    fn test_components_utils_parse_markup() {
+        assert_eq!(
+            parse_markup("<green bold>OK</> 12 tests"),
+            vec![
+                TextSpan::from("OK").fg(Color::Green).bold(),
+                TextSpan::from(" 12 tests")
+            ]
+        );
+        // Tags are nested, and closed by name as well
+        assert_eq!(
+            parse_markup("<white on red>a<i>b</i>c</>"),
+            vec![
+                TextSpan::from("a").fg(Color::White).bg(Color::Red),
+                TextSpan::from("b").fg(Color::White).bg(Color::Red).italic(),
+                TextSpan::from("c").fg(Color::White).bg(Color::Red),
+            ]
+        );
+        assert_eq!(
+            parse_markup("<fg=#ff8800 bg=42 u>x</u>"),
+            vec![TextSpan::from("x")
+                .fg(Color::Rgb(255, 136, 0))
+                .bg(Color::Indexed(42))
+                .underlined()]
+        );
+        // Invalid tags are kept as text
+        assert_eq!(
+            parse_markup("1 <2 and <nope>x</> <> <fg=nope>"),
+            vec![TextSpan::from("1 <2 and <nope>x</> <> <fg=nope>")]
+        );
+        // Escapes
+        assert_eq!(
+            parse_markup("\\<b>\\\\n\\t"),
+            vec![TextSpan::from("<b>\\n\\t")]
+        );
+    }
+
+    #[test]
+    fn test_components_utils_parse_ansi() {
+        assert_eq!(parse_ansi("plain"), vec![TextSpan::from("plain")]);
+        assert_eq!(
+            parse_ansi("\x1b[1;31mFAIL\x1b[0m tests\x1b[m"),
+            vec![
+                TextSpan::from("FAIL").fg(Color::Red).bold(),
+                TextSpan::from(" tests")
+            ]
+        );
+        // Modifiers and colors are changed one by one
+        assert_eq!(
+            parse_ansi("\x1b[4;38;5;208ma\x1b[48;2;1;2;3mb\x1b[24;39mc\x1b[97;49md"),
+            vec![
+                TextSpan::from("a").fg(Color::Indexed(208)).underlined(),
+                TextSpan::from("b")
+                    .fg(Color::Indexed(208))
+                    .bg(Color::Rgb(1, 2, 3))
+                    .underlined(),
+                TextSpan::from("c").bg(Color::Rgb(1, 2, 3)),
+                TextSpan::from("d").fg(Color::White),
+            ]
+        );
+        // Other escape sequences are removed
+        assert_eq!(
+            parse_ansi("\x1b[2K\x1b]0;title\x07a\x1b]8;;url\x1b\\b\x1b(Bc\nd"),
+            vec![TextSpan::from("abc\nd")]
+        );
+    }
+
+    #[test]
+    fn test_components_utils_wrap_text() {
+        assert_eq!(wrap_text("hello world", 8), vec!["hello", "world"]);
+        assert_eq!(wrap_text("", 8), vec![""]);
+        // Long words are broken
+        assert_eq!(wrap_text("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(
+            wrap_text_indented("aa bb cc dd", 8, 2, 4),
+            vec!["  aa bb", "    cc", "    dd"]
+        );
+        // Indents leave a column for the text
+        assert_eq!(wrap_text_indented("ab", 2, 4, 4), vec![" a", " b"]);
+    }
+
+    #[test]
+    fn test_components_utils_justify_line() {
+        assert_eq!(justify_line("a b c", 6), "a  b c");
+        assert_eq!(justify_line("  a b", 8), "  a    b");
+        assert_eq!(justify_line("日本 語", 8), "日本  語");
+        assert_eq!(justify_line("word", 8), "word");
+        assert_eq!(justify_line("too long", 4), "too long");
+    }
+
+    #[test]
+    fn test_components_utils_truncate_and_pad_text() {
+        assert_eq!(truncate_text("long_filename.txt", 8), "long_fi…");
+        assert_eq!(truncate_text("short", 8), "short");
+        assert_eq!(truncate_text("日本語", 5), "日本…");
+        assert_eq!(pad_text("ab", 6, Alignment::Left), "ab    ");
+        assert_eq!(pad_text("ab", 6, Alignment::Center), "  ab  ");
+        assert_eq!(pad_text("日本", 5, Alignment::Right), " 日本");
+        assert_eq!(pad_text("toolong", 4, Alignment::Center), "toolong");
+    }
+
+    #[test]
+    fn test_components_utils_truncate_spans() {
+        let text =
+            |spans: Vec<Span>| -> String { spans.iter().map(|x| x.content.as_ref()).collect() };
+        let spans = || vec![Span::raw("long_file"), Span::raw("name.txt")];
+        assert_eq!(
+            text(truncate_spans(spans(), 17, Truncation::End, 0)),
+            "long_filename.txt"
+        );
+        assert_eq!(
+            text(truncate_spans(spans(), 8, Truncation::Clip, 0)),
+            "long_filename.txt"
+        );
+        assert_eq!(
+            text(truncate_spans(spans(), 8, Truncation::End, 0)),
+            "long_fi…"
+        );
+        assert_eq!(
+            text(truncate_spans(spans(), 8, Truncation::Middle, 0)),
+            "long…txt"
+        );
+        assert_eq!(text(truncate_spans(spans(), 0, Truncation::End, 0)), "");
+        // The ellipsis takes the style of the span it follows
+        let styled = vec![
+            Span::styled("ab", Style::default().fg(Color::Red)),
+            Span::styled("cd", Style::default().fg(Color::Blue)),
+        ];
+        assert_eq!(
+            truncate_spans(styled, 3, Truncation::End, 0),
+            vec![Span::styled("ab…", Style::default().fg(Color::Red))]
+        );
+        // Graphemes aren't cut
+        assert_eq!(
+            text(truncate_spans(
+                vec![Span::raw("日本語")],
+                4,
+                Truncation::End,
+                0
+            )),
+            "日…"
+        );
+        assert_eq!(
+            text(truncate_spans(
+                vec![Span::raw("cafe\u{301}s")],
+                4,
+                Truncation::End,
+                0
+            )),
+            "caf…"
+        );
+        // The marquee goes around the text, with a gap between its end and its start
+        assert_eq!(
+            text(truncate_spans(spans(), 8, Truncation::Marquee, 0)),
+            "long_fil"
+        );
+        assert_eq!(
+            text(truncate_spans(spans(), 8, Truncation::Marquee, 14)),
+            "txt   lo"
+        );
+        assert_eq!(
+            text(truncate_spans(spans(), 8, Truncation::Marquee, 20)),
+            "long_fil"
+        );
+    }
+
+    #[test]
+    fn test_components_utils_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.0 KiB");
+        assert_eq!(format_bytes(1_503_238_554), "1.4 GiB");
+        assert_eq!(format_bytes(1024 * 1024 - 1), "1.0 MiB");
+        assert_eq!(format_bytes(u64::MAX), "16.0 EiB");
+    }
+
+    #[test]
+    fn test_components_utils_format_duration() {
+        assert_eq!(format_duration(Duration::from_millis(850)), "850ms");
+        assert_eq!(format_duration(Duration::from_secs(45)), "45s");
+        assert_eq!(format_duration(Duration::from_secs(133)), "2m 13s");
+        assert_eq!(format_duration(Duration::from_secs(3605)), "1h");
+        assert_eq!(
+            format_duration(Duration::from_secs(3 * 86400 + 4 * 3600)),
+            "3d 4h"
+        );
+    }
+
+    #[test]
+    fn test_components_utils_format_number() {
+        assert_eq!(format_number(999.0), "999");
+        assert_eq!(format_number(12.34), "12.3");
+        assert_eq!(format_number(12_345.0), "12.3k");
+        assert_eq!(format_number(999_960.0), "1M");
+        assert_eq!(format_number(-4_000_000.0), "-4M");
+        assert_eq!(format_number(-0.01), "0");
+        assert_eq!(format_number(2.5e15), "2500T");
+    }
+
+    #[test]
+    fn test_components_utils_format_percent() {
+        assert_eq!(format_percent(0.606), "61%");
+        assert_eq!(format_percent(1.0), "100%");
+    }
+
+    #[test]
+    fn test_components_utils_format_timestamp() {
+        assert_eq!(
+            format_timestamp(0.0, "%Y-%m-%d %H:%M:%S"),
+            "1970-01-01 00:00:00"
+        );
+        // 2024-02-29 12:30:05 UTC
+        assert_eq!(
+            format_timestamp(1709209805.0, "%a %e %b %y, %I:%M %p"),
+            "Thu 29 Feb 24, 12:30 PM"
+        );
+        assert_eq!(format_timestamp(1709209805.0, "%H:%M"), "12:30");
+        // Before epoch
+        assert_eq!(
+            format_timestamp(-1.0, "%Y-%m-%d %H:%M:%S"),
+            "1969-12-31 23:59:59"
+        );
+        // Escapes and unknown specifiers
+        assert_eq!(format_timestamp(0.0, "100%% %q%"), "100% %q%");
+    }
+
+    #[test]
+    fn test_components_utils_date() {
+        let date = Date::new(2024, 2, 29).unwrap();
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert_eq!(Date::parse("2024-02-29"), Some(date));
+        assert_eq!(Date::parse("2023-02-29"), None);
+        assert_eq!(Date::parse("2024-13-01"), None);
+        assert_eq!(Date::parse("yesterday"), None);
+        assert_eq!(Date::from_days(0).to_string(), "1970-01-01");
+        assert_eq!(Date::from_days(-1).to_string(), "1969-12-31");
+        assert_eq!(date.to_days(), 19782);
+        assert_eq!(Date::from_days(date.to_days()), date);
+        // Thursday
+        assert_eq!(date.weekday(), 3);
+        assert_eq!(Date::from_days(0).weekday(), 3);
+        // Arithmetic
+        assert_eq!(date.add_days(1).to_string(), "2024-03-01");
+        assert_eq!(date.add_days(-60).to_string(), "2023-12-31");
+        assert_eq!(date.add_months(12).to_string(), "2025-02-28");
+        assert_eq!(date.add_months(-2).to_string(), "2023-12-29");
+        assert_eq!(date.first_of_month().to_string(), "2024-02-01");
+        assert!(Date::new(2024, 1, 31).unwrap() < date);
+        // Leap years
+        assert!(Date::is_leap_year(2000));
+        assert!(!Date::is_leap_year(1900));
+        assert_eq!(Date::days_in_month(2023, 2), 28);
+        assert_eq!(Date::days_in_month(2023, 4), 30);
+    }
+
+    #[test]
+    fn test_components_utils_time() {
+        assert_eq!(Time::new(24, 0, 0), None);
+        assert_eq!(Time::new(12, 60, 0), None);
+        let time = Time::parse("09:05").unwrap();
+        assert_eq!(time, Time::new(9, 5, 0).unwrap());
+        assert_eq!(time.to_string(), "09:05:00");
+        assert_eq!(Time::parse("23:59:59").unwrap().to_seconds(), 86399);
+        assert_eq!(Time::parse("9"), None);
+        assert_eq!(Time::parse("12:00:61"), None);
+        assert_eq!(
+            Time::from_seconds(86400 + 3661),
+            Time::new(1, 1, 1).unwrap()
+        );
+        assert_eq!(Time::new(0, 0, 0).unwrap().hour12(), (12, false));
+        assert_eq!(Time::new(12, 0, 0).unwrap().hour12(), (12, true));
+        assert_eq!(Time::new(15, 0, 0).unwrap().hour12(), (3, true));
+    }
 }